@@ -0,0 +1,210 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A vector with a fixed capacity stored inline, without any allocation
+
+use container::Container;
+use iter::{Iterator, DoubleEndedIterator, range};
+use mem::{forget, move_val_init, transmute, uninit};
+use ops::Drop;
+use option::{Option, Some, None};
+use ptr::{offset, read_ptr};
+use result::{Result, Ok, Err};
+use slice::Slice;
+use fail::abort;
+
+/// A fixed-size array type usable as inline storage, implemented for `[T, ..N]` with common sizes.
+pub trait Array<T> {
+    /// Return the number of elements in the array.
+    fn capacity(&self) -> uint;
+
+    /// Return a pointer to the first element.
+    fn as_ptr(&self) -> *T;
+}
+
+macro_rules! array_impl(
+    ($($n:expr),*) => {
+        $(
+            impl<T> Array<T> for [T, ..$n] {
+                #[inline(always)]
+                fn capacity(&self) -> uint { $n }
+
+                #[inline(always)]
+                fn as_ptr(&self) -> *T { unsafe { transmute(self) } }
+            }
+        )*
+    }
+)
+
+array_impl!(1, 2, 3, 4, 5, 6, 7, 8, 12, 16, 24, 32, 48, 64, 128, 256, 512, 1024)
+
+/// A vector storing up to the capacity of the array type `A` inline.
+pub struct ArrayVec<T, A> {
+    priv len: uint,
+    // Always `Some` while the vector is alive. Taking it out and forgetting it on destruction
+    // keeps the array's drop glue from running on uninitialized elements.
+    priv data: Option<A>
+}
+
+impl<T, A: Array<T>> ArrayVec<T, A> {
+    #[inline]
+    pub fn new() -> ArrayVec<T, A> {
+        ArrayVec { len: 0, data: Some(unsafe { uninit() }) }
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> uint {
+        self.storage().capacity()
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Append an element, handing it back as an error if the vector is full.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value)
+        }
+        unsafe {
+            let end = offset(self.storage().as_ptr(), self.len as int) as *mut T;
+            move_val_init(&mut *end, value);
+        }
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            unsafe { Some(read_ptr(offset(self.storage().as_ptr(), self.len as int))) }
+        }
+    }
+
+    pub fn truncate(&mut self, len: uint) {
+        let old_len = self.len;
+        if len < old_len {
+            // shorten the vector before running the destructors, so a failure leaks the rest
+            self.len = len;
+            unsafe {
+                for i in range(len, old_len) {
+                    read_ptr(offset(self.storage().as_ptr(), i as int));
+                }
+            }
+        }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    #[inline]
+    pub fn as_slice<'a>(&'a self) -> &'a [T] {
+        let slice = Slice { data: self.storage().as_ptr(), len: self.len };
+        unsafe { transmute(slice) }
+    }
+
+    #[inline]
+    pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [T] {
+        let slice = Slice { data: self.storage().as_ptr(), len: self.len };
+        unsafe { transmute(slice) }
+    }
+
+    pub fn move_iter(self) -> MoveItems<T, A> {
+        let mut xs = self;
+        let len = xs.len;
+        xs.len = 0;
+        MoveItems { data: xs.data.take(), start: 0, end: len }
+    }
+
+    pub unsafe fn set_len(&mut self, len: uint) {
+        self.len = len;
+    }
+
+    #[inline]
+    fn storage<'a>(&'a self) -> &'a A {
+        match self.data {
+            Some(ref data) => data,
+            None => abort()
+        }
+    }
+}
+
+impl<T, A: Array<T>> Container for ArrayVec<T, A> {
+    #[inline(always)]
+    fn len(&self) -> uint {
+        self.len
+    }
+}
+
+#[unsafe_destructor]
+impl<T, A: Array<T>> Drop for ArrayVec<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+        forget(self.data.take())
+    }
+}
+
+pub struct MoveItems<T, A> {
+    priv data: Option<A>,
+    priv start: uint,
+    priv end: uint
+}
+
+impl<T, A: Array<T>> MoveItems<T, A> {
+    #[inline]
+    unsafe fn read(&self, index: uint) -> T {
+        match self.data {
+            Some(ref data) => read_ptr(offset(data.as_ptr(), index as int)),
+            None => abort()
+        }
+    }
+}
+
+impl<T, A: Array<T>> Iterator<T> for MoveItems<T, A> {
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            self.start += 1;
+            unsafe { Some(self.read(self.start - 1)) }
+        }
+    }
+
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T, A: Array<T>> DoubleEndedIterator<T> for MoveItems<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            None
+        } else {
+            self.end -= 1;
+            unsafe { Some(self.read(self.end)) }
+        }
+    }
+}
+
+#[unsafe_destructor]
+impl<T, A: Array<T>> Drop for MoveItems<T, A> {
+    fn drop(&mut self) {
+        // destroy the remaining elements
+        for _x in *self {}
+        forget(self.data.take())
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fixed-size unsigned big integers for the exact float conversions in `flt2dec` and `dec2flt`.
+
+use cmp::{Eq, Ord};
+use fail::abort;
+use iter::range;
+use u32;
+
+// 4096 bits. The largest intermediate value is below 2^3800, in `dec2flt` when it divides 800
+// digits by a power of ten approaching 10^1127 with 53 bits of precision.
+static DIGITS: uint = 128;
+
+/// An unsigned integer of up to 4096 bits, stored as little endian 32-bit digits. Calls `abort` if
+/// an operation overflows.
+pub struct Big {
+    // the number of digits in use, the highest of which is non-zero
+    priv size: uint,
+    priv base: [u32, ..DIGITS]
+}
+
+impl Big {
+    pub fn from_u64(x: u64) -> Big {
+        let mut big = Big { size: 0, base: [0, ..DIGITS] };
+        big.base[0] = x as u32;
+        big.base[1] = (x >> 32) as u32;
+        big.size = if x >> 32 != 0 { 2 } else if x != 0 { 1 } else { 0 };
+        big
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Return the number of bits needed to represent the value.
+    pub fn bit_length(&self) -> uint {
+        if self.size == 0 {
+            0
+        } else {
+            self.size * 32 - u32::leading_zeros(self.base[self.size - 1])
+        }
+    }
+
+    fn push(&mut self, digit: u32) {
+        if self.size == DIGITS {
+            abort()
+        }
+        self.base[self.size] = digit;
+        self.size += 1;
+    }
+
+    pub fn add(&mut self, other: &Big) {
+        let size = if self.size > other.size { self.size } else { other.size };
+        let mut carry = 0u64;
+        for i in range(0, size) {
+            let a = if i < self.size { self.base[i] as u64 } else { 0 };
+            let b = if i < other.size { other.base[i] as u64 } else { 0 };
+            let sum = a + b + carry;
+            self.base[i] = sum as u32;
+            carry = sum >> 32;
+        }
+        self.size = size;
+        if carry != 0 {
+            self.push(carry as u32)
+        }
+    }
+
+    pub fn add_small(&mut self, x: u32) {
+        let mut carry = x as u64;
+        let mut i = 0;
+        while carry != 0 && i < self.size {
+            let sum = self.base[i] as u64 + carry;
+            self.base[i] = sum as u32;
+            carry = sum >> 32;
+            i += 1;
+        }
+        if carry != 0 {
+            self.push(carry as u32)
+        }
+    }
+
+    /// Subtract `other`, which must not be larger than `self`.
+    pub fn sub(&mut self, other: &Big) {
+        let mut borrow = 0u64;
+        for i in range(0, self.size) {
+            let a = self.base[i] as u64;
+            let b = (if i < other.size { other.base[i] as u64 } else { 0 }) + borrow;
+            if a >= b {
+                self.base[i] = (a - b) as u32;
+                borrow = 0;
+            } else {
+                self.base[i] = (a + (1 << 32) - b) as u32;
+                borrow = 1;
+            }
+        }
+        while self.size > 0 && self.base[self.size - 1] == 0 {
+            self.size -= 1;
+        }
+    }
+
+    pub fn mul_small(&mut self, x: u32) {
+        if x == 0 {
+            self.size = 0;
+            return
+        }
+        let mut carry = 0u64;
+        for i in range(0, self.size) {
+            let product = self.base[i] as u64 * x as u64 + carry;
+            self.base[i] = product as u32;
+            carry = product >> 32;
+        }
+        if carry != 0 {
+            self.push(carry as u32)
+        }
+    }
+
+    /// Multiply by `2^n`.
+    pub fn mul_pow2(&mut self, n: uint) {
+        if self.size == 0 {
+            return
+        }
+        let digits = n / 32;
+        let bits = n % 32;
+        if self.size + digits > DIGITS {
+            abort()
+        }
+        let mut i = self.size;
+        while i > 0 {
+            i -= 1;
+            self.base[i + digits] = self.base[i];
+        }
+        for i in range(0, digits) {
+            self.base[i] = 0;
+        }
+        self.size += digits;
+        if bits > 0 {
+            let mut carry = 0;
+            for i in range(digits, self.size) {
+                let x = self.base[i];
+                self.base[i] = (x << bits as u32) | carry;
+                carry = x >> (32 - bits) as u32;
+            }
+            if carry != 0 {
+                self.push(carry)
+            }
+        }
+    }
+
+    /// Multiply by `10^n`.
+    pub fn mul_pow10(&mut self, n: uint) {
+        let mut n = n;
+        while n >= 9 {
+            self.mul_small(1000000000);
+            n -= 9;
+        }
+        let mut x = 1;
+        for _ in range(0, n) {
+            x *= 10;
+        }
+        self.mul_small(x)
+    }
+}
+
+impl Eq for Big {
+    fn eq(&self, other: &Big) -> bool {
+        if self.size != other.size {
+            return false
+        }
+        for i in range(0, self.size) {
+            if self.base[i] != other.base[i] {
+                return false
+            }
+        }
+        true
+    }
+}
+
+impl Ord for Big {
+    fn lt(&self, other: &Big) -> bool {
+        if self.size != other.size {
+            return self.size < other.size
+        }
+        let mut i = self.size;
+        while i > 0 {
+            i -= 1;
+            if self.base[i] != other.base[i] {
+                return self.base[i] < other.base[i]
+            }
+        }
+        false
+    }
+}
@@ -10,6 +10,9 @@
 
 use option::{None, Some, Option};
 use mem::transmute;
+use container::Container;
+use fail::abort;
+use unicode;
 
 /// The highest valid code point
 pub static MAX: char = '\U0010ffff';
@@ -23,3 +26,283 @@ pub fn from_u32(i: u32) -> Option<char> {
         Some(unsafe { transmute(i) })
     }
 }
+
+/// The Unicode `General_Category` of a character.
+#[deriving(Eq, Clone)]
+pub enum GeneralCategory {
+    /// Uppercase letter
+    Lu,
+    /// Lowercase letter
+    Ll,
+    /// Titlecase letter
+    Lt,
+    /// Modifier letter
+    Lm,
+    /// Other letter
+    Lo,
+    /// Nonspacing mark
+    Mn,
+    /// Spacing mark
+    Mc,
+    /// Enclosing mark
+    Me,
+    /// Decimal number
+    Nd,
+    /// Letter number
+    Nl,
+    /// Other number
+    No,
+    /// Connector punctuation
+    Pc,
+    /// Dash punctuation
+    Pd,
+    /// Open punctuation
+    Ps,
+    /// Close punctuation
+    Pe,
+    /// Initial punctuation
+    Pi,
+    /// Final punctuation
+    Pf,
+    /// Other punctuation
+    Po,
+    /// Math symbol
+    Sm,
+    /// Currency symbol
+    Sc,
+    /// Modifier symbol
+    Sk,
+    /// Other symbol
+    So,
+    /// Space separator
+    Zs,
+    /// Line separator
+    Zl,
+    /// Paragraph separator
+    Zp,
+    /// Control
+    Cc,
+    /// Format
+    Cf,
+    /// Surrogate (never the category of a `char`)
+    Cs,
+    /// Private use
+    Co,
+    /// Unassigned
+    Cn
+}
+
+/// The Unicode `East_Asian_Width` of a character, as defined by UAX #11.
+#[deriving(Eq, Clone)]
+pub enum EastAsianWidth {
+    /// Narrow in East Asian contexts and wide otherwise, or the reverse
+    Ambiguous,
+    /// Fullwidth compatibility form of a narrow character
+    Fullwidth,
+    /// Halfwidth compatibility form of a wide character
+    Halfwidth,
+    /// Does not occur in legacy East Asian character sets
+    Neutral,
+    /// Narrow, with a wide counterpart
+    Narrow,
+    /// Always wide in East Asian typography
+    Wide
+}
+
+/// Return the `General_Category` of the character.
+pub fn general_category(c: char) -> GeneralCategory {
+    match unicode::general_category::lookup(c) {
+        Some(category) => category,
+        None => Cn
+    }
+}
+
+/// Return the `East_Asian_Width` of the character.
+pub fn east_asian_width(c: char) -> EastAsianWidth {
+    match unicode::east_asian_width::lookup(c) {
+        Some(width) => width,
+        None => Neutral
+    }
+}
+
+/// Return true if the character has the Unicode `Alphabetic` property.
+pub fn is_alphabetic(c: char) -> bool {
+    match c {
+        'a' .. 'z' | 'A' .. 'Z' => true,
+        c if c < '\x80' => false,
+        c => unicode::derived_property::alphabetic(c)
+    }
+}
+
+/// Return true if the character is in one of the Unicode number categories (`Nd`, `Nl` or `No`).
+pub fn is_numeric(c: char) -> bool {
+    match c {
+        '0' .. '9' => true,
+        c if c < '\x80' => false,
+        c => unicode::general_category::number(c)
+    }
+}
+
+/// Return true if the character has the Unicode `Lowercase` property.
+pub fn is_lowercase(c: char) -> bool {
+    match c {
+        'a' .. 'z' => true,
+        c if c < '\x80' => false,
+        c => unicode::derived_property::lowercase(c)
+    }
+}
+
+/// Return true if the character has the Unicode `Uppercase` property.
+pub fn is_uppercase(c: char) -> bool {
+    match c {
+        'A' .. 'Z' => true,
+        c if c < '\x80' => false,
+        c => unicode::derived_property::uppercase(c)
+    }
+}
+
+/// Return true if the character has the Unicode `White_Space` property.
+pub fn is_whitespace(c: char) -> bool {
+    match c {
+        ' ' | '\x09' .. '\x0d' => true,
+        c if c < '\x80' => false,
+        c => unicode::property::white_space(c)
+    }
+}
+
+/// Return true if the character is in the `Cc` (control) category.
+pub fn is_control(c: char) -> bool {
+    // the set of control characters is fixed by the Unicode stability policy
+    match c {
+        '\x00' .. '\x1f' | '\x7f' .. '\x9f' => true,
+        _ => false
+    }
+}
+
+/// Convert the character to uppercase with the simple (one-to-one) Unicode case mapping. Characters
+/// without an uppercase form are returned unchanged.
+pub fn to_uppercase(c: char) -> char {
+    match c {
+        'a' .. 'z' => ((c as u8) - 32) as char,
+        c if c < '\x80' => c,
+        c => match unicode::conversions::to_upper(c) {
+            Some(upper) => upper,
+            None => c
+        }
+    }
+}
+
+/// Convert the character to lowercase with the simple (one-to-one) Unicode case mapping. Characters
+/// without a lowercase form are returned unchanged.
+pub fn to_lowercase(c: char) -> char {
+    match c {
+        'A' .. 'Z' => ((c as u8) + 32) as char,
+        c if c < '\x80' => c,
+        c => match unicode::conversions::to_lower(c) {
+            Some(lower) => lower,
+            None => c
+        }
+    }
+}
+
+/// Convert the character to a digit in the given radix, returning `None` if it is not a valid
+/// digit. Calls `abort` if `radix` is not between 2 and 36.
+pub fn to_digit(c: char, radix: uint) -> Option<uint> {
+    if radix < 2 || radix > 36 {
+        abort()
+    }
+    let value = match c {
+        '0' .. '9' => c as uint - '0' as uint,
+        'a' .. 'z' => c as uint - 'a' as uint + 10,
+        'A' .. 'Z' => c as uint - 'A' as uint + 10,
+        _ => return None
+    };
+    if value < radix { Some(value) } else { None }
+}
+
+/// Convert a digit in the given radix to a character, using lowercase letters above 9. Returns
+/// `None` if `num` is not less than `radix`. Calls `abort` if `radix` is not between 2 and 36.
+pub fn from_digit(num: uint, radix: uint) -> Option<char> {
+    if radix < 2 || radix > 36 {
+        abort()
+    }
+    if num >= radix {
+        None
+    } else if num < 10 {
+        Some(('0' as u8 + num as u8) as char)
+    } else {
+        Some(('a' as u8 + (num - 10) as u8) as char)
+    }
+}
+
+/// Return the number of bytes needed to encode the character as UTF-8.
+#[inline]
+pub fn len_utf8_bytes(c: char) -> uint {
+    let code = c as u32;
+    if code < 0x80 {
+        1
+    } else if code < 0x800 {
+        2
+    } else if code < 0x10000 {
+        3
+    } else {
+        4
+    }
+}
+
+/// Encode the character as UTF-8 into `dst`, returning the number of bytes written. Calls `abort`
+/// if `dst` is too short.
+#[inline]
+pub fn encode_utf8(c: char, dst: &mut [u8]) -> uint {
+    let code = c as u32;
+    let n = len_utf8_bytes(c);
+    if dst.len() < n {
+        abort()
+    }
+    match n {
+        1 => {
+            dst[0] = code as u8;
+        }
+        2 => {
+            dst[0] = (code >> 6 & 0x1F) as u8 | 0xC0;
+            dst[1] = (code & 0x3F) as u8 | 0x80;
+        }
+        3 => {
+            dst[0] = (code >> 12 & 0x0F) as u8 | 0xE0;
+            dst[1] = (code >> 6 & 0x3F) as u8 | 0x80;
+            dst[2] = (code & 0x3F) as u8 | 0x80;
+        }
+        _ => {
+            dst[0] = (code >> 18 & 0x07) as u8 | 0xF0;
+            dst[1] = (code >> 12 & 0x3F) as u8 | 0x80;
+            dst[2] = (code >> 6 & 0x3F) as u8 | 0x80;
+            dst[3] = (code & 0x3F) as u8 | 0x80;
+        }
+    }
+    n
+}
+
+/// Return the number of 16-bit code units needed to encode the character as UTF-16.
+#[inline]
+pub fn len_utf16_units(c: char) -> uint {
+    if (c as u32) < 0x10000 { 1 } else { 2 }
+}
+
+/// Encode the character as UTF-16 into `dst`, returning the number of code units written. Calls
+/// `abort` if `dst` is too short.
+#[inline]
+pub fn encode_utf16(c: char, dst: &mut [u16]) -> uint {
+    let code = c as u32;
+    let n = len_utf16_units(c);
+    if dst.len() < n {
+        abort()
+    }
+    if n == 1 {
+        dst[0] = code as u16;
+    } else {
+        let code = code - 0x10000;
+        dst[0] = 0xD800 | (code >> 10) as u16;
+        dst[1] = 0xDC00 | (code & 0x3FF) as u16;
+    }
+    n
+}
@@ -22,8 +22,10 @@ use mem::transmute;
 use thread::{Mutex, Cond, Timeout};
 use cmp::{Eq, Ord};
 use option::{Some, None, Option};
+use result::{Result, Ok, Err};
 use hash::{Hash, HashMap};
 use vec::Vec;
+use iter::{Iterator, Extendable, range};
 use kinds::Send;
 use kinds::marker::NoFreeze;
 use time::{Time, monotonic};
@@ -31,11 +33,23 @@ use time::{Time, monotonic};
 trait GenericQueue<T>: Container {
     fn generic_push(&mut self, item: T);
     fn generic_pop(&mut self) -> Option<T>;
+
+    // Move all of the items to the end of `out`, in the order they would be popped.
+    fn generic_drain(&mut self, out: &mut Vec<T>) {
+        out.reserve_additional(self.len());
+        loop {
+            match self.generic_pop() {
+                Some(x) => out.push(x),
+                None => break
+            }
+        }
+    }
 }
 
 impl<T> GenericQueue<T> for Deque<T> {
     fn generic_push(&mut self, item: T) { self.push_back(item) }
     fn generic_pop(&mut self) -> Option<T> { self.pop_front() }
+    fn generic_drain(&mut self, out: &mut Vec<T>) { out.extend(&mut self.drain()) }
 }
 
 impl<T: Ord> GenericQueue<T> for PriorityQueue<T> {
@@ -82,6 +96,16 @@ impl<A: Send, T: GenericQueue<A>> QueuePtr<T> {
         }
     }
 
+    fn pop_all(&self) -> Vec<A> {
+        unsafe {
+            let ptr: &mut QueueBox<T> = transmute(self.ptr.borrow());
+            let _guard = ptr.mutex.lock_guard();
+            let mut items = Vec::new();
+            ptr.queue.generic_drain(&mut items);
+            items
+        }
+    }
+
     fn pop_timeout(&self, reltime: Time) -> Option<A> {
         unsafe {
             let mut abstime = monotonic().to_timespec();
@@ -136,6 +160,11 @@ impl<T: Send> Queue<T> {
         self.ptr.try_pop()
     }
 
+    /// Pop all of the values from the queue without blocking, in order from front to back.
+    pub fn pop_all(&self) -> Vec<T> {
+        self.ptr.pop_all()
+    }
+
     /// Pop a value from the front of the queue, blocking until the queue is not empty or the
     /// timeout expires.
     pub fn pop_timeout(&self, reltime: Time) -> Option<T> {
@@ -176,6 +205,11 @@ impl<T: Ord + Send> BlockingPriorityQueue<T> {
         self.ptr.try_pop()
     }
 
+    /// Pop all of the values from the queue without blocking, in order from largest to smallest.
+    pub fn pop_all(&self) -> Vec<T> {
+        self.ptr.pop_all()
+    }
+
     /// Pop the largest value from the queue, blocking until the queue is not empty or the timeout
     /// expires.
     pub fn pop_timeout(&self, reltime: Time) -> Option<T> {
@@ -245,6 +279,19 @@ impl<A: Send, T: GenericQueue<A>> BoundedQueuePtr<T> {
         }
     }
 
+    fn pop_all(&self) -> Vec<A> {
+        unsafe {
+            let ptr: &mut BoundedQueueBox<T> = transmute(self.ptr.borrow());
+            let _guard = ptr.mutex.lock_guard();
+            let mut items = Vec::new();
+            ptr.deque.generic_drain(&mut items);
+            if !items.is_empty() {
+                ptr.not_full.broadcast();
+            }
+            items
+        }
+    }
+
     fn pop_timeout(&self, reltime: Time) -> Option<A> {
         unsafe {
             let mut abstime = monotonic().to_timespec();
@@ -276,21 +323,21 @@ impl<A: Send, T: GenericQueue<A>> BoundedQueuePtr<T> {
         }
     }
 
-    fn try_push(&self, item: A) -> Option<A> {
+    fn try_push(&self, item: A) -> Result<(), A> {
         unsafe {
             let ptr: &mut BoundedQueueBox<T> = transmute(self.ptr.borrow());
             let _guard = ptr.mutex.lock_guard();
             if ptr.deque.len() == ptr.maximum {
-                Some(item)
+                Err(item)
             } else {
                 ptr.deque.generic_push(item);
                 ptr.not_empty.signal();
-                None
+                Ok(())
             }
         }
     }
 
-    fn push_timeout(&self, item: A, reltime: Time) -> Option<A> {
+    fn push_timeout(&self, item: A, reltime: Time) -> Result<(), A> {
         unsafe {
             let mut abstime = monotonic().to_timespec();
             abstime.tv_sec += reltime.to_timespec().tv_sec;
@@ -300,12 +347,12 @@ impl<A: Send, T: GenericQueue<A>> BoundedQueuePtr<T> {
             let mut guard = ptr.mutex.lock_guard();
             while ptr.deque.len() == ptr.maximum {
                 if ptr.not_full.wait_until_guard(&mut guard, Time::from_timespec(abstime)) == Timeout {
-                    return Some(item)
+                    return Err(item)
                 }
             }
             ptr.deque.generic_push(item);
             ptr.not_empty.signal();
-            None
+            Ok(())
         }
     }
 }
@@ -337,6 +384,11 @@ impl<T: Send> BoundedQueue<T> {
         self.ptr.try_pop()
     }
 
+    /// Pop all of the values from the queue without blocking, in order from front to back.
+    pub fn pop_all(&self) -> Vec<T> {
+        self.ptr.pop_all()
+    }
+
     /// Pop a value from the front of the queue, blocking until the queue is not empty or the
     /// timeout expires.
     pub fn pop_timeout(&self, reltime: Time) -> Option<T> {
@@ -348,14 +400,14 @@ impl<T: Send> BoundedQueue<T> {
         self.ptr.push(item)
     }
 
-    /// Push a value to the back of the queue, or return `Some(item)` if the queue is full.
-    pub fn try_push(&self, item: T) -> Option<T> {
+    /// Push a value to the back of the queue, or return `Err(item)` if the queue is full.
+    pub fn try_push(&self, item: T) -> Result<(), T> {
         self.ptr.try_push(item)
     }
 
     /// Push a value to the back of the queue, blocking until the queue is not full or the timeout
-    /// expires. If the timeout expires, return `Some(item)`.
-    pub fn push_timeout(&self, item: T, reltime: Time) -> Option<T> {
+    /// expires. If the timeout expires, return `Err(item)`.
+    pub fn push_timeout(&self, item: T, reltime: Time) -> Result<(), T> {
         self.ptr.push_timeout(item, reltime)
     }
 }
@@ -388,6 +440,11 @@ impl<T: Ord + Send> BoundedPriorityQueue<T> {
         self.ptr.try_pop()
     }
 
+    /// Pop all of the values from the queue without blocking, in order from largest to smallest.
+    pub fn pop_all(&self) -> Vec<T> {
+        self.ptr.pop_all()
+    }
+
     /// Pop the largest value from the queue, blocking until the queue is not empty or the timeout
     /// expires.
     pub fn pop_timeout(&self, reltime: Time) -> Option<T> {
@@ -399,14 +456,14 @@ impl<T: Ord + Send> BoundedPriorityQueue<T> {
         self.ptr.push(item)
     }
 
-    /// Push a value into the queue, or return `Some(item)` if the queue is full.
-    pub fn try_push(&self, item: T) -> Option<T> {
+    /// Push a value into the queue, or return `Err(item)` if the queue is full.
+    pub fn try_push(&self, item: T) -> Result<(), T> {
         self.ptr.try_push(item)
     }
 
     /// Push a value into the queue, blocking until the queue is not full or the timeout expires. If
-    /// the timeout expires, return `Some(item)`.
-    pub fn push_timeout(&self, item: T, reltime: Time) -> Option<T> {
+    /// the timeout expires, return `Err(item)`.
+    pub fn push_timeout(&self, item: T, reltime: Time) -> Result<(), T> {
         self.ptr.push_timeout(item, reltime)
     }
 }
@@ -532,10 +589,8 @@ impl<K: Hash + Eq + Send, V: Send> ShardMap<K, V> {
     /// (`k0` and `k1`) and an initial `capacity`.
     pub fn with_capacity_and_keys(shards: uint, k0: u64, k1: u64, capacity: uint) -> ShardMap<K, V> {
         let mut xs = Vec::with_capacity(shards);
-        let mut i = 0;
-        while i < shards {
+        for _ in range(0, shards) {
             xs.push(LockedHashMap::with_capacity_and_keys(k0, k1, capacity));
-            i += 1;
         }
         let inner = ShardMapBox { maps: xs, k0: k0, k1: k1, no_freeze: NoFreeze };
         unsafe {
@@ -0,0 +1,336 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversion of decimal strings to floating point numbers.
+//!
+//! The accepted syntax is an optional `+` or `-` sign followed by either `inf`, `infinity` or
+//! `nan` in any case, or decimal digits with an optional fractional part and exponent, as in
+//! `12`, `-0.5`, `.5`, `5.` or `6.02e23`. There must be at least one digit before or after the
+//! point, and no whitespace.
+//!
+//! The result is correctly rounded to the nearest float, with ties to even. Short inputs are
+//! converted with a single exact floating point operation, and the rest with big integer
+//! arithmetic.
+
+use bignum::Big;
+use container::Container;
+use from_str::FromStr;
+use iter::range;
+use mem::transmute;
+use option::{Option, Some, None};
+use slice::{slice_from, slice_to};
+use str::as_bytes;
+
+// Digits after this many only matter through whether any of them is non-zero, since a value
+// halfway between two floats has at most 767 significant digits. Those are replaced by a single
+// non-zero digit.
+static MAX_DIGITS: uint = 800;
+static DIGITS_LEN: uint = 801;
+
+// The exponents and significand bits of a binary float format.
+struct Format {
+    // the number of significand bits, including the hidden bit
+    sig_bits: uint,
+    // the smallest and largest exponents of the lowest bit of a finite value
+    min_exp: int,
+    max_exp: int
+}
+
+static F32: Format = Format { sig_bits: 24, min_exp: -149, max_exp: 104 };
+static F64: Format = Format { sig_bits: 53, min_exp: -1074, max_exp: 971 };
+
+static F32_POW10: [f32, ..11] = [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10];
+static F64_POW10: [f64, ..23] = [1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11,
+                                 1e12, 1e13, 1e14, 1e15, 1e16, 1e17, 1e18, 1e19, 1e20, 1e21,
+                                 1e22];
+
+enum Parsed {
+    Infinity,
+    NotANumber,
+    // the number of significant digits and the exponent of the last one, without trailing zeroes
+    Number(uint, int)
+}
+
+fn eq_ignore_case(bytes: &[u8], lower: &[u8]) -> bool {
+    if bytes.len() != lower.len() {
+        return false
+    }
+    for i in range(0, bytes.len()) {
+        let b = bytes[i];
+        let b = if b >= 'A' as u8 && b <= 'Z' as u8 { b + 32 } else { b };
+        if b != lower[i] {
+            return false
+        }
+    }
+    true
+}
+
+fn is_digit(b: u8) -> bool {
+    b >= '0' as u8 && b <= '9' as u8
+}
+
+// Parse the syntax, storing the significant digits as values from 0 to 9 in `digits`. Returns
+// whether the sign is negative along with the parsed value.
+fn parse_decimal(bytes: &[u8], digits: &mut [u8]) -> Option<(bool, Parsed)> {
+    let mut pos = 0;
+    let mut negative = false;
+    if pos < bytes.len() && (bytes[pos] == '+' as u8 || bytes[pos] == '-' as u8) {
+        negative = bytes[pos] == '-' as u8;
+        pos += 1;
+    }
+    let rest = slice_from(bytes, pos);
+    if eq_ignore_case(rest, bytes!("inf")) || eq_ignore_case(rest, bytes!("infinity")) {
+        return Some((negative, Infinity))
+    }
+    if eq_ignore_case(rest, bytes!("nan")) {
+        return Some((negative, NotANumber))
+    }
+
+    let mut len = 0;
+    let mut exp = 0i;
+    let mut any_digits = false;
+    let mut dropped_non_zero = false;
+    let mut fraction = false;
+    loop {
+        if pos < bytes.len() && is_digit(bytes[pos]) {
+            let digit = bytes[pos] - '0' as u8;
+            any_digits = true;
+            if len == 0 && digit == 0 {
+                // a leading zero
+                if fraction {
+                    exp -= 1;
+                }
+            } else if len < MAX_DIGITS {
+                digits[len] = digit;
+                len += 1;
+                if fraction {
+                    exp -= 1;
+                }
+            } else {
+                dropped_non_zero = dropped_non_zero || digit != 0;
+                if !fraction {
+                    exp += 1;
+                }
+            }
+        } else if pos < bytes.len() && bytes[pos] == '.' as u8 && !fraction {
+            fraction = true;
+        } else {
+            break
+        }
+        pos += 1;
+    }
+    if !any_digits {
+        return None
+    }
+
+    if pos < bytes.len() && (bytes[pos] == 'e' as u8 || bytes[pos] == 'E' as u8) {
+        pos += 1;
+        let mut exp_negative = false;
+        if pos < bytes.len() && (bytes[pos] == '+' as u8 || bytes[pos] == '-' as u8) {
+            exp_negative = bytes[pos] == '-' as u8;
+            pos += 1;
+        }
+        if pos == bytes.len() || !is_digit(bytes[pos]) {
+            return None
+        }
+        // saturate, as anything this large overflows or underflows
+        let mut e = 0i;
+        while pos < bytes.len() && is_digit(bytes[pos]) {
+            if e < 100000 {
+                e = e * 10 + (bytes[pos] - '0' as u8) as int;
+            }
+            pos += 1;
+        }
+        exp += if exp_negative { -e } else { e };
+    }
+    if pos != bytes.len() {
+        return None
+    }
+
+    if dropped_non_zero {
+        digits[len] = 1;
+        len += 1;
+        exp -= 1;
+    }
+    while len > 0 && digits[len - 1] == 0 {
+        len -= 1;
+        exp += 1;
+    }
+    Some((negative, Number(len, exp)))
+}
+
+fn infinity_bits(fmt: &Format) -> u64 {
+    ((fmt.max_exp - fmt.min_exp + 2) as u64) << (fmt.sig_bits - 1) as u64
+}
+
+fn nan_bits(fmt: &Format) -> u64 {
+    infinity_bits(fmt) | 1 << (fmt.sig_bits - 2) as u64
+}
+
+// Round `digits * 10^exp` to the format, returning the bits of the float without the sign.
+//
+// With `num / den` as the exact value, this finds the exponent `k` for which `num / (den * 2^k)`
+// has `sig_bits` bits, or fewer for a subnormal. Then it computes that quotient by long division
+// and rounds it with the remainder.
+fn big_to_bits(digits: &[u8], exp: int, fmt: &Format) -> u64 {
+    let mut num = Big::from_u64(0);
+    for i in range(0, digits.len()) {
+        num.mul_small(10);
+        num.add_small(digits[i] as u32);
+    }
+    let mut den = Big::from_u64(1);
+    if exp >= 0 {
+        num.mul_pow10(exp as uint);
+    } else {
+        den.mul_pow10((-exp) as uint);
+    }
+
+    let sig_bits = fmt.sig_bits;
+    let mut k = num.bit_length() as int - den.bit_length() as int - sig_bits as int;
+    if k < fmt.min_exp {
+        k = fmt.min_exp;
+    }
+    if k >= 0 {
+        den.mul_pow2(k as uint);
+    } else {
+        num.mul_pow2((-k) as uint);
+    }
+    // the estimate from the bit lengths may be one too small
+    let mut top = den;
+    top.mul_pow2(sig_bits);
+    if num >= top {
+        den.mul_pow2(1);
+        k += 1;
+    }
+
+    let mut q = 0u64;
+    let mut i = sig_bits;
+    while i > 0 {
+        i -= 1;
+        let mut shifted = den;
+        shifted.mul_pow2(i);
+        if num >= shifted {
+            num.sub(&shifted);
+            q |= 1 << i as u64;
+        }
+    }
+
+    // round half to even, which may carry into the exponent
+    num.mul_pow2(1);
+    if num > den || (num == den && q & 1 == 1) {
+        q += 1;
+        if q == 1 << sig_bits as u64 {
+            q >>= 1;
+            k += 1;
+        }
+    }
+    if k > fmt.max_exp {
+        return infinity_bits(fmt)
+    }
+    let hidden = 1u64 << (sig_bits - 1) as u64;
+    if q < hidden {
+        q
+    } else {
+        ((k - fmt.min_exp + 1) as u64 << (sig_bits - 1) as u64) | (q - hidden)
+    }
+}
+
+fn to_bits(parsed: Parsed, digits: &[u8], fmt: &Format) -> u64 {
+    match parsed {
+        Infinity => infinity_bits(fmt),
+        NotANumber => nan_bits(fmt),
+        Number(0, _) => 0,
+        Number(len, exp) => {
+            // rule out values far outside the range of both formats, which could overflow the big
+            // integers
+            let magnitude = len as int + exp;
+            if magnitude > 310 {
+                infinity_bits(fmt)
+            } else if magnitude < -326 {
+                0
+            } else {
+                big_to_bits(slice_to(digits, len), exp, fmt)
+            }
+        }
+    }
+}
+
+fn mantissa(digits: &[u8]) -> u64 {
+    let mut x = 0;
+    for i in range(0, digits.len()) {
+        x = x * 10 + digits[i] as u64;
+    }
+    x
+}
+
+// With up to 7 digits and a power of ten up to 10^10, both are exact and a single operation rounds
+// correctly.
+fn fast_path_f32(parsed: &Parsed, digits: &[u8]) -> Option<f32> {
+    match *parsed {
+        Number(len, exp) if len <= 7 && exp >= -10 && exp <= 10 => {
+            let m = mantissa(slice_to(digits, len)) as f32;
+            Some(if exp >= 0 { m * F32_POW10[exp as uint] } else { m / F32_POW10[(-exp) as uint] })
+        }
+        _ => None
+    }
+}
+
+// The same for up to 15 digits and 10^22.
+fn fast_path_f64(parsed: &Parsed, digits: &[u8]) -> Option<f64> {
+    match *parsed {
+        Number(len, exp) if len <= 15 && exp >= -22 && exp <= 22 => {
+            let m = mantissa(slice_to(digits, len)) as f64;
+            Some(if exp >= 0 { m * F64_POW10[exp as uint] } else { m / F64_POW10[(-exp) as uint] })
+        }
+        _ => None
+    }
+}
+
+/// Parse a `f32` from ASCII bytes, returning `None` if the syntax is invalid.
+pub fn parse_f32(bytes: &[u8]) -> Option<f32> {
+    let mut digits = [0u8, ..DIGITS_LEN];
+    let (negative, parsed) = match parse_decimal(bytes, digits) {
+        Some(x) => x,
+        None => return None
+    };
+    let x = match fast_path_f32(&parsed, digits) {
+        Some(x) => x,
+        None => unsafe { transmute(to_bits(parsed, digits, &F32) as u32) }
+    };
+    Some(if negative { -x } else { x })
+}
+
+/// Parse a `f64` from ASCII bytes, returning `None` if the syntax is invalid.
+pub fn parse_f64(bytes: &[u8]) -> Option<f64> {
+    let mut digits = [0u8, ..DIGITS_LEN];
+    let (negative, parsed) = match parse_decimal(bytes, digits) {
+        Some(x) => x,
+        None => return None
+    };
+    let x = match fast_path_f64(&parsed, digits) {
+        Some(x) => x,
+        None => unsafe { transmute(to_bits(parsed, digits, &F64)) }
+    };
+    Some(if negative { -x } else { x })
+}
+
+impl FromStr for f32 {
+    #[inline]
+    fn from_str(s: &str) -> Option<f32> {
+        parse_f32(as_bytes(s))
+    }
+}
+
+impl FromStr for f64 {
+    #[inline]
+    fn from_str(s: &str) -> Option<f64> {
+        parse_f64(as_bytes(s))
+    }
+}
@@ -16,13 +16,19 @@
 // non-nullable pointers.
 
 use container::Container;
-use mem::move_val_init;
-use ptr::read_ptr;
-use ops::Drop;
+use clone::Clone;
+use mem::{move_val_init, transmute};
+use ptr::{copy_memory, offset, read_ptr};
+use ops::{Drop, Index};
 use vec::Vec;
-use slice::{unchecked_get, unchecked_mut_get, unchecked_swap};
+use slice;
+use slice::{Slice, mut_split, mut_slice_to, split, slice_to, to_mut_ptr, to_ptr, unchecked_get,
+            unchecked_mut_get, unchecked_swap};
 use fail::{abort, assert};
 use option::{Option, Some, None};
+use iter::{Iterator, DoubleEndedIterator, FromIterator, Extendable, range};
+use cmp::{Eq, Ord, max};
+use uint::add_with_overflow;
 
 pub struct Deque<T> {
     priv nelts: uint,
@@ -59,8 +65,24 @@ impl<T> Deque<T> {
         self.elts.capacity()
     }
 
+    /// Reserve capacity for at least `capacity` elements. Elements wrapped around the end of the
+    /// buffer are moved to the end of the new allocation to keep the ring contiguous.
     pub fn reserve(&mut self, capacity: uint) {
-        self.elts.reserve(capacity)
+        let old_capacity = self.capacity();
+        if capacity <= old_capacity {
+            return
+        }
+        self.elts.reserve(capacity);
+        if self.lo > old_capacity - self.nelts {
+            let head_len = old_capacity - self.lo;
+            let new_lo = capacity - head_len;
+            unsafe {
+                let base = to_mut_ptr(self.elts.as_mut_slice()) as *T;
+                copy_memory(offset(base, new_lo as int) as *mut T, offset(base, self.lo as int),
+                            head_len);
+            }
+            self.lo = new_lo;
+        }
     }
 
     pub fn get<'a>(&'a self, index: uint) -> Option<&'a T> {
@@ -145,10 +167,248 @@ impl<T> Deque<T> {
         self.nelts += 1;
     }
 
+    pub fn truncate(&mut self, len: uint) {
+        let old_len = self.nelts;
+        if len < old_len {
+            // shorten the queue before running the destructors, so a failure leaks the rest
+            self.nelts = len;
+            for i in range(len, old_len) {
+                let idx = self.raw_index(i);
+                unsafe {
+                    read_ptr(unchecked_get(self.elts.as_slice(), idx));
+                }
+            }
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+        self.lo = 0;
+    }
+
+    /// Insert an element at `index`, shifting the elements on the shorter side of it. Calls
+    /// `abort` if `index` is greater than the length.
+    pub fn insert(&mut self, index: uint, value: T) {
+        if index > self.nelts {
+            abort()
+        }
+        if index < self.nelts / 2 {
+            self.push_front(value);
+            for i in range(0, index) {
+                self.swap(i, i + 1)
+            }
+        } else {
+            self.push_back(value);
+            for i in range(index, self.nelts - 1).invert() {
+                self.swap(i, i + 1)
+            }
+        }
+    }
+
+    /// Remove and return the element at `index`, shifting the elements on the shorter side of it.
+    pub fn remove(&mut self, index: uint) -> Option<T> {
+        if index >= self.nelts {
+            None
+        } else if index < self.nelts / 2 {
+            for i in range(0, index).invert() {
+                self.swap(i, i + 1)
+            }
+            self.pop_front()
+        } else {
+            for i in range(index, self.nelts - 1) {
+                self.swap(i, i + 1)
+            }
+            self.pop_back()
+        }
+    }
+
+    /// Retain only the elements satisfying the predicate, preserving their order.
+    pub fn retain(&mut self, f: |&T| -> bool) {
+        // Each element is popped from the front and pushed back if it is kept, so the queue is
+        // always in a consistent state and never needs to grow.
+        for _ in range(0, self.nelts) {
+            let x = self.pop_front().get();
+            if f(&x) {
+                self.push_back(x)
+            }
+        }
+    }
+
+    /// Rotate the queue so that the element at `mid` comes first. Calls `abort` if `mid` is
+    /// greater than the length.
+    pub fn rotate_left(&mut self, mid: uint) {
+        if mid > self.nelts {
+            abort()
+        }
+        if self.nelts == self.capacity() {
+            // there are no free slots, so only the start of the ring has to move
+            self.lo = self.raw_index(mid);
+        } else if mid <= self.nelts / 2 {
+            for _ in range(0, mid) {
+                let x = self.pop_front().get();
+                self.push_back(x)
+            }
+        } else {
+            for _ in range(mid, self.nelts) {
+                let x = self.pop_back().get();
+                self.push_front(x)
+            }
+        }
+    }
+
+    /// Rotate the queue so that the last `k` elements come first. Calls `abort` if `k` is greater
+    /// than the length.
+    pub fn rotate_right(&mut self, k: uint) {
+        if k > self.nelts {
+            abort()
+        }
+        let mid = self.nelts - k;
+        self.rotate_left(mid)
+    }
+
+    /// Return the elements as two slices, the front of the queue followed by the part wrapped
+    /// around to the start of the buffer. The second slice is empty if the ring is contiguous.
+    pub fn as_slices<'a>(&'a self) -> (&'a [T], &'a [T]) {
+        let (wrapped, head) = split(self.ring(), self.lo);
+        if self.nelts <= head.len() {
+            (slice_to(head, self.nelts), slice_to(wrapped, 0))
+        } else {
+            (head, slice_to(wrapped, self.nelts - head.len()))
+        }
+    }
+
+    /// Return the elements as two mutable slices, in the same way as `as_slices`.
+    pub fn as_mut_slices<'a>(&'a mut self) -> (&'a mut [T], &'a mut [T]) {
+        let (nelts, lo) = (self.nelts, self.lo);
+        let (wrapped, head) = mut_split(self.mut_ring(), lo);
+        if nelts <= head.len() {
+            (mut_slice_to(head, nelts), mut_slice_to(wrapped, 0))
+        } else {
+            let wrapped_len = nelts - head.len();
+            (head, mut_slice_to(wrapped, wrapped_len))
+        }
+    }
+
+    /// Move the elements to be contiguous in the buffer if they wrap around its end, and return
+    /// them as a single slice.
+    pub fn make_contiguous<'a>(&'a mut self) -> &'a mut [T] {
+        if self.lo > self.capacity() - self.nelts {
+            let lo = self.lo;
+            slice::rotate_left(self.mut_ring(), lo);
+            self.lo = 0;
+        }
+        let (front, _) = self.as_mut_slices();
+        front
+    }
+
+    /// Shrink the capacity to the length, moving the elements to the start of the buffer.
+    pub fn shrink_to_fit(&mut self) {
+        let lo = self.lo;
+        slice::rotate_left(self.mut_ring(), lo);
+        self.lo = 0;
+        unsafe {
+            // the vector only considers its first `len` slots initialized while shrinking
+            self.elts.set_len(self.nelts);
+            self.elts.shrink_to_fit();
+            self.elts.set_len(0);
+        }
+    }
+
+    pub fn iter<'a>(&'a self) -> Items<'a, T> {
+        Items { ring: self.ring(), lo: self.lo, index: 0, end: self.nelts }
+    }
+
+    pub fn mut_iter<'a>(&'a mut self) -> MutItems<'a, T> {
+        let (lo, nelts) = (self.lo, self.nelts);
+        MutItems { ring: self.mut_ring(), lo: lo, index: 0, end: nelts }
+    }
+
+    pub fn move_iter(self) -> MoveItems<T> {
+        MoveItems { deque: self }
+    }
+
+    /// Remove all of the elements, returning them from an iterator in order from front to back.
+    /// Any elements not yielded are dropped along with the iterator.
+    pub fn drain<'a>(&'a mut self) -> Drain<'a, T> {
+        Drain { deque: self }
+    }
+
     #[inline(always)]
     fn raw_index(&self, index: uint) -> uint {
         raw_index(self.lo, self.capacity(), index)
     }
+
+    // The whole buffer, including the free slots, which are uninitialized.
+    #[inline]
+    fn ring<'a>(&'a self) -> &'a [T] {
+        let slice = Slice { data: to_ptr(self.elts.as_slice()), len: self.capacity() };
+        unsafe { transmute(slice) }
+    }
+
+    #[inline]
+    fn mut_ring<'a>(&'a mut self) -> &'a mut [T] {
+        let slice = Slice { data: to_ptr(self.elts.as_slice()), len: self.capacity() };
+        unsafe { transmute(slice) }
+    }
+}
+
+impl<T> FromIterator<T> for Deque<T> {
+    fn from_iterator<I: Iterator<T>>(iterator: &mut I) -> Deque<T> {
+        let (lower, _) = iterator.size_hint();
+        let mut deque = Deque::with_capacity(lower);
+        deque.extend(iterator);
+        deque
+    }
+}
+
+impl<T> Extendable<T> for Deque<T> {
+    fn extend<I: Iterator<T>>(&mut self, iterator: &mut I) {
+        let (lower, _) = iterator.size_hint();
+        let (needed, overflow) = add_with_overflow(self.nelts, lower);
+        if overflow {
+            abort()
+        }
+        if needed > self.capacity() {
+            let capacity = self.capacity();
+            self.reserve(max(needed, capacity * 2));
+        }
+        for x in *iterator {
+            self.push_back(x);
+        }
+    }
+}
+
+impl<T: Clone> Clone for Deque<T> {
+    fn clone(&self) -> Deque<T> {
+        self.iter().map(|x| x.clone()).collect()
+    }
+}
+
+impl<T: Eq> Eq for Deque<T> {
+    fn eq(&self, other: &Deque<T>) -> bool {
+        self.nelts == other.nelts && self.iter().zip(other.iter()).all(|(x, y)| *x == *y)
+    }
+}
+
+impl<T: Ord> Ord for Deque<T> {
+    /// Lexicographic comparison, where a proper prefix orders first.
+    fn lt(&self, other: &Deque<T>) -> bool {
+        for (x, y) in self.iter().zip(other.iter()) {
+            if *x < *y { return true }
+            if *y < *x { return false }
+        }
+        self.nelts < other.nelts
+    }
+}
+
+impl<T: Clone> Index<uint, T> for Deque<T> {
+    /// Return a copy of the element at `index`, calling `abort` if it is out of bounds.
+    fn index(&self, index: &uint) -> T {
+        match self.get(*index) {
+            Some(x) => x.clone(),
+            None => abort()
+        }
+    }
 }
 
 #[unsafe_destructor]
@@ -157,18 +417,139 @@ impl<T> Drop for Deque<T> {
         // Make sure the Vec destructor isn't going to ruin our day
         assert(self.elts.len() == 0);
 
-        let mut i = 0;
-        let len = self.len();
-        while i < len {
+        for i in range(0, self.len()) {
             let idx = self.raw_index(i);
             unsafe {
                 read_ptr(unchecked_get(self.elts.as_slice(), idx));
             }
-            i += 1;
         }
     }
 }
 
+pub struct Items<'a, T> {
+    priv ring: &'a [T],
+    priv lo: uint,
+    priv index: uint,
+    priv end: uint
+}
+
+impl<'a, T> Iterator<&'a T> for Items<'a, T> {
+    fn next(&mut self) -> Option<&'a T> {
+        if self.index == self.end {
+            None
+        } else {
+            let idx = raw_index(self.lo, self.ring.len(), self.index);
+            self.index += 1;
+            unsafe { Some(unchecked_get(self.ring, idx)) }
+        }
+    }
+
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator<&'a T> for Items<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.index == self.end {
+            None
+        } else {
+            self.end -= 1;
+            let idx = raw_index(self.lo, self.ring.len(), self.end);
+            unsafe { Some(unchecked_get(self.ring, idx)) }
+        }
+    }
+}
+
+pub struct MutItems<'a, T> {
+    priv ring: &'a mut [T],
+    priv lo: uint,
+    priv index: uint,
+    priv end: uint
+}
+
+impl<'a, T> Iterator<&'a mut T> for MutItems<'a, T> {
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.index == self.end {
+            None
+        } else {
+            let idx = raw_index(self.lo, self.ring.len(), self.index);
+            self.index += 1;
+            // each element is yielded at most once, so the references never alias
+            unsafe { Some(transmute(unchecked_mut_get(self.ring, idx))) }
+        }
+    }
+
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator<&'a mut T> for MutItems<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.index == self.end {
+            None
+        } else {
+            self.end -= 1;
+            let idx = raw_index(self.lo, self.ring.len(), self.end);
+            unsafe { Some(transmute(unchecked_mut_get(self.ring, idx))) }
+        }
+    }
+}
+
+pub struct MoveItems<T> {
+    priv deque: Deque<T>
+}
+
+impl<T> Iterator<T> for MoveItems<T> {
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        (self.deque.len(), Some(self.deque.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator<T> for MoveItems<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+}
+
+pub struct Drain<'a, T> {
+    priv deque: &'a mut Deque<T>
+}
+
+impl<'a, T> Iterator<T> for Drain<'a, T> {
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        (self.deque.len(), Some(self.deque.len()))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator<T> for Drain<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+}
+
+#[unsafe_destructor]
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        self.deque.clear()
+    }
+}
+
 fn grow<T>(nelts: uint, loptr: &mut uint, elts: &mut Vec<T>) {
     assert(nelts == elts.capacity());
     let lo = *loptr;
@@ -183,18 +564,14 @@ fn grow<T>(nelts: uint, loptr: &mut uint, elts: &mut Vec<T>) {
     if lo <= (nelts - lo) {
         // Before: [o o o|o o o o o]
         // After:  [. . .|o o o o o o o o|. . . . .]
-        let mut i = 0;
-        while i < lo {
+        for i in range(0, lo) {
             unsafe { unchecked_swap(elts.as_mut_slice(), i, nelts + i); }
-            i += 1;
         }
     } else {
         // Before: [o o o o o|o o o]
         // After:  [o o o o o|. . . . . . . .|o o o]
-        let mut i = lo;
-        while i < nelts {
+        for i in range(lo, nelts) {
             unsafe { unchecked_swap(elts.as_mut_slice(), i, newlen - nelts + i); }
-            i += 1;
         }
         *loptr += newlen - nelts;
     }
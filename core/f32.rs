@@ -8,9 +8,74 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use c_types::c_int;
-use mem::uninit;
+// the `libm` functions are safe, so wrapping them in `unsafe` blocks is redundant without `libc`
+#[allow(unused_unsafe)];
 
+use c_types::c_int;
+use cmp::{Ordering, Less, Equal, Greater};
+use mem::{transmute, uninit};
+use num::{FPCategory, FPNaN, FPInfinite, FPZero, FPSubnormal, FPNormal};
+
+pub static RADIX: uint = 2;
+/// The number of significand bits, including the hidden bit.
+pub static MANTISSA_DIGITS: uint = 24;
+/// The number of decimal digits which always survive a round trip through the type.
+pub static DIGITS: uint = 6;
+/// The difference between 1.0 and the next larger representable value.
+pub static EPSILON: f32 = 1.19209290e-07;
+/// The smallest positive normal value.
+pub static MIN_POSITIVE: f32 = 1.17549435e-38;
+/// The smallest finite value.
+pub static MIN: f32 = -3.40282347e+38;
+/// The largest finite value.
+pub static MAX: f32 = 3.40282347e+38;
+/// One more than the smallest and largest binary exponents of a normal value, as in C.
+pub static MIN_EXP: int = -125;
+pub static MAX_EXP: int = 128;
+/// The smallest and largest decimal exponents of a normal value.
+pub static MIN_10_EXP: int = -37;
+pub static MAX_10_EXP: int = 38;
+pub static INFINITY: f32 = 1.0 / 0.0;
+pub static NEG_INFINITY: f32 = -1.0 / 0.0;
+pub static NAN: f32 = 0.0 / 0.0;
+
+/// Mathematical constants, rounded to the nearest representable value.
+pub mod consts {
+    /// pi
+    pub static PI: f32 = 3.14159265358979323846264338327950288;
+    /// pi / 2
+    pub static FRAC_PI_2: f32 = 1.57079632679489661923132169163975144;
+    /// pi / 3
+    pub static FRAC_PI_3: f32 = 1.04719755119659774615421446109316763;
+    /// pi / 4
+    pub static FRAC_PI_4: f32 = 0.785398163397448309615660845819875721;
+    /// pi / 6
+    pub static FRAC_PI_6: f32 = 0.52359877559829887307710723054658381;
+    /// pi / 8
+    pub static FRAC_PI_8: f32 = 0.39269908169872415480783042290993786;
+    /// 1 / pi
+    pub static FRAC_1_PI: f32 = 0.318309886183790671537767526745028724;
+    /// 2 / pi
+    pub static FRAC_2_PI: f32 = 0.636619772367581343075535053490057448;
+    /// 2 / sqrt(pi)
+    pub static FRAC_2_SQRTPI: f32 = 1.12837916709551257389615890312154517;
+    /// sqrt(2)
+    pub static SQRT2: f32 = 1.41421356237309504880168872420969808;
+    /// 1 / sqrt(2)
+    pub static FRAC_1_SQRT2: f32 = 0.707106781186547524400844362104849039;
+    /// Euler's number
+    pub static E: f32 = 2.71828182845904523536028747135266250;
+    /// log2(e)
+    pub static LOG2E: f32 = 1.44269504088896340735992468100189214;
+    /// log10(e)
+    pub static LOG10E: f32 = 0.434294481903251827651128918916605082;
+    /// ln(2)
+    pub static LN_2: f32 = 0.693147180559945309417232121458176568;
+    /// ln(10)
+    pub static LN_10: f32 = 2.30258509299404568401799145468436421;
+}
+
+#[cfg(libc)]
 mod detail {
     use c_types::c_int;
 
@@ -68,6 +133,53 @@ mod detail {
     }
 }
 
+// Without a C library, only the operations that always lower to a single instruction or to the
+// compiler's runtime library stay intrinsics. The rest would become calls to the C functions of
+// the same names, so they come from the pure Rust implementations in `libm` instead.
+#[cfg(not(libc))]
+mod detail {
+    use c_types::c_int;
+    use libm;
+
+    pub use sinf32 = libm::sinf;
+    pub use cosf32 = libm::cosf;
+    pub use powf32 = libm::powf;
+    pub use expf32 = libm::expf;
+    pub use exp2f32 = libm::exp2f;
+    pub use logf32 = libm::logf;
+    pub use log10f32 = libm::log10f;
+    pub use log2f32 = libm::log2f;
+    pub use fmaf32 = libm::fmaf;
+    pub use floorf32 = libm::floorf;
+    pub use ceilf32 = libm::ceilf;
+    pub use truncf32 = libm::truncf;
+    pub use rintf32 = libm::rintf;
+    pub use nearbyintf32 = libm::nearbyintf;
+    pub use roundf32 = libm::roundf;
+    pub use libm::{fmaxf, fminf, fdimf, expm1f, log1pf, cbrtf, hypotf, tanf, asinf, acosf, atanf,
+                   atan2f, sinhf, coshf, tanhf, asinhf, acoshf, atanhf, erff, erfcf, tgammaf,
+                   ldexpf, ilogbf, logbf, nextafterf, nexttowardf};
+
+    extern "rust-intrinsic" {
+        pub fn sqrtf32(x: f32) -> f32;
+        pub fn powif32(a: f32, x: i32) -> f32;
+        pub fn fabsf32(x: f32) -> f32;
+        pub fn copysignf32(x: f32, y: f32) -> f32;
+    }
+
+    pub unsafe fn frexpf(x: f32, exp: *mut c_int) -> f32 {
+        let (m, e) = libm::frexpf(x);
+        *exp = e;
+        m
+    }
+
+    pub unsafe fn modff(x: f32, iptr: *mut f32) -> f32 {
+        let (i, frac) = libm::modff(x);
+        *iptr = i;
+        frac
+    }
+}
+
 #[inline(always)]
 pub fn max(x: f32, y: f32) -> f32 {
     unsafe { detail::fmaxf(x, y) }
@@ -320,3 +432,87 @@ pub fn nexttoward(from: f32, to: f32) -> f32 {
 pub fn is_nan(x: f64) -> bool {
     x != x
 }
+
+#[inline(always)]
+pub fn is_infinite(x: f32) -> bool {
+    x == INFINITY || x == NEG_INFINITY
+}
+
+#[inline(always)]
+pub fn is_finite(x: f32) -> bool {
+    !is_nan(x) && !is_infinite(x)
+}
+
+/// Return true if the value is neither zero, subnormal, infinite nor NaN.
+#[inline]
+pub fn is_normal(x: f32) -> bool {
+    classify(x) == FPNormal
+}
+
+pub fn classify(x: f32) -> FPCategory {
+    let bits = to_bits(x);
+    match (bits >> 23 & 0xff, bits & 0x7fffff) {
+        (0, 0) => FPZero,
+        (0, _) => FPSubnormal,
+        (0xff, 0) => FPInfinite,
+        (0xff, _) => FPNaN,
+        _ => FPNormal
+    }
+}
+
+/// Return true if the sign bit is clear, including for +0 and NaNs without the sign bit.
+#[inline(always)]
+pub fn is_sign_positive(x: f32) -> bool {
+    !is_sign_negative(x)
+}
+
+/// Return true if the sign bit is set, including for -0 and NaNs with the sign bit.
+#[inline(always)]
+pub fn is_sign_negative(x: f32) -> bool {
+    to_bits(x) >> 31 != 0
+}
+
+/// Return 1.0 with the sign of `x`, including for zero and infinity, or NaN if `x` is NaN.
+#[inline]
+pub fn signum(x: f32) -> f32 {
+    if is_nan(x) { NAN } else { copysign(1.0, x) }
+}
+
+/// Return the IEEE 754 representation.
+#[inline(always)]
+pub fn to_bits(x: f32) -> u32 {
+    unsafe { transmute(x) }
+}
+
+/// Return the value with the given IEEE 754 representation.
+#[inline(always)]
+pub fn from_bits(bits: u32) -> f32 {
+    unsafe { transmute(bits) }
+}
+
+/// Split into an integer mantissa, a binary exponent and a sign of `1` or `-1`, with
+/// `x = sign * mantissa * 2^exponent`. Infinity and NaN have the largest exponent.
+pub fn integer_decode(x: f32) -> (u64, i16, i8) {
+    let bits = to_bits(x);
+    let sign = if bits >> 31 == 0 { 1 } else { -1 };
+    let exp = (bits >> 23 & 0xff) as i16;
+    // subnormals have the exponent of the smallest normal values, without the hidden bit
+    let mantissa = if exp == 0 {
+        (bits & 0x7fffff) << 1
+    } else {
+        bits & 0x7fffff | 0x800000
+    };
+    (mantissa as u64, exp - (127 + 23), sign)
+}
+
+/// Compare with the totalOrder predicate of IEEE 754, which orders negative NaNs, negative
+/// infinity, the negative values, -0, +0, the positive values, infinity and positive NaNs, with
+/// NaNs ordered by their payload. Unlike the comparison operators, this is a total order.
+pub fn total_cmp(x: f32, y: f32) -> Ordering {
+    // flipping all but the sign bit of negative values orders the bits as signed integers
+    let mut a = to_bits(x) as i32;
+    let mut b = to_bits(y) as i32;
+    a ^= ((a >> 31) as u32 >> 1) as i32;
+    b ^= ((b >> 31) as u32 >> 1) as i32;
+    if a < b { Less } else if a > b { Greater } else { Equal }
+}
@@ -8,9 +8,74 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use c_types::c_int;
-use mem::uninit;
+// the `libm` functions are safe, so wrapping them in `unsafe` blocks is redundant without `libc`
+#[allow(unused_unsafe)];
 
+use c_types::c_int;
+use cmp::{Ordering, Less, Equal, Greater};
+use mem::{transmute, uninit};
+use num::{FPCategory, FPNaN, FPInfinite, FPZero, FPSubnormal, FPNormal};
+
+pub static RADIX: uint = 2;
+/// The number of significand bits, including the hidden bit.
+pub static MANTISSA_DIGITS: uint = 53;
+/// The number of decimal digits which always survive a round trip through the type.
+pub static DIGITS: uint = 15;
+/// The difference between 1.0 and the next larger representable value.
+pub static EPSILON: f64 = 2.2204460492503131e-16;
+/// The smallest positive normal value.
+pub static MIN_POSITIVE: f64 = 2.2250738585072014e-308;
+/// The smallest finite value.
+pub static MIN: f64 = -1.7976931348623157e308;
+/// The largest finite value.
+pub static MAX: f64 = 1.7976931348623157e308;
+/// One more than the smallest and largest binary exponents of a normal value, as in C.
+pub static MIN_EXP: int = -1021;
+pub static MAX_EXP: int = 1024;
+/// The smallest and largest decimal exponents of a normal value.
+pub static MIN_10_EXP: int = -307;
+pub static MAX_10_EXP: int = 308;
+pub static INFINITY: f64 = 1.0 / 0.0;
+pub static NEG_INFINITY: f64 = -1.0 / 0.0;
+pub static NAN: f64 = 0.0 / 0.0;
+
+/// Mathematical constants, rounded to the nearest representable value.
+pub mod consts {
+    /// pi
+    pub static PI: f64 = 3.14159265358979323846264338327950288;
+    /// pi / 2
+    pub static FRAC_PI_2: f64 = 1.57079632679489661923132169163975144;
+    /// pi / 3
+    pub static FRAC_PI_3: f64 = 1.04719755119659774615421446109316763;
+    /// pi / 4
+    pub static FRAC_PI_4: f64 = 0.785398163397448309615660845819875721;
+    /// pi / 6
+    pub static FRAC_PI_6: f64 = 0.52359877559829887307710723054658381;
+    /// pi / 8
+    pub static FRAC_PI_8: f64 = 0.39269908169872415480783042290993786;
+    /// 1 / pi
+    pub static FRAC_1_PI: f64 = 0.318309886183790671537767526745028724;
+    /// 2 / pi
+    pub static FRAC_2_PI: f64 = 0.636619772367581343075535053490057448;
+    /// 2 / sqrt(pi)
+    pub static FRAC_2_SQRTPI: f64 = 1.12837916709551257389615890312154517;
+    /// sqrt(2)
+    pub static SQRT2: f64 = 1.41421356237309504880168872420969808;
+    /// 1 / sqrt(2)
+    pub static FRAC_1_SQRT2: f64 = 0.707106781186547524400844362104849039;
+    /// Euler's number
+    pub static E: f64 = 2.71828182845904523536028747135266250;
+    /// log2(e)
+    pub static LOG2E: f64 = 1.44269504088896340735992468100189214;
+    /// log10(e)
+    pub static LOG10E: f64 = 0.434294481903251827651128918916605082;
+    /// ln(2)
+    pub static LN_2: f64 = 0.693147180559945309417232121458176568;
+    /// ln(10)
+    pub static LN_10: f64 = 2.30258509299404568401799145468436421;
+}
+
+#[cfg(libc)]
 mod detail {
     use c_types::c_int;
 
@@ -68,6 +133,53 @@ mod detail {
     }
 }
 
+// Without a C library, only the operations that always lower to a single instruction or to the
+// compiler's runtime library stay intrinsics. The rest would become calls to the C functions of
+// the same names, so they come from the pure Rust implementations in `libm` instead.
+#[cfg(not(libc))]
+mod detail {
+    use c_types::c_int;
+    use libm;
+
+    pub use sinf64 = libm::sin;
+    pub use cosf64 = libm::cos;
+    pub use powf64 = libm::pow;
+    pub use expf64 = libm::exp;
+    pub use exp2f64 = libm::exp2;
+    pub use logf64 = libm::log;
+    pub use log10f64 = libm::log10;
+    pub use log2f64 = libm::log2;
+    pub use fmaf64 = libm::fma;
+    pub use floorf64 = libm::floor;
+    pub use ceilf64 = libm::ceil;
+    pub use truncf64 = libm::trunc;
+    pub use rintf64 = libm::rint;
+    pub use nearbyintf64 = libm::nearbyint;
+    pub use roundf64 = libm::round;
+    pub use libm::{fmax, fmin, fdim, expm1, log1p, cbrt, hypot, tan, asin, acos, atan, atan2, sinh,
+                   cosh, tanh, asinh, acosh, atanh, erf, erfc, tgamma, ldexp, ilogb, logb,
+                   nextafter, nexttoward};
+
+    extern "rust-intrinsic" {
+        pub fn sqrtf64(x: f64) -> f64;
+        pub fn powif64(a: f64, x: i32) -> f64;
+        pub fn fabsf64(x: f64) -> f64;
+        pub fn copysignf64(x: f64, y: f64) -> f64;
+    }
+
+    pub unsafe fn frexp(x: f64, exp: *mut c_int) -> f64 {
+        let (m, e) = libm::frexp(x);
+        *exp = e;
+        m
+    }
+
+    pub unsafe fn modf(x: f64, iptr: *mut f64) -> f64 {
+        let (i, frac) = libm::modf(x);
+        *iptr = i;
+        frac
+    }
+}
+
 #[inline(always)]
 pub fn max(x: f64, y: f64) -> f64 {
     unsafe { detail::fmax(x, y) }
@@ -320,3 +432,87 @@ pub fn nexttoward(from: f64, to: f64) -> f64 {
 pub fn is_nan(x: f64) -> bool {
     x != x
 }
+
+#[inline(always)]
+pub fn is_infinite(x: f64) -> bool {
+    x == INFINITY || x == NEG_INFINITY
+}
+
+#[inline(always)]
+pub fn is_finite(x: f64) -> bool {
+    !is_nan(x) && !is_infinite(x)
+}
+
+/// Return true if the value is neither zero, subnormal, infinite nor NaN.
+#[inline]
+pub fn is_normal(x: f64) -> bool {
+    classify(x) == FPNormal
+}
+
+pub fn classify(x: f64) -> FPCategory {
+    let bits = to_bits(x);
+    match (bits >> 52 & 0x7ff, bits & 0xfffffffffffff) {
+        (0, 0) => FPZero,
+        (0, _) => FPSubnormal,
+        (0x7ff, 0) => FPInfinite,
+        (0x7ff, _) => FPNaN,
+        _ => FPNormal
+    }
+}
+
+/// Return true if the sign bit is clear, including for +0 and NaNs without the sign bit.
+#[inline(always)]
+pub fn is_sign_positive(x: f64) -> bool {
+    !is_sign_negative(x)
+}
+
+/// Return true if the sign bit is set, including for -0 and NaNs with the sign bit.
+#[inline(always)]
+pub fn is_sign_negative(x: f64) -> bool {
+    to_bits(x) >> 63 != 0
+}
+
+/// Return 1.0 with the sign of `x`, including for zero and infinity, or NaN if `x` is NaN.
+#[inline]
+pub fn signum(x: f64) -> f64 {
+    if is_nan(x) { NAN } else { copysign(1.0, x) }
+}
+
+/// Return the IEEE 754 representation.
+#[inline(always)]
+pub fn to_bits(x: f64) -> u64 {
+    unsafe { transmute(x) }
+}
+
+/// Return the value with the given IEEE 754 representation.
+#[inline(always)]
+pub fn from_bits(bits: u64) -> f64 {
+    unsafe { transmute(bits) }
+}
+
+/// Split into an integer mantissa, a binary exponent and a sign of `1` or `-1`, with
+/// `x = sign * mantissa * 2^exponent`. Infinity and NaN have the largest exponent.
+pub fn integer_decode(x: f64) -> (u64, i16, i8) {
+    let bits = to_bits(x);
+    let sign = if bits >> 63 == 0 { 1 } else { -1 };
+    let exp = (bits >> 52 & 0x7ff) as i16;
+    // subnormals have the exponent of the smallest normal values, without the hidden bit
+    let mantissa = if exp == 0 {
+        (bits & 0xfffffffffffff) << 1
+    } else {
+        bits & 0xfffffffffffff | 0x10000000000000
+    };
+    (mantissa as u64, exp - (1023 + 52), sign)
+}
+
+/// Compare with the totalOrder predicate of IEEE 754, which orders negative NaNs, negative
+/// infinity, the negative values, -0, +0, the positive values, infinity and positive NaNs, with
+/// NaNs ordered by their payload. Unlike the comparison operators, this is a total order.
+pub fn total_cmp(x: f64, y: f64) -> Ordering {
+    // flipping all but the sign bit of negative values orders the bits as signed integers
+    let mut a = to_bits(x) as i64;
+    let mut b = to_bits(y) as i64;
+    a ^= ((a >> 63) as u64 >> 1) as i64;
+    b ^= ((b >> 63) as u64 >> 1) as i64;
+    if a < b { Less } else if a > b { Greater } else { Equal }
+}
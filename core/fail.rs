@@ -9,11 +9,18 @@
 // except according to those terms.
 
 use c_types::c_int;
+#[cfg(libc)]
+use mem::transmute;
 
 mod detail {
+    #[cfg(libc)]
+    use c_types::c_int;
+
     extern {
         pub fn abort() -> !;
         pub fn breakpoint();
+        #[cfg(libc)]
+        pub fn write(fd: c_int, buf: *u8, count: uint) -> int;
     }
 }
 
@@ -26,6 +33,23 @@ pub fn breakpoint() {
     unsafe { detail::breakpoint() }
 }
 
+/// Write `msg` to standard error and call `abort`.
+#[cfg(libc)]
+pub fn fail(msg: &str) -> ! {
+    unsafe {
+        let (ptr, len): (*u8, uint) = transmute(msg);
+        detail::write(2, ptr, len);
+        detail::write(2, &('\n' as u8), 1);
+    }
+    abort()
+}
+
+/// Call `abort`. The message is discarded in a freestanding environment.
+#[cfg(not(libc))]
+pub fn fail(_: &str) -> ! {
+    abort()
+}
+
 #[inline]
 #[lang="fail_bounds_check"]
 pub fn fail_bounds_check(_: *u8, _: uint, _: uint, _: uint) -> ! {
@@ -56,6 +80,26 @@ pub fn assert(b: bool) {
 pub fn assert(_: bool) {
 }
 
+pub static EPERM: c_int = 1;
+pub static ENOENT: c_int = 2;
 pub static EINTR: c_int = 4;
+pub static EIO: c_int = 5;
+pub static EBADF: c_int = 9;
+pub static EAGAIN: c_int = 11;
+pub static ENOMEM: c_int = 12;
+pub static EACCES: c_int = 13;
 pub static EBUSY: c_int = 16;
+pub static EEXIST: c_int = 17;
+pub static ENOTDIR: c_int = 20;
+pub static EISDIR: c_int = 21;
+pub static EINVAL: c_int = 22;
+pub static EMFILE: c_int = 24;
+pub static EFBIG: c_int = 27;
+pub static ENOSPC: c_int = 28;
+pub static ESPIPE: c_int = 29;
+pub static EROFS: c_int = 30;
+pub static EPIPE: c_int = 32;
+pub static ENAMETOOLONG: c_int = 36;
+pub static ELOOP: c_int = 40;
 pub static ETIMEDOUT: c_int = 110;
+pub static EDQUOT: c_int = 122;
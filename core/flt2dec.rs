@@ -0,0 +1,469 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversion of floating point numbers to decimal digits.
+//!
+//! A float is first decoded into an integer mantissa and a binary exponent, along with the
+//! distances to the points halfway to its neighbours. The digits are then generated without any
+//! floating point arithmetic, as ASCII bytes `d1 d2 ... dn` with an exponent `k` for the value
+//! `0.d1d2...dn * 10^k`.
+//!
+//! `format_shortest` produces the shortest digits which read back as the same float, using Grisu3
+//! and falling back to Dragon4 on the rare inputs where Grisu3 can't prove its result is the
+//! shortest. `format_exact` produces correctly rounded digits up to a given length or decimal
+//! position, with Dragon4.
+
+use bignum::Big;
+use container::Container;
+use fail::abort;
+use iter::range;
+use mem::transmute;
+use option::{Option, Some, None};
+use slice::mut_slice_to;
+use u64;
+
+/// The largest number of digits produced by `format_shortest` for a `f32` or a `f64`.
+pub static MAX_SIG_DIGITS: uint = 17;
+
+/// The largest number of significant digits in the exact decimal expansion of a `f32` or a `f64`.
+/// `format_exact` never produces more digits than this.
+pub static MAX_EXACT_DIGITS: uint = 767;
+
+/// A finite, non-zero float decoded as `mant * 2^exp`. The values halfway to its neighbours are
+/// `(mant - minus) * 2^exp` and `(mant + plus) * 2^exp`. Every value strictly between them rounds
+/// to the float, as do the halfway values themselves if `inclusive` is true.
+#[deriving(Eq, Clone)]
+pub struct Decoded {
+    mant: u64,
+    minus: u64,
+    plus: u64,
+    exp: i16,
+    inclusive: bool
+}
+
+/// A decoded float of any class.
+#[deriving(Eq, Clone)]
+pub enum FullDecoded {
+    Nan,
+    Infinite,
+    Zero,
+    Finite(Decoded)
+}
+
+// Decode the fields of a float with `bits` bits of stored fraction, where `max_biased` is the
+// biased exponent of infinity and a subnormal is `fraction * 2^(bias + 1)`.
+fn decode(fraction: u64, biased: i16, bits: uint, max_biased: i16, bias: i16) -> FullDecoded {
+    // ties round to even when reading, so an even float includes its halfway values
+    let inclusive = fraction & 1 == 0;
+    if biased == max_biased {
+        if fraction == 0 { Infinite } else { Nan }
+    } else if biased == 0 {
+        if fraction == 0 {
+            Zero
+        } else {
+            Finite(Decoded { mant: fraction << 1, minus: 1, plus: 1, exp: bias,
+                             inclusive: inclusive })
+        }
+    } else {
+        let mant = fraction | 1 << bits as u64;
+        if fraction == 0 && biased > 1 {
+            // the lower neighbour of a power of two is twice as close as the upper one
+            Finite(Decoded { mant: mant << 2, minus: 1, plus: 2, exp: biased + bias - 2,
+                             inclusive: inclusive })
+        } else {
+            Finite(Decoded { mant: mant << 1, minus: 1, plus: 1, exp: biased + bias - 1,
+                             inclusive: inclusive })
+        }
+    }
+}
+
+/// Decode a `f32`, returning whether its sign bit is set and its value.
+pub fn decode_f32(x: f32) -> (bool, FullDecoded) {
+    let bits: u32 = unsafe { transmute(x) };
+    let fraction = (bits & 0x7fffff) as u64;
+    (bits >> 31 != 0, decode(fraction, ((bits >> 23) & 0xff) as i16, 23, 0xff, -150))
+}
+
+/// Decode a `f64`, returning whether its sign bit is set and its value.
+pub fn decode_f64(x: f64) -> (bool, FullDecoded) {
+    let bits: u64 = unsafe { transmute(x) };
+    let fraction = bits & 0xfffffffffffff;
+    (bits >> 63 != 0, decode(fraction, ((bits >> 52) & 0x7ff) as i16, 52, 0x7ff, -1075))
+}
+
+// Estimate the `k` for which `10^(k-1) < mant * 2^exp <= 10^k`. The estimate may be one too small.
+fn estimate_scaling_factor(mant: u64, exp: i16) -> i16 {
+    // 1292913986 is floor(2^32 * log10(2))
+    let bits = (64 - u64::leading_zeros(mant)) as i64;
+    (((bits + exp as i64) * 1292913986) >> 32) as i16
+}
+
+// Increment the ASCII digits, returning true if they were all nines and have become `10...0`.
+fn round_up(digits: &mut [u8]) -> bool {
+    let mut i = digits.len();
+    while i > 0 {
+        i -= 1;
+        if digits[i] != '9' as u8 {
+            digits[i] += 1;
+            return false
+        }
+        digits[i] = '0' as u8;
+    }
+    digits[0] = '1' as u8;
+    true
+}
+
+// Generate the next digit of `mant / scale`, leaving the remainder in `mant`.
+fn next_digit(mant: &mut Big, scale: &Big) -> u8 {
+    mant.mul_small(10);
+    let mut digit = '0' as u8;
+    while *mant >= *scale {
+        mant.sub(scale);
+        digit += 1;
+    }
+    digit
+}
+
+// Set up the exact value of `mant * 2^exp` as `mant / scale`, along with `minus` and `plus` over
+// the same `scale`.
+fn scaled(d: &Decoded) -> (Big, Big, Big, Big) {
+    let mut mant = Big::from_u64(d.mant);
+    let mut minus = Big::from_u64(d.minus);
+    let mut plus = Big::from_u64(d.plus);
+    let mut scale = Big::from_u64(1);
+    if d.exp < 0 {
+        scale.mul_pow2((-d.exp) as uint);
+    } else {
+        mant.mul_pow2(d.exp as uint);
+        minus.mul_pow2(d.exp as uint);
+        plus.mul_pow2(d.exp as uint);
+    }
+    (mant, minus, plus, scale)
+}
+
+fn dragon_shortest(d: &Decoded, buf: &mut [u8]) -> (uint, i16) {
+    let (mut mant, mut minus, mut plus, mut scale) = scaled(d);
+    let mut k = estimate_scaling_factor(d.mant + d.plus, d.exp);
+    if k >= 0 {
+        scale.mul_pow10(k as uint);
+    } else {
+        mant.mul_pow10((-k) as uint);
+        minus.mul_pow10((-k) as uint);
+        plus.mul_pow10((-k) as uint);
+    }
+
+    // make the upper halfway value fall below `10^k`, or not above it if it is excluded
+    loop {
+        let mut high = mant;
+        high.add(&plus);
+        if high < scale || (!d.inclusive && high == scale) {
+            break
+        }
+        scale.mul_small(10);
+        k += 1;
+    }
+
+    // stop as soon as the digits so far (`down`) or those with the last one incremented (`up`)
+    // are between the halfway values
+    let mut len = 0;
+    let mut down;
+    let mut up;
+    loop {
+        minus.mul_small(10);
+        plus.mul_small(10);
+        buf[len] = next_digit(&mut mant, &scale);
+        len += 1;
+        let mut high = mant;
+        high.add(&plus);
+        down = mant < minus || (d.inclusive && mant == minus);
+        up = high > scale || (d.inclusive && high == scale);
+        if down || up {
+            break
+        }
+    }
+
+    // when both are possible, take the closest
+    let mut twice = mant;
+    twice.mul_pow2(1);
+    if up && (!down || twice >= scale) {
+        if round_up(mut_slice_to(buf, len)) {
+            len = 1;
+            k += 1;
+        }
+    }
+    (len, k)
+}
+
+fn dragon_exact(d: &Decoded, buf: &mut [u8], limit: i16) -> (uint, i16) {
+    let (mut mant, _, _, mut scale) = scaled(d);
+    let mut k = estimate_scaling_factor(d.mant, d.exp);
+    if k >= 0 {
+        scale.mul_pow10(k as uint);
+    } else {
+        mant.mul_pow10((-k) as uint);
+    }
+    while mant >= scale {
+        scale.mul_small(10);
+        k += 1;
+    }
+
+    // the first digit is now non-zero
+    let len = if k > limit {
+        let len = (k as int - limit as int) as uint;
+        if len < buf.len() { len } else { buf.len() }
+    } else {
+        0
+    };
+    for i in range(0, len) {
+        buf[i] = next_digit(&mut mant, &scale);
+        if mant.is_zero() {
+            return (i + 1, k)
+        }
+    }
+    if len == 0 && k < limit {
+        // below a tenth of `10^limit`, so it rounds to zero
+        return (0, k)
+    }
+
+    // round the remainder, a fraction of the last digit or of `10^limit`, half to even
+    mant.mul_pow2(1);
+    let odd = len > 0 && (buf[len - 1] - '0' as u8) & 1 == 1;
+    if mant > scale || (mant == scale && odd) {
+        if len == 0 {
+            buf[0] = '1' as u8;
+            return (1, k + 1)
+        }
+        if round_up(mut_slice_to(buf, len)) {
+            return (len, k + 1)
+        }
+    }
+    (len, k)
+}
+
+// An unpacked floating point number `f * 2^e` with a 64-bit mantissa.
+struct Fp {
+    f: u64,
+    e: i16
+}
+
+impl Fp {
+    // Multiply, rounding the mantissa of the product to the nearest 64 bits.
+    fn mul(&self, other: &Fp) -> Fp {
+        static MASK: u64 = 0xffffffff;
+        let a = self.f >> 32;
+        let b = self.f & MASK;
+        let c = other.f >> 32;
+        let d = other.f & MASK;
+        let ac = a * c;
+        let bc = b * c;
+        let ad = a * d;
+        let bd = b * d;
+        let tmp = (bd >> 32) + (ad & MASK) + (bc & MASK) + (1 << 31);
+        Fp { f: ac + (ad >> 32) + (bc >> 32) + (tmp >> 32), e: self.e + other.e + 64 }
+    }
+
+    // Shift the mantissa left until its highest bit is set.
+    fn normalize(&self) -> Fp {
+        let shift = u64::leading_zeros(self.f);
+        Fp { f: self.f << shift as u64, e: self.e - shift as i16 }
+    }
+
+    // Shift the mantissa left to give the exponent `e`, which must not be larger than `self.e`.
+    fn normalize_to(&self, e: i16) -> Fp {
+        Fp { f: self.f << (self.e - e) as u64, e: e }
+    }
+}
+
+// `10^k` for `k` from -348 to 340 in steps of 8, as `(f, e, k)` with `10^k` rounded to `f * 2^e`
+// and the highest bit of `f` set.
+static CACHED_POW10: &'static [(u64, i16, i16)] = &[
+    (0xfa8fd5a0081c0288, -1220, -348), (0xbaaee17fa23ebf76, -1193, -340),
+    (0x8b16fb203055ac76, -1166, -332), (0xcf42894a5dce35ea, -1140, -324),
+    (0x9a6bb0aa55653b2d, -1113, -316), (0xe61acf033d1a45df, -1087, -308),
+    (0xab70fe17c79ac6ca, -1060, -300), (0xff77b1fcbebcdc4f, -1034, -292),
+    (0xbe5691ef416bd60c, -1007, -284), (0x8dd01fad907ffc3c, -980, -276),
+    (0xd3515c2831559a83, -954, -268), (0x9d71ac8fada6c9b5, -927, -260),
+    (0xea9c227723ee8bcb, -901, -252), (0xaecc49914078536d, -874, -244),
+    (0x823c12795db6ce57, -847, -236), (0xc21094364dfb5637, -821, -228),
+    (0x9096ea6f3848984f, -794, -220), (0xd77485cb25823ac7, -768, -212),
+    (0xa086cfcd97bf97f4, -741, -204), (0xef340a98172aace5, -715, -196),
+    (0xb23867fb2a35b28e, -688, -188), (0x84c8d4dfd2c63f3b, -661, -180),
+    (0xc5dd44271ad3cdba, -635, -172), (0x936b9fcebb25c996, -608, -164),
+    (0xdbac6c247d62a584, -582, -156), (0xa3ab66580d5fdaf6, -555, -148),
+    (0xf3e2f893dec3f126, -529, -140), (0xb5b5ada8aaff80b8, -502, -132),
+    (0x87625f056c7c4a8b, -475, -124), (0xc9bcff6034c13053, -449, -116),
+    (0x964e858c91ba2655, -422, -108), (0xdff9772470297ebd, -396, -100),
+    (0xa6dfbd9fb8e5b88f, -369, -92), (0xf8a95fcf88747d94, -343, -84),
+    (0xb94470938fa89bcf, -316, -76), (0x8a08f0f8bf0f156b, -289, -68),
+    (0xcdb02555653131b6, -263, -60), (0x993fe2c6d07b7fac, -236, -52),
+    (0xe45c10c42a2b3b06, -210, -44), (0xaa242499697392d3, -183, -36),
+    (0xfd87b5f28300ca0e, -157, -28), (0xbce5086492111aeb, -130, -20),
+    (0x8cbccc096f5088cc, -103, -12), (0xd1b71758e219652c, -77, -4), (0x9c40000000000000, -50, 4),
+    (0xe8d4a51000000000, -24, 12), (0xad78ebc5ac620000, 3, 20), (0x813f3978f8940984, 30, 28),
+    (0xc097ce7bc90715b3, 56, 36), (0x8f7e32ce7bea5c70, 83, 44), (0xd5d238a4abe98068, 109, 52),
+    (0x9f4f2726179a2245, 136, 60), (0xed63a231d4c4fb27, 162, 68), (0xb0de65388cc8ada8, 189, 76),
+    (0x83c7088e1aab65db, 216, 84), (0xc45d1df942711d9a, 242, 92), (0x924d692ca61be758, 269, 100),
+    (0xda01ee641a708dea, 295, 108), (0xa26da3999aef774a, 322, 116), (0xf209787bb47d6b85, 348, 124),
+    (0xb454e4a179dd1877, 375, 132), (0x865b86925b9bc5c2, 402, 140), (0xc83553c5c8965d3d, 428, 148),
+    (0x952ab45cfa97a0b3, 455, 156), (0xde469fbd99a05fe3, 481, 164), (0xa59bc234db398c25, 508, 172),
+    (0xf6c69a72a3989f5c, 534, 180), (0xb7dcbf5354e9bece, 561, 188), (0x88fcf317f22241e2, 588, 196),
+    (0xcc20ce9bd35c78a5, 614, 204), (0x98165af37b2153df, 641, 212), (0xe2a0b5dc971f303a, 667, 220),
+    (0xa8d9d1535ce3b396, 694, 228), (0xfb9b7cd9a4a7443c, 720, 236), (0xbb764c4ca7a44410, 747, 244),
+    (0x8bab8eefb6409c1a, 774, 252), (0xd01fef10a657842c, 800, 260), (0x9b10a4e5e9913129, 827, 268),
+    (0xe7109bfba19c0c9d, 853, 276), (0xac2820d9623bf429, 880, 284), (0x80444b5e7aa7cf85, 907, 292),
+    (0xbf21e44003acdd2d, 933, 300), (0x8e679c2f5e44ff8f, 960, 308), (0xd433179d9c8cb841, 986, 316),
+    (0x9e19db92b4e31ba9, 1013, 324), (0xeb96bf6ebadf77d9, 1039, 332),
+    (0xaf87023b9bf0ee6b, 1066, 340)
+];
+
+// Find a cached `10^k` as `f * 2^e` with `alpha <= e <= gamma`, returning `k` and the power.
+fn cached_power(alpha: i16, gamma: i16) -> (i16, Fp) {
+    for i in range(0, CACHED_POW10.len()) {
+        let (f, e, k) = CACHED_POW10[i];
+        if alpha <= e && e <= gamma {
+            return (k, Fp { f: f, e: e })
+        }
+    }
+    // the powers are 8 decimal orders of magnitude apart, so one is always found
+    abort()
+}
+
+// The range of the exponent of the scaled value, which leaves room for its integral part in 32
+// bits and for multiplying its fractional part by 10.
+static ALPHA: i16 = -60;
+static GAMMA: i16 = -32;
+
+// Return the largest `kappa` with `10^kappa <= x`, and `10^kappa`.
+fn max_pow10_no_more_than(x: u32) -> (i16, u32) {
+    let mut kappa = 0;
+    let mut ten_kappa = 1;
+    while kappa < 9 && ten_kappa * 10 <= x {
+        kappa += 1;
+        ten_kappa *= 10;
+    }
+    (kappa, ten_kappa)
+}
+
+// Move the last digit of a Grisu result towards the scaled value `w`, and check whether it is
+// certain to be the closest of the shortest digits. All values are scaled so that `rest` is the
+// distance from the digits to `too_high` and `ten_kappa` is the value of the last digit, and they
+// may each be off by `unit`.
+fn round_weed(buf: &mut [u8], len: uint, distance_too_high_w: u64, unsafe_interval: u64,
+              rest: u64, ten_kappa: u64, unit: u64) -> bool {
+    let small_distance = distance_too_high_w - unit;
+    let big_distance = distance_too_high_w + unit;
+    let mut rest = rest;
+    while rest < small_distance && unsafe_interval - rest >= ten_kappa &&
+          (rest + ten_kappa < small_distance ||
+           small_distance - rest >= rest + ten_kappa - small_distance) {
+        buf[len - 1] -= 1;
+        rest += ten_kappa;
+    }
+
+    // if decrementing the digit again might also have been right, give up
+    if rest < big_distance && unsafe_interval - rest >= ten_kappa &&
+       (rest + ten_kappa < big_distance ||
+        big_distance - rest > rest + ten_kappa - big_distance) {
+        return false
+    }
+
+    // the digits must be safely within the interval, given the error of `unit`
+    2 * unit <= rest && rest <= unsafe_interval - 4 * unit
+}
+
+fn grisu_shortest(d: &Decoded, buf: &mut [u8]) -> Option<(uint, i16)> {
+    let plus = Fp { f: d.mant + d.plus, e: d.exp };
+    let minus = Fp { f: d.mant - d.minus, e: d.exp };
+    let v = Fp { f: d.mant, e: d.exp };
+    let plus = plus.normalize();
+    let minus = minus.normalize_to(plus.e);
+    let v = v.normalize_to(plus.e);
+
+    // scale by a cached power of ten, so the exponent is within `ALPHA` and `GAMMA`
+    let (minusk, cached) = cached_power(ALPHA - plus.e - 64, GAMMA - plus.e - 64);
+    let plus = plus.mul(&cached);
+    let minus = minus.mul(&cached);
+    let v = v.mul(&cached);
+
+    // each product may be off by one, so only the digits strictly within `too_low` and
+    // `too_high` are certain to round to the float
+    let too_low = minus.f - 1;
+    let too_high = plus.f + 1;
+    let mut unsafe_interval = too_high - too_low;
+
+    let e = (-plus.e) as u64;
+    let one = 1u64 << e;
+    let mut integrals = (too_high >> e) as u32;
+    let mut fractionals = too_high & (one - 1);
+    let (max_kappa, mut ten_kappa) = max_pow10_no_more_than(integrals);
+
+    // generate the digits of the integral part, stopping once the rest is within the interval
+    let mut len = 0;
+    let mut kappa = max_kappa + 1;
+    while kappa > 0 {
+        buf[len] = '0' as u8 + (integrals / ten_kappa) as u8;
+        len += 1;
+        integrals %= ten_kappa;
+        kappa -= 1;
+        let rest = (integrals as u64 << e) + fractionals;
+        if rest < unsafe_interval {
+            return if round_weed(buf, len, too_high - v.f, unsafe_interval, rest,
+                                 ten_kappa as u64 << e, 1) {
+                Some((len, kappa - minusk + len as i16))
+            } else {
+                None
+            }
+        }
+        ten_kappa /= 10;
+    }
+
+    // then those of the fractional part, where the error grows with each digit
+    let mut unit = 1;
+    loop {
+        if len == buf.len() {
+            return None
+        }
+        fractionals *= 10;
+        unit *= 10;
+        unsafe_interval *= 10;
+        buf[len] = '0' as u8 + (fractionals >> e) as u8;
+        len += 1;
+        fractionals &= one - 1;
+        kappa -= 1;
+        if fractionals < unsafe_interval {
+            return if round_weed(buf, len, (too_high - v.f) * unit, unsafe_interval, fractionals,
+                                 one, unit) {
+                Some((len, kappa - minusk + len as i16))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// Write the shortest digits which read back as the decoded float into `buf`, returning their
+/// number and the exponent `k` of the value `0.d1d2...dn * 10^k`. The buffer must have room for
+/// `MAX_SIG_DIGITS`.
+pub fn format_shortest(d: &Decoded, buf: &mut [u8]) -> (uint, i16) {
+    match grisu_shortest(d, buf) {
+        Some(result) => result,
+        None => dragon_shortest(d, buf)
+    }
+}
+
+/// Write the digits of the decoded float, correctly rounded with ties to even, into `buf`,
+/// returning their number and the exponent `k` of the value `0.d1d2...dn * 10^k`.
+///
+/// The digits end when `buf` is full or with the digit for `10^limit`, whichever comes first, but
+/// fewer are returned when the rest are zero. No digits are returned if the value rounds to zero.
+/// The buffer must not be empty.
+pub fn format_exact(d: &Decoded, buf: &mut [u8], limit: i16) -> (uint, i16) {
+    dragon_exact(d, buf, limit)
+}
@@ -0,0 +1,773 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Formatting of values as text into an `io::Writer`, without allocating.
+//!
+//! A format string is copied to the output, with each `{}` replaced by the next argument. An
+//! explicit argument index may be given as `{0}`, which does not affect the implicit counter. A
+//! specification may follow a colon:
+//!
+//! ```
+//! {[index]:[[fill]align][+][#][0][width][.precision][type]}
+//! ```
+//!
+//! * `align` is `<` (left), `^` (center) or `>` (right), and `fill` is any character used for the
+//!   padding, defaulting to a space
+//! * `+` always prints the sign of a number
+//! * `#` adds a `0x`, `0o` or `0b` prefix to integers in those radixes
+//! * `0` pads numbers with zeroes after the sign and prefix
+//! * `width` is the minimum number of characters to write
+//! * `precision` is the number of digits after the decimal point for floats and the maximum
+//!   number of characters for strings
+//! * `type` is `x` or `X` for hexadecimal, `o` for octal, `b` for binary or `e` or `E` for the
+//!   exponential notation of floats
+//!
+//! Floats without a precision are written with the shortest digits which read back as the same
+//! value.
+//!
+//! `{{` and `}}` write literal braces. A malformed format string, a missing argument or a type
+//! which can't be written in the requested radix makes formatting fail with `InvalidInput`.
+//!
+//! The `write!`, `format!`, `print!` and `println!` macros check the arguments implement `Show`
+//! and call the functions in this module.
+
+use char;
+use container::Container;
+use flt2dec::{FullDecoded, Nan, Infinite, Zero, Finite, MAX_SIG_DIGITS, MAX_EXACT_DIGITS};
+use flt2dec::{decode_f32, decode_f64, format_exact, format_shortest};
+use i16;
+use io::{Writer, IoResult, Error, InvalidInput};
+use iter::{Iterator, range};
+use num::Int;
+use option::{Option, Some, None};
+use result::{Ok, Err};
+use slice::{iter, mut_slice, mut_slice_to, reverse, slice, slice_from, slice_to};
+use str;
+use str::{as_bytes, from_utf8_unchecked};
+#[cfg(libc)]
+use fail::fail;
+#[cfg(libc)]
+use io::{MemWriter, stdout};
+#[cfg(libc)]
+use string::String;
+
+/// The result of a formatting operation.
+pub type Result = IoResult<()>;
+
+/// The alignment of a value within its field.
+#[deriving(Eq, Clone)]
+pub enum Alignment {
+    AlignLeft,
+    AlignRight,
+    AlignCenter,
+    /// No alignment was requested, so the type's default is used.
+    AlignUnknown
+}
+
+/// The representation requested by the type of a specification.
+#[deriving(Eq, Clone)]
+pub enum Format {
+    Display,
+    LowerHex,
+    UpperHex,
+    Octal,
+    Binary,
+    LowerExp,
+    UpperExp
+}
+
+/// A value which can be written as text.
+pub trait Show {
+    /// Write the value to the formatter, respecting its options where they apply.
+    fn fmt(&self, f: &mut Formatter) -> Result;
+}
+
+/// The destination of a formatting operation, along with the options of the current
+/// specification.
+pub struct Formatter<'a> {
+    priv buf: &'a mut Writer,
+    priv fill: char,
+    priv align: Alignment,
+    priv sign_plus: bool,
+    priv alternate: bool,
+    priv zero_pad: bool,
+    priv width: Option<uint>,
+    priv precision: Option<uint>,
+    priv format: Format
+}
+
+fn invalid_input() -> Error {
+    Error::from_kind(InvalidInput)
+}
+
+// Parse a decimal number at `*pos`, advancing past it. If there is no number or it overflows,
+// return `None` and leave `*pos` at the start, so that an oversized number makes the
+// specification malformed.
+fn parse_uint(bytes: &[u8], pos: &mut uint) -> Option<uint> {
+    let start = *pos;
+    let mut n: uint = 0;
+    while *pos < bytes.len() && bytes[*pos] >= '0' as u8 && bytes[*pos] <= '9' as u8 {
+        let (x, overflow) = n.mul_with_overflow(&10);
+        let (x, overflow2) = x.add_with_overflow(&((bytes[*pos] - '0' as u8) as uint));
+        if overflow || overflow2 {
+            *pos = start;
+            return None
+        }
+        n = x;
+        *pos += 1;
+    }
+    if *pos == start { None } else { Some(n) }
+}
+
+fn parse_align(byte: u8) -> Option<Alignment> {
+    match byte as char {
+        '<' => Some(AlignLeft),
+        '^' => Some(AlignCenter),
+        '>' => Some(AlignRight),
+        _ => None
+    }
+}
+
+impl<'a> Formatter<'a> {
+    /// Write raw bytes to the destination, ignoring the options.
+    #[inline]
+    pub fn write(&mut self, bytes: &[u8]) -> Result {
+        self.buf.write_all(bytes)
+    }
+
+    /// Write a string to the destination, ignoring the options.
+    #[inline]
+    pub fn write_str(&mut self, s: &str) -> Result {
+        self.buf.write_all(as_bytes(s))
+    }
+
+    /// Return the character used for padding.
+    #[inline]
+    pub fn fill(&self) -> char {
+        self.fill
+    }
+
+    #[inline]
+    pub fn align(&self) -> Alignment {
+        self.align
+    }
+
+    /// Return true if the sign of a number should always be written.
+    #[inline]
+    pub fn sign_plus(&self) -> bool {
+        self.sign_plus
+    }
+
+    /// Return true if the alternate form (`#`) was requested.
+    #[inline]
+    pub fn alternate(&self) -> bool {
+        self.alternate
+    }
+
+    /// Return true if numbers should be padded with zeroes after the sign.
+    #[inline]
+    pub fn sign_aware_zero_pad(&self) -> bool {
+        self.zero_pad
+    }
+
+    #[inline]
+    pub fn width(&self) -> Option<uint> {
+        self.width
+    }
+
+    #[inline]
+    pub fn precision(&self) -> Option<uint> {
+        self.precision
+    }
+
+    #[inline]
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Write a string, truncated to `precision` characters and padded to `width`. Strings are
+    /// left-aligned by default.
+    pub fn pad(&mut self, s: &str) -> Result {
+        let s = match self.precision {
+            Some(max) => match str::char_indices(s).nth(max) {
+                Some((i, _)) => str::slice_to(s, i),
+                None => s
+            },
+            None => s
+        };
+        let len = str::char_len(s);
+        match self.width {
+            Some(width) if width > len => {
+                let (pre, post) = self.split_padding(width - len, AlignLeft);
+                try!(self.write_fill(pre));
+                try!(self.write_str(s));
+                self.write_fill(post)
+            }
+            _ => self.write_str(s)
+        }
+    }
+
+    /// Write the ASCII digits of a number with its sign and, in the alternate form, `prefix`.
+    /// Numbers are right-aligned by default.
+    pub fn pad_integral(&mut self, is_nonnegative: bool, prefix: &str, digits: &str) -> Result {
+        let sign = if !is_nonnegative {
+            "-"
+        } else if self.sign_plus {
+            "+"
+        } else {
+            ""
+        };
+        let prefix = if self.alternate { prefix } else { "" };
+        let len = sign.len() + prefix.len() + digits.len();
+        match self.width {
+            Some(width) if width > len => {
+                if self.zero_pad {
+                    try!(self.write_str(sign));
+                    try!(self.write_str(prefix));
+                    for _ in range(0, width - len) {
+                        try!(self.write(bytes!("0")));
+                    }
+                    self.write_str(digits)
+                } else {
+                    let (pre, post) = self.split_padding(width - len, AlignRight);
+                    try!(self.write_fill(pre));
+                    try!(self.write_str(sign));
+                    try!(self.write_str(prefix));
+                    try!(self.write_str(digits));
+                    self.write_fill(post)
+                }
+            }
+            _ => {
+                try!(self.write_str(sign));
+                try!(self.write_str(prefix));
+                self.write_str(digits)
+            }
+        }
+    }
+
+    // Split `padding` fill characters into those before and after the value.
+    fn split_padding(&self, padding: uint, default: Alignment) -> (uint, uint) {
+        let align = if self.align == AlignUnknown { default } else { self.align };
+        match align {
+            AlignLeft => (0, padding),
+            AlignCenter => (padding / 2, (padding + 1) / 2),
+            _ => (padding, 0)
+        }
+    }
+
+    fn write_fill(&mut self, n: uint) -> Result {
+        let mut buf = [0u8, ..4];
+        let len = char::encode_utf8(self.fill, buf);
+        for _ in range(0, n) {
+            try!(self.write(slice_to(buf, len)));
+        }
+        Ok(())
+    }
+
+    fn reset(&mut self) {
+        self.fill = ' ';
+        self.align = AlignUnknown;
+        self.sign_plus = false;
+        self.alternate = false;
+        self.zero_pad = false;
+        self.width = None;
+        self.precision = None;
+        self.format = Display;
+    }
+
+    // Parse the specification starting after a `{` at `pos`, write the argument and return the
+    // position after the closing `}`.
+    fn argument(&mut self, fmt: &str, pos: uint, args: &[&Show],
+                next: &mut uint) -> IoResult<uint> {
+        let bytes = as_bytes(fmt);
+        let mut pos = pos;
+        let index = match parse_uint(bytes, &mut pos) {
+            Some(i) => i,
+            None => {
+                *next += 1;
+                *next - 1
+            }
+        };
+
+        self.reset();
+        if pos < bytes.len() && bytes[pos] == ':' as u8 {
+            pos += 1;
+            if pos < bytes.len() {
+                let fill = str::char_range_at(fmt, pos);
+                let fill_align = if fill.next < bytes.len() {
+                    parse_align(bytes[fill.next])
+                } else {
+                    None
+                };
+                match fill_align {
+                    Some(align) => {
+                        self.fill = fill.ch;
+                        self.align = align;
+                        pos = fill.next + 1;
+                    }
+                    None => match parse_align(bytes[pos]) {
+                        Some(align) => {
+                            self.align = align;
+                            pos += 1;
+                        }
+                        None => ()
+                    }
+                }
+            }
+            if pos < bytes.len() && bytes[pos] == '+' as u8 {
+                self.sign_plus = true;
+                pos += 1;
+            }
+            if pos < bytes.len() && bytes[pos] == '#' as u8 {
+                self.alternate = true;
+                pos += 1;
+            }
+            if pos < bytes.len() && bytes[pos] == '0' as u8 {
+                self.zero_pad = true;
+                pos += 1;
+            }
+            self.width = parse_uint(bytes, &mut pos);
+            if pos < bytes.len() && bytes[pos] == '.' as u8 {
+                pos += 1;
+                match parse_uint(bytes, &mut pos) {
+                    Some(precision) => self.precision = Some(precision),
+                    None => return Err(invalid_input())
+                }
+            }
+            if pos < bytes.len() {
+                let format = match bytes[pos] as char {
+                    'x' => Some(LowerHex),
+                    'X' => Some(UpperHex),
+                    'o' => Some(Octal),
+                    'b' => Some(Binary),
+                    'e' => Some(LowerExp),
+                    'E' => Some(UpperExp),
+                    _ => None
+                };
+                match format {
+                    Some(format) => {
+                        self.format = format;
+                        pos += 1;
+                    }
+                    None => ()
+                }
+            }
+        }
+
+        if pos >= bytes.len() || bytes[pos] != '}' as u8 || index >= args.len() {
+            return Err(invalid_input())
+        }
+        try!(args[index].fmt(self));
+        Ok(pos + 1)
+    }
+
+    fn run(&mut self, fmt: &str, args: &[&Show]) -> Result {
+        let bytes = as_bytes(fmt);
+        let mut next = 0;
+        let mut start = 0;
+        let mut pos = 0;
+        while pos < bytes.len() {
+            match bytes[pos] as char {
+                '{' | '}' => {
+                    try!(self.write(slice(bytes, start, pos)));
+                    let escaped = pos + 1 < bytes.len() && bytes[pos + 1] == bytes[pos];
+                    if escaped {
+                        start = pos + 1;
+                        pos += 2;
+                    } else if bytes[pos] == '{' as u8 {
+                        pos = try!(self.argument(fmt, pos + 1, args, &mut next));
+                        start = pos;
+                    } else {
+                        return Err(invalid_input())
+                    }
+                }
+                _ => pos += 1
+            }
+        }
+        self.write(slice_from(bytes, start))
+    }
+}
+
+/// Write the arguments to `w` according to the format string `fmt`.
+pub fn write<W: Writer>(w: &mut W, fmt: &str, args: &[&Show]) -> Result {
+    let mut f = Formatter {
+        buf: w as &mut Writer,
+        fill: ' ',
+        align: AlignUnknown,
+        sign_plus: false,
+        alternate: false,
+        zero_pad: false,
+        width: None,
+        precision: None,
+        format: Display
+    };
+    f.run(fmt, args)
+}
+
+/// Format the arguments into a new `String`. Calls `fail` if the format string is invalid.
+#[cfg(libc)]
+pub fn format(fmt: &str, args: &[&Show]) -> String {
+    let mut w = MemWriter::new();
+    if write(&mut w, fmt, args).is_err() {
+        fail("invalid format string")
+    }
+    // the output is made only of pieces of strings and encoded characters
+    unsafe { String::from_utf8_unchecked(w.unwrap()) }
+}
+
+/// Write the arguments to standard output. Calls `fail` if writing fails.
+#[cfg(libc)]
+pub fn print(fmt: &str, args: &[&Show]) {
+    if write(&mut stdout(), fmt, args).is_err() {
+        fail("failed to write to standard output")
+    }
+}
+
+static LOWER_DIGITS: &'static [u8] = bytes!("0123456789abcdef");
+static UPPER_DIGITS: &'static [u8] = bytes!("0123456789ABCDEF");
+
+fn fmt_integer(f: &mut Formatter, is_nonnegative: bool, x: u64) -> Result {
+    let (radix, digits, prefix) = match f.format {
+        Display => (10, LOWER_DIGITS, ""),
+        LowerHex => (16, LOWER_DIGITS, "0x"),
+        UpperHex => (16, UPPER_DIGITS, "0x"),
+        Octal => (8, LOWER_DIGITS, "0o"),
+        Binary => (2, LOWER_DIGITS, "0b"),
+        LowerExp | UpperExp => return Err(invalid_input())
+    };
+    let mut buf = [0u8, ..64];
+    let mut pos = buf.len();
+    let mut n = x;
+    loop {
+        pos -= 1;
+        buf[pos] = digits[(n % radix) as uint];
+        n /= radix;
+        if n == 0 {
+            break
+        }
+    }
+    f.pad_integral(is_nonnegative, prefix, unsafe { from_utf8_unchecked(slice_from(buf, pos)) })
+}
+
+macro_rules! unsigned_show(
+    ($t:ty) => {
+        impl Show for $t {
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                fmt_integer(f, true, *self as u64)
+            }
+        }
+    }
+)
+
+unsigned_show!(uint)
+unsigned_show!(u8)
+unsigned_show!(u16)
+unsigned_show!(u32)
+unsigned_show!(u64)
+
+// Signed integers are written in two's complement in the radixes other than 10.
+macro_rules! signed_show(
+    ($t:ty, $u:ty) => {
+        impl Show for $t {
+            fn fmt(&self, f: &mut Formatter) -> Result {
+                if f.format != Display {
+                    fmt_integer(f, true, *self as $u as u64)
+                } else if *self < 0 {
+                    fmt_integer(f, false, -(*self as i64) as u64)
+                } else {
+                    fmt_integer(f, true, *self as u64)
+                }
+            }
+        }
+    }
+)
+
+signed_show!(int, uint)
+signed_show!(i8, u8)
+signed_show!(i16, u16)
+signed_show!(i32, u32)
+signed_show!(i64, u64)
+
+// A piece of a formatted float, so runs of zeroes need no buffer.
+enum Part<'a> {
+    Zeroes(uint),
+    Bytes(&'a [u8])
+}
+
+static ZEROES: &'static [u8] = bytes!("0000000000000000");
+
+// The fractional digits of any float end at or above the one for 10^-1074.
+static MAX_FRACTION_DIGITS: uint = 1074;
+
+// Write the exponent of a float in exponential notation into `buf`, returning its length.
+fn exponent(buf: &mut [u8], upper: bool, exp: int) -> uint {
+    buf[0] = if upper { 'E' as u8 } else { 'e' as u8 };
+    let mut len = 1;
+    if exp < 0 {
+        buf[len] = '-' as u8;
+        len += 1;
+    }
+    let mut n = if exp < 0 { -exp } else { exp };
+    let start = len;
+    loop {
+        buf[len] = '0' as u8 + (n % 10) as u8;
+        len += 1;
+        n /= 10;
+        if n == 0 {
+            break
+        }
+    }
+    reverse(mut_slice(buf, start, len));
+    len
+}
+
+fn write_parts(f: &mut Formatter, parts: &[Part]) -> Result {
+    for part in iter(parts) {
+        match *part {
+            Zeroes(n) => {
+                let mut n = n;
+                while n > 0 {
+                    let chunk = if n < ZEROES.len() { n } else { ZEROES.len() };
+                    try!(f.write(slice_to(ZEROES, chunk)));
+                    n -= chunk;
+                }
+            }
+            Bytes(bytes) => try!(f.write(bytes))
+        }
+    }
+    Ok(())
+}
+
+// Write the parts of a float with its sign, padded like an integer.
+fn pad_parts(f: &mut Formatter, is_nonnegative: bool, parts: &[Part]) -> Result {
+    let sign = if !is_nonnegative {
+        "-"
+    } else if f.sign_plus {
+        "+"
+    } else {
+        ""
+    };
+    let mut len = sign.len();
+    for part in iter(parts) {
+        len += match *part {
+            Zeroes(n) => n,
+            Bytes(bytes) => bytes.len()
+        };
+    }
+    match f.width {
+        Some(width) if width > len => {
+            if f.zero_pad {
+                try!(f.write_str(sign));
+                try!(write_parts(f, &[Zeroes(width - len)]));
+                write_parts(f, parts)
+            } else {
+                let (pre, post) = f.split_padding(width - len, AlignRight);
+                try!(f.write_fill(pre));
+                try!(f.write_str(sign));
+                try!(write_parts(f, parts));
+                f.write_fill(post)
+            }
+        }
+        _ => {
+            try!(f.write_str(sign));
+            write_parts(f, parts)
+        }
+    }
+}
+
+// Write `inf` or `NaN` after the sign, right-aligned with the fill character. As in C, zero padding
+// does not apply, and the precision is ignored.
+fn pad_nonfinite(f: &mut Formatter, sign: &str, s: &str) -> Result {
+    let len = sign.len() + s.len();
+    match f.width {
+        Some(width) if width > len => {
+            let (pre, post) = f.split_padding(width - len, AlignRight);
+            try!(f.write_fill(pre));
+            try!(f.write_str(sign));
+            try!(f.write_str(s));
+            f.write_fill(post)
+        }
+        _ => {
+            try!(f.write_str(sign));
+            f.write_str(s)
+        }
+    }
+}
+
+// Write a decoded float. Without a precision, the shortest digits which read back as the same
+// value are used, and otherwise the value is rounded to `precision` fractional digits with ties to
+// even.
+fn fmt_float(f: &mut Formatter, negative: bool, decoded: FullDecoded) -> Result {
+    let upper = match f.format {
+        Display | LowerExp => false,
+        UpperExp => true,
+        _ => return Err(invalid_input())
+    };
+    let exponential = f.format != Display;
+    let mut buf = [0u8, ..MAX_EXACT_DIGITS];
+    let (len, k) = match decoded {
+        Nan => return pad_nonfinite(f, "", "NaN"),
+        Infinite => {
+            let sign = if negative { "-" } else if f.sign_plus { "+" } else { "" };
+            return pad_nonfinite(f, sign, "inf")
+        }
+        Zero => (0, 0),
+        Finite(ref d) => match f.precision {
+            None => format_shortest(d, mut_slice_to(buf, MAX_SIG_DIGITS)),
+            Some(precision) if exponential => {
+                // any digits past the exact expansion are zero
+                let len = if precision < MAX_EXACT_DIGITS {
+                    precision + 1
+                } else {
+                    MAX_EXACT_DIGITS
+                };
+                format_exact(d, mut_slice_to(buf, len), i16::MIN)
+            }
+            Some(precision) => {
+                let precision = if precision < MAX_FRACTION_DIGITS {
+                    precision
+                } else {
+                    MAX_FRACTION_DIGITS
+                };
+                format_exact(d, buf, -(precision as i16))
+            }
+        }
+    };
+    let digits = slice_to(buf, len);
+    let k = k as int;
+
+    if exponential {
+        // `d.ddde-x`, with zero as `0e0`
+        let mut exp_buf = [0u8, ..8];
+        let exp_len = exponent(exp_buf, upper, if len == 0 { 0 } else { k - 1 });
+        let exp = slice_to(exp_buf, exp_len);
+        let first = if len == 0 { bytes!("0") } else { slice_to(digits, 1) };
+        let rest = if len == 0 { digits } else { slice_from(digits, 1) };
+        let zeroes = match f.precision {
+            Some(precision) => precision - rest.len(),
+            None => 0
+        };
+        if rest.len() + zeroes == 0 {
+            return pad_parts(f, !negative, &[Bytes(first), Bytes(exp)])
+        }
+        return pad_parts(f, !negative, &[Bytes(first), Bytes(bytes!(".")), Bytes(rest),
+                                          Zeroes(zeroes), Bytes(exp)])
+    }
+
+    match f.precision {
+        None if len == 0 => pad_parts(f, !negative, &[Bytes(bytes!("0"))]),
+        None if k <= 0 => {
+            pad_parts(f, !negative, &[Bytes(bytes!("0.")), Zeroes((-k) as uint), Bytes(digits)])
+        }
+        None if (k as uint) < len => {
+            pad_parts(f, !negative, &[Bytes(slice_to(digits, k as uint)), Bytes(bytes!(".")),
+                                      Bytes(slice_from(digits, k as uint))])
+        }
+        None => pad_parts(f, !negative, &[Bytes(digits), Zeroes(k as uint - len)]),
+        Some(precision) => {
+            // `len` is zero if the value rounded to zero, and otherwise the digits end at or above
+            // the last fractional one
+            let (integral, integral_zeroes, leading_zeroes, fraction) = if len == 0 {
+                (bytes!("0"), 0, 0, digits)
+            } else if k <= 0 {
+                (bytes!("0"), 0, (-k) as uint, digits)
+            } else if (k as uint) < len {
+                (slice_to(digits, k as uint), 0, 0, slice_from(digits, k as uint))
+            } else {
+                (digits, k as uint - len, 0, slice_from(digits, len))
+            };
+            if precision == 0 {
+                return pad_parts(f, !negative, &[Bytes(integral), Zeroes(integral_zeroes)])
+            }
+            pad_parts(f, !negative, &[Bytes(integral), Zeroes(integral_zeroes), Bytes(bytes!(".")),
+                                      Zeroes(leading_zeroes), Bytes(fraction),
+                                      Zeroes(precision - leading_zeroes - fraction.len())])
+        }
+    }
+}
+
+impl Show for f32 {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let (negative, decoded) = decode_f32(*self);
+        fmt_float(f, negative, decoded)
+    }
+}
+
+impl Show for f64 {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let (negative, decoded) = decode_f64(*self);
+        fmt_float(f, negative, decoded)
+    }
+}
+
+impl Show for bool {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if f.format != Display {
+            return Err(invalid_input())
+        }
+        f.pad(if *self { "true" } else { "false" })
+    }
+}
+
+impl Show for char {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if f.format != Display {
+            return Err(invalid_input())
+        }
+        let mut buf = [0u8, ..4];
+        let len = char::encode_utf8(*self, buf);
+        f.pad(unsafe { from_utf8_unchecked(slice_to(buf, len)) })
+    }
+}
+
+impl<'a> Show for &'a str {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        if f.format != Display {
+            return Err(invalid_input())
+        }
+        f.pad(*self)
+    }
+}
+
+impl<'a, T: Show> Show for &'a T {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        (**self).fmt(f)
+    }
+}
+
+/// The contained value is written with the options of the specification.
+impl<T: Show> Show for Option<T> {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match *self {
+            Some(ref x) => {
+                try!(f.write_str("Some("));
+                try!(x.fmt(f));
+                f.write_str(")")
+            }
+            None => f.write_str("None")
+        }
+    }
+}
+
+/// Each element is written with the options of the specification.
+impl<'a, T: Show> Show for &'a [T] {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        try!(f.write_str("["));
+        let mut first = true;
+        for x in iter(*self) {
+            if !first {
+                try!(f.write_str(", "));
+            }
+            first = false;
+            try!(x.fmt(f));
+        }
+        f.write_str("]")
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Parsing values from strings
+
+use option::{Option, Some, None};
+
+/// Types which can be parsed from a string.
+pub trait FromStr {
+    /// Parse a value, or return `None` if the string is not a valid representation.
+    fn from_str(s: &str) -> Option<Self>;
+}
+
+/// Parse a value of any type implementing `FromStr`.
+#[inline]
+pub fn from_str<T: FromStr>(s: &str) -> Option<T> {
+    FromStr::from_str(s)
+}
+
+impl FromStr for bool {
+    /// Parse `"true"` or `"false"`.
+    fn from_str(s: &str) -> Option<bool> {
+        match s {
+            "true" => Some(true),
+            "false" => Some(false),
+            _ => None
+        }
+    }
+}
@@ -9,8 +9,9 @@
 // except according to those terms.
 
 use container::Container;
-use iter::Iterator;
+use iter::{Iterator, FromIterator, Extendable, range};
 use slice::iter;
+use str::as_bytes;
 use option::{None, Option, Some};
 use fail::abort;
 use cmp::{Eq, max};
@@ -152,6 +153,14 @@ impl<'a, A: HashBytes> HashBytes for &'a [A] {
     }
 }
 
+impl<'a> HashBytes for &'a str {
+    #[inline]
+    fn hash_bytes(&self, f: |&[u8]|) {
+        self.len().hash_bytes(|x| f(x));
+        f(as_bytes(*self))
+    }
+}
+
 pub struct State {
     k0: u64,
     k1: u64,
@@ -317,6 +326,12 @@ impl State {
 
 static INITIAL_CAPACITY: uint = 32u; // 2^5
 
+/// The fixed 128-bit key used by containers built through `FromIterator`, which has no way to
+/// pass one in. These maps are not resistant to hash flooding, so build a map holding untrusted
+/// keys with `with_capacity_and_keys` and fill it with `extend` instead.
+pub static FROM_ITERATOR_K0: u64 = 0x0706050403020100;
+pub static FROM_ITERATOR_K1: u64 = 0x0f0e0d0c0b0a0908;
+
 struct Bucket<K,V> {
     hash: uint,
     key: K,
@@ -403,10 +418,8 @@ impl<K:Hash + Eq,V> HashMap<K, V> {
         self.resize_at = resize_at(new_capacity);
 
         let mut xs = Vec::with_capacity(new_capacity);
-        let mut i = 0;
-        while i < new_capacity {
+        for _ in range(0, new_capacity) {
             xs.push(None);
-            i += 1;
         }
 
         let mut old_buckets = replace(&mut self.buckets, xs);
@@ -558,10 +571,8 @@ impl<K: Hash + Eq, V> HashMap<K, V> {
     pub fn with_capacity_and_keys(k0: u64, k1: u64, capacity: uint) -> HashMap<K, V> {
         let capacity = max(INITIAL_CAPACITY, capacity);
         let mut xs = Vec::with_capacity(capacity);
-        let mut i = 0;
-        while i < capacity {
+        for _ in range(0, capacity) {
             xs.push(None);
-            i += 1;
         }
 
         HashMap {
@@ -641,6 +652,25 @@ impl<K: Hash + Eq, V> HashMap<K, V> {
     }
 }
 
+impl<K: Hash + Eq, V> FromIterator<(K, V)> for HashMap<K, V> {
+    fn from_iterator<T: Iterator<(K, V)>>(iterator: &mut T) -> HashMap<K, V> {
+        let mut map = HashMap::with_capacity_and_keys(FROM_ITERATOR_K0, FROM_ITERATOR_K1, 0);
+        map.extend(iterator);
+        map
+    }
+}
+
+impl<K: Hash + Eq, V> Extendable<(K, V)> for HashMap<K, V> {
+    fn extend<T: Iterator<(K, V)>>(&mut self, iterator: &mut T) {
+        let (lower, _) = iterator.size_hint();
+        let size = self.size;
+        self.reserve_at_least(size + lower);
+        for (k, v) in *iterator {
+            self.swap(k, v);
+        }
+    }
+}
+
 fn next_power_of_two(n: uint) -> uint {
     let halfbits: uint = size_of::<uint>() * 4u;
     let mut tmp: uint = n - 1u;
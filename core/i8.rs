@@ -8,6 +8,15 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use mem::transmute;
+use num;
+use option::Option;
+
+pub static BITS: uint = 8;
+pub static BYTES: uint = 1;
+pub static MIN: i8 = -MAX - 1;
+pub static MAX: i8 = 0x7f;
+
 extern "rust-intrinsic" {
     pub fn ctpop8(x: i8) -> i8;
     pub fn ctlz8(x: i8) -> i8;
@@ -31,3 +40,94 @@ pub fn sub_with_overflow(x: i8, y: i8) -> (i8, bool) {
 pub fn mul_with_overflow(x: i8, y: i8) -> (i8, bool) {
     unsafe { i8_mul_with_overflow(x, y) }
 }
+
+/// Return the number of one bits in `x`.
+#[inline(always)]
+pub fn count_ones(x: i8) -> uint {
+    unsafe { ctpop8(x) as uint }
+}
+
+/// Return the number of zero bits above the highest one bit in `x`.
+#[inline(always)]
+pub fn leading_zeros(x: i8) -> uint {
+    unsafe { ctlz8(x) as uint }
+}
+
+/// Return the number of zero bits below the lowest one bit in `x`.
+#[inline(always)]
+pub fn trailing_zeros(x: i8) -> uint {
+    unsafe { cttz8(x) as uint }
+}
+
+/// Byte swapping has no effect on a single byte.
+#[inline(always)]
+pub fn bswap(x: i8) -> i8 {
+    x
+}
+
+#[inline(always)]
+pub fn to_be(x: i8) -> i8 {
+    x
+}
+
+#[inline(always)]
+pub fn to_le(x: i8) -> i8 {
+    x
+}
+
+/// Convert from big endian to the target's byte order.
+#[inline(always)]
+pub fn from_be(x: i8) -> i8 {
+    to_be(x)
+}
+
+/// Convert from little endian to the target's byte order.
+#[inline(always)]
+pub fn from_le(x: i8) -> i8 {
+    to_le(x)
+}
+
+/// Return the big endian byte representation of `x`.
+#[inline]
+pub fn to_be_bytes(x: i8) -> [u8, ..BYTES] {
+    unsafe { transmute(to_be(x)) }
+}
+
+/// Return the little endian byte representation of `x`.
+#[inline]
+pub fn to_le_bytes(x: i8) -> [u8, ..BYTES] {
+    unsafe { transmute(to_le(x)) }
+}
+
+/// Create a value from its big endian byte representation.
+#[inline]
+pub fn from_be_bytes(bytes: [u8, ..BYTES]) -> i8 {
+    from_be(unsafe { transmute(bytes) })
+}
+
+/// Create a value from its little endian byte representation.
+#[inline]
+pub fn from_le_bytes(bytes: [u8, ..BYTES]) -> i8 {
+    from_le(unsafe { transmute(bytes) })
+}
+
+/// Parse an `i8` from ASCII digits in the given radix, with an optional leading `+` or `-`.
+/// Returns `None` if the digits are invalid or the value overflows. Calls `abort` if `radix` is not
+/// between 2 and 36.
+#[inline]
+pub fn from_bytes_radix(bytes: &[u8], radix: uint) -> Option<i8> {
+    num::from_bytes_radix(bytes, radix)
+}
+
+/// Parse an `i8` from a string in the given radix, as `from_bytes_radix` does.
+#[inline]
+pub fn from_str_radix(s: &str, radix: uint) -> Option<i8> {
+    num::from_str_radix(s, radix)
+}
+
+/// Write `x` in the given radix to the start of `buf`, returning the written string. Calls `abort`
+/// if `radix` is not between 2 and 36 or `buf` is too short for the digits and sign.
+#[inline]
+pub fn to_str_radix<'a>(x: i8, radix: uint, buf: &'a mut [u8]) -> &'a str {
+    num::to_str_radix(x, radix, buf)
+}
@@ -8,6 +8,23 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use mem::transmute;
+use num;
+use option::Option;
+
+#[cfg(target_word_size = "32")]
+pub static BITS: uint = 32;
+#[cfg(target_word_size = "64")]
+pub static BITS: uint = 64;
+
+#[cfg(target_word_size = "32")]
+pub static BYTES: uint = 4;
+#[cfg(target_word_size = "64")]
+pub static BYTES: uint = 8;
+
+pub static MIN: int = -MAX - 1;
+pub static MAX: int = (!0u >> 1) as int;
+
 #[cfg(target_word_size = "32")]
 #[inline(always)]
 pub fn add_with_overflow(x: int, y: int) -> (int, bool) {
@@ -79,3 +96,102 @@ pub fn to_le(x: int) -> int {
 pub fn to_le(x: int) -> int {
     x
 }
+
+/// Return the number of one bits in `x`.
+#[cfg(target_word_size = "32")]
+#[inline(always)]
+pub fn count_ones(x: int) -> uint {
+    ::i32::count_ones(x as i32)
+}
+
+/// Return the number of one bits in `x`.
+#[cfg(target_word_size = "64")]
+#[inline(always)]
+pub fn count_ones(x: int) -> uint {
+    ::i64::count_ones(x as i64)
+}
+
+/// Return the number of zero bits above the highest one bit in `x`.
+#[cfg(target_word_size = "32")]
+#[inline(always)]
+pub fn leading_zeros(x: int) -> uint {
+    ::i32::leading_zeros(x as i32)
+}
+
+/// Return the number of zero bits above the highest one bit in `x`.
+#[cfg(target_word_size = "64")]
+#[inline(always)]
+pub fn leading_zeros(x: int) -> uint {
+    ::i64::leading_zeros(x as i64)
+}
+
+/// Return the number of zero bits below the lowest one bit in `x`.
+#[cfg(target_word_size = "32")]
+#[inline(always)]
+pub fn trailing_zeros(x: int) -> uint {
+    ::i32::trailing_zeros(x as i32)
+}
+
+/// Return the number of zero bits below the lowest one bit in `x`.
+#[cfg(target_word_size = "64")]
+#[inline(always)]
+pub fn trailing_zeros(x: int) -> uint {
+    ::i64::trailing_zeros(x as i64)
+}
+
+/// Convert from big endian to the target's byte order.
+#[inline(always)]
+pub fn from_be(x: int) -> int {
+    to_be(x)
+}
+
+/// Convert from little endian to the target's byte order.
+#[inline(always)]
+pub fn from_le(x: int) -> int {
+    to_le(x)
+}
+
+/// Return the big endian byte representation of `x`.
+#[inline]
+pub fn to_be_bytes(x: int) -> [u8, ..BYTES] {
+    unsafe { transmute(to_be(x)) }
+}
+
+/// Return the little endian byte representation of `x`.
+#[inline]
+pub fn to_le_bytes(x: int) -> [u8, ..BYTES] {
+    unsafe { transmute(to_le(x)) }
+}
+
+/// Create a value from its big endian byte representation.
+#[inline]
+pub fn from_be_bytes(bytes: [u8, ..BYTES]) -> int {
+    from_be(unsafe { transmute(bytes) })
+}
+
+/// Create a value from its little endian byte representation.
+#[inline]
+pub fn from_le_bytes(bytes: [u8, ..BYTES]) -> int {
+    from_le(unsafe { transmute(bytes) })
+}
+
+/// Parse an `int` from ASCII digits in the given radix, with an optional leading `+` or `-`.
+/// Returns `None` if the digits are invalid or the value overflows. Calls `abort` if `radix` is not
+/// between 2 and 36.
+#[inline]
+pub fn from_bytes_radix(bytes: &[u8], radix: uint) -> Option<int> {
+    num::from_bytes_radix(bytes, radix)
+}
+
+/// Parse an `int` from a string in the given radix, as `from_bytes_radix` does.
+#[inline]
+pub fn from_str_radix(s: &str, radix: uint) -> Option<int> {
+    num::from_str_radix(s, radix)
+}
+
+/// Write `x` in the given radix to the start of `buf`, returning the written string. Calls `abort`
+/// if `radix` is not between 2 and 36 or `buf` is too short for the digits and sign.
+#[inline]
+pub fn to_str_radix<'a>(x: int, radix: uint, buf: &'a mut [u8]) -> &'a str {
+    num::to_str_radix(x, radix, buf)
+}
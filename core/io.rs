@@ -8,16 +8,37 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use fail::EINTR;
+use fail::{EPERM, ENOENT, EINTR, EIO, EBADF, EAGAIN, ENOMEM, EACCES, EBUSY, EEXIST, ENOTDIR};
+use fail::{EISDIR, EINVAL, EMFILE, EFBIG, ENOSPC, ESPIPE, EROFS, EPIPE, ENAMETOOLONG, ELOOP};
+use fail::{ETIMEDOUT, EDQUOT};
+#[cfg(libc)]
 use os::errno;
 use option::{Some, None, Option};
+#[cfg(libc)]
 use ops::Drop;
 use container::Container;
 use c_types::c_int;
-use slice::{to_mut_ptr, to_ptr};
+use cmp::{Eq, min};
+#[cfg(libc)]
+use cmp::max;
+use ptr::copy_nonoverlapping_memory;
+#[cfg(libc)]
+use ptr::{copy_memory, offset};
+use slice::{mut_slice_from, slice_from, slice_to, to_mut_ptr, to_ptr};
+#[cfg(libc)]
+use slice::{memchr, mut_slice_to, slice};
+#[cfg(libc)]
+use str::as_bytes;
+#[cfg(libc)]
+use iter::Iterator;
+#[cfg(libc)]
+use vec::Vec;
+use result::{Result, Ok, Err};
 
+#[cfg(libc)]
 enum FILE {}
 
+#[cfg(libc)]
 mod detail {
     use super::FILE;
 
@@ -28,14 +49,19 @@ mod detail {
     }
 }
 
+#[cfg(libc)]
 extern {
     fn fread(ptr: *mut u8, size: uint, nmemb: uint, stream: *mut FILE) -> uint;
     fn fwrite(ptr: *u8, size: uint, nmemb: uint, stream: *mut FILE) -> uint;
     fn fflush(fp: *mut FILE) -> c_int;
+    fn ferror(fp: *mut FILE) -> c_int;
+    fn clearerr(fp: *mut FILE);
 
     fn fread_unlocked(ptr: *mut u8, size: uint, nmemb: uint, stream: *mut FILE) -> uint;
     fn fwrite_unlocked(ptr: *u8, size: uint, nmemb: uint, stream: *mut FILE) -> uint;
     fn fflush_unlocked(fp: *mut FILE) -> c_int;
+    fn ferror_unlocked(fp: *mut FILE) -> c_int;
+    fn clearerr_unlocked(fp: *mut FILE);
 
     fn fileno(stream: *mut FILE) -> c_int;
 
@@ -44,113 +70,405 @@ extern {
 
     fn fopen(path: *u8, mode: *u8) -> *mut FILE;
     fn fclose(fp: *mut FILE) -> c_int;
+
+    // the XSI-compliant version, rather than the GNU extension returning a pointer
+    #[cfg(target_os = "linux")]
+    #[cfg(target_os = "android")]
+    #[link_name = "__xpg_strerror_r"]
+    fn strerror_r(errnum: c_int, buf: *mut u8, buflen: uint) -> c_int;
+    #[cfg(target_os = "macos")]
+    #[cfg(target_os = "freebsd")]
+    fn strerror_r(errnum: c_int, buf: *mut u8, buflen: uint) -> c_int;
+}
+
+/// A general classification of I/O errors.
+#[deriving(Eq, Clone)]
+pub enum ErrorKind {
+    NotFound,
+    PermissionDenied,
+    AlreadyExists,
+    Interrupted,
+    WouldBlock,
+    InvalidInput,
+    BrokenPipe,
+    TimedOut,
+    NoSpace,
+    IsDirectory,
+    NotDirectory,
+    TooManyOpenFiles,
+    OutOfMemory,
+    /// The end of the stream was reached before the operation could complete.
+    EndOfFile,
+    /// A writer stopped accepting data before the operation could complete.
+    ShortWrite,
+    OtherError
 }
 
+/// An I/O error, carrying the `errno` value reported by the C library.
+///
+/// Errors which do not originate from the C library (`EndOfFile` and `ShortWrite`) have a code
+/// of `0`.
 pub struct Error {
-    code: c_int
+    priv code: c_int,
+    priv kind: ErrorKind
+}
+
+impl Error {
+    /// Create an error from an `errno` value.
+    pub fn from_errno(code: c_int) -> Error {
+        let kind = match code {
+            ENOENT => NotFound,
+            EPERM | EACCES | EROFS => PermissionDenied,
+            EEXIST => AlreadyExists,
+            EINTR => Interrupted,
+            EAGAIN => WouldBlock,
+            EINVAL | EBADF | ESPIPE | ENAMETOOLONG | ELOOP => InvalidInput,
+            EPIPE => BrokenPipe,
+            ETIMEDOUT => TimedOut,
+            ENOSPC | EDQUOT | EFBIG => NoSpace,
+            EISDIR => IsDirectory,
+            ENOTDIR => NotDirectory,
+            EMFILE => TooManyOpenFiles,
+            ENOMEM => OutOfMemory,
+            _ => OtherError
+        };
+        Error { code: code, kind: kind }
+    }
+
+    /// Create an error from the current value of `errno`.
+    #[cfg(libc)]
+    #[inline]
+    pub fn last_error() -> Error {
+        Error::from_errno(errno())
+    }
+
+    /// Create an error of a kind with no corresponding `errno` value.
+    pub fn from_kind(kind: ErrorKind) -> Error {
+        Error { code: 0, kind: kind }
+    }
+
+    /// Return the `errno` value, or `0` if the error did not come from the C library.
+    #[inline(always)]
+    pub fn code(&self) -> c_int {
+        self.code
+    }
+
+    #[inline(always)]
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    /// Return the symbolic name of the `errno` value, such as `"ENOENT"`.
+    pub fn name(&self) -> &'static str {
+        match self.code {
+            0 => match self.kind {
+                EndOfFile => "EOF",
+                ShortWrite => "SHORTWRITE",
+                _ => "UNKNOWN"
+            },
+            EPERM => "EPERM",
+            ENOENT => "ENOENT",
+            EINTR => "EINTR",
+            EIO => "EIO",
+            EBADF => "EBADF",
+            EAGAIN => "EAGAIN",
+            ENOMEM => "ENOMEM",
+            EACCES => "EACCES",
+            EBUSY => "EBUSY",
+            EEXIST => "EEXIST",
+            ENOTDIR => "ENOTDIR",
+            EISDIR => "EISDIR",
+            EINVAL => "EINVAL",
+            EMFILE => "EMFILE",
+            EFBIG => "EFBIG",
+            ENOSPC => "ENOSPC",
+            ESPIPE => "ESPIPE",
+            EROFS => "EROFS",
+            EPIPE => "EPIPE",
+            ENAMETOOLONG => "ENAMETOOLONG",
+            ELOOP => "ELOOP",
+            ETIMEDOUT => "ETIMEDOUT",
+            EDQUOT => "EDQUOT",
+            _ => "UNKNOWN"
+        }
+    }
+
+    /// Write a human-readable description of the error into `buf` with `strerror_r`, returning
+    /// the used portion of the buffer. The description is truncated if `buf` is too small.
+    #[cfg(libc)]
+    pub fn describe<'a>(&self, buf: &'a mut [u8]) -> &'a [u8] {
+        let message = match self.kind {
+            EndOfFile if self.code == 0 => "unexpected end of file",
+            ShortWrite if self.code == 0 => "failed to write the whole buffer",
+            InvalidInput if self.code == 0 => "invalid input",
+            _ => {
+                if buf.len() == 0 {
+                    return buf
+                }
+                unsafe {
+                    if strerror_r(self.code, to_mut_ptr(buf), buf.len()) != 0 {
+                        // unknown error number or a truncated message, ensure termination
+                        buf[buf.len() - 1] = 0;
+                    }
+                }
+                let len = memchr(buf, 0).map_or(buf.len(), |i| i);
+                return mut_slice_to(buf, len)
+            }
+        };
+        let n = min(buf.len(), message.len());
+        unsafe {
+            copy_nonoverlapping_memory(to_mut_ptr(buf), to_ptr(as_bytes(message)), n);
+        }
+        mut_slice_to(buf, n)
+    }
+}
+
+impl Eq for Error {
+    fn eq(&self, other: &Error) -> bool {
+        self.code == other.code && self.kind == other.kind
+    }
+}
+
+/// The result of an I/O operation.
+pub type IoResult<T> = Result<T, Error>;
+
+#[cfg(libc)]
+static DEFAULT_BUF_SIZE: uint = 4096;
+
+/// A source of bytes.
+pub trait Reader {
+    /// Read up to `buf.len()` bytes into `buf`, returning the number of bytes read. A count of
+    /// `0` for a non-empty buffer indicates the end of the stream.
+    fn read(&mut self, buf: &mut [u8]) -> IoResult<uint>;
+
+    /// Read a single byte, or return `None` at the end of the stream.
+    fn read_byte(&mut self) -> IoResult<Option<u8>> {
+        let mut buf = [0u8];
+        match self.read(buf) {
+            Ok(0) => Ok(None),
+            Ok(_) => Ok(Some(buf[0])),
+            Err(e) => Err(e)
+        }
+    }
+
+    /// Fill the whole of `buf`, failing with `EndOfFile` if the stream ended first. The contents
+    /// of `buf` are unspecified on failure.
+    fn read_exact(&mut self, buf: &mut [u8]) -> IoResult<()> {
+        let mut pos = 0;
+        while pos < buf.len() {
+            match self.read(mut_slice_from(buf, pos)) {
+                Ok(0) => return Err(Error::from_kind(EndOfFile)),
+                Ok(n) => pos += n,
+                Err(e) => if e.kind != Interrupted { return Err(e) }
+            }
+        }
+        Ok(())
+    }
+
+    /// Read all of the remaining bytes in the stream.
+    #[cfg(libc)]
+    fn read_to_end(&mut self) -> IoResult<Vec<u8>> {
+        let mut buf = [0u8, ..DEFAULT_BUF_SIZE];
+        let mut xs = Vec::new();
+        loop {
+            match self.read(buf) {
+                Ok(0) => return Ok(xs),
+                Ok(n) => push_bytes(&mut xs, slice_to(buf, n)),
+                Err(e) => if e.kind != Interrupted { return Err(e) }
+            }
+        }
+    }
 }
 
+/// A sink for bytes.
+pub trait Writer {
+    /// Write up to `buf.len()` bytes from `buf`, returning the number of bytes written.
+    fn write(&mut self, buf: &[u8]) -> IoResult<uint>;
+
+    /// Flush any data buffered by the writer.
+    fn flush(&mut self) -> IoResult<()> {
+        Ok(())
+    }
+
+    /// Write a single byte.
+    fn write_byte(&mut self, byte: u8) -> IoResult<()> {
+        self.write_all([byte])
+    }
+
+    /// Write the whole of `buf`, failing with `ShortWrite` if the writer stopped accepting data
+    /// first.
+    fn write_all(&mut self, buf: &[u8]) -> IoResult<()> {
+        let mut pos = 0;
+        while pos < buf.len() {
+            match self.write(slice_from(buf, pos)) {
+                Ok(0) => return Err(Error::from_kind(ShortWrite)),
+                Ok(n) => pos += n,
+                Err(e) => if e.kind != Interrupted { return Err(e) }
+            }
+        }
+        Ok(())
+    }
+}
+
+// Append `bytes` to `xs`, growing the capacity geometrically.
+#[cfg(libc)]
+fn push_bytes(xs: &mut Vec<u8>, bytes: &[u8]) {
+    let len = xs.len();
+    let new_len = len + bytes.len();
+    if new_len > xs.capacity() {
+        let mut cap = if xs.capacity() == 0 { DEFAULT_BUF_SIZE } else { xs.capacity() };
+        while cap < new_len {
+            cap *= 2;
+        }
+        xs.reserve(cap);
+    }
+    unsafe {
+        let end = offset(to_ptr(xs.as_slice()), len as int) as *mut u8;
+        copy_nonoverlapping_memory(end, to_ptr(bytes), bytes.len());
+        xs.set_len(new_len);
+    }
+}
+
+#[cfg(libc)]
 pub struct StdStream {
     priv file: *mut FILE
 }
 
+#[cfg(libc)]
 pub fn stdin() -> StdStream {
     StdStream { file: detail::stdin }
 }
 
+#[cfg(libc)]
 pub fn stdout() -> StdStream {
     StdStream { file: detail::stdout }
 }
 
+#[cfg(libc)]
 pub fn stderr() -> StdStream {
     StdStream { file: detail::stderr }
 }
 
-impl StdStream {
-    pub fn read(&mut self, xs: &mut [u8]) -> uint {
+#[cfg(libc)]
+impl Reader for StdStream {
+    fn read(&mut self, xs: &mut [u8]) -> IoResult<uint> {
         unsafe {
-            fread(to_mut_ptr(xs), 1, xs.len(), self.file)
+            let n = fread(to_mut_ptr(xs), 1, xs.len(), self.file);
+            // a short count is either the end of the stream or an error
+            if n == 0 && xs.len() != 0 && ferror(self.file) != 0 {
+                let e = Error::last_error();
+                clearerr(self.file);
+                Err(e)
+            } else {
+                Ok(n)
+            }
         }
     }
+}
 
-    pub fn write(&mut self, xs: &[u8]) -> uint {
-        unsafe {
-            fwrite(to_ptr(xs), 1, xs.len(), self.file)
+#[cfg(libc)]
+impl Writer for StdStream {
+    fn write(&mut self, xs: &[u8]) -> IoResult<uint> {
+        let n = unsafe { fwrite(to_ptr(xs), 1, xs.len(), self.file) };
+        if n == 0 && xs.len() != 0 {
+            Err(Error::last_error())
+        } else {
+            Ok(n)
         }
     }
 
-    pub fn flush(&mut self) -> Option<Error> {
+    fn flush(&mut self) -> IoResult<()> {
         if unsafe { fflush(self.file) } != 0 {
-            Some(Error { code: errno() })
+            Err(Error::last_error())
         } else {
-            None
+            Ok(())
         }
     }
 }
 
+#[cfg(libc)]
 pub struct File {
     priv file: *mut FILE
 }
 
+#[cfg(libc)]
 impl File {
-    pub unsafe fn open(path: *u8, mode: *u8) -> Option<File> {
+    pub unsafe fn open(path: *u8, mode: *u8) -> IoResult<File> {
         let fp = fopen(path, mode);
         if fp == 0 as *mut FILE {
-            None
-        } else {
-            Some(File { file: fp })
-        }
-    }
-
-    pub fn read(&mut self, xs: &mut [u8]) -> uint {
-        unsafe {
-            fread_unlocked(to_mut_ptr(xs), 1, xs.len(), self.file)
-        }
-    }
-
-    pub fn write(&mut self, xs: &[u8]) -> uint {
-        unsafe {
-            fwrite_unlocked(to_ptr(xs), 1, xs.len(), self.file)
-        }
-    }
-
-    pub fn flush(&mut self) -> Option<Error> {
-        if unsafe { fflush_unlocked(self.file) } != 0 {
-            Some(Error { code: errno() })
+            Err(Error::last_error())
         } else {
-            None
+            Ok(File { file: fp })
         }
     }
 
-    pub fn datasync(&mut self) -> Option<Error> {
+    pub fn datasync(&mut self) -> IoResult<()> {
         loop {
             let ret = unsafe { fdatasync(fileno(self.file)) };
             if ret == -1 {
                 let code = errno();
                 if code != EINTR {
-                    return Some(Error { code: code })
+                    return Err(Error::from_errno(code))
                 }
             } else {
-                return None
+                return Ok(())
             }
         }
     }
 
-    pub fn sync(&mut self) -> Option<Error> {
+    pub fn sync(&mut self) -> IoResult<()> {
         loop {
             let ret = unsafe { fsync(fileno(self.file)) };
             if ret == -1 {
                 let code = errno();
                 if code != EINTR {
-                    return Some(Error { code: code })
+                    return Err(Error::from_errno(code))
                 }
             } else {
-                return None
+                return Ok(())
             }
         }
     }
 }
 
+#[cfg(libc)]
+impl Reader for File {
+    fn read(&mut self, xs: &mut [u8]) -> IoResult<uint> {
+        unsafe {
+            let n = fread_unlocked(to_mut_ptr(xs), 1, xs.len(), self.file);
+            // a short count is either the end of the stream or an error
+            if n == 0 && xs.len() != 0 && ferror_unlocked(self.file) != 0 {
+                let e = Error::last_error();
+                clearerr_unlocked(self.file);
+                Err(e)
+            } else {
+                Ok(n)
+            }
+        }
+    }
+}
+
+#[cfg(libc)]
+impl Writer for File {
+    fn write(&mut self, xs: &[u8]) -> IoResult<uint> {
+        let n = unsafe { fwrite_unlocked(to_ptr(xs), 1, xs.len(), self.file) };
+        if n == 0 && xs.len() != 0 {
+            Err(Error::last_error())
+        } else {
+            Ok(n)
+        }
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        if unsafe { fflush_unlocked(self.file) } != 0 {
+            Err(Error::last_error())
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(libc)]
 impl Drop for File {
     fn drop(&mut self) {
         unsafe {
@@ -158,3 +476,336 @@ impl Drop for File {
         }
     }
 }
+
+/// A `Writer` appending to an in-memory vector.
+#[cfg(libc)]
+pub struct MemWriter {
+    priv buf: Vec<u8>
+}
+
+#[cfg(libc)]
+impl MemWriter {
+    pub fn new() -> MemWriter {
+        MemWriter { buf: Vec::new() }
+    }
+
+    pub fn with_capacity(capacity: uint) -> MemWriter {
+        MemWriter { buf: Vec::with_capacity(capacity) }
+    }
+
+    /// Return the bytes written so far.
+    pub fn as_slice<'a>(&'a self) -> &'a [u8] {
+        self.buf.as_slice()
+    }
+
+    /// Consume the writer, returning the underlying vector.
+    pub fn unwrap(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+#[cfg(libc)]
+impl Writer for MemWriter {
+    fn write(&mut self, xs: &[u8]) -> IoResult<uint> {
+        push_bytes(&mut self.buf, xs);
+        Ok(xs.len())
+    }
+}
+
+/// A `Reader` over a borrowed byte slice.
+pub struct SliceReader<'a> {
+    priv buf: &'a [u8],
+    priv pos: uint
+}
+
+impl<'a> SliceReader<'a> {
+    pub fn new(buf: &'a [u8]) -> SliceReader<'a> {
+        SliceReader { buf: buf, pos: 0 }
+    }
+
+    /// Return the bytes which have not been read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        slice_from(self.buf, self.pos)
+    }
+}
+
+impl<'a> Reader for SliceReader<'a> {
+    fn read(&mut self, xs: &mut [u8]) -> IoResult<uint> {
+        let n = min(xs.len(), self.buf.len() - self.pos);
+        unsafe {
+            copy_nonoverlapping_memory(to_mut_ptr(xs), to_ptr(slice_from(self.buf, self.pos)), n);
+        }
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+/// A `Writer` into a borrowed, fixed-size byte slice. Once the slice is full, further writes
+/// accept no data and `write_all` fails with `ShortWrite`.
+pub struct BufWriter<'a> {
+    priv buf: &'a mut [u8],
+    priv pos: uint
+}
+
+impl<'a> BufWriter<'a> {
+    pub fn new(buf: &'a mut [u8]) -> BufWriter<'a> {
+        BufWriter { buf: buf, pos: 0 }
+    }
+
+    /// Return the number of bytes written so far.
+    #[inline]
+    pub fn position(&self) -> uint {
+        self.pos
+    }
+
+    /// Return the bytes written so far.
+    pub fn as_slice<'b>(&'b self) -> &'b [u8] {
+        slice_to(&*self.buf, self.pos)
+    }
+}
+
+impl<'a> Writer for BufWriter<'a> {
+    fn write(&mut self, xs: &[u8]) -> IoResult<uint> {
+        let n = min(xs.len(), self.buf.len() - self.pos);
+        unsafe {
+            let dst = to_mut_ptr(mut_slice_from(self.buf, self.pos));
+            copy_nonoverlapping_memory(dst, to_ptr(xs), n);
+        }
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+/// Wraps a `Reader` and buffers input from it.
+#[cfg(libc)]
+pub struct BufferedReader<R> {
+    priv inner: R,
+    priv buf: Vec<u8>,
+    priv pos: uint,
+    priv end: uint
+}
+
+#[cfg(libc)]
+impl<R: Reader> BufferedReader<R> {
+    /// Create a new `BufferedReader` with a default buffer capacity.
+    pub fn new(inner: R) -> BufferedReader<R> {
+        BufferedReader::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Create a new `BufferedReader` with the specified buffer capacity. A capacity of zero is
+    /// treated as one, as an empty buffer would read as the end of the stream.
+    pub fn with_capacity(capacity: uint, inner: R) -> BufferedReader<R> {
+        let buf = Vec::from_elem(max(capacity, 1), 0u8);
+        BufferedReader { inner: inner, buf: buf, pos: 0, end: 0 }
+    }
+
+    /// Return the buffered data, refilling the buffer from the underlying reader if it is empty.
+    /// An empty slice indicates the end of the stream.
+    pub fn fill_buf<'a>(&'a mut self) -> IoResult<&'a [u8]> {
+        if self.pos == self.end {
+            self.end = try!(self.inner.read(self.buf.as_mut_slice()));
+            self.pos = 0;
+        }
+        Ok(slice(self.buf.as_slice(), self.pos, self.end))
+    }
+
+    /// Mark `amount` bytes returned by `fill_buf` as read.
+    pub fn consume(&mut self, amount: uint) {
+        self.pos = min(self.pos + amount, self.end);
+    }
+
+    /// Read bytes up to and including the next occurrence of `byte`. The delimiter is missing from
+    /// the result if the stream ended first, and the result is empty if there was nothing left.
+    ///
+    /// On error, the bytes read so far are discarded.
+    pub fn read_until(&mut self, byte: u8) -> IoResult<Vec<u8>> {
+        let mut xs = Vec::new();
+        loop {
+            let (used, done) = {
+                let available = match self.fill_buf() {
+                    Ok(available) => available,
+                    Err(e) => if e.kind == Interrupted { continue } else { return Err(e) }
+                };
+                if available.len() == 0 {
+                    break
+                }
+                match memchr(available, byte) {
+                    Some(i) => {
+                        push_bytes(&mut xs, slice_to(available, i + 1));
+                        (i + 1, true)
+                    }
+                    None => {
+                        push_bytes(&mut xs, available);
+                        (available.len(), false)
+                    }
+                }
+            };
+            self.consume(used);
+            if done {
+                break
+            }
+        }
+        Ok(xs)
+    }
+
+    /// Read bytes up to and including the next newline.
+    #[inline]
+    pub fn read_line(&mut self) -> IoResult<Vec<u8>> {
+        self.read_until('\n' as u8)
+    }
+
+    /// Return an iterator over the remaining lines, without the trailing newlines.
+    pub fn lines<'a>(&'a mut self) -> Lines<'a, R> {
+        Lines { reader: self }
+    }
+
+    /// Return a reference to the underlying reader.
+    pub fn get_ref<'a>(&'a self) -> &'a R {
+        &self.inner
+    }
+
+    /// Consume the `BufferedReader`, returning the underlying reader. Any buffered data is lost.
+    pub fn unwrap(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(libc)]
+impl<R: Reader> Reader for BufferedReader<R> {
+    fn read(&mut self, xs: &mut [u8]) -> IoResult<uint> {
+        // bypass the buffer entirely for large reads
+        if self.pos == self.end && xs.len() >= self.buf.len() {
+            return self.inner.read(xs)
+        }
+        let n = {
+            let available = try!(self.fill_buf());
+            let n = min(xs.len(), available.len());
+            unsafe {
+                copy_nonoverlapping_memory(to_mut_ptr(xs), to_ptr(available), n);
+            }
+            n
+        };
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+/// An iterator over the lines of a `BufferedReader`, stopping at the end of the stream.
+#[cfg(libc)]
+pub struct Lines<'a, R> {
+    priv reader: &'a mut BufferedReader<R>
+}
+
+#[cfg(libc)]
+impl<'a, R: Reader> Iterator<IoResult<Vec<u8>>> for Lines<'a, R> {
+    fn next(&mut self) -> Option<IoResult<Vec<u8>>> {
+        match self.reader.read_line() {
+            Ok(line) => {
+                let mut line = line;
+                let len = line.len();
+                if len == 0 {
+                    return None
+                }
+                if line.as_slice()[len - 1] == '\n' as u8 {
+                    line.truncate(len - 1);
+                }
+                Some(Ok(line))
+            }
+            Err(e) => Some(Err(e))
+        }
+    }
+}
+
+/// Wraps a `Writer` and buffers output to it. The buffer is flushed when it is full and when the
+/// `BufferedWriter` is dropped.
+#[cfg(libc)]
+pub struct BufferedWriter<W> {
+    priv inner: W,
+    priv buf: Vec<u8>,
+    priv pos: uint
+}
+
+#[cfg(libc)]
+impl<W: Writer> BufferedWriter<W> {
+    /// Create a new `BufferedWriter` with a default buffer capacity.
+    pub fn new(inner: W) -> BufferedWriter<W> {
+        BufferedWriter::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Create a new `BufferedWriter` with the specified buffer capacity. A capacity of zero is
+    /// treated as one.
+    pub fn with_capacity(capacity: uint, inner: W) -> BufferedWriter<W> {
+        BufferedWriter { inner: inner, buf: Vec::from_elem(max(capacity, 1), 0u8), pos: 0 }
+    }
+
+    /// Return a reference to the underlying writer.
+    pub fn get_ref<'a>(&'a self) -> &'a W {
+        &self.inner
+    }
+
+    /// Return a mutable reference to the underlying writer. Writing to it directly will place the
+    /// data before any data still held in the buffer.
+    pub fn get_mut<'a>(&'a mut self) -> &'a mut W {
+        &mut self.inner
+    }
+
+    // Write out the buffered data, without flushing the underlying writer. On error, the data not
+    // yet written is moved to the start of the buffer, to be written by the next flush.
+    fn flush_buf(&mut self) -> IoResult<()> {
+        let mut written = 0;
+        let mut ret = Ok(());
+        while written < self.pos {
+            match self.inner.write(slice(self.buf.as_slice(), written, self.pos)) {
+                Ok(0) => {
+                    ret = Err(Error::from_kind(ShortWrite));
+                    break
+                }
+                Ok(n) => written += n,
+                Err(e) => if e.kind != Interrupted {
+                    ret = Err(e);
+                    break
+                }
+            }
+        }
+        if written > 0 {
+            unsafe {
+                let base = to_mut_ptr(self.buf.as_mut_slice());
+                copy_memory(base, offset(base as *u8, written as int), self.pos - written);
+            }
+            self.pos -= written;
+        }
+        ret
+    }
+}
+
+#[cfg(libc)]
+impl<W: Writer> Writer for BufferedWriter<W> {
+    fn write(&mut self, xs: &[u8]) -> IoResult<uint> {
+        if self.pos + xs.len() > self.buf.len() {
+            try!(self.flush_buf());
+        }
+        if xs.len() >= self.buf.len() {
+            self.inner.write(xs)
+        } else {
+            unsafe {
+                let dst = to_mut_ptr(mut_slice_from(self.buf.as_mut_slice(), self.pos));
+                copy_nonoverlapping_memory(dst, to_ptr(xs), xs.len());
+            }
+            self.pos += xs.len();
+            Ok(xs.len())
+        }
+    }
+
+    fn flush(&mut self) -> IoResult<()> {
+        try!(self.flush_buf());
+        self.inner.flush()
+    }
+}
+
+#[cfg(libc)]
+#[unsafe_destructor]
+impl<W: Writer> Drop for BufferedWriter<W> {
+    fn drop(&mut self) {
+        self.flush();
+    }
+}
@@ -9,6 +9,24 @@
 // except according to those terms.
 
 use option::{Option, Some, None};
+use clone::Clone;
+use cmp::{Ord, min};
+use ops::{Add, Mul};
+use num::{Zero, One, Int};
+use fail::abort;
+use uint;
+
+/// Conversion from an `Iterator`.
+pub trait FromIterator<A> {
+    /// Build a container with elements from an external iterator.
+    fn from_iterator<T: Iterator<A>>(iterator: &mut T) -> Self;
+}
+
+/// A type growable from an `Iterator` implementation.
+pub trait Extendable<A>: FromIterator<A> {
+    /// Extend a container with the elements yielded by an iterator.
+    fn extend<T: Iterator<A>>(&mut self, iterator: &mut T);
+}
 
 pub trait Iterator<A> {
     fn next(&mut self) -> Option<A>;
@@ -19,6 +37,115 @@ pub trait Iterator<A> {
     #[inline(always)]
     fn size_hint(&self) -> (uint, Option<uint>) { (0, None) }
 
+    /// Chain this iterator with another, yielding the elements of `other` once this iterator is
+    /// exhausted.
+    #[inline]
+    fn chain<U: Iterator<A>>(self, other: U) -> Chain<Self, U> {
+        Chain { a: self, b: other, flag: false }
+    }
+
+    /// Iterate over pairs of elements from both iterators, stopping when either is exhausted.
+    #[inline]
+    fn zip<B, U: Iterator<B>>(self, other: U) -> Zip<Self, U> {
+        Zip { a: self, b: other }
+    }
+
+    /// Apply a function to each element.
+    #[inline]
+    fn map<'r, B>(self, f: 'r |A| -> B) -> Map<'r, A, B, Self> {
+        Map { iter: self, f: f }
+    }
+
+    /// Yield only the elements satisfying the predicate.
+    #[inline]
+    fn filter<'r>(self, predicate: 'r |&A| -> bool) -> Filter<'r, A, Self> {
+        Filter { iter: self, predicate: predicate }
+    }
+
+    /// Apply a function to each element, yielding only the `Some` results.
+    #[inline]
+    fn filter_map<'r, B>(self, f: 'r |A| -> Option<B>) -> FilterMap<'r, A, B, Self> {
+        FilterMap { iter: self, f: f }
+    }
+
+    /// Yield pairs of the current iteration count and the element.
+    #[inline]
+    fn enumerate(self) -> Enumerate<Self> {
+        Enumerate { iter: self, count: 0 }
+    }
+
+    /// Create an iterator able to look at the next element without consuming it.
+    #[inline]
+    fn peekable(self) -> Peekable<A, Self> {
+        Peekable { iter: self, peeked: None }
+    }
+
+    /// Skip elements while the predicate is true, then yield the rest.
+    #[inline]
+    fn skip_while<'r>(self, predicate: 'r |&A| -> bool) -> SkipWhile<'r, A, Self> {
+        SkipWhile { iter: self, flag: false, predicate: predicate }
+    }
+
+    /// Yield elements while the predicate is true, then stop.
+    #[inline]
+    fn take_while<'r>(self, predicate: 'r |&A| -> bool) -> TakeWhile<'r, A, Self> {
+        TakeWhile { iter: self, flag: false, predicate: predicate }
+    }
+
+    /// Skip the first `n` elements.
+    #[inline]
+    fn skip(self, n: uint) -> Skip<Self> {
+        Skip { iter: self, n: n }
+    }
+
+    /// Yield at most the first `n` elements.
+    #[inline]
+    fn take(self, n: uint) -> Take<Self> {
+        Take { iter: self, n: n }
+    }
+
+    /// Thread a mutable state through the iteration, stopping when the function returns `None`.
+    #[inline]
+    fn scan<'r, St, B>(self, initial_state: St, f: 'r |&mut St, A| -> Option<B>)
+        -> Scan<'r, A, B, Self, St> {
+        Scan { iter: self, f: f, state: initial_state }
+    }
+
+    /// Map each element to an iterator and yield the elements of each in turn.
+    #[inline]
+    fn flat_map<'r, B, U: Iterator<B>>(self, f: 'r |A| -> U) -> FlatMap<'r, A, Self, U> {
+        FlatMap { iter: self, f: f, frontiter: None, backiter: None }
+    }
+
+    /// Create an iterator which keeps returning `None` after it is first exhausted.
+    #[inline]
+    fn fuse(self) -> Fuse<Self> {
+        Fuse { iter: self, done: false }
+    }
+
+    /// Call a function with a reference to each element before yielding it.
+    #[inline]
+    fn inspect<'r>(self, f: 'r |&A|) -> Inspect<'r, A, Self> {
+        Inspect { iter: self, f: f }
+    }
+
+    /// Yield the first element and then every `step`th element after it. Calls `abort` if `step`
+    /// is zero.
+    #[inline]
+    fn step_by(self, step: uint) -> StepBy<Self> {
+        if step == 0 {
+            abort()
+        }
+        StepBy { iter: self, step: step, first: true }
+    }
+
+    /// Borrow the iterator rather than consuming it, allowing an adaptor to be applied to part of
+    /// the iteration.
+    #[inline]
+    fn by_ref<'r>(&'r mut self) -> ByRef<'r, Self> {
+        ByRef { iter: self }
+    }
+
     #[inline]
     fn fold<B>(&mut self, init: B, f: |B, A| -> B) -> B {
         let mut accum = init;
@@ -42,6 +169,88 @@ pub trait Iterator<A> {
         for x in *self { if f(x) { return true; } }
         false
     }
+
+    /// Consume the iterator, building a container from the elements.
+    #[inline]
+    fn collect<B: FromIterator<A>>(&mut self) -> B {
+        FromIterator::from_iterator(self)
+    }
+
+    /// Consume the iterator, returning the number of elements.
+    #[inline]
+    fn count(&mut self) -> uint {
+        self.fold(0, |count, _| count + 1)
+    }
+
+    /// Consume the iterator, returning the last element.
+    #[inline]
+    fn last(&mut self) -> Option<A> {
+        let mut last = None;
+        for x in *self { last = Some(x); }
+        last
+    }
+
+    /// Return the `n`th element, consuming it and all of the preceding elements.
+    #[inline]
+    fn nth(&mut self, mut n: uint) -> Option<A> {
+        for x in *self {
+            if n == 0 { return Some(x) }
+            n -= 1;
+        }
+        None
+    }
+
+    /// Return the first element satisfying the predicate.
+    #[inline]
+    fn find(&mut self, predicate: |&A| -> bool) -> Option<A> {
+        for x in *self {
+            if predicate(&x) { return Some(x) }
+        }
+        None
+    }
+
+    /// Return the index of the first element satisfying the predicate.
+    #[inline]
+    fn position(&mut self, predicate: |A| -> bool) -> Option<uint> {
+        let mut i = 0;
+        for x in *self {
+            if predicate(x) { return Some(i) }
+            i += 1;
+        }
+        None
+    }
+
+    /// Return the element giving the largest value of `f`. The last one wins among equals.
+    #[inline]
+    fn max_by<B: Ord>(&mut self, f: |&A| -> B) -> Option<A> {
+        self.fold(None, |max: Option<(A, B)>, x| {
+            let x_val = f(&x);
+            match max {
+                None => Some((x, x_val)),
+                Some((y, y_val)) => if x_val < y_val {
+                    Some((y, y_val))
+                } else {
+                    Some((x, x_val))
+                }
+            }
+        }).map(|(x, _)| x)
+    }
+
+    /// Return the element giving the smallest value of `f`. The first one wins among equals.
+    #[inline]
+    fn min_by<B: Ord>(&mut self, f: |&A| -> B) -> Option<A> {
+        self.fold(None, |min: Option<(A, B)>, x| {
+            let x_val = f(&x);
+            match min {
+                None => Some((x, x_val)),
+                Some((y, y_val)) => if x_val < y_val {
+                    Some((x, x_val))
+                } else {
+                    Some((y, y_val))
+                }
+            }
+        }).map(|(x, _)| x)
+    }
 }
 
 pub trait DoubleEndedIterator<A>: Iterator<A> {
@@ -53,6 +262,51 @@ pub trait DoubleEndedIterator<A>: Iterator<A> {
     }
 }
 
+/// Iterators which can be restarted by cloning them.
+pub trait CloneableIterator {
+    /// Repeat the iteration endlessly.
+    fn cycle(self) -> Cycle<Self>;
+}
+
+impl<A, T: Clone + Iterator<A>> CloneableIterator for T {
+    #[inline]
+    fn cycle(self) -> Cycle<T> {
+        Cycle { orig: self.clone(), iter: self }
+    }
+}
+
+/// Iterators over elements which can be summed.
+pub trait AdditiveIterator<A> {
+    fn sum(&mut self) -> A;
+}
+
+impl<A: Add<A, A> + Zero, T: Iterator<A>> AdditiveIterator<A> for T {
+    #[inline]
+    fn sum(&mut self) -> A {
+        let zero: A = Zero::zero();
+        self.fold(zero, |s, x| s + x)
+    }
+}
+
+/// Iterators over elements which can be multiplied.
+pub trait MultiplicativeIterator<A> {
+    fn product(&mut self) -> A;
+}
+
+impl<A: Mul<A, A> + One, T: Iterator<A>> MultiplicativeIterator<A> for T {
+    #[inline]
+    fn product(&mut self) -> A {
+        let one: A = One::one();
+        self.fold(one, |p, x| p * x)
+    }
+}
+
+#[inline]
+fn saturating_add(x: uint, y: uint) -> uint {
+    let (sum, overflow) = uint::add_with_overflow(x, y);
+    if overflow { !0 } else { sum }
+}
+
 #[deriving(Clone)]
 pub struct Invert<T> {
     priv iter: T
@@ -70,3 +324,859 @@ impl<A, T: DoubleEndedIterator<A>> DoubleEndedIterator<A> for Invert<T> {
     #[inline(always)]
     fn next_back(&mut self) -> Option<A> { self.iter.next() }
 }
+
+/// An iterator borrowing another iterator.
+pub struct ByRef<'a, T> {
+    priv iter: &'a mut T
+}
+
+impl<'a, A, T: Iterator<A>> Iterator<A> for ByRef<'a, T> {
+    #[inline]
+    fn next(&mut self) -> Option<A> { self.iter.next() }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) { self.iter.size_hint() }
+}
+
+impl<'a, A, T: DoubleEndedIterator<A>> DoubleEndedIterator<A> for ByRef<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<A> { self.iter.next_back() }
+}
+
+/// An iterator yielding the elements of one iterator and then another.
+#[deriving(Clone)]
+pub struct Chain<T, U> {
+    priv a: T,
+    priv b: U,
+    priv flag: bool
+}
+
+impl<A, T: Iterator<A>, U: Iterator<A>> Iterator<A> for Chain<T, U> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        if self.flag {
+            self.b.next()
+        } else {
+            match self.a.next() {
+                Some(x) => return Some(x),
+                None => ()
+            }
+            self.flag = true;
+            self.b.next()
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+
+        let lower = saturating_add(a_lower, b_lower);
+
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => {
+                let (sum, overflow) = uint::add_with_overflow(x, y);
+                if overflow { None } else { Some(sum) }
+            }
+            _ => None
+        };
+
+        (lower, upper)
+    }
+}
+
+impl<A, T: DoubleEndedIterator<A>, U: DoubleEndedIterator<A>> DoubleEndedIterator<A>
+for Chain<T, U> {
+    #[inline]
+    fn next_back(&mut self) -> Option<A> {
+        match self.b.next_back() {
+            Some(x) => Some(x),
+            None => self.a.next_back()
+        }
+    }
+}
+
+/// An iterator over pairs of elements from two iterators.
+#[deriving(Clone)]
+pub struct Zip<T, U> {
+    priv a: T,
+    priv b: U
+}
+
+impl<A, B, T: Iterator<A>, U: Iterator<B>> Iterator<(A, B)> for Zip<T, U> {
+    #[inline]
+    fn next(&mut self) -> Option<(A, B)> {
+        match self.a.next() {
+            None => None,
+            Some(x) => match self.b.next() {
+                None => None,
+                Some(y) => Some((x, y))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+
+        let lower = min(a_lower, b_lower);
+
+        let upper = match (a_upper, b_upper) {
+            (Some(x), Some(y)) => Some(min(x, y)),
+            (Some(x), None) => Some(x),
+            (None, Some(y)) => Some(y),
+            (None, None) => None
+        };
+
+        (lower, upper)
+    }
+}
+
+/// An iterator mapping a function over the elements of another iterator.
+pub struct Map<'a, A, B, T> {
+    priv iter: T,
+    priv f: 'a |A| -> B
+}
+
+impl<'a, A, B, T: Iterator<A>> Iterator<B> for Map<'a, A, B, T> {
+    #[inline]
+    fn next(&mut self) -> Option<B> {
+        match self.iter.next() {
+            Some(a) => Some((self.f)(a)),
+            None => None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, A, B, T: DoubleEndedIterator<A>> DoubleEndedIterator<B> for Map<'a, A, B, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<B> {
+        match self.iter.next_back() {
+            Some(a) => Some((self.f)(a)),
+            None => None
+        }
+    }
+}
+
+/// An iterator yielding the elements of another iterator which satisfy a predicate.
+pub struct Filter<'a, A, T> {
+    priv iter: T,
+    priv predicate: 'a |&A| -> bool
+}
+
+impl<'a, A, T: Iterator<A>> Iterator<A> for Filter<'a, A, T> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        for x in self.iter {
+            if (self.predicate)(&x) {
+                return Some(x);
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper) // can't know a lower bound, due to the predicate
+    }
+}
+
+impl<'a, A, T: DoubleEndedIterator<A>> DoubleEndedIterator<A> for Filter<'a, A, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<A> {
+        loop {
+            match self.iter.next_back() {
+                None => return None,
+                Some(x) => if (self.predicate)(&x) { return Some(x) }
+            }
+        }
+    }
+}
+
+/// An iterator mapping a function over another iterator and yielding the `Some` results.
+pub struct FilterMap<'a, A, B, T> {
+    priv iter: T,
+    priv f: 'a |A| -> Option<B>
+}
+
+impl<'a, A, B, T: Iterator<A>> Iterator<B> for FilterMap<'a, A, B, T> {
+    #[inline]
+    fn next(&mut self) -> Option<B> {
+        for x in self.iter {
+            match (self.f)(x) {
+                Some(y) => return Some(y),
+                None => ()
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper) // can't know a lower bound, due to the predicate
+    }
+}
+
+impl<'a, A, B, T: DoubleEndedIterator<A>> DoubleEndedIterator<B> for FilterMap<'a, A, B, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<B> {
+        loop {
+            match self.iter.next_back() {
+                None => return None,
+                Some(x) => match (self.f)(x) {
+                    Some(y) => return Some(y),
+                    None => ()
+                }
+            }
+        }
+    }
+}
+
+/// An iterator yielding the current count along with each element.
+#[deriving(Clone)]
+pub struct Enumerate<T> {
+    priv iter: T,
+    priv count: uint
+}
+
+impl<A, T: Iterator<A>> Iterator<(uint, A)> for Enumerate<T> {
+    #[inline]
+    fn next(&mut self) -> Option<(uint, A)> {
+        match self.iter.next() {
+            Some(a) => {
+                let ret = Some((self.count, a));
+                self.count += 1;
+                ret
+            }
+            None => None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator able to peek at the next element.
+pub struct Peekable<A, T> {
+    priv iter: T,
+    priv peeked: Option<A>
+}
+
+impl<A, T: Iterator<A>> Iterator<A> for Peekable<A, T> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        if self.peeked.is_some() { self.peeked.take() }
+        else { self.iter.next() }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (lo, hi) = self.iter.size_hint();
+        if self.peeked.is_some() {
+            let lo = saturating_add(lo, 1);
+            let hi = match hi {
+                Some(x) => {
+                    let (sum, overflow) = uint::add_with_overflow(x, 1);
+                    if overflow { None } else { Some(sum) }
+                }
+                None => None
+            };
+            (lo, hi)
+        } else {
+            (lo, hi)
+        }
+    }
+}
+
+impl<'a, A, T: Iterator<A>> Peekable<A, T> {
+    /// Return a reference to the next element without advancing the iterator.
+    #[inline]
+    pub fn peek(&'a mut self) -> Option<&'a A> {
+        if self.peeked.is_none() {
+            self.peeked = self.iter.next();
+        }
+        self.peeked.as_ref()
+    }
+
+    /// Return true if the iterator is exhausted.
+    #[inline]
+    pub fn is_empty(&mut self) -> bool {
+        self.peek().is_none()
+    }
+}
+
+/// An iterator skipping elements while a predicate is true.
+pub struct SkipWhile<'a, A, T> {
+    priv iter: T,
+    priv flag: bool,
+    priv predicate: 'a |&A| -> bool
+}
+
+impl<'a, A, T: Iterator<A>> Iterator<A> for SkipWhile<'a, A, T> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        if self.flag {
+            return self.iter.next()
+        }
+        for x in self.iter {
+            if !(self.predicate)(&x) {
+                self.flag = true;
+                return Some(x)
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper) // can't know a lower bound, due to the predicate
+    }
+}
+
+/// An iterator yielding elements while a predicate is true.
+pub struct TakeWhile<'a, A, T> {
+    priv iter: T,
+    priv flag: bool,
+    priv predicate: 'a |&A| -> bool
+}
+
+impl<'a, A, T: Iterator<A>> Iterator<A> for TakeWhile<'a, A, T> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        if self.flag {
+            None
+        } else {
+            match self.iter.next() {
+                Some(x) => {
+                    if (self.predicate)(&x) {
+                        Some(x)
+                    } else {
+                        self.flag = true;
+                        None
+                    }
+                }
+                None => None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        if self.flag {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper) // can't know a lower bound, due to the predicate
+        }
+    }
+}
+
+/// An iterator skipping the first `n` elements of another iterator.
+#[deriving(Clone)]
+pub struct Skip<T> {
+    priv iter: T,
+    priv n: uint
+}
+
+impl<A, T: Iterator<A>> Iterator<A> for Skip<T> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        if self.n == 0 {
+            self.iter.next()
+        } else {
+            let n = self.n;
+            self.n = 0;
+            self.iter.nth(n)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (lower, upper) = self.iter.size_hint();
+
+        let lower = if lower >= self.n { lower - self.n } else { 0 };
+
+        let upper = match upper {
+            Some(x) => Some(if x >= self.n { x - self.n } else { 0 }),
+            None => None
+        };
+
+        (lower, upper)
+    }
+}
+
+/// An iterator yielding at most the first `n` elements of another iterator.
+#[deriving(Clone)]
+pub struct Take<T> {
+    priv iter: T,
+    priv n: uint
+}
+
+impl<A, T: Iterator<A>> Iterator<A> for Take<T> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        if self.n != 0 {
+            self.n -= 1;
+            self.iter.next()
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (lower, upper) = self.iter.size_hint();
+
+        let lower = min(lower, self.n);
+
+        let upper = match upper {
+            Some(x) if x < self.n => Some(x),
+            _ => Some(self.n)
+        };
+
+        (lower, upper)
+    }
+}
+
+/// An iterator threading a mutable state through the elements of another iterator.
+pub struct Scan<'a, A, B, T, St> {
+    priv iter: T,
+    priv f: 'a |&mut St, A| -> Option<B>,
+
+    /// The current internal state to be passed to the closure next.
+    state: St
+}
+
+impl<'a, A, B, T: Iterator<A>, St> Iterator<B> for Scan<'a, A, B, T, St> {
+    #[inline]
+    fn next(&mut self) -> Option<B> {
+        match self.iter.next() {
+            Some(a) => (self.f)(&mut self.state, a),
+            None => None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper) // can't know a lower bound, due to the scan function
+    }
+}
+
+/// An iterator mapping each element to an iterator and yielding the elements of each in turn.
+pub struct FlatMap<'a, A, T, U> {
+    priv iter: T,
+    priv f: 'a |A| -> U,
+    priv frontiter: Option<U>,
+    priv backiter: Option<U>,
+}
+
+impl<'a, A, T: Iterator<A>, B, U: Iterator<B>> Iterator<B> for FlatMap<'a, A, T, U> {
+    #[inline]
+    fn next(&mut self) -> Option<B> {
+        loop {
+            for inner in self.frontiter.mut_iter() {
+                for x in *inner {
+                    return Some(x)
+                }
+            }
+            match self.iter.next().map(|x| (self.f)(x)) {
+                None => return self.backiter.as_mut().and_then(|it| it.next()),
+                next => self.frontiter = next
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (flo, fhi) = self.frontiter.as_ref().map_or((0, Some(0)), |it| it.size_hint());
+        let (blo, bhi) = self.backiter.as_ref().map_or((0, Some(0)), |it| it.size_hint());
+        let lo = saturating_add(flo, blo);
+        match (self.iter.size_hint(), fhi, bhi) {
+            ((0, Some(0)), Some(a), Some(b)) => {
+                let (sum, overflow) = uint::add_with_overflow(a, b);
+                (lo, if overflow { None } else { Some(sum) })
+            }
+            _ => (lo, None)
+        }
+    }
+}
+
+impl<'a, A, T: DoubleEndedIterator<A>, B, U: DoubleEndedIterator<B>> DoubleEndedIterator<B>
+for FlatMap<'a, A, T, U> {
+    #[inline]
+    fn next_back(&mut self) -> Option<B> {
+        loop {
+            for inner in self.backiter.mut_iter() {
+                match inner.next_back() {
+                    None => (),
+                    y => return y
+                }
+            }
+            match self.iter.next_back().map(|x| (self.f)(x)) {
+                None => return self.frontiter.as_mut().and_then(|it| it.next_back()),
+                next => self.backiter = next
+            }
+        }
+    }
+}
+
+/// An iterator which returns `None` forever once the underlying iterator is exhausted.
+#[deriving(Clone)]
+pub struct Fuse<T> {
+    priv iter: T,
+    priv done: bool
+}
+
+impl<A, T: Iterator<A>> Iterator<A> for Fuse<T> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        if self.done {
+            None
+        } else {
+            match self.iter.next() {
+                None => {
+                    self.done = true;
+                    None
+                }
+                x => x
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.iter.size_hint()
+        }
+    }
+}
+
+impl<A, T: DoubleEndedIterator<A>> DoubleEndedIterator<A> for Fuse<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<A> {
+        if self.done {
+            None
+        } else {
+            match self.iter.next_back() {
+                None => {
+                    self.done = true;
+                    None
+                }
+                x => x
+            }
+        }
+    }
+}
+
+/// An iterator calling a function with a reference to each element before yielding it.
+pub struct Inspect<'a, A, T> {
+    priv iter: T,
+    priv f: 'a |&A|
+}
+
+impl<'a, A, T> Inspect<'a, A, T> {
+    #[inline]
+    fn do_inspect(&mut self, elt: Option<A>) -> Option<A> {
+        match elt {
+            Some(ref a) => (self.f)(a),
+            None => ()
+        }
+
+        elt
+    }
+}
+
+impl<'a, A, T: Iterator<A>> Iterator<A> for Inspect<'a, A, T> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        let next = self.iter.next();
+        self.do_inspect(next)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, A, T: DoubleEndedIterator<A>> DoubleEndedIterator<A> for Inspect<'a, A, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<A> {
+        let next = self.iter.next_back();
+        self.do_inspect(next)
+    }
+}
+
+/// An iterator repeating the elements of another iterator endlessly.
+#[deriving(Clone)]
+pub struct Cycle<T> {
+    priv orig: T,
+    priv iter: T
+}
+
+impl<A, T: Clone + Iterator<A>> Iterator<A> for Cycle<T> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        match self.iter.next() {
+            None => {
+                self.iter = self.orig.clone();
+                self.iter.next()
+            }
+            y => y
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        // the cycle iterator is either empty or infinite
+        match self.orig.size_hint() {
+            sz @ (0, Some(0)) => sz,
+            (0, _) => (0, None),
+            _ => (!0, None)
+        }
+    }
+}
+
+/// An iterator yielding every `step`th element of another iterator.
+#[deriving(Clone)]
+pub struct StepBy<T> {
+    priv iter: T,
+    priv step: uint,
+    priv first: bool
+}
+
+impl<A, T: Iterator<A>> Iterator<A> for StepBy<T> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        if self.first {
+            self.first = false;
+            self.iter.next()
+        } else {
+            self.iter.nth(self.step - 1)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (lower, upper) = self.iter.size_hint();
+        let f = |n: uint| {
+            if self.first {
+                if n == 0 { 0 } else { 1 + (n - 1) / self.step }
+            } else {
+                n / self.step
+            }
+        };
+        (f(lower), upper.map(|n| f(n)))
+    }
+}
+
+#[inline]
+fn exact_size_hint(n: u64) -> (uint, Option<uint>) {
+    let max: uint = !0;
+    if n > max as u64 {
+        (max, None)
+    } else {
+        (n as uint, Some(n as uint))
+    }
+}
+
+/// An iterator over the integers in `[start, stop)`.
+#[deriving(Clone)]
+pub struct Range<A> {
+    priv state: A,
+    priv stop: A
+}
+
+/// Return an iterator over the integers from `start` up to but excluding `stop`.
+#[inline]
+pub fn range<A: Int>(start: A, stop: A) -> Range<A> {
+    Range { state: start, stop: stop }
+}
+
+impl<A: Int> Iterator<A> for Range<A> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        if self.state < self.stop {
+            let result = self.state.clone();
+            let one: A = One::one();
+            self.state = self.state + one;
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        if self.state < self.stop {
+            // the wrapping difference is exact for signed types too, since it can't exceed 2^64
+            exact_size_hint(self.stop.to_u64() - self.state.to_u64())
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<A: Int> DoubleEndedIterator<A> for Range<A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<A> {
+        if self.stop > self.state {
+            let one: A = One::one();
+            self.stop = self.stop - one;
+            Some(self.stop.clone())
+        } else {
+            None
+        }
+    }
+}
+
+/// An iterator over the integers in `[start, stop]`.
+#[deriving(Clone)]
+pub struct RangeInclusive<A> {
+    priv range: Range<A>,
+    priv done: bool
+}
+
+/// Return an iterator over the integers from `start` up to and including `stop`.
+#[inline]
+pub fn range_inclusive<A: Int>(start: A, stop: A) -> RangeInclusive<A> {
+    RangeInclusive { range: range(start, stop), done: false }
+}
+
+impl<A: Int> Iterator<A> for RangeInclusive<A> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        match self.range.next() {
+            Some(x) => Some(x),
+            None => {
+                if !self.done && self.range.state == self.range.stop {
+                    self.done = true;
+                    Some(self.range.stop.clone())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (lo, hi) = self.range.size_hint();
+        if self.done || self.range.stop < self.range.state {
+            (lo, hi)
+        } else {
+            let lo = saturating_add(lo, 1);
+            let hi = match hi {
+                Some(x) => {
+                    let (sum, overflow) = uint::add_with_overflow(x, 1);
+                    if overflow { None } else { Some(sum) }
+                }
+                None => None
+            };
+            (lo, hi)
+        }
+    }
+}
+
+impl<A: Int> DoubleEndedIterator<A> for RangeInclusive<A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<A> {
+        if self.range.stop > self.range.state {
+            let result = self.range.stop.clone();
+            let one: A = One::one();
+            self.range.stop = self.range.stop - one;
+            Some(result)
+        } else if !self.done && self.range.state == self.range.stop {
+            self.done = true;
+            Some(self.range.stop.clone())
+        } else {
+            None
+        }
+    }
+}
+
+/// An iterator over the integers in `[start, stop)` by `step`, counting down for a negative step.
+#[deriving(Clone)]
+pub struct RangeStep<A> {
+    priv state: A,
+    priv stop: A,
+    priv step: A,
+    priv rev: bool
+}
+
+/// Return an iterator from `start` towards but excluding `stop` by `step`, which may be negative.
+/// The iteration ends early rather than wrapping if a step would overflow. Calls `abort` if `step`
+/// is zero.
+#[inline]
+pub fn range_step<A: Int>(start: A, stop: A, step: A) -> RangeStep<A> {
+    if step.is_zero() {
+        abort()
+    }
+    let zero: A = Zero::zero();
+    let rev = step < zero;
+    RangeStep { state: start, stop: stop, step: step, rev: rev }
+}
+
+impl<A: Int> RangeStep<A> {
+    // The number of remaining elements. The distance and the magnitude of the step are computed
+    // with wrapping `u64` arithmetic, which is exact since neither can exceed 2^64 - 1.
+    fn remaining(&self) -> u64 {
+        let (distance, step) = if self.rev {
+            if self.state <= self.stop { return 0 }
+            (self.state.to_u64() - self.stop.to_u64(), 0 - self.step.to_u64())
+        } else {
+            if self.state >= self.stop { return 0 }
+            (self.stop.to_u64() - self.state.to_u64(), self.step.to_u64())
+        };
+        distance / step + if distance % step != 0 { 1 } else { 0 }
+    }
+}
+
+impl<A: Int> Iterator<A> for RangeStep<A> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        if (self.rev && self.state > self.stop) || (!self.rev && self.state < self.stop) {
+            let result = self.state.clone();
+            match self.state.add_with_overflow(&self.step) {
+                (next, false) => self.state = next,
+                (_, true) => self.state = self.stop.clone()
+            }
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        exact_size_hint(self.remaining())
+    }
+}
+
+impl<A: Int> DoubleEndedIterator<A> for RangeStep<A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<A> {
+        let n = self.remaining();
+        if n == 0 {
+            None
+        } else {
+            let last: A = Int::from_u64(self.state.to_u64() + (n - 1) * self.step.to_u64());
+            self.stop = last.clone();
+            Some(last)
+        }
+    }
+}
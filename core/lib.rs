@@ -14,13 +14,18 @@
 #[crate_type = "rlib"];
 #[feature(macro_rules)];
 
+// must come first to make the macros available to the other modules
+pub mod macros;
+
 #[cfg(libc)]
 pub mod arc;
 pub mod rc;
 #[cfg(libc)]
 pub mod weak;
 
+pub mod array_vec;
 pub mod atomic;
+mod bignum;
 #[cfg(libc)]
 pub mod deque;
 pub mod cell;
@@ -30,28 +35,39 @@ pub mod cmp;
 #[cfg(libc)]
 pub mod concurrent;
 pub mod container;
+pub mod dec2flt;
 pub mod fail;
+pub mod flt2dec;
+pub mod fmt;
+pub mod from_str;
 #[cfg(libc)]
 pub mod hash;
-#[cfg(libc)]
 pub mod io;
 pub mod iter;
 pub mod kinds;
+pub mod libm;
 #[cfg(libc)]
 pub mod lru;
-pub mod macros;
 pub mod mem;
+pub mod num;
 pub mod ops;
 pub mod option;
 #[cfg(libc)]
 pub mod priority_queue;
 pub mod ptr;
+pub mod result;
+pub mod ring_buffer;
 pub mod slice;
+#[cfg(libc)]
+pub mod small_vec;
 pub mod str;
 #[cfg(libc)]
+pub mod string;
+#[cfg(libc)]
 pub mod thread;
 #[cfg(libc)]
 pub mod time;
+pub mod unicode;
 #[cfg(libc)]
 pub mod vec;
 
@@ -70,9 +86,7 @@ pub mod i64;
 #[cfg(target_os="linux")]
 pub mod c_types;
 
-#[cfg(libc)]
 pub mod f32;
-#[cfg(libc)]
 pub mod f64;
 
 #[cfg(libc)]
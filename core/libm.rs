@@ -0,0 +1,4396 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// The algorithms and constants below are ported from musl (MIT license), which takes most of them
+// from FreeBSD's msun. The notices of the original code are preserved here:
+//
+// ====================================================
+// Copyright (C) 1993, 2004 by Sun Microsystems, Inc. All rights reserved.
+//
+// Developed at SunSoft, a Sun Microsystems, Inc. business.
+// Permission to use, copy, modify, and distribute this
+// software is freely granted, provided that this notice
+// is preserved.
+// ====================================================
+//
+// Copyright (c) 2005-2011 David Schultz <das@FreeBSD.ORG>
+// All rights reserved.
+//
+// Redistribution and use in source and binary forms, with or without
+// modification, are permitted provided that the following conditions
+// are met:
+// 1. Redistributions of source code must retain the above copyright
+//    notice, this list of conditions and the following disclaimer.
+// 2. Redistributions in binary form must reproduce the above copyright
+//    notice, this list of conditions and the following disclaimer in the
+//    documentation and/or other materials provided with the distribution.
+//
+// THIS SOFTWARE IS PROVIDED BY THE AUTHOR AND CONTRIBUTORS ``AS IS'' AND
+// ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+// IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE
+// ARE DISCLAIMED.  IN NO EVENT SHALL THE AUTHOR OR CONTRIBUTORS BE LIABLE
+// FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+// DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS
+// OR SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION)
+// HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT
+// LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY
+// OUT OF THE USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF
+// SUCH DAMAGE.
+
+//! Pure Rust implementations of the C math library functions.
+//!
+//! `f32` and `f64` call these instead of the C library when the crate is built without `libc`.
+//! The algorithms are those of musl and FreeBSD's msun, with the same error bounds: the functions
+//! that round exactly in C do so here, and the rest are within a few ulp. As in C, the names
+//! ending in `f` are the `f32` variants.
+//!
+//! There is no floating point environment, so exceptions are not raised and the rounding mode is
+//! always round to nearest.
+
+use container::Container;
+use iter::{DoubleEndedIterator, range};
+use mem::transmute;
+use option::{Option, Some, None};
+use slice::slice_to;
+use u64;
+
+mod detail {
+    extern "rust-intrinsic" {
+        pub fn sqrtf32(x: f32) -> f32;
+        pub fn sqrtf64(x: f64) -> f64;
+    }
+}
+
+// Square roots are correctly rounded by the hardware instruction the intrinsic lowers to.
+#[inline(always)]
+fn sqrt(x: f64) -> f64 {
+    unsafe { detail::sqrtf64(x) }
+}
+
+#[inline(always)]
+fn sqrtf(x: f32) -> f32 {
+    unsafe { detail::sqrtf32(x) }
+}
+
+#[inline(always)]
+fn bits(x: f64) -> u64 {
+    unsafe { transmute(x) }
+}
+
+#[inline(always)]
+fn from_bits(x: u64) -> f64 {
+    unsafe { transmute(x) }
+}
+
+#[inline(always)]
+fn bitsf(x: f32) -> u32 {
+    unsafe { transmute(x) }
+}
+
+#[inline(always)]
+fn from_bitsf(x: u32) -> f32 {
+    unsafe { transmute(x) }
+}
+
+// The high 32 bits of a double, as the msun code works with them.
+#[inline(always)]
+fn high_word(x: f64) -> u32 {
+    (bits(x) >> 32) as u32
+}
+
+// Replace the low 32 bits of a double with zeroes.
+#[inline(always)]
+fn with_low_word_zero(x: f64) -> f64 {
+    from_bits(bits(x) & 0xffffffff00000000)
+}
+
+// Replace the high 32 bits of a double.
+#[inline(always)]
+fn with_high_word(x: f64, hi: u32) -> f64 {
+    from_bits((hi as u64) << 32 | bits(x) & 0xffffffff)
+}
+
+#[inline(always)]
+fn is_nan(x: f64) -> bool {
+    x != x
+}
+
+#[inline(always)]
+fn is_nanf(x: f32) -> bool {
+    x != x
+}
+
+// Exact operations
+
+pub fn fabs(x: f64) -> f64 {
+    from_bits(bits(x) & 0x7fffffffffffffff)
+}
+
+pub fn fabsf(x: f32) -> f32 {
+    from_bitsf(bitsf(x) & 0x7fffffff)
+}
+
+pub fn copysign(x: f64, y: f64) -> f64 {
+    from_bits(bits(x) & 0x7fffffffffffffff | bits(y) & 0x8000000000000000)
+}
+
+pub fn copysignf(x: f32, y: f32) -> f32 {
+    from_bitsf(bitsf(x) & 0x7fffffff | bitsf(y) & 0x80000000)
+}
+
+// Adding and subtracting this rounds a double of smaller magnitude to an integer.
+static TOINT: f64 = 4503599627370496.0;
+static TOINTF: f32 = 8388608.0;
+
+pub fn floor(x: f64) -> f64 {
+    let ui = bits(x);
+    let e = (ui >> 52 & 0x7ff) as i32;
+    if e >= 0x3ff + 52 || x == 0.0 {
+        return x
+    }
+    if e <= 0x3ff - 1 {
+        return if ui >> 63 != 0 { -1.0 } else { 0.0 }
+    }
+    // y = int(x) - x, where int(x) is an integer neighbor of x
+    let y = if ui >> 63 != 0 { x - TOINT + TOINT - x } else { x + TOINT - TOINT - x };
+    if y > 0.0 { x + y - 1.0 } else { x + y }
+}
+
+pub fn floorf(x: f32) -> f32 {
+    let mut ui = bitsf(x);
+    let e = (ui >> 23 & 0xff) as i32 - 0x7f;
+    if e >= 23 {
+        return x
+    }
+    if e >= 0 {
+        let m = 0x007fffff >> e as u32;
+        if ui & m == 0 {
+            return x
+        }
+        if ui >> 31 != 0 {
+            ui += m;
+        }
+        ui &= !m;
+    } else if ui >> 31 == 0 {
+        ui = 0;
+    } else if ui << 1 != 0 {
+        return -1.0
+    }
+    from_bitsf(ui)
+}
+
+pub fn ceil(x: f64) -> f64 {
+    let ui = bits(x);
+    let e = (ui >> 52 & 0x7ff) as i32;
+    if e >= 0x3ff + 52 || x == 0.0 {
+        return x
+    }
+    if e <= 0x3ff - 1 {
+        return if ui >> 63 != 0 { -0.0 } else { 1.0 }
+    }
+    let y = if ui >> 63 != 0 { x - TOINT + TOINT - x } else { x + TOINT - TOINT - x };
+    if y < 0.0 { x + y + 1.0 } else { x + y }
+}
+
+pub fn ceilf(x: f32) -> f32 {
+    let mut ui = bitsf(x);
+    let e = (ui >> 23 & 0xff) as i32 - 0x7f;
+    if e >= 23 {
+        return x
+    }
+    if e >= 0 {
+        let m = 0x007fffff >> e as u32;
+        if ui & m == 0 {
+            return x
+        }
+        if ui >> 31 == 0 {
+            ui += m;
+        }
+        ui &= !m;
+    } else if ui >> 31 != 0 {
+        return -0.0
+    } else if ui << 1 != 0 {
+        return 1.0
+    }
+    from_bitsf(ui)
+}
+
+pub fn trunc(x: f64) -> f64 {
+    let ui = bits(x);
+    let mut e = (ui >> 52 & 0x7ff) as i32 - 0x3ff + 12;
+    if e >= 52 + 12 {
+        return x
+    }
+    if e < 12 {
+        e = 1;
+    }
+    let m = !0u64 >> e as u64;
+    if ui & m == 0 {
+        return x
+    }
+    from_bits(ui & !m)
+}
+
+pub fn truncf(x: f32) -> f32 {
+    let ui = bitsf(x);
+    let mut e = (ui >> 23 & 0xff) as i32 - 0x7f + 9;
+    if e >= 23 + 9 {
+        return x
+    }
+    if e < 9 {
+        e = 1;
+    }
+    let m = !0u32 >> e as u32;
+    if ui & m == 0 {
+        return x
+    }
+    from_bitsf(ui & !m)
+}
+
+/// Round to the nearest integer, with halfway cases away from zero.
+pub fn round(x: f64) -> f64 {
+    let ui = bits(x);
+    let e = (ui >> 52 & 0x7ff) as i32;
+    if e >= 0x3ff + 52 {
+        return x
+    }
+    let x = fabs(x);
+    if e < 0x3ff - 1 {
+        return 0.0 * from_bits(ui)
+    }
+    let mut y = x + TOINT - TOINT - x;
+    if y > 0.5 {
+        y = y + x - 1.0;
+    } else if y <= -0.5 {
+        y = y + x + 1.0;
+    } else {
+        y = y + x;
+    }
+    if ui >> 63 != 0 { -y } else { y }
+}
+
+pub fn roundf(x: f32) -> f32 {
+    let ui = bitsf(x);
+    let e = (ui >> 23 & 0xff) as i32;
+    if e >= 0x7f + 23 {
+        return x
+    }
+    let x = fabsf(x);
+    if e < 0x7f - 1 {
+        return 0.0 * from_bitsf(ui)
+    }
+    let mut y = x + TOINTF - TOINTF - x;
+    if y > 0.5 {
+        y = y + x - 1.0;
+    } else if y <= -0.5 {
+        y = y + x + 1.0;
+    } else {
+        y = y + x;
+    }
+    if ui >> 31 != 0 { -y } else { y }
+}
+
+/// Round to the nearest integer, with halfway cases to even.
+pub fn rint(x: f64) -> f64 {
+    let ui = bits(x);
+    let e = (ui >> 52 & 0x7ff) as i32;
+    if e >= 0x3ff + 52 {
+        return x
+    }
+    let y = if ui >> 63 != 0 { x - TOINT + TOINT } else { x + TOINT - TOINT };
+    if y == 0.0 {
+        return if ui >> 63 != 0 { -0.0 } else { 0.0 }
+    }
+    y
+}
+
+pub fn rintf(x: f32) -> f32 {
+    let ui = bitsf(x);
+    let e = (ui >> 23 & 0xff) as i32;
+    if e >= 0x7f + 23 {
+        return x
+    }
+    let y = if ui >> 31 != 0 { x - TOINTF + TOINTF } else { x + TOINTF - TOINTF };
+    if y == 0.0 {
+        return if ui >> 31 != 0 { -0.0 } else { 0.0 }
+    }
+    y
+}
+
+/// The same as `rint`, since there is no inexact exception to leave unraised.
+pub fn nearbyint(x: f64) -> f64 {
+    rint(x)
+}
+
+pub fn nearbyintf(x: f32) -> f32 {
+    rintf(x)
+}
+
+/// Split `x` into its integral and fractional parts, both with the sign of `x`.
+pub fn modf(x: f64) -> (f64, f64) {
+    let ui = bits(x);
+    let e = (ui >> 52 & 0x7ff) as i32 - 0x3ff;
+    // no fractional part
+    if e >= 52 {
+        if e == 0x400 && ui << 12 != 0 {
+            return (x, x)
+        }
+        return (x, from_bits(ui & 1 << 63))
+    }
+    // no integral part
+    if e < 0 {
+        return (from_bits(ui & 1 << 63), x)
+    }
+    let mask = !0u64 >> 12 >> e as u64;
+    if ui & mask == 0 {
+        return (x, from_bits(ui & 1 << 63))
+    }
+    let i = from_bits(ui & !mask);
+    (i, x - i)
+}
+
+pub fn modff(x: f32) -> (f32, f32) {
+    let ui = bitsf(x);
+    let e = (ui >> 23 & 0xff) as i32 - 0x7f;
+    if e >= 23 {
+        if e == 0x80 && ui << 9 != 0 {
+            return (x, x)
+        }
+        return (x, from_bitsf(ui & 0x80000000))
+    }
+    if e < 0 {
+        return (from_bitsf(ui & 0x80000000), x)
+    }
+    let mask = 0x007fffff >> e as u32;
+    if ui & mask == 0 {
+        return (x, from_bitsf(ui & 0x80000000))
+    }
+    let i = from_bitsf(ui & !mask);
+    (i, x - i)
+}
+
+/// Split `x` into a fraction with a magnitude in `[0.5, 1)` and a power of two. Zero, infinity
+/// and NaN are returned unchanged with an exponent of zero.
+pub fn frexp(x: f64) -> (f64, i32) {
+    let ui = bits(x);
+    let e = (ui >> 52 & 0x7ff) as i32;
+    if e == 0 {
+        if x == 0.0 {
+            return (x, 0)
+        }
+        // subnormal
+        let (y, e) = frexp(x * 18446744073709551616.0);
+        return (y, e - 64)
+    } else if e == 0x7ff {
+        return (x, 0)
+    }
+    (from_bits(ui & 0x800fffffffffffff | 0x3fe0000000000000), e - 0x3fe)
+}
+
+pub fn frexpf(x: f32) -> (f32, i32) {
+    let ui = bitsf(x);
+    let e = (ui >> 23 & 0xff) as i32;
+    if e == 0 {
+        if x == 0.0 {
+            return (x, 0)
+        }
+        let (y, e) = frexpf(x * 18446744073709551616.0);
+        return (y, e - 64)
+    } else if e == 0xff {
+        return (x, 0)
+    }
+    (from_bitsf(ui & 0x807fffff | 0x3f000000), e - 0x7e)
+}
+
+/// Multiply `x` by `2^n`, rounding only once if the result is subnormal.
+pub fn scalbn(x: f64, n: i32) -> f64 {
+    let x1p1023 = from_bits(0x7fe0000000000000);
+    // 2^-1022 * 2^53, so that the final scaling is by less than 2^-53 and rounds only once
+    let x1p_969 = from_bits(0x0360000000000000);
+    let mut y = x;
+    let mut n = n;
+    if n > 1023 {
+        y *= x1p1023;
+        n -= 1023;
+        if n > 1023 {
+            y *= x1p1023;
+            n -= 1023;
+            if n > 1023 {
+                n = 1023;
+            }
+        }
+    } else if n < -1022 {
+        y *= x1p_969;
+        n += 1022 - 53;
+        if n < -1022 {
+            y *= x1p_969;
+            n += 1022 - 53;
+            if n < -1022 {
+                n = -1022;
+            }
+        }
+    }
+    y * from_bits(((0x3ff + n) as u64) << 52)
+}
+
+pub fn scalbnf(x: f32, n: i32) -> f32 {
+    let x1p127 = from_bitsf(0x7f000000);
+    // 2^-126 * 2^24
+    let x1p_102 = from_bitsf(0x0c800000);
+    let mut y = x;
+    let mut n = n;
+    if n > 127 {
+        y *= x1p127;
+        n -= 127;
+        if n > 127 {
+            y *= x1p127;
+            n -= 127;
+            if n > 127 {
+                n = 127;
+            }
+        }
+    } else if n < -126 {
+        y *= x1p_102;
+        n += 126 - 24;
+        if n < -126 {
+            y *= x1p_102;
+            n += 126 - 24;
+            if n < -126 {
+                n = -126;
+            }
+        }
+    }
+    y * from_bitsf(((0x7f + n) as u32) << 23)
+}
+
+pub fn ldexp(x: f64, n: i32) -> f64 {
+    scalbn(x, n)
+}
+
+pub fn ldexpf(x: f32, n: i32) -> f32 {
+    scalbnf(x, n)
+}
+
+/// The result of `ilogb` for zero.
+pub static FP_ILOGB0: i32 = -2147483647 - 1;
+/// The result of `ilogb` for NaN.
+pub static FP_ILOGBNAN: i32 = -2147483647 - 1;
+
+/// Return the unbiased exponent of `x`, `FP_ILOGB0` for zero, `FP_ILOGBNAN` for NaN and the
+/// largest `i32` for infinity.
+pub fn ilogb(x: f64) -> i32 {
+    let mut i = bits(x);
+    let mut e = (i >> 52 & 0x7ff) as i32;
+    if e == 0 {
+        i <<= 12;
+        if i == 0 {
+            return FP_ILOGB0
+        }
+        // subnormal
+        e = -0x3ff;
+        while i >> 63 == 0 {
+            e -= 1;
+            i <<= 1;
+        }
+        return e
+    }
+    if e == 0x7ff {
+        return if i << 12 != 0 { FP_ILOGBNAN } else { 2147483647 }
+    }
+    e - 0x3ff
+}
+
+pub fn ilogbf(x: f32) -> i32 {
+    let mut i = bitsf(x);
+    let mut e = (i >> 23 & 0xff) as i32;
+    if e == 0 {
+        i <<= 9;
+        if i == 0 {
+            return FP_ILOGB0
+        }
+        e = -0x7f;
+        while i >> 31 == 0 {
+            e -= 1;
+            i <<= 1;
+        }
+        return e
+    }
+    if e == 0xff {
+        return if i << 9 != 0 { FP_ILOGBNAN } else { 2147483647 }
+    }
+    e - 0x7f
+}
+
+/// Return the unbiased exponent of `x` as a float, negative infinity for zero and infinity for
+/// infinity.
+pub fn logb(x: f64) -> f64 {
+    if bits(x) >> 52 & 0x7ff == 0x7ff {
+        return x * x
+    }
+    if x == 0.0 {
+        return -1.0 / (x * x)
+    }
+    ilogb(x) as f64
+}
+
+pub fn logbf(x: f32) -> f32 {
+    if bitsf(x) >> 23 & 0xff == 0xff {
+        return x * x
+    }
+    if x == 0.0 {
+        return -1.0 / (x * x)
+    }
+    ilogbf(x) as f32
+}
+
+/// Return the next representable value after `x` in the direction of `y`.
+pub fn nextafter(x: f64, y: f64) -> f64 {
+    if is_nan(x) || is_nan(y) {
+        return x + y
+    }
+    let mut ux = bits(x);
+    let uy = bits(y);
+    if ux == uy {
+        return y
+    }
+    let ax = ux & 0x7fffffffffffffff;
+    let ay = uy & 0x7fffffffffffffff;
+    if ax == 0 {
+        if ay == 0 {
+            return y
+        }
+        ux = uy & 1 << 63 | 1;
+    } else if ax > ay || (ux ^ uy) & 1 << 63 != 0 {
+        ux -= 1;
+    } else {
+        ux += 1;
+    }
+    from_bits(ux)
+}
+
+pub fn nextafterf(x: f32, y: f32) -> f32 {
+    if is_nanf(x) || is_nanf(y) {
+        return x + y
+    }
+    let mut ux = bitsf(x);
+    let uy = bitsf(y);
+    if ux == uy {
+        return y
+    }
+    let ax = ux & 0x7fffffff;
+    let ay = uy & 0x7fffffff;
+    if ax == 0 {
+        if ay == 0 {
+            return y
+        }
+        ux = uy & 0x80000000 | 1;
+    } else if ax > ay || (ux ^ uy) & 0x80000000 != 0 {
+        ux -= 1;
+    } else {
+        ux += 1;
+    }
+    from_bitsf(ux)
+}
+
+/// The same as `nextafter`, as `y` has the type of `x` here rather than `long double` as in C.
+pub fn nexttoward(x: f64, y: f64) -> f64 {
+    nextafter(x, y)
+}
+
+pub fn nexttowardf(x: f32, y: f32) -> f32 {
+    nextafterf(x, y)
+}
+
+/// Return the larger argument, ignoring NaN unless both are NaN and treating -0 as less than 0.
+pub fn fmax(x: f64, y: f64) -> f64 {
+    if is_nan(x) {
+        return y
+    }
+    if is_nan(y) {
+        return x
+    }
+    if (bits(x) ^ bits(y)) >> 63 != 0 {
+        return if bits(x) >> 63 != 0 { y } else { x }
+    }
+    if x < y { y } else { x }
+}
+
+pub fn fmaxf(x: f32, y: f32) -> f32 {
+    if is_nanf(x) {
+        return y
+    }
+    if is_nanf(y) {
+        return x
+    }
+    if (bitsf(x) ^ bitsf(y)) >> 31 != 0 {
+        return if bitsf(x) >> 31 != 0 { y } else { x }
+    }
+    if x < y { y } else { x }
+}
+
+/// Return the smaller argument, ignoring NaN unless both are NaN and treating -0 as less than 0.
+pub fn fmin(x: f64, y: f64) -> f64 {
+    if is_nan(x) {
+        return y
+    }
+    if is_nan(y) {
+        return x
+    }
+    if (bits(x) ^ bits(y)) >> 63 != 0 {
+        return if bits(x) >> 63 != 0 { x } else { y }
+    }
+    if x < y { x } else { y }
+}
+
+pub fn fminf(x: f32, y: f32) -> f32 {
+    if is_nanf(x) {
+        return y
+    }
+    if is_nanf(y) {
+        return x
+    }
+    if (bitsf(x) ^ bitsf(y)) >> 31 != 0 {
+        return if bitsf(x) >> 31 != 0 { x } else { y }
+    }
+    if x < y { x } else { y }
+}
+
+/// Return `x - y` if it is positive and zero otherwise.
+pub fn fdim(x: f64, y: f64) -> f64 {
+    if is_nan(x) {
+        return x
+    }
+    if is_nan(y) {
+        return y
+    }
+    if x > y { x - y } else { 0.0 }
+}
+
+pub fn fdimf(x: f32, y: f32) -> f32 {
+    if is_nanf(x) {
+        return x
+    }
+    if is_nanf(y) {
+        return y
+    }
+    if x > y { x - y } else { 0.0 }
+}
+
+// A finite non-zero double as `(-1)^sign * m * 2^e`, with `m` in `[2^53, 2^54)` and its lowest
+// bit clear. `e` is at least `ZEROINFNAN` for zero, infinity and NaN.
+struct Num {
+    m: u64,
+    e: i32,
+    sign: i32
+}
+
+static ZEROINFNAN: i32 = 0x7ff - 0x3ff - 52 - 1;
+
+fn normalize(x: f64) -> Num {
+    let mut ix = bits(x);
+    let mut e = (ix >> 52) as i32;
+    let sign = e & 0x800;
+    e &= 0x7ff;
+    if e == 0 {
+        ix = bits(x * 9223372036854775808.0);
+        e = (ix >> 52 & 0x7ff) as i32;
+        e = if e != 0 { e - 63 } else { 0x800 };
+    }
+    ix &= (1 << 52) - 1;
+    ix |= 1 << 52;
+    ix <<= 1;
+    e -= 0x3ff + 52 + 1;
+    Num { m: ix, e: e, sign: sign }
+}
+
+// The full 128-bit product of `x` and `y` as `(high, low)`.
+fn mul_wide(x: u64, y: u64) -> (u64, u64) {
+    let xlo = x & 0xffffffff;
+    let xhi = x >> 32;
+    let ylo = y & 0xffffffff;
+    let yhi = y >> 32;
+    let t1 = xlo * ylo;
+    let t2 = xlo * yhi + xhi * ylo;
+    let t3 = xhi * yhi;
+    let lo = t1 + (t2 << 32);
+    let hi = t3 + (t2 >> 32) + if lo < t1 { 1 } else { 0 };
+    (hi, lo)
+}
+
+/// Compute `x * y + z` with a single rounding.
+pub fn fma(x: f64, y: f64, z: f64) -> f64 {
+    // normalize so the top 10 bits and the last bit are 0
+    let nx = normalize(x);
+    let ny = normalize(y);
+    let nz = normalize(z);
+
+    if nx.e >= ZEROINFNAN || ny.e >= ZEROINFNAN {
+        return x * y + z
+    }
+    if nz.e >= ZEROINFNAN {
+        if nz.e > ZEROINFNAN {
+            // z == 0
+            return x * y + z
+        }
+        return z
+    }
+
+    // r = x * y, where either the top 20 or 21 bits of rhi and the last 2 bits of rlo are 0
+    let (mut rhi, mut rlo) = mul_wide(nx.m, ny.m);
+
+    // align exponents, shifting z left by kz and r right by kr with kz + kr == d and
+    // e = e + kr == ez - kz
+    let mut e = nx.e + ny.e;
+    let mut d = nz.e - e;
+    let zhi;
+    let zlo;
+    if d > 0 {
+        if d < 64 {
+            zlo = nz.m << d as u64;
+            zhi = nz.m >> (64 - d) as u64;
+        } else {
+            zlo = 0;
+            zhi = nz.m;
+            e = nz.e - 64;
+            d -= 64;
+            if d >= 64 {
+                rlo = 1;
+                rhi = 0;
+            } else if d > 0 {
+                let sticky = if rlo << (64 - d) as u64 != 0 { 1 } else { 0 };
+                rlo = rhi << (64 - d) as u64 | rlo >> d as u64 | sticky;
+                rhi = rhi >> d as u64;
+            }
+        }
+    } else {
+        zhi = 0;
+        d = -d;
+        if d == 0 {
+            zlo = nz.m;
+        } else if d < 64 {
+            zlo = nz.m >> d as u64 | if nz.m << (64 - d) as u64 != 0 { 1 } else { 0 };
+        } else {
+            zlo = 1;
+        }
+    }
+
+    // add
+    let mut sign = nx.sign ^ ny.sign;
+    let samesign = sign ^ nz.sign == 0;
+    let mut nonzero = true;
+    if samesign {
+        // r += z
+        rlo += zlo;
+        rhi += zhi + if rlo < zlo { 1 } else { 0 };
+    } else {
+        // r -= z
+        let t = rlo;
+        rlo -= zlo;
+        rhi = rhi - zhi - if t < rlo { 1 } else { 0 };
+        if rhi >> 63 != 0 {
+            rlo = 0 - rlo;
+            rhi = 0 - rhi - if rlo != 0 { 1 } else { 0 };
+            sign = if sign == 0 { 1 } else { 0 };
+        }
+        nonzero = rhi != 0;
+    }
+
+    // set rhi to the top 63 bits of the result, with the last bit sticky
+    if nonzero {
+        e += 64;
+        d = u64::leading_zeros(rhi) as i32 - 1;
+        // d > 0
+        let sticky = if rlo << d as u64 != 0 { 1 } else { 0 };
+        rhi = rhi << d as u64 | rlo >> (64 - d) as u64 | sticky;
+    } else if rlo != 0 {
+        d = u64::leading_zeros(rlo) as i32 - 1;
+        if d < 0 {
+            rhi = rlo >> 1 | rlo & 1;
+        } else {
+            rhi = rlo << d as u64;
+        }
+    } else {
+        // exact +-0
+        return x * y + z
+    }
+    e -= d;
+
+    // convert to double, with i in [2^62, 2^63 - 1] and |r| in [2^62, 2^63]
+    let mut i = rhi as i64;
+    if sign != 0 {
+        i = -i;
+    }
+    let mut r = i as f64;
+
+    if e < -1022 - 62 {
+        // the result is subnormal before rounding
+        if e == -1022 - 63 {
+            let c = if sign != 0 { -9223372036854775808.0 } else { 9223372036854775808.0 };
+            if r == c {
+                // the smallest normal number after rounding
+                let min = from_bits(0x0010000000000000);
+                return if sign != 0 { -min } else { min }
+            }
+            // one bit is lost when scaled, so add another top bit to only round once at the
+            // conversion if it is inexact
+            if rhi << 53 != 0 {
+                i = (rhi >> 1 | rhi & 1 | 1 << 62) as i64;
+                if sign != 0 {
+                    i = -i;
+                }
+                r = i as f64;
+                // remove the top bit
+                r = 2.0 * r - c;
+            }
+        } else {
+            // only round once when scaled
+            d = 10;
+            let sticky = if rhi << (64 - d) as u64 != 0 { 1 } else { 0 };
+            i = ((rhi >> d as u64 | sticky) << d as u64) as i64;
+            if sign != 0 {
+                i = -i;
+            }
+            r = i as f64;
+        }
+    }
+    scalbn(r, e)
+}
+
+pub fn fmaf(x: f32, y: f32, z: f32) -> f32 {
+    // A double has more than twice the precision of a float, so double arithmetic is exact for
+    // the product and only needs fixing up for double rounding.
+    let xy = x as f64 * y as f64;
+    let result = xy + z as f64;
+    let mut ui = bits(result);
+    let e = (ui >> 52 & 0x7ff) as i32;
+    if ui & 0x1fffffff != 0x10000000 || e == 0x7ff ||
+       (result - xy == z as f64 && result - z as f64 == xy) {
+        // not a halfway case, NaN or exact
+        return result as f32
+    }
+    // the result is inexact and exactly halfway between two floats, so adjust the low bit in the
+    // direction of the error
+    let neg = ui >> 63 != 0;
+    let err = if neg == (z as f64 > xy) { xy - result + z as f64 } else { z as f64 - result + xy };
+    if neg == (err < 0.0) {
+        ui += 1;
+    } else {
+        ui -= 1;
+    }
+    from_bits(ui) as f32
+}
+
+// Trigonometric functions
+
+// The bits of 2/pi, 24 to an entry, which is enough for the reduction of any double.
+static IPIO2: [i32, ..66] = [
+    0xA2F983, 0x6E4E44, 0x1529FC, 0x2757D1, 0xF534DD, 0xC0DB62, 0x95993C, 0x439041, 0xFE5163,
+    0xABDEBB, 0xC561B7, 0x246E3A, 0x424DD2, 0xE00649, 0x2EEA09, 0xD1921C, 0xFE1DEB, 0x1CB129,
+    0xA73EE8, 0x8235F5, 0x2EBB44, 0x84E99C, 0x7026B4, 0x5F7E41, 0x3991D6, 0x398353, 0x39F49C,
+    0x845F8B, 0xBDF928, 0x3B1FF8, 0x97FFDE, 0x05980F, 0xEF2F11, 0x8B5A0A, 0x6D1F6D, 0x367ECF,
+    0x27CB09, 0xB74F46, 0x3F669E, 0x5FEA2D, 0x7527BA, 0xC7EBE5, 0xF17B3D, 0x0739F7, 0x8A5292,
+    0xEA6BFB, 0x5FB11F, 0x8D5D08, 0x560330, 0x46FC7B, 0x6BABF0, 0xCFBC20, 0x9AF436, 0x1DA9E3,
+    0x91615E, 0xE61B08, 0x659985, 0x5F14A0, 0x68408D, 0xFFD880, 0x4D7327, 0x310606, 0x1556CA,
+    0x73A8C9, 0x60E27B, 0xC08C6B
+];
+
+// pi/2 split into pieces of 24 bits.
+static PIO2: [f64, ..8] = [
+    1.57079625129699707031e+00, // 0x3FF921FB, 0x40000000
+    7.54978941586159635335e-08, // 0x3E74442D, 0x00000000
+    5.39030252995776476554e-15, // 0x3CF84698, 0x80000000
+    3.28200341580791294123e-22, // 0x3B78CC51, 0x60000000
+    1.27065575308067607349e-29, // 0x39F01B83, 0x80000000
+    1.22933308981111328932e-36, // 0x387A2520, 0x40000000
+    2.73370053816464559624e-44, // 0x36E38222, 0x80000000
+    2.16741683877804819444e-51  // 0x3569F31D, 0x00000000
+];
+
+// Reduce a large value modulo pi/2, returning the low 3 bits of the quotient.
+//
+// The input is `sum(x[i] * 2^(e0 - 24 * i))`, where each `x[i]` is an integer below 2^24 and
+// `x[0]` is non-zero. The remainder is stored in `y[0]` with 24 bits of precision if `prec` is 0,
+// and in `y[0] + y[1]` with 53 bits if `prec` is 1.
+//
+// The product with 2/pi only needs the bits of 2/pi around the binary point of the result, since
+// the higher ones contribute multiples of 8 and the lower ones are below the precision. Those are
+// taken from `IPIO2` starting at `jv`, with more terms added whenever the fraction cancels too
+// much.
+fn rem_pio2_large(x: &[f64], y: &mut [f64], e0: i32, prec: uint) -> i32 {
+    let x1p24 = from_bits(0x4170000000000000);
+    let x1p_24 = from_bits(0x3e70000000000000);
+
+    let mut f = [0.0, ..20];
+    let mut fq = [0.0, ..20];
+    let mut q = [0.0, ..20];
+    let mut iq = [0i32, ..20];
+
+    // the number of terms of the product needed for the precision
+    let jk = if prec == 0 { 3 } else { 4 };
+    let jp = jk;
+
+    // determine jx, jv and q0, where q0 < 3
+    let jx = x.len() - 1;
+    let mut jv = (e0 - 3) / 24;
+    if jv < 0 {
+        jv = 0;
+    }
+    let mut q0 = e0 - 24 * (jv + 1);
+    let jv = jv as uint;
+
+    // set up f[0] to f[jx + jk], where f[jx + jk] = IPIO2[jv + jk]
+    let mut j = jv as i32 - jx as i32;
+    for i in range(0, jx + jk + 1) {
+        f[i] = if j < 0 { 0.0 } else { IPIO2[j as uint] as f64 };
+        j += 1;
+    }
+
+    // compute q[0], q[1], ... q[jk]
+    for i in range(0, jk + 1) {
+        let mut fw = 0.0;
+        for j in range(0, jx + 1) {
+            fw += x[j] * f[jx + i - j];
+        }
+        q[i] = fw;
+    }
+
+    let mut jz = jk;
+    let mut z;
+    let mut n;
+    let mut ih;
+    loop {
+        // distill q[] into iq[] in reverse
+        z = q[jz];
+        let mut i = 0;
+        for j in range(1, jz + 1).invert() {
+            let fw = (x1p_24 * z) as i32 as f64;
+            iq[i] = (z - x1p24 * fw) as i32;
+            z = q[j - 1] + fw;
+            i += 1;
+        }
+
+        // compute n, trimming off the integer part above 8
+        z = scalbn(z, q0);
+        z -= 8.0 * floor(z * 0.125);
+        n = z as i32;
+        z -= n as f64;
+        ih = 0;
+        if q0 > 0 {
+            // iq[jz - 1] is needed to determine n
+            let i = iq[jz - 1] >> (24 - q0) as i32;
+            n += i;
+            iq[jz - 1] -= i << (24 - q0) as i32;
+            ih = iq[jz - 1] >> (23 - q0) as i32;
+        } else if q0 == 0 {
+            ih = iq[jz - 1] >> 23;
+        } else if z >= 0.5 {
+            ih = 2;
+        }
+
+        if ih > 0 {
+            // the fraction is above 0.5, so compute 1 - q
+            n += 1;
+            let mut carry = 0;
+            for i in range(0, jz) {
+                let j = iq[i];
+                if carry == 0 {
+                    if j != 0 {
+                        carry = 1;
+                        iq[i] = 0x1000000 - j;
+                    }
+                } else {
+                    iq[i] = 0xffffff - j;
+                }
+            }
+            // rare cases, with a chance of 1 in 12
+            if q0 == 1 {
+                iq[jz - 1] &= 0x7fffff;
+            } else if q0 == 2 {
+                iq[jz - 1] &= 0x3fffff;
+            }
+            if ih == 2 {
+                z = 1.0 - z;
+                if carry != 0 {
+                    z -= scalbn(1.0, q0);
+                }
+            }
+        }
+
+        // recompute with more terms if the fraction cancelled out
+        if z == 0.0 {
+            let mut j = 0;
+            for i in range(jk, jz) {
+                j |= iq[i];
+            }
+            if j == 0 {
+                let mut k = 1;
+                while iq[jk - k] == 0 {
+                    k += 1;
+                }
+                for i in range(jz + 1, jz + k + 1) {
+                    f[jx + i] = IPIO2[jv + i] as f64;
+                    let mut fw = 0.0;
+                    for j in range(0, jx + 1) {
+                        fw += x[j] * f[jx + i - j];
+                    }
+                    q[i] = fw;
+                }
+                jz += k;
+                continue
+            }
+        }
+        break
+    }
+
+    // chop off zero terms
+    if z == 0.0 {
+        jz -= 1;
+        q0 -= 24;
+        while iq[jz] == 0 {
+            jz -= 1;
+            q0 -= 24;
+        }
+    } else {
+        // break z into 24-bit pieces if necessary
+        z = scalbn(z, -q0);
+        if z >= x1p24 {
+            let fw = (x1p_24 * z) as i32 as f64;
+            iq[jz] = (z - x1p24 * fw) as i32;
+            jz += 1;
+            q0 += 24;
+            iq[jz] = fw as i32;
+        } else {
+            iq[jz] = z as i32;
+        }
+    }
+
+    // convert the integer pieces to floating point values
+    let mut fw = scalbn(1.0, q0);
+    for i in range(0, jz + 1).invert() {
+        q[i] = fw * iq[i] as f64;
+        fw *= x1p_24;
+    }
+
+    // compute PIO2[0, ..., jp] * q[jz, ..., 0]
+    for i in range(0, jz + 1).invert() {
+        let mut fw = 0.0;
+        let mut k = 0;
+        while k <= jp && k <= jz - i {
+            fw += PIO2[k] * q[i + k];
+            k += 1;
+        }
+        fq[jz - i] = fw;
+    }
+
+    // compress fq[] into y[]
+    let mut fw = 0.0;
+    for i in range(0, jz + 1).invert() {
+        fw += fq[i];
+    }
+    y[0] = if ih == 0 { fw } else { -fw };
+    if prec > 0 {
+        fw = fq[0] - fw;
+        for i in range(1, jz + 1) {
+            fw += fq[i];
+        }
+        y[1] = if ih == 0 { fw } else { -fw };
+    }
+    n & 7
+}
+
+// Reduce `x` modulo pi/2, returning the low bits of the quotient and the remainder as the sum of
+// two doubles. The caller handles the case where no reduction is needed, |x| ~<= pi/4.
+fn rem_pio2(x: f64) -> (i32, f64, f64) {
+    // 1.5 / DBL_EPSILON, to round to an integer by adding and subtracting
+    static TOINT: f64 = 6755399441055744.0;
+    // 53 bits of 2/pi
+    static INV_PIO2: f64 = 6.36619772367581382433e-01; // 0x3FE45F30, 0x6DC9C883
+    // the first 33 bits of pi/2
+    static PIO2_1: f64 = 1.57079632673412561417e+00; // 0x3FF921FB, 0x54400000
+    // pi/2 - PIO2_1
+    static PIO2_1T: f64 = 6.07710050650619224932e-11; // 0x3DD0B461, 0x1A626331
+    // the second 33 bits of pi/2
+    static PIO2_2: f64 = 6.07710050630396597660e-11; // 0x3DD0B461, 0x1A600000
+    // pi/2 - (PIO2_1 + PIO2_2)
+    static PIO2_2T: f64 = 2.02226624879595063154e-21; // 0x3BA3198A, 0x2E037073
+    // the third 33 bits of pi/2
+    static PIO2_3: f64 = 2.02226624871116645580e-21; // 0x3BA3198A, 0x2E000000
+    // pi/2 - (PIO2_1 + PIO2_2 + PIO2_3)
+    static PIO2_3T: f64 = 8.47842766036889956997e-32; // 0x397B839A, 0x252049C1
+
+    // |x| ~< 2^20 * (pi/2), rounding x / (pi/2) to an integer
+    fn medium(x: f64, ix: u32) -> (i32, f64, f64) {
+        let f_n = x * INV_PIO2 + TOINT - TOINT;
+        let n = f_n as i32;
+        let mut r = x - f_n * PIO2_1;
+        // the first round, good to 85 bits
+        let mut w = f_n * PIO2_1T;
+        let mut y0 = r - w;
+        let ex = (ix >> 20) as i32;
+        let ey = (bits(y0) >> 52 & 0x7ff) as i32;
+        if ex - ey > 16 {
+            // the second round, good to 118 bits
+            let t = r;
+            w = f_n * PIO2_2;
+            r = t - w;
+            w = f_n * PIO2_2T - ((t - r) - w);
+            y0 = r - w;
+            let ey = (bits(y0) >> 52 & 0x7ff) as i32;
+            if ex - ey > 49 {
+                // the third round, good to 151 bits, which covers all cases
+                let t = r;
+                w = f_n * PIO2_3;
+                r = t - w;
+                w = f_n * PIO2_3T - ((t - r) - w);
+                y0 = r - w;
+            }
+        }
+        let y1 = (r - y0) - w;
+        (n, y0, y1)
+    }
+
+    // subtract k * pi/2, for small k
+    fn small(x: f64, k: i32) -> (i32, f64, f64) {
+        let kf = k as f64;
+        // one round, good to 85 bits
+        let z = x - kf * PIO2_1;
+        let y0 = z - kf * PIO2_1T;
+        let y1 = (z - y0) - kf * PIO2_1T;
+        (k, y0, y1)
+    }
+
+    let sign = bits(x) >> 63 != 0;
+    let ix = high_word(x) & 0x7fffffff;
+    if ix <= 0x400f6a7a {
+        // |x| ~<= 5pi/4
+        if ix & 0xfffff == 0x921fb {
+            // |x| ~= pi/2 or 2pi/2, so cancellation needs the medium case
+            return medium(x, ix)
+        }
+        if ix <= 0x4002d97c {
+            // |x| ~<= 3pi/4
+            return small(x, if sign { -1 } else { 1 })
+        }
+        return small(x, if sign { -2 } else { 2 })
+    }
+    if ix <= 0x401c463b {
+        // |x| ~<= 9pi/4
+        if ix <= 0x4015fdbc {
+            // |x| ~<= 7pi/4
+            if ix == 0x4012d97c {
+                // |x| ~= 3pi/2
+                return medium(x, ix)
+            }
+            return small(x, if sign { -3 } else { 3 })
+        }
+        if ix == 0x401921fb {
+            // |x| ~= 4pi/2
+            return medium(x, ix)
+        }
+        return small(x, if sign { -4 } else { 4 })
+    }
+    if ix < 0x413921fb {
+        return medium(x, ix)
+    }
+    if ix >= 0x7ff00000 {
+        // x is infinite or NaN
+        let y = x - x;
+        return (0, y, y)
+    }
+
+    // split |x| scaled to [2^23, 2^24) into 24-bit integers for the large case
+    let mut z = from_bits(bits(x) & (!0u64 >> 12) | (0x3ff + 23) << 52);
+    let mut tx = [0.0, ..3];
+    for i in range(0u, 2) {
+        tx[i] = z as i32 as f64;
+        z = (z - tx[i]) * from_bits(0x4170000000000000);
+    }
+    tx[2] = z;
+    // skip zero terms, where the first term is non-zero
+    let mut len = 3;
+    while tx[len - 1] == 0.0 {
+        len -= 1;
+    }
+    let mut ty = [0.0, ..2];
+    let n = rem_pio2_large(slice_to(tx, len), ty, (ix >> 20) as i32 - (0x3ff + 23), 1);
+    if sign {
+        return (-n, -ty[0], -ty[1])
+    }
+    (n, ty[0], ty[1])
+}
+
+// The sine of `x + y` for |x| ~<= pi/4, where `y` is the tail of `x` and `iy` is 0 if it is zero.
+// Callers return sin(-0) = -0 themselves.
+//
+// sin(x) is approximated by an odd polynomial of degree 13 on [0, pi/4] with an error below 2^-58
+// relative to x. With r = x^3 * (S2 + x^2 * (S3 + x^2 * (S4 + x^2 * (S5 + x^2 * S6)))) for
+// better accuracy, sin(x + y) ~ x + (S1 * x^3 + (x^2 * (r - y / 2) + y)).
+fn k_sin(x: f64, y: f64, iy: i32) -> f64 {
+    static S1: f64 = -1.66666666666666324348e-01; // 0xBFC55555, 0x55555549
+    static S2: f64 = 8.33333333332248946124e-03; // 0x3F811111, 0x1110F8A6
+    static S3: f64 = -1.98412698298579493134e-04; // 0xBF2A01A0, 0x19C161D5
+    static S4: f64 = 2.75573137070700676789e-06; // 0x3EC71DE3, 0x57B1FE7D
+    static S5: f64 = -2.50507602534068634195e-08; // 0xBE5AE5E6, 0x8A2B9CEB
+    static S6: f64 = 1.58969099521155010221e-10; // 0x3DE5D93A, 0x5ACFD57C
+
+    let z = x * x;
+    let w = z * z;
+    let r = S2 + z * (S3 + z * S4) + z * w * (S5 + z * S6);
+    let v = z * x;
+    if iy == 0 {
+        x + v * (S1 + z * r)
+    } else {
+        x - ((z * (0.5 * y - v * r) - y) - v * S1)
+    }
+}
+
+// The cosine of `x + y` for |x| ~<= pi/4, where `y` is the tail of `x`.
+//
+// cos(x) is approximated by 1 - x^2 / 2 + r with an even polynomial r of degree 14 on [0, pi/4],
+// with an error below 2^-58. As cos(x + y) ~ cos(x) - x * y, the result is computed as
+// w + (((1 - w) - x^2 / 2) + (r - x * y)) with w = 1 - x^2 / 2, where the first term corrects the
+// rounding of w.
+fn k_cos(x: f64, y: f64) -> f64 {
+    static C1: f64 = 4.16666666666666019037e-02; // 0x3FA55555, 0x5555554C
+    static C2: f64 = -1.38888888888741095749e-03; // 0xBF56C16C, 0x16C15177
+    static C3: f64 = 2.48015872894767294178e-05; // 0x3EFA01A0, 0x19CB1590
+    static C4: f64 = -2.75573143513906633035e-07; // 0xBE927E4F, 0x809C52AD
+    static C5: f64 = 2.08757232129817482790e-09; // 0x3E21EE9E, 0xBDB4B1C4
+    static C6: f64 = -1.13596475577881948265e-11; // 0xBDA8FAE9, 0xBE8838D4
+
+    let z = x * x;
+    let w = z * z;
+    let r = z * (C1 + z * (C2 + z * C3)) + w * w * (C4 + z * (C5 + z * C6));
+    let hz = 0.5 * z;
+    let w = 1.0 - hz;
+    w + (((1.0 - w) - hz) + (z * r - x * y))
+}
+
+// The tangent of `x + y` for |x| ~<= pi/4 if `odd` is 0, and -1 / tan(x + y) otherwise. Callers
+// return tan(-0) = -0 themselves.
+//
+// tan(x) is approximated by an odd polynomial of degree 27 on [0, 0.67434] with an error below
+// 2^-59.2 relative to x. Above that, tan(x) = tan(pi/4 - y) = (1 - tan(y)) / (1 + tan(y)) with
+// y = pi/4 - x.
+fn k_tan(x: f64, y: f64, odd: i32) -> f64 {
+    static T: [f64, ..13] = [
+        3.33333333333334091986e-01, // 3FD55555, 55555563
+        1.33333333333201242699e-01, // 3FC11111, 1110FE7A
+        5.39682539762260521377e-02, // 3FABA1BA, 1BB341FE
+        2.18694882948595424599e-02, // 3F9664F4, 8406D637
+        8.86323982359930005737e-03, // 3F8226E3, E96E8493
+        3.59207910759131235356e-03, // 3F6D6D22, C9560328
+        1.45620945432529025516e-03, // 3F57DBC8, FEE08315
+        5.88041240820264096874e-04, // 3F4344D8, F2F26501
+        2.46463134818469906812e-04, // 3F3026F7, 1A8D1068
+        7.81794442939557092300e-05, // 3F147E88, A03792A6
+        7.14072491382608190305e-05, // 3F12B80F, 32F0A7E9
+        -1.85586374855275456654e-05, // BEF375CB, DB605373
+        2.59073051863633712884e-05 // 3EFB2A70, 74BF7AD4
+    ];
+    static PIO4: f64 = 7.85398163397448278999e-01; // 3FE921FB, 54442D18
+    static PIO4_LO: f64 = 3.06161699786838301793e-17; // 3C81A626, 33145C07
+
+    let mut x = x;
+    let mut y = y;
+    let hx = high_word(x);
+    // |x| >= 0.6744
+    let big = hx & 0x7fffffff >= 0x3FE59428;
+    let sign = hx >> 31 != 0;
+    if big {
+        if sign {
+            x = -x;
+            y = -y;
+        }
+        x = (PIO4 - x) + (PIO4_LO - y);
+        y = 0.0;
+    }
+    let z = x * x;
+    let w = z * z;
+    // break x^5 * (T[1] + x^2 * T[2] + ...) into
+    // x^5 * (T[1] + x^4 * T[3] + ... + x^20 * T[11]) +
+    // x^5 * (x^2 * (T[2] + x^4 * T[4] + ... + x^22 * T[12]))
+    let r = T[1] + w * (T[3] + w * (T[5] + w * (T[7] + w * (T[9] + w * T[11]))));
+    let v = z * (T[2] + w * (T[4] + w * (T[6] + w * (T[8] + w * (T[10] + w * T[12])))));
+    let s = z * x;
+    let r = y + z * (s * (r + v) + y) + s * T[0];
+    let w = x + r;
+    if big {
+        let s = 1.0 - 2.0 * odd as f64;
+        let v = s - 2.0 * (x + (r - w * w / (w + s)));
+        return if sign { -v } else { v }
+    }
+    if odd == 0 {
+        return w
+    }
+    // -1.0 / (x + r) has an error of up to 2 ulp, so compute it accurately
+    let w0 = with_low_word_zero(w);
+    // w0 + v = r + x
+    let v = r - (w0 - x);
+    let a = -1.0 / w;
+    let a0 = with_low_word_zero(a);
+    a0 + a * (1.0 + a0 * w0 + a0 * v)
+}
+
+pub fn sin(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    if ix <= 0x3fe921fb {
+        // |x| ~< pi/4
+        if ix < 0x3e500000 {
+            // |x| < 2^-26
+            return x
+        }
+        return k_sin(x, 0.0, 0)
+    }
+    if ix >= 0x7ff00000 {
+        // sin(inf) and sin(NaN) are NaN
+        return x - x
+    }
+    let (n, y0, y1) = rem_pio2(x);
+    match n & 3 {
+        0 => k_sin(y0, y1, 1),
+        1 => k_cos(y0, y1),
+        2 => -k_sin(y0, y1, 1),
+        _ => -k_cos(y0, y1)
+    }
+}
+
+pub fn cos(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    if ix <= 0x3fe921fb {
+        // |x| ~< pi/4
+        if ix < 0x3e46a09e {
+            // |x| < 2^-27 * sqrt(2)
+            return 1.0
+        }
+        return k_cos(x, 0.0)
+    }
+    if ix >= 0x7ff00000 {
+        return x - x
+    }
+    let (n, y0, y1) = rem_pio2(x);
+    match n & 3 {
+        0 => k_cos(y0, y1),
+        1 => -k_sin(y0, y1, 1),
+        2 => -k_cos(y0, y1),
+        _ => k_sin(y0, y1, 1)
+    }
+}
+
+pub fn tan(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fffffff;
+    if ix <= 0x3fe921fb {
+        // |x| ~< pi/4
+        if ix < 0x3e400000 {
+            // |x| < 2^-27
+            return x
+        }
+        return k_tan(x, 0.0, 0)
+    }
+    if ix >= 0x7ff00000 {
+        return x - x
+    }
+    let (n, y0, y1) = rem_pio2(x);
+    k_tan(y0, y1, n & 1)
+}
+
+// The single precision trigonometric functions evaluate their polynomials in double precision.
+// The reduction only needs 25 + 53 bits of pi/2 for |x| ~< 2^28 * (pi/2).
+fn rem_pio2f(x: f32) -> (i32, f64) {
+    static TOINT: f64 = 6755399441055744.0;
+    static INV_PIO2: f64 = 6.36619772367581382433e-01; // 0x3FE45F30, 0x6DC9C883
+    // the first 25 bits of pi/2
+    static PIO2_1: f64 = 1.57079631090164184570e+00; // 0x3FF921FB, 0x50000000
+    // pi/2 - PIO2_1
+    static PIO2_1T: f64 = 1.58932547735281966916e-08; // 0x3E5110b4, 0x611A6263
+
+    let ix = bitsf(x) & 0x7fffffff;
+    if ix < 0x4dc90fdb {
+        // |x| ~< 2^28 * (pi/2)
+        let f_n = x as f64 * INV_PIO2 + TOINT - TOINT;
+        return (f_n as i32, x as f64 - f_n * PIO2_1 - f_n * PIO2_1T)
+    }
+    if ix >= 0x7f800000 {
+        // x is infinite or NaN
+        return (0, x as f64 - x as f64)
+    }
+    // scale |x| into [2^23, 2^24)
+    let e0 = (ix >> 23) as i32 - (0x7f + 23);
+    let tx = [from_bitsf(ix - (e0 << 23) as u32) as f64];
+    let mut ty = [0.0];
+    let n = rem_pio2_large(tx, ty, e0, 0);
+    if bitsf(x) >> 31 != 0 {
+        return (-n, -ty[0])
+    }
+    (n, ty[0])
+}
+
+// |sin(x) / x - s(x)| < 2^-37.5 on [-pi/4, pi/4].
+fn k_sinf(x: f64) -> f32 {
+    static S1: f64 = -0.166666666416265235595; // -0x15555554cbac77.0p-55
+    static S2: f64 = 0.0083333293858894631756; // 0x111110896efbb2.0p-59
+    static S3: f64 = -0.000198393348360966317347; // -0x1a00f9e2cae774.0p-65
+    static S4: f64 = 0.0000027183114939898219064; // 0x16cd878c3b46a7.0p-71
+
+    let z = x * x;
+    let w = z * z;
+    let r = S3 + z * S4;
+    let s = z * x;
+    ((x + s * (S1 + z * S2)) + s * w * r) as f32
+}
+
+// |cos(x) - c(x)| < 2^-34.1 on [-pi/4, pi/4].
+fn k_cosf(x: f64) -> f32 {
+    static C0: f64 = -0.499999997251031003120; // -0x1ffffffd0c5e81.0p-54
+    static C1: f64 = 0.0416666233237390631894; // 0x155553e1053a42.0p-57
+    static C2: f64 = -0.00138867637746099294692; // -0x16c087e80f1e27.0p-62
+    static C3: f64 = 0.0000243904487962774090654; // 0x199342e0ee5069.0p-68
+
+    let z = x * x;
+    let w = z * z;
+    let r = C2 + z * C3;
+    (((1.0 + z * C0) + w * C1) + (w * z) * r) as f32
+}
+
+// |tan(x) / x - t(x)| < 2^-25.5 on [-pi/4, pi/4], returning -1 / tan(x) if `odd` is set.
+fn k_tanf(x: f64, odd: bool) -> f32 {
+    static T: [f64, ..6] = [
+        0.333331395030791399758, // 0x15554d3418c99f.0p-54
+        0.133392002712976742718, // 0x1112fd38999f72.0p-55
+        0.0533812378445670393523, // 0x1b54c91d865afe.0p-57
+        0.0245283181166547278873, // 0x191df3908c33ce.0p-58
+        0.00297435743359967304927, // 0x185dadfcecf44e.0p-61
+        0.00946564784943673166728 // 0x1362b9bf971bcd.0p-59
+    ];
+
+    let z = x * x;
+    // split the polynomial into independent terms, adding the small ones first
+    let r = T[4] + z * T[5];
+    let t = T[2] + z * T[3];
+    let w = z * z;
+    let s = z * x;
+    let u = T[0] + z * T[1];
+    let r = (x + s * u) + (s * w) * (t + w * r);
+    (if odd { -1.0 / r } else { r }) as f32
+}
+
+// Small multiples of pi/2 rounded to double precision.
+static PIO2_X1: f64 = 1.57079632679489655800e+00; // 0x3FF921FB, 0x54442D18
+static PIO2_X2: f64 = 3.14159265358979311600e+00; // 0x400921FB, 0x54442D18
+static PIO2_X3: f64 = 4.71238898038468985769e+00; // 0x4012D97C, 0x7F3321D2
+static PIO2_X4: f64 = 6.28318530717958623200e+00; // 0x401921FB, 0x54442D18
+
+pub fn sinf(x: f32) -> f32 {
+    let x64 = x as f64;
+    let sign = bitsf(x) >> 31 != 0;
+    let ix = bitsf(x) & 0x7fffffff;
+    if ix <= 0x3f490fda {
+        // |x| ~<= pi/4
+        if ix < 0x39800000 {
+            // |x| < 2^-12
+            return x
+        }
+        return k_sinf(x64)
+    }
+    if ix <= 0x407b53d1 {
+        // |x| ~<= 5pi/4
+        if ix <= 0x4016cbe3 {
+            // |x| ~<= 3pi/4
+            return if sign { -k_cosf(x64 + PIO2_X1) } else { k_cosf(x64 - PIO2_X1) }
+        }
+        return k_sinf(if sign { -(x64 + PIO2_X2) } else { -(x64 - PIO2_X2) })
+    }
+    if ix <= 0x40e231d5 {
+        // |x| ~<= 9pi/4
+        if ix <= 0x40afeddf {
+            // |x| ~<= 7pi/4
+            return if sign { k_cosf(x64 + PIO2_X3) } else { -k_cosf(x64 - PIO2_X3) }
+        }
+        return k_sinf(if sign { x64 + PIO2_X4 } else { x64 - PIO2_X4 })
+    }
+    if ix >= 0x7f800000 {
+        return x - x
+    }
+    let (n, y) = rem_pio2f(x);
+    match n & 3 {
+        0 => k_sinf(y),
+        1 => k_cosf(y),
+        2 => k_sinf(-y),
+        _ => -k_cosf(y)
+    }
+}
+
+pub fn cosf(x: f32) -> f32 {
+    let x64 = x as f64;
+    let sign = bitsf(x) >> 31 != 0;
+    let ix = bitsf(x) & 0x7fffffff;
+    if ix <= 0x3f490fda {
+        // |x| ~<= pi/4
+        if ix < 0x39800000 {
+            // |x| < 2^-12
+            return 1.0
+        }
+        return k_cosf(x64)
+    }
+    if ix <= 0x407b53d1 {
+        // |x| ~<= 5pi/4
+        if ix > 0x4016cbe3 {
+            // |x| ~> 3pi/4
+            return -k_cosf(if sign { x64 + PIO2_X2 } else { x64 - PIO2_X2 })
+        }
+        return if sign { k_sinf(x64 + PIO2_X1) } else { k_sinf(PIO2_X1 - x64) }
+    }
+    if ix <= 0x40e231d5 {
+        // |x| ~<= 9pi/4
+        if ix > 0x40afeddf {
+            // |x| ~> 7pi/4
+            return k_cosf(if sign { x64 + PIO2_X4 } else { x64 - PIO2_X4 })
+        }
+        return if sign { k_sinf(-x64 - PIO2_X3) } else { k_sinf(x64 - PIO2_X3) }
+    }
+    if ix >= 0x7f800000 {
+        return x - x
+    }
+    let (n, y) = rem_pio2f(x);
+    match n & 3 {
+        0 => k_cosf(y),
+        1 => k_sinf(-y),
+        2 => -k_cosf(y),
+        _ => k_sinf(y)
+    }
+}
+
+pub fn tanf(x: f32) -> f32 {
+    let x64 = x as f64;
+    let sign = bitsf(x) >> 31 != 0;
+    let ix = bitsf(x) & 0x7fffffff;
+    if ix <= 0x3f490fda {
+        // |x| ~<= pi/4
+        if ix < 0x39800000 {
+            // |x| < 2^-12
+            return x
+        }
+        return k_tanf(x64, false)
+    }
+    if ix <= 0x407b53d1 {
+        // |x| ~<= 5pi/4
+        if ix <= 0x4016cbe3 {
+            // |x| ~<= 3pi/4
+            return k_tanf(if sign { x64 + PIO2_X1 } else { x64 - PIO2_X1 }, true)
+        }
+        return k_tanf(if sign { x64 + PIO2_X2 } else { x64 - PIO2_X2 }, false)
+    }
+    if ix <= 0x40e231d5 {
+        // |x| ~<= 9pi/4
+        if ix <= 0x40afeddf {
+            // |x| ~<= 7pi/4
+            return k_tanf(if sign { x64 + PIO2_X3 } else { x64 - PIO2_X3 }, true)
+        }
+        return k_tanf(if sign { x64 + PIO2_X4 } else { x64 - PIO2_X4 }, false)
+    }
+    if ix >= 0x7f800000 {
+        return x - x
+    }
+    let (n, y) = rem_pio2f(x);
+    k_tanf(y, n & 1 != 0)
+}
+
+// Inverse trigonometric functions
+
+static PIO2_HI: f64 = 1.57079632679489655800e+00; // 0x3FF921FB, 0x54442D18
+static PIO2_LO: f64 = 6.12323399573676603587e-17; // 0x3C91A626, 0x33145C07
+
+// A rational approximation of (asin(x) - x) / x^3 on [0, 0.5] as a function of z = x^2, with an
+// error below 2^-58.75.
+fn asin_r(z: f64) -> f64 {
+    static PS0: f64 = 1.66666666666666657415e-01; // 0x3FC55555, 0x55555555
+    static PS1: f64 = -3.25565818622400915405e-01; // 0xBFD4D612, 0x03EB6F7D
+    static PS2: f64 = 2.01212532134862925881e-01; // 0x3FC9C155, 0x0E884455
+    static PS3: f64 = -4.00555345006794114027e-02; // 0xBFA48228, 0xB5688F3B
+    static PS4: f64 = 7.91534994289814532176e-04; // 0x3F49EFE0, 0x7501B288
+    static PS5: f64 = 3.47933107596021167570e-05; // 0x3F023DE1, 0x0DFDF709
+    static QS1: f64 = -2.40339491173441421878e+00; // 0xC0033A27, 0x1C8A2D4B
+    static QS2: f64 = 2.02094576023350569471e+00; // 0x40002AE5, 0x9C598AC8
+    static QS3: f64 = -6.88283971605453293030e-01; // 0xBFE6066C, 0x1B8D0159
+    static QS4: f64 = 7.70381505559019352791e-02; // 0x3FB3B8C5, 0xB12E9282
+
+    let p = z * (PS0 + z * (PS1 + z * (PS2 + z * (PS3 + z * (PS4 + z * PS5)))));
+    let q = 1.0 + z * (QS1 + z * (QS2 + z * (QS3 + z * QS4)));
+    p / q
+}
+
+// asin(x) = x + x^3 * r(x^2) for |x| < 0.5. Above that,
+// asin(x) = pi/2 - 2 * asin(sqrt((1 - x) / 2)), where the square root is split in two parts to
+// keep the extra precision for |x| <= 0.975.
+pub fn asin(x: f64) -> f64 {
+    let hx = high_word(x);
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x3ff00000 {
+        // |x| >= 1 or NaN
+        if ix - 0x3ff00000 | bits(x) as u32 == 0 {
+            return x * PIO2_HI
+        }
+        return 0.0 / (x - x)
+    }
+    if ix < 0x3fe00000 {
+        // |x| < 0.5
+        if ix < 0x3e500000 {
+            // |x| < 2^-26
+            return x
+        }
+        return x + x * asin_r(x * x)
+    }
+    let z = (1.0 - fabs(x)) * 0.5;
+    let s = sqrt(z);
+    let r = asin_r(z);
+    let y = if ix >= 0x3fef3333 {
+        // |x| > 0.975
+        PIO2_HI - (2.0 * (s + s * r) - PIO2_LO)
+    } else {
+        // f + c = sqrt(z)
+        let f = with_low_word_zero(s);
+        let c = (z - f * f) / (s + f);
+        0.5 * PIO2_HI - (2.0 * s * r - (PIO2_LO - 2.0 * c) - (0.5 * PIO2_HI - 2.0 * f))
+    };
+    if hx >> 31 != 0 { -y } else { y }
+}
+
+// acos(x) = pi/2 - asin(x) for |x| < 0.5, acos(x) = 2 * asin(sqrt((1 - x) / 2)) for x > 0.5 and
+// acos(x) = pi - 2 * asin(sqrt((1 + x) / 2)) for x < -0.5.
+pub fn acos(x: f64) -> f64 {
+    let hx = high_word(x);
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x3ff00000 {
+        // |x| >= 1 or NaN
+        if ix - 0x3ff00000 | bits(x) as u32 == 0 {
+            return if hx >> 31 != 0 { 2.0 * PIO2_HI } else { 0.0 }
+        }
+        return 0.0 / (x - x)
+    }
+    if ix < 0x3fe00000 {
+        // |x| < 0.5
+        if ix <= 0x3c600000 {
+            // |x| < 2^-57
+            return PIO2_HI
+        }
+        return PIO2_HI - (x - (PIO2_LO - x * asin_r(x * x)))
+    }
+    if hx >> 31 != 0 {
+        let z = (1.0 + x) * 0.5;
+        let s = sqrt(z);
+        let w = asin_r(z) * s - PIO2_LO;
+        return 2.0 * (PIO2_HI - (s + w))
+    }
+    let z = (1.0 - x) * 0.5;
+    let s = sqrt(z);
+    let df = with_low_word_zero(s);
+    let c = (z - df * df) / (s + df);
+    let w = asin_r(z) * s + c;
+    2.0 * (df + w)
+}
+
+// atan(x) is reduced to an argument in [0, 7/16] by subtracting one of atan(0.5), atan(1),
+// atan(1.5) or atan(inf) = pi/2, and then approximated by an odd polynomial of degree 23.
+pub fn atan(x: f64) -> f64 {
+    static ATANHI: [f64, ..4] = [
+        4.63647609000806093515e-01, // atan(0.5)hi 0x3FDDAC67, 0x0561BB4F
+        7.85398163397448278999e-01, // atan(1.0)hi 0x3FE921FB, 0x54442D18
+        9.82793723247329054082e-01, // atan(1.5)hi 0x3FEF730B, 0xD281F69B
+        1.57079632679489655800e+00 // atan(inf)hi 0x3FF921FB, 0x54442D18
+    ];
+    static ATANLO: [f64, ..4] = [
+        2.26987774529616870924e-17, // atan(0.5)lo 0x3C7A2B7F, 0x222F65E2
+        3.06161699786838301793e-17, // atan(1.0)lo 0x3C81A626, 0x33145C07
+        1.39033110312309984516e-17, // atan(1.5)lo 0x3C700788, 0x7AF0CBBD
+        6.12323399573676603587e-17 // atan(inf)lo 0x3C91A626, 0x33145C07
+    ];
+    static AT: [f64, ..11] = [
+        3.33333333333329318027e-01, // 0x3FD55555, 0x5555550D
+        -1.99999999998764832476e-01, // 0xBFC99999, 0x9998EBC4
+        1.42857142725034663711e-01, // 0x3FC24924, 0x920083FF
+        -1.11111104054623557880e-01, // 0xBFBC71C6, 0xFE231671
+        9.09088713343650656196e-02, // 0x3FB745CD, 0xC54C206E
+        -7.69187620504482999495e-02, // 0xBFB3B0F2, 0xAF749A6D
+        6.66107313738753120669e-02, // 0x3FB10D66, 0xA0D03D51
+        -5.83357013379057348645e-02, // 0xBFADDE2D, 0x52DEFD9A
+        4.97687799461593236017e-02, // 0x3FA97B4B, 0x24760DEB
+        -3.65315727442169155270e-02, // 0xBFA2B444, 0x2C6A6C2F
+        1.62858201153657823623e-02 // 0x3F90AD3A, 0xE322DA11
+    ];
+
+    let hx = high_word(x);
+    let sign = hx >> 31 != 0;
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x44100000 {
+        // |x| >= 2^66 or NaN
+        if is_nan(x) {
+            return x
+        }
+        return if sign { -ATANHI[3] } else { ATANHI[3] }
+    }
+    let mut x = x;
+    let id = if ix < 0x3fdc0000 {
+        // |x| < 0.4375
+        if ix < 0x3e400000 {
+            // |x| < 2^-27
+            return x
+        }
+        -1
+    } else {
+        x = fabs(x);
+        if ix < 0x3ff30000 {
+            // |x| < 1.1875
+            if ix < 0x3fe60000 {
+                // 7/16 <= |x| < 11/16
+                x = (2.0 * x - 1.0) / (2.0 + x);
+                0
+            } else {
+                // 11/16 <= |x| < 19/16
+                x = (x - 1.0) / (x + 1.0);
+                1
+            }
+        } else if ix < 0x40038000 {
+            // |x| < 2.4375
+            x = (x - 1.5) / (1.0 + 1.5 * x);
+            2
+        } else {
+            // 2.4375 <= |x| < 2^66
+            x = -1.0 / x;
+            3
+        }
+    };
+    let z = x * x;
+    let w = z * z;
+    // break the sum of AT[i] * z^(i + 1) into odd and even polynomials
+    let s1 = z * (AT[0] + w * (AT[2] + w * (AT[4] + w * (AT[6] + w * (AT[8] + w * AT[10])))));
+    let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
+    if id < 0 {
+        return x - x * (s1 + s2)
+    }
+    let id = id as uint;
+    let z = ATANHI[id] - (x * (s1 + s2) - ATANLO[id] - x);
+    if sign { -z } else { z }
+}
+
+pub fn atan2(y: f64, x: f64) -> f64 {
+    static PI: f64 = 3.1415926535897931160e+00; // 0x400921FB, 0x54442D18
+    static PI_LO: f64 = 1.2246467991473531772e-16; // 0x3CA1A626, 0x33145C07
+
+    if is_nan(x) || is_nan(y) {
+        return x + y
+    }
+    let ix = high_word(x);
+    let lx = bits(x) as u32;
+    let iy = high_word(y);
+    let ly = bits(y) as u32;
+    if ix - 0x3ff00000 | lx == 0 {
+        // x = 1
+        return atan(y)
+    }
+    // 2 * sign(x) + sign(y)
+    let m = iy >> 31 & 1 | ix >> 30 & 2;
+    let ix = ix & 0x7fffffff;
+    let iy = iy & 0x7fffffff;
+
+    if iy | ly == 0 {
+        return match m {
+            0 | 1 => y,
+            2 => PI,
+            _ => -PI
+        }
+    }
+    if ix | lx == 0 {
+        return if m & 1 != 0 { -PI / 2.0 } else { PI / 2.0 }
+    }
+    if ix == 0x7ff00000 {
+        // x is infinite
+        if iy == 0x7ff00000 {
+            return match m {
+                0 => PI / 4.0,
+                1 => -PI / 4.0,
+                2 => 3.0 * PI / 4.0,
+                _ => -3.0 * PI / 4.0
+            }
+        }
+        return match m {
+            0 => 0.0,
+            1 => -0.0,
+            2 => PI,
+            _ => -PI
+        }
+    }
+    if ix + (64 << 20) < iy || iy == 0x7ff00000 {
+        // |y / x| > 2^64
+        return if m & 1 != 0 { -PI / 2.0 } else { PI / 2.0 }
+    }
+    let z = if m & 2 != 0 && iy + (64 << 20) < ix {
+        // |y / x| < 2^-64 and x < 0
+        0.0
+    } else {
+        atan(fabs(y / x))
+    };
+    match m {
+        0 => z,
+        1 => -z,
+        2 => PI - (z - PI_LO),
+        _ => (z - PI_LO) - PI
+    }
+}
+
+static PIO2_HIF: f32 = 1.5707962513e+00; // 0x3fc90fda
+static PIO2_LOF: f32 = 7.5497894159e-08; // 0x33a22168
+
+// The same as `asin_r` on [0, 0.5] with an error below 2^-24.
+fn asin_rf(z: f32) -> f32 {
+    static PS0: f32 = 1.6666586697e-01;
+    static PS1: f32 = -4.2743422091e-02;
+    static PS2: f32 = -8.6563630030e-03;
+    static QS1: f32 = -7.0662963390e-01;
+
+    let p = z * (PS0 + z * (PS1 + z * PS2));
+    let q = 1.0 + z * QS1;
+    p / q
+}
+
+pub fn asinf(x: f32) -> f32 {
+    static PIO2: f64 = 1.570796326794896558e+00;
+
+    let hx = bitsf(x);
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x3f800000 {
+        // |x| >= 1 or NaN
+        if ix == 0x3f800000 {
+            return (x as f64 * PIO2) as f32
+        }
+        return 0.0 / (x - x)
+    }
+    if ix < 0x3f000000 {
+        // |x| < 0.5
+        if ix < 0x39800000 {
+            // |x| < 2^-12
+            return x
+        }
+        return x + x * asin_rf(x * x)
+    }
+    let z = (1.0 - fabsf(x)) * 0.5;
+    let s = sqrt(z as f64);
+    let y = (PIO2 - 2.0 * (s + s * asin_rf(z) as f64)) as f32;
+    if hx >> 31 != 0 { -y } else { y }
+}
+
+pub fn acosf(x: f32) -> f32 {
+    let hx = bitsf(x);
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x3f800000 {
+        // |x| >= 1 or NaN
+        if ix == 0x3f800000 {
+            return if hx >> 31 != 0 { 2.0 * PIO2_HIF } else { 0.0 }
+        }
+        return 0.0 / (x - x)
+    }
+    if ix < 0x3f000000 {
+        // |x| < 0.5
+        if ix <= 0x32800000 {
+            // |x| < 2^-26
+            return PIO2_HIF
+        }
+        return PIO2_HIF - (x - (PIO2_LOF - x * asin_rf(x * x)))
+    }
+    if hx >> 31 != 0 {
+        let z = (1.0 + x) * 0.5;
+        let s = sqrtf(z);
+        let w = asin_rf(z) * s - PIO2_LOF;
+        return 2.0 * (PIO2_HIF - (s + w))
+    }
+    let z = (1.0 - x) * 0.5;
+    let s = sqrtf(z);
+    let df = from_bitsf(bitsf(s) & 0xfffff000);
+    let c = (z - df * df) / (s + df);
+    let w = asin_rf(z) * s + c;
+    2.0 * (df + w)
+}
+
+pub fn atanf(x: f32) -> f32 {
+    static ATANHI: [f32, ..4] = [
+        4.6364760399e-01, // atan(0.5)hi 0x3eed6338
+        7.8539812565e-01, // atan(1.0)hi 0x3f490fda
+        9.8279368877e-01, // atan(1.5)hi 0x3f7b985e
+        1.5707962513e+00 // atan(inf)hi 0x3fc90fda
+    ];
+    static ATANLO: [f32, ..4] = [
+        5.0121582440e-09, // atan(0.5)lo 0x31ac3769
+        3.7748947079e-08, // atan(1.0)lo 0x33222168
+        3.4473217170e-08, // atan(1.5)lo 0x33140fb4
+        7.5497894159e-08 // atan(inf)lo 0x33a22168
+    ];
+    static AT: [f32, ..5] = [
+        3.3333328366e-01, -1.9999158382e-01, 1.4253635705e-01, -1.0648017377e-01,
+        6.1687607318e-02
+    ];
+
+    let hx = bitsf(x);
+    let sign = hx >> 31 != 0;
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x4c800000 {
+        // |x| >= 2^26 or NaN
+        if is_nanf(x) {
+            return x
+        }
+        return if sign { -ATANHI[3] } else { ATANHI[3] }
+    }
+    let mut x = x;
+    let id = if ix < 0x3ee00000 {
+        // |x| < 0.4375
+        if ix < 0x39800000 {
+            // |x| < 2^-12
+            return x
+        }
+        -1
+    } else {
+        x = fabsf(x);
+        if ix < 0x3f980000 {
+            // |x| < 1.1875
+            if ix < 0x3f300000 {
+                // 7/16 <= |x| < 11/16
+                x = (2.0 * x - 1.0) / (2.0 + x);
+                0
+            } else {
+                // 11/16 <= |x| < 19/16
+                x = (x - 1.0) / (x + 1.0);
+                1
+            }
+        } else if ix < 0x401c0000 {
+            // |x| < 2.4375
+            x = (x - 1.5) / (1.0 + 1.5 * x);
+            2
+        } else {
+            // 2.4375 <= |x| < 2^26
+            x = -1.0 / x;
+            3
+        }
+    };
+    let z = x * x;
+    let w = z * z;
+    let s1 = z * (AT[0] + w * (AT[2] + w * AT[4]));
+    let s2 = w * (AT[1] + w * AT[3]);
+    if id < 0 {
+        return x - x * (s1 + s2)
+    }
+    let id = id as uint;
+    let z = ATANHI[id] - ((x * (s1 + s2) - ATANLO[id]) - x);
+    if sign { -z } else { z }
+}
+
+pub fn atan2f(y: f32, x: f32) -> f32 {
+    static PI: f32 = 3.1415927410e+00; // 0x40490fdb
+    static PI_LO: f32 = -8.7422776573e-08; // 0xb3bbbd2e
+
+    if is_nanf(x) || is_nanf(y) {
+        return x + y
+    }
+    let ix = bitsf(x);
+    let iy = bitsf(y);
+    if ix == 0x3f800000 {
+        // x = 1
+        return atanf(y)
+    }
+    // 2 * sign(x) + sign(y)
+    let m = iy >> 31 & 1 | ix >> 30 & 2;
+    let ix = ix & 0x7fffffff;
+    let iy = iy & 0x7fffffff;
+
+    if iy == 0 {
+        return match m {
+            0 | 1 => y,
+            2 => PI,
+            _ => -PI
+        }
+    }
+    if ix == 0 {
+        return if m & 1 != 0 { -PI / 2.0 } else { PI / 2.0 }
+    }
+    if ix == 0x7f800000 {
+        // x is infinite
+        if iy == 0x7f800000 {
+            return match m {
+                0 => PI / 4.0,
+                1 => -PI / 4.0,
+                2 => 3.0 * PI / 4.0,
+                _ => -3.0 * PI / 4.0
+            }
+        }
+        return match m {
+            0 => 0.0,
+            1 => -0.0,
+            2 => PI,
+            _ => -PI
+        }
+    }
+    if ix + (26 << 23) < iy || iy == 0x7f800000 {
+        // |y / x| > 2^26
+        return if m & 1 != 0 { -PI / 2.0 } else { PI / 2.0 }
+    }
+    let z = if m & 2 != 0 && iy + (26 << 23) < ix {
+        // |y / x| < 2^-26 and x < 0
+        0.0
+    } else {
+        atanf(fabsf(y / x))
+    };
+    match m {
+        0 => z,
+        1 => -z,
+        2 => PI - (z - PI_LO),
+        _ => (z - PI_LO) - PI
+    }
+}
+
+// Exponential functions
+
+static LN2_HI: f64 = 6.93147180369123816490e-01; // 0x3fe62e42, 0xfee00000
+static LN2_LO: f64 = 1.90821492927058770002e-10; // 0x3dea39ef, 0x35793c76
+static INV_LN2: f64 = 1.44269504088896338700e+00; // 0x3ff71547, 0x652b82fe
+
+// x is reduced to r = x - k * ln2 with |r| <= ln2 / 2, so that exp(x) = 2^k * exp(r). With
+// R(r^2) a polynomial of degree 5 approximating r * (exp(r) + 1) / (exp(r) - 1) within 2^-59 on
+// that interval, exp(r) = 1 + 2r / (R - r) = 1 + r + r * c / (2 - c) where c = r - r^2 * R1(r^2)
+// and R1 = (R - 2) / r^2. r is kept as hi - lo for the extra precision.
+pub fn exp(x: f64) -> f64 {
+    static P1: f64 = 1.66666666666666019037e-01; // 0x3FC55555, 0x5555553E
+    static P2: f64 = -2.77777777770155933842e-03; // 0xBF66C16C, 0x16BEBD93
+    static P3: f64 = 6.61375632143793436117e-05; // 0x3F11566A, 0xAF25DE2C
+    static P4: f64 = -1.65339022054652515390e-06; // 0xBEBBBD41, 0xC5D26BF1
+    static P5: f64 = 4.13813679705723846039e-08; // 0x3E663769, 0x72BEA4D0
+
+    let hx = high_word(x);
+    let sign = hx >> 31 != 0;
+    let hx = hx & 0x7fffffff;
+    if hx >= 0x4086232b {
+        // |x| >= 708.39 or NaN
+        if is_nan(x) {
+            return x
+        }
+        if x > 709.782712893383973096 {
+            // overflow, unless x is infinite
+            return x * from_bits(0x7fe0000000000000)
+        }
+        if x < -745.13321910194110842 {
+            return 0.0
+        }
+    }
+
+    let mut x = x;
+    let k;
+    let hi;
+    let lo;
+    if hx > 0x3fd62e42 {
+        // |x| > ln2 / 2
+        k = if hx >= 0x3ff0a2b2 {
+            // |x| >= 1.5 ln2
+            (INV_LN2 * x + if sign { -0.5 } else { 0.5 }) as i32
+        } else if sign {
+            -1
+        } else {
+            1
+        };
+        // k * LN2_HI is exact here
+        hi = x - k as f64 * LN2_HI;
+        lo = k as f64 * LN2_LO;
+        x = hi - lo;
+    } else if hx > 0x3e300000 {
+        // |x| > 2^-28
+        k = 0;
+        hi = x;
+        lo = 0.0;
+    } else {
+        return 1.0 + x
+    }
+
+    let xx = x * x;
+    let c = x - xx * (P1 + xx * (P2 + xx * (P3 + xx * (P4 + xx * P5))));
+    let y = 1.0 + (x * c / (2.0 - c) - lo + hi);
+    if k == 0 { y } else { scalbn(y, k) }
+}
+
+// The same method with a polynomial of degree 2, for an error below 2^-27.74.
+pub fn expf(x: f32) -> f32 {
+    static LN2_HIF: f32 = 6.9314575195e-01; // 0x3f317200
+    static LN2_LOF: f32 = 1.4286067653e-06; // 0x35bfbe8e
+    static INV_LN2F: f32 = 1.4426950216e+00; // 0x3fb8aa3b
+    static P1: f32 = 1.6666625440e-1; // 0xaaaa8f.0p-26
+    static P2: f32 = -2.7667332906e-3; // -0xb55215.0p-32
+
+    let hx = bitsf(x);
+    let sign = hx >> 31 != 0;
+    let hx = hx & 0x7fffffff;
+    if hx >= 0x42aeac50 {
+        // |x| >= 87.33655 or NaN
+        if hx > 0x7f800000 {
+            return x
+        }
+        if hx >= 0x42b17218 && !sign {
+            // x >= 88.722839 overflows
+            return x * from_bitsf(0x7f000000)
+        }
+        if sign && hx >= 0x42cff1b5 {
+            // x <= -103.972084 underflows
+            return 0.0
+        }
+    }
+
+    let mut x = x;
+    let k;
+    let hi;
+    let lo;
+    if hx > 0x3eb17218 {
+        // |x| > ln2 / 2
+        k = if hx > 0x3f851592 {
+            // |x| > 1.5 ln2
+            (INV_LN2F * x + if sign { -0.5 } else { 0.5 }) as i32
+        } else if sign {
+            -1
+        } else {
+            1
+        };
+        let kf = k as f32;
+        hi = x - kf * LN2_HIF;
+        lo = kf * LN2_LOF;
+        x = hi - lo;
+    } else if hx > 0x39000000 {
+        // |x| > 2^-14
+        k = 0;
+        hi = x;
+        lo = 0.0;
+    } else {
+        return 1.0 + x
+    }
+
+    let xx = x * x;
+    let c = x - xx * (P1 + xx * P2);
+    let y = 1.0 + (x * c / (2.0 - c) - lo + hi);
+    if k == 0 { y } else { scalbnf(y, k) }
+}
+
+// Interleaved pairs of exp2(i / 256 + eps) and eps for i from -128 to 127, where each eps is a
+// small offset chosen so that the first value is accurate to 2^-64.
+static EXP2_TBL: [u64, ..512] = [
+    0x3fe6a09e667f3d5d, 0x3d39880000000000,
+    0x3fe6b052fa751744, 0x3cd8000000000000,
+    0x3fe6c012750bd9fe, 0xbd28780000000000,
+    0x3fe6cfdcddd476bf, 0x3d1ec00000000000,
+    0x3fe6dfb23c651a29, 0xbcd8000000000000,
+    0x3fe6ef9298593ae3, 0xbcbc000000000000,
+    0x3fe6ff7df9519386, 0xbd2fd80000000000,
+    0x3fe70f7466f42da3, 0xbd2c880000000000,
+    0x3fe71f75e8ec5fc3, 0x3d13c00000000000,
+    0x3fe72f8286eacf05, 0xbd38300000000000,
+    0x3fe73f9a48a58152, 0xbd00c00000000000,
+    0x3fe74fbd35d7ccfc, 0x3d2f880000000000,
+    0x3fe75feb564267f1, 0x3d03e00000000000,
+    0x3fe77024b1ab6d48, 0xbd27d00000000000,
+    0x3fe780694fde5d38, 0xbcdd000000000000,
+    0x3fe790b938ac1d00, 0x3ce3000000000000,
+    0x3fe7a11473eb0178, 0xbced000000000000,
+    0x3fe7b17b0976d060, 0x3d20400000000000,
+    0x3fe7c1ed0130c133, 0x3ca0000000000000,
+    0x3fe7d26a62ff8636, 0xbd26900000000000,
+    0x3fe7e2f336cf4e3b, 0xbd02e00000000000,
+    0x3fe7f3878491c3e8, 0xbd24580000000000,
+    0x3fe80427543e1b4e, 0x3d33000000000000,
+    0x3fe814d2add1071a, 0x3d0f000000000000,
+    0x3fe82589994ccd7e, 0xbd21c00000000000,
+    0x3fe8364c1eb942d0, 0x3d29d00000000000,
+    0x3fe8471a4623cab5, 0x3d47100000000000,
+    0x3fe857f4179f5bbc, 0x3d22600000000000,
+    0x3fe868d99b4491af, 0xbd32c40000000000,
+    0x3fe879cad931a395, 0xbd23000000000000,
+    0x3fe88ac7d98a65b8, 0xbd2a800000000000,
+    0x3fe89bd0a4785800, 0xbced000000000000,
+    0x3fe8ace5422aa223, 0x3d33280000000000,
+    0x3fe8be05bad619fa, 0x3d42b40000000000,
+    0x3fe8cf3216b54383, 0xbd2ed00000000000,
+    0x3fe8e06a5e08664c, 0xbd20500000000000,
+    0x3fe8f1ae99157807, 0x3d28280000000000,
+    0x3fe902fed0282c0e, 0xbd1cb00000000000,
+    0x3fe9145b0b91ff96, 0xbd05e00000000000,
+    0x3fe925c353aa2ff9, 0x3cf5400000000000,
+    0x3fe93737b0cdc64a, 0x3d17200000000000,
+    0x3fe948b82b5f98ae, 0xbd09000000000000,
+    0x3fe95a44cbc852cb, 0x3d25680000000000,
+    0x3fe96bdd9a766f21, 0xbd36d00000000000,
+    0x3fe97d829fde4e2a, 0xbd01000000000000,
+    0x3fe98f33e47a23a3, 0x3d2d000000000000,
+    0x3fe9a0f170ca0604, 0xbd38a40000000000,
+    0x3fe9b2bb4d53ff89, 0x3d355c0000000000,
+    0x3fe9c49182a3f15b, 0x3d26b80000000000,
+    0x3fe9d674194bb8c5, 0xbcec000000000000,
+    0x3fe9e86319e3238e, 0x3d17d00000000000,
+    0x3fe9fa5e8d07f302, 0x3d16400000000000,
+    0x3fea0c667b5de54d, 0xbcf5000000000000,
+    0x3fea1e7aed8eb8f6, 0x3d09e00000000000,
+    0x3fea309bec4a2e27, 0x3d2ad80000000000,
+    0x3fea42c980460a5d, 0xbd1af00000000000,
+    0x3fea5503b23e259b, 0x3d0b600000000000,
+    0x3fea674a8af46213, 0x3d38880000000000,
+    0x3fea799e1330b3a7, 0x3d11200000000000,
+    0x3fea8bfe53c12e8d, 0x3d06c00000000000,
+    0x3fea9e6b5579fcd2, 0xbd29b80000000000,
+    0x3feab0e521356fb8, 0x3d2b700000000000,
+    0x3feac36bbfd3f381, 0x3cd9000000000000,
+    0x3fead5ff3a3c2780, 0x3ce4000000000000,
+    0x3feae89f995ad2a3, 0xbd2c900000000000,
+    0x3feafb4ce622f367, 0x3d16500000000000,
+    0x3feb0e07298db790, 0x3d2fd40000000000,
+    0x3feb20ce6c9a89a9, 0x3d12700000000000,
+    0x3feb33a2b84f1a4b, 0x3d4d470000000000,
+    0x3feb468415b747e7, 0xbd38380000000000,
+    0x3feb59728de5593a, 0x3c98000000000000,
+    0x3feb6c6e29f1c56a, 0x3d0ad00000000000,
+    0x3feb7f76f2fb5e50, 0x3cde800000000000,
+    0x3feb928cf22749b2, 0xbd04c00000000000,
+    0x3feba5b030a10603, 0xbd0d700000000000,
+    0x3febb8e0b79a6f66, 0x3d0d900000000000,
+    0x3febcc1e904bc1ff, 0x3d02a00000000000,
+    0x3febdf69c3f3a16f, 0xbd1f780000000000,
+    0x3febf2c25bd71db8, 0xbd10a00000000000,
+    0x3fec06286141b2e9, 0xbd11400000000000,
+    0x3fec199bdd8552e0, 0x3d0be00000000000,
+    0x3fec2d1cd9fa64ee, 0xbd09400000000000,
+    0x3fec40ab5fffd02f, 0xbd0ed00000000000,
+    0x3fec544778fafd15, 0x3d39660000000000,
+    0x3fec67f12e57d0cb, 0xbd1a100000000000,
+    0x3fec7ba88988c1b6, 0xbd58458000000000,
+    0x3fec8f6d9406e733, 0xbd1a480000000000,
+    0x3feca3405751c4df, 0x3ccb000000000000,
+    0x3fecb720dcef9094, 0x3d01400000000000,
+    0x3feccb0f2e6d1689, 0x3cf0200000000000,
+    0x3fecdf0b555dc412, 0x3cf3600000000000,
+    0x3fecf3155b5bab3b, 0xbd06900000000000,
+    0x3fed072d4a0789bc, 0x3d09a00000000000,
+    0x3fed1b532b08c8fa, 0xbd15e00000000000,
+    0x3fed2f87080d8a85, 0x3d1d280000000000,
+    0x3fed43c8eacaa203, 0x3d01a00000000000,
+    0x3fed5818dcfba491, 0x3cdf000000000000,
+    0x3fed6c76e862e6a1, 0xbd03a00000000000,
+    0x3fed80e316c9834e, 0xbd0cd80000000000,
+    0x3fed955d71ff6090, 0x3cf4c00000000000,
+    0x3feda9e603db32ae, 0x3cff900000000000,
+    0x3fedbe7cd63a8325, 0x3ce9800000000000,
+    0x3fedd321f301b445, 0xbcf5200000000000,
+    0x3fede7d5641c05bf, 0xbd1d700000000000,
+    0x3fedfc97337b9aec, 0xbd16140000000000,
+    0x3fee11676b197d5e, 0x3d0b480000000000,
+    0x3fee264614f5a3e7, 0x3d40ce0000000000,
+    0x3fee3b333b16ee5c, 0x3d0c680000000000,
+    0x3fee502ee78b3fb4, 0xbd09300000000000,
+    0x3fee653924676d68, 0xbce5000000000000,
+    0x3fee7a51fbc74c44, 0xbd07f80000000000,
+    0x3fee8f7977cdb726, 0xbcf3700000000000,
+    0x3feea4afa2a490e8, 0x3ce5d00000000000,
+    0x3feeb9f4867ccae4, 0x3d161a0000000000,
+    0x3feecf482d8e680d, 0x3cf5500000000000,
+    0x3feee4aaa2188514, 0x3cc6400000000000,
+    0x3feefa1bee615a13, 0xbcee800000000000,
+    0x3fef0f9c1cb64106, 0xbcfa880000000000,
+    0x3fef252b376bb963, 0xbd2c900000000000,
+    0x3fef3ac948dd7275, 0x3caa000000000000,
+    0x3fef50765b6e4524, 0xbcf4f00000000000,
+    0x3fef6632798844fd, 0x3cca800000000000,
+    0x3fef7bfdad9cbe38, 0x3cfabc0000000000,
+    0x3fef91d802243c82, 0xbcd4600000000000,
+    0x3fefa7c1819e908e, 0xbd0b0c0000000000,
+    0x3fefbdba3692d511, 0xbcc0e00000000000,
+    0x3fefd3c22b8f7194, 0xbd10de8000000000,
+    0x3fefe9d96b2a23ee, 0x3cee430000000000,
+    0x3ff0000000000000, 0x0000000000000000,
+    0x3ff00b1afa5abcbe, 0xbcb3400000000000,
+    0x3ff0163da9fb3303, 0xbd12170000000000,
+    0x3ff02168143b0282, 0x3cba400000000000,
+    0x3ff02c9a3e77806c, 0x3cef980000000000,
+    0x3ff037d42e11bbca, 0xbcc7400000000000,
+    0x3ff04315e86e7f89, 0x3cd8300000000000,
+    0x3ff04e5f72f65467, 0xbd1a3f0000000000,
+    0x3ff059b0d315855a, 0xbd02840000000000,
+    0x3ff0650a0e3c1f95, 0x3cf1600000000000,
+    0x3ff0706b29ddf71a, 0x3d15240000000000,
+    0x3ff07bd42b72a82d, 0xbce9a00000000000,
+    0x3ff0874518759bd0, 0x3ce6400000000000,
+    0x3ff092bdf66607c8, 0xbd00780000000000,
+    0x3ff09e3ecac6f383, 0xbc98000000000000,
+    0x3ff0a9c79b1f3930, 0x3cffa00000000000,
+    0x3ff0b5586cf988fc, 0xbcfac80000000000,
+    0x3ff0c0f145e46c8a, 0x3cd9c00000000000,
+    0x3ff0cc922b724816, 0x3d05200000000000,
+    0x3ff0d83b23395dd8, 0xbcfad00000000000,
+    0x3ff0e3ec32d3d1f3, 0x3d1bac0000000000,
+    0x3ff0efa55fdfa9a6, 0xbd04e80000000000,
+    0x3ff0fb66affed2f0, 0xbd0d300000000000,
+    0x3ff1073028d7234b, 0x3cf1500000000000,
+    0x3ff11301d0125b5b, 0x3cec000000000000,
+    0x3ff11edbab5e2af9, 0x3d16bc0000000000,
+    0x3ff12abdc06c31d5, 0x3ce8400000000000,
+    0x3ff136a814f2047d, 0xbd0ed00000000000,
+    0x3ff1429aaea92de9, 0x3ce8e00000000000,
+    0x3ff14e95934f3138, 0x3ceb400000000000,
+    0x3ff15a98c8a58e71, 0x3d05300000000000,
+    0x3ff166a45471c3df, 0x3d03380000000000,
+    0x3ff172b83c7d5211, 0x3d28d40000000000,
+    0x3ff17ed48695bb9f, 0xbd05d00000000000,
+    0x3ff18af9388c8d93, 0xbd1c880000000000,
+    0x3ff1972658375d66, 0x3d11f00000000000,
+    0x3ff1a35beb6fcba7, 0x3d10480000000000,
+    0x3ff1af99f81387e3, 0xbd47390000000000,
+    0x3ff1bbe084045d54, 0x3d24e40000000000,
+    0x3ff1c82f95281c43, 0xbd0a200000000000,
+    0x3ff1d4873168b9b2, 0x3ce3800000000000,
+    0x3ff1e0e75eb44031, 0x3ceac00000000000,
+    0x3ff1ed5022fcd938, 0x3d01900000000000,
+    0x3ff1f9c18438cdf7, 0xbd1b780000000000,
+    0x3ff2063b88628d8f, 0x3d2d940000000000,
+    0x3ff212be3578a81e, 0x3cd8000000000000,
+    0x3ff21f49917ddd41, 0x3d2b340000000000,
+    0x3ff22bdda2791323, 0x3d19f80000000000,
+    0x3ff2387a6e7561e7, 0xbd19c80000000000,
+    0x3ff2451ffb821427, 0x3d02300000000000,
+    0x3ff251ce4fb2a602, 0xbd13480000000000,
+    0x3ff25e85711eceb0, 0x3d12700000000000,
+    0x3ff26b4565e27d16, 0x3d11d00000000000,
+    0x3ff2780e341de00f, 0x3d31ee0000000000,
+    0x3ff284dfe1f5633e, 0xbd14c00000000000,
+    0x3ff291ba7591bb30, 0xbd13d80000000000,
+    0x3ff29e9df51fdf09, 0x3d08b00000000000,
+    0x3ff2ab8a66d10e9b, 0xbd227c0000000000,
+    0x3ff2b87fd0dada3a, 0x3d2a340000000000,
+    0x3ff2c57e39771af9, 0xbd10800000000000,
+    0x3ff2d285a6e402d9, 0xbd0ed00000000000,
+    0x3ff2df961f641579, 0xbcf4200000000000,
+    0x3ff2ecafa93e2ecf, 0xbd24980000000000,
+    0x3ff2f9d24abd8822, 0xbd16300000000000,
+    0x3ff306fe0a31b625, 0xbd32360000000000,
+    0x3ff31432edeea50b, 0xbd70df8000000000,
+    0x3ff32170fc4cd7b8, 0xbd22480000000000,
+    0x3ff32eb83ba8e9a2, 0xbd25980000000000,
+    0x3ff33c08b2641766, 0x3d1ed00000000000,
+    0x3ff3496266e3fa27, 0xbcdc000000000000,
+    0x3ff356c55f929f0f, 0xbd30d80000000000,
+    0x3ff36431a2de88b9, 0x3d22c80000000000,
+    0x3ff371a7373aaa39, 0x3d20600000000000,
+    0x3ff37f26231e74fe, 0xbd16600000000000,
+    0x3ff38cae6d05d838, 0xbd0ae00000000000,
+    0x3ff39a401b713ec3, 0xbd44720000000000,
+    0x3ff3a7db34e5a020, 0x3d08200000000000,
+    0x3ff3b57fbfec6e95, 0x3d3e800000000000,
+    0x3ff3c32dc313a8f2, 0x3cef800000000000,
+    0x3ff3d0e544ede122, 0xbd17a00000000000,
+    0x3ff3dea64c1234bb, 0x3d26300000000000,
+    0x3ff3ec70df1c4ecc, 0xbd48a60000000000,
+    0x3ff3fa4504ac7e8c, 0xbd3cdc0000000000,
+    0x3ff40822c367a0bb, 0x3d25b80000000000,
+    0x3ff4160a21f72e95, 0x3d1ec00000000000,
+    0x3ff423fb27094646, 0xbd13600000000000,
+    0x3ff431f5d950a920, 0x3d23980000000000,
+    0x3ff43ffa3f84b9eb, 0x3cfa000000000000,
+    0x3ff44e0860618919, 0xbcf6c00000000000,
+    0x3ff45c2042a7d201, 0xbd0bc00000000000,
+    0x3ff46a41ed1d0016, 0xbd12800000000000,
+    0x3ff4786d668b3326, 0x3d30e00000000000,
+    0x3ff486a2b5c13c00, 0xbd2d400000000000,
+    0x3ff494e1e192af04, 0x3d0c200000000000,
+    0x3ff4a32af0d7d372, 0xbd1e500000000000,
+    0x3ff4b17dea6db801, 0x3d07800000000000,
+    0x3ff4bfdad53629e1, 0xbd13800000000000,
+    0x3ff4ce41b817c132, 0x3d00800000000000,
+    0x3ff4dcb299fddddb, 0x3d2c700000000000,
+    0x3ff4eb2d81d8ab96, 0xbd1ce00000000000,
+    0x3ff4f9b2769d2d02, 0x3d19200000000000,
+    0x3ff508417f4531c1, 0xbd08c00000000000,
+    0x3ff516daa2cf662a, 0xbcfa000000000000,
+    0x3ff5257de83f51ea, 0x3d4a080000000000,
+    0x3ff5342b569d4eda, 0xbd26d80000000000,
+    0x3ff542e2f4f6ac1a, 0xbd32440000000000,
+    0x3ff551a4ca5d94db, 0x3d483c0000000000,
+    0x3ff56070dde9116b, 0x3d24b00000000000,
+    0x3ff56f4736b529de, 0x3d415a0000000000,
+    0x3ff57e27dbe2c40e, 0xbd29e00000000000,
+    0x3ff58d12d497c76f, 0xbd23080000000000,
+    0x3ff59c0827ff0b4c, 0x3d4dec0000000000,
+    0x3ff5ab07dd485427, 0xbcc4000000000000,
+    0x3ff5ba11fba87af4, 0x3d30080000000000,
+    0x3ff5c9268a59460b, 0xbd26c80000000000,
+    0x3ff5d84590998e3f, 0x3d469a0000000000,
+    0x3ff5e76f15ad20e1, 0xbd1b400000000000,
+    0x3ff5f6a320dcebca, 0x3d17700000000000,
+    0x3ff605e1b976dcb8, 0x3d26f80000000000,
+    0x3ff6152ae6cdf715, 0x3d01000000000000,
+    0x3ff6247eb03a5531, 0xbd15d00000000000,
+    0x3ff633dd1d1929b5, 0xbd12d00000000000,
+    0x3ff6434634ccc313, 0xbcea800000000000,
+    0x3ff652b9febc8efa, 0xbd28600000000000,
+    0x3ff6623882553397, 0x3d71fe0000000000,
+    0x3ff671c1c708328e, 0xbd37200000000000,
+    0x3ff68155d44ca97e, 0x3ce6800000000000,
+    0x3ff690f4b19e9471, 0xbd29780000000000
+];
+
+// x = k + i / 256 + z with |z| <= 2^-9, so that exp2(x) = 2^k * exp2(i / 256 + eps) * exp2(z - eps)
+// where the last factor is approximated by a polynomial of degree 5 within 1.3 * 2^-61. This is
+// due to Gal and Bachelis, and the error is below 0.503 ulp for normal results.
+pub fn exp2(x: f64) -> f64 {
+    static TBLSIZE: u32 = 256;
+    static P1: u64 = 0x3fe62e42fefa39ef;
+    static P2: u64 = 0x3fcebfbdff82c575;
+    static P3: u64 = 0x3fac6b08d704a0a6;
+    static P4: u64 = 0x3f83b2ab88f70400;
+    static P5: u64 = 0x3f55d88003875c74;
+
+    let redux = from_bits(0x4338000000000000) / TBLSIZE as f64;
+    let ui = bits(x);
+    let ix = high_word(x) & 0x7fffffff;
+    if ix >= 0x408ff000 {
+        // |x| >= 1022 or NaN
+        if ix >= 0x40900000 && ui >> 63 == 0 {
+            // x >= 1024 or NaN
+            return x * from_bits(0x7fe0000000000000)
+        }
+        if ix >= 0x7ff00000 {
+            // x is -inf or NaN
+            return -1.0 / x
+        }
+        if ui >> 63 != 0 && x <= -1075.0 {
+            return 0.0
+        }
+    } else if ix < 0x3c900000 {
+        // |x| < 2^-54
+        return 1.0 + x
+    }
+
+    // reduce x, computing z, i0 and k
+    let ui = bits(x + redux);
+    let i0 = ui as u32 + TBLSIZE / 2;
+    let k = (i0 / TBLSIZE * TBLSIZE) as i32 / TBLSIZE as i32;
+    let i0 = (i0 % TBLSIZE) as uint;
+    let mut z = x - (from_bits(ui) - redux);
+
+    let t = from_bits(EXP2_TBL[2 * i0]);
+    z -= from_bits(EXP2_TBL[2 * i0 + 1]);
+    let r = t + t * z * (from_bits(P1) + z * (from_bits(P2) + z * (from_bits(P3) +
+            z * (from_bits(P4) + z * from_bits(P5)))));
+    scalbn(r, k)
+}
+
+// exp2(i / 16) for i from -8 to 7.
+static EXP2F_TBL: [u64, ..16] = [
+    0x3fe6a09e667f3bcd, 0x3fe7a11473eb0187, 0x3fe8ace5422aa0db, 0x3fe9c49182a3f090,
+    0x3feae89f995ad3ad, 0x3fec199bdd85529c, 0x3fed5818dcfba487, 0x3feea4afa2a490da,
+    0x3ff0000000000000, 0x3ff0b5586cf9890f, 0x3ff172b83c7d517b, 0x3ff2387a6e756238,
+    0x3ff306fe0a31b715, 0x3ff3dea64c123422, 0x3ff4bfdad5362a27, 0x3ff5ab07dd485429
+];
+
+// The same as `exp2` with a table of 16 entries and a polynomial of degree 4, all in double
+// precision, for an error below 0.501 ulp.
+pub fn exp2f(x: f32) -> f32 {
+    static TBLSIZE: u32 = 16;
+    static P1: u32 = 0x3f317218;
+    static P2: u32 = 0x3e75fdf0;
+    static P3: u32 = 0x3d6359a4;
+    static P4: u32 = 0x3c1d964e;
+
+    let redux = from_bitsf(0x4b400000) / TBLSIZE as f32;
+    let ui = bitsf(x);
+    let ix = ui & 0x7fffffff;
+    if ix > 0x42fc0000 {
+        // |x| > 126
+        if ix > 0x7f800000 {
+            return x
+        }
+        if ui >= 0x43000000 && ui < 0x80000000 {
+            // x >= 128
+            return x * from_bitsf(0x7f000000)
+        }
+        if ui >= 0xc3160000 {
+            // x <= -150
+            return 0.0
+        }
+    } else if ix <= 0x33000000 {
+        // |x| <= 2^-25
+        return 1.0 + x
+    }
+
+    // reduce x, computing z, i0 and k
+    let ui = bitsf(x + redux);
+    let i0 = ui + TBLSIZE / 2;
+    let k = i0 / TBLSIZE;
+    let ukf = from_bits(((0x3ff + k) as u64) << 52);
+    let i0 = (i0 & (TBLSIZE - 1)) as uint;
+    let z = (x - (from_bitsf(ui) - redux)) as f64;
+
+    let r = from_bits(EXP2F_TBL[i0]);
+    let t = r * z;
+    let r = r + t * (from_bitsf(P1) as f64 + z * from_bitsf(P2) as f64) +
+            t * (z * z) * (from_bitsf(P3) as f64 + z * from_bitsf(P4) as f64);
+    (r * ukf) as f32
+}
+
+// exp(x) - 1 keeps the relative precision for small x. With the same reduction as `exp` and the
+// rational approximation R1(r) of r / 2 * (exp(r) + 1) / (exp(r) - 1) as an even polynomial of
+// degree 10 within 2^-61, the result is rebuilt as 2^k * (r - e + 1) - 1 in the way that loses
+// the least precision for each k. The error is below 1 ulp.
+pub fn expm1(x: f64) -> f64 {
+    static O_THRESHOLD: f64 = 7.09782712893383973096e+02; // 0x40862E42, 0xFEFA39EF
+    static Q1: f64 = -3.33333333333331316428e-02; // BFA11111 111110F4
+    static Q2: f64 = 1.58730158725481460165e-03; // 3F5A01A0 19FE5585
+    static Q3: f64 = -7.93650757867487942473e-05; // BF14CE19 9EAADBB7
+    static Q4: f64 = 4.00821782732936239552e-06; // 3ED0CFCA 86E65239
+    static Q5: f64 = -2.01099218183624371326e-07; // BE8AFDB7 6E09C32D
+
+    let hx = high_word(x) & 0x7fffffff;
+    let sign = bits(x) >> 63 != 0;
+    if hx >= 0x4043687a {
+        // |x| >= 56 * ln2 or NaN
+        if is_nan(x) {
+            return x
+        }
+        if sign {
+            return -1.0
+        }
+        if x > O_THRESHOLD {
+            return x * from_bits(0x7fe0000000000000)
+        }
+    }
+
+    let mut x = x;
+    let k;
+    let c;
+    if hx > 0x3fd62e42 {
+        // |x| > ln2 / 2
+        let hi;
+        let lo;
+        if hx < 0x3ff0a2b2 {
+            // |x| < 1.5 ln2
+            if !sign {
+                hi = x - LN2_HI;
+                lo = LN2_LO;
+                k = 1;
+            } else {
+                hi = x + LN2_HI;
+                lo = -LN2_LO;
+                k = -1;
+            }
+        } else {
+            k = (INV_LN2 * x + if sign { -0.5 } else { 0.5 }) as i32;
+            let t = k as f64;
+            // t * LN2_HI is exact here
+            hi = x - t * LN2_HI;
+            lo = t * LN2_LO;
+        }
+        x = hi - lo;
+        c = (hi - x) - lo;
+    } else if hx < 0x3c900000 {
+        // |x| < 2^-54
+        return x
+    } else {
+        c = 0.0;
+        k = 0;
+    }
+
+    let hfx = 0.5 * x;
+    let hxs = x * hfx;
+    let r1 = 1.0 + hxs * (Q1 + hxs * (Q2 + hxs * (Q3 + hxs * (Q4 + hxs * Q5))));
+    let t = 3.0 - r1 * hfx;
+    let e = hxs * ((r1 - t) / (6.0 - x * t));
+    if k == 0 {
+        // c is 0
+        return x - (x * e - hxs)
+    }
+    let e = x * (e - c) - c - hxs;
+    // exp(x) ~ 2^k * (x - e + 1)
+    if k == -1 {
+        return 0.5 * (x - e) - 0.5
+    }
+    if k == 1 {
+        if x < -0.25 {
+            return -2.0 * (e - (x + 0.5))
+        }
+        return 1.0 + 2.0 * (x - e)
+    }
+    let twopk = from_bits(((0x3ff + k) as u64) << 52);
+    if k < 0 || k > 56 {
+        let y = x - e + 1.0;
+        let y = if k == 1024 { y * 2.0 * from_bits(0x7fe0000000000000) } else { y * twopk };
+        return y - 1.0
+    }
+    let uf = from_bits(((0x3ff - k) as u64) << 52);
+    if k < 20 {
+        (x - e + (1.0 - uf)) * twopk
+    } else {
+        (x - (e + uf) + 1.0) * twopk
+    }
+}
+
+// The same method with a polynomial of degree 4, for an error below 1 ulp.
+pub fn expm1f(x: f32) -> f32 {
+    static O_THRESHOLD: f32 = 8.8721679688e+01; // 0x42b17180
+    static LN2_HIF: f32 = 6.9313812256e-01; // 0x3f317180
+    static LN2_LOF: f32 = 9.0580006145e-06; // 0x3717f7d1
+    static INV_LN2F: f32 = 1.4426950216e+00; // 0x3fb8aa3b
+    static Q1: f32 = -3.3333212137e-2; // -0x888868.0p-28
+    static Q2: f32 = 1.5807170421e-3; // 0xcf3010.0p-33
+
+    let hx = bitsf(x);
+    let sign = hx >> 31 != 0;
+    let hx = hx & 0x7fffffff;
+    if hx >= 0x4195b844 {
+        // |x| >= 27 * ln2 or NaN
+        if hx > 0x7f800000 {
+            return x
+        }
+        if sign {
+            return -1.0
+        }
+        if x > O_THRESHOLD {
+            return x * from_bitsf(0x7f000000)
+        }
+    }
+
+    let mut x = x;
+    let k;
+    let mut c = 0.0;
+    if hx > 0x3eb17218 {
+        // |x| > ln2 / 2
+        let hi;
+        let lo;
+        if hx < 0x3f851592 {
+            // |x| < 1.5 ln2
+            if !sign {
+                hi = x - LN2_HIF;
+                lo = LN2_LOF;
+                k = 1;
+            } else {
+                hi = x + LN2_HIF;
+                lo = -LN2_LOF;
+                k = -1;
+            }
+        } else {
+            k = (INV_LN2F * x + if sign { -0.5 } else { 0.5 }) as i32;
+            let t = k as f32;
+            hi = x - t * LN2_HIF;
+            lo = t * LN2_LOF;
+        }
+        x = hi - lo;
+        c = (hi - x) - lo;
+    } else if hx < 0x33000000 {
+        // |x| < 2^-25
+        return x
+    } else {
+        k = 0;
+    }
+
+    let hfx = 0.5 * x;
+    let hxs = x * hfx;
+    let r1 = 1.0 + hxs * (Q1 + hxs * Q2);
+    let t = 3.0 - r1 * hfx;
+    let e = hxs * ((r1 - t) / (6.0 - x * t));
+    if k == 0 {
+        return x - (x * e - hxs)
+    }
+    let e = x * (e - c) - c - hxs;
+    if k == -1 {
+        return 0.5 * (x - e) - 0.5
+    }
+    if k == 1 {
+        if x < -0.25 {
+            return -2.0 * (e - (x + 0.5))
+        }
+        return 1.0 + 2.0 * (x - e)
+    }
+    let twopk = from_bitsf(((0x7f + k) << 23) as u32);
+    if k < 0 || k > 56 {
+        let y = x - e + 1.0;
+        let y = if k == 128 { y * 2.0 * from_bitsf(0x7f000000) } else { y * twopk };
+        return y - 1.0
+    }
+    let uf = from_bitsf(((0x7f - k) << 23) as u32);
+    if k < 23 {
+        (x - e + (1.0 - uf)) * twopk
+    } else {
+        (x - (e + uf) + 1.0) * twopk
+    }
+}
+
+// exp(x) / 2 for x in [log(DBL_MAX), 2 * log(DBL_MAX)), where exp(x) itself overflows. This
+// computes exp(x - k * ln2) * 2^(k - 1) with k = 2043, scaling in two steps as 2^(k - 1) is out
+// of range.
+fn k_expo2(x: f64) -> f64 {
+    let k_ln2 = from_bits(0x40962066151add8b);
+    let scale = from_bits(((0x3ff + 2043 / 2) as u64) << 52);
+    exp(x - k_ln2) * scale * scale
+}
+
+// The same with k = 235, for x in [log(FLT_MAX), 2 * log(FLT_MAX)).
+fn k_expo2f(x: f32) -> f32 {
+    let k_ln2 = from_bitsf(0x4322e3bc);
+    let scale = from_bitsf(((0x7f + 235 / 2) as u32) << 23);
+    expf(x - k_ln2) * scale * scale
+}
+
+// Logarithms
+
+// The logarithms of zero, negative numbers, infinity, NaN and one, which are the same in every
+// base.
+fn log_special(x: f64) -> Option<f64> {
+    let ui = bits(x);
+    if ui << 1 == 0 {
+        return Some(-1.0 / (x * x))
+    }
+    if ui >> 63 != 0 {
+        return Some((x - x) / 0.0)
+    }
+    if ui >= 0x7ff0000000000000 {
+        return Some(x)
+    }
+    if ui == 0x3ff0000000000000 {
+        return Some(0.0)
+    }
+    None
+}
+
+fn log_specialf(x: f32) -> Option<f32> {
+    let ui = bitsf(x);
+    if ui << 1 == 0 {
+        return Some(-1.0 / (x * x))
+    }
+    if ui >> 31 != 0 {
+        return Some((x - x) / 0.0)
+    }
+    if ui >= 0x7f800000 {
+        return Some(x)
+    }
+    if ui == 0x3f800000 {
+        return Some(0.0)
+    }
+    None
+}
+
+// Split a positive finite `x` into `(k, f)` with x = 2^k * (1 + f) and 1 + f in
+// [sqrt(2)/2, sqrt(2)).
+fn log_reduce(x: f64) -> (i32, f64) {
+    let mut k = 0;
+    let mut ui = bits(x);
+    if ui < 0x0010000000000000 {
+        // scale subnormals up
+        k = -54;
+        ui = bits(x * from_bits(0x4350000000000000));
+    }
+    let hx = (ui >> 32) as u32 + (0x3ff00000 - 0x3fe6a09e);
+    k += (hx >> 20) as i32 - 0x3ff;
+    let hx = (hx & 0x000fffff) + 0x3fe6a09e;
+    (k, from_bits((hx as u64) << 32 | ui & 0xffffffff) - 1.0)
+}
+
+fn log_reducef(x: f32) -> (i32, f32) {
+    let mut k = 0;
+    let mut ix = bitsf(x);
+    if ix < 0x00800000 {
+        k = -25;
+        ix = bitsf(x * from_bitsf(0x4c000000));
+    }
+    ix += 0x3f800000 - 0x3f3504f3;
+    k += (ix >> 23) as i32 - 0x7f;
+    (k, from_bitsf((ix & 0x007fffff) + 0x3f3504f3) - 1.0)
+}
+
+// The part of log(1 + f) beyond f - f^2 / 2 for 1 + f in [sqrt(2)/2, sqrt(2)], which is
+// s * (f^2 / 2 + R(s^2)) with s = f / (2 + f).
+//
+// As log(1 + f) = log(1 + s) - log(1 - s) = 2s + 2/3 s^3 + 2/5 s^5 + ..., R(z) is a polynomial
+// of degree 7 approximating (log(1 + s) - log(1 - s) - 2s) / s with an error below 2^-58.45 for
+// |s| <= 0.1716, and 2s = f - s * f = f - f^2 / 2 + s * f^2 / 2.
+fn k_log1p(f: f64) -> f64 {
+    static LG1: f64 = 6.666666666666735130e-01; // 3FE55555 55555593
+    static LG2: f64 = 3.999999999940941908e-01; // 3FD99999 9997FA04
+    static LG3: f64 = 2.857142874366239149e-01; // 3FD24924 94229359
+    static LG4: f64 = 2.222219843214978396e-01; // 3FCC71C5 1D8E78AF
+    static LG5: f64 = 1.818357216161805012e-01; // 3FC74664 96CB03DE
+    static LG6: f64 = 1.531383769920937332e-01; // 3FC39A09 D078C69F
+    static LG7: f64 = 1.479819860511658591e-01; // 3FC2F112 DF3E5244
+
+    let hfsq = 0.5 * f * f;
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * (LG2 + w * (LG4 + w * LG6));
+    let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+    s * (hfsq + (t2 + t1))
+}
+
+// The same with a polynomial of degree 4, for an error below 2^-25.7.
+fn k_log1pf(f: f32) -> f32 {
+    static LG1: f32 = 0.66666662693; // 0xaaaaaa.0p-24
+    static LG2: f32 = 0.40000972152; // 0xccce13.0p-25
+    static LG3: f32 = 0.28498786688; // 0x91e9ee.0p-25
+    static LG4: f32 = 0.24279078841; // 0xf89e26.0p-26
+
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * (LG2 + w * LG4);
+    let t2 = z * (LG1 + w * LG3);
+    let hfsq = 0.5 * f * f;
+    s * (hfsq + (t2 + t1))
+}
+
+pub fn log(x: f64) -> f64 {
+    match log_special(x) {
+        Some(y) => return y,
+        None => {}
+    }
+    let (k, f) = log_reduce(x);
+    let hfsq = 0.5 * f * f;
+    let dk = k as f64;
+    k_log1p(f) + dk * LN2_LO - hfsq + f + dk * LN2_HI
+}
+
+pub fn logf(x: f32) -> f32 {
+    static LN2_HIF: f32 = 6.9313812256e-01; // 0x3f317180
+    static LN2_LOF: f32 = 9.0580006145e-06; // 0x3717f7d1
+
+    match log_specialf(x) {
+        Some(y) => return y,
+        None => {}
+    }
+    let (k, f) = log_reducef(x);
+    let hfsq = 0.5 * f * f;
+    let dk = k as f32;
+    k_log1pf(f) + dk * LN2_LOF - hfsq + f + dk * LN2_HIF
+}
+
+// log(1 + f) is computed as hi + lo, with hi = f - f^2 / 2 rounded to 21 bits so that its
+// products with the high part of the scale factor are exact.
+pub fn log2(x: f64) -> f64 {
+    static IVLN2HI: f64 = 1.44269504072144627571e+00; // 0x3ff71547, 0x65200000
+    static IVLN2LO: f64 = 1.67517131648865118353e-10; // 0x3de705fc, 0x2eefa200
+
+    match log_special(x) {
+        Some(y) => return y,
+        None => {}
+    }
+    let (k, f) = log_reduce(x);
+    let hfsq = 0.5 * f * f;
+    let hi = with_low_word_zero(f - hfsq);
+    let lo = f - hi - hfsq + k_log1p(f);
+    let val_hi = hi * IVLN2HI;
+    let val_lo = (lo + hi) * IVLN2LO + lo * IVLN2HI;
+    // add k with extra precision
+    let y = k as f64;
+    let w = y + val_hi;
+    let val_lo = val_lo + ((y - w) + val_hi);
+    val_lo + w
+}
+
+pub fn log2f(x: f32) -> f32 {
+    static IVLN2HI: f32 = 1.4428710938e+00; // 0x3fb8b000
+    static IVLN2LO: f32 = -1.7605285393e-04; // 0xb9389ad4
+
+    match log_specialf(x) {
+        Some(y) => return y,
+        None => {}
+    }
+    let (k, f) = log_reducef(x);
+    let hfsq = 0.5 * f * f;
+    let hi = from_bitsf(bitsf(f - hfsq) & 0xfffff000);
+    let lo = f - hi - hfsq + k_log1pf(f);
+    (lo + hi) * IVLN2LO + lo * IVLN2HI + hi * IVLN2HI + k as f32
+}
+
+pub fn log10(x: f64) -> f64 {
+    static IVLN10HI: f64 = 4.34294481878168880939e-01; // 0x3fdbcb7b, 0x15200000
+    static IVLN10LO: f64 = 2.50829467116452752298e-11; // 0x3dbb9438, 0xca9aadd5
+    static LOG10_2HI: f64 = 3.01029995663611771306e-01; // 0x3FD34413, 0x509F6000
+    static LOG10_2LO: f64 = 3.69423907715893078616e-13; // 0x3D59FEF3, 0x11F12B36
+
+    match log_special(x) {
+        Some(y) => return y,
+        None => {}
+    }
+    let (k, f) = log_reduce(x);
+    let hfsq = 0.5 * f * f;
+    let hi = with_low_word_zero(f - hfsq);
+    let lo = f - hi - hfsq + k_log1p(f);
+    // val_hi + val_lo ~ log10(1 + f) + k * log10(2)
+    let val_hi = hi * IVLN10HI;
+    let dk = k as f64;
+    let y = dk * LOG10_2HI;
+    let val_lo = dk * LOG10_2LO + (lo + hi) * IVLN10LO + lo * IVLN10HI;
+    let w = y + val_hi;
+    let val_lo = val_lo + ((y - w) + val_hi);
+    val_lo + w
+}
+
+pub fn log10f(x: f32) -> f32 {
+    static IVLN10HI: f32 = 4.3432617188e-01; // 0x3ede6000
+    static IVLN10LO: f32 = -3.1689971365e-05; // 0xb804ead9
+    static LOG10_2HI: f32 = 3.0102920532e-01; // 0x3e9a2080
+    static LOG10_2LO: f32 = 7.9034151668e-07; // 0x355427db
+
+    match log_specialf(x) {
+        Some(y) => return y,
+        None => {}
+    }
+    let (k, f) = log_reducef(x);
+    let hfsq = 0.5 * f * f;
+    let hi = from_bitsf(bitsf(f - hfsq) & 0xfffff000);
+    let lo = f - hi - hfsq + k_log1pf(f);
+    let dk = k as f32;
+    dk * LOG10_2LO + (lo + hi) * IVLN10LO + lo * IVLN10HI + hi * IVLN10HI + dk * LOG10_2HI
+}
+
+// log(1 + x) without the loss of precision of forming 1 + x for small x. 1 + x is rounded to u
+// and reduced as in `log`, and c ~ log(1 + x) - log(u) corrects for the rounding.
+pub fn log1p(x: f64) -> f64 {
+    let hx = high_word(x);
+    let mut k = 1;
+    let mut f = 0.0;
+    let mut c = 0.0;
+    if hx < 0x3fda827a || hx >> 31 != 0 {
+        // 1 + x < sqrt(2)
+        if hx >= 0xbff00000 {
+            // x <= -1
+            if x == -1.0 {
+                return x / 0.0
+            }
+            return (x - x) / 0.0
+        }
+        if hx << 1 < 0x3ca00000 << 1 {
+            // |x| < 2^-53
+            return x
+        }
+        if hx <= 0xbfd2bec4 {
+            // sqrt(2)/2 <= 1 + x < sqrt(2)
+            k = 0;
+            f = x;
+        }
+    } else if hx >= 0x7ff00000 {
+        return x
+    }
+    if k > 0 {
+        let u = 1.0 + x;
+        let ui = bits(u);
+        let hu = (ui >> 32) as u32 + (0x3ff00000 - 0x3fe6a09e);
+        k = (hu >> 20) as i32 - 0x3ff;
+        // the correction term, avoiding underflow in c / u
+        if k < 54 {
+            c = if k >= 2 { 1.0 - (u - x) } else { x - (u - 1.0) };
+            c /= u;
+        }
+        let hu = (hu & 0x000fffff) + 0x3fe6a09e;
+        f = from_bits((hu as u64) << 32 | ui & 0xffffffff) - 1.0;
+    }
+    let hfsq = 0.5 * f * f;
+    let dk = k as f64;
+    k_log1p(f) + (dk * LN2_LO + c) - hfsq + f + dk * LN2_HI
+}
+
+pub fn log1pf(x: f32) -> f32 {
+    static LN2_HIF: f32 = 6.9313812256e-01; // 0x3f317180
+    static LN2_LOF: f32 = 9.0580006145e-06; // 0x3717f7d1
+
+    let ix = bitsf(x);
+    let mut k = 1;
+    let mut f = 0.0;
+    let mut c = 0.0;
+    if ix < 0x3ed413d0 || ix >> 31 != 0 {
+        // 1 + x < sqrt(2)
+        if ix >= 0xbf800000 {
+            // x <= -1
+            if x == -1.0 {
+                return x / 0.0
+            }
+            return (x - x) / 0.0
+        }
+        if ix << 1 < 0x33800000 << 1 {
+            // |x| < 2^-24
+            return x
+        }
+        if ix <= 0xbe95f619 {
+            // sqrt(2)/2 <= 1 + x < sqrt(2)
+            k = 0;
+            f = x;
+        }
+    } else if ix >= 0x7f800000 {
+        return x
+    }
+    if k > 0 {
+        let u = 1.0 + x;
+        let iu = bitsf(u) + (0x3f800000 - 0x3f3504f3);
+        k = (iu >> 23) as i32 - 0x7f;
+        if k < 25 {
+            c = if k >= 2 { 1.0 - (u - x) } else { x - (u - 1.0) };
+            c /= u;
+        }
+        f = from_bitsf((iu & 0x007fffff) + 0x3f3504f3) - 1.0;
+    }
+    let hfsq = 0.5 * f * f;
+    let dk = k as f32;
+    k_log1pf(f) + (dk * LN2_LOF + c) - hfsq + f + dk * LN2_HIF
+}
+
+// Powers
+
+// Whether `y` is an odd integer (1), an even integer (2) or not an integer (0), from its high word
+// without the sign and its low word.
+fn int_kind(iy: i32, ly: u32) -> i32 {
+    if iy >= 0x43400000 {
+        return 2
+    }
+    if iy >= 0x3ff00000 {
+        let k = (iy >> 20) - 0x3ff;
+        if k > 20 {
+            let j = ly >> (52 - k) as u32;
+            if j << (52 - k) as u32 == ly {
+                return 2 - (j & 1) as i32
+            }
+        } else if ly == 0 {
+            let j = iy >> (20 - k);
+            if j << (20 - k) == iy {
+                return 2 - (j & 1)
+            }
+        }
+    }
+    0
+}
+
+// x^y is computed as 2^(y * log2(x)), with log2(x) = t1 + t2 to 53 + 24 bits and the product
+// split in the same way, so that the error of the result stays below 1 ulp. Integer powers of
+// integers are exact whenever the result is representable.
+pub fn pow(x: f64, y: f64) -> f64 {
+    static BP: [f64, ..2] = [1.0, 1.5];
+    static DP_H: [f64, ..2] = [0.0, 5.84962487220764160156e-01]; // 0x3fe2b803_40000000
+    static DP_L: [f64, ..2] = [0.0, 1.35003920212974897128e-08]; // 0x3E4CFDEB, 0x43CFD006
+    static TWO53: f64 = 9007199254740992.0; // 0x43400000_00000000
+    static HUGE: f64 = 1.0e300;
+    static TINY: f64 = 1.0e-300;
+    // coefficients of (3/2) * (log(x) - 2s - 2/3 * s^3)
+    static L1: f64 = 5.99999999999994648725e-01; // 0x3fe33333_33333303
+    static L2: f64 = 4.28571428578550184252e-01; // 0x3fdb6db6_db6fabff
+    static L3: f64 = 3.33333329818377432918e-01; // 0x3fd55555_518f264d
+    static L4: f64 = 2.72728123808534006489e-01; // 0x3fd17460_a91d4101
+    static L5: f64 = 2.30660745775561754067e-01; // 0x3fcd864a_93c9db65
+    static L6: f64 = 2.06975017800338417784e-01; // 0x3fca7e28_4a454eef
+    static P1: f64 = 1.66666666666666019037e-01; // 0x3fc55555_5555553e
+    static P2: f64 = -2.77777777770155933842e-03; // 0xbf66c16c_16bebd93
+    static P3: f64 = 6.61375632143793436117e-05; // 0x3f11566a_af25de2c
+    static P4: f64 = -1.65339022054652515390e-06; // 0xbebbbd41_c5d26bf1
+    static P5: f64 = 4.13813679705723846039e-08; // 0x3e663769_72bea4d0
+    static LG2: f64 = 6.93147180559945286227e-01; // 0x3fe62e42_fefa39ef
+    static LG2_H: f64 = 6.93147182464599609375e-01; // 0x3fe62e43_00000000
+    static LG2_L: f64 = -1.90465429995776804525e-09; // 0xbe205c61_0ca86c39
+    // -(1024 - log2(overflow + 0.5 ulp))
+    static OVT: f64 = 8.0085662595372944372e-017;
+    // 2 / (3 * ln2), and its high 24 bits and tail
+    static CP: f64 = 9.61796693925975554329e-01; // 0x3feec709_dc3a03fd
+    static CP_H: f64 = 9.61796700954437255859e-01; // 0x3feec709_e0000000
+    static CP_L: f64 = -7.02846165095275826516e-09; // 0xbe3e2fe0_145b01f5
+    // 1 / ln2, and its high 24 bits and tail
+    static IVLN2: f64 = 1.44269504088896338700e+00; // 0x3ff71547_652b82fe
+    static IVLN2_H: f64 = 1.44269502162933349609e+00; // 0x3ff71547_60000000
+    static IVLN2_L: f64 = 1.92596299112661746887e-08; // 0x3e54ae0b_f85ddf44
+
+    let hx = high_word(x) as i32;
+    let lx = bits(x) as u32;
+    let hy = high_word(y) as i32;
+    let ly = bits(y) as u32;
+    let mut ix = hx & 0x7fffffff;
+    let iy = hy & 0x7fffffff;
+
+    // x^0 = 1 and 1^y = 1, even for NaN
+    if iy as u32 | ly == 0 {
+        return 1.0
+    }
+    if hx == 0x3ff00000 && lx == 0 {
+        return 1.0
+    }
+    if ix > 0x7ff00000 || (ix == 0x7ff00000 && lx != 0) ||
+       iy > 0x7ff00000 || (iy == 0x7ff00000 && ly != 0) {
+        return x + y
+    }
+
+    let yisint = if hx < 0 { int_kind(iy, ly) } else { 0 };
+
+    if ly == 0 {
+        if iy == 0x7ff00000 {
+            // y is infinite
+            return if (ix - 0x3ff00000) as u32 | lx == 0 {
+                // (-1)^+-inf = 1
+                1.0
+            } else if ix >= 0x3ff00000 {
+                // (|x| > 1)^+-inf = inf, 0
+                if hy >= 0 { y } else { 0.0 }
+            } else {
+                // (|x| < 1)^+-inf = 0, inf
+                if hy >= 0 { 0.0 } else { -y }
+            }
+        }
+        if iy == 0x3ff00000 {
+            // y is +-1
+            return if hy >= 0 { x } else { 1.0 / x }
+        }
+        if hy == 0x40000000 {
+            // y is 2
+            return x * x
+        }
+        if hy == 0x3fe00000 && hx >= 0 {
+            // y is 0.5 and x >= +0
+            return sqrt(x)
+        }
+    }
+
+    let mut ax = fabs(x);
+    if lx == 0 && (ix == 0x7ff00000 || ix == 0 || ix == 0x3ff00000) {
+        // x is +-0, +-inf or +-1
+        let mut z = if hy < 0 { 1.0 / ax } else { ax };
+        if hx < 0 {
+            if (ix - 0x3ff00000) | yisint == 0 {
+                // (-1)^non-integer is NaN
+                z = (z - z) / (z - z);
+            } else if yisint == 1 {
+                // (x < 0)^odd = -(|x|^odd)
+                z = -z;
+            }
+        }
+        return z
+    }
+
+    // the sign of the result
+    let mut s = 1.0;
+    if hx < 0 {
+        if yisint == 0 {
+            // (x < 0)^non-integer is NaN
+            return (x - x) / (x - x)
+        }
+        if yisint == 1 {
+            s = -1.0;
+        }
+    }
+
+    let t1;
+    let t2;
+    if iy > 0x41e00000 {
+        // |y| > 2^31
+        if iy > 0x43f00000 {
+            // |y| > 2^64 must overflow or underflow
+            if ix <= 0x3fefffff {
+                return if hy < 0 { HUGE * HUGE } else { TINY * TINY }
+            }
+            if ix >= 0x3ff00000 {
+                return if hy > 0 { HUGE * HUGE } else { TINY * TINY }
+            }
+        }
+        // as will any x not close to one
+        if ix < 0x3fefffff {
+            return if hy < 0 { s * HUGE * HUGE } else { s * TINY * TINY }
+        }
+        if ix > 0x3ff00000 {
+            return if hy > 0 { s * HUGE * HUGE } else { s * TINY * TINY }
+        }
+        // |1 - x| <= 2^-20, so log(x) ~ t - t^2 / 2 + t^3 / 3 - t^4 / 4 with t = x - 1
+        let t = ax - 1.0;
+        let w = (t * t) * (0.5 - t * (0.3333333333333333333333 - t * 0.25));
+        let u = IVLN2_H * t;
+        let v = t * IVLN2_L - w * IVLN2;
+        t1 = with_low_word_zero(u + v);
+        t2 = v - (t1 - u);
+    } else {
+        let mut n = 0;
+        if ix < 0x00100000 {
+            // scale subnormals up
+            ax *= TWO53;
+            n -= 53;
+            ix = high_word(ax) as i32;
+        }
+        n += (ix >> 20) - 0x3ff;
+        let j = ix & 0x000fffff;
+        // normalize ix, and determine the interval
+        ix = j | 0x3ff00000;
+        let k = if j <= 0x3988e {
+            // |x| < sqrt(3/2)
+            0
+        } else if j < 0xbb67a {
+            // |x| < sqrt(3)
+            1
+        } else {
+            n += 1;
+            ix -= 0x00100000;
+            0
+        };
+        ax = with_high_word(ax, ix as u32);
+
+        // ss = s_h + s_l = (x - 1) / (x + 1) or (x - 1.5) / (x + 1.5)
+        let u = ax - BP[k];
+        let v = 1.0 / (ax + BP[k]);
+        let ss = u * v;
+        let s_h = with_low_word_zero(ss);
+        // t_h = ax + BP[k], high part
+        let t_h = with_high_word(0.0, (ix as u32 >> 1 | 0x20000000) + 0x00080000 +
+                                      (k << 18) as u32);
+        let t_l = ax - (t_h - BP[k]);
+        let s_l = v * ((u - s_h * t_h) - s_h * t_l);
+
+        // log(ax)
+        let s2 = ss * ss;
+        let mut r = s2 * s2 * (L1 + s2 * (L2 + s2 * (L3 + s2 * (L4 + s2 * (L5 + s2 * L6)))));
+        r += s_l * (s_h + ss);
+        let s2 = s_h * s_h;
+        let t_h = with_low_word_zero(3.0 + s2 + r);
+        let t_l = r - ((t_h - 3.0) - s2);
+        // u + v = ss * (1 + ...)
+        let u = s_h * t_h;
+        let v = s_l * t_h + t_l * ss;
+        // 2 / (3 * ln2) * (ss + ...)
+        let p_h = with_low_word_zero(u + v);
+        let p_l = v - (p_h - u);
+        let z_h = CP_H * p_h;
+        let z_l = CP_L * p_h + p_l * CP + DP_L[k];
+        // log2(ax) = (ss + ...) * 2 / (3 * ln2) = n + DP_H + z_h + z_l
+        let t = n as f64;
+        t1 = with_low_word_zero(((z_h + z_l) + DP_H[k]) + t);
+        t2 = z_l - (((t1 - t) - DP_H[k]) - z_h);
+    }
+
+    // split y into y1 + y2 and compute (y1 + y2) * (t1 + t2)
+    let y1 = with_low_word_zero(y);
+    let p_l = (y - y1) * t1 + y * t2;
+    let mut p_h = y1 * t1;
+    let z = p_l + p_h;
+    let j = high_word(z) as i32;
+    let i = bits(z) as u32;
+    if j >= 0x40900000 {
+        // z >= 1024
+        if (j - 0x40900000) as u32 | i != 0 || p_l + OVT > z - p_h {
+            return s * HUGE * HUGE
+        }
+    } else if j & 0x7fffffff >= 0x4090cc00 {
+        // z <= -1075
+        if (j as u32 - 0xc090cc00) | i != 0 || p_l <= z - p_h {
+            return s * TINY * TINY
+        }
+    }
+
+    // 2^(p_h + p_l)
+    let i = j & 0x7fffffff;
+    let mut k = (i >> 20) - 0x3ff;
+    let mut n = 0;
+    if i > 0x3fe00000 {
+        // |z| > 0.5, so set n = [z + 0.5]
+        n = j + (0x00100000 >> (k + 1));
+        k = ((n & 0x7fffffff) >> 20) - 0x3ff;
+        let t = from_bits(((n & !(0x000fffff >> k)) as u32 as u64) << 32);
+        n = ((n & 0x000fffff) | 0x00100000) >> (20 - k);
+        if j < 0 {
+            n = -n;
+        }
+        p_h -= t;
+    }
+    let t = with_low_word_zero(p_l + p_h);
+    let u = t * LG2_H;
+    let v = (p_l - (t - p_h)) * LG2 + t * LG2_L;
+    let z = u + v;
+    let w = v - (z - u);
+    let t = z * z;
+    let t1 = z - t * (P1 + t * (P2 + t * (P3 + t * (P4 + t * P5))));
+    let r = (z * t1) / (t1 - 2.0) - (w + z * w);
+    let z = 1.0 - (r - z);
+    let j = high_word(z) as i32 + (n << 20);
+    if j >> 20 <= 0 {
+        // the result is subnormal
+        s * scalbn(z, n)
+    } else {
+        s * with_high_word(z, j as u32)
+    }
+}
+
+// The same in single precision, with the parts of the logarithm and the product split at 12 bits.
+pub fn powf(x: f32, y: f32) -> f32 {
+    static BP: [f32, ..2] = [1.0, 1.5];
+    static DP_H: [f32, ..2] = [0.0, 5.84960938e-01]; // 0x3f15c000
+    static DP_L: [f32, ..2] = [0.0, 1.56322085e-06]; // 0x35d1cfdc
+    static TWO24: f32 = 16777216.0; // 0x4b800000
+    static HUGE: f32 = 1.0e30;
+    static TINY: f32 = 1.0e-30;
+    static L1: f32 = 6.0000002384e-01; // 0x3f19999a
+    static L2: f32 = 4.2857143283e-01; // 0x3edb6db7
+    static L3: f32 = 3.3333334327e-01; // 0x3eaaaaab
+    static L4: f32 = 2.7272811532e-01; // 0x3e8ba305
+    static L5: f32 = 2.3066075146e-01; // 0x3e6c3255
+    static L6: f32 = 2.0697501302e-01; // 0x3e53f142
+    static P1: f32 = 1.6666667163e-01; // 0x3e2aaaab
+    static P2: f32 = -2.7777778450e-03; // 0xbb360b61
+    static P3: f32 = 6.6137559770e-05; // 0x388ab355
+    static P4: f32 = -1.6533901999e-06; // 0xb5ddea0e
+    static P5: f32 = 4.1381369442e-08; // 0x3331bb4c
+    static LG2: f32 = 6.9314718246e-01; // 0x3f317218
+    static LG2_H: f32 = 6.93145752e-01; // 0x3f317200
+    static LG2_L: f32 = 1.42860654e-06; // 0x35bfbe8c
+    static OVT: f32 = 4.2995665694e-08;
+    static CP: f32 = 9.6179670095e-01; // 0x3f76384f
+    static CP_H: f32 = 9.6191406250e-01; // 0x3f764000
+    static CP_L: f32 = -1.1736857402e-04; // 0xb8f623c6
+    static IVLN2: f32 = 1.4426950216e+00;
+    static IVLN2_H: f32 = 1.4426879883e+00;
+    static IVLN2_L: f32 = 7.0526075433e-06;
+
+    // clear the low 12 bits
+    fn high_part(x: f32) -> f32 {
+        from_bitsf(bitsf(x) & 0xfffff000)
+    }
+
+    let hx = bitsf(x) as i32;
+    let hy = bitsf(y) as i32;
+    let mut ix = hx & 0x7fffffff;
+    let iy = hy & 0x7fffffff;
+
+    if iy == 0 {
+        return 1.0
+    }
+    if hx == 0x3f800000 {
+        return 1.0
+    }
+    if ix > 0x7f800000 || iy > 0x7f800000 {
+        return x + y
+    }
+
+    let mut yisint = 0;
+    if hx < 0 {
+        if iy >= 0x4b800000 {
+            yisint = 2;
+        } else if iy >= 0x3f800000 {
+            let k = (iy >> 23) - 0x7f;
+            let j = iy >> (23 - k);
+            if j << (23 - k) == iy {
+                yisint = 2 - (j & 1);
+            }
+        }
+    }
+
+    if iy == 0x7f800000 {
+        // y is infinite
+        return if ix == 0x3f800000 {
+            1.0
+        } else if ix > 0x3f800000 {
+            if hy >= 0 { y } else { 0.0 }
+        } else {
+            if hy >= 0 { 0.0 } else { -y }
+        }
+    }
+    if iy == 0x3f800000 {
+        return if hy >= 0 { x } else { 1.0 / x }
+    }
+    if hy == 0x40000000 {
+        return x * x
+    }
+    if hy == 0x3f000000 && hx >= 0 {
+        return sqrtf(x)
+    }
+
+    let mut ax = fabsf(x);
+    if ix == 0x7f800000 || ix == 0 || ix == 0x3f800000 {
+        let mut z = if hy < 0 { 1.0 / ax } else { ax };
+        if hx < 0 {
+            if (ix - 0x3f800000) | yisint == 0 {
+                z = (z - z) / (z - z);
+            } else if yisint == 1 {
+                z = -z;
+            }
+        }
+        return z
+    }
+
+    let mut sn = 1.0;
+    if hx < 0 {
+        if yisint == 0 {
+            return (x - x) / (x - x)
+        }
+        if yisint == 1 {
+            sn = -1.0;
+        }
+    }
+
+    let t1;
+    let t2;
+    if iy > 0x4d000000 {
+        // |y| > 2^27, which overflows or underflows unless x is close to one
+        if ix < 0x3f7ffff8 {
+            return if hy < 0 { sn * HUGE * HUGE } else { sn * TINY * TINY }
+        }
+        if ix > 0x3f800007 {
+            return if hy > 0 { sn * HUGE * HUGE } else { sn * TINY * TINY }
+        }
+        let t = ax - 1.0;
+        let w = (t * t) * (0.5 - t * (0.333333333333 - t * 0.25));
+        let u = IVLN2_H * t;
+        let v = t * IVLN2_L - w * IVLN2;
+        t1 = high_part(u + v);
+        t2 = v - (t1 - u);
+    } else {
+        let mut n = 0;
+        if ix < 0x00800000 {
+            ax *= TWO24;
+            n -= 24;
+            ix = bitsf(ax) as i32;
+        }
+        n += (ix >> 23) - 0x7f;
+        let j = ix & 0x007fffff;
+        ix = j | 0x3f800000;
+        let k = if j <= 0x1cc471 {
+            // |x| < sqrt(3/2)
+            0
+        } else if j < 0x5db3d7 {
+            // |x| < sqrt(3)
+            1
+        } else {
+            n += 1;
+            ix -= 0x00800000;
+            0
+        };
+        ax = from_bitsf(ix as u32);
+
+        let u = ax - BP[k];
+        let v = 1.0 / (ax + BP[k]);
+        let s = u * v;
+        let s_h = high_part(s);
+        let t_h = from_bitsf((ix as u32 >> 1 & 0xfffff000 | 0x20000000) + 0x00400000 +
+                             (k << 21) as u32);
+        let t_l = ax - (t_h - BP[k]);
+        let s_l = v * ((u - s_h * t_h) - s_h * t_l);
+
+        let s2 = s * s;
+        let mut r = s2 * s2 * (L1 + s2 * (L2 + s2 * (L3 + s2 * (L4 + s2 * (L5 + s2 * L6)))));
+        r += s_l * (s_h + s);
+        let s2 = s_h * s_h;
+        let t_h = high_part(3.0 + s2 + r);
+        let t_l = r - ((t_h - 3.0) - s2);
+        let u = s_h * t_h;
+        let v = s_l * t_h + t_l * s;
+        let p_h = high_part(u + v);
+        let p_l = v - (p_h - u);
+        let z_h = CP_H * p_h;
+        let z_l = CP_L * p_h + p_l * CP + DP_L[k];
+        let t = n as f32;
+        t1 = high_part(((z_h + z_l) + DP_H[k]) + t);
+        t2 = z_l - (((t1 - t) - DP_H[k]) - z_h);
+    }
+
+    let y1 = high_part(y);
+    let p_l = (y - y1) * t1 + y * t2;
+    let mut p_h = y1 * t1;
+    let z = p_l + p_h;
+    let j = bitsf(z) as i32;
+    if j > 0x43000000 {
+        // z > 128
+        return sn * HUGE * HUGE
+    } else if j == 0x43000000 {
+        if p_l + OVT > z - p_h {
+            return sn * HUGE * HUGE
+        }
+    } else if j & 0x7fffffff > 0x43160000 {
+        // z < -150
+        return sn * TINY * TINY
+    } else if j as u32 == 0xc3160000 && p_l <= z - p_h {
+        return sn * TINY * TINY
+    }
+
+    let i = j & 0x7fffffff;
+    let mut k = (i >> 23) - 0x7f;
+    let mut n = 0;
+    if i > 0x3f000000 {
+        // |z| > 0.5, so set n = [z + 0.5]
+        n = j + (0x00800000 >> (k + 1));
+        k = ((n & 0x7fffffff) >> 23) - 0x7f;
+        let t = from_bitsf(n as u32 & !(0x007fffff >> k as u32));
+        n = ((n & 0x007fffff) | 0x00800000) >> (23 - k);
+        if j < 0 {
+            n = -n;
+        }
+        p_h -= t;
+    }
+    let t = from_bitsf(bitsf(p_l + p_h) & 0xffff8000);
+    let u = t * LG2_H;
+    let v = (p_l - (t - p_h)) * LG2 + t * LG2_L;
+    let z = u + v;
+    let w = v - (z - u);
+    let t = z * z;
+    let t1 = z - t * (P1 + t * (P2 + t * (P3 + t * (P4 + t * P5))));
+    let r = (z * t1) / (t1 - 2.0) - (w + z * w);
+    let z = 1.0 - (r - z);
+    let j = bitsf(z) as i32 + (n << 23);
+    if j >> 23 <= 0 {
+        sn * scalbnf(z, n)
+    } else {
+        sn * from_bitsf(j as u32)
+    }
+}
+
+// Hyperbolic functions
+
+// sinh(x) = (exp(x) - 1 / exp(x)) / 2 = (t + t / (t + 1)) / 2 with t = expm1(|x|), and the
+// subtraction is rearranged for |x| < 1 to avoid cancellation.
+pub fn sinh(x: f64) -> f64 {
+    let h = if bits(x) >> 63 != 0 { -0.5 } else { 0.5 };
+    let absx = fabs(x);
+    let w = high_word(absx);
+    if w < 0x40862e42 {
+        // |x| < log(DBL_MAX)
+        let t = expm1(absx);
+        if w < 0x3ff00000 {
+            if w < 0x3ff00000 - (26 << 20) {
+                return x
+            }
+            return h * (2.0 * t - t * t / (t + 1.0))
+        }
+        return h * (t + t / (t + 1.0))
+    }
+    // |x| > log(DBL_MAX) or NaN
+    2.0 * h * k_expo2(absx)
+}
+
+pub fn sinhf(x: f32) -> f32 {
+    let h = if bitsf(x) >> 31 != 0 { -0.5 } else { 0.5 };
+    let absx = fabsf(x);
+    let w = bitsf(absx);
+    if w < 0x42b17217 {
+        // |x| < log(FLT_MAX)
+        let t = expm1f(absx);
+        if w < 0x3f800000 {
+            if w < 0x3f800000 - (12 << 23) {
+                return x
+            }
+            return h * (2.0 * t - t * t / (t + 1.0))
+        }
+        return h * (t + t / (t + 1.0))
+    }
+    2.0 * h * k_expo2f(absx)
+}
+
+// cosh(x) = 1 + t^2 / (2 * (1 + t)) with t = expm1(|x|) for |x| < ln2, and
+// (exp(|x|) + 1 / exp(|x|)) / 2 above.
+pub fn cosh(x: f64) -> f64 {
+    let x = fabs(x);
+    let w = high_word(x);
+    if w < 0x3fe62e42 {
+        // |x| < ln2
+        if w < 0x3ff00000 - (26 << 20) {
+            return 1.0
+        }
+        let t = expm1(x);
+        return 1.0 + t * t / (2.0 * (1.0 + t))
+    }
+    if w < 0x40862e42 {
+        // |x| < log(DBL_MAX)
+        let t = exp(x);
+        return 0.5 * (t + 1.0 / t)
+    }
+    // |x| > log(DBL_MAX) or NaN
+    k_expo2(x)
+}
+
+pub fn coshf(x: f32) -> f32 {
+    let x = fabsf(x);
+    let w = bitsf(x);
+    if w < 0x3f317217 {
+        // |x| < ln2
+        if w < 0x3f800000 - (12 << 23) {
+            return 1.0
+        }
+        let t = expm1f(x);
+        return 1.0 + t * t / (2.0 * (1.0 + t))
+    }
+    if w < 0x42b17217 {
+        // |x| < log(FLT_MAX)
+        let t = expf(x);
+        return 0.5 * (t + 1.0 / t)
+    }
+    k_expo2f(x)
+}
+
+// tanh(x) = (exp(2x) - 1) / (exp(2x) + 1) = t / (t + 2) with t = expm1(2|x|), which is rewritten
+// as 1 - 2 / (t + 2) for |x| > log(3) / 2 and computed from expm1(-2|x|) for |x| < log(5/3) / 2.
+// The error is up to 2 ulp in [0.1, 0.2554].
+pub fn tanh(x: f64) -> f64 {
+    let sign = bits(x) >> 63 != 0;
+    let x = fabs(x);
+    let w = high_word(x);
+    let t = if w > 0x3fe193ea {
+        // |x| > log(3) / 2 or NaN
+        if w > 0x40340000 {
+            // |x| > 20 or NaN
+            1.0 - 0.0 / x
+        } else {
+            let t = expm1(2.0 * x);
+            1.0 - 2.0 / (t + 2.0)
+        }
+    } else if w > 0x3fd058ae {
+        // |x| > log(5/3) / 2
+        let t = expm1(2.0 * x);
+        t / (t + 2.0)
+    } else if w >= 0x00100000 {
+        // |x| >= 2^-1022
+        let t = expm1(-2.0 * x);
+        -t / (t + 2.0)
+    } else {
+        // |x| is subnormal
+        x
+    };
+    if sign { -t } else { t }
+}
+
+pub fn tanhf(x: f32) -> f32 {
+    let sign = bitsf(x) >> 31 != 0;
+    let x = fabsf(x);
+    let w = bitsf(x);
+    let t = if w > 0x3f0c9f54 {
+        // |x| > log(3) / 2 or NaN
+        if w > 0x41200000 {
+            // |x| > 10
+            1.0 + 0.0 / x
+        } else {
+            let t = expm1f(2.0 * x);
+            1.0 - 2.0 / (t + 2.0)
+        }
+    } else if w > 0x3e82c578 {
+        // |x| > log(5/3) / 2
+        let t = expm1f(2.0 * x);
+        t / (t + 2.0)
+    } else if w >= 0x00800000 {
+        // |x| >= 2^-126
+        let t = expm1f(-2.0 * x);
+        -t / (t + 2.0)
+    } else {
+        x
+    };
+    if sign { -t } else { t }
+}
+
+static LN2: f64 = 0.693147180559945309417232121458176568; // 0x3fe62e42, 0xfefa39ef
+static LN2F: f32 = 0.693147180559945309417232121458176568;
+
+// asinh(x) = sign(x) * log(|x| + sqrt(x^2 + 1)), rearranged for each range to keep the precision.
+// The error is up to 1.6 ulp in [0.125, 0.5].
+pub fn asinh(x: f64) -> f64 {
+    let e = (bits(x) >> 52 & 0x7ff) as i32;
+    let sign = bits(x) >> 63 != 0;
+    let x = fabs(x);
+    let y = if e >= 0x3ff + 26 {
+        // |x| >= 2^26, infinite or NaN
+        log(x) + LN2
+    } else if e >= 0x3ff + 1 {
+        // |x| >= 2
+        log(2.0 * x + 1.0 / (sqrt(x * x + 1.0) + x))
+    } else if e >= 0x3ff - 26 {
+        // |x| >= 2^-26
+        log1p(x + x * x / (sqrt(x * x + 1.0) + 1.0))
+    } else {
+        x
+    };
+    if sign { -y } else { y }
+}
+
+pub fn asinhf(x: f32) -> f32 {
+    let i = bitsf(x) & 0x7fffffff;
+    let sign = bitsf(x) >> 31 != 0;
+    let x = from_bitsf(i);
+    let y = if i >= 0x3f800000 + (12 << 23) {
+        // |x| >= 2^12, infinite or NaN
+        logf(x) + LN2F
+    } else if i >= 0x3f800000 + (1 << 23) {
+        // |x| >= 2
+        logf(2.0 * x + 1.0 / (sqrtf(x * x + 1.0) + x))
+    } else if i >= 0x3f800000 - (12 << 23) {
+        // |x| >= 2^-12
+        log1pf(x + x * x / (sqrtf(x * x + 1.0) + 1.0))
+    } else {
+        x
+    };
+    if sign { -y } else { y }
+}
+
+// acosh(x) = log(x + sqrt(x^2 - 1)), rearranged in the same way. The error is up to 2 ulp in
+// [1, 1.125]. Positive x < 1 gives NaN through the logarithms, while negative x is caught first,
+// as unlike in musl the rearranged formula can cancel to a finite value for x < -2.
+pub fn acosh(x: f64) -> f64 {
+    if bits(x) >> 63 != 0 {
+        return (x - x) / 0.0
+    }
+    let e = (bits(x) >> 52 & 0x7ff) as i32;
+    if e < 0x3ff + 1 {
+        // |x| < 2
+        return log1p(x - 1.0 + sqrt((x - 1.0) * (x - 1.0) + 2.0 * (x - 1.0)))
+    }
+    if e < 0x3ff + 26 {
+        // |x| < 2^26
+        return log(2.0 * x - 1.0 / (x + sqrt(x * x - 1.0)))
+    }
+    log(x) + LN2
+}
+
+pub fn acoshf(x: f32) -> f32 {
+    if bitsf(x) >> 31 != 0 {
+        return (x - x) / 0.0
+    }
+    let a = bitsf(x);
+    if a < 0x3f800000 + (1 << 23) {
+        // |x| < 2
+        return log1pf(x - 1.0 + sqrtf((x - 1.0) * (x - 1.0) + 2.0 * (x - 1.0)))
+    }
+    if a < 0x3f800000 + (12 << 23) {
+        // |x| < 2^12
+        return logf(2.0 * x - 1.0 / (x + sqrtf(x * x - 1.0)))
+    }
+    logf(x) + LN2F
+}
+
+// atanh(x) = log((1 + x) / (1 - x)) / 2 = log1p(2x / (1 - x)) / 2, rearranged for |x| < 0.5 where
+// the error is up to 1.7 ulp.
+pub fn atanh(x: f64) -> f64 {
+    let e = (bits(x) >> 52 & 0x7ff) as i32;
+    let sign = bits(x) >> 63 != 0;
+    let y = fabs(x);
+    let y = if e < 0x3ff - 1 {
+        if e < 0x3ff - 32 {
+            y
+        } else {
+            // |x| < 0.5
+            0.5 * log1p(2.0 * y + 2.0 * y * y / (1.0 - y))
+        }
+    } else {
+        0.5 * log1p(2.0 * (y / (1.0 - y)))
+    };
+    if sign { -y } else { y }
+}
+
+pub fn atanhf(x: f32) -> f32 {
+    let u = bitsf(x) & 0x7fffffff;
+    let sign = bitsf(x) >> 31 != 0;
+    let y = from_bitsf(u);
+    let y = if u < 0x3f800000 - (1 << 23) {
+        if u < 0x3f800000 - (32 << 23) {
+            y
+        } else {
+            // |x| < 0.5
+            0.5 * log1pf(2.0 * y + 2.0 * y * y / (1.0 - y))
+        }
+    } else {
+        0.5 * log1pf(2.0 * (y / (1.0 - y)))
+    };
+    if sign { -y } else { y }
+}
+
+// Roots
+
+// A rough cube root to 5 bits from dividing the exponent bits by 3, refined to 23 bits with a
+// polynomial, rounded away from zero to 23 bits and then to 53 bits with one Newton step. The
+// error is below 0.667 ulp.
+pub fn cbrt(x: f64) -> f64 {
+    // (1023 - 1023 / 3 - 0.03306235651) * 2^20
+    static B1: u32 = 715094163;
+    // (1023 - 1023 / 3 - 54 / 3 - 0.03306235651) * 2^20
+    static B2: u32 = 696219795;
+    // a polynomial approximating 1 / cbrt(r) within 2^-23.5 for |r - 1| < 1 / 10
+    static P0: f64 = 1.87595182427177009643; // 0x3ffe03e6, 0x0f61e692
+    static P1: f64 = -1.88497979543377169875; // 0xbffe28e0, 0x92f02420
+    static P2: f64 = 1.621429720105354466140; // 0x3ff9f160, 0x4a49d6c2
+    static P3: f64 = -0.758397934778766047437; // 0xbfe844cb, 0xbee751d9
+    static P4: f64 = 0.145996192886612446982; // 0x3fc2b000, 0xd4e4edd7
+
+    let mut ui = bits(x);
+    let mut hx = high_word(x) & 0x7fffffff;
+    if hx >= 0x7ff00000 {
+        // cbrt(NaN) and cbrt(inf) are themselves
+        return x + x
+    }
+    if hx < 0x00100000 {
+        // zero or subnormal
+        ui = bits(x * from_bits(0x4350000000000000));
+        hx = (ui >> 32) as u32 & 0x7fffffff;
+        if hx == 0 {
+            return x
+        }
+        hx = hx / 3 + B2;
+    } else {
+        hx = hx / 3 + B1;
+    }
+    let t = from_bits(ui & 1 << 63 | (hx as u64) << 32);
+
+    // cbrt(x) = t * cbrt(x / t^3) ~= t * P(t^3 / x)
+    let r = (t * t) * (t / x);
+    let t = t * ((P0 + r * (P1 + r * P2)) + ((r * r) * r) * (P3 + r * P4));
+
+    // round t away from zero to 23 bits, so that it is larger in magnitude than cbrt(x)
+    let t = from_bits((bits(t) + 0x80000000) & 0xffffffffc0000000);
+
+    // one Newton step to 53 bits, where t * t and t + t are exact
+    let s = t * t;
+    let r = x / s;
+    let w = t + t;
+    let r = (r - t) / (w + r);
+    t + t * r
+}
+
+// The rough cube root followed by two Newton steps in double precision, which round perfectly
+// to single precision.
+pub fn cbrtf(x: f32) -> f32 {
+    // (127 - 127 / 3 - 0.03306235651) * 2^23
+    static B1: u32 = 709958130;
+    // (127 - 127 / 3 - 24 / 3 - 0.03306235651) * 2^23
+    static B2: u32 = 642849266;
+
+    let mut ui = bitsf(x);
+    let mut hx = ui & 0x7fffffff;
+    if hx >= 0x7f800000 {
+        return x + x
+    }
+    if hx < 0x00800000 {
+        // zero or subnormal
+        if hx == 0 {
+            return x
+        }
+        ui = bitsf(x * from_bitsf(0x4b800000));
+        hx = ui & 0x7fffffff;
+        hx = hx / 3 + B2;
+    } else {
+        hx = hx / 3 + B1;
+    }
+    let t = from_bitsf(ui & 0x80000000 | hx) as f64;
+    let x = x as f64;
+
+    // solving t * t - x / t = 0 to 16 bits, then 47 bits
+    let r = t * t * t;
+    let t = t * (x + x + r) / (x + r + r);
+    let r = t * t * t;
+    let t = t * (x + x + r) / (x + r + r);
+    t as f32
+}
+
+// The square of `x` as an exact sum `(hi, lo)`, by splitting `x` in halves of 26 bits.
+fn sq(x: f64) -> (f64, f64) {
+    // 2^27 + 1
+    static SPLIT: f64 = 134217729.0;
+
+    let xc = x * SPLIT;
+    let xh = x - xc + xc;
+    let xl = x - xh;
+    let hi = x * x;
+    let lo = xh * xh - hi + 2.0 * xh * xl + xl * xl;
+    (hi, lo)
+}
+
+// sqrt(x^2 + y^2) without undue overflow or underflow, from the exact squares of the arguments
+// scaled into a safe range. The error is below 1 ulp.
+pub fn hypot(x: f64, y: f64) -> f64 {
+    let mut uxi = bits(x) & !0 >> 1;
+    let mut uyi = bits(y) & !0 >> 1;
+    // arrange |x| >= |y|
+    if uxi < uyi {
+        let uti = uxi;
+        uxi = uyi;
+        uyi = uti;
+    }
+    let ex = (uxi >> 52) as i32;
+    let ey = (uyi >> 52) as i32;
+    let mut x = from_bits(uxi);
+    let mut y = from_bits(uyi);
+    // hypot(inf, NaN) is inf
+    if ey == 0x7ff {
+        return y
+    }
+    if ex == 0x7ff || uyi == 0 {
+        return x
+    }
+    // hypot(x, y) ~= x + y * y / x / 2 for small y / x
+    if ex - ey > 64 {
+        return x + y
+    }
+    // the high squares must not overflow and the low ones not underflow
+    let mut z = 1.0;
+    if ex > 0x3ff + 510 {
+        z = from_bits(0x6bb0000000000000);
+        x *= from_bits(0x1430000000000000);
+        y *= from_bits(0x1430000000000000);
+    } else if ey < 0x3ff - 450 {
+        z = from_bits(0x1430000000000000);
+        x *= from_bits(0x6bb0000000000000);
+        y *= from_bits(0x6bb0000000000000);
+    }
+    let (hx, lx) = sq(x);
+    let (hy, ly) = sq(y);
+    z * sqrt(ly + lx + hy + hx)
+}
+
+// The same with the squares computed exactly in double precision.
+pub fn hypotf(x: f32, y: f32) -> f32 {
+    let mut uxi = bitsf(x) & !0 >> 1;
+    let mut uyi = bitsf(y) & !0 >> 1;
+    if uxi < uyi {
+        let uti = uxi;
+        uxi = uyi;
+        uyi = uti;
+    }
+    let mut x = from_bitsf(uxi);
+    let mut y = from_bitsf(uyi);
+    if uyi == 0xff << 23 {
+        return y
+    }
+    if uxi >= 0xff << 23 || uyi == 0 || uxi - uyi >= 25 << 23 {
+        return x + y
+    }
+    let mut z = 1.0;
+    if uxi >= (0x7f + 60) << 23 {
+        z = from_bitsf(0x6c800000);
+        x *= from_bitsf(0x12800000);
+        y *= from_bitsf(0x12800000);
+    } else if uyi < (0x7f - 60) << 23 {
+        z = from_bitsf(0x12800000);
+        x *= from_bitsf(0x6c800000);
+        y *= from_bitsf(0x6c800000);
+    }
+    z * sqrtf((x as f64 * x as f64 + y as f64 * y as f64) as f32)
+}
+
+// Error functions
+
+// erf(x) / x - 1 for |x| < 0.84375, as a rational function of `z = x^2` within 2^-57.90.
+fn erf_r(z: f64) -> f64 {
+    static PP0: f64 = 1.28379167095512558561e-01; // 0x3FC06EBA, 0x8214DB68
+    static PP1: f64 = -3.25042107247001499370e-01; // 0xBFD4CD7D, 0x691CB913
+    static PP2: f64 = -2.84817495755985104766e-02; // 0xBF9D2A51, 0xDBD7194F
+    static PP3: f64 = -5.77027029648944159157e-03; // 0xBF77A291, 0x236668E4
+    static PP4: f64 = -2.37630166566501626084e-05; // 0xBEF8EAD6, 0x120016AC
+    static QQ1: f64 = 3.97917223959155352819e-01; // 0x3FD97779, 0xCDDADC09
+    static QQ2: f64 = 6.50222499887672944485e-02; // 0x3FB0A54C, 0x5536CEBA
+    static QQ3: f64 = 5.08130628187576562776e-03; // 0x3F74D022, 0xC4D36B0F
+    static QQ4: f64 = 1.32494738004321644526e-04; // 0x3F215DC9, 0x221C1A10
+    static QQ5: f64 = -3.96022827877536812320e-06; // 0xBED09C43, 0x42A26120
+    let r = PP0 + z * (PP1 + z * (PP2 + z * (PP3 + z * PP4)));
+    let s = 1.0 + z * (QQ1 + z * (QQ2 + z * (QQ3 + z * (QQ4 + z * QQ5))));
+    r / s
+}
+
+// erfc(|x|) for 0.84375 <= |x| < 1.25, from erf(1 + s) - c as a rational function of s within
+// 2^-59.06, where c is erf(1) rounded to single precision.
+fn erfc1(x: f64) -> f64 {
+    static ERX: f64 = 8.45062911510467529297e-01; // 0x3FEB0AC1, 0x60000000
+    static PA0: f64 = -2.36211856075265944077e-03; // 0xBF6359B8, 0xBEF77538
+    static PA1: f64 = 4.14856118683748331666e-01; // 0x3FDA8D00, 0xAD92B34D
+    static PA2: f64 = -3.72207876035701323847e-01; // 0xBFD7D240, 0xFBB8C3F1
+    static PA3: f64 = 3.18346619901161753674e-01; // 0x3FD45FCA, 0x805120E4
+    static PA4: f64 = -1.10894694282396677476e-01; // 0xBFBC6398, 0x3D3E28EC
+    static PA5: f64 = 3.54783043256182359371e-02; // 0x3FA22A36, 0x599795EB
+    static PA6: f64 = -2.16637559486879084300e-03; // 0xBF61BF38, 0x0A96073F
+    static QA1: f64 = 1.06420880400844228286e-01; // 0x3FBB3E66, 0x18EEE323
+    static QA2: f64 = 5.40397917702171048937e-01; // 0x3FE14AF0, 0x92EB6F33
+    static QA3: f64 = 7.18286544141962662868e-02; // 0x3FB2635C, 0xD99FE9A7
+    static QA4: f64 = 1.26171219808761642112e-01; // 0x3FC02660, 0xE763351F
+    static QA5: f64 = 1.36370839120290507362e-02; // 0x3F8BEDC2, 0x6B51DD1C
+    static QA6: f64 = 1.19844998467991074170e-02; // 0x3F888B54, 0x5735151D
+
+    let s = fabs(x) - 1.0;
+    let p = PA0 + s * (PA1 + s * (PA2 + s * (PA3 + s * (PA4 + s * (PA5 + s * PA6)))));
+    let q = 1.0 + s * (QA1 + s * (QA2 + s * (QA3 + s * (QA4 + s * (QA5 + s * QA6)))));
+    1.0 - ERX - p / q
+}
+
+// erfc(|x|) for 0.84375 <= |x| < 28. Beyond 1.25 this is exp(-x * x - 0.5625 + R / S) / x with a
+// rational function of 1 / x^2 within 2^-61.52, where -x * x is split as -z * z + (z - x) * (z + x)
+// with z holding the upper half of x to keep it exact.
+fn erfc2(ix: u32, x: f64) -> f64 {
+    static RA0: f64 = -9.86494403484714822705e-03; // 0xBF843412, 0x600D6435
+    static RA1: f64 = -6.93858572707181764372e-01; // 0xBFE63416, 0xE4BA7360
+    static RA2: f64 = -1.05586262253232909814e+01; // 0xC0251E04, 0x41B0E726
+    static RA3: f64 = -6.23753324503260060396e+01; // 0xC04F300A, 0xE4CBA38D
+    static RA4: f64 = -1.62396669462573470355e+02; // 0xC0644CB1, 0x84282266
+    static RA5: f64 = -1.84605092906711035994e+02; // 0xC067135C, 0xEBCCABB2
+    static RA6: f64 = -8.12874355063065934246e+01; // 0xC0545265, 0x57E4D2F2
+    static RA7: f64 = -9.81432934416914548592e+00; // 0xC023A0EF, 0xC69AC25C
+    static SA1: f64 = 1.96512716674392571292e+01; // 0x4033A6B9, 0xBD707687
+    static SA2: f64 = 1.37657754143519042600e+02; // 0x4061350C, 0x526AE721
+    static SA3: f64 = 4.34565877475229228821e+02; // 0x407B290D, 0xD58A1A71
+    static SA4: f64 = 6.45387271733267880336e+02; // 0x40842B19, 0x21EC2868
+    static SA5: f64 = 4.29008140027567833386e+02; // 0x407AD021, 0x57700314
+    static SA6: f64 = 1.08635005541779435134e+02; // 0x405B28A3, 0xEE48AE2C
+    static SA7: f64 = 6.57024977031928170135e+00; // 0x401A47EF, 0x8E484A93
+    static SA8: f64 = -6.04244152148580987438e-02; // 0xBFAEEFF2, 0xEE749A62
+    static RB0: f64 = -9.86494292470009928597e-03; // 0xBF843412, 0x39E86F4A
+    static RB1: f64 = -7.99283237680523006574e-01; // 0xBFE993BA, 0x70C285DE
+    static RB2: f64 = -1.77579549177547519889e+01; // 0xC031C209, 0x555F995A
+    static RB3: f64 = -1.60636384855821916062e+02; // 0xC064145D, 0x43C5ED98
+    static RB4: f64 = -6.37566443368389627722e+02; // 0xC083EC88, 0x1375F228
+    static RB5: f64 = -1.02509513161107724954e+03; // 0xC0900461, 0x6A2E5992
+    static RB6: f64 = -4.83519191608651397019e+02; // 0xC07E384E, 0x9BDC383F
+    static SB1: f64 = 3.03380607434824582924e+01; // 0x403E568B, 0x261D5190
+    static SB2: f64 = 3.25792512996573918826e+02; // 0x40745CAE, 0x221B9F0A
+    static SB3: f64 = 1.53672958608443695994e+03; // 0x409802EB, 0x189D5118
+    static SB4: f64 = 3.19985821950859553908e+03; // 0x40A8FFB7, 0x688C246A
+    static SB5: f64 = 2.55305040643316442583e+03; // 0x40A3F219, 0xCEDF3BE6
+    static SB6: f64 = 4.74528541206955367215e+02; // 0x407DA874, 0xE79FE763
+    static SB7: f64 = -2.24409524465858183362e+01; // 0xC03670E2, 0x42712D62
+
+    if ix < 0x3ff40000 {
+        // |x| < 1.25
+        return erfc1(x)
+    }
+    let x = fabs(x);
+    let s = 1.0 / (x * x);
+    let (r, big_s) = if ix < 0x4006db6d {
+        // |x| < 1 / 0.35
+        (RA0 + s * (RA1 + s * (RA2 + s * (RA3 + s * (RA4 + s * (RA5 + s * (RA6 + s * RA7)))))),
+         1.0 + s * (SA1 + s * (SA2 + s * (SA3 + s * (SA4 + s * (SA5
+             + s * (SA6 + s * (SA7 + s * SA8))))))))
+    } else {
+        (RB0 + s * (RB1 + s * (RB2 + s * (RB3 + s * (RB4 + s * (RB5 + s * RB6))))),
+         1.0 + s * (SB1 + s * (SB2 + s * (SB3 + s * (SB4 + s * (SB5 + s * (SB6 + s * SB7)))))))
+    };
+    let z = with_low_word_zero(x);
+    exp(-z * z - 0.5625) * exp((z - x) * (z + x) + r / big_s) / x
+}
+
+// The error below 1 ulp comes from the rational approximations on [0, 0.84375) and
+// [0.84375, 1.25) and from the asymptotic expansion of erfc beyond.
+pub fn erf(x: f64) -> f64 {
+    // 2 / sqrt(pi) - 1, scaled by 8
+    static EFX8: f64 = 1.02703333676410069053e+00; // 0x3FF06EBA, 0x8214DB69
+
+    let hx = high_word(x);
+    let sign = hx >> 31 != 0;
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x7ff00000 {
+        // erf(NaN) is NaN and erf(+-inf) is +-1
+        return (if sign { -1.0 } else { 1.0 }) + 1.0 / x
+    }
+    if ix < 0x3feb0000 {
+        // |x| < 0.84375
+        if ix < 0x3e300000 {
+            // |x| < 2^-28, scaled to avoid underflow
+            return 0.125 * (8.0 * x + EFX8 * x)
+        }
+        return x + x * erf_r(x * x)
+    }
+    let y = if ix < 0x40180000 {
+        // |x| < 6
+        1.0 - erfc2(ix, x)
+    } else {
+        1.0 - from_bits(0x0010000000000000)
+    };
+    if sign { -y } else { y }
+}
+
+// Computed directly rather than as 1 - erf(x), so the result keeps its precision where erf(x)
+// approaches 1.
+pub fn erfc(x: f64) -> f64 {
+    let hx = high_word(x);
+    let sign = hx >> 31 != 0;
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x7ff00000 {
+        // erfc(NaN) is NaN, erfc(inf) is 0 and erfc(-inf) is 2
+        return (if sign { 2.0 } else { 0.0 }) + 1.0 / x
+    }
+    if ix < 0x3feb0000 {
+        // |x| < 0.84375
+        if ix < 0x3c700000 {
+            // |x| < 2^-56
+            return 1.0 - x
+        }
+        let y = erf_r(x * x);
+        if sign || ix < 0x3fd00000 {
+            // x < 1 / 4
+            return 1.0 - (x + x * y)
+        }
+        return 0.5 - (x - 0.5 + x * y)
+    }
+    if ix < 0x403c0000 {
+        // |x| < 28
+        return if sign { 2.0 - erfc2(ix, x) } else { erfc2(ix, x) }
+    }
+    let tiny = from_bits(0x0010000000000000);
+    if sign { 2.0 - tiny } else { tiny * tiny }
+}
+
+fn erf_rf(z: f32) -> f32 {
+    static PP0: f32 = 1.2837916613e-01; // 0x3e0375d4
+    static PP1: f32 = -3.2504209876e-01; // 0xbea66beb
+    static PP2: f32 = -2.8481749818e-02; // 0xbce9528f
+    static PP3: f32 = -5.7702702470e-03; // 0xbbbd1489
+    static PP4: f32 = -2.3763017452e-05; // 0xb7c756b1
+    static QQ1: f32 = 3.9791721106e-01; // 0x3ecbbbce
+    static QQ2: f32 = 6.5022252500e-02; // 0x3d852a63
+    static QQ3: f32 = 5.0813062117e-03; // 0x3ba68116
+    static QQ4: f32 = 1.3249473704e-04; // 0x390aee49
+    static QQ5: f32 = -3.9602282413e-06; // 0xb684e21a
+    let r = PP0 + z * (PP1 + z * (PP2 + z * (PP3 + z * PP4)));
+    let s = 1.0 + z * (QQ1 + z * (QQ2 + z * (QQ3 + z * (QQ4 + z * QQ5))));
+    r / s
+}
+
+fn erfc1f(x: f32) -> f32 {
+    static ERX: f32 = 8.4506291151e-01; // 0x3f58560b
+    static PA0: f32 = -2.3621185683e-03; // 0xbb1acdc6
+    static PA1: f32 = 4.1485610604e-01; // 0x3ed46805
+    static PA2: f32 = -3.7220788002e-01; // 0xbebe9208
+    static PA3: f32 = 3.1834661961e-01; // 0x3ea2fe54
+    static PA4: f32 = -1.1089469492e-01; // 0xbde31cc2
+    static PA5: f32 = 3.5478305072e-02; // 0x3d1151b3
+    static PA6: f32 = -2.1663755178e-03; // 0xbb0df9c0
+    static QA1: f32 = 1.0642088205e-01; // 0x3dd9f331
+    static QA2: f32 = 5.4039794207e-01; // 0x3f0a5785
+    static QA3: f32 = 7.1828655899e-02; // 0x3d931ae7
+    static QA4: f32 = 1.2617121637e-01; // 0x3e013307
+    static QA5: f32 = 1.3637083583e-02; // 0x3c5f6e13
+    static QA6: f32 = 1.1984500103e-02; // 0x3c445aa3
+
+    let s = fabsf(x) - 1.0;
+    let p = PA0 + s * (PA1 + s * (PA2 + s * (PA3 + s * (PA4 + s * (PA5 + s * PA6)))));
+    let q = 1.0 + s * (QA1 + s * (QA2 + s * (QA3 + s * (QA4 + s * (QA5 + s * QA6)))));
+    1.0 - ERX - p / q
+}
+
+// The same splitting of -x * x with z holding the upper 12 bits of x.
+fn erfc2f(ix: u32, x: f32) -> f32 {
+    static RA0: f32 = -9.8649440333e-03; // 0xbc21a093
+    static RA1: f32 = -6.9385856390e-01; // 0xbf31a0b7
+    static RA2: f32 = -1.0558626175e+01; // 0xc128f022
+    static RA3: f32 = -6.2375331879e+01; // 0xc2798057
+    static RA4: f32 = -1.6239666748e+02; // 0xc322658c
+    static RA5: f32 = -1.8460508728e+02; // 0xc3389ae7
+    static RA6: f32 = -8.1287437439e+01; // 0xc2a2932b
+    static RA7: f32 = -9.8143291473e+00; // 0xc11d077e
+    static SA1: f32 = 1.9651271820e+01; // 0x419d35ce
+    static SA2: f32 = 1.3765776062e+02; // 0x4309a863
+    static SA3: f32 = 4.3456588745e+02; // 0x43d9486f
+    static SA4: f32 = 6.4538726807e+02; // 0x442158c9
+    static SA5: f32 = 4.2900814819e+02; // 0x43d6810b
+    static SA6: f32 = 1.0863500214e+02; // 0x42d9451f
+    static SA7: f32 = 6.5702495575e+00; // 0x40d23f7c
+    static SA8: f32 = -6.0424413532e-02; // 0xbd777f97
+    static RB0: f32 = -9.8649431020e-03; // 0xbc21a092
+    static RB1: f32 = -7.9928326607e-01; // 0xbf4c9dd4
+    static RB2: f32 = -1.7757955551e+01; // 0xc18e104b
+    static RB3: f32 = -1.6063638306e+02; // 0xc320a2ea
+    static RB4: f32 = -6.3756646729e+02; // 0xc41f6441
+    static RB5: f32 = -1.0250950928e+03; // 0xc480230b
+    static RB6: f32 = -4.8351919556e+02; // 0xc3f1c275
+    static SB1: f32 = 3.0338060379e+01; // 0x41f2b459
+    static SB2: f32 = 3.2579251099e+02; // 0x43a2e571
+    static SB3: f32 = 1.5367296143e+03; // 0x44c01759
+    static SB4: f32 = 3.1998581543e+03; // 0x4547fdbb
+    static SB5: f32 = 2.5530502930e+03; // 0x451f90ce
+    static SB6: f32 = 4.7452853394e+02; // 0x43ed43a7
+    static SB7: f32 = -2.2440952301e+01; // 0xc1b38712
+
+    if ix < 0x3fa00000 {
+        // |x| < 1.25
+        return erfc1f(x)
+    }
+    let x = fabsf(x);
+    let s = 1.0 / (x * x);
+    let (r, big_s) = if ix < 0x4036db6d {
+        // |x| < 1 / 0.35
+        (RA0 + s * (RA1 + s * (RA2 + s * (RA3 + s * (RA4 + s * (RA5 + s * (RA6 + s * RA7)))))),
+         1.0 + s * (SA1 + s * (SA2 + s * (SA3 + s * (SA4 + s * (SA5
+             + s * (SA6 + s * (SA7 + s * SA8))))))))
+    } else {
+        (RB0 + s * (RB1 + s * (RB2 + s * (RB3 + s * (RB4 + s * (RB5 + s * RB6))))),
+         1.0 + s * (SB1 + s * (SB2 + s * (SB3 + s * (SB4 + s * (SB5 + s * (SB6 + s * SB7)))))))
+    };
+    let z = from_bitsf(bitsf(x) & 0xffffe000);
+    expf(-z * z - 0.5625) * expf((z - x) * (z + x) + r / big_s) / x
+}
+
+pub fn erff(x: f32) -> f32 {
+    static EFX8: f32 = 1.0270333290e+00; // 0x3f8375d4
+
+    let hx = bitsf(x);
+    let sign = hx >> 31 != 0;
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x7f800000 {
+        return (if sign { -1.0 } else { 1.0 }) + 1.0 / x
+    }
+    if ix < 0x3f580000 {
+        // |x| < 0.84375
+        if ix < 0x31800000 {
+            // |x| < 2^-28
+            return 0.125 * (8.0 * x + EFX8 * x)
+        }
+        return x + x * erf_rf(x * x)
+    }
+    let y = if ix < 0x40c00000 {
+        // |x| < 6
+        1.0 - erfc2f(ix, x)
+    } else {
+        1.0 - from_bitsf(0x03800000)
+    };
+    if sign { -y } else { y }
+}
+
+pub fn erfcf(x: f32) -> f32 {
+    let hx = bitsf(x);
+    let sign = hx >> 31 != 0;
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x7f800000 {
+        return (if sign { 2.0 } else { 0.0 }) + 1.0 / x
+    }
+    if ix < 0x3f580000 {
+        // |x| < 0.84375
+        if ix < 0x23800000 {
+            // |x| < 2^-56
+            return 1.0 - x
+        }
+        let y = erf_rf(x * x);
+        if sign || ix < 0x3e800000 {
+            // x < 1 / 4
+            return 1.0 - (x + x * y)
+        }
+        return 0.5 - (x - 0.5 + x * y)
+    }
+    if ix < 0x41e00000 {
+        // |x| < 28
+        return if sign { 2.0 - erfc2f(ix, x) } else { erfc2f(ix, x) }
+    }
+    let tiny = from_bitsf(0x03800000);
+    if sign { 2.0 - tiny } else { tiny * tiny }
+}
+
+// Gamma function
+
+static PI: f64 = 3.141592653589793238462643383279502884;
+
+// sin(pi * x) for x > 2^-100, reduced to |x| <= 1 / 4 through x mod 2. The sign is arbitrary when
+// the result is zero.
+fn sinpi(x: f64) -> f64 {
+    let x = x * 0.5;
+    let x = 2.0 * (x - floor(x));
+    let n = ((4.0 * x) as int + 1) / 2;
+    let x = (x - n as f64 * 0.5) * PI;
+    match n {
+        1 => k_cos(x, 0.0),
+        2 => k_sin(-x, 0.0, 0),
+        3 => -k_cos(x, 0.0),
+        _ => k_sin(x, 0.0, 0)
+    }
+}
+
+// The number of terms of the Lanczos sum, whose parameter g is 6.024680040776729583740234375.
+static LANCZOS_N: uint = 12;
+// g - 0.5
+static GMHALF: f64 = 5.524680040776729583740234375;
+
+// The Lanczos sum S(x) for x > 0, as a ratio of polynomials. Large x are evaluated in 1 / x to
+// avoid overflow.
+fn lanczos_sum(x: f64) -> f64 {
+    static SNUM: [f64, ..13] = [
+        23531376880.410759688572007674451636754734846804940,
+        42919803642.649098768957899047001988850926355848959,
+        35711959237.355668049440185451547166705960488635843,
+        17921034426.037209699919755754458931112671403265390,
+        6039542586.3520280050642916443072979210699388420708,
+        1439720407.3117216736632230727949123939715485786772,
+        248874557.86205415651146038641322942321632125127801,
+        31426415.585400194380614231628318205362874684987640,
+        2876370.6289353724412254090516208496135991145378768,
+        186056.26539522349504029498971604569928220784236328,
+        8071.6720023658162106380029022722506138218516325024,
+        210.82427775157934587250973392071336271166969580291,
+        2.5066282746310002701649081771338373386264310793408
+    ];
+    static SDEN: [f64, ..13] = [
+        0.0, 39916800.0, 120543840.0, 150917976.0, 105258076.0, 45995730.0, 13339535.0,
+        2637558.0, 357423.0, 32670.0, 1925.0, 66.0, 1.0
+    ];
+
+    let mut num = 0.0;
+    let mut den = 0.0;
+    if x < 8.0 {
+        let mut i = LANCZOS_N + 1;
+        while i > 0 {
+            i -= 1;
+            num = num * x + SNUM[i];
+            den = den * x + SDEN[i];
+        }
+    } else {
+        for i in range(0, LANCZOS_N + 1) {
+            num = num / x + SNUM[i];
+            den = den / x + SDEN[i];
+        }
+    }
+    num / den
+}
+
+// The Lanczos approximation
+//
+//     gamma(x) = (x + g - 0.5)^(x - 0.5) * S(x) / exp(x + g - 0.5)
+//
+// with a correction for the rounding error of x + g - 0.5, and the reflection formula
+// gamma(x) * gamma(-x) = -pi / (x * sin(pi * x)) for negative x. Factorials are exact. The error
+// is within a few ulp for small arguments, and grows with |x| through the power.
+pub fn tgamma(x: f64) -> f64 {
+    // n! for the integers n below 23
+    static FACT: [f64, ..23] = [
+        1.0, 1.0, 2.0, 6.0, 24.0, 120.0, 720.0, 5040.0, 40320.0, 362880.0, 3628800.0,
+        39916800.0, 479001600.0, 6227020800.0, 87178291200.0, 1307674368000.0,
+        20922789888000.0, 355687428096000.0, 6402373705728000.0, 121645100408832000.0,
+        2432902008176640000.0, 51090942171709440000.0, 1124000727777607680000.0
+    ];
+
+    let hx = high_word(x);
+    let sign = hx >> 31 != 0;
+    let ix = hx & 0x7fffffff;
+    if ix >= 0x7ff00000 {
+        // tgamma(NaN) is NaN, tgamma(inf) is inf and tgamma(-inf) is NaN
+        return x + from_bits(0x7ff0000000000000)
+    }
+    if ix < (0x3ff - 54) << 20 {
+        // |x| < 2^-54, where tgamma(x) ~= 1 / x
+        return 1.0 / x
+    }
+
+    if x == floor(x) {
+        // the poles at the negative integers
+        if sign {
+            return 0.0 / 0.0
+        }
+        if x <= 23.0 {
+            return FACT[x as uint - 1]
+        }
+    }
+
+    if ix >= 0x40670000 {
+        // |x| >= 184, so tgamma(x) overflows for positive x and underflows for negative x, with
+        // the sign alternating between the poles
+        if sign {
+            return if floor(x) * 0.5 == floor(x * 0.5) { 0.0 } else { -0.0 }
+        }
+        return x * from_bits(0x7fe0000000000000)
+    }
+
+    let absx = fabs(x);
+    let y = absx + GMHALF;
+    let mut dy = if absx > GMHALF { y - absx - GMHALF } else { y - GMHALF - absx };
+    let mut z = absx - 0.5;
+    let mut r = lanczos_sum(absx) * exp(-y);
+    if sign {
+        // sinpi(absx) is not zero, as the integers are handled above
+        r = -PI / (sinpi(absx) * absx * r);
+        dy = -dy;
+        z = -z;
+    }
+    r += dy * (GMHALF + 0.5) * r / y;
+    let z = pow(y, 0.5 * z);
+    r * z * z
+}
+
+// Computed in double precision.
+pub fn tgammaf(x: f32) -> f32 {
+    tgamma(x as f64) as f32
+}
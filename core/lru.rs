@@ -34,10 +34,11 @@
 //! ```
 
 use container::Container;
-use hash::{HashMap, HashBytes};
+use hash::{HashMap, HashBytes, FROM_ITERATOR_K0, FROM_ITERATOR_K1};
+use iter::{Iterator, FromIterator, Extendable, range};
 use mem::transmute;
 use option::{Some, None, Option};
-use cmp::Eq;
+use cmp::{Eq, max};
 use ops::Drop;
 
 struct KeyRef<K> { k: *K }
@@ -174,10 +175,8 @@ impl<K: HashBytes + Eq, V> LruCache<K, V> {
     /// Change the number of key-value pairs the cache can hold. Remove
     /// least-recently-used key-value pairs if necessary.
     pub fn change_capacity(&mut self, capacity: uint) {
-        let mut i = capacity;
-        while i < self.len() {
+        for _ in range(capacity, self.len()) {
             self.remove_lru();
-            i += 1;
         }
         self.max_size = capacity;
     }
@@ -222,6 +221,32 @@ impl<K: HashBytes + Eq, V> Container for LruCache<K, V> {
     }
 }
 
+impl<K: HashBytes + Eq, V> FromIterator<(K, V)> for LruCache<K, V> {
+    /// Build a cache with the fixed `hash::FROM_ITERATOR_K0` and `hash::FROM_ITERATOR_K1` keys,
+    /// holding every distinct key yielded. The capacity is the number of pairs in the cache, but
+    /// at least the lower bound of the iterator's size hint and never zero.
+    fn from_iterator<T: Iterator<(K, V)>>(iterator: &mut T) -> LruCache<K, V> {
+        let (lower, _) = iterator.size_hint();
+        let mut cache = LruCache::new(FROM_ITERATOR_K0, FROM_ITERATOR_K1, lower);
+        cache.max_size = !0;
+        for (k, v) in *iterator {
+            cache.put(k, v);
+        }
+        cache.max_size = max(max(cache.len(), lower), 1);
+        cache
+    }
+}
+
+impl<K: HashBytes + Eq, V> Extendable<(K, V)> for LruCache<K, V> {
+    /// Put each pair into the cache in turn. The capacity is unchanged, so the least-recently-used
+    /// pairs are evicted if it is exceeded.
+    fn extend<T: Iterator<(K, V)>>(&mut self, iterator: &mut T) {
+        for (k, v) in *iterator {
+            self.put(k, v);
+        }
+    }
+}
+
 #[unsafe_destructor]
 impl<K, V> Drop for LruCache<K, V> {
     fn drop(&mut self) {
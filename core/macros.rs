@@ -53,3 +53,48 @@ macro_rules! thread_local(
         }
     }
 )
+
+// Unwrap an `Ok` value, or return early from the enclosing function with the `Err` value. The `Ok`
+// and `Err` variants of `core::result::Result` must be in scope.
+#[macro_export]
+macro_rules! try(
+    ($e:expr) => {
+        match $e {
+            Ok(x) => x,
+            Err(e) => return Err(e)
+        }
+    }
+)
+
+// Write the arguments to a `core::io::Writer` according to the format string, as described in
+// `core::fmt`. Evaluates to a `core::fmt::Result`.
+#[macro_export]
+macro_rules! write(
+    ($dst:expr, $fmt:expr $(, $arg:expr)*) => {
+        ::core::fmt::write($dst, $fmt, &[$(&$arg as &::core::fmt::Show),*])
+    }
+)
+
+// Format the arguments into a new `core::string::String`. Requires `--cfg libc`.
+#[macro_export]
+macro_rules! format(
+    ($fmt:expr $(, $arg:expr)*) => {
+        ::core::fmt::format($fmt, &[$(&$arg as &::core::fmt::Show),*])
+    }
+)
+
+// Write the arguments to standard output. Requires `--cfg libc`.
+#[macro_export]
+macro_rules! print(
+    ($fmt:expr $(, $arg:expr)*) => {
+        ::core::fmt::print($fmt, &[$(&$arg as &::core::fmt::Show),*])
+    }
+)
+
+// Write the arguments to standard output, followed by a newline. Requires `--cfg libc`.
+#[macro_export]
+macro_rules! println(
+    ($fmt:expr $(, $arg:expr)*) => {
+        ::core::fmt::print(concat!($fmt, "\n"), &[$(&$arg as &::core::fmt::Show),*])
+    }
+)
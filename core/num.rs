@@ -0,0 +1,1017 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+//! Numeric traits
+
+use char;
+use clone::Clone;
+use cmp::{Eq, Ord, Ordering, Equal, Less};
+use container::Container;
+use fail::abort;
+use from_str::FromStr;
+use ops::{Add, Sub, Mul, Div, Neg};
+use option::{Option, Some, None};
+use iter::range;
+use slice::{iter, mut_slice_to, slice_from};
+use str::{as_bytes, from_utf8_unchecked};
+
+/// Types with an additive identity.
+pub trait Zero {
+    fn zero() -> Self;
+    fn is_zero(&self) -> bool;
+}
+
+/// Types with a multiplicative identity.
+pub trait One {
+    fn one() -> Self;
+}
+
+macro_rules! zero_one_impl(
+    ($t:ty) => {
+        impl Zero for $t {
+            #[inline(always)]
+            fn zero() -> $t { 0 }
+
+            #[inline(always)]
+            fn is_zero(&self) -> bool { *self == 0 }
+        }
+
+        impl One for $t {
+            #[inline(always)]
+            fn one() -> $t { 1 }
+        }
+    }
+)
+
+zero_one_impl!(int)
+zero_one_impl!(i8)
+zero_one_impl!(i16)
+zero_one_impl!(i32)
+zero_one_impl!(i64)
+
+zero_one_impl!(uint)
+zero_one_impl!(u8)
+zero_one_impl!(u16)
+zero_one_impl!(u32)
+zero_one_impl!(u64)
+
+macro_rules! float_zero_one_impl(
+    ($t:ty) => {
+        impl Zero for $t {
+            #[inline(always)]
+            fn zero() -> $t { 0.0 }
+
+            #[inline(always)]
+            fn is_zero(&self) -> bool { *self == 0.0 }
+        }
+
+        impl One for $t {
+            #[inline(always)]
+            fn one() -> $t { 1.0 }
+        }
+    }
+)
+
+float_zero_one_impl!(f32)
+float_zero_one_impl!(f64)
+
+/// Primitive integer types.
+///
+/// Arithmetic with the operators wraps on overflow, as do `pow` and the `wrapping_*` methods.
+pub trait Int: Clone + Eq + Ord + Zero + One + Add<Self, Self> + Sub<Self, Self>
+             + Mul<Self, Self> {
+    /// Add, returning the wrapped result and whether an overflow occurred.
+    fn add_with_overflow(&self, other: &Self) -> (Self, bool);
+
+    /// Subtract, returning the wrapped result and whether an overflow occurred.
+    fn sub_with_overflow(&self, other: &Self) -> (Self, bool);
+
+    /// Multiply, returning the wrapped result and whether an overflow occurred.
+    fn mul_with_overflow(&self, other: &Self) -> (Self, bool);
+
+    /// Convert to `u64` like an `as` cast, sign-extending signed types.
+    fn to_u64(&self) -> u64;
+
+    /// Convert from `u64` like an `as` cast, truncating to the width of the type.
+    fn from_u64(x: u64) -> Self;
+
+    /// Return the smallest value of the type.
+    fn min_value() -> Self;
+
+    /// Return the largest value of the type.
+    fn max_value() -> Self;
+
+    /// Return the width of the type in bits. The value itself is ignored.
+    fn bits(&self) -> uint;
+
+    /// Return true if the type is signed. The value itself is ignored.
+    fn is_signed(&self) -> bool;
+
+    /// Add, returning `None` on overflow.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    /// Subtract, returning `None` on overflow.
+    fn checked_sub(&self, other: &Self) -> Option<Self>;
+
+    /// Multiply, returning `None` on overflow.
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+
+    /// Divide, returning `None` if `other` is zero or the quotient overflows (the minimum of a
+    /// signed type divided by `-1`).
+    fn checked_div(&self, other: &Self) -> Option<Self>;
+
+    /// Take the remainder, returning `None` if `other` is zero or the division overflows.
+    fn checked_rem(&self, other: &Self) -> Option<Self>;
+
+    /// Negate, returning `None` on overflow. Only zero can be negated for unsigned types.
+    fn checked_neg(&self) -> Option<Self>;
+
+    /// Shift left, returning `None` if `n` is not less than the number of bits.
+    fn checked_shl(&self, n: uint) -> Option<Self>;
+
+    /// Shift right (arithmetically for signed types), returning `None` if `n` is not less than the
+    /// number of bits.
+    fn checked_shr(&self, n: uint) -> Option<Self>;
+
+    /// Raise to the power of `exp`, returning `None` on overflow.
+    fn checked_pow(&self, exp: uint) -> Option<Self>;
+
+    /// Add, clamping the result to the bounds of the type.
+    fn saturating_add(&self, other: &Self) -> Self;
+
+    /// Subtract, clamping the result to the bounds of the type.
+    fn saturating_sub(&self, other: &Self) -> Self;
+
+    /// Multiply, clamping the result to the bounds of the type.
+    fn saturating_mul(&self, other: &Self) -> Self;
+
+    /// Add, wrapping around at the bounds of the type.
+    fn wrapping_add(&self, other: &Self) -> Self;
+
+    /// Subtract, wrapping around at the bounds of the type.
+    fn wrapping_sub(&self, other: &Self) -> Self;
+
+    /// Multiply, wrapping around at the bounds of the type.
+    fn wrapping_mul(&self, other: &Self) -> Self;
+
+    /// Negate, wrapping around at the bounds of the type.
+    fn wrapping_neg(&self) -> Self;
+
+    /// Shift left by `n` modulo the number of bits.
+    fn wrapping_shl(&self, n: uint) -> Self;
+
+    /// Shift right by `n` modulo the number of bits.
+    fn wrapping_shr(&self, n: uint) -> Self;
+
+    /// Raise to the power of `exp` by squaring, wrapping on overflow.
+    fn pow(&self, exp: uint) -> Self;
+
+    /// Return the number of one bits.
+    fn count_ones(&self) -> uint;
+
+    /// Return the number of zero bits.
+    fn count_zeros(&self) -> uint;
+
+    /// Return the number of zero bits above the highest one bit.
+    fn leading_zeros(&self) -> uint;
+
+    /// Return the number of zero bits below the lowest one bit.
+    fn trailing_zeros(&self) -> uint;
+
+    /// Rotate the bits left by `n` modulo the number of bits.
+    fn rotate_left(&self, n: uint) -> Self;
+
+    /// Rotate the bits right by `n` modulo the number of bits.
+    fn rotate_right(&self, n: uint) -> Self;
+
+    /// Reverse the order of the bytes.
+    fn swap_bytes(&self) -> Self;
+
+    /// Convert from the target's byte order to big endian.
+    fn to_be(&self) -> Self;
+
+    /// Convert from the target's byte order to little endian.
+    fn to_le(&self) -> Self;
+
+    /// Convert from big endian to the target's byte order.
+    fn from_be(x: Self) -> Self;
+
+    /// Convert from little endian to the target's byte order.
+    fn from_le(x: Self) -> Self;
+
+    /// Write the big endian representation to the start of `dst`. Calls `abort` if `dst` is
+    /// shorter than the type.
+    fn write_be_bytes(&self, dst: &mut [u8]);
+
+    /// Write the little endian representation to the start of `dst`. Calls `abort` if `dst` is
+    /// shorter than the type.
+    fn write_le_bytes(&self, dst: &mut [u8]);
+
+    /// Read a value from the big endian representation at the start of `src`. Calls `abort` if
+    /// `src` is shorter than the type.
+    fn read_be_bytes(src: &[u8]) -> Self;
+
+    /// Read a value from the little endian representation at the start of `src`. Calls `abort` if
+    /// `src` is shorter than the type.
+    fn read_le_bytes(src: &[u8]) -> Self;
+}
+
+/// Primitive unsigned integer types.
+pub trait UnsignedInt: Int {
+    /// Return true if the value is a power of two. Zero is not a power of two.
+    fn is_power_of_two(&self) -> bool;
+
+    /// Return the smallest power of two greater than or equal to the value, wrapping to zero if it
+    /// is not representable.
+    fn next_power_of_two(&self) -> Self;
+
+    /// Return the smallest power of two greater than or equal to the value, or `None` if it is not
+    /// representable.
+    fn checked_next_power_of_two(&self) -> Option<Self>;
+}
+
+// Raise `base` to the power of `exp` by squaring, returning the wrapped result and whether an
+// overflow occurred.
+fn pow_with_overflow<T: Int>(base: T, exp: uint) -> (T, bool) {
+    let mut acc: T = One::one();
+    let mut base = base;
+    let mut exp = exp;
+    let mut overflow = false;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            let (x, o) = acc.mul_with_overflow(&base);
+            acc = x;
+            overflow = overflow || o;
+        }
+        exp >>= 1;
+        // a square which overflows is always multiplied into the result later
+        if exp > 0 {
+            let (x, o) = base.mul_with_overflow(&base);
+            base = x;
+            overflow = overflow || o;
+        }
+    }
+    (acc, overflow)
+}
+
+macro_rules! int_impl(
+    ($t:ty, $m:ident, $u:ty, $signed:expr) => {
+        impl Int for $t {
+            #[inline(always)]
+            fn add_with_overflow(&self, other: &$t) -> ($t, bool) {
+                ::$m::add_with_overflow(*self, *other)
+            }
+
+            #[inline(always)]
+            fn sub_with_overflow(&self, other: &$t) -> ($t, bool) {
+                ::$m::sub_with_overflow(*self, *other)
+            }
+
+            #[inline(always)]
+            fn mul_with_overflow(&self, other: &$t) -> ($t, bool) {
+                ::$m::mul_with_overflow(*self, *other)
+            }
+
+            #[inline(always)]
+            fn to_u64(&self) -> u64 { *self as u64 }
+
+            #[inline(always)]
+            fn from_u64(x: u64) -> $t { x as $t }
+
+            #[inline(always)]
+            fn min_value() -> $t { ::$m::MIN }
+
+            #[inline(always)]
+            fn max_value() -> $t { ::$m::MAX }
+
+            #[inline(always)]
+            fn bits(&self) -> uint { ::$m::BITS }
+
+            #[inline(always)]
+            fn is_signed(&self) -> bool { $signed }
+
+            #[inline]
+            fn checked_add(&self, other: &$t) -> Option<$t> {
+                match ::$m::add_with_overflow(*self, *other) {
+                    (x, false) => Some(x),
+                    (_, true) => None
+                }
+            }
+
+            #[inline]
+            fn checked_sub(&self, other: &$t) -> Option<$t> {
+                match ::$m::sub_with_overflow(*self, *other) {
+                    (x, false) => Some(x),
+                    (_, true) => None
+                }
+            }
+
+            #[inline]
+            fn checked_mul(&self, other: &$t) -> Option<$t> {
+                match ::$m::mul_with_overflow(*self, *other) {
+                    (x, false) => Some(x),
+                    (_, true) => None
+                }
+            }
+
+            #[inline]
+            fn checked_div(&self, other: &$t) -> Option<$t> {
+                // `!0` is `-1` for signed types
+                if *other == 0 || ($signed && *self == ::$m::MIN && *other == !0) {
+                    None
+                } else {
+                    Some(*self / *other)
+                }
+            }
+
+            #[inline]
+            fn checked_rem(&self, other: &$t) -> Option<$t> {
+                if *other == 0 || ($signed && *self == ::$m::MIN && *other == !0) {
+                    None
+                } else {
+                    Some(*self % *other)
+                }
+            }
+
+            #[inline]
+            fn checked_neg(&self) -> Option<$t> {
+                match ::$m::sub_with_overflow(0, *self) {
+                    (x, false) => Some(x),
+                    (_, true) => None
+                }
+            }
+
+            #[inline]
+            fn checked_shl(&self, n: uint) -> Option<$t> {
+                if n < ::$m::BITS { Some(*self << n as $t) } else { None }
+            }
+
+            #[inline]
+            fn checked_shr(&self, n: uint) -> Option<$t> {
+                if n < ::$m::BITS { Some(*self >> n as $t) } else { None }
+            }
+
+            #[inline]
+            fn checked_pow(&self, exp: uint) -> Option<$t> {
+                match pow_with_overflow(*self, exp) {
+                    (x, false) => Some(x),
+                    (_, true) => None
+                }
+            }
+
+            #[inline]
+            fn saturating_add(&self, other: &$t) -> $t {
+                match ::$m::add_with_overflow(*self, *other) {
+                    (x, false) => x,
+                    (_, true) => if *other > 0 { ::$m::MAX } else { ::$m::MIN }
+                }
+            }
+
+            #[inline]
+            fn saturating_sub(&self, other: &$t) -> $t {
+                match ::$m::sub_with_overflow(*self, *other) {
+                    (x, false) => x,
+                    (_, true) => if *other > 0 { ::$m::MIN } else { ::$m::MAX }
+                }
+            }
+
+            #[inline]
+            fn saturating_mul(&self, other: &$t) -> $t {
+                match ::$m::mul_with_overflow(*self, *other) {
+                    (x, false) => x,
+                    (_, true) => {
+                        // the signs differ, so the product is negative
+                        if $signed && ((*self as i64) < 0) != ((*other as i64) < 0) {
+                            ::$m::MIN
+                        } else {
+                            ::$m::MAX
+                        }
+                    }
+                }
+            }
+
+            #[inline]
+            fn wrapping_add(&self, other: &$t) -> $t {
+                let (x, _) = ::$m::add_with_overflow(*self, *other);
+                x
+            }
+
+            #[inline]
+            fn wrapping_sub(&self, other: &$t) -> $t {
+                let (x, _) = ::$m::sub_with_overflow(*self, *other);
+                x
+            }
+
+            #[inline]
+            fn wrapping_mul(&self, other: &$t) -> $t {
+                let (x, _) = ::$m::mul_with_overflow(*self, *other);
+                x
+            }
+
+            #[inline]
+            fn wrapping_neg(&self) -> $t {
+                let (x, _) = ::$m::sub_with_overflow(0, *self);
+                x
+            }
+
+            #[inline]
+            fn wrapping_shl(&self, n: uint) -> $t {
+                *self << (n % ::$m::BITS) as $t
+            }
+
+            #[inline]
+            fn wrapping_shr(&self, n: uint) -> $t {
+                *self >> (n % ::$m::BITS) as $t
+            }
+
+            #[inline]
+            fn pow(&self, exp: uint) -> $t {
+                let (x, _) = pow_with_overflow(*self, exp);
+                x
+            }
+
+            #[inline(always)]
+            fn count_ones(&self) -> uint { ::$m::count_ones(*self) }
+
+            #[inline(always)]
+            fn count_zeros(&self) -> uint { ::$m::BITS - ::$m::count_ones(*self) }
+
+            #[inline(always)]
+            fn leading_zeros(&self) -> uint { ::$m::leading_zeros(*self) }
+
+            #[inline(always)]
+            fn trailing_zeros(&self) -> uint { ::$m::trailing_zeros(*self) }
+
+            #[inline]
+            fn rotate_left(&self, n: uint) -> $t {
+                // rotate the unsigned counterpart, as the right shift must not extend the sign
+                let n = n % ::$m::BITS;
+                let x = *self as $u;
+                if n == 0 {
+                    *self
+                } else {
+                    ((x << n as $u) | (x >> (::$m::BITS - n) as $u)) as $t
+                }
+            }
+
+            #[inline]
+            fn rotate_right(&self, n: uint) -> $t {
+                self.rotate_left(::$m::BITS - n % ::$m::BITS)
+            }
+
+            #[inline(always)]
+            fn swap_bytes(&self) -> $t { ::$m::bswap(*self) }
+
+            #[inline(always)]
+            fn to_be(&self) -> $t { ::$m::to_be(*self) }
+
+            #[inline(always)]
+            fn to_le(&self) -> $t { ::$m::to_le(*self) }
+
+            #[inline(always)]
+            fn from_be(x: $t) -> $t { ::$m::from_be(x) }
+
+            #[inline(always)]
+            fn from_le(x: $t) -> $t { ::$m::from_le(x) }
+
+            #[inline]
+            fn write_be_bytes(&self, dst: &mut [u8]) {
+                let bytes = ::$m::to_be_bytes(*self);
+                for i in range(0, ::$m::BYTES) {
+                    dst[i] = bytes[i];
+                }
+            }
+
+            #[inline]
+            fn write_le_bytes(&self, dst: &mut [u8]) {
+                let bytes = ::$m::to_le_bytes(*self);
+                for i in range(0, ::$m::BYTES) {
+                    dst[i] = bytes[i];
+                }
+            }
+
+            #[inline]
+            fn read_be_bytes(src: &[u8]) -> $t {
+                let mut bytes = [0u8, ..::$m::BYTES];
+                for i in range(0, ::$m::BYTES) {
+                    bytes[i] = src[i];
+                }
+                ::$m::from_be_bytes(bytes)
+            }
+
+            #[inline]
+            fn read_le_bytes(src: &[u8]) -> $t {
+                let mut bytes = [0u8, ..::$m::BYTES];
+                for i in range(0, ::$m::BYTES) {
+                    bytes[i] = src[i];
+                }
+                ::$m::from_le_bytes(bytes)
+            }
+        }
+
+        impl FromStr for $t {
+            #[inline]
+            fn from_str(s: &str) -> Option<$t> {
+                from_str_radix(s, 10)
+            }
+        }
+    }
+)
+
+int_impl!(int, int, uint, true)
+int_impl!(i8, i8, u8, true)
+int_impl!(i16, i16, u16, true)
+int_impl!(i32, i32, u32, true)
+int_impl!(i64, i64, u64, true)
+
+int_impl!(uint, uint, uint, false)
+int_impl!(u8, u8, u8, false)
+int_impl!(u16, u16, u16, false)
+int_impl!(u32, u32, u32, false)
+int_impl!(u64, u64, u64, false)
+
+macro_rules! unsigned_int_impl(
+    ($t:ty, $m:ident) => {
+        impl UnsignedInt for $t {
+            #[inline]
+            fn is_power_of_two(&self) -> bool {
+                *self != 0 && *self & (*self - 1) == 0
+            }
+
+            #[inline]
+            fn next_power_of_two(&self) -> $t {
+                self.checked_next_power_of_two().unwrap_or(0)
+            }
+
+            #[inline]
+            fn checked_next_power_of_two(&self) -> Option<$t> {
+                if *self <= 1 {
+                    return Some(1)
+                }
+                let shift = ::$m::BITS - ::$m::leading_zeros(*self - 1);
+                if shift < ::$m::BITS { Some(1 << shift as $t) } else { None }
+            }
+        }
+    }
+)
+
+unsigned_int_impl!(uint, uint)
+unsigned_int_impl!(u8, u8)
+unsigned_int_impl!(u16, u16)
+unsigned_int_impl!(u32, u32)
+unsigned_int_impl!(u64, u64)
+
+/// Parse an integer from ASCII digits in the given radix, with an optional leading `+` (or `-` for
+/// signed types). Letters of either case are digits above 9. Returns `None` if there are no digits,
+/// a byte is not a valid digit or the value overflows. Calls `abort` if `radix` is not between 2
+/// and 36.
+pub fn from_bytes_radix<T: Int>(bytes: &[u8], radix: uint) -> Option<T> {
+    if radix < 2 || radix > 36 {
+        abort()
+    }
+    let zero: T = Zero::zero();
+    let (negative, digits) = if bytes.len() > 0 && bytes[0] == '-' as u8 && zero.is_signed() {
+        (true, slice_from(bytes, 1))
+    } else if bytes.len() > 0 && bytes[0] == '+' as u8 {
+        (false, slice_from(bytes, 1))
+    } else {
+        (false, bytes)
+    };
+    if digits.len() == 0 {
+        return None
+    }
+
+    // negative numbers are accumulated downwards, as the minimum has no positive counterpart
+    let base: T = Int::from_u64(radix as u64);
+    let mut result = zero;
+    for &b in iter(digits) {
+        let digit: T = match char::to_digit(b as char, radix) {
+            Some(d) => Int::from_u64(d as u64),
+            None => return None
+        };
+        let (x, overflow) = result.mul_with_overflow(&base);
+        if overflow {
+            return None
+        }
+        let (x, overflow) = if negative {
+            x.sub_with_overflow(&digit)
+        } else {
+            x.add_with_overflow(&digit)
+        };
+        if overflow {
+            return None
+        }
+        result = x;
+    }
+    Some(result)
+}
+
+/// Parse an integer from a string in the given radix, as `from_bytes_radix` does.
+#[inline]
+pub fn from_str_radix<T: Int>(s: &str, radix: uint) -> Option<T> {
+    from_bytes_radix(as_bytes(s), radix)
+}
+
+/// The largest number of bytes written by `to_str_radix`, for a 64-bit value in binary with a sign.
+pub static MAX_RADIX_DIGITS: uint = 65;
+
+/// Write an integer in the given radix to the start of `buf`, using lowercase letters for digits
+/// above 9 and a leading `-` for negative values, and return the written string. Calls `abort` if
+/// `radix` is not between 2 and 36 or `buf` is too short. `MAX_RADIX_DIGITS` bytes always suffice.
+pub fn to_str_radix<'a, T: Int>(x: T, radix: uint, buf: &'a mut [u8]) -> &'a str {
+    if radix < 2 || radix > 36 {
+        abort()
+    }
+    let bits = x.to_u64();
+    let negative = x.is_signed() && (bits as i64) < 0;
+    let mut n = if negative { -(bits as i64) as u64 } else { bits };
+
+    let mut digits = [0u8, ..MAX_RADIX_DIGITS];
+    let mut pos = digits.len();
+    loop {
+        pos -= 1;
+        digits[pos] = char::from_digit((n % radix as u64) as uint, radix).get() as u8;
+        n /= radix as u64;
+        if n == 0 {
+            break
+        }
+    }
+    if negative {
+        pos -= 1;
+        digits[pos] = '-' as u8;
+    }
+
+    let len = digits.len() - pos;
+    if buf.len() < len {
+        abort()
+    }
+    for i in range(0, len) {
+        buf[i] = digits[pos + i];
+    }
+    let written: &'a [u8] = mut_slice_to(buf, len);
+    unsafe { from_utf8_unchecked(written) }
+}
+
+/// The IEEE 754 classes of floating point values.
+#[deriving(Eq, Clone)]
+pub enum FPCategory {
+    FPNaN,
+    FPInfinite,
+    FPZero,
+    /// Nonzero values below the smallest normal value, with reduced precision.
+    FPSubnormal,
+    FPNormal
+}
+
+/// Primitive floating point types.
+///
+/// The methods follow the functions of the same names in the `f32` and `f64` modules, which have
+/// the semantics of the C library.
+pub trait Float: Clone + Zero + One + Add<Self, Self> + Sub<Self, Self> + Mul<Self, Self>
+               + Div<Self, Self> + Neg<Self> {
+    /// Return a quiet NaN.
+    fn nan() -> Self;
+
+    /// Return positive infinity.
+    fn infinity() -> Self;
+
+    /// Return negative infinity.
+    fn neg_infinity() -> Self;
+
+    /// Return negative zero.
+    fn neg_zero() -> Self;
+
+    /// Return the smallest finite value.
+    fn min_value() -> Self;
+
+    /// Return the largest finite value.
+    fn max_value() -> Self;
+
+    /// Return the smallest positive normal value.
+    fn min_positive() -> Self;
+
+    /// Return the difference between 1.0 and the next larger representable value.
+    fn epsilon() -> Self;
+
+    /// Return the number of significand bits, including the hidden bit. The value itself is
+    /// ignored.
+    fn mantissa_digits(&self) -> uint;
+
+    fn is_nan(&self) -> bool;
+
+    fn is_infinite(&self) -> bool;
+
+    fn is_finite(&self) -> bool;
+
+    /// Return true if the value is neither zero, subnormal, infinite nor NaN.
+    fn is_normal(&self) -> bool;
+
+    fn classify(&self) -> FPCategory;
+
+    /// Return true if the sign bit is clear.
+    fn is_sign_positive(&self) -> bool;
+
+    /// Return true if the sign bit is set.
+    fn is_sign_negative(&self) -> bool;
+
+    /// Return 1.0 with the sign of the value, or NaN for NaN.
+    fn signum(&self) -> Self;
+
+    /// Return the IEEE 754 representation, zero-extended to `u64`.
+    fn to_bits(&self) -> u64;
+
+    /// Return the value with the IEEE 754 representation in the low bits of `bits`.
+    fn from_bits(bits: u64) -> Self;
+
+    /// Split into an integer mantissa, a binary exponent and a sign of `1` or `-1`.
+    fn integer_decode(&self) -> (u64, i16, i8);
+
+    /// Compare with the IEEE 754 totalOrder predicate.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+
+    fn max(&self, other: &Self) -> Self;
+    fn min(&self, other: &Self) -> Self;
+    fn dim(&self, other: &Self) -> Self;
+    fn sqrt(&self) -> Self;
+    fn cbrt(&self) -> Self;
+    fn hypot(&self, other: &Self) -> Self;
+    fn powi(&self, n: i32) -> Self;
+    fn pow(&self, other: &Self) -> Self;
+    fn exp(&self) -> Self;
+    fn exp2(&self) -> Self;
+    fn expm1(&self) -> Self;
+    fn log(&self) -> Self;
+    fn log10(&self) -> Self;
+    fn log1p(&self) -> Self;
+    fn log2(&self) -> Self;
+    fn fma(&self, b: &Self, c: &Self) -> Self;
+    fn abs(&self) -> Self;
+    fn copysign(&self, other: &Self) -> Self;
+    fn floor(&self) -> Self;
+    fn ceil(&self) -> Self;
+    fn trunc(&self) -> Self;
+    fn rint(&self) -> Self;
+    fn nearbyint(&self) -> Self;
+    fn round(&self) -> Self;
+    fn sin(&self) -> Self;
+    fn cos(&self) -> Self;
+    fn tan(&self) -> Self;
+    fn asin(&self) -> Self;
+    fn acos(&self) -> Self;
+    fn atan(&self) -> Self;
+    fn atan2(&self, other: &Self) -> Self;
+    fn sinh(&self) -> Self;
+    fn cosh(&self) -> Self;
+    fn tanh(&self) -> Self;
+    fn asinh(&self) -> Self;
+    fn acosh(&self) -> Self;
+    fn atanh(&self) -> Self;
+    fn erf(&self) -> Self;
+    fn erfc(&self) -> Self;
+    fn tgamma(&self) -> Self;
+    fn frexp(&self) -> (Self, i32);
+    fn ldexp(&self, exp: i32) -> Self;
+    fn modf(&self) -> (Self, Self);
+    fn ilogb(&self) -> i32;
+    fn logb(&self) -> Self;
+    fn nextafter(&self, to: &Self) -> Self;
+}
+
+macro_rules! float_impl(
+    ($t:ty, $m:ident, $u:ty) => {
+        impl Float for $t {
+            #[inline(always)]
+            fn nan() -> $t { ::$m::NAN }
+
+            #[inline(always)]
+            fn infinity() -> $t { ::$m::INFINITY }
+
+            #[inline(always)]
+            fn neg_infinity() -> $t { ::$m::NEG_INFINITY }
+
+            #[inline(always)]
+            fn neg_zero() -> $t { -0.0 }
+
+            #[inline(always)]
+            fn min_value() -> $t { ::$m::MIN }
+
+            #[inline(always)]
+            fn max_value() -> $t { ::$m::MAX }
+
+            #[inline(always)]
+            fn min_positive() -> $t { ::$m::MIN_POSITIVE }
+
+            #[inline(always)]
+            fn epsilon() -> $t { ::$m::EPSILON }
+
+            #[inline(always)]
+            fn mantissa_digits(&self) -> uint { ::$m::MANTISSA_DIGITS }
+
+            #[inline(always)]
+            fn is_nan(&self) -> bool { ::$m::is_nan(*self) }
+
+            #[inline(always)]
+            fn is_infinite(&self) -> bool { ::$m::is_infinite(*self) }
+
+            #[inline(always)]
+            fn is_finite(&self) -> bool { ::$m::is_finite(*self) }
+
+            #[inline(always)]
+            fn is_normal(&self) -> bool { ::$m::is_normal(*self) }
+
+            #[inline(always)]
+            fn classify(&self) -> FPCategory { ::$m::classify(*self) }
+
+            #[inline(always)]
+            fn is_sign_positive(&self) -> bool { ::$m::is_sign_positive(*self) }
+
+            #[inline(always)]
+            fn is_sign_negative(&self) -> bool { ::$m::is_sign_negative(*self) }
+
+            #[inline(always)]
+            fn signum(&self) -> $t { ::$m::signum(*self) }
+
+            #[inline(always)]
+            fn to_bits(&self) -> u64 { ::$m::to_bits(*self) as u64 }
+
+            #[inline(always)]
+            fn from_bits(bits: u64) -> $t { ::$m::from_bits(bits as $u) }
+
+            #[inline(always)]
+            fn integer_decode(&self) -> (u64, i16, i8) { ::$m::integer_decode(*self) }
+
+            #[inline(always)]
+            fn total_cmp(&self, other: &$t) -> Ordering { ::$m::total_cmp(*self, *other) }
+
+            #[inline(always)]
+            fn max(&self, other: &$t) -> $t { ::$m::max(*self, *other) }
+
+            #[inline(always)]
+            fn min(&self, other: &$t) -> $t { ::$m::min(*self, *other) }
+
+            #[inline(always)]
+            fn dim(&self, other: &$t) -> $t { ::$m::dim(*self, *other) }
+
+            #[inline(always)]
+            fn sqrt(&self) -> $t { ::$m::sqrt(*self) }
+
+            #[inline(always)]
+            fn cbrt(&self) -> $t { ::$m::cbrt(*self) }
+
+            #[inline(always)]
+            fn hypot(&self, other: &$t) -> $t { ::$m::hypot(*self, *other) }
+
+            #[inline(always)]
+            fn powi(&self, n: i32) -> $t { ::$m::powi(*self, n) }
+
+            #[inline(always)]
+            fn pow(&self, other: &$t) -> $t { ::$m::pow(*self, *other) }
+
+            #[inline(always)]
+            fn exp(&self) -> $t { ::$m::exp(*self) }
+
+            #[inline(always)]
+            fn exp2(&self) -> $t { ::$m::exp2(*self) }
+
+            #[inline(always)]
+            fn expm1(&self) -> $t { ::$m::expm1(*self) }
+
+            #[inline(always)]
+            fn log(&self) -> $t { ::$m::log(*self) }
+
+            #[inline(always)]
+            fn log10(&self) -> $t { ::$m::log10(*self) }
+
+            #[inline(always)]
+            fn log1p(&self) -> $t { ::$m::log1p(*self) }
+
+            #[inline(always)]
+            fn log2(&self) -> $t { ::$m::log2(*self) }
+
+            #[inline(always)]
+            fn fma(&self, b: &$t, c: &$t) -> $t { ::$m::fma(*self, *b, *c) }
+
+            #[inline(always)]
+            fn abs(&self) -> $t { ::$m::abs(*self) }
+
+            #[inline(always)]
+            fn copysign(&self, other: &$t) -> $t { ::$m::copysign(*self, *other) }
+
+            #[inline(always)]
+            fn floor(&self) -> $t { ::$m::floor(*self) }
+
+            #[inline(always)]
+            fn ceil(&self) -> $t { ::$m::ceil(*self) }
+
+            #[inline(always)]
+            fn trunc(&self) -> $t { ::$m::trunc(*self) }
+
+            #[inline(always)]
+            fn rint(&self) -> $t { ::$m::rint(*self) }
+
+            #[inline(always)]
+            fn nearbyint(&self) -> $t { ::$m::nearbyint(*self) }
+
+            #[inline(always)]
+            fn round(&self) -> $t { ::$m::round(*self) }
+
+            #[inline(always)]
+            fn sin(&self) -> $t { ::$m::sin(*self) }
+
+            #[inline(always)]
+            fn cos(&self) -> $t { ::$m::cos(*self) }
+
+            #[inline(always)]
+            fn tan(&self) -> $t { ::$m::tan(*self) }
+
+            #[inline(always)]
+            fn asin(&self) -> $t { ::$m::asin(*self) }
+
+            #[inline(always)]
+            fn acos(&self) -> $t { ::$m::acos(*self) }
+
+            #[inline(always)]
+            fn atan(&self) -> $t { ::$m::atan(*self) }
+
+            #[inline(always)]
+            fn atan2(&self, other: &$t) -> $t { ::$m::atan2(*self, *other) }
+
+            #[inline(always)]
+            fn sinh(&self) -> $t { ::$m::sinh(*self) }
+
+            #[inline(always)]
+            fn cosh(&self) -> $t { ::$m::cosh(*self) }
+
+            #[inline(always)]
+            fn tanh(&self) -> $t { ::$m::tanh(*self) }
+
+            #[inline(always)]
+            fn asinh(&self) -> $t { ::$m::asinh(*self) }
+
+            #[inline(always)]
+            fn acosh(&self) -> $t { ::$m::acosh(*self) }
+
+            #[inline(always)]
+            fn atanh(&self) -> $t { ::$m::atanh(*self) }
+
+            #[inline(always)]
+            fn erf(&self) -> $t { ::$m::erf(*self) }
+
+            #[inline(always)]
+            fn erfc(&self) -> $t { ::$m::erfc(*self) }
+
+            #[inline(always)]
+            fn tgamma(&self) -> $t { ::$m::tgamma(*self) }
+
+            #[inline(always)]
+            fn frexp(&self) -> ($t, i32) { ::$m::frexp(*self) }
+
+            #[inline(always)]
+            fn ldexp(&self, exp: i32) -> $t { ::$m::ldexp(*self, exp) }
+
+            #[inline(always)]
+            fn modf(&self) -> ($t, $t) { ::$m::modf(*self) }
+
+            #[inline(always)]
+            fn ilogb(&self) -> i32 { ::$m::ilogb(*self) }
+
+            #[inline(always)]
+            fn logb(&self) -> $t { ::$m::logb(*self) }
+
+            #[inline(always)]
+            fn nextafter(&self, to: &$t) -> $t { ::$m::nextafter(*self, *to) }
+        }
+    }
+)
+
+float_impl!(f32, f32, u32)
+float_impl!(f64, f64, u64)
+
+/// A float ordered by `total_cmp`, so it can be the key of a `PriorityQueue` or be sorted with
+/// `slice::sort`. Zeroes of opposite signs and NaNs with different bits are unequal.
+#[deriving(Clone)]
+pub struct Total<T>(T);
+
+impl<T: Float> Eq for Total<T> {
+    #[inline]
+    fn eq(&self, other: &Total<T>) -> bool {
+        let (&Total(ref x), &Total(ref y)) = (self, other);
+        x.total_cmp(y) == Equal
+    }
+}
+
+impl<T: Float> Ord for Total<T> {
+    #[inline]
+    fn lt(&self, other: &Total<T>) -> bool {
+        let (&Total(ref x), &Total(ref y)) = (self, other);
+        x.total_cmp(y) == Less
+    }
+}
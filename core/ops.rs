@@ -89,6 +89,39 @@ num_impl!(u16)
 num_impl!(u32)
 num_impl!(u64)
 
+// `Rem` is left out, as the remainder of floats is a call to `fmod` from the C library
+macro_rules! float_num_impl(
+    ($T:ty) => {
+        impl Add<$T,$T> for $T {
+            #[inline]
+            fn add(&self, other: &$T) -> $T { *self + *other }
+        }
+
+        impl Sub<$T,$T> for $T {
+            #[inline]
+            fn sub(&self, other: &$T) -> $T { *self - *other }
+        }
+
+        impl Mul<$T,$T> for $T {
+            #[inline]
+            fn mul(&self, other: &$T) -> $T { *self * *other }
+        }
+
+        impl Div<$T,$T> for $T {
+            #[inline]
+            fn div(&self, other: &$T) -> $T { *self / *other }
+        }
+
+        impl Neg<$T> for $T {
+            #[inline]
+            fn neg(&self) -> $T { -*self }
+        }
+    }
+)
+
+float_num_impl!(f32)
+float_num_impl!(f64)
+
 #[lang="not"]
 pub trait Not<Result> {
     fn not(&self) -> Result;
@@ -8,8 +8,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use fail::abort;
+use fail::{abort, fail};
 use mem::replace;
+use clone::{Clone, DeepClone};
+use cmp::{Eq, Ord};
+use iter::{Iterator, DoubleEndedIterator};
 
 pub enum Option<T> {
     Some(T),
@@ -25,6 +28,12 @@ impl<T> Option<T> {
         }
     }
 
+    /// Returns true if the option is `None`
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
     /// Convert from `Option<T>` to `Option<&T>`
     pub fn as_ref<'a>(&'a self) -> Option<&'a T> {
         match *self { Some(ref x) => Some(x), None => None }
@@ -40,6 +49,21 @@ impl<T> Option<T> {
         match self { Some(x) => x, None => abort() }
     }
 
+    /// Return the value in an `Option` or fail with `msg` if it is `None`.
+    pub fn expect(self, msg: &str) -> T {
+        match self { Some(x) => x, None => fail(msg) }
+    }
+
+    /// Return the value in an `Option` or a default.
+    pub fn unwrap_or(self, def: T) -> T {
+        match self { Some(x) => x, None => def }
+    }
+
+    /// Return the value in an `Option` or compute it from a closure.
+    pub fn unwrap_or_else(self, f: || -> T) -> T {
+        match self { Some(x) => x, None => f() }
+    }
+
     /// Maps an `Option<T>` to `Option<U>` by applying a function to a contained value.
     pub fn map<U>(self, f: |T| -> U) -> Option<U> {
         match self { Some(x) => Some(f(x)), None => None }
@@ -50,9 +74,118 @@ impl<T> Option<T> {
         match self { None => def, Some(t) => f(t) }
     }
 
+    /// Returns `None` if the option is `None`, otherwise calls `f` with the wrapped value.
+    pub fn and_then<U>(self, f: |T| -> Option<U>) -> Option<U> {
+        match self { Some(x) => f(x), None => None }
+    }
+
+    /// Returns the option if it contains a value, otherwise returns `optb`.
+    pub fn or(self, optb: Option<T>) -> Option<T> {
+        match self { Some(x) => Some(x), None => optb }
+    }
+
+    /// Returns the option if it contains a value, otherwise calls `f`.
+    pub fn or_else(self, f: || -> Option<T>) -> Option<T> {
+        match self { Some(x) => Some(x), None => f() }
+    }
+
+    /// Returns the option if it contains a value satisfying the predicate, otherwise `None`.
+    pub fn filter(self, f: |&T| -> bool) -> Option<T> {
+        match self { Some(x) => if f(&x) { Some(x) } else { None }, None => None }
+    }
+
+    /// Combine two options into an option of a pair, if both contain a value.
+    pub fn zip<U>(self, other: Option<U>) -> Option<(T, U)> {
+        match (self, other) {
+            (Some(x), Some(y)) => Some((x, y)),
+            _ => None
+        }
+    }
+
     /// Take the value out of the option, leaving a `None` in its place.
     #[inline(always)]
     pub fn take(&mut self) -> Option<T> {
         replace(self, None)
     }
+
+    /// Replace the value in the option with `value`, returning the old value if present.
+    #[inline(always)]
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        replace(self, Some(value))
+    }
+
+    /// Return an iterator over the possibly contained value.
+    #[inline]
+    pub fn iter<'a>(&'a self) -> Item<&'a T> {
+        Item { opt: self.as_ref() }
+    }
+
+    /// Return a mutable iterator over the possibly contained value.
+    #[inline]
+    pub fn mut_iter<'a>(&'a mut self) -> Item<&'a mut T> {
+        Item { opt: self.as_mut() }
+    }
+
+    /// Return a consuming iterator over the possibly contained value.
+    #[inline]
+    pub fn move_iter(self) -> Item<T> {
+        Item { opt: self }
+    }
+}
+
+impl<T: Eq> Eq for Option<T> {
+    fn eq(&self, other: &Option<T>) -> bool {
+        match (self, other) {
+            (&Some(ref x), &Some(ref y)) => *x == *y,
+            (&None, &None) => true,
+            _ => false
+        }
+    }
+}
+
+/// `None` compares less than any `Some` value.
+impl<T: Ord> Ord for Option<T> {
+    fn lt(&self, other: &Option<T>) -> bool {
+        match (self, other) {
+            (&Some(ref x), &Some(ref y)) => *x < *y,
+            (&None, &Some(_)) => true,
+            _ => false
+        }
+    }
+}
+
+impl<T: Clone> Clone for Option<T> {
+    fn clone(&self) -> Option<T> {
+        match *self { Some(ref x) => Some(x.clone()), None => None }
+    }
+}
+
+impl<T: DeepClone> DeepClone for Option<T> {
+    fn deep_clone(&self) -> Option<T> {
+        match *self { Some(ref x) => Some(x.deep_clone()), None => None }
+    }
+}
+
+/// An iterator over the value in an `Option`.
+pub struct Item<A> {
+    priv opt: Option<A>
+}
+
+impl<A> Iterator<A> for Item<A> {
+    #[inline]
+    fn next(&mut self) -> Option<A> {
+        self.opt.take()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        if self.opt.is_some() { (1, Some(1)) } else { (0, Some(0)) }
+    }
+}
+
+impl<A> DoubleEndedIterator<A> for Item<A> {
+    #[inline]
+    fn next_back(&mut self) -> Option<A> {
+        self.opt.take()
+    }
 }
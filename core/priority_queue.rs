@@ -14,7 +14,9 @@ use container::Container;
 use vec::Vec;
 use cmp::Ord;
 use option::{Option, Some, None};
-use mem::swap;
+use iter::{Iterator, FromIterator, Extendable};
+use mem::{replace, swap};
+use result::{Result, Ok, Err};
 use slice;
 
 /// A priority queue implemented with a binary heap
@@ -135,3 +137,215 @@ impl<T: Ord> PriorityQueue<T> {
         self.siftdown_range(pos, len);
     }
 }
+
+impl<T: Ord> FromIterator<T> for PriorityQueue<T> {
+    fn from_iterator<I: Iterator<T>>(iterator: &mut I) -> PriorityQueue<T> {
+        PriorityQueue::from_vec(iterator.collect())
+    }
+}
+
+impl<T: Ord> Extendable<T> for PriorityQueue<T> {
+    fn extend<I: Iterator<T>>(&mut self, iterator: &mut I) {
+        let (lower, _) = iterator.size_hint();
+        self.data.reserve_additional(lower);
+        for x in *iterator {
+            self.push(x);
+        }
+    }
+}
+
+/// A reference to an element of an `IndexedPriorityQueue`, returned by `push`. It stops referring
+/// to the element once the element leaves the queue, even if its storage is reused.
+#[deriving(Eq, Clone)]
+pub struct Handle {
+    priv index: uint,
+    priv generation: uint
+}
+
+struct Entry<T> {
+    slot: uint,
+    value: T
+}
+
+// The position of an element in the heap. The generation is incremented when the element leaves
+// the queue, invalidating the handles to it.
+struct Slot {
+    position: uint,
+    generation: uint
+}
+
+/// A priority queue implemented with a binary heap, where elements can be found through the
+/// handle returned by `push` to change their priority or remove them.
+pub struct IndexedPriorityQueue<T> {
+    priv data: Vec<Entry<T>>,
+    priv slots: Vec<Slot>,
+    priv free: Vec<uint> // unused slots
+}
+
+impl<T> Container for IndexedPriorityQueue<T> {
+    #[inline(always)]
+    fn len(&self) -> uint {
+        self.data.len()
+    }
+}
+
+impl<T: Ord> IndexedPriorityQueue<T> {
+    #[inline(always)]
+    pub fn new() -> IndexedPriorityQueue<T> {
+        IndexedPriorityQueue { data: Vec::new(), slots: Vec::new(), free: Vec::new() }
+    }
+
+    pub fn with_capacity(capacity: uint) -> IndexedPriorityQueue<T> {
+        IndexedPriorityQueue {
+            data: Vec::with_capacity(capacity),
+            slots: Vec::with_capacity(capacity),
+            free: Vec::new()
+        }
+    }
+
+    #[inline(always)]
+    pub fn capacity(&self) -> uint {
+        self.data.capacity()
+    }
+
+    pub fn top<'a>(&'a self) -> Option<&'a T> {
+        if self.len() == 0 {
+            None
+        } else {
+            Some(&self.data.as_slice()[0].value)
+        }
+    }
+
+    /// Push an element, returning a handle to it.
+    pub fn push(&mut self, item: T) -> Handle {
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                self.slots.push(Slot { position: 0, generation: 0 });
+                self.slots.len() - 1
+            }
+        };
+        let pos = self.len();
+        self.slots.as_mut_slice()[index].position = pos;
+        self.data.push(Entry { slot: index, value: item });
+        self.siftup(0, pos);
+        Handle { index: index, generation: self.slots.as_slice()[index].generation }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len() == 0 {
+            None
+        } else {
+            Some(self.remove_at(0))
+        }
+    }
+
+    /// Return true if the element referred to by the handle is still in the queue.
+    #[inline]
+    pub fn contains(&self, handle: Handle) -> bool {
+        self.position(handle).is_some()
+    }
+
+    pub fn get<'a>(&'a self, handle: Handle) -> Option<&'a T> {
+        match self.position(handle) {
+            Some(pos) => Some(&self.data.as_slice()[pos].value),
+            None => None
+        }
+    }
+
+    /// Replace the element referred to by the handle, moving it to the position for its new
+    /// priority. Return the old element, or `Err(item)` if the handle is no longer in the queue.
+    pub fn change_priority(&mut self, handle: Handle, item: T) -> Result<T, T> {
+        match self.position(handle) {
+            Some(pos) => {
+                let old = replace(&mut self.data.as_mut_slice()[pos].value, item);
+                self.restore(pos);
+                Ok(old)
+            }
+            None => Err(item)
+        }
+    }
+
+    /// Remove and return the element referred to by the handle, if it is still in the queue.
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        match self.position(handle) {
+            Some(pos) => Some(self.remove_at(pos)),
+            None => None
+        }
+    }
+
+    fn position(&self, handle: Handle) -> Option<uint> {
+        if handle.index < self.slots.len() {
+            let slot = &self.slots.as_slice()[handle.index];
+            if slot.generation == handle.generation {
+                return Some(slot.position)
+            }
+        }
+        None
+    }
+
+    fn remove_at(&mut self, pos: uint) -> T {
+        let last = self.len() - 1;
+        self.swap(pos, last);
+        let Entry { slot, value } = self.data.pop().get();
+        self.slots.as_mut_slice()[slot].generation += 1;
+        self.free.push(slot);
+        if pos < last {
+            self.restore(pos);
+        }
+        value
+    }
+
+    // Move the element at `pos` up or down to its place after it was replaced.
+    fn restore(&mut self, pos: uint) {
+        if pos > 0 && self.data.as_slice()[pos].value > self.data.as_slice()[(pos - 1) >> 1].value {
+            self.siftup(0, pos)
+        } else {
+            self.siftdown(pos)
+        }
+    }
+
+    // Swap two elements, updating the positions of their slots.
+    fn swap(&mut self, a: uint, b: uint) {
+        slice::swap(self.data.as_mut_slice(), a, b);
+        let slot_a = self.data.as_slice()[a].slot;
+        let slot_b = self.data.as_slice()[b].slot;
+        self.slots.as_mut_slice()[slot_a].position = a;
+        self.slots.as_mut_slice()[slot_b].position = b;
+    }
+
+    fn siftup(&mut self, start: uint, mut pos: uint) {
+        while pos > start {
+            let parent = (pos - 1) >> 1;
+            if self.data.as_slice()[pos].value > self.data.as_slice()[parent].value {
+                self.swap(parent, pos);
+                pos = parent;
+                continue
+            }
+            break
+        }
+    }
+
+    fn siftdown_range(&mut self, mut pos: uint, end: uint) {
+        let start = pos;
+
+        let mut child = 2 * pos + 1;
+        while child < end {
+            let right = child + 1;
+            if right < end &&
+                    !(self.data.as_slice()[child].value > self.data.as_slice()[right].value) {
+                child = right;
+            }
+            self.swap(child, pos);
+            pos = child;
+            child = 2 * pos + 1;
+        }
+
+        self.siftup(start, pos);
+    }
+
+    fn siftdown(&mut self, pos: uint) {
+        let len = self.len();
+        self.siftdown_range(pos, len);
+    }
+}
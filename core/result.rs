@@ -0,0 +1,98 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+//! Error handling with the `Result` type
+
+use fail::abort;
+use option::{Option, Some, None};
+
+/// A value which is either a success (`Ok`) or a failure (`Err`).
+pub enum Result<T, E> {
+    Ok(T),
+    Err(E)
+}
+
+impl<T, E> Result<T, E> {
+    /// Returns true if the result is `Ok`
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        match *self {
+            Ok(_) => true,
+            Err(_) => false
+        }
+    }
+
+    /// Returns true if the result is `Err`
+    #[inline]
+    pub fn is_err(&self) -> bool {
+        !self.is_ok()
+    }
+
+    /// Convert from `Result<T, E>` to `Result<&T, &E>`
+    pub fn as_ref<'a>(&'a self) -> Result<&'a T, &'a E> {
+        match *self { Ok(ref x) => Ok(x), Err(ref e) => Err(e) }
+    }
+
+    /// Convert from `Result<T, E>` to `Result<&mut T, &mut E>`
+    pub fn as_mut<'a>(&'a mut self) -> Result<&'a mut T, &'a mut E> {
+        match *self { Ok(ref mut x) => Ok(x), Err(ref mut e) => Err(e) }
+    }
+
+    /// Convert from `Result<T, E>` to `Option<T>`, discarding the error.
+    pub fn ok(self) -> Option<T> {
+        match self { Ok(x) => Some(x), Err(_) => None }
+    }
+
+    /// Convert from `Result<T, E>` to `Option<E>`, discarding the success value.
+    pub fn err(self) -> Option<E> {
+        match self { Ok(_) => None, Err(e) => Some(e) }
+    }
+
+    /// Return the value in an `Ok` or call `abort` if it is an `Err`.
+    pub fn get(self) -> T {
+        match self { Ok(x) => x, Err(_) => abort() }
+    }
+
+    /// Return the value in an `Err` or call `abort` if it is an `Ok`.
+    pub fn get_err(self) -> E {
+        match self { Ok(_) => abort(), Err(e) => e }
+    }
+
+    /// Maps a `Result<T, E>` to `Result<U, E>` by applying a function to an `Ok` value.
+    pub fn map<U>(self, f: |T| -> U) -> Result<U, E> {
+        match self { Ok(x) => Ok(f(x)), Err(e) => Err(e) }
+    }
+
+    /// Maps a `Result<T, E>` to `Result<T, F>` by applying a function to an `Err` value.
+    pub fn map_err<F>(self, f: |E| -> F) -> Result<T, F> {
+        match self { Ok(x) => Ok(x), Err(e) => Err(f(e)) }
+    }
+
+    /// Calls `f` with the value if the result is `Ok`, otherwise returns the `Err`.
+    pub fn and_then<U>(self, f: |T| -> Result<U, E>) -> Result<U, E> {
+        match self { Ok(x) => f(x), Err(e) => Err(e) }
+    }
+
+    /// Calls `f` with the error if the result is `Err`, otherwise returns the `Ok`.
+    pub fn or_else<F>(self, f: |E| -> Result<T, F>) -> Result<T, F> {
+        match self { Ok(x) => Ok(x), Err(e) => f(e) }
+    }
+
+    /// Return the value in an `Ok` or a default.
+    pub fn unwrap_or(self, def: T) -> T {
+        match self { Ok(x) => x, Err(_) => def }
+    }
+
+    /// Return the value in an `Ok` or compute it from the error.
+    pub fn unwrap_or_else(self, f: |E| -> T) -> T {
+        match self { Ok(x) => x, Err(e) => f(e) }
+    }
+}
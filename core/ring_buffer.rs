@@ -0,0 +1,310 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Fixed-capacity circular buffers, overwriting the oldest element when full
+//!
+//! `RingBuffer` owns a heap allocation. `SliceRingBuffer` is backed by a caller-provided slice, so
+//! it is available in a freestanding environment.
+
+use container::Container;
+use iter::{Iterator, DoubleEndedIterator};
+use kinds::Pod;
+use mem::{move_val_init, transmute};
+use option::{Option, Some, None};
+use ptr::{offset, read_ptr};
+use slice::{Slice, to_mut_ptr};
+#[cfg(libc)]
+use ops::Drop;
+#[cfg(libc)]
+use vec::Vec;
+
+// The state shared by both buffers: `len` elements starting at slot `head` of the `cap` slots at
+// `ptr`, wrapping around the end. Slots outside of the elements may be uninitialized.
+struct Ring<T> {
+    ptr: *mut T,
+    cap: uint,
+    head: uint,
+    len: uint
+}
+
+impl<T> Ring<T> {
+    fn new(ptr: *mut T, cap: uint) -> Ring<T> {
+        Ring { ptr: ptr, cap: cap, head: 0, len: 0 }
+    }
+
+    // The slot holding the element at `index`, which must be less than the capacity.
+    #[inline]
+    fn slot(&self, index: uint) -> *mut T {
+        let i = if self.head >= self.cap - index {
+            self.head + index - self.cap
+        } else {
+            self.head + index
+        };
+        unsafe { offset(self.ptr as *T, i as int) as *mut T }
+    }
+
+    unsafe fn push(&mut self, value: T) -> Option<T> {
+        if self.cap == 0 {
+            Some(value)
+        } else if self.len == self.cap {
+            let slot = self.slot(0);
+            let oldest = read_ptr(slot as *T);
+            move_val_init(&mut *slot, value);
+            self.head = if self.head + 1 == self.cap { 0 } else { self.head + 1 };
+            Some(oldest)
+        } else {
+            move_val_init(&mut *self.slot(self.len), value);
+            self.len += 1;
+            None
+        }
+    }
+
+    unsafe fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            let x = read_ptr(self.slot(0) as *T);
+            self.head = if self.head + 1 == self.cap { 0 } else { self.head + 1 };
+            self.len -= 1;
+            Some(x)
+        }
+    }
+
+    unsafe fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            None
+        } else {
+            self.len -= 1;
+            Some(read_ptr(self.slot(self.len) as *T))
+        }
+    }
+
+    fn get<'a>(&'a self, index: uint) -> Option<&'a T> {
+        if index < self.len {
+            unsafe { Some(&*self.slot(index)) }
+        } else {
+            None
+        }
+    }
+
+    fn iter<'a>(&'a self) -> Items<'a, T> {
+        Items { ring: self, index: 0, end: self.len }
+    }
+
+    fn as_slices<'a>(&'a self) -> (&'a [T], &'a [T]) {
+        let head_len = self.cap - self.head;
+        unsafe {
+            if self.len <= head_len {
+                (self.raw_slice(self.head, self.len), self.raw_slice(0, 0))
+            } else {
+                (self.raw_slice(self.head, head_len), self.raw_slice(0, self.len - head_len))
+            }
+        }
+    }
+
+    unsafe fn raw_slice<'a>(&'a self, start: uint, len: uint) -> &'a [T] {
+        transmute(Slice { data: offset(self.ptr as *T, start as int), len: len })
+    }
+}
+
+/// A buffer holding the most recent elements pushed to it, up to a capacity fixed at construction.
+#[cfg(libc)]
+pub struct RingBuffer<T> {
+    priv ring: Ring<T>,
+    priv elts: Vec<T> // only the allocation is used, so the length is always zero
+}
+
+#[cfg(libc)]
+impl<T> RingBuffer<T> {
+    pub fn new(capacity: uint) -> RingBuffer<T> {
+        let mut elts = Vec::with_capacity(capacity);
+        let ptr = to_mut_ptr(elts.as_mut_slice());
+        RingBuffer { ring: Ring::new(ptr, capacity), elts: elts }
+    }
+
+    #[inline(always)]
+    pub fn capacity(&self) -> uint {
+        self.ring.cap
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.ring.len == self.ring.cap
+    }
+
+    /// Push an element as the newest. If the buffer is full, the oldest element is removed to make
+    /// room and returned.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Option<T> {
+        unsafe { self.ring.push(value) }
+    }
+
+    /// Remove and return the oldest element.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        unsafe { self.ring.pop_front() }
+    }
+
+    /// Remove and return the newest element.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        unsafe { self.ring.pop_back() }
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    /// Return the element at `index`, counting from the oldest.
+    #[inline]
+    pub fn get<'a>(&'a self, index: uint) -> Option<&'a T> {
+        self.ring.get(index)
+    }
+
+    /// Return an iterator over the elements from the oldest to the newest.
+    #[inline]
+    pub fn iter<'a>(&'a self) -> Items<'a, T> {
+        self.ring.iter()
+    }
+
+    /// Return the elements as two slices, from the oldest to the newest. The second slice is
+    /// empty if the elements do not wrap around the end of the buffer.
+    #[inline]
+    pub fn as_slices<'a>(&'a self) -> (&'a [T], &'a [T]) {
+        self.ring.as_slices()
+    }
+}
+
+#[cfg(libc)]
+impl<T> Container for RingBuffer<T> {
+    #[inline(always)]
+    fn len(&self) -> uint {
+        self.ring.len
+    }
+}
+
+#[cfg(libc)]
+#[unsafe_destructor]
+impl<T> Drop for RingBuffer<T> {
+    fn drop(&mut self) {
+        self.clear();
+        // `elts` only frees the allocation, as its length is zero
+    }
+}
+
+/// A buffer holding the most recent elements pushed to it, stored in a slice provided by the
+/// caller. The elements are `Pod`, so the slots of the slice need no destruction and may be
+/// overwritten freely.
+pub struct SliceRingBuffer<'a, T> {
+    priv ring: Ring<T>,
+    priv buf: &'a mut [T] // keeps the slice borrowed for the lifetime of the buffer
+}
+
+impl<'a, T: Pod> SliceRingBuffer<'a, T> {
+    /// Create an empty buffer with the length of `buf` as its capacity. The existing contents of
+    /// `buf` are ignored.
+    pub fn new(buf: &'a mut [T]) -> SliceRingBuffer<'a, T> {
+        let ring = Ring::new(to_mut_ptr(buf), buf.len());
+        SliceRingBuffer { ring: ring, buf: buf }
+    }
+
+    #[inline(always)]
+    pub fn capacity(&self) -> uint {
+        self.ring.cap
+    }
+
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.ring.len == self.ring.cap
+    }
+
+    /// Push an element as the newest. If the buffer is full, the oldest element is overwritten and
+    /// returned.
+    #[inline]
+    pub fn push(&mut self, value: T) -> Option<T> {
+        unsafe { self.ring.push(value) }
+    }
+
+    /// Remove and return the oldest element.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        unsafe { self.ring.pop_front() }
+    }
+
+    /// Remove and return the newest element.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        unsafe { self.ring.pop_back() }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.ring.head = 0;
+        self.ring.len = 0;
+    }
+
+    /// Return the element at `index`, counting from the oldest.
+    #[inline]
+    pub fn get<'b>(&'b self, index: uint) -> Option<&'b T> {
+        self.ring.get(index)
+    }
+
+    /// Return an iterator over the elements from the oldest to the newest.
+    #[inline]
+    pub fn iter<'b>(&'b self) -> Items<'b, T> {
+        self.ring.iter()
+    }
+
+    /// Return the elements as two slices, in the same way as `RingBuffer::as_slices`.
+    #[inline]
+    pub fn as_slices<'b>(&'b self) -> (&'b [T], &'b [T]) {
+        self.ring.as_slices()
+    }
+}
+
+impl<'a, T> Container for SliceRingBuffer<'a, T> {
+    #[inline(always)]
+    fn len(&self) -> uint {
+        self.ring.len
+    }
+}
+
+pub struct Items<'a, T> {
+    priv ring: &'a Ring<T>,
+    priv index: uint,
+    priv end: uint
+}
+
+impl<'a, T> Iterator<&'a T> for Items<'a, T> {
+    fn next(&mut self) -> Option<&'a T> {
+        if self.index == self.end {
+            None
+        } else {
+            self.index += 1;
+            unsafe { Some(&*self.ring.slot(self.index - 1)) }
+        }
+    }
+
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let len = self.end - self.index;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator<&'a T> for Items<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.index == self.end {
+            None
+        } else {
+            self.end -= 1;
+            unsafe { Some(&*self.ring.slot(self.end)) }
+        }
+    }
+}
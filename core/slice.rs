@@ -8,15 +8,21 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use mem::{nonzero_size_of, size_of, transmute};
-use ptr::{offset, read_ptr, swap_ptr};
+use mem::{move_val_init, nonzero_size_of, size_of, transmute};
+use ptr::{copy_memory, offset, read_ptr, swap_ptr};
 use fail::abort;
 use container::Container;
 use option::{Option, Some, None};
 use clone::Clone;
-use iter::{Iterator, DoubleEndedIterator};
-use cmp::{Ord, Ordering, Equal, Less, Greater};
+use iter::{Iterator, DoubleEndedIterator, Invert, range};
+use cmp::{Eq, Ord, Ordering, Equal, Less, Greater, max, min};
 use kinds::marker::ContravariantLifetime;
+#[cfg(libc)]
+use heap::{alloc, free};
+#[cfg(libc)]
+use iter::range_step;
+#[cfg(libc)]
+use ptr::copy_nonoverlapping_memory;
 
 pub struct Slice<T> {
     data: *T,
@@ -140,6 +146,661 @@ pub unsafe fn unchecked_swap<T>(xs: &mut [T], a: uint, b: uint) {
     swap_ptr(x, y);
 }
 
+static INSERTION_SORT_THRESHOLD: uint = 16;
+static MERGE_SORT_RUN: uint = 32;
+
+/// Sort the slice with a stable merge sort, allocating a buffer of the same length.
+#[cfg(libc)]
+pub fn sort<T: Ord>(xs: &mut [T]) {
+    sort_by(xs, |a, b| a.cmp(b))
+}
+
+/// Sort the slice with a stable merge sort and a comparison function, allocating a buffer of the
+/// same length.
+#[cfg(libc)]
+pub fn sort_by<T>(xs: &mut [T], compare: |&T, &T| -> Ordering) {
+    let len = xs.len();
+    if size_of::<T>() == 0 || len <= 1 {
+        return
+    }
+
+    for start in range_step(0, len, MERGE_SORT_RUN) {
+        let end = min(start + MERGE_SORT_RUN, len);
+        insertion_sort(mut_slice(xs, start, end), |a, b| compare(a, b));
+    }
+    if len <= MERGE_SORT_RUN {
+        return
+    }
+
+    // Merge runs of doubling width back and forth between the slice and the buffer. The elements
+    // are only ever moved bitwise, so each one is owned by exactly one of the two at a time.
+    unsafe {
+        let xs_ptr = to_mut_ptr(xs);
+        let buf = alloc(len * size_of::<T>()) as *mut T;
+        let mut src = xs_ptr as *T;
+        let mut dst = buf;
+        let mut width = MERGE_SORT_RUN;
+        while width < len {
+            for start in range_step(0, len, 2 * width) {
+                let mid = min(start + width, len);
+                let end = min(start + 2 * width, len);
+                merge(src, start, mid, end, dst, |a, b| compare(a, b));
+            }
+            let tmp = src;
+            src = dst as *T;
+            dst = tmp as *mut T;
+            width *= 2;
+        }
+        if src != xs_ptr as *T {
+            copy_nonoverlapping_memory(xs_ptr, src, len);
+        }
+        free(buf as *mut u8);
+    }
+}
+
+// Merge the sorted runs `[start, mid)` and `[mid, end)` of `src` into the same range of `dst`,
+// taking from the left run on ties to keep the sort stable.
+#[cfg(libc)]
+unsafe fn merge<T>(src: *T, start: uint, mid: uint, end: uint, dst: *mut T,
+                   compare: |&T, &T| -> Ordering) {
+    let mut left = start;
+    let mut right = mid;
+    let mut out = start;
+    while left < mid && right < end {
+        let l = offset(src, left as int);
+        let r = offset(src, right as int);
+        let out_ptr = offset(dst as *T, out as int) as *mut T;
+        if compare(&*r, &*l) == Less {
+            copy_nonoverlapping_memory(out_ptr, r, 1);
+            right += 1;
+        } else {
+            copy_nonoverlapping_memory(out_ptr, l, 1);
+            left += 1;
+        }
+        out += 1;
+    }
+    copy_nonoverlapping_memory(offset(dst as *T, out as int) as *mut T,
+                               offset(src, left as int), mid - left);
+    out += mid - left;
+    copy_nonoverlapping_memory(offset(dst as *T, out as int) as *mut T,
+                               offset(src, right as int), end - right);
+}
+
+// Stable and allocation-free, but quadratic, so only used for short slices.
+fn insertion_sort<T>(xs: &mut [T], compare: |&T, &T| -> Ordering) {
+    let p = to_mut_ptr(xs) as *T;
+    for i in range(1, xs.len()) {
+        unsafe {
+            let read = offset(p, i as int);
+            let mut j = i;
+            while j > 0 && compare(&*read, &*offset(p, (j - 1) as int)) == Less {
+                j -= 1;
+            }
+            if j != i {
+                let tmp = read_ptr(read);
+                let hole = offset(p, j as int);
+                copy_memory(offset(hole, 1) as *mut T, hole, i - j);
+                move_val_init(&mut *(hole as *mut T), tmp);
+            }
+        }
+    }
+}
+
+/// Sort the slice in place with an introsort, which is not stable but needs no allocation.
+pub fn sort_unstable<T: Ord>(xs: &mut [T]) {
+    sort_unstable_by(xs, |a, b| a.cmp(b))
+}
+
+/// Sort the slice in place with an introsort and a comparison function. The sort is not stable
+/// but needs no allocation and is `O(n log n)` in the worst case.
+pub fn sort_unstable_by<T>(xs: &mut [T], compare: |&T, &T| -> Ordering) {
+    let len = xs.len();
+    introsort(xs, 0, len, compare, depth_limit(len))
+}
+
+// Quicksort falls back to heapsort past twice the ideal recursion depth.
+fn depth_limit(len: uint) -> uint {
+    let mut limit = 0;
+    let mut n = len;
+    while n > 0 {
+        limit += 2;
+        n >>= 1;
+    }
+    limit
+}
+
+fn introsort<T>(xs: &mut [T], mut lo: uint, mut hi: uint, compare: |&T, &T| -> Ordering,
+                mut limit: uint) {
+    loop {
+        if hi - lo <= INSERTION_SORT_THRESHOLD {
+            insertion_sort(mut_slice(xs, lo, hi), |a, b| compare(a, b));
+            return
+        }
+        if limit == 0 {
+            heapsort(mut_slice(xs, lo, hi), |a, b| compare(a, b));
+            return
+        }
+        limit -= 1;
+
+        // recurse into the smaller side to bound the stack depth by `O(log n)`
+        let mid = lo + partition_at_pivot(mut_slice(xs, lo, hi), |a, b| compare(a, b));
+        if mid - lo < hi - mid {
+            introsort(xs, lo, mid, |a, b| compare(a, b), limit);
+            lo = mid + 1;
+        } else {
+            introsort(xs, mid + 1, hi, |a, b| compare(a, b), limit);
+            hi = mid;
+        }
+    }
+}
+
+fn median_of_three<T>(xs: &[T], a: uint, b: uint, c: uint, compare: |&T, &T| -> Ordering)
+    -> uint {
+    if compare(&xs[a], &xs[b]) == Less {
+        if compare(&xs[b], &xs[c]) == Less { b }
+        else if compare(&xs[a], &xs[c]) == Less { c }
+        else { a }
+    } else {
+        if compare(&xs[a], &xs[c]) == Less { a }
+        else if compare(&xs[b], &xs[c]) == Less { c }
+        else { b }
+    }
+}
+
+// Partition around a median-of-three pivot, returning its final index. Elements equal to the
+// pivot stop both scans, which keeps the split balanced when there are many duplicates.
+fn partition_at_pivot<T>(xs: &mut [T], compare: |&T, &T| -> Ordering) -> uint {
+    let len = xs.len();
+    let pivot = median_of_three(xs, 0, len / 2, len - 1, |a, b| compare(a, b));
+    swap(xs, 0, pivot);
+
+    let mut i = 0;
+    let mut j = len;
+    loop {
+        i += 1;
+        while i < len && compare(&xs[i], &xs[0]) == Less {
+            i += 1;
+        }
+        j -= 1;
+        while compare(&xs[j], &xs[0]) == Greater {
+            j -= 1;
+        }
+        if i >= j {
+            break
+        }
+        swap(xs, i, j);
+    }
+    swap(xs, 0, j);
+    j
+}
+
+fn heapsort<T>(xs: &mut [T], compare: |&T, &T| -> Ordering) {
+    let len = xs.len();
+    for start in range(0, len / 2).invert() {
+        sift_down(xs, start, len, |a, b| compare(a, b));
+    }
+    for end in range(1, len).invert() {
+        swap(xs, 0, end);
+        sift_down(xs, 0, end, |a, b| compare(a, b));
+    }
+}
+
+fn sift_down<T>(xs: &mut [T], mut node: uint, end: uint, compare: |&T, &T| -> Ordering) {
+    loop {
+        let mut child = 2 * node + 1;
+        if child >= end {
+            return
+        }
+        if child + 1 < end && compare(&xs[child], &xs[child + 1]) == Less {
+            child += 1;
+        }
+        if compare(&xs[node], &xs[child]) != Less {
+            return
+        }
+        swap(xs, node, child);
+        node = child;
+    }
+}
+
+/// Return true if the slice is sorted in ascending order.
+pub fn is_sorted<T: Ord>(xs: &[T]) -> bool {
+    is_sorted_by(xs, |a, b| a.cmp(b))
+}
+
+/// Return true if the slice is sorted in ascending order according to a comparison function.
+pub fn is_sorted_by<T>(xs: &[T], compare: |&T, &T| -> Ordering) -> bool {
+    for i in range(1, xs.len()) {
+        if compare(&xs[i], &xs[i - 1]) == Less {
+            return false
+        }
+    }
+    true
+}
+
+/// Reorder the slice so that the element at `n` is the one which would be there if the slice was
+/// sorted, with no greater elements before it and no lesser elements after it. Calls `abort` if
+/// `n` is out of bounds.
+pub fn select_nth<T: Ord>(xs: &mut [T], n: uint) {
+    select_nth_by(xs, n, |a, b| a.cmp(b))
+}
+
+/// Reorder the slice like `select_nth` according to a comparison function.
+pub fn select_nth_by<T>(xs: &mut [T], n: uint, compare: |&T, &T| -> Ordering) {
+    if n >= xs.len() {
+        abort()
+    }
+    let mut lo = 0;
+    let mut hi = xs.len();
+    let mut limit = depth_limit(hi);
+    loop {
+        if hi - lo <= INSERTION_SORT_THRESHOLD {
+            insertion_sort(mut_slice(xs, lo, hi), |a, b| compare(a, b));
+            return
+        }
+        if limit == 0 {
+            heapsort(mut_slice(xs, lo, hi), |a, b| compare(a, b));
+            return
+        }
+        limit -= 1;
+
+        let mid = lo + partition_at_pivot(mut_slice(xs, lo, hi), |a, b| compare(a, b));
+        if n == mid {
+            return
+        } else if n < mid {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+}
+
+/// Move the elements satisfying the predicate before those which don't, returning the number of
+/// elements satisfying it. The relative order within each group is not preserved.
+pub fn partition<T>(xs: &mut [T], predicate: |&T| -> bool) -> uint {
+    let mut first = 0;
+    for i in range(0, xs.len()) {
+        if predicate(&xs[i]) {
+            swap(xs, first, i);
+            first += 1;
+        }
+    }
+    first
+}
+
+/// Return true if the slice contains an element equal to `x`.
+pub fn contains<T: Eq>(xs: &[T], x: &T) -> bool {
+    position_elem(xs, x).is_some()
+}
+
+/// Return the index of the first element equal to `x`.
+pub fn position_elem<T: Eq>(xs: &[T], x: &T) -> Option<uint> {
+    iter(xs).position(|y| *y == *x)
+}
+
+/// Return the index of the last element equal to `x`.
+pub fn rposition_elem<T: Eq>(xs: &[T], x: &T) -> Option<uint> {
+    match iter(xs).invert().position(|y| *y == *x) {
+        Some(pos) => Some(xs.len() - 1 - pos),
+        None => None
+    }
+}
+
+/// Return true if `prefix` is a prefix of the slice.
+pub fn starts_with<T: Eq>(xs: &[T], prefix: &[T]) -> bool {
+    prefix.len() <= xs.len() && slice_to(xs, prefix.len()) == prefix
+}
+
+/// Return true if `suffix` is a suffix of the slice.
+pub fn ends_with<T: Eq>(xs: &[T], suffix: &[T]) -> bool {
+    suffix.len() <= xs.len() && slice_from(xs, xs.len() - suffix.len()) == suffix
+}
+
+// Bit patterns with every byte of a word set to 0x01 and 0x80.
+static LO_BYTES: uint = !0 / 255;
+static HI_BYTES: uint = (!0 / 255) << 7;
+
+#[inline(always)]
+fn contains_zero_byte(x: uint) -> bool {
+    (x - LO_BYTES) & !x & HI_BYTES != 0
+}
+
+/// Return the index of the first occurrence of `byte`, comparing a word at a time.
+pub fn memchr(haystack: &[u8], byte: u8) -> Option<uint> {
+    let len = haystack.len();
+    let p = to_ptr(haystack);
+    let word_size = size_of::<uint>();
+    let repeated = LO_BYTES * byte as uint;
+
+    // scan up to an aligned address, then a word at a time until a word holds a match
+    let mut i = 0;
+    while i < len && (p as uint + i) % word_size != 0 {
+        if haystack[i] == byte {
+            return Some(i)
+        }
+        i += 1;
+    }
+    while i + word_size <= len {
+        let word = unsafe { *(offset(p, i as int) as *uint) };
+        if contains_zero_byte(word ^ repeated) {
+            break
+        }
+        i += word_size;
+    }
+    while i < len {
+        if haystack[i] == byte {
+            return Some(i)
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Return the index of the last occurrence of `byte`, comparing a word at a time.
+pub fn memrchr(haystack: &[u8], byte: u8) -> Option<uint> {
+    let p = to_ptr(haystack);
+    let word_size = size_of::<uint>();
+    let repeated = LO_BYTES * byte as uint;
+
+    // scan down to an aligned address, then a word at a time until a word holds a match
+    let mut end = haystack.len();
+    while end > 0 && (p as uint + end) % word_size != 0 {
+        if haystack[end - 1] == byte {
+            return Some(end - 1)
+        }
+        end -= 1;
+    }
+    while end >= word_size {
+        let word = unsafe { *(offset(p, (end - word_size) as int) as *uint) };
+        if contains_zero_byte(word ^ repeated) {
+            break
+        }
+        end -= word_size;
+    }
+    while end > 0 {
+        if haystack[end - 1] == byte {
+            return Some(end - 1)
+        }
+        end -= 1;
+    }
+    None
+}
+
+/// Return the index of the first occurrence of `needle` in `haystack`, using the Two-Way
+/// algorithm for linear time and constant space.
+pub fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<uint> {
+    match needle.len() {
+        0 => Some(0),
+        1 => memchr(haystack, needle[0]),
+        _ => TwoWay::new(needle).find(haystack)
+    }
+}
+
+/// Return the index of the last occurrence of `needle` in `haystack`, using the Two-Way
+/// algorithm run from the end.
+pub fn rfind_bytes(haystack: &[u8], needle: &[u8]) -> Option<uint> {
+    match needle.len() {
+        0 => Some(haystack.len()),
+        1 => memrchr(haystack, needle[0]),
+        _ => TwoWay::new(needle).rfind(haystack)
+    }
+}
+
+// The Two-Way string matching algorithm of Crochemore and Perrin. The needle is split at a
+// critical factorization `needle[..crit_pos]`, `needle[crit_pos..]`. The right half is matched
+// forwards, then the left half backwards, and the period of the needle bounds the shift on a
+// mismatch. For a periodic needle, `memory` remembers how much of the left half is known to match
+// after a shift by the period. Searching from the end mirrors this with `crit_pos_back` and
+// `memory_back`, matching the left half backwards first.
+struct TwoWay<'a> {
+    needle: &'a [u8],
+    crit_pos: uint,
+    crit_pos_back: uint,
+    period: uint,
+    // a bloom filter of the bytes in the needle, for skipping over whole windows
+    byteset: u64,
+    long_period: bool,
+    memory: uint,
+    memory_back: uint
+}
+
+impl<'a> TwoWay<'a> {
+    fn new(needle: &'a [u8]) -> TwoWay<'a> {
+        let (crit_pos_false, period_false) = maximal_suffix(needle, false);
+        let (crit_pos_true, period_true) = maximal_suffix(needle, true);
+        let (crit_pos, period) = if crit_pos_false > crit_pos_true {
+            (crit_pos_false, period_false)
+        } else {
+            (crit_pos_true, period_true)
+        };
+
+        let mut byteset = 0u64;
+        for &b in iter(needle) {
+            byteset |= 1 << (b & 63) as u64;
+        }
+
+        if crit_pos + period <= needle.len() &&
+           slice_to(needle, crit_pos) == slice(needle, period, period + crit_pos) {
+            // the reverse search needs a critical factorization whose right half is no longer
+            // than the period, which the forward one doesn't guarantee
+            let crit_pos_back = needle.len() - max(reverse_maximal_suffix(needle, period, false),
+                                                   reverse_maximal_suffix(needle, period, true));
+            TwoWay { needle: needle, crit_pos: crit_pos, crit_pos_back: crit_pos_back,
+                     period: period, byteset: byteset, long_period: false, memory: 0,
+                     memory_back: needle.len() }
+        } else {
+            // no useful period, so shift by a safe lower bound on it instead
+            let period = max(crit_pos, needle.len() - crit_pos) + 1;
+            TwoWay { needle: needle, crit_pos: crit_pos, crit_pos_back: crit_pos, period: period,
+                     byteset: byteset, long_period: true, memory: 0, memory_back: needle.len() }
+        }
+    }
+
+    fn find(&mut self, haystack: &[u8]) -> Option<uint> {
+        let n = self.needle.len();
+        let mut position = 0;
+        while position + n <= haystack.len() {
+            let tail = haystack[position + n - 1];
+            if self.byteset & (1 << (tail & 63) as u64) == 0 {
+                // the last byte of the window isn't in the needle, so no match overlaps it
+                position += n;
+                self.memory = 0;
+                continue
+            }
+            match self.mismatch_shift(haystack, position) {
+                None => return Some(position),
+                Some(shift) => position += shift
+            }
+        }
+        None
+    }
+
+    // Match the needle at `position`, returning how far to shift on a mismatch.
+    fn mismatch_shift(&mut self, haystack: &[u8], position: uint) -> Option<uint> {
+        let needle = self.needle;
+        let start = if self.long_period { self.crit_pos } else { max(self.crit_pos, self.memory) };
+        for i in range(start, needle.len()) {
+            if needle[i] != haystack[position + i] {
+                self.memory = 0;
+                return Some(i - self.crit_pos + 1)
+            }
+        }
+
+        let start = if self.long_period { 0 } else { self.memory };
+        for i in range(start, self.crit_pos).invert() {
+            if needle[i] != haystack[position + i] {
+                if !self.long_period {
+                    self.memory = needle.len() - self.period;
+                }
+                return Some(self.period)
+            }
+        }
+        None
+    }
+
+    fn rfind(&mut self, haystack: &[u8]) -> Option<uint> {
+        let n = self.needle.len();
+        let mut end = haystack.len();
+        while end >= n {
+            let head = haystack[end - n];
+            if self.byteset & (1 << (head & 63) as u64) == 0 {
+                // the first byte of the window isn't in the needle, so no match overlaps it
+                end -= n;
+                self.memory_back = n;
+                continue
+            }
+            match self.mismatch_shift_back(haystack, end - n) {
+                None => return Some(end - n),
+                Some(shift) => end -= shift
+            }
+        }
+        None
+    }
+
+    // Match the needle at `position` from the end, returning how far to shift back on a mismatch.
+    fn mismatch_shift_back(&mut self, haystack: &[u8], position: uint) -> Option<uint> {
+        let needle = self.needle;
+        let end = if self.long_period {
+            self.crit_pos_back
+        } else {
+            min(self.crit_pos_back, self.memory_back)
+        };
+        for i in range(0, end).invert() {
+            if needle[i] != haystack[position + i] {
+                self.memory_back = needle.len();
+                return Some(self.crit_pos_back - i)
+            }
+        }
+
+        let end = if self.long_period { needle.len() } else { self.memory_back };
+        for i in range(self.crit_pos_back, end) {
+            if needle[i] != haystack[position + i] {
+                if !self.long_period {
+                    self.memory_back = self.period;
+                }
+                return Some(self.period)
+            }
+        }
+        None
+    }
+}
+
+// Compute the maximal suffix of `xs` under the byte order, or the reversed byte order if
+// `order_greater` is true. Return its starting index and period.
+fn maximal_suffix(xs: &[u8], order_greater: bool) -> (uint, uint) {
+    let mut left = 0;
+    let mut right = 1;
+    let mut offset = 0;
+    let mut period = 1;
+
+    while right + offset < xs.len() {
+        let a = xs[right + offset];
+        let b = xs[left + offset];
+        if (a < b && !order_greater) || (a > b && order_greater) {
+            // the suffix is smaller, the period is the entire prefix so far
+            right += offset + 1;
+            offset = 0;
+            period = right - left;
+        } else if a == b {
+            // advance through the repetition of the current period
+            if offset + 1 == period {
+                right += offset + 1;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            // the suffix is larger, start over from the current location
+            left = right;
+            right += 1;
+            offset = 0;
+            period = 1;
+        }
+    }
+    (left, period)
+}
+
+// Compute the maximal suffix of the reversed `xs` in the same way as `maximal_suffix`, stopping
+// once the period reaches `known_period`, the period of the whole needle. Return the length of
+// the suffix, counted from the end of `xs`.
+fn reverse_maximal_suffix(xs: &[u8], known_period: uint, order_greater: bool) -> uint {
+    let mut left = 0;
+    let mut right = 1;
+    let mut offset = 0;
+    let mut period = 1;
+    let n = xs.len();
+
+    while right + offset < n {
+        let a = xs[n - (1 + right + offset)];
+        let b = xs[n - (1 + left + offset)];
+        if (a < b && !order_greater) || (a > b && order_greater) {
+            right += offset + 1;
+            offset = 0;
+            period = right - left;
+        } else if a == b {
+            if offset + 1 == period {
+                right += offset + 1;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            left = right;
+            right += 1;
+            offset = 0;
+            period = 1;
+        }
+        if period == known_period {
+            break
+        }
+    }
+    left
+}
+
+/// Reverse the order of the elements in place.
+pub fn reverse<T>(xs: &mut [T]) {
+    let len = xs.len();
+    for i in range(0, len / 2) {
+        unsafe { unchecked_swap(xs, i, len - 1 - i) }
+    }
+}
+
+/// Rotate the elements in place so that the element at `mid` comes first. Calls `abort` if `mid`
+/// is greater than the length.
+pub fn rotate_left<T>(xs: &mut [T], mid: uint) {
+    if mid > xs.len() {
+        abort()
+    }
+    reverse(mut_slice_to(xs, mid));
+    reverse(mut_slice_from(xs, mid));
+    reverse(xs);
+}
+
+/// Rotate the elements in place so that the last `k` elements come first. Calls `abort` if `k` is
+/// greater than the length.
+pub fn rotate_right<T>(xs: &mut [T], k: uint) {
+    if k > xs.len() {
+        abort()
+    }
+    let mid = xs.len() - k;
+    rotate_left(xs, mid)
+}
+
+/// Clone elements from `src` into `dst`, returning the number copied, which is the length of the
+/// shorter slice.
+pub fn copy_from<T: Clone>(dst: &mut [T], src: &[T]) -> uint {
+    let n = min(dst.len(), src.len());
+    for i in range(0, n) {
+        dst[i] = src[i].clone();
+    }
+    n
+}
+
+/// Set every element to a clone of `value`.
+pub fn fill<T: Clone>(xs: &mut [T], value: &T) {
+    for x in mut_iter(xs) {
+        *x = value.clone();
+    }
+}
 
 impl<'a, T> Container for &'a [T] {
     fn len(&self) -> uint {
@@ -150,6 +811,23 @@ impl<'a, T> Container for &'a [T] {
     }
 }
 
+impl<'a, T: Eq> Eq for &'a [T] {
+    fn eq(&self, other: &&'a [T]) -> bool {
+        self.len() == other.len() && iter(*self).zip(iter(*other)).all(|(x, y)| *x == *y)
+    }
+}
+
+impl<'a, T: Ord> Ord for &'a [T] {
+    /// Lexicographic comparison, where a proper prefix orders first.
+    fn lt(&self, other: &&'a [T]) -> bool {
+        for (x, y) in iter(*self).zip(iter(*other)) {
+            if *x < *y { return true }
+            if *y < *x { return false }
+        }
+        self.len() < other.len()
+    }
+}
+
 pub fn iter<'a, T>(xs: &'a [T]) -> Items<'a, T> {
     unsafe {
         let p = to_ptr(xs);
@@ -177,7 +855,7 @@ pub fn mut_iter<'a, T>(xs: &'a mut [T]) -> MutItems<'a, T> {
 }
 
 macro_rules! iterator {
-    (struct $name:ident -> $ptr:ty, $elem:ty) => {
+    (struct $name:ident -> $ptr:ty, $elem:ty, $slice:ty) => {
         /// An iterator for iterating over a slice.
         pub struct $name<'a, T> {
             priv ptr: $ptr,
@@ -208,8 +886,7 @@ macro_rules! iterator {
 
             #[inline]
             fn size_hint(&self) -> (uint, Option<uint>) {
-                let diff = (self.end as uint) - (self.ptr as uint);
-                let exact = diff / nonzero_size_of::<T>();
+                let exact = self.remaining();
                 (exact, Some(exact))
             }
         }
@@ -233,14 +910,329 @@ macro_rules! iterator {
                 }
             }
         }
+
+        impl<'a, T> $name<'a, T> {
+            #[inline]
+            fn remaining(&self) -> uint {
+                let diff = (self.end as uint) - (self.ptr as uint);
+                diff / nonzero_size_of::<T>()
+            }
+
+            // Advance past the next `n` elements, returning them as a slice. `n` must not be
+            // greater than the number of elements left.
+            #[inline]
+            fn take_front(&mut self, n: uint) -> $slice {
+                unsafe {
+                    let data = self.ptr as *T;
+                    self.ptr = if size_of::<T>() == 0 {
+                        transmute(self.ptr as uint + n)
+                    } else {
+                        offset(self.ptr as *T, n as int) as $ptr
+                    };
+                    transmute(Slice { data: data, len: n })
+                }
+            }
+
+            // Drop the last `n` elements, returning them as a slice. `n` must not be greater than
+            // the number of elements left.
+            #[inline]
+            fn take_back(&mut self, n: uint) -> $slice {
+                unsafe {
+                    self.end = if size_of::<T>() == 0 {
+                        transmute(self.end as uint - n)
+                    } else {
+                        offset(self.end as *T, -(n as int)) as $ptr
+                    };
+                    transmute(Slice { data: self.end as *T, len: n })
+                }
+            }
+        }
     }
 }
 
-iterator!{struct Items -> *T, &'a T}
-iterator!{struct MutItems -> *mut T, &'a mut T}
+iterator!{struct Items -> *T, &'a T, &'a [T]}
+iterator!{struct MutItems -> *mut T, &'a mut T, &'a mut [T]}
+
+impl<'a, T> Items<'a, T> {
+    // Return the elements left, without advancing the iterator.
+    #[inline]
+    fn as_slice(&self) -> &'a [T] {
+        unsafe { transmute(Slice { data: self.ptr, len: self.remaining() }) }
+    }
+}
 
 impl<'a, T> Clone for Items<'a, T> {
     fn clone(&self) -> Items<'a, T> {
         *self
     }
 }
+
+/// Return an iterator over non-overlapping subslices of `size` elements, with a shorter last
+/// subslice if `size` doesn't divide the length. Calls `abort` if `size` is zero.
+pub fn chunks<'a, T>(xs: &'a [T], size: uint) -> Chunks<'a, T> {
+    if size == 0 {
+        abort()
+    }
+    Chunks { iter: iter(xs), size: size }
+}
+
+/// Return an iterator over non-overlapping mutable subslices of `size` elements, with a shorter
+/// last subslice if `size` doesn't divide the length. Calls `abort` if `size` is zero.
+pub fn mut_chunks<'a, T>(xs: &'a mut [T], size: uint) -> MutChunks<'a, T> {
+    if size == 0 {
+        abort()
+    }
+    MutChunks { iter: mut_iter(xs), size: size }
+}
+
+macro_rules! chunks_iterator {
+    (struct $name:ident -> $items:ident, $slice:ty) => {
+        /// An iterator over a slice in non-overlapping chunks.
+        pub struct $name<'a, T> {
+            priv iter: $items<'a, T>,
+            priv size: uint
+        }
+
+        impl<'a, T> Iterator<$slice> for $name<'a, T> {
+            #[inline]
+            fn next(&mut self) -> Option<$slice> {
+                let len = self.iter.remaining();
+                if len == 0 {
+                    None
+                } else {
+                    Some(self.iter.take_front(min(len, self.size)))
+                }
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (uint, Option<uint>) {
+                let len = self.iter.remaining();
+                let n = len / self.size + if len % self.size != 0 { 1 } else { 0 };
+                (n, Some(n))
+            }
+        }
+
+        impl<'a, T> DoubleEndedIterator<$slice> for $name<'a, T> {
+            #[inline]
+            fn next_back(&mut self) -> Option<$slice> {
+                let len = self.iter.remaining();
+                if len == 0 {
+                    None
+                } else {
+                    let remainder = len % self.size;
+                    Some(self.iter.take_back(if remainder == 0 { self.size } else { remainder }))
+                }
+            }
+        }
+    }
+}
+
+chunks_iterator!{struct Chunks -> Items, &'a [T]}
+chunks_iterator!{struct MutChunks -> MutItems, &'a mut [T]}
+
+impl<'a, T> Clone for Chunks<'a, T> {
+    fn clone(&self) -> Chunks<'a, T> {
+        *self
+    }
+}
+
+/// Return an iterator over non-overlapping subslices of exactly `size` elements. The elements
+/// left over at the end are available from `ExactChunks::remainder`. Calls `abort` if `size` is
+/// zero.
+pub fn exact_chunks<'a, T>(xs: &'a [T], size: uint) -> ExactChunks<'a, T> {
+    if size == 0 {
+        abort()
+    }
+    let (head, remainder) = split(xs, xs.len() - xs.len() % size);
+    ExactChunks { iter: chunks(head, size), remainder: remainder }
+}
+
+/// An iterator over a slice in non-overlapping chunks of exactly the same size.
+#[deriving(Clone)]
+pub struct ExactChunks<'a, T> {
+    priv iter: Chunks<'a, T>,
+    priv remainder: &'a [T]
+}
+
+impl<'a, T> ExactChunks<'a, T> {
+    /// Return the elements left over at the end, fewer than the chunk size.
+    #[inline]
+    pub fn remainder(&self) -> &'a [T] {
+        self.remainder
+    }
+}
+
+impl<'a, T> Iterator<&'a [T]> for ExactChunks<'a, T> {
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> { self.iter.next() }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) { self.iter.size_hint() }
+}
+
+impl<'a, T> DoubleEndedIterator<&'a [T]> for ExactChunks<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> { self.iter.next_back() }
+}
+
+/// Return an iterator over all the overlapping subslices of `size` elements. Calls `abort` if
+/// `size` is zero.
+pub fn windows<'a, T>(xs: &'a [T], size: uint) -> Windows<'a, T> {
+    if size == 0 {
+        abort()
+    }
+    Windows { iter: iter(xs), size: size }
+}
+
+/// An iterator over the overlapping subslices of a slice.
+#[deriving(Clone)]
+pub struct Windows<'a, T> {
+    priv iter: Items<'a, T>,
+    priv size: uint
+}
+
+impl<'a, T> Iterator<&'a [T]> for Windows<'a, T> {
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        let v = self.iter.as_slice();
+        if self.size > v.len() {
+            None
+        } else {
+            self.iter.next();
+            Some(slice_to(v, self.size))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let len = self.iter.remaining();
+        let n = if self.size > len { 0 } else { len - self.size + 1 };
+        (n, Some(n))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator<&'a [T]> for Windows<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        let v = self.iter.as_slice();
+        if self.size > v.len() {
+            None
+        } else {
+            self.iter.next_back();
+            Some(slice_from(v, v.len() - self.size))
+        }
+    }
+}
+
+/// Return an iterator over the subslices separated by elements matching the predicate. The
+/// matching elements are not included, so `n` matches always produce `n + 1` subslices.
+pub fn split_by<'a, T>(xs: &'a [T], predicate: 'a |&T| -> bool) -> Splits<'a, T> {
+    Splits { iter: iter(xs), predicate: predicate, finished: false }
+}
+
+/// Like `split_by`, but splitting at most `n` times, at the first matches.
+pub fn splitn<'a, T>(xs: &'a [T], n: uint, predicate: 'a |&T| -> bool) -> SplitsN<'a, T> {
+    SplitsN { iter: split_by(xs, predicate), count: n, invert: false }
+}
+
+/// Like `split_by`, but yielding the subslices from the end of the slice.
+pub fn rsplit<'a, T>(xs: &'a [T], predicate: 'a |&T| -> bool) -> Invert<Splits<'a, T>> {
+    split_by(xs, predicate).invert()
+}
+
+/// Like `rsplit`, but splitting at most `n` times, at the last matches.
+pub fn rsplitn<'a, T>(xs: &'a [T], n: uint, predicate: 'a |&T| -> bool) -> SplitsN<'a, T> {
+    SplitsN { iter: split_by(xs, predicate), count: n, invert: true }
+}
+
+/// An iterator over the subslices of a slice separated by elements matching a predicate.
+pub struct Splits<'a, T> {
+    priv iter: Items<'a, T>,
+    priv predicate: 'a |&T| -> bool,
+    priv finished: bool
+}
+
+impl<'a, T> Splits<'a, T> {
+    #[inline]
+    fn finish(&mut self) -> Option<&'a [T]> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            let len = self.iter.remaining();
+            Some(self.iter.take_front(len))
+        }
+    }
+}
+
+impl<'a, T> Iterator<&'a [T]> for Splits<'a, T> {
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.finished {
+            return None
+        }
+        match self.iter.clone().position(|x| (self.predicate)(x)) {
+            None => self.finish(),
+            Some(idx) => {
+                let ret = self.iter.take_front(idx);
+                self.iter.next(); // skip the separator
+                Some(ret)
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.iter.remaining() + 1))
+        }
+    }
+}
+
+impl<'a, T> DoubleEndedIterator<&'a [T]> for Splits<'a, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.finished {
+            return None
+        }
+        match self.iter.clone().invert().position(|x| (self.predicate)(x)) {
+            None => self.finish(),
+            Some(pos) => {
+                let ret = self.iter.take_back(pos);
+                self.iter.next_back(); // skip the separator
+                Some(ret)
+            }
+        }
+    }
+}
+
+/// An iterator over the subslices of a slice separated by elements matching a predicate, splitting
+/// a limited number of times.
+pub struct SplitsN<'a, T> {
+    priv iter: Splits<'a, T>,
+    priv count: uint,
+    priv invert: bool
+}
+
+impl<'a, T> Iterator<&'a [T]> for SplitsN<'a, T> {
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.count == 0 {
+            self.iter.finish()
+        } else {
+            self.count -= 1;
+            if self.invert { self.iter.next_back() } else { self.iter.next() }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        if self.iter.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(min(self.count, self.iter.iter.remaining()) + 1))
+        }
+    }
+}
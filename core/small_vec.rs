@@ -0,0 +1,156 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A vector storing a small number of elements inline before spilling to the heap
+
+use array_vec;
+use array_vec::{Array, ArrayVec};
+use container::Container;
+use iter::{Iterator, DoubleEndedIterator, FromIterator, Extendable};
+use option::{Option, Some, None};
+use ptr::copy_nonoverlapping_memory;
+use result::{Ok, Err};
+use slice::{to_mut_ptr, to_ptr};
+use vec;
+use vec::Vec;
+
+/// A vector storing up to the capacity of the array type `A` inline, and moving the elements to a
+/// heap allocation when it grows beyond that.
+pub struct SmallVec<T, A> {
+    priv inline: ArrayVec<T, A>,
+    priv heap: Vec<T> // holds the elements once it has a capacity, leaving `inline` empty
+}
+
+impl<T, A: Array<T>> SmallVec<T, A> {
+    #[inline]
+    pub fn new() -> SmallVec<T, A> {
+        SmallVec { inline: ArrayVec::new(), heap: Vec::new() }
+    }
+
+    /// Return true if the elements have been moved to the heap.
+    #[inline]
+    pub fn spilled(&self) -> bool {
+        self.heap.capacity() != 0
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> uint {
+        if self.spilled() { self.heap.capacity() } else { self.inline.capacity() }
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.spilled() {
+            self.heap.push(value)
+        } else {
+            match self.inline.push(value) {
+                Ok(()) => (),
+                Err(value) => {
+                    self.spill();
+                    self.heap.push(value)
+                }
+            }
+        }
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.spilled() { self.heap.pop() } else { self.inline.pop() }
+    }
+
+    pub fn truncate(&mut self, len: uint) {
+        if self.spilled() { self.heap.truncate(len) } else { self.inline.truncate(len) }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    #[inline]
+    pub fn as_slice<'a>(&'a self) -> &'a [T] {
+        if self.spilled() { self.heap.as_slice() } else { self.inline.as_slice() }
+    }
+
+    #[inline]
+    pub fn as_mut_slice<'a>(&'a mut self) -> &'a mut [T] {
+        if self.spilled() { self.heap.as_mut_slice() } else { self.inline.as_mut_slice() }
+    }
+
+    pub fn move_iter(self) -> MoveItems<T, A> {
+        let SmallVec { inline, heap } = self;
+        MoveItems { inline: inline.move_iter(), heap: heap.move_iter() }
+    }
+
+    // Move the inline elements to a heap allocation with twice the inline capacity.
+    fn spill(&mut self) {
+        let len = self.inline.len();
+        self.heap.reserve(self.inline.capacity() * 2);
+        unsafe {
+            copy_nonoverlapping_memory(to_mut_ptr(self.heap.as_mut_slice()),
+                                       to_ptr(self.inline.as_slice()), len);
+            self.inline.set_len(0);
+            self.heap.set_len(len);
+        }
+    }
+}
+
+impl<T, A: Array<T>> Container for SmallVec<T, A> {
+    #[inline]
+    fn len(&self) -> uint {
+        if self.spilled() { self.heap.len() } else { self.inline.len() }
+    }
+}
+
+impl<T, A: Array<T>> FromIterator<T> for SmallVec<T, A> {
+    fn from_iterator<I: Iterator<T>>(iterator: &mut I) -> SmallVec<T, A> {
+        let mut xs = SmallVec::new();
+        xs.extend(iterator);
+        xs
+    }
+}
+
+impl<T, A: Array<T>> Extendable<T> for SmallVec<T, A> {
+    fn extend<I: Iterator<T>>(&mut self, iterator: &mut I) {
+        for x in *iterator {
+            self.push(x);
+        }
+    }
+}
+
+/// An iterator moving the elements out of a `SmallVec`. Only one of the two halves is non-empty.
+pub struct MoveItems<T, A> {
+    priv inline: array_vec::MoveItems<T, A>,
+    priv heap: vec::MoveItems<T>
+}
+
+impl<T, A: Array<T>> Iterator<T> for MoveItems<T, A> {
+    fn next(&mut self) -> Option<T> {
+        match self.inline.next() {
+            None => self.heap.next(),
+            x => x
+        }
+    }
+
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (inline, _) = self.inline.size_hint();
+        let (heap, _) = self.heap.size_hint();
+        (inline + heap, Some(inline + heap))
+    }
+}
+
+impl<T, A: Array<T>> DoubleEndedIterator<T> for MoveItems<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        match self.heap.next_back() {
+            None => self.inline.next_back(),
+            x => x
+        }
+    }
+}
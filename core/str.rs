@@ -9,8 +9,16 @@
 // except according to those terms.
 
 use container::Container;
-use cmp::Eq;
+use cmp::{Eq, Ord, min};
 use mem::transmute;
+use option::{Option, Some, None};
+use result::{Result, Ok, Err};
+use iter::{Iterator, DoubleEndedIterator, range};
+use char;
+use slice;
+use fail::abort;
+#[cfg(libc)]
+use string::String;
 
 impl<'a> Container for &'a str {
     #[no_split_stack]
@@ -26,11 +34,128 @@ impl<'a> Eq for &'a str {
 	}
 }
 
+impl<'a> Ord for &'a str {
+    /// Lexicographic comparison of the bytes, which is the same as by code point.
+    #[inline]
+    fn lt(&self, other: & &'a str) -> bool {
+        as_bytes(*self) < as_bytes(*other)
+    }
+}
+
 #[inline(always)]
 pub fn as_bytes<'a>(string: &'a str) -> &'a [u8] {
     unsafe { transmute(string) }
 }
 
+/// Convert a byte slice to a string slice, or return `None` if it isn't well-formed UTF-8.
+pub fn from_utf8<'a>(v: &'a [u8]) -> Option<&'a str> {
+    if is_utf8(v) {
+        Some(unsafe { transmute(v) })
+    } else {
+        None
+    }
+}
+
+/// Convert a byte slice to a string slice without checking that it is well-formed UTF-8.
+#[inline(always)]
+pub unsafe fn from_utf8_unchecked<'a>(v: &'a [u8]) -> &'a str {
+    transmute(v)
+}
+
+/// Return true if the bytes are well-formed UTF-8.
+pub fn is_utf8(v: &[u8]) -> bool {
+    let mut i = 0;
+    while i < v.len() {
+        match utf8_sequence_len(v, i) {
+            Ok(width) => i += width,
+            Err(_) => return false
+        }
+    }
+    true
+}
+
+/// Check the UTF-8 sequence starting at index `i`. Return the width of a well-formed sequence, or
+/// otherwise the length of its maximal ill-formed prefix, which is the number of bytes that should
+/// be replaced by a single U+FFFD.
+pub fn utf8_sequence_len(v: &[u8], i: uint) -> Result<uint, uint> {
+    let first = v[i];
+    let (width, lo, hi) = match first {
+        0x00 .. 0x7F => return Ok(1),
+        0xC2 .. 0xDF => (2, 0x80, 0xBF),
+        0xE0 => (3, 0xA0, 0xBF), // overlong
+        0xED => (3, 0x80, 0x9F), // surrogates
+        0xE1 .. 0xEF => (3, 0x80, 0xBF),
+        0xF0 => (4, 0x90, 0xBF), // overlong
+        0xF4 => (4, 0x80, 0x8F), // above `char::MAX`
+        0xF1 .. 0xF3 => (4, 0x80, 0xBF),
+        _ => return Err(1)
+    };
+    if i + 1 >= v.len() || v[i + 1] < lo || v[i + 1] > hi {
+        return Err(1)
+    }
+    for k in range(2, width) {
+        if i + k >= v.len() || v[i + k] & 0xC0 != 0x80 {
+            return Err(k)
+        }
+    }
+    Ok(width)
+}
+
+/// A decoded character and the index of the next one.
+pub struct CharRange {
+    ch: char,
+    next: uint
+}
+
+/// Return the width in bytes of the UTF-8 sequence starting with the byte `first`.
+#[inline]
+pub fn utf8_char_width(first: u8) -> uint {
+    if first < 0x80 {
+        1
+    } else if first < 0xE0 {
+        2
+    } else if first < 0xF0 {
+        3
+    } else {
+        4
+    }
+}
+
+/// Return true if the index is at the start of a character or at the end of the string.
+#[inline]
+pub fn is_char_boundary(s: &str, index: uint) -> bool {
+    let bytes = as_bytes(s);
+    index == bytes.len() || (index < bytes.len() && bytes[index] & 0xC0 != 0x80)
+}
+
+/// Decode the character starting at byte index `i`, which must be a character boundary.
+#[inline]
+pub fn char_range_at(s: &str, i: uint) -> CharRange {
+    let bytes = as_bytes(s);
+    let first = bytes[i];
+    if first < 0x80 {
+        return CharRange { ch: first as char, next: i + 1 }
+    }
+    let width = utf8_char_width(first);
+    let mut code = (first & (0x7F >> width)) as u32;
+    for j in range(1, width) {
+        code = (code << 6) | (bytes[i + j] & 0x3F) as u32;
+    }
+    CharRange { ch: char::from_u32(code).get(), next: i + width }
+}
+
+/// Decode the character ending just before byte index `end`, which must be a character boundary.
+/// The returned `next` is the index of its first byte.
+#[inline]
+pub fn char_range_at_reverse(s: &str, end: uint) -> CharRange {
+    let bytes = as_bytes(s);
+    let mut i = end - 1;
+    while bytes[i] & 0xC0 == 0x80 {
+        i -= 1;
+    }
+    CharRange { ch: char_range_at(s, i).ch, next: i }
+}
+
 unsafe fn memcmp (a: *u8, b: *u8, len: uint) -> bool {
 	let mut x = 0;
 	let a = a as uint;
@@ -50,3 +175,456 @@ pub unsafe fn eq_slice(a: &str, b:&str) ->bool {
 	
 	al == bl && memcmp(amem, bmem, al)
 }
+
+// Slice by byte indices which are known to be character boundaries.
+#[inline]
+fn unchecked_slice<'a>(s: &'a str, begin: uint, end: uint) -> &'a str {
+    unsafe { transmute(slice::slice(as_bytes(s), begin, end)) }
+}
+
+/// Return a substring by byte indices. Calls `abort` if either index is not a character boundary
+/// or `begin > end`.
+#[inline]
+pub fn slice<'a>(s: &'a str, begin: uint, end: uint) -> &'a str {
+    if begin > end || !is_char_boundary(s, begin) || !is_char_boundary(s, end) {
+        abort()
+    }
+    unchecked_slice(s, begin, end)
+}
+
+/// Return the substring from byte index `begin` to the end.
+#[inline]
+pub fn slice_from<'a>(s: &'a str, begin: uint) -> &'a str {
+    slice(s, begin, s.len())
+}
+
+/// Return the substring from the start to byte index `end`.
+#[inline]
+pub fn slice_to<'a>(s: &'a str, end: uint) -> &'a str {
+    slice(s, 0, end)
+}
+
+/// Return the number of characters in the string.
+pub fn char_len(s: &str) -> uint {
+    let mut n = 0;
+    for &b in slice::iter(as_bytes(s)) {
+        if b & 0xC0 != 0x80 {
+            n += 1;
+        }
+    }
+    n
+}
+
+/// Return an iterator over the characters of the string.
+#[inline]
+pub fn chars<'a>(s: &'a str) -> Chars<'a> {
+    Chars { iter: char_indices(s) }
+}
+
+/// Return an iterator over the characters of the string and their byte indices.
+#[inline]
+pub fn char_indices<'a>(s: &'a str) -> CharIndices<'a> {
+    CharIndices { string: s, front: 0 }
+}
+
+/// An iterator over the characters of a string.
+#[deriving(Clone)]
+pub struct Chars<'a> {
+    priv iter: CharIndices<'a>
+}
+
+impl<'a> Iterator<char> for Chars<'a> {
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        match self.iter.next() {
+            Some((_, c)) => Some(c),
+            None => None
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) { self.iter.size_hint() }
+}
+
+impl<'a> DoubleEndedIterator<char> for Chars<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        match self.iter.next_back() {
+            Some((_, c)) => Some(c),
+            None => None
+        }
+    }
+}
+
+/// An iterator over the characters of a string and their byte indices.
+#[deriving(Clone)]
+pub struct CharIndices<'a> {
+    priv string: &'a str,
+    priv front: uint
+}
+
+impl<'a> Iterator<(uint, char)> for CharIndices<'a> {
+    #[inline]
+    fn next(&mut self) -> Option<(uint, char)> {
+        if self.string.len() == 0 {
+            None
+        } else {
+            let CharRange { ch, next } = char_range_at(self.string, 0);
+            let index = self.front;
+            self.string = unchecked_slice(self.string, next, self.string.len());
+            self.front += next;
+            Some((index, ch))
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let len = self.string.len();
+        ((len + 3) / 4, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator<(uint, char)> for CharIndices<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(uint, char)> {
+        let len = self.string.len();
+        if len == 0 {
+            None
+        } else {
+            let CharRange { ch, next } = char_range_at_reverse(self.string, len);
+            self.string = unchecked_slice(self.string, 0, next);
+            Some((self.front + next, ch))
+        }
+    }
+}
+
+/// Something which can be matched against a character: a `char`, a `&[char]` matching any of its
+/// elements, or a predicate.
+pub trait CharEq {
+    fn matches(&mut self, c: char) -> bool;
+}
+
+impl CharEq for char {
+    #[inline]
+    fn matches(&mut self, c: char) -> bool { *self == c }
+}
+
+impl<'a> CharEq for &'a [char] {
+    #[inline]
+    fn matches(&mut self, c: char) -> bool { slice::contains(*self, &c) }
+}
+
+impl<'a> CharEq for 'a |char| -> bool {
+    #[inline]
+    fn matches(&mut self, c: char) -> bool { (*self)(c) }
+}
+
+impl CharEq for extern "Rust" fn(char) -> bool {
+    #[inline]
+    fn matches(&mut self, c: char) -> bool { (*self)(c) }
+}
+
+/// Return the byte index of the first character matching `m`.
+pub fn find<M: CharEq>(s: &str, mut m: M) -> Option<uint> {
+    for (i, c) in char_indices(s) {
+        if m.matches(c) {
+            return Some(i)
+        }
+    }
+    None
+}
+
+/// Return the byte index of the last character matching `m`.
+pub fn rfind<M: CharEq>(s: &str, mut m: M) -> Option<uint> {
+    for (i, c) in char_indices(s).invert() {
+        if m.matches(c) {
+            return Some(i)
+        }
+    }
+    None
+}
+
+/// Return the byte index of the first occurrence of `needle`.
+#[inline]
+pub fn find_str(s: &str, needle: &str) -> Option<uint> {
+    slice::find_bytes(as_bytes(s), as_bytes(needle))
+}
+
+/// Return the byte index of the last occurrence of `needle`.
+#[inline]
+pub fn rfind_str(s: &str, needle: &str) -> Option<uint> {
+    slice::rfind_bytes(as_bytes(s), as_bytes(needle))
+}
+
+/// Return true if `prefix` is a prefix of the string.
+#[inline]
+pub fn starts_with(s: &str, prefix: &str) -> bool {
+    slice::starts_with(as_bytes(s), as_bytes(prefix))
+}
+
+/// Return true if `suffix` is a suffix of the string.
+#[inline]
+pub fn ends_with(s: &str, suffix: &str) -> bool {
+    slice::ends_with(as_bytes(s), as_bytes(suffix))
+}
+
+/// Return the string with leading whitespace removed.
+pub fn trim_left<'a>(s: &'a str) -> &'a str {
+    match find(s, |c: char| !char::is_whitespace(c)) {
+        Some(i) => unchecked_slice(s, i, s.len()),
+        None => ""
+    }
+}
+
+/// Return the string with trailing whitespace removed.
+pub fn trim_right<'a>(s: &'a str) -> &'a str {
+    match rfind(s, |c: char| !char::is_whitespace(c)) {
+        Some(i) => unchecked_slice(s, 0, char_range_at(s, i).next),
+        None => ""
+    }
+}
+
+/// Return the string with leading and trailing whitespace removed.
+#[inline]
+pub fn trim<'a>(s: &'a str) -> &'a str {
+    trim_left(trim_right(s))
+}
+
+/// Return an iterator over the substrings separated by characters matching `sep`. The matching
+/// characters are not included, so `n` matches always produce `n + 1` substrings.
+#[inline]
+pub fn split<'a, Sep: CharEq>(s: &'a str, sep: Sep) -> CharSplits<'a, Sep> {
+    CharSplits { string: s, sep: sep, finished: false }
+}
+
+/// Like `split`, but splitting at most `n` times, at the first matches.
+#[inline]
+pub fn splitn<'a, Sep: CharEq>(s: &'a str, n: uint, sep: Sep) -> CharSplitsN<'a, Sep> {
+    CharSplitsN { iter: split(s, sep), count: n }
+}
+
+/// An iterator over the substrings of a string separated by characters matching a `CharEq`.
+pub struct CharSplits<'a, Sep> {
+    priv string: &'a str,
+    priv sep: Sep,
+    priv finished: bool
+}
+
+impl<'a, Sep> CharSplits<'a, Sep> {
+    #[inline]
+    fn finish(&mut self) -> Option<&'a str> {
+        if self.finished {
+            None
+        } else {
+            self.finished = true;
+            Some(self.string)
+        }
+    }
+}
+
+impl<'a, Sep: CharEq> Iterator<&'a str> for CharSplits<'a, Sep> {
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        if self.finished {
+            return None
+        }
+        let string = self.string;
+        for (i, c) in char_indices(string) {
+            if self.sep.matches(c) {
+                self.string = unchecked_slice(string, i + char::len_utf8_bytes(c), string.len());
+                return Some(unchecked_slice(string, 0, i))
+            }
+        }
+        self.finish()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(self.string.len() + 1))
+        }
+    }
+}
+
+impl<'a, Sep: CharEq> DoubleEndedIterator<&'a str> for CharSplits<'a, Sep> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        if self.finished {
+            return None
+        }
+        let string = self.string;
+        for (i, c) in char_indices(string).invert() {
+            if self.sep.matches(c) {
+                self.string = unchecked_slice(string, 0, i);
+                return Some(unchecked_slice(string, i + char::len_utf8_bytes(c), string.len()))
+            }
+        }
+        self.finish()
+    }
+}
+
+/// An iterator over the substrings of a string separated by characters matching a `CharEq`,
+/// splitting a limited number of times.
+pub struct CharSplitsN<'a, Sep> {
+    priv iter: CharSplits<'a, Sep>,
+    priv count: uint
+}
+
+impl<'a, Sep: CharEq> Iterator<&'a str> for CharSplitsN<'a, Sep> {
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        if self.count == 0 {
+            self.iter.finish()
+        } else {
+            self.count -= 1;
+            self.iter.next()
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        if self.iter.finished {
+            (0, Some(0))
+        } else {
+            (1, Some(min(self.count, self.iter.string.len()) + 1))
+        }
+    }
+}
+
+/// Return an iterator over the lines of the string, without their `\n` or `\r\n` terminators. A
+/// final line terminator doesn't produce an empty last line.
+#[inline]
+pub fn lines<'a>(s: &'a str) -> Lines<'a> {
+    Lines { string: s }
+}
+
+/// An iterator over the lines of a string.
+#[deriving(Clone)]
+pub struct Lines<'a> {
+    priv string: &'a str
+}
+
+#[inline]
+fn strip_cr<'a>(line: &'a str) -> &'a str {
+    if ends_with(line, "\r") {
+        unchecked_slice(line, 0, line.len() - 1)
+    } else {
+        line
+    }
+}
+
+impl<'a> Iterator<&'a str> for Lines<'a> {
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        let string = self.string;
+        if string.len() == 0 {
+            return None
+        }
+        match slice::memchr(as_bytes(string), '\n' as u8) {
+            Some(i) => {
+                self.string = unchecked_slice(string, i + 1, string.len());
+                Some(strip_cr(unchecked_slice(string, 0, i)))
+            }
+            None => {
+                self.string = "";
+                Some(strip_cr(string))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let len = self.string.len();
+        (if len == 0 { 0 } else { 1 }, Some(len))
+    }
+}
+
+impl<'a> DoubleEndedIterator<&'a str> for Lines<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        let string = self.string;
+        if string.len() == 0 {
+            return None
+        }
+        let body = if ends_with(string, "\n") {
+            unchecked_slice(string, 0, string.len() - 1)
+        } else {
+            string
+        };
+        match slice::memrchr(as_bytes(body), '\n' as u8) {
+            Some(i) => {
+                self.string = unchecked_slice(string, 0, i + 1);
+                Some(strip_cr(unchecked_slice(body, i + 1, body.len())))
+            }
+            None => {
+                self.string = "";
+                Some(strip_cr(body))
+            }
+        }
+    }
+}
+
+/// Return an iterator over the non-empty substrings separated by whitespace.
+#[inline]
+pub fn words<'a>(s: &'a str) -> Words<'a> {
+    Words { iter: split(s, char::is_whitespace) }
+}
+
+/// An iterator over the whitespace-separated words of a string.
+pub struct Words<'a> {
+    priv iter: CharSplits<'a, extern "Rust" fn(char) -> bool>
+}
+
+impl<'a> Iterator<&'a str> for Words<'a> {
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        for word in self.iter {
+            if word.len() != 0 {
+                return Some(word)
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+impl<'a> DoubleEndedIterator<&'a str> for Words<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        loop {
+            match self.iter.next_back() {
+                Some(word) => if word.len() != 0 { return Some(word) },
+                None => return None
+            }
+        }
+    }
+}
+
+/// Return a copy of the string with every non-overlapping occurrence of `from` replaced by `to`.
+/// An empty `from` matches nothing.
+#[cfg(libc)]
+pub fn replace(s: &str, from: &str, to: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut rest = s;
+    if from.len() != 0 {
+        loop {
+            match find_str(rest, from) {
+                Some(i) => {
+                    result.push_str(unchecked_slice(rest, 0, i));
+                    result.push_str(to);
+                    rest = unchecked_slice(rest, i + from.len(), rest.len());
+                }
+                None => break
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
@@ -0,0 +1,231 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! An owned, growable UTF-8 string
+
+use container::Container;
+use clone::Clone;
+use cmp::{Eq, Ord};
+use char;
+use fmt;
+use fmt::Show;
+use hash::HashBytes;
+use iter::{Iterator, FromIterator, Extendable};
+use mem::transmute;
+use option::{Option, Some, None};
+use result::{Result, Ok, Err};
+use ptr::{copy_nonoverlapping_memory, offset};
+use slice::{slice, slice_to, to_mut_ptr, to_ptr};
+use str;
+use str::{as_bytes, is_char_boundary, is_utf8, utf8_sequence_len};
+use vec::Vec;
+use fail::abort;
+
+static REPLACEMENT: &'static str = "\uFFFD";
+
+/// A UTF-8 string backed by a `Vec<u8>`.
+pub struct String {
+    priv vec: Vec<u8>
+}
+
+impl String {
+    /// Create an empty string.
+    #[inline]
+    pub fn new() -> String {
+        String { vec: Vec::new() }
+    }
+
+    /// Create an empty string with space for at least `capacity` bytes.
+    #[inline]
+    pub fn with_capacity(capacity: uint) -> String {
+        String { vec: Vec::with_capacity(capacity) }
+    }
+
+    /// Create a string holding a copy of a string slice.
+    pub fn from_str(s: &str) -> String {
+        let mut string = String::with_capacity(s.len());
+        string.push_str(s);
+        string
+    }
+
+    /// Convert a vector of bytes to a string, or give the vector back if it isn't well-formed
+    /// UTF-8.
+    pub fn from_utf8(vec: Vec<u8>) -> Result<String, Vec<u8>> {
+        if is_utf8(vec.as_slice()) {
+            Ok(String { vec: vec })
+        } else {
+            Err(vec)
+        }
+    }
+
+    /// Convert a vector of bytes to a string without checking that it is well-formed UTF-8.
+    #[inline]
+    pub unsafe fn from_utf8_unchecked(vec: Vec<u8>) -> String {
+        String { vec: vec }
+    }
+
+    /// Convert bytes to a string, replacing each maximal ill-formed subsequence with U+FFFD.
+    pub fn from_utf8_lossy(v: &[u8]) -> String {
+        let mut string = String::with_capacity(v.len());
+        let mut start = 0;
+        let mut i = 0;
+        while i < v.len() {
+            match utf8_sequence_len(v, i) {
+                Ok(width) => i += width,
+                Err(n) => {
+                    string.push_bytes(slice(v, start, i));
+                    string.push_str(REPLACEMENT);
+                    i += n;
+                    start = i;
+                }
+            }
+        }
+        string.push_bytes(slice(v, start, i));
+        string
+    }
+
+    /// Return the number of bytes the string can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> uint {
+        self.vec.capacity()
+    }
+
+    /// Reserve space for at least `capacity` bytes in total.
+    #[inline]
+    pub fn reserve(&mut self, capacity: uint) {
+        if capacity > self.vec.capacity() {
+            self.vec.reserve(capacity)
+        }
+    }
+
+    /// Append a character.
+    #[inline]
+    pub fn push_char(&mut self, c: char) {
+        let mut buf = [0u8, ..4];
+        let n = char::encode_utf8(c, buf);
+        self.push_bytes(slice_to(buf, n));
+    }
+
+    /// Append a string slice.
+    #[inline]
+    pub fn push_str(&mut self, s: &str) {
+        self.push_bytes(as_bytes(s))
+    }
+
+    // The bytes must be well-formed UTF-8.
+    fn push_bytes(&mut self, bytes: &[u8]) {
+        let len = self.vec.len();
+        self.vec.reserve_additional(bytes.len());
+        unsafe {
+            let end = offset(to_mut_ptr(self.vec.as_mut_slice()) as *u8, len as int) as *mut u8;
+            copy_nonoverlapping_memory(end, to_ptr(bytes), bytes.len());
+            self.vec.set_len(len + bytes.len());
+        }
+    }
+
+    /// Remove and return the last character, or `None` if the string is empty.
+    pub fn pop_char(&mut self) -> Option<char> {
+        let len = self.len();
+        if len == 0 {
+            None
+        } else {
+            let range = str::char_range_at_reverse(self.as_slice(), len);
+            self.vec.truncate(range.next);
+            Some(range.ch)
+        }
+    }
+
+    /// Shorten the string to `len` bytes. Calls `abort` if `len` is not a character boundary.
+    pub fn truncate(&mut self, len: uint) {
+        if len < self.len() {
+            if !is_char_boundary(self.as_slice(), len) {
+                abort()
+            }
+            self.vec.truncate(len)
+        }
+    }
+
+    /// Return a string slice of the whole string.
+    #[inline]
+    pub fn as_slice<'a>(&'a self) -> &'a str {
+        unsafe { transmute(self.vec.as_slice()) }
+    }
+
+    /// Return the UTF-8 bytes of the string.
+    #[inline]
+    pub fn as_bytes<'a>(&'a self) -> &'a [u8] {
+        self.vec.as_slice()
+    }
+
+    /// Convert the string into its vector of UTF-8 bytes.
+    #[inline]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.vec
+    }
+}
+
+impl Container for String {
+    #[inline]
+    fn len(&self) -> uint {
+        self.vec.len()
+    }
+}
+
+impl Clone for String {
+    #[inline]
+    fn clone(&self) -> String {
+        String { vec: self.vec.clone() }
+    }
+}
+
+impl Eq for String {
+    #[inline]
+    fn eq(&self, other: &String) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl Ord for String {
+    #[inline]
+    fn lt(&self, other: &String) -> bool {
+        self.as_slice() < other.as_slice()
+    }
+}
+
+impl HashBytes for String {
+    #[inline]
+    fn hash_bytes(&self, f: |&[u8]|) {
+        self.as_slice().hash_bytes(f)
+    }
+}
+
+impl FromIterator<char> for String {
+    fn from_iterator<T: Iterator<char>>(iterator: &mut T) -> String {
+        let mut string = String::new();
+        string.extend(iterator);
+        string
+    }
+}
+
+impl Extendable<char> for String {
+    fn extend<T: Iterator<char>>(&mut self, iterator: &mut T) {
+        let (lower, _) = iterator.size_hint();
+        self.vec.reserve_additional(lower);
+        for c in *iterator {
+            self.push_char(c);
+        }
+    }
+}
+
+impl Show for String {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
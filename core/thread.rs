@@ -20,6 +20,7 @@ use concurrent::Queue;
 use vec::Vec;
 use option::{Option, Some, None};
 use clone::Clone;
+use iter::{Iterator, range};
 
 #[deriving(Eq, Clone)]
 pub enum TimeoutStatus {
@@ -324,8 +325,7 @@ impl Pool {
     pub fn new(n_threads: uint) -> Pool {
         let queue = Queue::<Option<proc()>>::new();
         let mut pool = Vec::with_capacity(n_threads);
-        let mut i = 0;
-        while i < n_threads {
+        for _ in range(0, n_threads) {
             let send_queue = queue.clone();
             pool.push(spawn(proc() {
                 let queue = send_queue;
@@ -336,7 +336,6 @@ impl Pool {
                     }
                 }
             }));
-            i += 1;
         }
         Pool { queue: queue, pool: pool }
     }
@@ -349,10 +348,8 @@ impl Pool {
 
 impl Drop for Pool {
     fn drop(&mut self) {
-        let mut i = 0;
-        while i < self.pool.len() {
+        for _ in range(0, self.pool.len()) {
             self.queue.push(None);
-            i += 1;
         }
     }
 }
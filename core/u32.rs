@@ -8,6 +8,15 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use mem::transmute;
+use num;
+use option::Option;
+
+pub static BITS: uint = 32;
+pub static BYTES: uint = 4;
+pub static MIN: u32 = 0;
+pub static MAX: u32 = !0;
+
 extern "rust-intrinsic" {
     fn u32_add_with_overflow(x: u32, y: u32) -> (u32, bool);
     fn u32_sub_with_overflow(x: u32, y: u32) -> (u32, bool);
@@ -52,3 +61,78 @@ pub fn to_le(x: u32) -> u32 {
 pub fn to_le(x: u32) -> u32 {
     x
 }
+
+/// Return the number of one bits in `x`.
+#[inline(always)]
+pub fn count_ones(x: u32) -> uint {
+    ::i32::count_ones(x as i32)
+}
+
+/// Return the number of zero bits above the highest one bit in `x`.
+#[inline(always)]
+pub fn leading_zeros(x: u32) -> uint {
+    ::i32::leading_zeros(x as i32)
+}
+
+/// Return the number of zero bits below the lowest one bit in `x`.
+#[inline(always)]
+pub fn trailing_zeros(x: u32) -> uint {
+    ::i32::trailing_zeros(x as i32)
+}
+
+/// Convert from big endian to the target's byte order.
+#[inline(always)]
+pub fn from_be(x: u32) -> u32 {
+    to_be(x)
+}
+
+/// Convert from little endian to the target's byte order.
+#[inline(always)]
+pub fn from_le(x: u32) -> u32 {
+    to_le(x)
+}
+
+/// Return the big endian byte representation of `x`.
+#[inline]
+pub fn to_be_bytes(x: u32) -> [u8, ..BYTES] {
+    unsafe { transmute(to_be(x)) }
+}
+
+/// Return the little endian byte representation of `x`.
+#[inline]
+pub fn to_le_bytes(x: u32) -> [u8, ..BYTES] {
+    unsafe { transmute(to_le(x)) }
+}
+
+/// Create a value from its big endian byte representation.
+#[inline]
+pub fn from_be_bytes(bytes: [u8, ..BYTES]) -> u32 {
+    from_be(unsafe { transmute(bytes) })
+}
+
+/// Create a value from its little endian byte representation.
+#[inline]
+pub fn from_le_bytes(bytes: [u8, ..BYTES]) -> u32 {
+    from_le(unsafe { transmute(bytes) })
+}
+
+/// Parse a `u32` from ASCII digits in the given radix, with an optional leading `+`.
+/// Returns `None` if the digits are invalid or the value overflows. Calls `abort` if `radix` is not
+/// between 2 and 36.
+#[inline]
+pub fn from_bytes_radix(bytes: &[u8], radix: uint) -> Option<u32> {
+    num::from_bytes_radix(bytes, radix)
+}
+
+/// Parse a `u32` from a string in the given radix, as `from_bytes_radix` does.
+#[inline]
+pub fn from_str_radix(s: &str, radix: uint) -> Option<u32> {
+    num::from_str_radix(s, radix)
+}
+
+/// Write `x` in the given radix to the start of `buf`, returning the written string. Calls `abort`
+/// if `radix` is not between 2 and 36 or `buf` is too short for the digits.
+#[inline]
+pub fn to_str_radix<'a>(x: u32, radix: uint, buf: &'a mut [u8]) -> &'a str {
+    num::to_str_radix(x, radix, buf)
+}
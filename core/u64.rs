@@ -8,6 +8,16 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use mem::transmute;
+use num;
+use option::Option;
+
+pub static BITS: uint = 64;
+pub static BYTES: uint = 8;
+
+pub static MIN: u64 = 0;
+pub static MAX: u64 = !0;
+
 extern "rust-intrinsic" {
     fn u64_add_with_overflow(x: u64, y: u64) -> (u64, bool);
     fn u64_sub_with_overflow(x: u64, y: u64) -> (u64, bool);
@@ -52,3 +62,78 @@ pub fn to_le(x: u64) -> u64 {
 pub fn to_le(x: u64) -> u64 {
     x
 }
+
+/// Return the number of one bits in `x`.
+#[inline(always)]
+pub fn count_ones(x: u64) -> uint {
+    ::i64::count_ones(x as i64)
+}
+
+/// Return the number of zero bits above the highest one bit in `x`.
+#[inline(always)]
+pub fn leading_zeros(x: u64) -> uint {
+    ::i64::leading_zeros(x as i64)
+}
+
+/// Return the number of zero bits below the lowest one bit in `x`.
+#[inline(always)]
+pub fn trailing_zeros(x: u64) -> uint {
+    ::i64::trailing_zeros(x as i64)
+}
+
+/// Convert from big endian to the target's byte order.
+#[inline(always)]
+pub fn from_be(x: u64) -> u64 {
+    to_be(x)
+}
+
+/// Convert from little endian to the target's byte order.
+#[inline(always)]
+pub fn from_le(x: u64) -> u64 {
+    to_le(x)
+}
+
+/// Return the big endian byte representation of `x`.
+#[inline]
+pub fn to_be_bytes(x: u64) -> [u8, ..BYTES] {
+    unsafe { transmute(to_be(x)) }
+}
+
+/// Return the little endian byte representation of `x`.
+#[inline]
+pub fn to_le_bytes(x: u64) -> [u8, ..BYTES] {
+    unsafe { transmute(to_le(x)) }
+}
+
+/// Create a value from its big endian byte representation.
+#[inline]
+pub fn from_be_bytes(bytes: [u8, ..BYTES]) -> u64 {
+    from_be(unsafe { transmute(bytes) })
+}
+
+/// Create a value from its little endian byte representation.
+#[inline]
+pub fn from_le_bytes(bytes: [u8, ..BYTES]) -> u64 {
+    from_le(unsafe { transmute(bytes) })
+}
+
+/// Parse a `u64` from ASCII digits in the given radix, with an optional leading `+`.
+/// Returns `None` if the digits are invalid or the value overflows. Calls `abort` if `radix` is not
+/// between 2 and 36.
+#[inline]
+pub fn from_bytes_radix(bytes: &[u8], radix: uint) -> Option<u64> {
+    num::from_bytes_radix(bytes, radix)
+}
+
+/// Parse a `u64` from a string in the given radix, as `from_bytes_radix` does.
+#[inline]
+pub fn from_str_radix(s: &str, radix: uint) -> Option<u64> {
+    num::from_str_radix(s, radix)
+}
+
+/// Write `x` in the given radix to the start of `buf`, returning the written string. Calls `abort`
+/// if `radix` is not between 2 and 36 or `buf` is too short for the digits.
+#[inline]
+pub fn to_str_radix<'a>(x: u64, radix: uint, buf: &'a mut [u8]) -> &'a str {
+    num::to_str_radix(x, radix, buf)
+}
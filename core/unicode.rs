@@ -0,0 +1,2865 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// NOTE: generated by etc/unicode.py from the Unicode Character Database 14.0.0, do not edit
+
+//! Unicode property tables backing the classification functions in `char`.
+
+use container::Container;
+use option::{Option, Some, None};
+
+/// The version of the Unicode Character Database the tables were generated from.
+pub static UNICODE_VERSION: (uint, uint, uint) = (14, 0, 0);
+
+fn bsearch_range_table(c: char, r: &'static [(char, char)]) -> bool {
+    let mut lo = 0;
+    let mut hi = r.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (start, end) = r[mid];
+        if c < start {
+            hi = mid
+        } else if c > end {
+            lo = mid + 1
+        } else {
+            return true
+        }
+    }
+    false
+}
+
+fn bsearch_value_table<T: Clone>(c: char, r: &'static [(char, char, T)]) -> Option<T> {
+    let mut lo = 0;
+    let mut hi = r.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (start, end, ref value) = r[mid];
+        if c < start {
+            hi = mid
+        } else if c > end {
+            lo = mid + 1
+        } else {
+            return Some(value.clone())
+        }
+    }
+    None
+}
+
+fn bsearch_map_table(c: char, r: &'static [(char, char)]) -> Option<char> {
+    let mut lo = 0;
+    let mut hi = r.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (key, value) = r[mid];
+        if c < key {
+            hi = mid
+        } else if c > key {
+            lo = mid + 1
+        } else {
+            return Some(value)
+        }
+    }
+    None
+}
+
+pub mod general_category {
+    use option::Option;
+    use char::{GeneralCategory, Lu, Ll, Lt, Lm, Lo, Mn, Mc, Me, Nd, Nl, No, Pc, Pd, Ps, Pe, Pi, Pf,
+              Po, Sm, Sc, Sk, So, Zs, Zl, Zp, Cc, Cf, Cs, Co};
+
+    static GENERAL_CATEGORY_TABLE: &'static [(char, char, GeneralCategory)] = &[
+        ('\x00', '\x1f', Cc), ('\x20', '\x20', Zs), ('\x21', '\x23', Po), ('\x24', '\x24', Sc),
+        ('\x25', '\x27', Po), ('\x28', '\x28', Ps), ('\x29', '\x29', Pe), ('\x2a', '\x2a', Po),
+        ('\x2b', '\x2b', Sm), ('\x2c', '\x2c', Po), ('\x2d', '\x2d', Pd), ('\x2e', '\x2f', Po),
+        ('\x30', '\x39', Nd), ('\x3a', '\x3b', Po), ('\x3c', '\x3e', Sm), ('\x3f', '\x40', Po),
+        ('\x41', '\x5a', Lu), ('\x5b', '\x5b', Ps), ('\x5c', '\x5c', Po), ('\x5d', '\x5d', Pe),
+        ('\x5e', '\x5e', Sk), ('\x5f', '\x5f', Pc), ('\x60', '\x60', Sk), ('\x61', '\x7a', Ll),
+        ('\x7b', '\x7b', Ps), ('\x7c', '\x7c', Sm), ('\x7d', '\x7d', Pe), ('\x7e', '\x7e', Sm),
+        ('\x7f', '\u009f', Cc), ('\u00a0', '\u00a0', Zs), ('\u00a1', '\u00a1', Po),
+        ('\u00a2', '\u00a5', Sc), ('\u00a6', '\u00a6', So), ('\u00a7', '\u00a7', Po),
+        ('\u00a8', '\u00a8', Sk), ('\u00a9', '\u00a9', So), ('\u00aa', '\u00aa', Lo),
+        ('\u00ab', '\u00ab', Pi), ('\u00ac', '\u00ac', Sm), ('\u00ad', '\u00ad', Cf),
+        ('\u00ae', '\u00ae', So), ('\u00af', '\u00af', Sk), ('\u00b0', '\u00b0', So),
+        ('\u00b1', '\u00b1', Sm), ('\u00b2', '\u00b3', No), ('\u00b4', '\u00b4', Sk),
+        ('\u00b5', '\u00b5', Ll), ('\u00b6', '\u00b7', Po), ('\u00b8', '\u00b8', Sk),
+        ('\u00b9', '\u00b9', No), ('\u00ba', '\u00ba', Lo), ('\u00bb', '\u00bb', Pf),
+        ('\u00bc', '\u00be', No), ('\u00bf', '\u00bf', Po), ('\u00c0', '\u00d6', Lu),
+        ('\u00d7', '\u00d7', Sm), ('\u00d8', '\u00de', Lu), ('\u00df', '\u00f6', Ll),
+        ('\u00f7', '\u00f7', Sm), ('\u00f8', '\u00ff', Ll), ('\u0100', '\u0100', Lu),
+        ('\u0101', '\u0101', Ll), ('\u0102', '\u0102', Lu), ('\u0103', '\u0103', Ll),
+        ('\u0104', '\u0104', Lu), ('\u0105', '\u0105', Ll), ('\u0106', '\u0106', Lu),
+        ('\u0107', '\u0107', Ll), ('\u0108', '\u0108', Lu), ('\u0109', '\u0109', Ll),
+        ('\u010a', '\u010a', Lu), ('\u010b', '\u010b', Ll), ('\u010c', '\u010c', Lu),
+        ('\u010d', '\u010d', Ll), ('\u010e', '\u010e', Lu), ('\u010f', '\u010f', Ll),
+        ('\u0110', '\u0110', Lu), ('\u0111', '\u0111', Ll), ('\u0112', '\u0112', Lu),
+        ('\u0113', '\u0113', Ll), ('\u0114', '\u0114', Lu), ('\u0115', '\u0115', Ll),
+        ('\u0116', '\u0116', Lu), ('\u0117', '\u0117', Ll), ('\u0118', '\u0118', Lu),
+        ('\u0119', '\u0119', Ll), ('\u011a', '\u011a', Lu), ('\u011b', '\u011b', Ll),
+        ('\u011c', '\u011c', Lu), ('\u011d', '\u011d', Ll), ('\u011e', '\u011e', Lu),
+        ('\u011f', '\u011f', Ll), ('\u0120', '\u0120', Lu), ('\u0121', '\u0121', Ll),
+        ('\u0122', '\u0122', Lu), ('\u0123', '\u0123', Ll), ('\u0124', '\u0124', Lu),
+        ('\u0125', '\u0125', Ll), ('\u0126', '\u0126', Lu), ('\u0127', '\u0127', Ll),
+        ('\u0128', '\u0128', Lu), ('\u0129', '\u0129', Ll), ('\u012a', '\u012a', Lu),
+        ('\u012b', '\u012b', Ll), ('\u012c', '\u012c', Lu), ('\u012d', '\u012d', Ll),
+        ('\u012e', '\u012e', Lu), ('\u012f', '\u012f', Ll), ('\u0130', '\u0130', Lu),
+        ('\u0131', '\u0131', Ll), ('\u0132', '\u0132', Lu), ('\u0133', '\u0133', Ll),
+        ('\u0134', '\u0134', Lu), ('\u0135', '\u0135', Ll), ('\u0136', '\u0136', Lu),
+        ('\u0137', '\u0138', Ll), ('\u0139', '\u0139', Lu), ('\u013a', '\u013a', Ll),
+        ('\u013b', '\u013b', Lu), ('\u013c', '\u013c', Ll), ('\u013d', '\u013d', Lu),
+        ('\u013e', '\u013e', Ll), ('\u013f', '\u013f', Lu), ('\u0140', '\u0140', Ll),
+        ('\u0141', '\u0141', Lu), ('\u0142', '\u0142', Ll), ('\u0143', '\u0143', Lu),
+        ('\u0144', '\u0144', Ll), ('\u0145', '\u0145', Lu), ('\u0146', '\u0146', Ll),
+        ('\u0147', '\u0147', Lu), ('\u0148', '\u0149', Ll), ('\u014a', '\u014a', Lu),
+        ('\u014b', '\u014b', Ll), ('\u014c', '\u014c', Lu), ('\u014d', '\u014d', Ll),
+        ('\u014e', '\u014e', Lu), ('\u014f', '\u014f', Ll), ('\u0150', '\u0150', Lu),
+        ('\u0151', '\u0151', Ll), ('\u0152', '\u0152', Lu), ('\u0153', '\u0153', Ll),
+        ('\u0154', '\u0154', Lu), ('\u0155', '\u0155', Ll), ('\u0156', '\u0156', Lu),
+        ('\u0157', '\u0157', Ll), ('\u0158', '\u0158', Lu), ('\u0159', '\u0159', Ll),
+        ('\u015a', '\u015a', Lu), ('\u015b', '\u015b', Ll), ('\u015c', '\u015c', Lu),
+        ('\u015d', '\u015d', Ll), ('\u015e', '\u015e', Lu), ('\u015f', '\u015f', Ll),
+        ('\u0160', '\u0160', Lu), ('\u0161', '\u0161', Ll), ('\u0162', '\u0162', Lu),
+        ('\u0163', '\u0163', Ll), ('\u0164', '\u0164', Lu), ('\u0165', '\u0165', Ll),
+        ('\u0166', '\u0166', Lu), ('\u0167', '\u0167', Ll), ('\u0168', '\u0168', Lu),
+        ('\u0169', '\u0169', Ll), ('\u016a', '\u016a', Lu), ('\u016b', '\u016b', Ll),
+        ('\u016c', '\u016c', Lu), ('\u016d', '\u016d', Ll), ('\u016e', '\u016e', Lu),
+        ('\u016f', '\u016f', Ll), ('\u0170', '\u0170', Lu), ('\u0171', '\u0171', Ll),
+        ('\u0172', '\u0172', Lu), ('\u0173', '\u0173', Ll), ('\u0174', '\u0174', Lu),
+        ('\u0175', '\u0175', Ll), ('\u0176', '\u0176', Lu), ('\u0177', '\u0177', Ll),
+        ('\u0178', '\u0179', Lu), ('\u017a', '\u017a', Ll), ('\u017b', '\u017b', Lu),
+        ('\u017c', '\u017c', Ll), ('\u017d', '\u017d', Lu), ('\u017e', '\u0180', Ll),
+        ('\u0181', '\u0182', Lu), ('\u0183', '\u0183', Ll), ('\u0184', '\u0184', Lu),
+        ('\u0185', '\u0185', Ll), ('\u0186', '\u0187', Lu), ('\u0188', '\u0188', Ll),
+        ('\u0189', '\u018b', Lu), ('\u018c', '\u018d', Ll), ('\u018e', '\u0191', Lu),
+        ('\u0192', '\u0192', Ll), ('\u0193', '\u0194', Lu), ('\u0195', '\u0195', Ll),
+        ('\u0196', '\u0198', Lu), ('\u0199', '\u019b', Ll), ('\u019c', '\u019d', Lu),
+        ('\u019e', '\u019e', Ll), ('\u019f', '\u01a0', Lu), ('\u01a1', '\u01a1', Ll),
+        ('\u01a2', '\u01a2', Lu), ('\u01a3', '\u01a3', Ll), ('\u01a4', '\u01a4', Lu),
+        ('\u01a5', '\u01a5', Ll), ('\u01a6', '\u01a7', Lu), ('\u01a8', '\u01a8', Ll),
+        ('\u01a9', '\u01a9', Lu), ('\u01aa', '\u01ab', Ll), ('\u01ac', '\u01ac', Lu),
+        ('\u01ad', '\u01ad', Ll), ('\u01ae', '\u01af', Lu), ('\u01b0', '\u01b0', Ll),
+        ('\u01b1', '\u01b3', Lu), ('\u01b4', '\u01b4', Ll), ('\u01b5', '\u01b5', Lu),
+        ('\u01b6', '\u01b6', Ll), ('\u01b7', '\u01b8', Lu), ('\u01b9', '\u01ba', Ll),
+        ('\u01bb', '\u01bb', Lo), ('\u01bc', '\u01bc', Lu), ('\u01bd', '\u01bf', Ll),
+        ('\u01c0', '\u01c3', Lo), ('\u01c4', '\u01c4', Lu), ('\u01c5', '\u01c5', Lt),
+        ('\u01c6', '\u01c6', Ll), ('\u01c7', '\u01c7', Lu), ('\u01c8', '\u01c8', Lt),
+        ('\u01c9', '\u01c9', Ll), ('\u01ca', '\u01ca', Lu), ('\u01cb', '\u01cb', Lt),
+        ('\u01cc', '\u01cc', Ll), ('\u01cd', '\u01cd', Lu), ('\u01ce', '\u01ce', Ll),
+        ('\u01cf', '\u01cf', Lu), ('\u01d0', '\u01d0', Ll), ('\u01d1', '\u01d1', Lu),
+        ('\u01d2', '\u01d2', Ll), ('\u01d3', '\u01d3', Lu), ('\u01d4', '\u01d4', Ll),
+        ('\u01d5', '\u01d5', Lu), ('\u01d6', '\u01d6', Ll), ('\u01d7', '\u01d7', Lu),
+        ('\u01d8', '\u01d8', Ll), ('\u01d9', '\u01d9', Lu), ('\u01da', '\u01da', Ll),
+        ('\u01db', '\u01db', Lu), ('\u01dc', '\u01dd', Ll), ('\u01de', '\u01de', Lu),
+        ('\u01df', '\u01df', Ll), ('\u01e0', '\u01e0', Lu), ('\u01e1', '\u01e1', Ll),
+        ('\u01e2', '\u01e2', Lu), ('\u01e3', '\u01e3', Ll), ('\u01e4', '\u01e4', Lu),
+        ('\u01e5', '\u01e5', Ll), ('\u01e6', '\u01e6', Lu), ('\u01e7', '\u01e7', Ll),
+        ('\u01e8', '\u01e8', Lu), ('\u01e9', '\u01e9', Ll), ('\u01ea', '\u01ea', Lu),
+        ('\u01eb', '\u01eb', Ll), ('\u01ec', '\u01ec', Lu), ('\u01ed', '\u01ed', Ll),
+        ('\u01ee', '\u01ee', Lu), ('\u01ef', '\u01f0', Ll), ('\u01f1', '\u01f1', Lu),
+        ('\u01f2', '\u01f2', Lt), ('\u01f3', '\u01f3', Ll), ('\u01f4', '\u01f4', Lu),
+        ('\u01f5', '\u01f5', Ll), ('\u01f6', '\u01f8', Lu), ('\u01f9', '\u01f9', Ll),
+        ('\u01fa', '\u01fa', Lu), ('\u01fb', '\u01fb', Ll), ('\u01fc', '\u01fc', Lu),
+        ('\u01fd', '\u01fd', Ll), ('\u01fe', '\u01fe', Lu), ('\u01ff', '\u01ff', Ll),
+        ('\u0200', '\u0200', Lu), ('\u0201', '\u0201', Ll), ('\u0202', '\u0202', Lu),
+        ('\u0203', '\u0203', Ll), ('\u0204', '\u0204', Lu), ('\u0205', '\u0205', Ll),
+        ('\u0206', '\u0206', Lu), ('\u0207', '\u0207', Ll), ('\u0208', '\u0208', Lu),
+        ('\u0209', '\u0209', Ll), ('\u020a', '\u020a', Lu), ('\u020b', '\u020b', Ll),
+        ('\u020c', '\u020c', Lu), ('\u020d', '\u020d', Ll), ('\u020e', '\u020e', Lu),
+        ('\u020f', '\u020f', Ll), ('\u0210', '\u0210', Lu), ('\u0211', '\u0211', Ll),
+        ('\u0212', '\u0212', Lu), ('\u0213', '\u0213', Ll), ('\u0214', '\u0214', Lu),
+        ('\u0215', '\u0215', Ll), ('\u0216', '\u0216', Lu), ('\u0217', '\u0217', Ll),
+        ('\u0218', '\u0218', Lu), ('\u0219', '\u0219', Ll), ('\u021a', '\u021a', Lu),
+        ('\u021b', '\u021b', Ll), ('\u021c', '\u021c', Lu), ('\u021d', '\u021d', Ll),
+        ('\u021e', '\u021e', Lu), ('\u021f', '\u021f', Ll), ('\u0220', '\u0220', Lu),
+        ('\u0221', '\u0221', Ll), ('\u0222', '\u0222', Lu), ('\u0223', '\u0223', Ll),
+        ('\u0224', '\u0224', Lu), ('\u0225', '\u0225', Ll), ('\u0226', '\u0226', Lu),
+        ('\u0227', '\u0227', Ll), ('\u0228', '\u0228', Lu), ('\u0229', '\u0229', Ll),
+        ('\u022a', '\u022a', Lu), ('\u022b', '\u022b', Ll), ('\u022c', '\u022c', Lu),
+        ('\u022d', '\u022d', Ll), ('\u022e', '\u022e', Lu), ('\u022f', '\u022f', Ll),
+        ('\u0230', '\u0230', Lu), ('\u0231', '\u0231', Ll), ('\u0232', '\u0232', Lu),
+        ('\u0233', '\u0239', Ll), ('\u023a', '\u023b', Lu), ('\u023c', '\u023c', Ll),
+        ('\u023d', '\u023e', Lu), ('\u023f', '\u0240', Ll), ('\u0241', '\u0241', Lu),
+        ('\u0242', '\u0242', Ll), ('\u0243', '\u0246', Lu), ('\u0247', '\u0247', Ll),
+        ('\u0248', '\u0248', Lu), ('\u0249', '\u0249', Ll), ('\u024a', '\u024a', Lu),
+        ('\u024b', '\u024b', Ll), ('\u024c', '\u024c', Lu), ('\u024d', '\u024d', Ll),
+        ('\u024e', '\u024e', Lu), ('\u024f', '\u0293', Ll), ('\u0294', '\u0294', Lo),
+        ('\u0295', '\u02af', Ll), ('\u02b0', '\u02c1', Lm), ('\u02c2', '\u02c5', Sk),
+        ('\u02c6', '\u02d1', Lm), ('\u02d2', '\u02df', Sk), ('\u02e0', '\u02e4', Lm),
+        ('\u02e5', '\u02eb', Sk), ('\u02ec', '\u02ec', Lm), ('\u02ed', '\u02ed', Sk),
+        ('\u02ee', '\u02ee', Lm), ('\u02ef', '\u02ff', Sk), ('\u0300', '\u036f', Mn),
+        ('\u0370', '\u0370', Lu), ('\u0371', '\u0371', Ll), ('\u0372', '\u0372', Lu),
+        ('\u0373', '\u0373', Ll), ('\u0374', '\u0374', Lm), ('\u0375', '\u0375', Sk),
+        ('\u0376', '\u0376', Lu), ('\u0377', '\u0377', Ll), ('\u037a', '\u037a', Lm),
+        ('\u037b', '\u037d', Ll), ('\u037e', '\u037e', Po), ('\u037f', '\u037f', Lu),
+        ('\u0384', '\u0385', Sk), ('\u0386', '\u0386', Lu), ('\u0387', '\u0387', Po),
+        ('\u0388', '\u038a', Lu), ('\u038c', '\u038c', Lu), ('\u038e', '\u038f', Lu),
+        ('\u0390', '\u0390', Ll), ('\u0391', '\u03a1', Lu), ('\u03a3', '\u03ab', Lu),
+        ('\u03ac', '\u03ce', Ll), ('\u03cf', '\u03cf', Lu), ('\u03d0', '\u03d1', Ll),
+        ('\u03d2', '\u03d4', Lu), ('\u03d5', '\u03d7', Ll), ('\u03d8', '\u03d8', Lu),
+        ('\u03d9', '\u03d9', Ll), ('\u03da', '\u03da', Lu), ('\u03db', '\u03db', Ll),
+        ('\u03dc', '\u03dc', Lu), ('\u03dd', '\u03dd', Ll), ('\u03de', '\u03de', Lu),
+        ('\u03df', '\u03df', Ll), ('\u03e0', '\u03e0', Lu), ('\u03e1', '\u03e1', Ll),
+        ('\u03e2', '\u03e2', Lu), ('\u03e3', '\u03e3', Ll), ('\u03e4', '\u03e4', Lu),
+        ('\u03e5', '\u03e5', Ll), ('\u03e6', '\u03e6', Lu), ('\u03e7', '\u03e7', Ll),
+        ('\u03e8', '\u03e8', Lu), ('\u03e9', '\u03e9', Ll), ('\u03ea', '\u03ea', Lu),
+        ('\u03eb', '\u03eb', Ll), ('\u03ec', '\u03ec', Lu), ('\u03ed', '\u03ed', Ll),
+        ('\u03ee', '\u03ee', Lu), ('\u03ef', '\u03f3', Ll), ('\u03f4', '\u03f4', Lu),
+        ('\u03f5', '\u03f5', Ll), ('\u03f6', '\u03f6', Sm), ('\u03f7', '\u03f7', Lu),
+        ('\u03f8', '\u03f8', Ll), ('\u03f9', '\u03fa', Lu), ('\u03fb', '\u03fc', Ll),
+        ('\u03fd', '\u042f', Lu), ('\u0430', '\u045f', Ll), ('\u0460', '\u0460', Lu),
+        ('\u0461', '\u0461', Ll), ('\u0462', '\u0462', Lu), ('\u0463', '\u0463', Ll),
+        ('\u0464', '\u0464', Lu), ('\u0465', '\u0465', Ll), ('\u0466', '\u0466', Lu),
+        ('\u0467', '\u0467', Ll), ('\u0468', '\u0468', Lu), ('\u0469', '\u0469', Ll),
+        ('\u046a', '\u046a', Lu), ('\u046b', '\u046b', Ll), ('\u046c', '\u046c', Lu),
+        ('\u046d', '\u046d', Ll), ('\u046e', '\u046e', Lu), ('\u046f', '\u046f', Ll),
+        ('\u0470', '\u0470', Lu), ('\u0471', '\u0471', Ll), ('\u0472', '\u0472', Lu),
+        ('\u0473', '\u0473', Ll), ('\u0474', '\u0474', Lu), ('\u0475', '\u0475', Ll),
+        ('\u0476', '\u0476', Lu), ('\u0477', '\u0477', Ll), ('\u0478', '\u0478', Lu),
+        ('\u0479', '\u0479', Ll), ('\u047a', '\u047a', Lu), ('\u047b', '\u047b', Ll),
+        ('\u047c', '\u047c', Lu), ('\u047d', '\u047d', Ll), ('\u047e', '\u047e', Lu),
+        ('\u047f', '\u047f', Ll), ('\u0480', '\u0480', Lu), ('\u0481', '\u0481', Ll),
+        ('\u0482', '\u0482', So), ('\u0483', '\u0487', Mn), ('\u0488', '\u0489', Me),
+        ('\u048a', '\u048a', Lu), ('\u048b', '\u048b', Ll), ('\u048c', '\u048c', Lu),
+        ('\u048d', '\u048d', Ll), ('\u048e', '\u048e', Lu), ('\u048f', '\u048f', Ll),
+        ('\u0490', '\u0490', Lu), ('\u0491', '\u0491', Ll), ('\u0492', '\u0492', Lu),
+        ('\u0493', '\u0493', Ll), ('\u0494', '\u0494', Lu), ('\u0495', '\u0495', Ll),
+        ('\u0496', '\u0496', Lu), ('\u0497', '\u0497', Ll), ('\u0498', '\u0498', Lu),
+        ('\u0499', '\u0499', Ll), ('\u049a', '\u049a', Lu), ('\u049b', '\u049b', Ll),
+        ('\u049c', '\u049c', Lu), ('\u049d', '\u049d', Ll), ('\u049e', '\u049e', Lu),
+        ('\u049f', '\u049f', Ll), ('\u04a0', '\u04a0', Lu), ('\u04a1', '\u04a1', Ll),
+        ('\u04a2', '\u04a2', Lu), ('\u04a3', '\u04a3', Ll), ('\u04a4', '\u04a4', Lu),
+        ('\u04a5', '\u04a5', Ll), ('\u04a6', '\u04a6', Lu), ('\u04a7', '\u04a7', Ll),
+        ('\u04a8', '\u04a8', Lu), ('\u04a9', '\u04a9', Ll), ('\u04aa', '\u04aa', Lu),
+        ('\u04ab', '\u04ab', Ll), ('\u04ac', '\u04ac', Lu), ('\u04ad', '\u04ad', Ll),
+        ('\u04ae', '\u04ae', Lu), ('\u04af', '\u04af', Ll), ('\u04b0', '\u04b0', Lu),
+        ('\u04b1', '\u04b1', Ll), ('\u04b2', '\u04b2', Lu), ('\u04b3', '\u04b3', Ll),
+        ('\u04b4', '\u04b4', Lu), ('\u04b5', '\u04b5', Ll), ('\u04b6', '\u04b6', Lu),
+        ('\u04b7', '\u04b7', Ll), ('\u04b8', '\u04b8', Lu), ('\u04b9', '\u04b9', Ll),
+        ('\u04ba', '\u04ba', Lu), ('\u04bb', '\u04bb', Ll), ('\u04bc', '\u04bc', Lu),
+        ('\u04bd', '\u04bd', Ll), ('\u04be', '\u04be', Lu), ('\u04bf', '\u04bf', Ll),
+        ('\u04c0', '\u04c1', Lu), ('\u04c2', '\u04c2', Ll), ('\u04c3', '\u04c3', Lu),
+        ('\u04c4', '\u04c4', Ll), ('\u04c5', '\u04c5', Lu), ('\u04c6', '\u04c6', Ll),
+        ('\u04c7', '\u04c7', Lu), ('\u04c8', '\u04c8', Ll), ('\u04c9', '\u04c9', Lu),
+        ('\u04ca', '\u04ca', Ll), ('\u04cb', '\u04cb', Lu), ('\u04cc', '\u04cc', Ll),
+        ('\u04cd', '\u04cd', Lu), ('\u04ce', '\u04cf', Ll), ('\u04d0', '\u04d0', Lu),
+        ('\u04d1', '\u04d1', Ll), ('\u04d2', '\u04d2', Lu), ('\u04d3', '\u04d3', Ll),
+        ('\u04d4', '\u04d4', Lu), ('\u04d5', '\u04d5', Ll), ('\u04d6', '\u04d6', Lu),
+        ('\u04d7', '\u04d7', Ll), ('\u04d8', '\u04d8', Lu), ('\u04d9', '\u04d9', Ll),
+        ('\u04da', '\u04da', Lu), ('\u04db', '\u04db', Ll), ('\u04dc', '\u04dc', Lu),
+        ('\u04dd', '\u04dd', Ll), ('\u04de', '\u04de', Lu), ('\u04df', '\u04df', Ll),
+        ('\u04e0', '\u04e0', Lu), ('\u04e1', '\u04e1', Ll), ('\u04e2', '\u04e2', Lu),
+        ('\u04e3', '\u04e3', Ll), ('\u04e4', '\u04e4', Lu), ('\u04e5', '\u04e5', Ll),
+        ('\u04e6', '\u04e6', Lu), ('\u04e7', '\u04e7', Ll), ('\u04e8', '\u04e8', Lu),
+        ('\u04e9', '\u04e9', Ll), ('\u04ea', '\u04ea', Lu), ('\u04eb', '\u04eb', Ll),
+        ('\u04ec', '\u04ec', Lu), ('\u04ed', '\u04ed', Ll), ('\u04ee', '\u04ee', Lu),
+        ('\u04ef', '\u04ef', Ll), ('\u04f0', '\u04f0', Lu), ('\u04f1', '\u04f1', Ll),
+        ('\u04f2', '\u04f2', Lu), ('\u04f3', '\u04f3', Ll), ('\u04f4', '\u04f4', Lu),
+        ('\u04f5', '\u04f5', Ll), ('\u04f6', '\u04f6', Lu), ('\u04f7', '\u04f7', Ll),
+        ('\u04f8', '\u04f8', Lu), ('\u04f9', '\u04f9', Ll), ('\u04fa', '\u04fa', Lu),
+        ('\u04fb', '\u04fb', Ll), ('\u04fc', '\u04fc', Lu), ('\u04fd', '\u04fd', Ll),
+        ('\u04fe', '\u04fe', Lu), ('\u04ff', '\u04ff', Ll), ('\u0500', '\u0500', Lu),
+        ('\u0501', '\u0501', Ll), ('\u0502', '\u0502', Lu), ('\u0503', '\u0503', Ll),
+        ('\u0504', '\u0504', Lu), ('\u0505', '\u0505', Ll), ('\u0506', '\u0506', Lu),
+        ('\u0507', '\u0507', Ll), ('\u0508', '\u0508', Lu), ('\u0509', '\u0509', Ll),
+        ('\u050a', '\u050a', Lu), ('\u050b', '\u050b', Ll), ('\u050c', '\u050c', Lu),
+        ('\u050d', '\u050d', Ll), ('\u050e', '\u050e', Lu), ('\u050f', '\u050f', Ll),
+        ('\u0510', '\u0510', Lu), ('\u0511', '\u0511', Ll), ('\u0512', '\u0512', Lu),
+        ('\u0513', '\u0513', Ll), ('\u0514', '\u0514', Lu), ('\u0515', '\u0515', Ll),
+        ('\u0516', '\u0516', Lu), ('\u0517', '\u0517', Ll), ('\u0518', '\u0518', Lu),
+        ('\u0519', '\u0519', Ll), ('\u051a', '\u051a', Lu), ('\u051b', '\u051b', Ll),
+        ('\u051c', '\u051c', Lu), ('\u051d', '\u051d', Ll), ('\u051e', '\u051e', Lu),
+        ('\u051f', '\u051f', Ll), ('\u0520', '\u0520', Lu), ('\u0521', '\u0521', Ll),
+        ('\u0522', '\u0522', Lu), ('\u0523', '\u0523', Ll), ('\u0524', '\u0524', Lu),
+        ('\u0525', '\u0525', Ll), ('\u0526', '\u0526', Lu), ('\u0527', '\u0527', Ll),
+        ('\u0528', '\u0528', Lu), ('\u0529', '\u0529', Ll), ('\u052a', '\u052a', Lu),
+        ('\u052b', '\u052b', Ll), ('\u052c', '\u052c', Lu), ('\u052d', '\u052d', Ll),
+        ('\u052e', '\u052e', Lu), ('\u052f', '\u052f', Ll), ('\u0531', '\u0556', Lu),
+        ('\u0559', '\u0559', Lm), ('\u055a', '\u055f', Po), ('\u0560', '\u0588', Ll),
+        ('\u0589', '\u0589', Po), ('\u058a', '\u058a', Pd), ('\u058d', '\u058e', So),
+        ('\u058f', '\u058f', Sc), ('\u0591', '\u05bd', Mn), ('\u05be', '\u05be', Pd),
+        ('\u05bf', '\u05bf', Mn), ('\u05c0', '\u05c0', Po), ('\u05c1', '\u05c2', Mn),
+        ('\u05c3', '\u05c3', Po), ('\u05c4', '\u05c5', Mn), ('\u05c6', '\u05c6', Po),
+        ('\u05c7', '\u05c7', Mn), ('\u05d0', '\u05ea', Lo), ('\u05ef', '\u05f2', Lo),
+        ('\u05f3', '\u05f4', Po), ('\u0600', '\u0605', Cf), ('\u0606', '\u0608', Sm),
+        ('\u0609', '\u060a', Po), ('\u060b', '\u060b', Sc), ('\u060c', '\u060d', Po),
+        ('\u060e', '\u060f', So), ('\u0610', '\u061a', Mn), ('\u061b', '\u061b', Po),
+        ('\u061c', '\u061c', Cf), ('\u061d', '\u061f', Po), ('\u0620', '\u063f', Lo),
+        ('\u0640', '\u0640', Lm), ('\u0641', '\u064a', Lo), ('\u064b', '\u065f', Mn),
+        ('\u0660', '\u0669', Nd), ('\u066a', '\u066d', Po), ('\u066e', '\u066f', Lo),
+        ('\u0670', '\u0670', Mn), ('\u0671', '\u06d3', Lo), ('\u06d4', '\u06d4', Po),
+        ('\u06d5', '\u06d5', Lo), ('\u06d6', '\u06dc', Mn), ('\u06dd', '\u06dd', Cf),
+        ('\u06de', '\u06de', So), ('\u06df', '\u06e4', Mn), ('\u06e5', '\u06e6', Lm),
+        ('\u06e7', '\u06e8', Mn), ('\u06e9', '\u06e9', So), ('\u06ea', '\u06ed', Mn),
+        ('\u06ee', '\u06ef', Lo), ('\u06f0', '\u06f9', Nd), ('\u06fa', '\u06fc', Lo),
+        ('\u06fd', '\u06fe', So), ('\u06ff', '\u06ff', Lo), ('\u0700', '\u070d', Po),
+        ('\u070f', '\u070f', Cf), ('\u0710', '\u0710', Lo), ('\u0711', '\u0711', Mn),
+        ('\u0712', '\u072f', Lo), ('\u0730', '\u074a', Mn), ('\u074d', '\u07a5', Lo),
+        ('\u07a6', '\u07b0', Mn), ('\u07b1', '\u07b1', Lo), ('\u07c0', '\u07c9', Nd),
+        ('\u07ca', '\u07ea', Lo), ('\u07eb', '\u07f3', Mn), ('\u07f4', '\u07f5', Lm),
+        ('\u07f6', '\u07f6', So), ('\u07f7', '\u07f9', Po), ('\u07fa', '\u07fa', Lm),
+        ('\u07fd', '\u07fd', Mn), ('\u07fe', '\u07ff', Sc), ('\u0800', '\u0815', Lo),
+        ('\u0816', '\u0819', Mn), ('\u081a', '\u081a', Lm), ('\u081b', '\u0823', Mn),
+        ('\u0824', '\u0824', Lm), ('\u0825', '\u0827', Mn), ('\u0828', '\u0828', Lm),
+        ('\u0829', '\u082d', Mn), ('\u0830', '\u083e', Po), ('\u0840', '\u0858', Lo),
+        ('\u0859', '\u085b', Mn), ('\u085e', '\u085e', Po), ('\u0860', '\u086a', Lo),
+        ('\u0870', '\u0887', Lo), ('\u0888', '\u0888', Sk), ('\u0889', '\u088e', Lo),
+        ('\u0890', '\u0891', Cf), ('\u0898', '\u089f', Mn), ('\u08a0', '\u08c8', Lo),
+        ('\u08c9', '\u08c9', Lm), ('\u08ca', '\u08e1', Mn), ('\u08e2', '\u08e2', Cf),
+        ('\u08e3', '\u0902', Mn), ('\u0903', '\u0903', Mc), ('\u0904', '\u0939', Lo),
+        ('\u093a', '\u093a', Mn), ('\u093b', '\u093b', Mc), ('\u093c', '\u093c', Mn),
+        ('\u093d', '\u093d', Lo), ('\u093e', '\u0940', Mc), ('\u0941', '\u0948', Mn),
+        ('\u0949', '\u094c', Mc), ('\u094d', '\u094d', Mn), ('\u094e', '\u094f', Mc),
+        ('\u0950', '\u0950', Lo), ('\u0951', '\u0957', Mn), ('\u0958', '\u0961', Lo),
+        ('\u0962', '\u0963', Mn), ('\u0964', '\u0965', Po), ('\u0966', '\u096f', Nd),
+        ('\u0970', '\u0970', Po), ('\u0971', '\u0971', Lm), ('\u0972', '\u0980', Lo),
+        ('\u0981', '\u0981', Mn), ('\u0982', '\u0983', Mc), ('\u0985', '\u098c', Lo),
+        ('\u098f', '\u0990', Lo), ('\u0993', '\u09a8', Lo), ('\u09aa', '\u09b0', Lo),
+        ('\u09b2', '\u09b2', Lo), ('\u09b6', '\u09b9', Lo), ('\u09bc', '\u09bc', Mn),
+        ('\u09bd', '\u09bd', Lo), ('\u09be', '\u09c0', Mc), ('\u09c1', '\u09c4', Mn),
+        ('\u09c7', '\u09c8', Mc), ('\u09cb', '\u09cc', Mc), ('\u09cd', '\u09cd', Mn),
+        ('\u09ce', '\u09ce', Lo), ('\u09d7', '\u09d7', Mc), ('\u09dc', '\u09dd', Lo),
+        ('\u09df', '\u09e1', Lo), ('\u09e2', '\u09e3', Mn), ('\u09e6', '\u09ef', Nd),
+        ('\u09f0', '\u09f1', Lo), ('\u09f2', '\u09f3', Sc), ('\u09f4', '\u09f9', No),
+        ('\u09fa', '\u09fa', So), ('\u09fb', '\u09fb', Sc), ('\u09fc', '\u09fc', Lo),
+        ('\u09fd', '\u09fd', Po), ('\u09fe', '\u09fe', Mn), ('\u0a01', '\u0a02', Mn),
+        ('\u0a03', '\u0a03', Mc), ('\u0a05', '\u0a0a', Lo), ('\u0a0f', '\u0a10', Lo),
+        ('\u0a13', '\u0a28', Lo), ('\u0a2a', '\u0a30', Lo), ('\u0a32', '\u0a33', Lo),
+        ('\u0a35', '\u0a36', Lo), ('\u0a38', '\u0a39', Lo), ('\u0a3c', '\u0a3c', Mn),
+        ('\u0a3e', '\u0a40', Mc), ('\u0a41', '\u0a42', Mn), ('\u0a47', '\u0a48', Mn),
+        ('\u0a4b', '\u0a4d', Mn), ('\u0a51', '\u0a51', Mn), ('\u0a59', '\u0a5c', Lo),
+        ('\u0a5e', '\u0a5e', Lo), ('\u0a66', '\u0a6f', Nd), ('\u0a70', '\u0a71', Mn),
+        ('\u0a72', '\u0a74', Lo), ('\u0a75', '\u0a75', Mn), ('\u0a76', '\u0a76', Po),
+        ('\u0a81', '\u0a82', Mn), ('\u0a83', '\u0a83', Mc), ('\u0a85', '\u0a8d', Lo),
+        ('\u0a8f', '\u0a91', Lo), ('\u0a93', '\u0aa8', Lo), ('\u0aaa', '\u0ab0', Lo),
+        ('\u0ab2', '\u0ab3', Lo), ('\u0ab5', '\u0ab9', Lo), ('\u0abc', '\u0abc', Mn),
+        ('\u0abd', '\u0abd', Lo), ('\u0abe', '\u0ac0', Mc), ('\u0ac1', '\u0ac5', Mn),
+        ('\u0ac7', '\u0ac8', Mn), ('\u0ac9', '\u0ac9', Mc), ('\u0acb', '\u0acc', Mc),
+        ('\u0acd', '\u0acd', Mn), ('\u0ad0', '\u0ad0', Lo), ('\u0ae0', '\u0ae1', Lo),
+        ('\u0ae2', '\u0ae3', Mn), ('\u0ae6', '\u0aef', Nd), ('\u0af0', '\u0af0', Po),
+        ('\u0af1', '\u0af1', Sc), ('\u0af9', '\u0af9', Lo), ('\u0afa', '\u0aff', Mn),
+        ('\u0b01', '\u0b01', Mn), ('\u0b02', '\u0b03', Mc), ('\u0b05', '\u0b0c', Lo),
+        ('\u0b0f', '\u0b10', Lo), ('\u0b13', '\u0b28', Lo), ('\u0b2a', '\u0b30', Lo),
+        ('\u0b32', '\u0b33', Lo), ('\u0b35', '\u0b39', Lo), ('\u0b3c', '\u0b3c', Mn),
+        ('\u0b3d', '\u0b3d', Lo), ('\u0b3e', '\u0b3e', Mc), ('\u0b3f', '\u0b3f', Mn),
+        ('\u0b40', '\u0b40', Mc), ('\u0b41', '\u0b44', Mn), ('\u0b47', '\u0b48', Mc),
+        ('\u0b4b', '\u0b4c', Mc), ('\u0b4d', '\u0b4d', Mn), ('\u0b55', '\u0b56', Mn),
+        ('\u0b57', '\u0b57', Mc), ('\u0b5c', '\u0b5d', Lo), ('\u0b5f', '\u0b61', Lo),
+        ('\u0b62', '\u0b63', Mn), ('\u0b66', '\u0b6f', Nd), ('\u0b70', '\u0b70', So),
+        ('\u0b71', '\u0b71', Lo), ('\u0b72', '\u0b77', No), ('\u0b82', '\u0b82', Mn),
+        ('\u0b83', '\u0b83', Lo), ('\u0b85', '\u0b8a', Lo), ('\u0b8e', '\u0b90', Lo),
+        ('\u0b92', '\u0b95', Lo), ('\u0b99', '\u0b9a', Lo), ('\u0b9c', '\u0b9c', Lo),
+        ('\u0b9e', '\u0b9f', Lo), ('\u0ba3', '\u0ba4', Lo), ('\u0ba8', '\u0baa', Lo),
+        ('\u0bae', '\u0bb9', Lo), ('\u0bbe', '\u0bbf', Mc), ('\u0bc0', '\u0bc0', Mn),
+        ('\u0bc1', '\u0bc2', Mc), ('\u0bc6', '\u0bc8', Mc), ('\u0bca', '\u0bcc', Mc),
+        ('\u0bcd', '\u0bcd', Mn), ('\u0bd0', '\u0bd0', Lo), ('\u0bd7', '\u0bd7', Mc),
+        ('\u0be6', '\u0bef', Nd), ('\u0bf0', '\u0bf2', No), ('\u0bf3', '\u0bf8', So),
+        ('\u0bf9', '\u0bf9', Sc), ('\u0bfa', '\u0bfa', So), ('\u0c00', '\u0c00', Mn),
+        ('\u0c01', '\u0c03', Mc), ('\u0c04', '\u0c04', Mn), ('\u0c05', '\u0c0c', Lo),
+        ('\u0c0e', '\u0c10', Lo), ('\u0c12', '\u0c28', Lo), ('\u0c2a', '\u0c39', Lo),
+        ('\u0c3c', '\u0c3c', Mn), ('\u0c3d', '\u0c3d', Lo), ('\u0c3e', '\u0c40', Mn),
+        ('\u0c41', '\u0c44', Mc), ('\u0c46', '\u0c48', Mn), ('\u0c4a', '\u0c4d', Mn),
+        ('\u0c55', '\u0c56', Mn), ('\u0c58', '\u0c5a', Lo), ('\u0c5d', '\u0c5d', Lo),
+        ('\u0c60', '\u0c61', Lo), ('\u0c62', '\u0c63', Mn), ('\u0c66', '\u0c6f', Nd),
+        ('\u0c77', '\u0c77', Po), ('\u0c78', '\u0c7e', No), ('\u0c7f', '\u0c7f', So),
+        ('\u0c80', '\u0c80', Lo), ('\u0c81', '\u0c81', Mn), ('\u0c82', '\u0c83', Mc),
+        ('\u0c84', '\u0c84', Po), ('\u0c85', '\u0c8c', Lo), ('\u0c8e', '\u0c90', Lo),
+        ('\u0c92', '\u0ca8', Lo), ('\u0caa', '\u0cb3', Lo), ('\u0cb5', '\u0cb9', Lo),
+        ('\u0cbc', '\u0cbc', Mn), ('\u0cbd', '\u0cbd', Lo), ('\u0cbe', '\u0cbe', Mc),
+        ('\u0cbf', '\u0cbf', Mn), ('\u0cc0', '\u0cc4', Mc), ('\u0cc6', '\u0cc6', Mn),
+        ('\u0cc7', '\u0cc8', Mc), ('\u0cca', '\u0ccb', Mc), ('\u0ccc', '\u0ccd', Mn),
+        ('\u0cd5', '\u0cd6', Mc), ('\u0cdd', '\u0cde', Lo), ('\u0ce0', '\u0ce1', Lo),
+        ('\u0ce2', '\u0ce3', Mn), ('\u0ce6', '\u0cef', Nd), ('\u0cf1', '\u0cf2', Lo),
+        ('\u0d00', '\u0d01', Mn), ('\u0d02', '\u0d03', Mc), ('\u0d04', '\u0d0c', Lo),
+        ('\u0d0e', '\u0d10', Lo), ('\u0d12', '\u0d3a', Lo), ('\u0d3b', '\u0d3c', Mn),
+        ('\u0d3d', '\u0d3d', Lo), ('\u0d3e', '\u0d40', Mc), ('\u0d41', '\u0d44', Mn),
+        ('\u0d46', '\u0d48', Mc), ('\u0d4a', '\u0d4c', Mc), ('\u0d4d', '\u0d4d', Mn),
+        ('\u0d4e', '\u0d4e', Lo), ('\u0d4f', '\u0d4f', So), ('\u0d54', '\u0d56', Lo),
+        ('\u0d57', '\u0d57', Mc), ('\u0d58', '\u0d5e', No), ('\u0d5f', '\u0d61', Lo),
+        ('\u0d62', '\u0d63', Mn), ('\u0d66', '\u0d6f', Nd), ('\u0d70', '\u0d78', No),
+        ('\u0d79', '\u0d79', So), ('\u0d7a', '\u0d7f', Lo), ('\u0d81', '\u0d81', Mn),
+        ('\u0d82', '\u0d83', Mc), ('\u0d85', '\u0d96', Lo), ('\u0d9a', '\u0db1', Lo),
+        ('\u0db3', '\u0dbb', Lo), ('\u0dbd', '\u0dbd', Lo), ('\u0dc0', '\u0dc6', Lo),
+        ('\u0dca', '\u0dca', Mn), ('\u0dcf', '\u0dd1', Mc), ('\u0dd2', '\u0dd4', Mn),
+        ('\u0dd6', '\u0dd6', Mn), ('\u0dd8', '\u0ddf', Mc), ('\u0de6', '\u0def', Nd),
+        ('\u0df2', '\u0df3', Mc), ('\u0df4', '\u0df4', Po), ('\u0e01', '\u0e30', Lo),
+        ('\u0e31', '\u0e31', Mn), ('\u0e32', '\u0e33', Lo), ('\u0e34', '\u0e3a', Mn),
+        ('\u0e3f', '\u0e3f', Sc), ('\u0e40', '\u0e45', Lo), ('\u0e46', '\u0e46', Lm),
+        ('\u0e47', '\u0e4e', Mn), ('\u0e4f', '\u0e4f', Po), ('\u0e50', '\u0e59', Nd),
+        ('\u0e5a', '\u0e5b', Po), ('\u0e81', '\u0e82', Lo), ('\u0e84', '\u0e84', Lo),
+        ('\u0e86', '\u0e8a', Lo), ('\u0e8c', '\u0ea3', Lo), ('\u0ea5', '\u0ea5', Lo),
+        ('\u0ea7', '\u0eb0', Lo), ('\u0eb1', '\u0eb1', Mn), ('\u0eb2', '\u0eb3', Lo),
+        ('\u0eb4', '\u0ebc', Mn), ('\u0ebd', '\u0ebd', Lo), ('\u0ec0', '\u0ec4', Lo),
+        ('\u0ec6', '\u0ec6', Lm), ('\u0ec8', '\u0ecd', Mn), ('\u0ed0', '\u0ed9', Nd),
+        ('\u0edc', '\u0edf', Lo), ('\u0f00', '\u0f00', Lo), ('\u0f01', '\u0f03', So),
+        ('\u0f04', '\u0f12', Po), ('\u0f13', '\u0f13', So), ('\u0f14', '\u0f14', Po),
+        ('\u0f15', '\u0f17', So), ('\u0f18', '\u0f19', Mn), ('\u0f1a', '\u0f1f', So),
+        ('\u0f20', '\u0f29', Nd), ('\u0f2a', '\u0f33', No), ('\u0f34', '\u0f34', So),
+        ('\u0f35', '\u0f35', Mn), ('\u0f36', '\u0f36', So), ('\u0f37', '\u0f37', Mn),
+        ('\u0f38', '\u0f38', So), ('\u0f39', '\u0f39', Mn), ('\u0f3a', '\u0f3a', Ps),
+        ('\u0f3b', '\u0f3b', Pe), ('\u0f3c', '\u0f3c', Ps), ('\u0f3d', '\u0f3d', Pe),
+        ('\u0f3e', '\u0f3f', Mc), ('\u0f40', '\u0f47', Lo), ('\u0f49', '\u0f6c', Lo),
+        ('\u0f71', '\u0f7e', Mn), ('\u0f7f', '\u0f7f', Mc), ('\u0f80', '\u0f84', Mn),
+        ('\u0f85', '\u0f85', Po), ('\u0f86', '\u0f87', Mn), ('\u0f88', '\u0f8c', Lo),
+        ('\u0f8d', '\u0f97', Mn), ('\u0f99', '\u0fbc', Mn), ('\u0fbe', '\u0fc5', So),
+        ('\u0fc6', '\u0fc6', Mn), ('\u0fc7', '\u0fcc', So), ('\u0fce', '\u0fcf', So),
+        ('\u0fd0', '\u0fd4', Po), ('\u0fd5', '\u0fd8', So), ('\u0fd9', '\u0fda', Po),
+        ('\u1000', '\u102a', Lo), ('\u102b', '\u102c', Mc), ('\u102d', '\u1030', Mn),
+        ('\u1031', '\u1031', Mc), ('\u1032', '\u1037', Mn), ('\u1038', '\u1038', Mc),
+        ('\u1039', '\u103a', Mn), ('\u103b', '\u103c', Mc), ('\u103d', '\u103e', Mn),
+        ('\u103f', '\u103f', Lo), ('\u1040', '\u1049', Nd), ('\u104a', '\u104f', Po),
+        ('\u1050', '\u1055', Lo), ('\u1056', '\u1057', Mc), ('\u1058', '\u1059', Mn),
+        ('\u105a', '\u105d', Lo), ('\u105e', '\u1060', Mn), ('\u1061', '\u1061', Lo),
+        ('\u1062', '\u1064', Mc), ('\u1065', '\u1066', Lo), ('\u1067', '\u106d', Mc),
+        ('\u106e', '\u1070', Lo), ('\u1071', '\u1074', Mn), ('\u1075', '\u1081', Lo),
+        ('\u1082', '\u1082', Mn), ('\u1083', '\u1084', Mc), ('\u1085', '\u1086', Mn),
+        ('\u1087', '\u108c', Mc), ('\u108d', '\u108d', Mn), ('\u108e', '\u108e', Lo),
+        ('\u108f', '\u108f', Mc), ('\u1090', '\u1099', Nd), ('\u109a', '\u109c', Mc),
+        ('\u109d', '\u109d', Mn), ('\u109e', '\u109f', So), ('\u10a0', '\u10c5', Lu),
+        ('\u10c7', '\u10c7', Lu), ('\u10cd', '\u10cd', Lu), ('\u10d0', '\u10fa', Ll),
+        ('\u10fb', '\u10fb', Po), ('\u10fc', '\u10fc', Lm), ('\u10fd', '\u10ff', Ll),
+        ('\u1100', '\u1248', Lo), ('\u124a', '\u124d', Lo), ('\u1250', '\u1256', Lo),
+        ('\u1258', '\u1258', Lo), ('\u125a', '\u125d', Lo), ('\u1260', '\u1288', Lo),
+        ('\u128a', '\u128d', Lo), ('\u1290', '\u12b0', Lo), ('\u12b2', '\u12b5', Lo),
+        ('\u12b8', '\u12be', Lo), ('\u12c0', '\u12c0', Lo), ('\u12c2', '\u12c5', Lo),
+        ('\u12c8', '\u12d6', Lo), ('\u12d8', '\u1310', Lo), ('\u1312', '\u1315', Lo),
+        ('\u1318', '\u135a', Lo), ('\u135d', '\u135f', Mn), ('\u1360', '\u1368', Po),
+        ('\u1369', '\u137c', No), ('\u1380', '\u138f', Lo), ('\u1390', '\u1399', So),
+        ('\u13a0', '\u13f5', Lu), ('\u13f8', '\u13fd', Ll), ('\u1400', '\u1400', Pd),
+        ('\u1401', '\u166c', Lo), ('\u166d', '\u166d', So), ('\u166e', '\u166e', Po),
+        ('\u166f', '\u167f', Lo), ('\u1680', '\u1680', Zs), ('\u1681', '\u169a', Lo),
+        ('\u169b', '\u169b', Ps), ('\u169c', '\u169c', Pe), ('\u16a0', '\u16ea', Lo),
+        ('\u16eb', '\u16ed', Po), ('\u16ee', '\u16f0', Nl), ('\u16f1', '\u16f8', Lo),
+        ('\u1700', '\u1711', Lo), ('\u1712', '\u1714', Mn), ('\u1715', '\u1715', Mc),
+        ('\u171f', '\u1731', Lo), ('\u1732', '\u1733', Mn), ('\u1734', '\u1734', Mc),
+        ('\u1735', '\u1736', Po), ('\u1740', '\u1751', Lo), ('\u1752', '\u1753', Mn),
+        ('\u1760', '\u176c', Lo), ('\u176e', '\u1770', Lo), ('\u1772', '\u1773', Mn),
+        ('\u1780', '\u17b3', Lo), ('\u17b4', '\u17b5', Mn), ('\u17b6', '\u17b6', Mc),
+        ('\u17b7', '\u17bd', Mn), ('\u17be', '\u17c5', Mc), ('\u17c6', '\u17c6', Mn),
+        ('\u17c7', '\u17c8', Mc), ('\u17c9', '\u17d3', Mn), ('\u17d4', '\u17d6', Po),
+        ('\u17d7', '\u17d7', Lm), ('\u17d8', '\u17da', Po), ('\u17db', '\u17db', Sc),
+        ('\u17dc', '\u17dc', Lo), ('\u17dd', '\u17dd', Mn), ('\u17e0', '\u17e9', Nd),
+        ('\u17f0', '\u17f9', No), ('\u1800', '\u1805', Po), ('\u1806', '\u1806', Pd),
+        ('\u1807', '\u180a', Po), ('\u180b', '\u180d', Mn), ('\u180e', '\u180e', Cf),
+        ('\u180f', '\u180f', Mn), ('\u1810', '\u1819', Nd), ('\u1820', '\u1842', Lo),
+        ('\u1843', '\u1843', Lm), ('\u1844', '\u1878', Lo), ('\u1880', '\u1884', Lo),
+        ('\u1885', '\u1886', Mn), ('\u1887', '\u18a8', Lo), ('\u18a9', '\u18a9', Mn),
+        ('\u18aa', '\u18aa', Lo), ('\u18b0', '\u18f5', Lo), ('\u1900', '\u191e', Lo),
+        ('\u1920', '\u1922', Mn), ('\u1923', '\u1926', Mc), ('\u1927', '\u1928', Mn),
+        ('\u1929', '\u192b', Mc), ('\u1930', '\u1931', Mc), ('\u1932', '\u1932', Mn),
+        ('\u1933', '\u1938', Mc), ('\u1939', '\u193b', Mn), ('\u1940', '\u1940', So),
+        ('\u1944', '\u1945', Po), ('\u1946', '\u194f', Nd), ('\u1950', '\u196d', Lo),
+        ('\u1970', '\u1974', Lo), ('\u1980', '\u19ab', Lo), ('\u19b0', '\u19c9', Lo),
+        ('\u19d0', '\u19d9', Nd), ('\u19da', '\u19da', No), ('\u19de', '\u19ff', So),
+        ('\u1a00', '\u1a16', Lo), ('\u1a17', '\u1a18', Mn), ('\u1a19', '\u1a1a', Mc),
+        ('\u1a1b', '\u1a1b', Mn), ('\u1a1e', '\u1a1f', Po), ('\u1a20', '\u1a54', Lo),
+        ('\u1a55', '\u1a55', Mc), ('\u1a56', '\u1a56', Mn), ('\u1a57', '\u1a57', Mc),
+        ('\u1a58', '\u1a5e', Mn), ('\u1a60', '\u1a60', Mn), ('\u1a61', '\u1a61', Mc),
+        ('\u1a62', '\u1a62', Mn), ('\u1a63', '\u1a64', Mc), ('\u1a65', '\u1a6c', Mn),
+        ('\u1a6d', '\u1a72', Mc), ('\u1a73', '\u1a7c', Mn), ('\u1a7f', '\u1a7f', Mn),
+        ('\u1a80', '\u1a89', Nd), ('\u1a90', '\u1a99', Nd), ('\u1aa0', '\u1aa6', Po),
+        ('\u1aa7', '\u1aa7', Lm), ('\u1aa8', '\u1aad', Po), ('\u1ab0', '\u1abd', Mn),
+        ('\u1abe', '\u1abe', Me), ('\u1abf', '\u1ace', Mn), ('\u1b00', '\u1b03', Mn),
+        ('\u1b04', '\u1b04', Mc), ('\u1b05', '\u1b33', Lo), ('\u1b34', '\u1b34', Mn),
+        ('\u1b35', '\u1b35', Mc), ('\u1b36', '\u1b3a', Mn), ('\u1b3b', '\u1b3b', Mc),
+        ('\u1b3c', '\u1b3c', Mn), ('\u1b3d', '\u1b41', Mc), ('\u1b42', '\u1b42', Mn),
+        ('\u1b43', '\u1b44', Mc), ('\u1b45', '\u1b4c', Lo), ('\u1b50', '\u1b59', Nd),
+        ('\u1b5a', '\u1b60', Po), ('\u1b61', '\u1b6a', So), ('\u1b6b', '\u1b73', Mn),
+        ('\u1b74', '\u1b7c', So), ('\u1b7d', '\u1b7e', Po), ('\u1b80', '\u1b81', Mn),
+        ('\u1b82', '\u1b82', Mc), ('\u1b83', '\u1ba0', Lo), ('\u1ba1', '\u1ba1', Mc),
+        ('\u1ba2', '\u1ba5', Mn), ('\u1ba6', '\u1ba7', Mc), ('\u1ba8', '\u1ba9', Mn),
+        ('\u1baa', '\u1baa', Mc), ('\u1bab', '\u1bad', Mn), ('\u1bae', '\u1baf', Lo),
+        ('\u1bb0', '\u1bb9', Nd), ('\u1bba', '\u1be5', Lo), ('\u1be6', '\u1be6', Mn),
+        ('\u1be7', '\u1be7', Mc), ('\u1be8', '\u1be9', Mn), ('\u1bea', '\u1bec', Mc),
+        ('\u1bed', '\u1bed', Mn), ('\u1bee', '\u1bee', Mc), ('\u1bef', '\u1bf1', Mn),
+        ('\u1bf2', '\u1bf3', Mc), ('\u1bfc', '\u1bff', Po), ('\u1c00', '\u1c23', Lo),
+        ('\u1c24', '\u1c2b', Mc), ('\u1c2c', '\u1c33', Mn), ('\u1c34', '\u1c35', Mc),
+        ('\u1c36', '\u1c37', Mn), ('\u1c3b', '\u1c3f', Po), ('\u1c40', '\u1c49', Nd),
+        ('\u1c4d', '\u1c4f', Lo), ('\u1c50', '\u1c59', Nd), ('\u1c5a', '\u1c77', Lo),
+        ('\u1c78', '\u1c7d', Lm), ('\u1c7e', '\u1c7f', Po), ('\u1c80', '\u1c88', Ll),
+        ('\u1c90', '\u1cba', Lu), ('\u1cbd', '\u1cbf', Lu), ('\u1cc0', '\u1cc7', Po),
+        ('\u1cd0', '\u1cd2', Mn), ('\u1cd3', '\u1cd3', Po), ('\u1cd4', '\u1ce0', Mn),
+        ('\u1ce1', '\u1ce1', Mc), ('\u1ce2', '\u1ce8', Mn), ('\u1ce9', '\u1cec', Lo),
+        ('\u1ced', '\u1ced', Mn), ('\u1cee', '\u1cf3', Lo), ('\u1cf4', '\u1cf4', Mn),
+        ('\u1cf5', '\u1cf6', Lo), ('\u1cf7', '\u1cf7', Mc), ('\u1cf8', '\u1cf9', Mn),
+        ('\u1cfa', '\u1cfa', Lo), ('\u1d00', '\u1d2b', Ll), ('\u1d2c', '\u1d6a', Lm),
+        ('\u1d6b', '\u1d77', Ll), ('\u1d78', '\u1d78', Lm), ('\u1d79', '\u1d9a', Ll),
+        ('\u1d9b', '\u1dbf', Lm), ('\u1dc0', '\u1dff', Mn), ('\u1e00', '\u1e00', Lu),
+        ('\u1e01', '\u1e01', Ll), ('\u1e02', '\u1e02', Lu), ('\u1e03', '\u1e03', Ll),
+        ('\u1e04', '\u1e04', Lu), ('\u1e05', '\u1e05', Ll), ('\u1e06', '\u1e06', Lu),
+        ('\u1e07', '\u1e07', Ll), ('\u1e08', '\u1e08', Lu), ('\u1e09', '\u1e09', Ll),
+        ('\u1e0a', '\u1e0a', Lu), ('\u1e0b', '\u1e0b', Ll), ('\u1e0c', '\u1e0c', Lu),
+        ('\u1e0d', '\u1e0d', Ll), ('\u1e0e', '\u1e0e', Lu), ('\u1e0f', '\u1e0f', Ll),
+        ('\u1e10', '\u1e10', Lu), ('\u1e11', '\u1e11', Ll), ('\u1e12', '\u1e12', Lu),
+        ('\u1e13', '\u1e13', Ll), ('\u1e14', '\u1e14', Lu), ('\u1e15', '\u1e15', Ll),
+        ('\u1e16', '\u1e16', Lu), ('\u1e17', '\u1e17', Ll), ('\u1e18', '\u1e18', Lu),
+        ('\u1e19', '\u1e19', Ll), ('\u1e1a', '\u1e1a', Lu), ('\u1e1b', '\u1e1b', Ll),
+        ('\u1e1c', '\u1e1c', Lu), ('\u1e1d', '\u1e1d', Ll), ('\u1e1e', '\u1e1e', Lu),
+        ('\u1e1f', '\u1e1f', Ll), ('\u1e20', '\u1e20', Lu), ('\u1e21', '\u1e21', Ll),
+        ('\u1e22', '\u1e22', Lu), ('\u1e23', '\u1e23', Ll), ('\u1e24', '\u1e24', Lu),
+        ('\u1e25', '\u1e25', Ll), ('\u1e26', '\u1e26', Lu), ('\u1e27', '\u1e27', Ll),
+        ('\u1e28', '\u1e28', Lu), ('\u1e29', '\u1e29', Ll), ('\u1e2a', '\u1e2a', Lu),
+        ('\u1e2b', '\u1e2b', Ll), ('\u1e2c', '\u1e2c', Lu), ('\u1e2d', '\u1e2d', Ll),
+        ('\u1e2e', '\u1e2e', Lu), ('\u1e2f', '\u1e2f', Ll), ('\u1e30', '\u1e30', Lu),
+        ('\u1e31', '\u1e31', Ll), ('\u1e32', '\u1e32', Lu), ('\u1e33', '\u1e33', Ll),
+        ('\u1e34', '\u1e34', Lu), ('\u1e35', '\u1e35', Ll), ('\u1e36', '\u1e36', Lu),
+        ('\u1e37', '\u1e37', Ll), ('\u1e38', '\u1e38', Lu), ('\u1e39', '\u1e39', Ll),
+        ('\u1e3a', '\u1e3a', Lu), ('\u1e3b', '\u1e3b', Ll), ('\u1e3c', '\u1e3c', Lu),
+        ('\u1e3d', '\u1e3d', Ll), ('\u1e3e', '\u1e3e', Lu), ('\u1e3f', '\u1e3f', Ll),
+        ('\u1e40', '\u1e40', Lu), ('\u1e41', '\u1e41', Ll), ('\u1e42', '\u1e42', Lu),
+        ('\u1e43', '\u1e43', Ll), ('\u1e44', '\u1e44', Lu), ('\u1e45', '\u1e45', Ll),
+        ('\u1e46', '\u1e46', Lu), ('\u1e47', '\u1e47', Ll), ('\u1e48', '\u1e48', Lu),
+        ('\u1e49', '\u1e49', Ll), ('\u1e4a', '\u1e4a', Lu), ('\u1e4b', '\u1e4b', Ll),
+        ('\u1e4c', '\u1e4c', Lu), ('\u1e4d', '\u1e4d', Ll), ('\u1e4e', '\u1e4e', Lu),
+        ('\u1e4f', '\u1e4f', Ll), ('\u1e50', '\u1e50', Lu), ('\u1e51', '\u1e51', Ll),
+        ('\u1e52', '\u1e52', Lu), ('\u1e53', '\u1e53', Ll), ('\u1e54', '\u1e54', Lu),
+        ('\u1e55', '\u1e55', Ll), ('\u1e56', '\u1e56', Lu), ('\u1e57', '\u1e57', Ll),
+        ('\u1e58', '\u1e58', Lu), ('\u1e59', '\u1e59', Ll), ('\u1e5a', '\u1e5a', Lu),
+        ('\u1e5b', '\u1e5b', Ll), ('\u1e5c', '\u1e5c', Lu), ('\u1e5d', '\u1e5d', Ll),
+        ('\u1e5e', '\u1e5e', Lu), ('\u1e5f', '\u1e5f', Ll), ('\u1e60', '\u1e60', Lu),
+        ('\u1e61', '\u1e61', Ll), ('\u1e62', '\u1e62', Lu), ('\u1e63', '\u1e63', Ll),
+        ('\u1e64', '\u1e64', Lu), ('\u1e65', '\u1e65', Ll), ('\u1e66', '\u1e66', Lu),
+        ('\u1e67', '\u1e67', Ll), ('\u1e68', '\u1e68', Lu), ('\u1e69', '\u1e69', Ll),
+        ('\u1e6a', '\u1e6a', Lu), ('\u1e6b', '\u1e6b', Ll), ('\u1e6c', '\u1e6c', Lu),
+        ('\u1e6d', '\u1e6d', Ll), ('\u1e6e', '\u1e6e', Lu), ('\u1e6f', '\u1e6f', Ll),
+        ('\u1e70', '\u1e70', Lu), ('\u1e71', '\u1e71', Ll), ('\u1e72', '\u1e72', Lu),
+        ('\u1e73', '\u1e73', Ll), ('\u1e74', '\u1e74', Lu), ('\u1e75', '\u1e75', Ll),
+        ('\u1e76', '\u1e76', Lu), ('\u1e77', '\u1e77', Ll), ('\u1e78', '\u1e78', Lu),
+        ('\u1e79', '\u1e79', Ll), ('\u1e7a', '\u1e7a', Lu), ('\u1e7b', '\u1e7b', Ll),
+        ('\u1e7c', '\u1e7c', Lu), ('\u1e7d', '\u1e7d', Ll), ('\u1e7e', '\u1e7e', Lu),
+        ('\u1e7f', '\u1e7f', Ll), ('\u1e80', '\u1e80', Lu), ('\u1e81', '\u1e81', Ll),
+        ('\u1e82', '\u1e82', Lu), ('\u1e83', '\u1e83', Ll), ('\u1e84', '\u1e84', Lu),
+        ('\u1e85', '\u1e85', Ll), ('\u1e86', '\u1e86', Lu), ('\u1e87', '\u1e87', Ll),
+        ('\u1e88', '\u1e88', Lu), ('\u1e89', '\u1e89', Ll), ('\u1e8a', '\u1e8a', Lu),
+        ('\u1e8b', '\u1e8b', Ll), ('\u1e8c', '\u1e8c', Lu), ('\u1e8d', '\u1e8d', Ll),
+        ('\u1e8e', '\u1e8e', Lu), ('\u1e8f', '\u1e8f', Ll), ('\u1e90', '\u1e90', Lu),
+        ('\u1e91', '\u1e91', Ll), ('\u1e92', '\u1e92', Lu), ('\u1e93', '\u1e93', Ll),
+        ('\u1e94', '\u1e94', Lu), ('\u1e95', '\u1e9d', Ll), ('\u1e9e', '\u1e9e', Lu),
+        ('\u1e9f', '\u1e9f', Ll), ('\u1ea0', '\u1ea0', Lu), ('\u1ea1', '\u1ea1', Ll),
+        ('\u1ea2', '\u1ea2', Lu), ('\u1ea3', '\u1ea3', Ll), ('\u1ea4', '\u1ea4', Lu),
+        ('\u1ea5', '\u1ea5', Ll), ('\u1ea6', '\u1ea6', Lu), ('\u1ea7', '\u1ea7', Ll),
+        ('\u1ea8', '\u1ea8', Lu), ('\u1ea9', '\u1ea9', Ll), ('\u1eaa', '\u1eaa', Lu),
+        ('\u1eab', '\u1eab', Ll), ('\u1eac', '\u1eac', Lu), ('\u1ead', '\u1ead', Ll),
+        ('\u1eae', '\u1eae', Lu), ('\u1eaf', '\u1eaf', Ll), ('\u1eb0', '\u1eb0', Lu),
+        ('\u1eb1', '\u1eb1', Ll), ('\u1eb2', '\u1eb2', Lu), ('\u1eb3', '\u1eb3', Ll),
+        ('\u1eb4', '\u1eb4', Lu), ('\u1eb5', '\u1eb5', Ll), ('\u1eb6', '\u1eb6', Lu),
+        ('\u1eb7', '\u1eb7', Ll), ('\u1eb8', '\u1eb8', Lu), ('\u1eb9', '\u1eb9', Ll),
+        ('\u1eba', '\u1eba', Lu), ('\u1ebb', '\u1ebb', Ll), ('\u1ebc', '\u1ebc', Lu),
+        ('\u1ebd', '\u1ebd', Ll), ('\u1ebe', '\u1ebe', Lu), ('\u1ebf', '\u1ebf', Ll),
+        ('\u1ec0', '\u1ec0', Lu), ('\u1ec1', '\u1ec1', Ll), ('\u1ec2', '\u1ec2', Lu),
+        ('\u1ec3', '\u1ec3', Ll), ('\u1ec4', '\u1ec4', Lu), ('\u1ec5', '\u1ec5', Ll),
+        ('\u1ec6', '\u1ec6', Lu), ('\u1ec7', '\u1ec7', Ll), ('\u1ec8', '\u1ec8', Lu),
+        ('\u1ec9', '\u1ec9', Ll), ('\u1eca', '\u1eca', Lu), ('\u1ecb', '\u1ecb', Ll),
+        ('\u1ecc', '\u1ecc', Lu), ('\u1ecd', '\u1ecd', Ll), ('\u1ece', '\u1ece', Lu),
+        ('\u1ecf', '\u1ecf', Ll), ('\u1ed0', '\u1ed0', Lu), ('\u1ed1', '\u1ed1', Ll),
+        ('\u1ed2', '\u1ed2', Lu), ('\u1ed3', '\u1ed3', Ll), ('\u1ed4', '\u1ed4', Lu),
+        ('\u1ed5', '\u1ed5', Ll), ('\u1ed6', '\u1ed6', Lu), ('\u1ed7', '\u1ed7', Ll),
+        ('\u1ed8', '\u1ed8', Lu), ('\u1ed9', '\u1ed9', Ll), ('\u1eda', '\u1eda', Lu),
+        ('\u1edb', '\u1edb', Ll), ('\u1edc', '\u1edc', Lu), ('\u1edd', '\u1edd', Ll),
+        ('\u1ede', '\u1ede', Lu), ('\u1edf', '\u1edf', Ll), ('\u1ee0', '\u1ee0', Lu),
+        ('\u1ee1', '\u1ee1', Ll), ('\u1ee2', '\u1ee2', Lu), ('\u1ee3', '\u1ee3', Ll),
+        ('\u1ee4', '\u1ee4', Lu), ('\u1ee5', '\u1ee5', Ll), ('\u1ee6', '\u1ee6', Lu),
+        ('\u1ee7', '\u1ee7', Ll), ('\u1ee8', '\u1ee8', Lu), ('\u1ee9', '\u1ee9', Ll),
+        ('\u1eea', '\u1eea', Lu), ('\u1eeb', '\u1eeb', Ll), ('\u1eec', '\u1eec', Lu),
+        ('\u1eed', '\u1eed', Ll), ('\u1eee', '\u1eee', Lu), ('\u1eef', '\u1eef', Ll),
+        ('\u1ef0', '\u1ef0', Lu), ('\u1ef1', '\u1ef1', Ll), ('\u1ef2', '\u1ef2', Lu),
+        ('\u1ef3', '\u1ef3', Ll), ('\u1ef4', '\u1ef4', Lu), ('\u1ef5', '\u1ef5', Ll),
+        ('\u1ef6', '\u1ef6', Lu), ('\u1ef7', '\u1ef7', Ll), ('\u1ef8', '\u1ef8', Lu),
+        ('\u1ef9', '\u1ef9', Ll), ('\u1efa', '\u1efa', Lu), ('\u1efb', '\u1efb', Ll),
+        ('\u1efc', '\u1efc', Lu), ('\u1efd', '\u1efd', Ll), ('\u1efe', '\u1efe', Lu),
+        ('\u1eff', '\u1f07', Ll), ('\u1f08', '\u1f0f', Lu), ('\u1f10', '\u1f15', Ll),
+        ('\u1f18', '\u1f1d', Lu), ('\u1f20', '\u1f27', Ll), ('\u1f28', '\u1f2f', Lu),
+        ('\u1f30', '\u1f37', Ll), ('\u1f38', '\u1f3f', Lu), ('\u1f40', '\u1f45', Ll),
+        ('\u1f48', '\u1f4d', Lu), ('\u1f50', '\u1f57', Ll), ('\u1f59', '\u1f59', Lu),
+        ('\u1f5b', '\u1f5b', Lu), ('\u1f5d', '\u1f5d', Lu), ('\u1f5f', '\u1f5f', Lu),
+        ('\u1f60', '\u1f67', Ll), ('\u1f68', '\u1f6f', Lu), ('\u1f70', '\u1f7d', Ll),
+        ('\u1f80', '\u1f87', Ll), ('\u1f88', '\u1f8f', Lt), ('\u1f90', '\u1f97', Ll),
+        ('\u1f98', '\u1f9f', Lt), ('\u1fa0', '\u1fa7', Ll), ('\u1fa8', '\u1faf', Lt),
+        ('\u1fb0', '\u1fb4', Ll), ('\u1fb6', '\u1fb7', Ll), ('\u1fb8', '\u1fbb', Lu),
+        ('\u1fbc', '\u1fbc', Lt), ('\u1fbd', '\u1fbd', Sk), ('\u1fbe', '\u1fbe', Ll),
+        ('\u1fbf', '\u1fc1', Sk), ('\u1fc2', '\u1fc4', Ll), ('\u1fc6', '\u1fc7', Ll),
+        ('\u1fc8', '\u1fcb', Lu), ('\u1fcc', '\u1fcc', Lt), ('\u1fcd', '\u1fcf', Sk),
+        ('\u1fd0', '\u1fd3', Ll), ('\u1fd6', '\u1fd7', Ll), ('\u1fd8', '\u1fdb', Lu),
+        ('\u1fdd', '\u1fdf', Sk), ('\u1fe0', '\u1fe7', Ll), ('\u1fe8', '\u1fec', Lu),
+        ('\u1fed', '\u1fef', Sk), ('\u1ff2', '\u1ff4', Ll), ('\u1ff6', '\u1ff7', Ll),
+        ('\u1ff8', '\u1ffb', Lu), ('\u1ffc', '\u1ffc', Lt), ('\u1ffd', '\u1ffe', Sk),
+        ('\u2000', '\u200a', Zs), ('\u200b', '\u200f', Cf), ('\u2010', '\u2015', Pd),
+        ('\u2016', '\u2017', Po), ('\u2018', '\u2018', Pi), ('\u2019', '\u2019', Pf),
+        ('\u201a', '\u201a', Ps), ('\u201b', '\u201c', Pi), ('\u201d', '\u201d', Pf),
+        ('\u201e', '\u201e', Ps), ('\u201f', '\u201f', Pi), ('\u2020', '\u2027', Po),
+        ('\u2028', '\u2028', Zl), ('\u2029', '\u2029', Zp), ('\u202a', '\u202e', Cf),
+        ('\u202f', '\u202f', Zs), ('\u2030', '\u2038', Po), ('\u2039', '\u2039', Pi),
+        ('\u203a', '\u203a', Pf), ('\u203b', '\u203e', Po), ('\u203f', '\u2040', Pc),
+        ('\u2041', '\u2043', Po), ('\u2044', '\u2044', Sm), ('\u2045', '\u2045', Ps),
+        ('\u2046', '\u2046', Pe), ('\u2047', '\u2051', Po), ('\u2052', '\u2052', Sm),
+        ('\u2053', '\u2053', Po), ('\u2054', '\u2054', Pc), ('\u2055', '\u205e', Po),
+        ('\u205f', '\u205f', Zs), ('\u2060', '\u2064', Cf), ('\u2066', '\u206f', Cf),
+        ('\u2070', '\u2070', No), ('\u2071', '\u2071', Lm), ('\u2074', '\u2079', No),
+        ('\u207a', '\u207c', Sm), ('\u207d', '\u207d', Ps), ('\u207e', '\u207e', Pe),
+        ('\u207f', '\u207f', Lm), ('\u2080', '\u2089', No), ('\u208a', '\u208c', Sm),
+        ('\u208d', '\u208d', Ps), ('\u208e', '\u208e', Pe), ('\u2090', '\u209c', Lm),
+        ('\u20a0', '\u20c0', Sc), ('\u20d0', '\u20dc', Mn), ('\u20dd', '\u20e0', Me),
+        ('\u20e1', '\u20e1', Mn), ('\u20e2', '\u20e4', Me), ('\u20e5', '\u20f0', Mn),
+        ('\u2100', '\u2101', So), ('\u2102', '\u2102', Lu), ('\u2103', '\u2106', So),
+        ('\u2107', '\u2107', Lu), ('\u2108', '\u2109', So), ('\u210a', '\u210a', Ll),
+        ('\u210b', '\u210d', Lu), ('\u210e', '\u210f', Ll), ('\u2110', '\u2112', Lu),
+        ('\u2113', '\u2113', Ll), ('\u2114', '\u2114', So), ('\u2115', '\u2115', Lu),
+        ('\u2116', '\u2117', So), ('\u2118', '\u2118', Sm), ('\u2119', '\u211d', Lu),
+        ('\u211e', '\u2123', So), ('\u2124', '\u2124', Lu), ('\u2125', '\u2125', So),
+        ('\u2126', '\u2126', Lu), ('\u2127', '\u2127', So), ('\u2128', '\u2128', Lu),
+        ('\u2129', '\u2129', So), ('\u212a', '\u212d', Lu), ('\u212e', '\u212e', So),
+        ('\u212f', '\u212f', Ll), ('\u2130', '\u2133', Lu), ('\u2134', '\u2134', Ll),
+        ('\u2135', '\u2138', Lo), ('\u2139', '\u2139', Ll), ('\u213a', '\u213b', So),
+        ('\u213c', '\u213d', Ll), ('\u213e', '\u213f', Lu), ('\u2140', '\u2144', Sm),
+        ('\u2145', '\u2145', Lu), ('\u2146', '\u2149', Ll), ('\u214a', '\u214a', So),
+        ('\u214b', '\u214b', Sm), ('\u214c', '\u214d', So), ('\u214e', '\u214e', Ll),
+        ('\u214f', '\u214f', So), ('\u2150', '\u215f', No), ('\u2160', '\u2182', Nl),
+        ('\u2183', '\u2183', Lu), ('\u2184', '\u2184', Ll), ('\u2185', '\u2188', Nl),
+        ('\u2189', '\u2189', No), ('\u218a', '\u218b', So), ('\u2190', '\u2194', Sm),
+        ('\u2195', '\u2199', So), ('\u219a', '\u219b', Sm), ('\u219c', '\u219f', So),
+        ('\u21a0', '\u21a0', Sm), ('\u21a1', '\u21a2', So), ('\u21a3', '\u21a3', Sm),
+        ('\u21a4', '\u21a5', So), ('\u21a6', '\u21a6', Sm), ('\u21a7', '\u21ad', So),
+        ('\u21ae', '\u21ae', Sm), ('\u21af', '\u21cd', So), ('\u21ce', '\u21cf', Sm),
+        ('\u21d0', '\u21d1', So), ('\u21d2', '\u21d2', Sm), ('\u21d3', '\u21d3', So),
+        ('\u21d4', '\u21d4', Sm), ('\u21d5', '\u21f3', So), ('\u21f4', '\u22ff', Sm),
+        ('\u2300', '\u2307', So), ('\u2308', '\u2308', Ps), ('\u2309', '\u2309', Pe),
+        ('\u230a', '\u230a', Ps), ('\u230b', '\u230b', Pe), ('\u230c', '\u231f', So),
+        ('\u2320', '\u2321', Sm), ('\u2322', '\u2328', So), ('\u2329', '\u2329', Ps),
+        ('\u232a', '\u232a', Pe), ('\u232b', '\u237b', So), ('\u237c', '\u237c', Sm),
+        ('\u237d', '\u239a', So), ('\u239b', '\u23b3', Sm), ('\u23b4', '\u23db', So),
+        ('\u23dc', '\u23e1', Sm), ('\u23e2', '\u2426', So), ('\u2440', '\u244a', So),
+        ('\u2460', '\u249b', No), ('\u249c', '\u24e9', So), ('\u24ea', '\u24ff', No),
+        ('\u2500', '\u25b6', So), ('\u25b7', '\u25b7', Sm), ('\u25b8', '\u25c0', So),
+        ('\u25c1', '\u25c1', Sm), ('\u25c2', '\u25f7', So), ('\u25f8', '\u25ff', Sm),
+        ('\u2600', '\u266e', So), ('\u266f', '\u266f', Sm), ('\u2670', '\u2767', So),
+        ('\u2768', '\u2768', Ps), ('\u2769', '\u2769', Pe), ('\u276a', '\u276a', Ps),
+        ('\u276b', '\u276b', Pe), ('\u276c', '\u276c', Ps), ('\u276d', '\u276d', Pe),
+        ('\u276e', '\u276e', Ps), ('\u276f', '\u276f', Pe), ('\u2770', '\u2770', Ps),
+        ('\u2771', '\u2771', Pe), ('\u2772', '\u2772', Ps), ('\u2773', '\u2773', Pe),
+        ('\u2774', '\u2774', Ps), ('\u2775', '\u2775', Pe), ('\u2776', '\u2793', No),
+        ('\u2794', '\u27bf', So), ('\u27c0', '\u27c4', Sm), ('\u27c5', '\u27c5', Ps),
+        ('\u27c6', '\u27c6', Pe), ('\u27c7', '\u27e5', Sm), ('\u27e6', '\u27e6', Ps),
+        ('\u27e7', '\u27e7', Pe), ('\u27e8', '\u27e8', Ps), ('\u27e9', '\u27e9', Pe),
+        ('\u27ea', '\u27ea', Ps), ('\u27eb', '\u27eb', Pe), ('\u27ec', '\u27ec', Ps),
+        ('\u27ed', '\u27ed', Pe), ('\u27ee', '\u27ee', Ps), ('\u27ef', '\u27ef', Pe),
+        ('\u27f0', '\u27ff', Sm), ('\u2800', '\u28ff', So), ('\u2900', '\u2982', Sm),
+        ('\u2983', '\u2983', Ps), ('\u2984', '\u2984', Pe), ('\u2985', '\u2985', Ps),
+        ('\u2986', '\u2986', Pe), ('\u2987', '\u2987', Ps), ('\u2988', '\u2988', Pe),
+        ('\u2989', '\u2989', Ps), ('\u298a', '\u298a', Pe), ('\u298b', '\u298b', Ps),
+        ('\u298c', '\u298c', Pe), ('\u298d', '\u298d', Ps), ('\u298e', '\u298e', Pe),
+        ('\u298f', '\u298f', Ps), ('\u2990', '\u2990', Pe), ('\u2991', '\u2991', Ps),
+        ('\u2992', '\u2992', Pe), ('\u2993', '\u2993', Ps), ('\u2994', '\u2994', Pe),
+        ('\u2995', '\u2995', Ps), ('\u2996', '\u2996', Pe), ('\u2997', '\u2997', Ps),
+        ('\u2998', '\u2998', Pe), ('\u2999', '\u29d7', Sm), ('\u29d8', '\u29d8', Ps),
+        ('\u29d9', '\u29d9', Pe), ('\u29da', '\u29da', Ps), ('\u29db', '\u29db', Pe),
+        ('\u29dc', '\u29fb', Sm), ('\u29fc', '\u29fc', Ps), ('\u29fd', '\u29fd', Pe),
+        ('\u29fe', '\u2aff', Sm), ('\u2b00', '\u2b2f', So), ('\u2b30', '\u2b44', Sm),
+        ('\u2b45', '\u2b46', So), ('\u2b47', '\u2b4c', Sm), ('\u2b4d', '\u2b73', So),
+        ('\u2b76', '\u2b95', So), ('\u2b97', '\u2bff', So), ('\u2c00', '\u2c2f', Lu),
+        ('\u2c30', '\u2c5f', Ll), ('\u2c60', '\u2c60', Lu), ('\u2c61', '\u2c61', Ll),
+        ('\u2c62', '\u2c64', Lu), ('\u2c65', '\u2c66', Ll), ('\u2c67', '\u2c67', Lu),
+        ('\u2c68', '\u2c68', Ll), ('\u2c69', '\u2c69', Lu), ('\u2c6a', '\u2c6a', Ll),
+        ('\u2c6b', '\u2c6b', Lu), ('\u2c6c', '\u2c6c', Ll), ('\u2c6d', '\u2c70', Lu),
+        ('\u2c71', '\u2c71', Ll), ('\u2c72', '\u2c72', Lu), ('\u2c73', '\u2c74', Ll),
+        ('\u2c75', '\u2c75', Lu), ('\u2c76', '\u2c7b', Ll), ('\u2c7c', '\u2c7d', Lm),
+        ('\u2c7e', '\u2c80', Lu), ('\u2c81', '\u2c81', Ll), ('\u2c82', '\u2c82', Lu),
+        ('\u2c83', '\u2c83', Ll), ('\u2c84', '\u2c84', Lu), ('\u2c85', '\u2c85', Ll),
+        ('\u2c86', '\u2c86', Lu), ('\u2c87', '\u2c87', Ll), ('\u2c88', '\u2c88', Lu),
+        ('\u2c89', '\u2c89', Ll), ('\u2c8a', '\u2c8a', Lu), ('\u2c8b', '\u2c8b', Ll),
+        ('\u2c8c', '\u2c8c', Lu), ('\u2c8d', '\u2c8d', Ll), ('\u2c8e', '\u2c8e', Lu),
+        ('\u2c8f', '\u2c8f', Ll), ('\u2c90', '\u2c90', Lu), ('\u2c91', '\u2c91', Ll),
+        ('\u2c92', '\u2c92', Lu), ('\u2c93', '\u2c93', Ll), ('\u2c94', '\u2c94', Lu),
+        ('\u2c95', '\u2c95', Ll), ('\u2c96', '\u2c96', Lu), ('\u2c97', '\u2c97', Ll),
+        ('\u2c98', '\u2c98', Lu), ('\u2c99', '\u2c99', Ll), ('\u2c9a', '\u2c9a', Lu),
+        ('\u2c9b', '\u2c9b', Ll), ('\u2c9c', '\u2c9c', Lu), ('\u2c9d', '\u2c9d', Ll),
+        ('\u2c9e', '\u2c9e', Lu), ('\u2c9f', '\u2c9f', Ll), ('\u2ca0', '\u2ca0', Lu),
+        ('\u2ca1', '\u2ca1', Ll), ('\u2ca2', '\u2ca2', Lu), ('\u2ca3', '\u2ca3', Ll),
+        ('\u2ca4', '\u2ca4', Lu), ('\u2ca5', '\u2ca5', Ll), ('\u2ca6', '\u2ca6', Lu),
+        ('\u2ca7', '\u2ca7', Ll), ('\u2ca8', '\u2ca8', Lu), ('\u2ca9', '\u2ca9', Ll),
+        ('\u2caa', '\u2caa', Lu), ('\u2cab', '\u2cab', Ll), ('\u2cac', '\u2cac', Lu),
+        ('\u2cad', '\u2cad', Ll), ('\u2cae', '\u2cae', Lu), ('\u2caf', '\u2caf', Ll),
+        ('\u2cb0', '\u2cb0', Lu), ('\u2cb1', '\u2cb1', Ll), ('\u2cb2', '\u2cb2', Lu),
+        ('\u2cb3', '\u2cb3', Ll), ('\u2cb4', '\u2cb4', Lu), ('\u2cb5', '\u2cb5', Ll),
+        ('\u2cb6', '\u2cb6', Lu), ('\u2cb7', '\u2cb7', Ll), ('\u2cb8', '\u2cb8', Lu),
+        ('\u2cb9', '\u2cb9', Ll), ('\u2cba', '\u2cba', Lu), ('\u2cbb', '\u2cbb', Ll),
+        ('\u2cbc', '\u2cbc', Lu), ('\u2cbd', '\u2cbd', Ll), ('\u2cbe', '\u2cbe', Lu),
+        ('\u2cbf', '\u2cbf', Ll), ('\u2cc0', '\u2cc0', Lu), ('\u2cc1', '\u2cc1', Ll),
+        ('\u2cc2', '\u2cc2', Lu), ('\u2cc3', '\u2cc3', Ll), ('\u2cc4', '\u2cc4', Lu),
+        ('\u2cc5', '\u2cc5', Ll), ('\u2cc6', '\u2cc6', Lu), ('\u2cc7', '\u2cc7', Ll),
+        ('\u2cc8', '\u2cc8', Lu), ('\u2cc9', '\u2cc9', Ll), ('\u2cca', '\u2cca', Lu),
+        ('\u2ccb', '\u2ccb', Ll), ('\u2ccc', '\u2ccc', Lu), ('\u2ccd', '\u2ccd', Ll),
+        ('\u2cce', '\u2cce', Lu), ('\u2ccf', '\u2ccf', Ll), ('\u2cd0', '\u2cd0', Lu),
+        ('\u2cd1', '\u2cd1', Ll), ('\u2cd2', '\u2cd2', Lu), ('\u2cd3', '\u2cd3', Ll),
+        ('\u2cd4', '\u2cd4', Lu), ('\u2cd5', '\u2cd5', Ll), ('\u2cd6', '\u2cd6', Lu),
+        ('\u2cd7', '\u2cd7', Ll), ('\u2cd8', '\u2cd8', Lu), ('\u2cd9', '\u2cd9', Ll),
+        ('\u2cda', '\u2cda', Lu), ('\u2cdb', '\u2cdb', Ll), ('\u2cdc', '\u2cdc', Lu),
+        ('\u2cdd', '\u2cdd', Ll), ('\u2cde', '\u2cde', Lu), ('\u2cdf', '\u2cdf', Ll),
+        ('\u2ce0', '\u2ce0', Lu), ('\u2ce1', '\u2ce1', Ll), ('\u2ce2', '\u2ce2', Lu),
+        ('\u2ce3', '\u2ce4', Ll), ('\u2ce5', '\u2cea', So), ('\u2ceb', '\u2ceb', Lu),
+        ('\u2cec', '\u2cec', Ll), ('\u2ced', '\u2ced', Lu), ('\u2cee', '\u2cee', Ll),
+        ('\u2cef', '\u2cf1', Mn), ('\u2cf2', '\u2cf2', Lu), ('\u2cf3', '\u2cf3', Ll),
+        ('\u2cf9', '\u2cfc', Po), ('\u2cfd', '\u2cfd', No), ('\u2cfe', '\u2cff', Po),
+        ('\u2d00', '\u2d25', Ll), ('\u2d27', '\u2d27', Ll), ('\u2d2d', '\u2d2d', Ll),
+        ('\u2d30', '\u2d67', Lo), ('\u2d6f', '\u2d6f', Lm), ('\u2d70', '\u2d70', Po),
+        ('\u2d7f', '\u2d7f', Mn), ('\u2d80', '\u2d96', Lo), ('\u2da0', '\u2da6', Lo),
+        ('\u2da8', '\u2dae', Lo), ('\u2db0', '\u2db6', Lo), ('\u2db8', '\u2dbe', Lo),
+        ('\u2dc0', '\u2dc6', Lo), ('\u2dc8', '\u2dce', Lo), ('\u2dd0', '\u2dd6', Lo),
+        ('\u2dd8', '\u2dde', Lo), ('\u2de0', '\u2dff', Mn), ('\u2e00', '\u2e01', Po),
+        ('\u2e02', '\u2e02', Pi), ('\u2e03', '\u2e03', Pf), ('\u2e04', '\u2e04', Pi),
+        ('\u2e05', '\u2e05', Pf), ('\u2e06', '\u2e08', Po), ('\u2e09', '\u2e09', Pi),
+        ('\u2e0a', '\u2e0a', Pf), ('\u2e0b', '\u2e0b', Po), ('\u2e0c', '\u2e0c', Pi),
+        ('\u2e0d', '\u2e0d', Pf), ('\u2e0e', '\u2e16', Po), ('\u2e17', '\u2e17', Pd),
+        ('\u2e18', '\u2e19', Po), ('\u2e1a', '\u2e1a', Pd), ('\u2e1b', '\u2e1b', Po),
+        ('\u2e1c', '\u2e1c', Pi), ('\u2e1d', '\u2e1d', Pf), ('\u2e1e', '\u2e1f', Po),
+        ('\u2e20', '\u2e20', Pi), ('\u2e21', '\u2e21', Pf), ('\u2e22', '\u2e22', Ps),
+        ('\u2e23', '\u2e23', Pe), ('\u2e24', '\u2e24', Ps), ('\u2e25', '\u2e25', Pe),
+        ('\u2e26', '\u2e26', Ps), ('\u2e27', '\u2e27', Pe), ('\u2e28', '\u2e28', Ps),
+        ('\u2e29', '\u2e29', Pe), ('\u2e2a', '\u2e2e', Po), ('\u2e2f', '\u2e2f', Lm),
+        ('\u2e30', '\u2e39', Po), ('\u2e3a', '\u2e3b', Pd), ('\u2e3c', '\u2e3f', Po),
+        ('\u2e40', '\u2e40', Pd), ('\u2e41', '\u2e41', Po), ('\u2e42', '\u2e42', Ps),
+        ('\u2e43', '\u2e4f', Po), ('\u2e50', '\u2e51', So), ('\u2e52', '\u2e54', Po),
+        ('\u2e55', '\u2e55', Ps), ('\u2e56', '\u2e56', Pe), ('\u2e57', '\u2e57', Ps),
+        ('\u2e58', '\u2e58', Pe), ('\u2e59', '\u2e59', Ps), ('\u2e5a', '\u2e5a', Pe),
+        ('\u2e5b', '\u2e5b', Ps), ('\u2e5c', '\u2e5c', Pe), ('\u2e5d', '\u2e5d', Pd),
+        ('\u2e80', '\u2e99', So), ('\u2e9b', '\u2ef3', So), ('\u2f00', '\u2fd5', So),
+        ('\u2ff0', '\u2ffb', So), ('\u3000', '\u3000', Zs), ('\u3001', '\u3003', Po),
+        ('\u3004', '\u3004', So), ('\u3005', '\u3005', Lm), ('\u3006', '\u3006', Lo),
+        ('\u3007', '\u3007', Nl), ('\u3008', '\u3008', Ps), ('\u3009', '\u3009', Pe),
+        ('\u300a', '\u300a', Ps), ('\u300b', '\u300b', Pe), ('\u300c', '\u300c', Ps),
+        ('\u300d', '\u300d', Pe), ('\u300e', '\u300e', Ps), ('\u300f', '\u300f', Pe),
+        ('\u3010', '\u3010', Ps), ('\u3011', '\u3011', Pe), ('\u3012', '\u3013', So),
+        ('\u3014', '\u3014', Ps), ('\u3015', '\u3015', Pe), ('\u3016', '\u3016', Ps),
+        ('\u3017', '\u3017', Pe), ('\u3018', '\u3018', Ps), ('\u3019', '\u3019', Pe),
+        ('\u301a', '\u301a', Ps), ('\u301b', '\u301b', Pe), ('\u301c', '\u301c', Pd),
+        ('\u301d', '\u301d', Ps), ('\u301e', '\u301f', Pe), ('\u3020', '\u3020', So),
+        ('\u3021', '\u3029', Nl), ('\u302a', '\u302d', Mn), ('\u302e', '\u302f', Mc),
+        ('\u3030', '\u3030', Pd), ('\u3031', '\u3035', Lm), ('\u3036', '\u3037', So),
+        ('\u3038', '\u303a', Nl), ('\u303b', '\u303b', Lm), ('\u303c', '\u303c', Lo),
+        ('\u303d', '\u303d', Po), ('\u303e', '\u303f', So), ('\u3041', '\u3096', Lo),
+        ('\u3099', '\u309a', Mn), ('\u309b', '\u309c', Sk), ('\u309d', '\u309e', Lm),
+        ('\u309f', '\u309f', Lo), ('\u30a0', '\u30a0', Pd), ('\u30a1', '\u30fa', Lo),
+        ('\u30fb', '\u30fb', Po), ('\u30fc', '\u30fe', Lm), ('\u30ff', '\u30ff', Lo),
+        ('\u3105', '\u312f', Lo), ('\u3131', '\u318e', Lo), ('\u3190', '\u3191', So),
+        ('\u3192', '\u3195', No), ('\u3196', '\u319f', So), ('\u31a0', '\u31bf', Lo),
+        ('\u31c0', '\u31e3', So), ('\u31f0', '\u31ff', Lo), ('\u3200', '\u321e', So),
+        ('\u3220', '\u3229', No), ('\u322a', '\u3247', So), ('\u3248', '\u324f', No),
+        ('\u3250', '\u3250', So), ('\u3251', '\u325f', No), ('\u3260', '\u327f', So),
+        ('\u3280', '\u3289', No), ('\u328a', '\u32b0', So), ('\u32b1', '\u32bf', No),
+        ('\u32c0', '\u33ff', So), ('\u3400', '\u4dbf', Lo), ('\u4dc0', '\u4dff', So),
+        ('\u4e00', '\ua014', Lo), ('\ua015', '\ua015', Lm), ('\ua016', '\ua48c', Lo),
+        ('\ua490', '\ua4c6', So), ('\ua4d0', '\ua4f7', Lo), ('\ua4f8', '\ua4fd', Lm),
+        ('\ua4fe', '\ua4ff', Po), ('\ua500', '\ua60b', Lo), ('\ua60c', '\ua60c', Lm),
+        ('\ua60d', '\ua60f', Po), ('\ua610', '\ua61f', Lo), ('\ua620', '\ua629', Nd),
+        ('\ua62a', '\ua62b', Lo), ('\ua640', '\ua640', Lu), ('\ua641', '\ua641', Ll),
+        ('\ua642', '\ua642', Lu), ('\ua643', '\ua643', Ll), ('\ua644', '\ua644', Lu),
+        ('\ua645', '\ua645', Ll), ('\ua646', '\ua646', Lu), ('\ua647', '\ua647', Ll),
+        ('\ua648', '\ua648', Lu), ('\ua649', '\ua649', Ll), ('\ua64a', '\ua64a', Lu),
+        ('\ua64b', '\ua64b', Ll), ('\ua64c', '\ua64c', Lu), ('\ua64d', '\ua64d', Ll),
+        ('\ua64e', '\ua64e', Lu), ('\ua64f', '\ua64f', Ll), ('\ua650', '\ua650', Lu),
+        ('\ua651', '\ua651', Ll), ('\ua652', '\ua652', Lu), ('\ua653', '\ua653', Ll),
+        ('\ua654', '\ua654', Lu), ('\ua655', '\ua655', Ll), ('\ua656', '\ua656', Lu),
+        ('\ua657', '\ua657', Ll), ('\ua658', '\ua658', Lu), ('\ua659', '\ua659', Ll),
+        ('\ua65a', '\ua65a', Lu), ('\ua65b', '\ua65b', Ll), ('\ua65c', '\ua65c', Lu),
+        ('\ua65d', '\ua65d', Ll), ('\ua65e', '\ua65e', Lu), ('\ua65f', '\ua65f', Ll),
+        ('\ua660', '\ua660', Lu), ('\ua661', '\ua661', Ll), ('\ua662', '\ua662', Lu),
+        ('\ua663', '\ua663', Ll), ('\ua664', '\ua664', Lu), ('\ua665', '\ua665', Ll),
+        ('\ua666', '\ua666', Lu), ('\ua667', '\ua667', Ll), ('\ua668', '\ua668', Lu),
+        ('\ua669', '\ua669', Ll), ('\ua66a', '\ua66a', Lu), ('\ua66b', '\ua66b', Ll),
+        ('\ua66c', '\ua66c', Lu), ('\ua66d', '\ua66d', Ll), ('\ua66e', '\ua66e', Lo),
+        ('\ua66f', '\ua66f', Mn), ('\ua670', '\ua672', Me), ('\ua673', '\ua673', Po),
+        ('\ua674', '\ua67d', Mn), ('\ua67e', '\ua67e', Po), ('\ua67f', '\ua67f', Lm),
+        ('\ua680', '\ua680', Lu), ('\ua681', '\ua681', Ll), ('\ua682', '\ua682', Lu),
+        ('\ua683', '\ua683', Ll), ('\ua684', '\ua684', Lu), ('\ua685', '\ua685', Ll),
+        ('\ua686', '\ua686', Lu), ('\ua687', '\ua687', Ll), ('\ua688', '\ua688', Lu),
+        ('\ua689', '\ua689', Ll), ('\ua68a', '\ua68a', Lu), ('\ua68b', '\ua68b', Ll),
+        ('\ua68c', '\ua68c', Lu), ('\ua68d', '\ua68d', Ll), ('\ua68e', '\ua68e', Lu),
+        ('\ua68f', '\ua68f', Ll), ('\ua690', '\ua690', Lu), ('\ua691', '\ua691', Ll),
+        ('\ua692', '\ua692', Lu), ('\ua693', '\ua693', Ll), ('\ua694', '\ua694', Lu),
+        ('\ua695', '\ua695', Ll), ('\ua696', '\ua696', Lu), ('\ua697', '\ua697', Ll),
+        ('\ua698', '\ua698', Lu), ('\ua699', '\ua699', Ll), ('\ua69a', '\ua69a', Lu),
+        ('\ua69b', '\ua69b', Ll), ('\ua69c', '\ua69d', Lm), ('\ua69e', '\ua69f', Mn),
+        ('\ua6a0', '\ua6e5', Lo), ('\ua6e6', '\ua6ef', Nl), ('\ua6f0', '\ua6f1', Mn),
+        ('\ua6f2', '\ua6f7', Po), ('\ua700', '\ua716', Sk), ('\ua717', '\ua71f', Lm),
+        ('\ua720', '\ua721', Sk), ('\ua722', '\ua722', Lu), ('\ua723', '\ua723', Ll),
+        ('\ua724', '\ua724', Lu), ('\ua725', '\ua725', Ll), ('\ua726', '\ua726', Lu),
+        ('\ua727', '\ua727', Ll), ('\ua728', '\ua728', Lu), ('\ua729', '\ua729', Ll),
+        ('\ua72a', '\ua72a', Lu), ('\ua72b', '\ua72b', Ll), ('\ua72c', '\ua72c', Lu),
+        ('\ua72d', '\ua72d', Ll), ('\ua72e', '\ua72e', Lu), ('\ua72f', '\ua731', Ll),
+        ('\ua732', '\ua732', Lu), ('\ua733', '\ua733', Ll), ('\ua734', '\ua734', Lu),
+        ('\ua735', '\ua735', Ll), ('\ua736', '\ua736', Lu), ('\ua737', '\ua737', Ll),
+        ('\ua738', '\ua738', Lu), ('\ua739', '\ua739', Ll), ('\ua73a', '\ua73a', Lu),
+        ('\ua73b', '\ua73b', Ll), ('\ua73c', '\ua73c', Lu), ('\ua73d', '\ua73d', Ll),
+        ('\ua73e', '\ua73e', Lu), ('\ua73f', '\ua73f', Ll), ('\ua740', '\ua740', Lu),
+        ('\ua741', '\ua741', Ll), ('\ua742', '\ua742', Lu), ('\ua743', '\ua743', Ll),
+        ('\ua744', '\ua744', Lu), ('\ua745', '\ua745', Ll), ('\ua746', '\ua746', Lu),
+        ('\ua747', '\ua747', Ll), ('\ua748', '\ua748', Lu), ('\ua749', '\ua749', Ll),
+        ('\ua74a', '\ua74a', Lu), ('\ua74b', '\ua74b', Ll), ('\ua74c', '\ua74c', Lu),
+        ('\ua74d', '\ua74d', Ll), ('\ua74e', '\ua74e', Lu), ('\ua74f', '\ua74f', Ll),
+        ('\ua750', '\ua750', Lu), ('\ua751', '\ua751', Ll), ('\ua752', '\ua752', Lu),
+        ('\ua753', '\ua753', Ll), ('\ua754', '\ua754', Lu), ('\ua755', '\ua755', Ll),
+        ('\ua756', '\ua756', Lu), ('\ua757', '\ua757', Ll), ('\ua758', '\ua758', Lu),
+        ('\ua759', '\ua759', Ll), ('\ua75a', '\ua75a', Lu), ('\ua75b', '\ua75b', Ll),
+        ('\ua75c', '\ua75c', Lu), ('\ua75d', '\ua75d', Ll), ('\ua75e', '\ua75e', Lu),
+        ('\ua75f', '\ua75f', Ll), ('\ua760', '\ua760', Lu), ('\ua761', '\ua761', Ll),
+        ('\ua762', '\ua762', Lu), ('\ua763', '\ua763', Ll), ('\ua764', '\ua764', Lu),
+        ('\ua765', '\ua765', Ll), ('\ua766', '\ua766', Lu), ('\ua767', '\ua767', Ll),
+        ('\ua768', '\ua768', Lu), ('\ua769', '\ua769', Ll), ('\ua76a', '\ua76a', Lu),
+        ('\ua76b', '\ua76b', Ll), ('\ua76c', '\ua76c', Lu), ('\ua76d', '\ua76d', Ll),
+        ('\ua76e', '\ua76e', Lu), ('\ua76f', '\ua76f', Ll), ('\ua770', '\ua770', Lm),
+        ('\ua771', '\ua778', Ll), ('\ua779', '\ua779', Lu), ('\ua77a', '\ua77a', Ll),
+        ('\ua77b', '\ua77b', Lu), ('\ua77c', '\ua77c', Ll), ('\ua77d', '\ua77e', Lu),
+        ('\ua77f', '\ua77f', Ll), ('\ua780', '\ua780', Lu), ('\ua781', '\ua781', Ll),
+        ('\ua782', '\ua782', Lu), ('\ua783', '\ua783', Ll), ('\ua784', '\ua784', Lu),
+        ('\ua785', '\ua785', Ll), ('\ua786', '\ua786', Lu), ('\ua787', '\ua787', Ll),
+        ('\ua788', '\ua788', Lm), ('\ua789', '\ua78a', Sk), ('\ua78b', '\ua78b', Lu),
+        ('\ua78c', '\ua78c', Ll), ('\ua78d', '\ua78d', Lu), ('\ua78e', '\ua78e', Ll),
+        ('\ua78f', '\ua78f', Lo), ('\ua790', '\ua790', Lu), ('\ua791', '\ua791', Ll),
+        ('\ua792', '\ua792', Lu), ('\ua793', '\ua795', Ll), ('\ua796', '\ua796', Lu),
+        ('\ua797', '\ua797', Ll), ('\ua798', '\ua798', Lu), ('\ua799', '\ua799', Ll),
+        ('\ua79a', '\ua79a', Lu), ('\ua79b', '\ua79b', Ll), ('\ua79c', '\ua79c', Lu),
+        ('\ua79d', '\ua79d', Ll), ('\ua79e', '\ua79e', Lu), ('\ua79f', '\ua79f', Ll),
+        ('\ua7a0', '\ua7a0', Lu), ('\ua7a1', '\ua7a1', Ll), ('\ua7a2', '\ua7a2', Lu),
+        ('\ua7a3', '\ua7a3', Ll), ('\ua7a4', '\ua7a4', Lu), ('\ua7a5', '\ua7a5', Ll),
+        ('\ua7a6', '\ua7a6', Lu), ('\ua7a7', '\ua7a7', Ll), ('\ua7a8', '\ua7a8', Lu),
+        ('\ua7a9', '\ua7a9', Ll), ('\ua7aa', '\ua7ae', Lu), ('\ua7af', '\ua7af', Ll),
+        ('\ua7b0', '\ua7b4', Lu), ('\ua7b5', '\ua7b5', Ll), ('\ua7b6', '\ua7b6', Lu),
+        ('\ua7b7', '\ua7b7', Ll), ('\ua7b8', '\ua7b8', Lu), ('\ua7b9', '\ua7b9', Ll),
+        ('\ua7ba', '\ua7ba', Lu), ('\ua7bb', '\ua7bb', Ll), ('\ua7bc', '\ua7bc', Lu),
+        ('\ua7bd', '\ua7bd', Ll), ('\ua7be', '\ua7be', Lu), ('\ua7bf', '\ua7bf', Ll),
+        ('\ua7c0', '\ua7c0', Lu), ('\ua7c1', '\ua7c1', Ll), ('\ua7c2', '\ua7c2', Lu),
+        ('\ua7c3', '\ua7c3', Ll), ('\ua7c4', '\ua7c7', Lu), ('\ua7c8', '\ua7c8', Ll),
+        ('\ua7c9', '\ua7c9', Lu), ('\ua7ca', '\ua7ca', Ll), ('\ua7d0', '\ua7d0', Lu),
+        ('\ua7d1', '\ua7d1', Ll), ('\ua7d3', '\ua7d3', Ll), ('\ua7d5', '\ua7d5', Ll),
+        ('\ua7d6', '\ua7d6', Lu), ('\ua7d7', '\ua7d7', Ll), ('\ua7d8', '\ua7d8', Lu),
+        ('\ua7d9', '\ua7d9', Ll), ('\ua7f2', '\ua7f4', Lm), ('\ua7f5', '\ua7f5', Lu),
+        ('\ua7f6', '\ua7f6', Ll), ('\ua7f7', '\ua7f7', Lo), ('\ua7f8', '\ua7f9', Lm),
+        ('\ua7fa', '\ua7fa', Ll), ('\ua7fb', '\ua801', Lo), ('\ua802', '\ua802', Mn),
+        ('\ua803', '\ua805', Lo), ('\ua806', '\ua806', Mn), ('\ua807', '\ua80a', Lo),
+        ('\ua80b', '\ua80b', Mn), ('\ua80c', '\ua822', Lo), ('\ua823', '\ua824', Mc),
+        ('\ua825', '\ua826', Mn), ('\ua827', '\ua827', Mc), ('\ua828', '\ua82b', So),
+        ('\ua82c', '\ua82c', Mn), ('\ua830', '\ua835', No), ('\ua836', '\ua837', So),
+        ('\ua838', '\ua838', Sc), ('\ua839', '\ua839', So), ('\ua840', '\ua873', Lo),
+        ('\ua874', '\ua877', Po), ('\ua880', '\ua881', Mc), ('\ua882', '\ua8b3', Lo),
+        ('\ua8b4', '\ua8c3', Mc), ('\ua8c4', '\ua8c5', Mn), ('\ua8ce', '\ua8cf', Po),
+        ('\ua8d0', '\ua8d9', Nd), ('\ua8e0', '\ua8f1', Mn), ('\ua8f2', '\ua8f7', Lo),
+        ('\ua8f8', '\ua8fa', Po), ('\ua8fb', '\ua8fb', Lo), ('\ua8fc', '\ua8fc', Po),
+        ('\ua8fd', '\ua8fe', Lo), ('\ua8ff', '\ua8ff', Mn), ('\ua900', '\ua909', Nd),
+        ('\ua90a', '\ua925', Lo), ('\ua926', '\ua92d', Mn), ('\ua92e', '\ua92f', Po),
+        ('\ua930', '\ua946', Lo), ('\ua947', '\ua951', Mn), ('\ua952', '\ua953', Mc),
+        ('\ua95f', '\ua95f', Po), ('\ua960', '\ua97c', Lo), ('\ua980', '\ua982', Mn),
+        ('\ua983', '\ua983', Mc), ('\ua984', '\ua9b2', Lo), ('\ua9b3', '\ua9b3', Mn),
+        ('\ua9b4', '\ua9b5', Mc), ('\ua9b6', '\ua9b9', Mn), ('\ua9ba', '\ua9bb', Mc),
+        ('\ua9bc', '\ua9bd', Mn), ('\ua9be', '\ua9c0', Mc), ('\ua9c1', '\ua9cd', Po),
+        ('\ua9cf', '\ua9cf', Lm), ('\ua9d0', '\ua9d9', Nd), ('\ua9de', '\ua9df', Po),
+        ('\ua9e0', '\ua9e4', Lo), ('\ua9e5', '\ua9e5', Mn), ('\ua9e6', '\ua9e6', Lm),
+        ('\ua9e7', '\ua9ef', Lo), ('\ua9f0', '\ua9f9', Nd), ('\ua9fa', '\ua9fe', Lo),
+        ('\uaa00', '\uaa28', Lo), ('\uaa29', '\uaa2e', Mn), ('\uaa2f', '\uaa30', Mc),
+        ('\uaa31', '\uaa32', Mn), ('\uaa33', '\uaa34', Mc), ('\uaa35', '\uaa36', Mn),
+        ('\uaa40', '\uaa42', Lo), ('\uaa43', '\uaa43', Mn), ('\uaa44', '\uaa4b', Lo),
+        ('\uaa4c', '\uaa4c', Mn), ('\uaa4d', '\uaa4d', Mc), ('\uaa50', '\uaa59', Nd),
+        ('\uaa5c', '\uaa5f', Po), ('\uaa60', '\uaa6f', Lo), ('\uaa70', '\uaa70', Lm),
+        ('\uaa71', '\uaa76', Lo), ('\uaa77', '\uaa79', So), ('\uaa7a', '\uaa7a', Lo),
+        ('\uaa7b', '\uaa7b', Mc), ('\uaa7c', '\uaa7c', Mn), ('\uaa7d', '\uaa7d', Mc),
+        ('\uaa7e', '\uaaaf', Lo), ('\uaab0', '\uaab0', Mn), ('\uaab1', '\uaab1', Lo),
+        ('\uaab2', '\uaab4', Mn), ('\uaab5', '\uaab6', Lo), ('\uaab7', '\uaab8', Mn),
+        ('\uaab9', '\uaabd', Lo), ('\uaabe', '\uaabf', Mn), ('\uaac0', '\uaac0', Lo),
+        ('\uaac1', '\uaac1', Mn), ('\uaac2', '\uaac2', Lo), ('\uaadb', '\uaadc', Lo),
+        ('\uaadd', '\uaadd', Lm), ('\uaade', '\uaadf', Po), ('\uaae0', '\uaaea', Lo),
+        ('\uaaeb', '\uaaeb', Mc), ('\uaaec', '\uaaed', Mn), ('\uaaee', '\uaaef', Mc),
+        ('\uaaf0', '\uaaf1', Po), ('\uaaf2', '\uaaf2', Lo), ('\uaaf3', '\uaaf4', Lm),
+        ('\uaaf5', '\uaaf5', Mc), ('\uaaf6', '\uaaf6', Mn), ('\uab01', '\uab06', Lo),
+        ('\uab09', '\uab0e', Lo), ('\uab11', '\uab16', Lo), ('\uab20', '\uab26', Lo),
+        ('\uab28', '\uab2e', Lo), ('\uab30', '\uab5a', Ll), ('\uab5b', '\uab5b', Sk),
+        ('\uab5c', '\uab5f', Lm), ('\uab60', '\uab68', Ll), ('\uab69', '\uab69', Lm),
+        ('\uab6a', '\uab6b', Sk), ('\uab70', '\uabbf', Ll), ('\uabc0', '\uabe2', Lo),
+        ('\uabe3', '\uabe4', Mc), ('\uabe5', '\uabe5', Mn), ('\uabe6', '\uabe7', Mc),
+        ('\uabe8', '\uabe8', Mn), ('\uabe9', '\uabea', Mc), ('\uabeb', '\uabeb', Po),
+        ('\uabec', '\uabec', Mc), ('\uabed', '\uabed', Mn), ('\uabf0', '\uabf9', Nd),
+        ('\uac00', '\ud7a3', Lo), ('\ud7b0', '\ud7c6', Lo), ('\ud7cb', '\ud7fb', Lo),
+        ('\ud800', '\udfff', Cs), ('\ue000', '\uf8ff', Co), ('\uf900', '\ufa6d', Lo),
+        ('\ufa70', '\ufad9', Lo), ('\ufb00', '\ufb06', Ll), ('\ufb13', '\ufb17', Ll),
+        ('\ufb1d', '\ufb1d', Lo), ('\ufb1e', '\ufb1e', Mn), ('\ufb1f', '\ufb28', Lo),
+        ('\ufb29', '\ufb29', Sm), ('\ufb2a', '\ufb36', Lo), ('\ufb38', '\ufb3c', Lo),
+        ('\ufb3e', '\ufb3e', Lo), ('\ufb40', '\ufb41', Lo), ('\ufb43', '\ufb44', Lo),
+        ('\ufb46', '\ufbb1', Lo), ('\ufbb2', '\ufbc2', Sk), ('\ufbd3', '\ufd3d', Lo),
+        ('\ufd3e', '\ufd3e', Pe), ('\ufd3f', '\ufd3f', Ps), ('\ufd40', '\ufd4f', So),
+        ('\ufd50', '\ufd8f', Lo), ('\ufd92', '\ufdc7', Lo), ('\ufdcf', '\ufdcf', So),
+        ('\ufdf0', '\ufdfb', Lo), ('\ufdfc', '\ufdfc', Sc), ('\ufdfd', '\ufdff', So),
+        ('\ufe00', '\ufe0f', Mn), ('\ufe10', '\ufe16', Po), ('\ufe17', '\ufe17', Ps),
+        ('\ufe18', '\ufe18', Pe), ('\ufe19', '\ufe19', Po), ('\ufe20', '\ufe2f', Mn),
+        ('\ufe30', '\ufe30', Po), ('\ufe31', '\ufe32', Pd), ('\ufe33', '\ufe34', Pc),
+        ('\ufe35', '\ufe35', Ps), ('\ufe36', '\ufe36', Pe), ('\ufe37', '\ufe37', Ps),
+        ('\ufe38', '\ufe38', Pe), ('\ufe39', '\ufe39', Ps), ('\ufe3a', '\ufe3a', Pe),
+        ('\ufe3b', '\ufe3b', Ps), ('\ufe3c', '\ufe3c', Pe), ('\ufe3d', '\ufe3d', Ps),
+        ('\ufe3e', '\ufe3e', Pe), ('\ufe3f', '\ufe3f', Ps), ('\ufe40', '\ufe40', Pe),
+        ('\ufe41', '\ufe41', Ps), ('\ufe42', '\ufe42', Pe), ('\ufe43', '\ufe43', Ps),
+        ('\ufe44', '\ufe44', Pe), ('\ufe45', '\ufe46', Po), ('\ufe47', '\ufe47', Ps),
+        ('\ufe48', '\ufe48', Pe), ('\ufe49', '\ufe4c', Po), ('\ufe4d', '\ufe4f', Pc),
+        ('\ufe50', '\ufe52', Po), ('\ufe54', '\ufe57', Po), ('\ufe58', '\ufe58', Pd),
+        ('\ufe59', '\ufe59', Ps), ('\ufe5a', '\ufe5a', Pe), ('\ufe5b', '\ufe5b', Ps),
+        ('\ufe5c', '\ufe5c', Pe), ('\ufe5d', '\ufe5d', Ps), ('\ufe5e', '\ufe5e', Pe),
+        ('\ufe5f', '\ufe61', Po), ('\ufe62', '\ufe62', Sm), ('\ufe63', '\ufe63', Pd),
+        ('\ufe64', '\ufe66', Sm), ('\ufe68', '\ufe68', Po), ('\ufe69', '\ufe69', Sc),
+        ('\ufe6a', '\ufe6b', Po), ('\ufe70', '\ufe74', Lo), ('\ufe76', '\ufefc', Lo),
+        ('\ufeff', '\ufeff', Cf), ('\uff01', '\uff03', Po), ('\uff04', '\uff04', Sc),
+        ('\uff05', '\uff07', Po), ('\uff08', '\uff08', Ps), ('\uff09', '\uff09', Pe),
+        ('\uff0a', '\uff0a', Po), ('\uff0b', '\uff0b', Sm), ('\uff0c', '\uff0c', Po),
+        ('\uff0d', '\uff0d', Pd), ('\uff0e', '\uff0f', Po), ('\uff10', '\uff19', Nd),
+        ('\uff1a', '\uff1b', Po), ('\uff1c', '\uff1e', Sm), ('\uff1f', '\uff20', Po),
+        ('\uff21', '\uff3a', Lu), ('\uff3b', '\uff3b', Ps), ('\uff3c', '\uff3c', Po),
+        ('\uff3d', '\uff3d', Pe), ('\uff3e', '\uff3e', Sk), ('\uff3f', '\uff3f', Pc),
+        ('\uff40', '\uff40', Sk), ('\uff41', '\uff5a', Ll), ('\uff5b', '\uff5b', Ps),
+        ('\uff5c', '\uff5c', Sm), ('\uff5d', '\uff5d', Pe), ('\uff5e', '\uff5e', Sm),
+        ('\uff5f', '\uff5f', Ps), ('\uff60', '\uff60', Pe), ('\uff61', '\uff61', Po),
+        ('\uff62', '\uff62', Ps), ('\uff63', '\uff63', Pe), ('\uff64', '\uff65', Po),
+        ('\uff66', '\uff6f', Lo), ('\uff70', '\uff70', Lm), ('\uff71', '\uff9d', Lo),
+        ('\uff9e', '\uff9f', Lm), ('\uffa0', '\uffbe', Lo), ('\uffc2', '\uffc7', Lo),
+        ('\uffca', '\uffcf', Lo), ('\uffd2', '\uffd7', Lo), ('\uffda', '\uffdc', Lo),
+        ('\uffe0', '\uffe1', Sc), ('\uffe2', '\uffe2', Sm), ('\uffe3', '\uffe3', Sk),
+        ('\uffe4', '\uffe4', So), ('\uffe5', '\uffe6', Sc), ('\uffe8', '\uffe8', So),
+        ('\uffe9', '\uffec', Sm), ('\uffed', '\uffee', So), ('\ufff9', '\ufffb', Cf),
+        ('\ufffc', '\ufffd', So), ('\U00010000', '\U0001000b', Lo),
+        ('\U0001000d', '\U00010026', Lo), ('\U00010028', '\U0001003a', Lo),
+        ('\U0001003c', '\U0001003d', Lo), ('\U0001003f', '\U0001004d', Lo),
+        ('\U00010050', '\U0001005d', Lo), ('\U00010080', '\U000100fa', Lo),
+        ('\U00010100', '\U00010102', Po), ('\U00010107', '\U00010133', No),
+        ('\U00010137', '\U0001013f', So), ('\U00010140', '\U00010174', Nl),
+        ('\U00010175', '\U00010178', No), ('\U00010179', '\U00010189', So),
+        ('\U0001018a', '\U0001018b', No), ('\U0001018c', '\U0001018e', So),
+        ('\U00010190', '\U0001019c', So), ('\U000101a0', '\U000101a0', So),
+        ('\U000101d0', '\U000101fc', So), ('\U000101fd', '\U000101fd', Mn),
+        ('\U00010280', '\U0001029c', Lo), ('\U000102a0', '\U000102d0', Lo),
+        ('\U000102e0', '\U000102e0', Mn), ('\U000102e1', '\U000102fb', No),
+        ('\U00010300', '\U0001031f', Lo), ('\U00010320', '\U00010323', No),
+        ('\U0001032d', '\U00010340', Lo), ('\U00010341', '\U00010341', Nl),
+        ('\U00010342', '\U00010349', Lo), ('\U0001034a', '\U0001034a', Nl),
+        ('\U00010350', '\U00010375', Lo), ('\U00010376', '\U0001037a', Mn),
+        ('\U00010380', '\U0001039d', Lo), ('\U0001039f', '\U0001039f', Po),
+        ('\U000103a0', '\U000103c3', Lo), ('\U000103c8', '\U000103cf', Lo),
+        ('\U000103d0', '\U000103d0', Po), ('\U000103d1', '\U000103d5', Nl),
+        ('\U00010400', '\U00010427', Lu), ('\U00010428', '\U0001044f', Ll),
+        ('\U00010450', '\U0001049d', Lo), ('\U000104a0', '\U000104a9', Nd),
+        ('\U000104b0', '\U000104d3', Lu), ('\U000104d8', '\U000104fb', Ll),
+        ('\U00010500', '\U00010527', Lo), ('\U00010530', '\U00010563', Lo),
+        ('\U0001056f', '\U0001056f', Po), ('\U00010570', '\U0001057a', Lu),
+        ('\U0001057c', '\U0001058a', Lu), ('\U0001058c', '\U00010592', Lu),
+        ('\U00010594', '\U00010595', Lu), ('\U00010597', '\U000105a1', Ll),
+        ('\U000105a3', '\U000105b1', Ll), ('\U000105b3', '\U000105b9', Ll),
+        ('\U000105bb', '\U000105bc', Ll), ('\U00010600', '\U00010736', Lo),
+        ('\U00010740', '\U00010755', Lo), ('\U00010760', '\U00010767', Lo),
+        ('\U00010780', '\U00010785', Lm), ('\U00010787', '\U000107b0', Lm),
+        ('\U000107b2', '\U000107ba', Lm), ('\U00010800', '\U00010805', Lo),
+        ('\U00010808', '\U00010808', Lo), ('\U0001080a', '\U00010835', Lo),
+        ('\U00010837', '\U00010838', Lo), ('\U0001083c', '\U0001083c', Lo),
+        ('\U0001083f', '\U00010855', Lo), ('\U00010857', '\U00010857', Po),
+        ('\U00010858', '\U0001085f', No), ('\U00010860', '\U00010876', Lo),
+        ('\U00010877', '\U00010878', So), ('\U00010879', '\U0001087f', No),
+        ('\U00010880', '\U0001089e', Lo), ('\U000108a7', '\U000108af', No),
+        ('\U000108e0', '\U000108f2', Lo), ('\U000108f4', '\U000108f5', Lo),
+        ('\U000108fb', '\U000108ff', No), ('\U00010900', '\U00010915', Lo),
+        ('\U00010916', '\U0001091b', No), ('\U0001091f', '\U0001091f', Po),
+        ('\U00010920', '\U00010939', Lo), ('\U0001093f', '\U0001093f', Po),
+        ('\U00010980', '\U000109b7', Lo), ('\U000109bc', '\U000109bd', No),
+        ('\U000109be', '\U000109bf', Lo), ('\U000109c0', '\U000109cf', No),
+        ('\U000109d2', '\U000109ff', No), ('\U00010a00', '\U00010a00', Lo),
+        ('\U00010a01', '\U00010a03', Mn), ('\U00010a05', '\U00010a06', Mn),
+        ('\U00010a0c', '\U00010a0f', Mn), ('\U00010a10', '\U00010a13', Lo),
+        ('\U00010a15', '\U00010a17', Lo), ('\U00010a19', '\U00010a35', Lo),
+        ('\U00010a38', '\U00010a3a', Mn), ('\U00010a3f', '\U00010a3f', Mn),
+        ('\U00010a40', '\U00010a48', No), ('\U00010a50', '\U00010a58', Po),
+        ('\U00010a60', '\U00010a7c', Lo), ('\U00010a7d', '\U00010a7e', No),
+        ('\U00010a7f', '\U00010a7f', Po), ('\U00010a80', '\U00010a9c', Lo),
+        ('\U00010a9d', '\U00010a9f', No), ('\U00010ac0', '\U00010ac7', Lo),
+        ('\U00010ac8', '\U00010ac8', So), ('\U00010ac9', '\U00010ae4', Lo),
+        ('\U00010ae5', '\U00010ae6', Mn), ('\U00010aeb', '\U00010aef', No),
+        ('\U00010af0', '\U00010af6', Po), ('\U00010b00', '\U00010b35', Lo),
+        ('\U00010b39', '\U00010b3f', Po), ('\U00010b40', '\U00010b55', Lo),
+        ('\U00010b58', '\U00010b5f', No), ('\U00010b60', '\U00010b72', Lo),
+        ('\U00010b78', '\U00010b7f', No), ('\U00010b80', '\U00010b91', Lo),
+        ('\U00010b99', '\U00010b9c', Po), ('\U00010ba9', '\U00010baf', No),
+        ('\U00010c00', '\U00010c48', Lo), ('\U00010c80', '\U00010cb2', Lu),
+        ('\U00010cc0', '\U00010cf2', Ll), ('\U00010cfa', '\U00010cff', No),
+        ('\U00010d00', '\U00010d23', Lo), ('\U00010d24', '\U00010d27', Mn),
+        ('\U00010d30', '\U00010d39', Nd), ('\U00010e60', '\U00010e7e', No),
+        ('\U00010e80', '\U00010ea9', Lo), ('\U00010eab', '\U00010eac', Mn),
+        ('\U00010ead', '\U00010ead', Pd), ('\U00010eb0', '\U00010eb1', Lo),
+        ('\U00010f00', '\U00010f1c', Lo), ('\U00010f1d', '\U00010f26', No),
+        ('\U00010f27', '\U00010f27', Lo), ('\U00010f30', '\U00010f45', Lo),
+        ('\U00010f46', '\U00010f50', Mn), ('\U00010f51', '\U00010f54', No),
+        ('\U00010f55', '\U00010f59', Po), ('\U00010f70', '\U00010f81', Lo),
+        ('\U00010f82', '\U00010f85', Mn), ('\U00010f86', '\U00010f89', Po),
+        ('\U00010fb0', '\U00010fc4', Lo), ('\U00010fc5', '\U00010fcb', No),
+        ('\U00010fe0', '\U00010ff6', Lo), ('\U00011000', '\U00011000', Mc),
+        ('\U00011001', '\U00011001', Mn), ('\U00011002', '\U00011002', Mc),
+        ('\U00011003', '\U00011037', Lo), ('\U00011038', '\U00011046', Mn),
+        ('\U00011047', '\U0001104d', Po), ('\U00011052', '\U00011065', No),
+        ('\U00011066', '\U0001106f', Nd), ('\U00011070', '\U00011070', Mn),
+        ('\U00011071', '\U00011072', Lo), ('\U00011073', '\U00011074', Mn),
+        ('\U00011075', '\U00011075', Lo), ('\U0001107f', '\U00011081', Mn),
+        ('\U00011082', '\U00011082', Mc), ('\U00011083', '\U000110af', Lo),
+        ('\U000110b0', '\U000110b2', Mc), ('\U000110b3', '\U000110b6', Mn),
+        ('\U000110b7', '\U000110b8', Mc), ('\U000110b9', '\U000110ba', Mn),
+        ('\U000110bb', '\U000110bc', Po), ('\U000110bd', '\U000110bd', Cf),
+        ('\U000110be', '\U000110c1', Po), ('\U000110c2', '\U000110c2', Mn),
+        ('\U000110cd', '\U000110cd', Cf), ('\U000110d0', '\U000110e8', Lo),
+        ('\U000110f0', '\U000110f9', Nd), ('\U00011100', '\U00011102', Mn),
+        ('\U00011103', '\U00011126', Lo), ('\U00011127', '\U0001112b', Mn),
+        ('\U0001112c', '\U0001112c', Mc), ('\U0001112d', '\U00011134', Mn),
+        ('\U00011136', '\U0001113f', Nd), ('\U00011140', '\U00011143', Po),
+        ('\U00011144', '\U00011144', Lo), ('\U00011145', '\U00011146', Mc),
+        ('\U00011147', '\U00011147', Lo), ('\U00011150', '\U00011172', Lo),
+        ('\U00011173', '\U00011173', Mn), ('\U00011174', '\U00011175', Po),
+        ('\U00011176', '\U00011176', Lo), ('\U00011180', '\U00011181', Mn),
+        ('\U00011182', '\U00011182', Mc), ('\U00011183', '\U000111b2', Lo),
+        ('\U000111b3', '\U000111b5', Mc), ('\U000111b6', '\U000111be', Mn),
+        ('\U000111bf', '\U000111c0', Mc), ('\U000111c1', '\U000111c4', Lo),
+        ('\U000111c5', '\U000111c8', Po), ('\U000111c9', '\U000111cc', Mn),
+        ('\U000111cd', '\U000111cd', Po), ('\U000111ce', '\U000111ce', Mc),
+        ('\U000111cf', '\U000111cf', Mn), ('\U000111d0', '\U000111d9', Nd),
+        ('\U000111da', '\U000111da', Lo), ('\U000111db', '\U000111db', Po),
+        ('\U000111dc', '\U000111dc', Lo), ('\U000111dd', '\U000111df', Po),
+        ('\U000111e1', '\U000111f4', No), ('\U00011200', '\U00011211', Lo),
+        ('\U00011213', '\U0001122b', Lo), ('\U0001122c', '\U0001122e', Mc),
+        ('\U0001122f', '\U00011231', Mn), ('\U00011232', '\U00011233', Mc),
+        ('\U00011234', '\U00011234', Mn), ('\U00011235', '\U00011235', Mc),
+        ('\U00011236', '\U00011237', Mn), ('\U00011238', '\U0001123d', Po),
+        ('\U0001123e', '\U0001123e', Mn), ('\U00011280', '\U00011286', Lo),
+        ('\U00011288', '\U00011288', Lo), ('\U0001128a', '\U0001128d', Lo),
+        ('\U0001128f', '\U0001129d', Lo), ('\U0001129f', '\U000112a8', Lo),
+        ('\U000112a9', '\U000112a9', Po), ('\U000112b0', '\U000112de', Lo),
+        ('\U000112df', '\U000112df', Mn), ('\U000112e0', '\U000112e2', Mc),
+        ('\U000112e3', '\U000112ea', Mn), ('\U000112f0', '\U000112f9', Nd),
+        ('\U00011300', '\U00011301', Mn), ('\U00011302', '\U00011303', Mc),
+        ('\U00011305', '\U0001130c', Lo), ('\U0001130f', '\U00011310', Lo),
+        ('\U00011313', '\U00011328', Lo), ('\U0001132a', '\U00011330', Lo),
+        ('\U00011332', '\U00011333', Lo), ('\U00011335', '\U00011339', Lo),
+        ('\U0001133b', '\U0001133c', Mn), ('\U0001133d', '\U0001133d', Lo),
+        ('\U0001133e', '\U0001133f', Mc), ('\U00011340', '\U00011340', Mn),
+        ('\U00011341', '\U00011344', Mc), ('\U00011347', '\U00011348', Mc),
+        ('\U0001134b', '\U0001134d', Mc), ('\U00011350', '\U00011350', Lo),
+        ('\U00011357', '\U00011357', Mc), ('\U0001135d', '\U00011361', Lo),
+        ('\U00011362', '\U00011363', Mc), ('\U00011366', '\U0001136c', Mn),
+        ('\U00011370', '\U00011374', Mn), ('\U00011400', '\U00011434', Lo),
+        ('\U00011435', '\U00011437', Mc), ('\U00011438', '\U0001143f', Mn),
+        ('\U00011440', '\U00011441', Mc), ('\U00011442', '\U00011444', Mn),
+        ('\U00011445', '\U00011445', Mc), ('\U00011446', '\U00011446', Mn),
+        ('\U00011447', '\U0001144a', Lo), ('\U0001144b', '\U0001144f', Po),
+        ('\U00011450', '\U00011459', Nd), ('\U0001145a', '\U0001145b', Po),
+        ('\U0001145d', '\U0001145d', Po), ('\U0001145e', '\U0001145e', Mn),
+        ('\U0001145f', '\U00011461', Lo), ('\U00011480', '\U000114af', Lo),
+        ('\U000114b0', '\U000114b2', Mc), ('\U000114b3', '\U000114b8', Mn),
+        ('\U000114b9', '\U000114b9', Mc), ('\U000114ba', '\U000114ba', Mn),
+        ('\U000114bb', '\U000114be', Mc), ('\U000114bf', '\U000114c0', Mn),
+        ('\U000114c1', '\U000114c1', Mc), ('\U000114c2', '\U000114c3', Mn),
+        ('\U000114c4', '\U000114c5', Lo), ('\U000114c6', '\U000114c6', Po),
+        ('\U000114c7', '\U000114c7', Lo), ('\U000114d0', '\U000114d9', Nd),
+        ('\U00011580', '\U000115ae', Lo), ('\U000115af', '\U000115b1', Mc),
+        ('\U000115b2', '\U000115b5', Mn), ('\U000115b8', '\U000115bb', Mc),
+        ('\U000115bc', '\U000115bd', Mn), ('\U000115be', '\U000115be', Mc),
+        ('\U000115bf', '\U000115c0', Mn), ('\U000115c1', '\U000115d7', Po),
+        ('\U000115d8', '\U000115db', Lo), ('\U000115dc', '\U000115dd', Mn),
+        ('\U00011600', '\U0001162f', Lo), ('\U00011630', '\U00011632', Mc),
+        ('\U00011633', '\U0001163a', Mn), ('\U0001163b', '\U0001163c', Mc),
+        ('\U0001163d', '\U0001163d', Mn), ('\U0001163e', '\U0001163e', Mc),
+        ('\U0001163f', '\U00011640', Mn), ('\U00011641', '\U00011643', Po),
+        ('\U00011644', '\U00011644', Lo), ('\U00011650', '\U00011659', Nd),
+        ('\U00011660', '\U0001166c', Po), ('\U00011680', '\U000116aa', Lo),
+        ('\U000116ab', '\U000116ab', Mn), ('\U000116ac', '\U000116ac', Mc),
+        ('\U000116ad', '\U000116ad', Mn), ('\U000116ae', '\U000116af', Mc),
+        ('\U000116b0', '\U000116b5', Mn), ('\U000116b6', '\U000116b6', Mc),
+        ('\U000116b7', '\U000116b7', Mn), ('\U000116b8', '\U000116b8', Lo),
+        ('\U000116b9', '\U000116b9', Po), ('\U000116c0', '\U000116c9', Nd),
+        ('\U00011700', '\U0001171a', Lo), ('\U0001171d', '\U0001171f', Mn),
+        ('\U00011720', '\U00011721', Mc), ('\U00011722', '\U00011725', Mn),
+        ('\U00011726', '\U00011726', Mc), ('\U00011727', '\U0001172b', Mn),
+        ('\U00011730', '\U00011739', Nd), ('\U0001173a', '\U0001173b', No),
+        ('\U0001173c', '\U0001173e', Po), ('\U0001173f', '\U0001173f', So),
+        ('\U00011740', '\U00011746', Lo), ('\U00011800', '\U0001182b', Lo),
+        ('\U0001182c', '\U0001182e', Mc), ('\U0001182f', '\U00011837', Mn),
+        ('\U00011838', '\U00011838', Mc), ('\U00011839', '\U0001183a', Mn),
+        ('\U0001183b', '\U0001183b', Po), ('\U000118a0', '\U000118bf', Lu),
+        ('\U000118c0', '\U000118df', Ll), ('\U000118e0', '\U000118e9', Nd),
+        ('\U000118ea', '\U000118f2', No), ('\U000118ff', '\U00011906', Lo),
+        ('\U00011909', '\U00011909', Lo), ('\U0001190c', '\U00011913', Lo),
+        ('\U00011915', '\U00011916', Lo), ('\U00011918', '\U0001192f', Lo),
+        ('\U00011930', '\U00011935', Mc), ('\U00011937', '\U00011938', Mc),
+        ('\U0001193b', '\U0001193c', Mn), ('\U0001193d', '\U0001193d', Mc),
+        ('\U0001193e', '\U0001193e', Mn), ('\U0001193f', '\U0001193f', Lo),
+        ('\U00011940', '\U00011940', Mc), ('\U00011941', '\U00011941', Lo),
+        ('\U00011942', '\U00011942', Mc), ('\U00011943', '\U00011943', Mn),
+        ('\U00011944', '\U00011946', Po), ('\U00011950', '\U00011959', Nd),
+        ('\U000119a0', '\U000119a7', Lo), ('\U000119aa', '\U000119d0', Lo),
+        ('\U000119d1', '\U000119d3', Mc), ('\U000119d4', '\U000119d7', Mn),
+        ('\U000119da', '\U000119db', Mn), ('\U000119dc', '\U000119df', Mc),
+        ('\U000119e0', '\U000119e0', Mn), ('\U000119e1', '\U000119e1', Lo),
+        ('\U000119e2', '\U000119e2', Po), ('\U000119e3', '\U000119e3', Lo),
+        ('\U000119e4', '\U000119e4', Mc), ('\U00011a00', '\U00011a00', Lo),
+        ('\U00011a01', '\U00011a0a', Mn), ('\U00011a0b', '\U00011a32', Lo),
+        ('\U00011a33', '\U00011a38', Mn), ('\U00011a39', '\U00011a39', Mc),
+        ('\U00011a3a', '\U00011a3a', Lo), ('\U00011a3b', '\U00011a3e', Mn),
+        ('\U00011a3f', '\U00011a46', Po), ('\U00011a47', '\U00011a47', Mn),
+        ('\U00011a50', '\U00011a50', Lo), ('\U00011a51', '\U00011a56', Mn),
+        ('\U00011a57', '\U00011a58', Mc), ('\U00011a59', '\U00011a5b', Mn),
+        ('\U00011a5c', '\U00011a89', Lo), ('\U00011a8a', '\U00011a96', Mn),
+        ('\U00011a97', '\U00011a97', Mc), ('\U00011a98', '\U00011a99', Mn),
+        ('\U00011a9a', '\U00011a9c', Po), ('\U00011a9d', '\U00011a9d', Lo),
+        ('\U00011a9e', '\U00011aa2', Po), ('\U00011ab0', '\U00011af8', Lo),
+        ('\U00011c00', '\U00011c08', Lo), ('\U00011c0a', '\U00011c2e', Lo),
+        ('\U00011c2f', '\U00011c2f', Mc), ('\U00011c30', '\U00011c36', Mn),
+        ('\U00011c38', '\U00011c3d', Mn), ('\U00011c3e', '\U00011c3e', Mc),
+        ('\U00011c3f', '\U00011c3f', Mn), ('\U00011c40', '\U00011c40', Lo),
+        ('\U00011c41', '\U00011c45', Po), ('\U00011c50', '\U00011c59', Nd),
+        ('\U00011c5a', '\U00011c6c', No), ('\U00011c70', '\U00011c71', Po),
+        ('\U00011c72', '\U00011c8f', Lo), ('\U00011c92', '\U00011ca7', Mn),
+        ('\U00011ca9', '\U00011ca9', Mc), ('\U00011caa', '\U00011cb0', Mn),
+        ('\U00011cb1', '\U00011cb1', Mc), ('\U00011cb2', '\U00011cb3', Mn),
+        ('\U00011cb4', '\U00011cb4', Mc), ('\U00011cb5', '\U00011cb6', Mn),
+        ('\U00011d00', '\U00011d06', Lo), ('\U00011d08', '\U00011d09', Lo),
+        ('\U00011d0b', '\U00011d30', Lo), ('\U00011d31', '\U00011d36', Mn),
+        ('\U00011d3a', '\U00011d3a', Mn), ('\U00011d3c', '\U00011d3d', Mn),
+        ('\U00011d3f', '\U00011d45', Mn), ('\U00011d46', '\U00011d46', Lo),
+        ('\U00011d47', '\U00011d47', Mn), ('\U00011d50', '\U00011d59', Nd),
+        ('\U00011d60', '\U00011d65', Lo), ('\U00011d67', '\U00011d68', Lo),
+        ('\U00011d6a', '\U00011d89', Lo), ('\U00011d8a', '\U00011d8e', Mc),
+        ('\U00011d90', '\U00011d91', Mn), ('\U00011d93', '\U00011d94', Mc),
+        ('\U00011d95', '\U00011d95', Mn), ('\U00011d96', '\U00011d96', Mc),
+        ('\U00011d97', '\U00011d97', Mn), ('\U00011d98', '\U00011d98', Lo),
+        ('\U00011da0', '\U00011da9', Nd), ('\U00011ee0', '\U00011ef2', Lo),
+        ('\U00011ef3', '\U00011ef4', Mn), ('\U00011ef5', '\U00011ef6', Mc),
+        ('\U00011ef7', '\U00011ef8', Po), ('\U00011fb0', '\U00011fb0', Lo),
+        ('\U00011fc0', '\U00011fd4', No), ('\U00011fd5', '\U00011fdc', So),
+        ('\U00011fdd', '\U00011fe0', Sc), ('\U00011fe1', '\U00011ff1', So),
+        ('\U00011fff', '\U00011fff', Po), ('\U00012000', '\U00012399', Lo),
+        ('\U00012400', '\U0001246e', Nl), ('\U00012470', '\U00012474', Po),
+        ('\U00012480', '\U00012543', Lo), ('\U00012f90', '\U00012ff0', Lo),
+        ('\U00012ff1', '\U00012ff2', Po), ('\U00013000', '\U0001342e', Lo),
+        ('\U00013430', '\U00013438', Cf), ('\U00014400', '\U00014646', Lo),
+        ('\U00016800', '\U00016a38', Lo), ('\U00016a40', '\U00016a5e', Lo),
+        ('\U00016a60', '\U00016a69', Nd), ('\U00016a6e', '\U00016a6f', Po),
+        ('\U00016a70', '\U00016abe', Lo), ('\U00016ac0', '\U00016ac9', Nd),
+        ('\U00016ad0', '\U00016aed', Lo), ('\U00016af0', '\U00016af4', Mn),
+        ('\U00016af5', '\U00016af5', Po), ('\U00016b00', '\U00016b2f', Lo),
+        ('\U00016b30', '\U00016b36', Mn), ('\U00016b37', '\U00016b3b', Po),
+        ('\U00016b3c', '\U00016b3f', So), ('\U00016b40', '\U00016b43', Lm),
+        ('\U00016b44', '\U00016b44', Po), ('\U00016b45', '\U00016b45', So),
+        ('\U00016b50', '\U00016b59', Nd), ('\U00016b5b', '\U00016b61', No),
+        ('\U00016b63', '\U00016b77', Lo), ('\U00016b7d', '\U00016b8f', Lo),
+        ('\U00016e40', '\U00016e5f', Lu), ('\U00016e60', '\U00016e7f', Ll),
+        ('\U00016e80', '\U00016e96', No), ('\U00016e97', '\U00016e9a', Po),
+        ('\U00016f00', '\U00016f4a', Lo), ('\U00016f4f', '\U00016f4f', Mn),
+        ('\U00016f50', '\U00016f50', Lo), ('\U00016f51', '\U00016f87', Mc),
+        ('\U00016f8f', '\U00016f92', Mn), ('\U00016f93', '\U00016f9f', Lm),
+        ('\U00016fe0', '\U00016fe1', Lm), ('\U00016fe2', '\U00016fe2', Po),
+        ('\U00016fe3', '\U00016fe3', Lm), ('\U00016fe4', '\U00016fe4', Mn),
+        ('\U00016ff0', '\U00016ff1', Mc), ('\U00017000', '\U000187f7', Lo),
+        ('\U00018800', '\U00018cd5', Lo), ('\U00018d00', '\U00018d08', Lo),
+        ('\U0001aff0', '\U0001aff3', Lm), ('\U0001aff5', '\U0001affb', Lm),
+        ('\U0001affd', '\U0001affe', Lm), ('\U0001b000', '\U0001b122', Lo),
+        ('\U0001b150', '\U0001b152', Lo), ('\U0001b164', '\U0001b167', Lo),
+        ('\U0001b170', '\U0001b2fb', Lo), ('\U0001bc00', '\U0001bc6a', Lo),
+        ('\U0001bc70', '\U0001bc7c', Lo), ('\U0001bc80', '\U0001bc88', Lo),
+        ('\U0001bc90', '\U0001bc99', Lo), ('\U0001bc9c', '\U0001bc9c', So),
+        ('\U0001bc9d', '\U0001bc9e', Mn), ('\U0001bc9f', '\U0001bc9f', Po),
+        ('\U0001bca0', '\U0001bca3', Cf), ('\U0001cf00', '\U0001cf2d', Mn),
+        ('\U0001cf30', '\U0001cf46', Mn), ('\U0001cf50', '\U0001cfc3', So),
+        ('\U0001d000', '\U0001d0f5', So), ('\U0001d100', '\U0001d126', So),
+        ('\U0001d129', '\U0001d164', So), ('\U0001d165', '\U0001d166', Mc),
+        ('\U0001d167', '\U0001d169', Mn), ('\U0001d16a', '\U0001d16c', So),
+        ('\U0001d16d', '\U0001d172', Mc), ('\U0001d173', '\U0001d17a', Cf),
+        ('\U0001d17b', '\U0001d182', Mn), ('\U0001d183', '\U0001d184', So),
+        ('\U0001d185', '\U0001d18b', Mn), ('\U0001d18c', '\U0001d1a9', So),
+        ('\U0001d1aa', '\U0001d1ad', Mn), ('\U0001d1ae', '\U0001d1ea', So),
+        ('\U0001d200', '\U0001d241', So), ('\U0001d242', '\U0001d244', Mn),
+        ('\U0001d245', '\U0001d245', So), ('\U0001d2e0', '\U0001d2f3', No),
+        ('\U0001d300', '\U0001d356', So), ('\U0001d360', '\U0001d378', No),
+        ('\U0001d400', '\U0001d419', Lu), ('\U0001d41a', '\U0001d433', Ll),
+        ('\U0001d434', '\U0001d44d', Lu), ('\U0001d44e', '\U0001d454', Ll),
+        ('\U0001d456', '\U0001d467', Ll), ('\U0001d468', '\U0001d481', Lu),
+        ('\U0001d482', '\U0001d49b', Ll), ('\U0001d49c', '\U0001d49c', Lu),
+        ('\U0001d49e', '\U0001d49f', Lu), ('\U0001d4a2', '\U0001d4a2', Lu),
+        ('\U0001d4a5', '\U0001d4a6', Lu), ('\U0001d4a9', '\U0001d4ac', Lu),
+        ('\U0001d4ae', '\U0001d4b5', Lu), ('\U0001d4b6', '\U0001d4b9', Ll),
+        ('\U0001d4bb', '\U0001d4bb', Ll), ('\U0001d4bd', '\U0001d4c3', Ll),
+        ('\U0001d4c5', '\U0001d4cf', Ll), ('\U0001d4d0', '\U0001d4e9', Lu),
+        ('\U0001d4ea', '\U0001d503', Ll), ('\U0001d504', '\U0001d505', Lu),
+        ('\U0001d507', '\U0001d50a', Lu), ('\U0001d50d', '\U0001d514', Lu),
+        ('\U0001d516', '\U0001d51c', Lu), ('\U0001d51e', '\U0001d537', Ll),
+        ('\U0001d538', '\U0001d539', Lu), ('\U0001d53b', '\U0001d53e', Lu),
+        ('\U0001d540', '\U0001d544', Lu), ('\U0001d546', '\U0001d546', Lu),
+        ('\U0001d54a', '\U0001d550', Lu), ('\U0001d552', '\U0001d56b', Ll),
+        ('\U0001d56c', '\U0001d585', Lu), ('\U0001d586', '\U0001d59f', Ll),
+        ('\U0001d5a0', '\U0001d5b9', Lu), ('\U0001d5ba', '\U0001d5d3', Ll),
+        ('\U0001d5d4', '\U0001d5ed', Lu), ('\U0001d5ee', '\U0001d607', Ll),
+        ('\U0001d608', '\U0001d621', Lu), ('\U0001d622', '\U0001d63b', Ll),
+        ('\U0001d63c', '\U0001d655', Lu), ('\U0001d656', '\U0001d66f', Ll),
+        ('\U0001d670', '\U0001d689', Lu), ('\U0001d68a', '\U0001d6a5', Ll),
+        ('\U0001d6a8', '\U0001d6c0', Lu), ('\U0001d6c1', '\U0001d6c1', Sm),
+        ('\U0001d6c2', '\U0001d6da', Ll), ('\U0001d6db', '\U0001d6db', Sm),
+        ('\U0001d6dc', '\U0001d6e1', Ll), ('\U0001d6e2', '\U0001d6fa', Lu),
+        ('\U0001d6fb', '\U0001d6fb', Sm), ('\U0001d6fc', '\U0001d714', Ll),
+        ('\U0001d715', '\U0001d715', Sm), ('\U0001d716', '\U0001d71b', Ll),
+        ('\U0001d71c', '\U0001d734', Lu), ('\U0001d735', '\U0001d735', Sm),
+        ('\U0001d736', '\U0001d74e', Ll), ('\U0001d74f', '\U0001d74f', Sm),
+        ('\U0001d750', '\U0001d755', Ll), ('\U0001d756', '\U0001d76e', Lu),
+        ('\U0001d76f', '\U0001d76f', Sm), ('\U0001d770', '\U0001d788', Ll),
+        ('\U0001d789', '\U0001d789', Sm), ('\U0001d78a', '\U0001d78f', Ll),
+        ('\U0001d790', '\U0001d7a8', Lu), ('\U0001d7a9', '\U0001d7a9', Sm),
+        ('\U0001d7aa', '\U0001d7c2', Ll), ('\U0001d7c3', '\U0001d7c3', Sm),
+        ('\U0001d7c4', '\U0001d7c9', Ll), ('\U0001d7ca', '\U0001d7ca', Lu),
+        ('\U0001d7cb', '\U0001d7cb', Ll), ('\U0001d7ce', '\U0001d7ff', Nd),
+        ('\U0001d800', '\U0001d9ff', So), ('\U0001da00', '\U0001da36', Mn),
+        ('\U0001da37', '\U0001da3a', So), ('\U0001da3b', '\U0001da6c', Mn),
+        ('\U0001da6d', '\U0001da74', So), ('\U0001da75', '\U0001da75', Mn),
+        ('\U0001da76', '\U0001da83', So), ('\U0001da84', '\U0001da84', Mn),
+        ('\U0001da85', '\U0001da86', So), ('\U0001da87', '\U0001da8b', Po),
+        ('\U0001da9b', '\U0001da9f', Mn), ('\U0001daa1', '\U0001daaf', Mn),
+        ('\U0001df00', '\U0001df09', Ll), ('\U0001df0a', '\U0001df0a', Lo),
+        ('\U0001df0b', '\U0001df1e', Ll), ('\U0001e000', '\U0001e006', Mn),
+        ('\U0001e008', '\U0001e018', Mn), ('\U0001e01b', '\U0001e021', Mn),
+        ('\U0001e023', '\U0001e024', Mn), ('\U0001e026', '\U0001e02a', Mn),
+        ('\U0001e100', '\U0001e12c', Lo), ('\U0001e130', '\U0001e136', Mn),
+        ('\U0001e137', '\U0001e13d', Lm), ('\U0001e140', '\U0001e149', Nd),
+        ('\U0001e14e', '\U0001e14e', Lo), ('\U0001e14f', '\U0001e14f', So),
+        ('\U0001e290', '\U0001e2ad', Lo), ('\U0001e2ae', '\U0001e2ae', Mn),
+        ('\U0001e2c0', '\U0001e2eb', Lo), ('\U0001e2ec', '\U0001e2ef', Mn),
+        ('\U0001e2f0', '\U0001e2f9', Nd), ('\U0001e2ff', '\U0001e2ff', Sc),
+        ('\U0001e7e0', '\U0001e7e6', Lo), ('\U0001e7e8', '\U0001e7eb', Lo),
+        ('\U0001e7ed', '\U0001e7ee', Lo), ('\U0001e7f0', '\U0001e7fe', Lo),
+        ('\U0001e800', '\U0001e8c4', Lo), ('\U0001e8c7', '\U0001e8cf', No),
+        ('\U0001e8d0', '\U0001e8d6', Mn), ('\U0001e900', '\U0001e921', Lu),
+        ('\U0001e922', '\U0001e943', Ll), ('\U0001e944', '\U0001e94a', Mn),
+        ('\U0001e94b', '\U0001e94b', Lm), ('\U0001e950', '\U0001e959', Nd),
+        ('\U0001e95e', '\U0001e95f', Po), ('\U0001ec71', '\U0001ecab', No),
+        ('\U0001ecac', '\U0001ecac', So), ('\U0001ecad', '\U0001ecaf', No),
+        ('\U0001ecb0', '\U0001ecb0', Sc), ('\U0001ecb1', '\U0001ecb4', No),
+        ('\U0001ed01', '\U0001ed2d', No), ('\U0001ed2e', '\U0001ed2e', So),
+        ('\U0001ed2f', '\U0001ed3d', No), ('\U0001ee00', '\U0001ee03', Lo),
+        ('\U0001ee05', '\U0001ee1f', Lo), ('\U0001ee21', '\U0001ee22', Lo),
+        ('\U0001ee24', '\U0001ee24', Lo), ('\U0001ee27', '\U0001ee27', Lo),
+        ('\U0001ee29', '\U0001ee32', Lo), ('\U0001ee34', '\U0001ee37', Lo),
+        ('\U0001ee39', '\U0001ee39', Lo), ('\U0001ee3b', '\U0001ee3b', Lo),
+        ('\U0001ee42', '\U0001ee42', Lo), ('\U0001ee47', '\U0001ee47', Lo),
+        ('\U0001ee49', '\U0001ee49', Lo), ('\U0001ee4b', '\U0001ee4b', Lo),
+        ('\U0001ee4d', '\U0001ee4f', Lo), ('\U0001ee51', '\U0001ee52', Lo),
+        ('\U0001ee54', '\U0001ee54', Lo), ('\U0001ee57', '\U0001ee57', Lo),
+        ('\U0001ee59', '\U0001ee59', Lo), ('\U0001ee5b', '\U0001ee5b', Lo),
+        ('\U0001ee5d', '\U0001ee5d', Lo), ('\U0001ee5f', '\U0001ee5f', Lo),
+        ('\U0001ee61', '\U0001ee62', Lo), ('\U0001ee64', '\U0001ee64', Lo),
+        ('\U0001ee67', '\U0001ee6a', Lo), ('\U0001ee6c', '\U0001ee72', Lo),
+        ('\U0001ee74', '\U0001ee77', Lo), ('\U0001ee79', '\U0001ee7c', Lo),
+        ('\U0001ee7e', '\U0001ee7e', Lo), ('\U0001ee80', '\U0001ee89', Lo),
+        ('\U0001ee8b', '\U0001ee9b', Lo), ('\U0001eea1', '\U0001eea3', Lo),
+        ('\U0001eea5', '\U0001eea9', Lo), ('\U0001eeab', '\U0001eebb', Lo),
+        ('\U0001eef0', '\U0001eef1', Sm), ('\U0001f000', '\U0001f02b', So),
+        ('\U0001f030', '\U0001f093', So), ('\U0001f0a0', '\U0001f0ae', So),
+        ('\U0001f0b1', '\U0001f0bf', So), ('\U0001f0c1', '\U0001f0cf', So),
+        ('\U0001f0d1', '\U0001f0f5', So), ('\U0001f100', '\U0001f10c', No),
+        ('\U0001f10d', '\U0001f1ad', So), ('\U0001f1e6', '\U0001f202', So),
+        ('\U0001f210', '\U0001f23b', So), ('\U0001f240', '\U0001f248', So),
+        ('\U0001f250', '\U0001f251', So), ('\U0001f260', '\U0001f265', So),
+        ('\U0001f300', '\U0001f3fa', So), ('\U0001f3fb', '\U0001f3ff', Sk),
+        ('\U0001f400', '\U0001f6d7', So), ('\U0001f6dd', '\U0001f6ec', So),
+        ('\U0001f6f0', '\U0001f6fc', So), ('\U0001f700', '\U0001f773', So),
+        ('\U0001f780', '\U0001f7d8', So), ('\U0001f7e0', '\U0001f7eb', So),
+        ('\U0001f7f0', '\U0001f7f0', So), ('\U0001f800', '\U0001f80b', So),
+        ('\U0001f810', '\U0001f847', So), ('\U0001f850', '\U0001f859', So),
+        ('\U0001f860', '\U0001f887', So), ('\U0001f890', '\U0001f8ad', So),
+        ('\U0001f8b0', '\U0001f8b1', So), ('\U0001f900', '\U0001fa53', So),
+        ('\U0001fa60', '\U0001fa6d', So), ('\U0001fa70', '\U0001fa74', So),
+        ('\U0001fa78', '\U0001fa7c', So), ('\U0001fa80', '\U0001fa86', So),
+        ('\U0001fa90', '\U0001faac', So), ('\U0001fab0', '\U0001faba', So),
+        ('\U0001fac0', '\U0001fac5', So), ('\U0001fad0', '\U0001fad9', So),
+        ('\U0001fae0', '\U0001fae7', So), ('\U0001faf0', '\U0001faf6', So),
+        ('\U0001fb00', '\U0001fb92', So), ('\U0001fb94', '\U0001fbca', So),
+        ('\U0001fbf0', '\U0001fbf9', Nd), ('\U00020000', '\U0002a6df', Lo),
+        ('\U0002a700', '\U0002b738', Lo), ('\U0002b740', '\U0002b81d', Lo),
+        ('\U0002b820', '\U0002cea1', Lo), ('\U0002ceb0', '\U0002ebe0', Lo),
+        ('\U0002f800', '\U0002fa1d', Lo), ('\U00030000', '\U0003134a', Lo),
+        ('\U000e0001', '\U000e0001', Cf), ('\U000e0020', '\U000e007f', Cf),
+        ('\U000e0100', '\U000e01ef', Mn), ('\U000f0000', '\U000ffffd', Co),
+        ('\U00100000', '\U0010fffd', Co)
+    ];
+
+    /// Look up the `General_Category` of a character, or `None` if it is unassigned.
+    pub fn lookup(c: char) -> Option<GeneralCategory> {
+        super::bsearch_value_table(c, GENERAL_CATEGORY_TABLE)
+    }
+
+    static NUMBER_TABLE: &'static [(char, char)] = &[
+        ('\x30', '\x39'), ('\u00b2', '\u00b3'), ('\u00b9', '\u00b9'), ('\u00bc', '\u00be'),
+        ('\u0660', '\u0669'), ('\u06f0', '\u06f9'), ('\u07c0', '\u07c9'), ('\u0966', '\u096f'),
+        ('\u09e6', '\u09ef'), ('\u09f4', '\u09f9'), ('\u0a66', '\u0a6f'), ('\u0ae6', '\u0aef'),
+        ('\u0b66', '\u0b6f'), ('\u0b72', '\u0b77'), ('\u0be6', '\u0bf2'), ('\u0c66', '\u0c6f'),
+        ('\u0c78', '\u0c7e'), ('\u0ce6', '\u0cef'), ('\u0d58', '\u0d5e'), ('\u0d66', '\u0d78'),
+        ('\u0de6', '\u0def'), ('\u0e50', '\u0e59'), ('\u0ed0', '\u0ed9'), ('\u0f20', '\u0f33'),
+        ('\u1040', '\u1049'), ('\u1090', '\u1099'), ('\u1369', '\u137c'), ('\u16ee', '\u16f0'),
+        ('\u17e0', '\u17e9'), ('\u17f0', '\u17f9'), ('\u1810', '\u1819'), ('\u1946', '\u194f'),
+        ('\u19d0', '\u19da'), ('\u1a80', '\u1a89'), ('\u1a90', '\u1a99'), ('\u1b50', '\u1b59'),
+        ('\u1bb0', '\u1bb9'), ('\u1c40', '\u1c49'), ('\u1c50', '\u1c59'), ('\u2070', '\u2070'),
+        ('\u2074', '\u2079'), ('\u2080', '\u2089'), ('\u2150', '\u2182'), ('\u2185', '\u2189'),
+        ('\u2460', '\u249b'), ('\u24ea', '\u24ff'), ('\u2776', '\u2793'), ('\u2cfd', '\u2cfd'),
+        ('\u3007', '\u3007'), ('\u3021', '\u3029'), ('\u3038', '\u303a'), ('\u3192', '\u3195'),
+        ('\u3220', '\u3229'), ('\u3248', '\u324f'), ('\u3251', '\u325f'), ('\u3280', '\u3289'),
+        ('\u32b1', '\u32bf'), ('\ua620', '\ua629'), ('\ua6e6', '\ua6ef'), ('\ua830', '\ua835'),
+        ('\ua8d0', '\ua8d9'), ('\ua900', '\ua909'), ('\ua9d0', '\ua9d9'), ('\ua9f0', '\ua9f9'),
+        ('\uaa50', '\uaa59'), ('\uabf0', '\uabf9'), ('\uff10', '\uff19'),
+        ('\U00010107', '\U00010133'), ('\U00010140', '\U00010178'), ('\U0001018a', '\U0001018b'),
+        ('\U000102e1', '\U000102fb'), ('\U00010320', '\U00010323'), ('\U00010341', '\U00010341'),
+        ('\U0001034a', '\U0001034a'), ('\U000103d1', '\U000103d5'), ('\U000104a0', '\U000104a9'),
+        ('\U00010858', '\U0001085f'), ('\U00010879', '\U0001087f'), ('\U000108a7', '\U000108af'),
+        ('\U000108fb', '\U000108ff'), ('\U00010916', '\U0001091b'), ('\U000109bc', '\U000109bd'),
+        ('\U000109c0', '\U000109cf'), ('\U000109d2', '\U000109ff'), ('\U00010a40', '\U00010a48'),
+        ('\U00010a7d', '\U00010a7e'), ('\U00010a9d', '\U00010a9f'), ('\U00010aeb', '\U00010aef'),
+        ('\U00010b58', '\U00010b5f'), ('\U00010b78', '\U00010b7f'), ('\U00010ba9', '\U00010baf'),
+        ('\U00010cfa', '\U00010cff'), ('\U00010d30', '\U00010d39'), ('\U00010e60', '\U00010e7e'),
+        ('\U00010f1d', '\U00010f26'), ('\U00010f51', '\U00010f54'), ('\U00010fc5', '\U00010fcb'),
+        ('\U00011052', '\U0001106f'), ('\U000110f0', '\U000110f9'), ('\U00011136', '\U0001113f'),
+        ('\U000111d0', '\U000111d9'), ('\U000111e1', '\U000111f4'), ('\U000112f0', '\U000112f9'),
+        ('\U00011450', '\U00011459'), ('\U000114d0', '\U000114d9'), ('\U00011650', '\U00011659'),
+        ('\U000116c0', '\U000116c9'), ('\U00011730', '\U0001173b'), ('\U000118e0', '\U000118f2'),
+        ('\U00011950', '\U00011959'), ('\U00011c50', '\U00011c6c'), ('\U00011d50', '\U00011d59'),
+        ('\U00011da0', '\U00011da9'), ('\U00011fc0', '\U00011fd4'), ('\U00012400', '\U0001246e'),
+        ('\U00016a60', '\U00016a69'), ('\U00016ac0', '\U00016ac9'), ('\U00016b50', '\U00016b59'),
+        ('\U00016b5b', '\U00016b61'), ('\U00016e80', '\U00016e96'), ('\U0001d2e0', '\U0001d2f3'),
+        ('\U0001d360', '\U0001d378'), ('\U0001d7ce', '\U0001d7ff'), ('\U0001e140', '\U0001e149'),
+        ('\U0001e2f0', '\U0001e2f9'), ('\U0001e8c7', '\U0001e8cf'), ('\U0001e950', '\U0001e959'),
+        ('\U0001ec71', '\U0001ecab'), ('\U0001ecad', '\U0001ecaf'), ('\U0001ecb1', '\U0001ecb4'),
+        ('\U0001ed01', '\U0001ed2d'), ('\U0001ed2f', '\U0001ed3d'), ('\U0001f100', '\U0001f10c'),
+        ('\U0001fbf0', '\U0001fbf9')
+    ];
+
+    /// Return true if the character is in one of the `Nd`, `Nl` or `No` categories.
+    pub fn number(c: char) -> bool {
+        super::bsearch_range_table(c, NUMBER_TABLE)
+    }
+}
+
+pub mod derived_property {
+    static ALPHABETIC_TABLE: &'static [(char, char)] = &[
+        ('\x41', '\x5a'), ('\x61', '\x7a'), ('\u00aa', '\u00aa'), ('\u00b5', '\u00b5'),
+        ('\u00ba', '\u00ba'), ('\u00c0', '\u00d6'), ('\u00d8', '\u00f6'), ('\u00f8', '\u02c1'),
+        ('\u02c6', '\u02d1'), ('\u02e0', '\u02e4'), ('\u02ec', '\u02ec'), ('\u02ee', '\u02ee'),
+        ('\u0345', '\u0345'), ('\u0370', '\u0374'), ('\u0376', '\u0377'), ('\u037a', '\u037d'),
+        ('\u037f', '\u037f'), ('\u0386', '\u0386'), ('\u0388', '\u038a'), ('\u038c', '\u038c'),
+        ('\u038e', '\u03a1'), ('\u03a3', '\u03f5'), ('\u03f7', '\u0481'), ('\u048a', '\u052f'),
+        ('\u0531', '\u0556'), ('\u0559', '\u0559'), ('\u0560', '\u0588'), ('\u05b0', '\u05bd'),
+        ('\u05bf', '\u05bf'), ('\u05c1', '\u05c2'), ('\u05c4', '\u05c5'), ('\u05c7', '\u05c7'),
+        ('\u05d0', '\u05ea'), ('\u05ef', '\u05f2'), ('\u0610', '\u061a'), ('\u0620', '\u0657'),
+        ('\u0659', '\u065f'), ('\u066e', '\u06d3'), ('\u06d5', '\u06dc'), ('\u06e1', '\u06e8'),
+        ('\u06ed', '\u06ef'), ('\u06fa', '\u06fc'), ('\u06ff', '\u06ff'), ('\u0710', '\u073f'),
+        ('\u074d', '\u07b1'), ('\u07ca', '\u07ea'), ('\u07f4', '\u07f5'), ('\u07fa', '\u07fa'),
+        ('\u0800', '\u0817'), ('\u081a', '\u082c'), ('\u0840', '\u0858'), ('\u0860', '\u086a'),
+        ('\u0870', '\u0887'), ('\u0889', '\u088e'), ('\u08a0', '\u08c9'), ('\u08d4', '\u08df'),
+        ('\u08e3', '\u08e9'), ('\u08f0', '\u093b'), ('\u093d', '\u094c'), ('\u094e', '\u0950'),
+        ('\u0955', '\u0963'), ('\u0971', '\u0983'), ('\u0985', '\u098c'), ('\u098f', '\u0990'),
+        ('\u0993', '\u09a8'), ('\u09aa', '\u09b0'), ('\u09b2', '\u09b2'), ('\u09b6', '\u09b9'),
+        ('\u09bd', '\u09c4'), ('\u09c7', '\u09c8'), ('\u09cb', '\u09cc'), ('\u09ce', '\u09ce'),
+        ('\u09d7', '\u09d7'), ('\u09dc', '\u09dd'), ('\u09df', '\u09e3'), ('\u09f0', '\u09f1'),
+        ('\u09fc', '\u09fc'), ('\u0a01', '\u0a03'), ('\u0a05', '\u0a0a'), ('\u0a0f', '\u0a10'),
+        ('\u0a13', '\u0a28'), ('\u0a2a', '\u0a30'), ('\u0a32', '\u0a33'), ('\u0a35', '\u0a36'),
+        ('\u0a38', '\u0a39'), ('\u0a3e', '\u0a42'), ('\u0a47', '\u0a48'), ('\u0a4b', '\u0a4c'),
+        ('\u0a51', '\u0a51'), ('\u0a59', '\u0a5c'), ('\u0a5e', '\u0a5e'), ('\u0a70', '\u0a75'),
+        ('\u0a81', '\u0a83'), ('\u0a85', '\u0a8d'), ('\u0a8f', '\u0a91'), ('\u0a93', '\u0aa8'),
+        ('\u0aaa', '\u0ab0'), ('\u0ab2', '\u0ab3'), ('\u0ab5', '\u0ab9'), ('\u0abd', '\u0ac5'),
+        ('\u0ac7', '\u0ac9'), ('\u0acb', '\u0acc'), ('\u0ad0', '\u0ad0'), ('\u0ae0', '\u0ae3'),
+        ('\u0af9', '\u0afc'), ('\u0b01', '\u0b03'), ('\u0b05', '\u0b0c'), ('\u0b0f', '\u0b10'),
+        ('\u0b13', '\u0b28'), ('\u0b2a', '\u0b30'), ('\u0b32', '\u0b33'), ('\u0b35', '\u0b39'),
+        ('\u0b3d', '\u0b44'), ('\u0b47', '\u0b48'), ('\u0b4b', '\u0b4c'), ('\u0b56', '\u0b57'),
+        ('\u0b5c', '\u0b5d'), ('\u0b5f', '\u0b63'), ('\u0b71', '\u0b71'), ('\u0b82', '\u0b83'),
+        ('\u0b85', '\u0b8a'), ('\u0b8e', '\u0b90'), ('\u0b92', '\u0b95'), ('\u0b99', '\u0b9a'),
+        ('\u0b9c', '\u0b9c'), ('\u0b9e', '\u0b9f'), ('\u0ba3', '\u0ba4'), ('\u0ba8', '\u0baa'),
+        ('\u0bae', '\u0bb9'), ('\u0bbe', '\u0bc2'), ('\u0bc6', '\u0bc8'), ('\u0bca', '\u0bcc'),
+        ('\u0bd0', '\u0bd0'), ('\u0bd7', '\u0bd7'), ('\u0c00', '\u0c03'), ('\u0c05', '\u0c0c'),
+        ('\u0c0e', '\u0c10'), ('\u0c12', '\u0c28'), ('\u0c2a', '\u0c39'), ('\u0c3d', '\u0c44'),
+        ('\u0c46', '\u0c48'), ('\u0c4a', '\u0c4c'), ('\u0c55', '\u0c56'), ('\u0c58', '\u0c5a'),
+        ('\u0c5d', '\u0c5d'), ('\u0c60', '\u0c63'), ('\u0c80', '\u0c83'), ('\u0c85', '\u0c8c'),
+        ('\u0c8e', '\u0c90'), ('\u0c92', '\u0ca8'), ('\u0caa', '\u0cb3'), ('\u0cb5', '\u0cb9'),
+        ('\u0cbd', '\u0cc4'), ('\u0cc6', '\u0cc8'), ('\u0cca', '\u0ccc'), ('\u0cd5', '\u0cd6'),
+        ('\u0cdd', '\u0cde'), ('\u0ce0', '\u0ce3'), ('\u0cf1', '\u0cf2'), ('\u0d00', '\u0d0c'),
+        ('\u0d0e', '\u0d10'), ('\u0d12', '\u0d3a'), ('\u0d3d', '\u0d44'), ('\u0d46', '\u0d48'),
+        ('\u0d4a', '\u0d4c'), ('\u0d4e', '\u0d4e'), ('\u0d54', '\u0d57'), ('\u0d5f', '\u0d63'),
+        ('\u0d7a', '\u0d7f'), ('\u0d81', '\u0d83'), ('\u0d85', '\u0d96'), ('\u0d9a', '\u0db1'),
+        ('\u0db3', '\u0dbb'), ('\u0dbd', '\u0dbd'), ('\u0dc0', '\u0dc6'), ('\u0dcf', '\u0dd4'),
+        ('\u0dd6', '\u0dd6'), ('\u0dd8', '\u0ddf'), ('\u0df2', '\u0df3'), ('\u0e01', '\u0e3a'),
+        ('\u0e40', '\u0e46'), ('\u0e4d', '\u0e4d'), ('\u0e81', '\u0e82'), ('\u0e84', '\u0e84'),
+        ('\u0e86', '\u0e8a'), ('\u0e8c', '\u0ea3'), ('\u0ea5', '\u0ea5'), ('\u0ea7', '\u0eb9'),
+        ('\u0ebb', '\u0ebd'), ('\u0ec0', '\u0ec4'), ('\u0ec6', '\u0ec6'), ('\u0ecd', '\u0ecd'),
+        ('\u0edc', '\u0edf'), ('\u0f00', '\u0f00'), ('\u0f40', '\u0f47'), ('\u0f49', '\u0f6c'),
+        ('\u0f71', '\u0f81'), ('\u0f88', '\u0f97'), ('\u0f99', '\u0fbc'), ('\u1000', '\u1036'),
+        ('\u1038', '\u1038'), ('\u103b', '\u103f'), ('\u1050', '\u108f'), ('\u109a', '\u109d'),
+        ('\u10a0', '\u10c5'), ('\u10c7', '\u10c7'), ('\u10cd', '\u10cd'), ('\u10d0', '\u10fa'),
+        ('\u10fc', '\u1248'), ('\u124a', '\u124d'), ('\u1250', '\u1256'), ('\u1258', '\u1258'),
+        ('\u125a', '\u125d'), ('\u1260', '\u1288'), ('\u128a', '\u128d'), ('\u1290', '\u12b0'),
+        ('\u12b2', '\u12b5'), ('\u12b8', '\u12be'), ('\u12c0', '\u12c0'), ('\u12c2', '\u12c5'),
+        ('\u12c8', '\u12d6'), ('\u12d8', '\u1310'), ('\u1312', '\u1315'), ('\u1318', '\u135a'),
+        ('\u1380', '\u138f'), ('\u13a0', '\u13f5'), ('\u13f8', '\u13fd'), ('\u1401', '\u166c'),
+        ('\u166f', '\u167f'), ('\u1681', '\u169a'), ('\u16a0', '\u16ea'), ('\u16ee', '\u16f8'),
+        ('\u1700', '\u1713'), ('\u171f', '\u1733'), ('\u1740', '\u1753'), ('\u1760', '\u176c'),
+        ('\u176e', '\u1770'), ('\u1772', '\u1773'), ('\u1780', '\u17b3'), ('\u17b6', '\u17c8'),
+        ('\u17d7', '\u17d7'), ('\u17dc', '\u17dc'), ('\u1820', '\u1878'), ('\u1880', '\u18aa'),
+        ('\u18b0', '\u18f5'), ('\u1900', '\u191e'), ('\u1920', '\u192b'), ('\u1930', '\u1938'),
+        ('\u1950', '\u196d'), ('\u1970', '\u1974'), ('\u1980', '\u19ab'), ('\u19b0', '\u19c9'),
+        ('\u1a00', '\u1a1b'), ('\u1a20', '\u1a5e'), ('\u1a61', '\u1a74'), ('\u1aa7', '\u1aa7'),
+        ('\u1abf', '\u1ac0'), ('\u1acc', '\u1ace'), ('\u1b00', '\u1b33'), ('\u1b35', '\u1b43'),
+        ('\u1b45', '\u1b4c'), ('\u1b80', '\u1ba9'), ('\u1bac', '\u1baf'), ('\u1bba', '\u1be5'),
+        ('\u1be7', '\u1bf1'), ('\u1c00', '\u1c36'), ('\u1c4d', '\u1c4f'), ('\u1c5a', '\u1c7d'),
+        ('\u1c80', '\u1c88'), ('\u1c90', '\u1cba'), ('\u1cbd', '\u1cbf'), ('\u1ce9', '\u1cec'),
+        ('\u1cee', '\u1cf3'), ('\u1cf5', '\u1cf6'), ('\u1cfa', '\u1cfa'), ('\u1d00', '\u1dbf'),
+        ('\u1de7', '\u1df4'), ('\u1e00', '\u1f15'), ('\u1f18', '\u1f1d'), ('\u1f20', '\u1f45'),
+        ('\u1f48', '\u1f4d'), ('\u1f50', '\u1f57'), ('\u1f59', '\u1f59'), ('\u1f5b', '\u1f5b'),
+        ('\u1f5d', '\u1f5d'), ('\u1f5f', '\u1f7d'), ('\u1f80', '\u1fb4'), ('\u1fb6', '\u1fbc'),
+        ('\u1fbe', '\u1fbe'), ('\u1fc2', '\u1fc4'), ('\u1fc6', '\u1fcc'), ('\u1fd0', '\u1fd3'),
+        ('\u1fd6', '\u1fdb'), ('\u1fe0', '\u1fec'), ('\u1ff2', '\u1ff4'), ('\u1ff6', '\u1ffc'),
+        ('\u2071', '\u2071'), ('\u207f', '\u207f'), ('\u2090', '\u209c'), ('\u2102', '\u2102'),
+        ('\u2107', '\u2107'), ('\u210a', '\u2113'), ('\u2115', '\u2115'), ('\u2119', '\u211d'),
+        ('\u2124', '\u2124'), ('\u2126', '\u2126'), ('\u2128', '\u2128'), ('\u212a', '\u212d'),
+        ('\u212f', '\u2139'), ('\u213c', '\u213f'), ('\u2145', '\u2149'), ('\u214e', '\u214e'),
+        ('\u2160', '\u2188'), ('\u24b6', '\u24e9'), ('\u2c00', '\u2ce4'), ('\u2ceb', '\u2cee'),
+        ('\u2cf2', '\u2cf3'), ('\u2d00', '\u2d25'), ('\u2d27', '\u2d27'), ('\u2d2d', '\u2d2d'),
+        ('\u2d30', '\u2d67'), ('\u2d6f', '\u2d6f'), ('\u2d80', '\u2d96'), ('\u2da0', '\u2da6'),
+        ('\u2da8', '\u2dae'), ('\u2db0', '\u2db6'), ('\u2db8', '\u2dbe'), ('\u2dc0', '\u2dc6'),
+        ('\u2dc8', '\u2dce'), ('\u2dd0', '\u2dd6'), ('\u2dd8', '\u2dde'), ('\u2de0', '\u2dff'),
+        ('\u2e2f', '\u2e2f'), ('\u3005', '\u3007'), ('\u3021', '\u3029'), ('\u3031', '\u3035'),
+        ('\u3038', '\u303c'), ('\u3041', '\u3096'), ('\u309d', '\u309f'), ('\u30a1', '\u30fa'),
+        ('\u30fc', '\u30ff'), ('\u3105', '\u312f'), ('\u3131', '\u318e'), ('\u31a0', '\u31bf'),
+        ('\u31f0', '\u31ff'), ('\u3400', '\u4dbf'), ('\u4e00', '\ua48c'), ('\ua4d0', '\ua4fd'),
+        ('\ua500', '\ua60c'), ('\ua610', '\ua61f'), ('\ua62a', '\ua62b'), ('\ua640', '\ua66e'),
+        ('\ua674', '\ua67b'), ('\ua67f', '\ua6ef'), ('\ua717', '\ua71f'), ('\ua722', '\ua788'),
+        ('\ua78b', '\ua7ca'), ('\ua7d0', '\ua7d1'), ('\ua7d3', '\ua7d3'), ('\ua7d5', '\ua7d9'),
+        ('\ua7f2', '\ua805'), ('\ua807', '\ua827'), ('\ua840', '\ua873'), ('\ua880', '\ua8c3'),
+        ('\ua8c5', '\ua8c5'), ('\ua8f2', '\ua8f7'), ('\ua8fb', '\ua8fb'), ('\ua8fd', '\ua8ff'),
+        ('\ua90a', '\ua92a'), ('\ua930', '\ua952'), ('\ua960', '\ua97c'), ('\ua980', '\ua9b2'),
+        ('\ua9b4', '\ua9bf'), ('\ua9cf', '\ua9cf'), ('\ua9e0', '\ua9ef'), ('\ua9fa', '\ua9fe'),
+        ('\uaa00', '\uaa36'), ('\uaa40', '\uaa4d'), ('\uaa60', '\uaa76'), ('\uaa7a', '\uaabe'),
+        ('\uaac0', '\uaac0'), ('\uaac2', '\uaac2'), ('\uaadb', '\uaadd'), ('\uaae0', '\uaaef'),
+        ('\uaaf2', '\uaaf5'), ('\uab01', '\uab06'), ('\uab09', '\uab0e'), ('\uab11', '\uab16'),
+        ('\uab20', '\uab26'), ('\uab28', '\uab2e'), ('\uab30', '\uab5a'), ('\uab5c', '\uab69'),
+        ('\uab70', '\uabea'), ('\uac00', '\ud7a3'), ('\ud7b0', '\ud7c6'), ('\ud7cb', '\ud7fb'),
+        ('\uf900', '\ufa6d'), ('\ufa70', '\ufad9'), ('\ufb00', '\ufb06'), ('\ufb13', '\ufb17'),
+        ('\ufb1d', '\ufb28'), ('\ufb2a', '\ufb36'), ('\ufb38', '\ufb3c'), ('\ufb3e', '\ufb3e'),
+        ('\ufb40', '\ufb41'), ('\ufb43', '\ufb44'), ('\ufb46', '\ufbb1'), ('\ufbd3', '\ufd3d'),
+        ('\ufd50', '\ufd8f'), ('\ufd92', '\ufdc7'), ('\ufdf0', '\ufdfb'), ('\ufe70', '\ufe74'),
+        ('\ufe76', '\ufefc'), ('\uff21', '\uff3a'), ('\uff41', '\uff5a'), ('\uff66', '\uffbe'),
+        ('\uffc2', '\uffc7'), ('\uffca', '\uffcf'), ('\uffd2', '\uffd7'), ('\uffda', '\uffdc'),
+        ('\U00010000', '\U0001000b'), ('\U0001000d', '\U00010026'), ('\U00010028', '\U0001003a'),
+        ('\U0001003c', '\U0001003d'), ('\U0001003f', '\U0001004d'), ('\U00010050', '\U0001005d'),
+        ('\U00010080', '\U000100fa'), ('\U00010140', '\U00010174'), ('\U00010280', '\U0001029c'),
+        ('\U000102a0', '\U000102d0'), ('\U00010300', '\U0001031f'), ('\U0001032d', '\U0001034a'),
+        ('\U00010350', '\U0001037a'), ('\U00010380', '\U0001039d'), ('\U000103a0', '\U000103c3'),
+        ('\U000103c8', '\U000103cf'), ('\U000103d1', '\U000103d5'), ('\U00010400', '\U0001049d'),
+        ('\U000104b0', '\U000104d3'), ('\U000104d8', '\U000104fb'), ('\U00010500', '\U00010527'),
+        ('\U00010530', '\U00010563'), ('\U00010570', '\U0001057a'), ('\U0001057c', '\U0001058a'),
+        ('\U0001058c', '\U00010592'), ('\U00010594', '\U00010595'), ('\U00010597', '\U000105a1'),
+        ('\U000105a3', '\U000105b1'), ('\U000105b3', '\U000105b9'), ('\U000105bb', '\U000105bc'),
+        ('\U00010600', '\U00010736'), ('\U00010740', '\U00010755'), ('\U00010760', '\U00010767'),
+        ('\U00010780', '\U00010785'), ('\U00010787', '\U000107b0'), ('\U000107b2', '\U000107ba'),
+        ('\U00010800', '\U00010805'), ('\U00010808', '\U00010808'), ('\U0001080a', '\U00010835'),
+        ('\U00010837', '\U00010838'), ('\U0001083c', '\U0001083c'), ('\U0001083f', '\U00010855'),
+        ('\U00010860', '\U00010876'), ('\U00010880', '\U0001089e'), ('\U000108e0', '\U000108f2'),
+        ('\U000108f4', '\U000108f5'), ('\U00010900', '\U00010915'), ('\U00010920', '\U00010939'),
+        ('\U00010980', '\U000109b7'), ('\U000109be', '\U000109bf'), ('\U00010a00', '\U00010a03'),
+        ('\U00010a05', '\U00010a06'), ('\U00010a0c', '\U00010a13'), ('\U00010a15', '\U00010a17'),
+        ('\U00010a19', '\U00010a35'), ('\U00010a60', '\U00010a7c'), ('\U00010a80', '\U00010a9c'),
+        ('\U00010ac0', '\U00010ac7'), ('\U00010ac9', '\U00010ae4'), ('\U00010b00', '\U00010b35'),
+        ('\U00010b40', '\U00010b55'), ('\U00010b60', '\U00010b72'), ('\U00010b80', '\U00010b91'),
+        ('\U00010c00', '\U00010c48'), ('\U00010c80', '\U00010cb2'), ('\U00010cc0', '\U00010cf2'),
+        ('\U00010d00', '\U00010d27'), ('\U00010e80', '\U00010ea9'), ('\U00010eab', '\U00010eac'),
+        ('\U00010eb0', '\U00010eb1'), ('\U00010f00', '\U00010f1c'), ('\U00010f27', '\U00010f27'),
+        ('\U00010f30', '\U00010f45'), ('\U00010f70', '\U00010f81'), ('\U00010fb0', '\U00010fc4'),
+        ('\U00010fe0', '\U00010ff6'), ('\U00011000', '\U00011045'), ('\U00011071', '\U00011075'),
+        ('\U00011082', '\U000110b8'), ('\U000110c2', '\U000110c2'), ('\U000110d0', '\U000110e8'),
+        ('\U00011100', '\U00011132'), ('\U00011144', '\U00011147'), ('\U00011150', '\U00011172'),
+        ('\U00011176', '\U00011176'), ('\U00011180', '\U000111bf'), ('\U000111c1', '\U000111c4'),
+        ('\U000111ce', '\U000111cf'), ('\U000111da', '\U000111da'), ('\U000111dc', '\U000111dc'),
+        ('\U00011200', '\U00011211'), ('\U00011213', '\U00011234'), ('\U00011237', '\U00011237'),
+        ('\U0001123e', '\U0001123e'), ('\U00011280', '\U00011286'), ('\U00011288', '\U00011288'),
+        ('\U0001128a', '\U0001128d'), ('\U0001128f', '\U0001129d'), ('\U0001129f', '\U000112a8'),
+        ('\U000112b0', '\U000112e8'), ('\U00011300', '\U00011303'), ('\U00011305', '\U0001130c'),
+        ('\U0001130f', '\U00011310'), ('\U00011313', '\U00011328'), ('\U0001132a', '\U00011330'),
+        ('\U00011332', '\U00011333'), ('\U00011335', '\U00011339'), ('\U0001133d', '\U00011344'),
+        ('\U00011347', '\U00011348'), ('\U0001134b', '\U0001134c'), ('\U00011350', '\U00011350'),
+        ('\U00011357', '\U00011357'), ('\U0001135d', '\U00011363'), ('\U00011400', '\U00011441'),
+        ('\U00011443', '\U00011445'), ('\U00011447', '\U0001144a'), ('\U0001145f', '\U00011461'),
+        ('\U00011480', '\U000114c1'), ('\U000114c4', '\U000114c5'), ('\U000114c7', '\U000114c7'),
+        ('\U00011580', '\U000115b5'), ('\U000115b8', '\U000115be'), ('\U000115d8', '\U000115dd'),
+        ('\U00011600', '\U0001163e'), ('\U00011640', '\U00011640'), ('\U00011644', '\U00011644'),
+        ('\U00011680', '\U000116b5'), ('\U000116b8', '\U000116b8'), ('\U00011700', '\U0001171a'),
+        ('\U0001171d', '\U0001172a'), ('\U00011740', '\U00011746'), ('\U00011800', '\U00011838'),
+        ('\U000118a0', '\U000118df'), ('\U000118ff', '\U00011906'), ('\U00011909', '\U00011909'),
+        ('\U0001190c', '\U00011913'), ('\U00011915', '\U00011916'), ('\U00011918', '\U00011935'),
+        ('\U00011937', '\U00011938'), ('\U0001193b', '\U0001193c'), ('\U0001193f', '\U00011942'),
+        ('\U000119a0', '\U000119a7'), ('\U000119aa', '\U000119d7'), ('\U000119da', '\U000119df'),
+        ('\U000119e1', '\U000119e1'), ('\U000119e3', '\U000119e4'), ('\U00011a00', '\U00011a32'),
+        ('\U00011a35', '\U00011a3e'), ('\U00011a50', '\U00011a97'), ('\U00011a9d', '\U00011a9d'),
+        ('\U00011ab0', '\U00011af8'), ('\U00011c00', '\U00011c08'), ('\U00011c0a', '\U00011c36'),
+        ('\U00011c38', '\U00011c3e'), ('\U00011c40', '\U00011c40'), ('\U00011c72', '\U00011c8f'),
+        ('\U00011c92', '\U00011ca7'), ('\U00011ca9', '\U00011cb6'), ('\U00011d00', '\U00011d06'),
+        ('\U00011d08', '\U00011d09'), ('\U00011d0b', '\U00011d36'), ('\U00011d3a', '\U00011d3a'),
+        ('\U00011d3c', '\U00011d3d'), ('\U00011d3f', '\U00011d41'), ('\U00011d43', '\U00011d43'),
+        ('\U00011d46', '\U00011d47'), ('\U00011d60', '\U00011d65'), ('\U00011d67', '\U00011d68'),
+        ('\U00011d6a', '\U00011d8e'), ('\U00011d90', '\U00011d91'), ('\U00011d93', '\U00011d96'),
+        ('\U00011d98', '\U00011d98'), ('\U00011ee0', '\U00011ef6'), ('\U00011fb0', '\U00011fb0'),
+        ('\U00012000', '\U00012399'), ('\U00012400', '\U0001246e'), ('\U00012480', '\U00012543'),
+        ('\U00012f90', '\U00012ff0'), ('\U00013000', '\U0001342e'), ('\U00014400', '\U00014646'),
+        ('\U00016800', '\U00016a38'), ('\U00016a40', '\U00016a5e'), ('\U00016a70', '\U00016abe'),
+        ('\U00016ad0', '\U00016aed'), ('\U00016b00', '\U00016b2f'), ('\U00016b40', '\U00016b43'),
+        ('\U00016b63', '\U00016b77'), ('\U00016b7d', '\U00016b8f'), ('\U00016e40', '\U00016e7f'),
+        ('\U00016f00', '\U00016f4a'), ('\U00016f4f', '\U00016f87'), ('\U00016f8f', '\U00016f9f'),
+        ('\U00016fe0', '\U00016fe1'), ('\U00016fe3', '\U00016fe3'), ('\U00016ff0', '\U00016ff1'),
+        ('\U00017000', '\U000187f7'), ('\U00018800', '\U00018cd5'), ('\U00018d00', '\U00018d08'),
+        ('\U0001aff0', '\U0001aff3'), ('\U0001aff5', '\U0001affb'), ('\U0001affd', '\U0001affe'),
+        ('\U0001b000', '\U0001b122'), ('\U0001b150', '\U0001b152'), ('\U0001b164', '\U0001b167'),
+        ('\U0001b170', '\U0001b2fb'), ('\U0001bc00', '\U0001bc6a'), ('\U0001bc70', '\U0001bc7c'),
+        ('\U0001bc80', '\U0001bc88'), ('\U0001bc90', '\U0001bc99'), ('\U0001bc9e', '\U0001bc9e'),
+        ('\U0001d400', '\U0001d454'), ('\U0001d456', '\U0001d49c'), ('\U0001d49e', '\U0001d49f'),
+        ('\U0001d4a2', '\U0001d4a2'), ('\U0001d4a5', '\U0001d4a6'), ('\U0001d4a9', '\U0001d4ac'),
+        ('\U0001d4ae', '\U0001d4b9'), ('\U0001d4bb', '\U0001d4bb'), ('\U0001d4bd', '\U0001d4c3'),
+        ('\U0001d4c5', '\U0001d505'), ('\U0001d507', '\U0001d50a'), ('\U0001d50d', '\U0001d514'),
+        ('\U0001d516', '\U0001d51c'), ('\U0001d51e', '\U0001d539'), ('\U0001d53b', '\U0001d53e'),
+        ('\U0001d540', '\U0001d544'), ('\U0001d546', '\U0001d546'), ('\U0001d54a', '\U0001d550'),
+        ('\U0001d552', '\U0001d6a5'), ('\U0001d6a8', '\U0001d6c0'), ('\U0001d6c2', '\U0001d6da'),
+        ('\U0001d6dc', '\U0001d6fa'), ('\U0001d6fc', '\U0001d714'), ('\U0001d716', '\U0001d734'),
+        ('\U0001d736', '\U0001d74e'), ('\U0001d750', '\U0001d76e'), ('\U0001d770', '\U0001d788'),
+        ('\U0001d78a', '\U0001d7a8'), ('\U0001d7aa', '\U0001d7c2'), ('\U0001d7c4', '\U0001d7cb'),
+        ('\U0001df00', '\U0001df1e'), ('\U0001e000', '\U0001e006'), ('\U0001e008', '\U0001e018'),
+        ('\U0001e01b', '\U0001e021'), ('\U0001e023', '\U0001e024'), ('\U0001e026', '\U0001e02a'),
+        ('\U0001e100', '\U0001e12c'), ('\U0001e137', '\U0001e13d'), ('\U0001e14e', '\U0001e14e'),
+        ('\U0001e290', '\U0001e2ad'), ('\U0001e2c0', '\U0001e2eb'), ('\U0001e7e0', '\U0001e7e6'),
+        ('\U0001e7e8', '\U0001e7eb'), ('\U0001e7ed', '\U0001e7ee'), ('\U0001e7f0', '\U0001e7fe'),
+        ('\U0001e800', '\U0001e8c4'), ('\U0001e900', '\U0001e943'), ('\U0001e947', '\U0001e947'),
+        ('\U0001e94b', '\U0001e94b'), ('\U0001ee00', '\U0001ee03'), ('\U0001ee05', '\U0001ee1f'),
+        ('\U0001ee21', '\U0001ee22'), ('\U0001ee24', '\U0001ee24'), ('\U0001ee27', '\U0001ee27'),
+        ('\U0001ee29', '\U0001ee32'), ('\U0001ee34', '\U0001ee37'), ('\U0001ee39', '\U0001ee39'),
+        ('\U0001ee3b', '\U0001ee3b'), ('\U0001ee42', '\U0001ee42'), ('\U0001ee47', '\U0001ee47'),
+        ('\U0001ee49', '\U0001ee49'), ('\U0001ee4b', '\U0001ee4b'), ('\U0001ee4d', '\U0001ee4f'),
+        ('\U0001ee51', '\U0001ee52'), ('\U0001ee54', '\U0001ee54'), ('\U0001ee57', '\U0001ee57'),
+        ('\U0001ee59', '\U0001ee59'), ('\U0001ee5b', '\U0001ee5b'), ('\U0001ee5d', '\U0001ee5d'),
+        ('\U0001ee5f', '\U0001ee5f'), ('\U0001ee61', '\U0001ee62'), ('\U0001ee64', '\U0001ee64'),
+        ('\U0001ee67', '\U0001ee6a'), ('\U0001ee6c', '\U0001ee72'), ('\U0001ee74', '\U0001ee77'),
+        ('\U0001ee79', '\U0001ee7c'), ('\U0001ee7e', '\U0001ee7e'), ('\U0001ee80', '\U0001ee89'),
+        ('\U0001ee8b', '\U0001ee9b'), ('\U0001eea1', '\U0001eea3'), ('\U0001eea5', '\U0001eea9'),
+        ('\U0001eeab', '\U0001eebb'), ('\U0001f130', '\U0001f149'), ('\U0001f150', '\U0001f169'),
+        ('\U0001f170', '\U0001f189'), ('\U00020000', '\U0002a6df'), ('\U0002a700', '\U0002b738'),
+        ('\U0002b740', '\U0002b81d'), ('\U0002b820', '\U0002cea1'), ('\U0002ceb0', '\U0002ebe0'),
+        ('\U0002f800', '\U0002fa1d'), ('\U00030000', '\U0003134a')
+    ];
+
+    /// Return true if the character has the `Alphabetic` property.
+    pub fn alphabetic(c: char) -> bool {
+        super::bsearch_range_table(c, ALPHABETIC_TABLE)
+    }
+
+    static LOWERCASE_TABLE: &'static [(char, char)] = &[
+        ('\x61', '\x7a'), ('\u00aa', '\u00aa'), ('\u00b5', '\u00b5'), ('\u00ba', '\u00ba'),
+        ('\u00df', '\u00f6'), ('\u00f8', '\u00ff'), ('\u0101', '\u0101'), ('\u0103', '\u0103'),
+        ('\u0105', '\u0105'), ('\u0107', '\u0107'), ('\u0109', '\u0109'), ('\u010b', '\u010b'),
+        ('\u010d', '\u010d'), ('\u010f', '\u010f'), ('\u0111', '\u0111'), ('\u0113', '\u0113'),
+        ('\u0115', '\u0115'), ('\u0117', '\u0117'), ('\u0119', '\u0119'), ('\u011b', '\u011b'),
+        ('\u011d', '\u011d'), ('\u011f', '\u011f'), ('\u0121', '\u0121'), ('\u0123', '\u0123'),
+        ('\u0125', '\u0125'), ('\u0127', '\u0127'), ('\u0129', '\u0129'), ('\u012b', '\u012b'),
+        ('\u012d', '\u012d'), ('\u012f', '\u012f'), ('\u0131', '\u0131'), ('\u0133', '\u0133'),
+        ('\u0135', '\u0135'), ('\u0137', '\u0138'), ('\u013a', '\u013a'), ('\u013c', '\u013c'),
+        ('\u013e', '\u013e'), ('\u0140', '\u0140'), ('\u0142', '\u0142'), ('\u0144', '\u0144'),
+        ('\u0146', '\u0146'), ('\u0148', '\u0149'), ('\u014b', '\u014b'), ('\u014d', '\u014d'),
+        ('\u014f', '\u014f'), ('\u0151', '\u0151'), ('\u0153', '\u0153'), ('\u0155', '\u0155'),
+        ('\u0157', '\u0157'), ('\u0159', '\u0159'), ('\u015b', '\u015b'), ('\u015d', '\u015d'),
+        ('\u015f', '\u015f'), ('\u0161', '\u0161'), ('\u0163', '\u0163'), ('\u0165', '\u0165'),
+        ('\u0167', '\u0167'), ('\u0169', '\u0169'), ('\u016b', '\u016b'), ('\u016d', '\u016d'),
+        ('\u016f', '\u016f'), ('\u0171', '\u0171'), ('\u0173', '\u0173'), ('\u0175', '\u0175'),
+        ('\u0177', '\u0177'), ('\u017a', '\u017a'), ('\u017c', '\u017c'), ('\u017e', '\u0180'),
+        ('\u0183', '\u0183'), ('\u0185', '\u0185'), ('\u0188', '\u0188'), ('\u018c', '\u018d'),
+        ('\u0192', '\u0192'), ('\u0195', '\u0195'), ('\u0199', '\u019b'), ('\u019e', '\u019e'),
+        ('\u01a1', '\u01a1'), ('\u01a3', '\u01a3'), ('\u01a5', '\u01a5'), ('\u01a8', '\u01a8'),
+        ('\u01aa', '\u01ab'), ('\u01ad', '\u01ad'), ('\u01b0', '\u01b0'), ('\u01b4', '\u01b4'),
+        ('\u01b6', '\u01b6'), ('\u01b9', '\u01ba'), ('\u01bd', '\u01bf'), ('\u01c6', '\u01c6'),
+        ('\u01c9', '\u01c9'), ('\u01cc', '\u01cc'), ('\u01ce', '\u01ce'), ('\u01d0', '\u01d0'),
+        ('\u01d2', '\u01d2'), ('\u01d4', '\u01d4'), ('\u01d6', '\u01d6'), ('\u01d8', '\u01d8'),
+        ('\u01da', '\u01da'), ('\u01dc', '\u01dd'), ('\u01df', '\u01df'), ('\u01e1', '\u01e1'),
+        ('\u01e3', '\u01e3'), ('\u01e5', '\u01e5'), ('\u01e7', '\u01e7'), ('\u01e9', '\u01e9'),
+        ('\u01eb', '\u01eb'), ('\u01ed', '\u01ed'), ('\u01ef', '\u01f0'), ('\u01f3', '\u01f3'),
+        ('\u01f5', '\u01f5'), ('\u01f9', '\u01f9'), ('\u01fb', '\u01fb'), ('\u01fd', '\u01fd'),
+        ('\u01ff', '\u01ff'), ('\u0201', '\u0201'), ('\u0203', '\u0203'), ('\u0205', '\u0205'),
+        ('\u0207', '\u0207'), ('\u0209', '\u0209'), ('\u020b', '\u020b'), ('\u020d', '\u020d'),
+        ('\u020f', '\u020f'), ('\u0211', '\u0211'), ('\u0213', '\u0213'), ('\u0215', '\u0215'),
+        ('\u0217', '\u0217'), ('\u0219', '\u0219'), ('\u021b', '\u021b'), ('\u021d', '\u021d'),
+        ('\u021f', '\u021f'), ('\u0221', '\u0221'), ('\u0223', '\u0223'), ('\u0225', '\u0225'),
+        ('\u0227', '\u0227'), ('\u0229', '\u0229'), ('\u022b', '\u022b'), ('\u022d', '\u022d'),
+        ('\u022f', '\u022f'), ('\u0231', '\u0231'), ('\u0233', '\u0239'), ('\u023c', '\u023c'),
+        ('\u023f', '\u0240'), ('\u0242', '\u0242'), ('\u0247', '\u0247'), ('\u0249', '\u0249'),
+        ('\u024b', '\u024b'), ('\u024d', '\u024d'), ('\u024f', '\u0293'), ('\u0295', '\u02b8'),
+        ('\u02c0', '\u02c1'), ('\u02e0', '\u02e4'), ('\u0345', '\u0345'), ('\u0371', '\u0371'),
+        ('\u0373', '\u0373'), ('\u0377', '\u0377'), ('\u037a', '\u037d'), ('\u0390', '\u0390'),
+        ('\u03ac', '\u03ce'), ('\u03d0', '\u03d1'), ('\u03d5', '\u03d7'), ('\u03d9', '\u03d9'),
+        ('\u03db', '\u03db'), ('\u03dd', '\u03dd'), ('\u03df', '\u03df'), ('\u03e1', '\u03e1'),
+        ('\u03e3', '\u03e3'), ('\u03e5', '\u03e5'), ('\u03e7', '\u03e7'), ('\u03e9', '\u03e9'),
+        ('\u03eb', '\u03eb'), ('\u03ed', '\u03ed'), ('\u03ef', '\u03f3'), ('\u03f5', '\u03f5'),
+        ('\u03f8', '\u03f8'), ('\u03fb', '\u03fc'), ('\u0430', '\u045f'), ('\u0461', '\u0461'),
+        ('\u0463', '\u0463'), ('\u0465', '\u0465'), ('\u0467', '\u0467'), ('\u0469', '\u0469'),
+        ('\u046b', '\u046b'), ('\u046d', '\u046d'), ('\u046f', '\u046f'), ('\u0471', '\u0471'),
+        ('\u0473', '\u0473'), ('\u0475', '\u0475'), ('\u0477', '\u0477'), ('\u0479', '\u0479'),
+        ('\u047b', '\u047b'), ('\u047d', '\u047d'), ('\u047f', '\u047f'), ('\u0481', '\u0481'),
+        ('\u048b', '\u048b'), ('\u048d', '\u048d'), ('\u048f', '\u048f'), ('\u0491', '\u0491'),
+        ('\u0493', '\u0493'), ('\u0495', '\u0495'), ('\u0497', '\u0497'), ('\u0499', '\u0499'),
+        ('\u049b', '\u049b'), ('\u049d', '\u049d'), ('\u049f', '\u049f'), ('\u04a1', '\u04a1'),
+        ('\u04a3', '\u04a3'), ('\u04a5', '\u04a5'), ('\u04a7', '\u04a7'), ('\u04a9', '\u04a9'),
+        ('\u04ab', '\u04ab'), ('\u04ad', '\u04ad'), ('\u04af', '\u04af'), ('\u04b1', '\u04b1'),
+        ('\u04b3', '\u04b3'), ('\u04b5', '\u04b5'), ('\u04b7', '\u04b7'), ('\u04b9', '\u04b9'),
+        ('\u04bb', '\u04bb'), ('\u04bd', '\u04bd'), ('\u04bf', '\u04bf'), ('\u04c2', '\u04c2'),
+        ('\u04c4', '\u04c4'), ('\u04c6', '\u04c6'), ('\u04c8', '\u04c8'), ('\u04ca', '\u04ca'),
+        ('\u04cc', '\u04cc'), ('\u04ce', '\u04cf'), ('\u04d1', '\u04d1'), ('\u04d3', '\u04d3'),
+        ('\u04d5', '\u04d5'), ('\u04d7', '\u04d7'), ('\u04d9', '\u04d9'), ('\u04db', '\u04db'),
+        ('\u04dd', '\u04dd'), ('\u04df', '\u04df'), ('\u04e1', '\u04e1'), ('\u04e3', '\u04e3'),
+        ('\u04e5', '\u04e5'), ('\u04e7', '\u04e7'), ('\u04e9', '\u04e9'), ('\u04eb', '\u04eb'),
+        ('\u04ed', '\u04ed'), ('\u04ef', '\u04ef'), ('\u04f1', '\u04f1'), ('\u04f3', '\u04f3'),
+        ('\u04f5', '\u04f5'), ('\u04f7', '\u04f7'), ('\u04f9', '\u04f9'), ('\u04fb', '\u04fb'),
+        ('\u04fd', '\u04fd'), ('\u04ff', '\u04ff'), ('\u0501', '\u0501'), ('\u0503', '\u0503'),
+        ('\u0505', '\u0505'), ('\u0507', '\u0507'), ('\u0509', '\u0509'), ('\u050b', '\u050b'),
+        ('\u050d', '\u050d'), ('\u050f', '\u050f'), ('\u0511', '\u0511'), ('\u0513', '\u0513'),
+        ('\u0515', '\u0515'), ('\u0517', '\u0517'), ('\u0519', '\u0519'), ('\u051b', '\u051b'),
+        ('\u051d', '\u051d'), ('\u051f', '\u051f'), ('\u0521', '\u0521'), ('\u0523', '\u0523'),
+        ('\u0525', '\u0525'), ('\u0527', '\u0527'), ('\u0529', '\u0529'), ('\u052b', '\u052b'),
+        ('\u052d', '\u052d'), ('\u052f', '\u052f'), ('\u0560', '\u0588'), ('\u10d0', '\u10fa'),
+        ('\u10fd', '\u10ff'), ('\u13f8', '\u13fd'), ('\u1c80', '\u1c88'), ('\u1d00', '\u1dbf'),
+        ('\u1e01', '\u1e01'), ('\u1e03', '\u1e03'), ('\u1e05', '\u1e05'), ('\u1e07', '\u1e07'),
+        ('\u1e09', '\u1e09'), ('\u1e0b', '\u1e0b'), ('\u1e0d', '\u1e0d'), ('\u1e0f', '\u1e0f'),
+        ('\u1e11', '\u1e11'), ('\u1e13', '\u1e13'), ('\u1e15', '\u1e15'), ('\u1e17', '\u1e17'),
+        ('\u1e19', '\u1e19'), ('\u1e1b', '\u1e1b'), ('\u1e1d', '\u1e1d'), ('\u1e1f', '\u1e1f'),
+        ('\u1e21', '\u1e21'), ('\u1e23', '\u1e23'), ('\u1e25', '\u1e25'), ('\u1e27', '\u1e27'),
+        ('\u1e29', '\u1e29'), ('\u1e2b', '\u1e2b'), ('\u1e2d', '\u1e2d'), ('\u1e2f', '\u1e2f'),
+        ('\u1e31', '\u1e31'), ('\u1e33', '\u1e33'), ('\u1e35', '\u1e35'), ('\u1e37', '\u1e37'),
+        ('\u1e39', '\u1e39'), ('\u1e3b', '\u1e3b'), ('\u1e3d', '\u1e3d'), ('\u1e3f', '\u1e3f'),
+        ('\u1e41', '\u1e41'), ('\u1e43', '\u1e43'), ('\u1e45', '\u1e45'), ('\u1e47', '\u1e47'),
+        ('\u1e49', '\u1e49'), ('\u1e4b', '\u1e4b'), ('\u1e4d', '\u1e4d'), ('\u1e4f', '\u1e4f'),
+        ('\u1e51', '\u1e51'), ('\u1e53', '\u1e53'), ('\u1e55', '\u1e55'), ('\u1e57', '\u1e57'),
+        ('\u1e59', '\u1e59'), ('\u1e5b', '\u1e5b'), ('\u1e5d', '\u1e5d'), ('\u1e5f', '\u1e5f'),
+        ('\u1e61', '\u1e61'), ('\u1e63', '\u1e63'), ('\u1e65', '\u1e65'), ('\u1e67', '\u1e67'),
+        ('\u1e69', '\u1e69'), ('\u1e6b', '\u1e6b'), ('\u1e6d', '\u1e6d'), ('\u1e6f', '\u1e6f'),
+        ('\u1e71', '\u1e71'), ('\u1e73', '\u1e73'), ('\u1e75', '\u1e75'), ('\u1e77', '\u1e77'),
+        ('\u1e79', '\u1e79'), ('\u1e7b', '\u1e7b'), ('\u1e7d', '\u1e7d'), ('\u1e7f', '\u1e7f'),
+        ('\u1e81', '\u1e81'), ('\u1e83', '\u1e83'), ('\u1e85', '\u1e85'), ('\u1e87', '\u1e87'),
+        ('\u1e89', '\u1e89'), ('\u1e8b', '\u1e8b'), ('\u1e8d', '\u1e8d'), ('\u1e8f', '\u1e8f'),
+        ('\u1e91', '\u1e91'), ('\u1e93', '\u1e93'), ('\u1e95', '\u1e9d'), ('\u1e9f', '\u1e9f'),
+        ('\u1ea1', '\u1ea1'), ('\u1ea3', '\u1ea3'), ('\u1ea5', '\u1ea5'), ('\u1ea7', '\u1ea7'),
+        ('\u1ea9', '\u1ea9'), ('\u1eab', '\u1eab'), ('\u1ead', '\u1ead'), ('\u1eaf', '\u1eaf'),
+        ('\u1eb1', '\u1eb1'), ('\u1eb3', '\u1eb3'), ('\u1eb5', '\u1eb5'), ('\u1eb7', '\u1eb7'),
+        ('\u1eb9', '\u1eb9'), ('\u1ebb', '\u1ebb'), ('\u1ebd', '\u1ebd'), ('\u1ebf', '\u1ebf'),
+        ('\u1ec1', '\u1ec1'), ('\u1ec3', '\u1ec3'), ('\u1ec5', '\u1ec5'), ('\u1ec7', '\u1ec7'),
+        ('\u1ec9', '\u1ec9'), ('\u1ecb', '\u1ecb'), ('\u1ecd', '\u1ecd'), ('\u1ecf', '\u1ecf'),
+        ('\u1ed1', '\u1ed1'), ('\u1ed3', '\u1ed3'), ('\u1ed5', '\u1ed5'), ('\u1ed7', '\u1ed7'),
+        ('\u1ed9', '\u1ed9'), ('\u1edb', '\u1edb'), ('\u1edd', '\u1edd'), ('\u1edf', '\u1edf'),
+        ('\u1ee1', '\u1ee1'), ('\u1ee3', '\u1ee3'), ('\u1ee5', '\u1ee5'), ('\u1ee7', '\u1ee7'),
+        ('\u1ee9', '\u1ee9'), ('\u1eeb', '\u1eeb'), ('\u1eed', '\u1eed'), ('\u1eef', '\u1eef'),
+        ('\u1ef1', '\u1ef1'), ('\u1ef3', '\u1ef3'), ('\u1ef5', '\u1ef5'), ('\u1ef7', '\u1ef7'),
+        ('\u1ef9', '\u1ef9'), ('\u1efb', '\u1efb'), ('\u1efd', '\u1efd'), ('\u1eff', '\u1f07'),
+        ('\u1f10', '\u1f15'), ('\u1f20', '\u1f27'), ('\u1f30', '\u1f37'), ('\u1f40', '\u1f45'),
+        ('\u1f50', '\u1f57'), ('\u1f60', '\u1f67'), ('\u1f70', '\u1f7d'), ('\u1f80', '\u1f87'),
+        ('\u1f90', '\u1f97'), ('\u1fa0', '\u1fa7'), ('\u1fb0', '\u1fb4'), ('\u1fb6', '\u1fb7'),
+        ('\u1fbe', '\u1fbe'), ('\u1fc2', '\u1fc4'), ('\u1fc6', '\u1fc7'), ('\u1fd0', '\u1fd3'),
+        ('\u1fd6', '\u1fd7'), ('\u1fe0', '\u1fe7'), ('\u1ff2', '\u1ff4'), ('\u1ff6', '\u1ff7'),
+        ('\u2071', '\u2071'), ('\u207f', '\u207f'), ('\u2090', '\u209c'), ('\u210a', '\u210a'),
+        ('\u210e', '\u210f'), ('\u2113', '\u2113'), ('\u212f', '\u212f'), ('\u2134', '\u2134'),
+        ('\u2139', '\u2139'), ('\u213c', '\u213d'), ('\u2146', '\u2149'), ('\u214e', '\u214e'),
+        ('\u2170', '\u217f'), ('\u2184', '\u2184'), ('\u24d0', '\u24e9'), ('\u2c30', '\u2c5f'),
+        ('\u2c61', '\u2c61'), ('\u2c65', '\u2c66'), ('\u2c68', '\u2c68'), ('\u2c6a', '\u2c6a'),
+        ('\u2c6c', '\u2c6c'), ('\u2c71', '\u2c71'), ('\u2c73', '\u2c74'), ('\u2c76', '\u2c7d'),
+        ('\u2c81', '\u2c81'), ('\u2c83', '\u2c83'), ('\u2c85', '\u2c85'), ('\u2c87', '\u2c87'),
+        ('\u2c89', '\u2c89'), ('\u2c8b', '\u2c8b'), ('\u2c8d', '\u2c8d'), ('\u2c8f', '\u2c8f'),
+        ('\u2c91', '\u2c91'), ('\u2c93', '\u2c93'), ('\u2c95', '\u2c95'), ('\u2c97', '\u2c97'),
+        ('\u2c99', '\u2c99'), ('\u2c9b', '\u2c9b'), ('\u2c9d', '\u2c9d'), ('\u2c9f', '\u2c9f'),
+        ('\u2ca1', '\u2ca1'), ('\u2ca3', '\u2ca3'), ('\u2ca5', '\u2ca5'), ('\u2ca7', '\u2ca7'),
+        ('\u2ca9', '\u2ca9'), ('\u2cab', '\u2cab'), ('\u2cad', '\u2cad'), ('\u2caf', '\u2caf'),
+        ('\u2cb1', '\u2cb1'), ('\u2cb3', '\u2cb3'), ('\u2cb5', '\u2cb5'), ('\u2cb7', '\u2cb7'),
+        ('\u2cb9', '\u2cb9'), ('\u2cbb', '\u2cbb'), ('\u2cbd', '\u2cbd'), ('\u2cbf', '\u2cbf'),
+        ('\u2cc1', '\u2cc1'), ('\u2cc3', '\u2cc3'), ('\u2cc5', '\u2cc5'), ('\u2cc7', '\u2cc7'),
+        ('\u2cc9', '\u2cc9'), ('\u2ccb', '\u2ccb'), ('\u2ccd', '\u2ccd'), ('\u2ccf', '\u2ccf'),
+        ('\u2cd1', '\u2cd1'), ('\u2cd3', '\u2cd3'), ('\u2cd5', '\u2cd5'), ('\u2cd7', '\u2cd7'),
+        ('\u2cd9', '\u2cd9'), ('\u2cdb', '\u2cdb'), ('\u2cdd', '\u2cdd'), ('\u2cdf', '\u2cdf'),
+        ('\u2ce1', '\u2ce1'), ('\u2ce3', '\u2ce4'), ('\u2cec', '\u2cec'), ('\u2cee', '\u2cee'),
+        ('\u2cf3', '\u2cf3'), ('\u2d00', '\u2d25'), ('\u2d27', '\u2d27'), ('\u2d2d', '\u2d2d'),
+        ('\ua641', '\ua641'), ('\ua643', '\ua643'), ('\ua645', '\ua645'), ('\ua647', '\ua647'),
+        ('\ua649', '\ua649'), ('\ua64b', '\ua64b'), ('\ua64d', '\ua64d'), ('\ua64f', '\ua64f'),
+        ('\ua651', '\ua651'), ('\ua653', '\ua653'), ('\ua655', '\ua655'), ('\ua657', '\ua657'),
+        ('\ua659', '\ua659'), ('\ua65b', '\ua65b'), ('\ua65d', '\ua65d'), ('\ua65f', '\ua65f'),
+        ('\ua661', '\ua661'), ('\ua663', '\ua663'), ('\ua665', '\ua665'), ('\ua667', '\ua667'),
+        ('\ua669', '\ua669'), ('\ua66b', '\ua66b'), ('\ua66d', '\ua66d'), ('\ua681', '\ua681'),
+        ('\ua683', '\ua683'), ('\ua685', '\ua685'), ('\ua687', '\ua687'), ('\ua689', '\ua689'),
+        ('\ua68b', '\ua68b'), ('\ua68d', '\ua68d'), ('\ua68f', '\ua68f'), ('\ua691', '\ua691'),
+        ('\ua693', '\ua693'), ('\ua695', '\ua695'), ('\ua697', '\ua697'), ('\ua699', '\ua699'),
+        ('\ua69b', '\ua69d'), ('\ua723', '\ua723'), ('\ua725', '\ua725'), ('\ua727', '\ua727'),
+        ('\ua729', '\ua729'), ('\ua72b', '\ua72b'), ('\ua72d', '\ua72d'), ('\ua72f', '\ua731'),
+        ('\ua733', '\ua733'), ('\ua735', '\ua735'), ('\ua737', '\ua737'), ('\ua739', '\ua739'),
+        ('\ua73b', '\ua73b'), ('\ua73d', '\ua73d'), ('\ua73f', '\ua73f'), ('\ua741', '\ua741'),
+        ('\ua743', '\ua743'), ('\ua745', '\ua745'), ('\ua747', '\ua747'), ('\ua749', '\ua749'),
+        ('\ua74b', '\ua74b'), ('\ua74d', '\ua74d'), ('\ua74f', '\ua74f'), ('\ua751', '\ua751'),
+        ('\ua753', '\ua753'), ('\ua755', '\ua755'), ('\ua757', '\ua757'), ('\ua759', '\ua759'),
+        ('\ua75b', '\ua75b'), ('\ua75d', '\ua75d'), ('\ua75f', '\ua75f'), ('\ua761', '\ua761'),
+        ('\ua763', '\ua763'), ('\ua765', '\ua765'), ('\ua767', '\ua767'), ('\ua769', '\ua769'),
+        ('\ua76b', '\ua76b'), ('\ua76d', '\ua76d'), ('\ua76f', '\ua778'), ('\ua77a', '\ua77a'),
+        ('\ua77c', '\ua77c'), ('\ua77f', '\ua77f'), ('\ua781', '\ua781'), ('\ua783', '\ua783'),
+        ('\ua785', '\ua785'), ('\ua787', '\ua787'), ('\ua78c', '\ua78c'), ('\ua78e', '\ua78e'),
+        ('\ua791', '\ua791'), ('\ua793', '\ua795'), ('\ua797', '\ua797'), ('\ua799', '\ua799'),
+        ('\ua79b', '\ua79b'), ('\ua79d', '\ua79d'), ('\ua79f', '\ua79f'), ('\ua7a1', '\ua7a1'),
+        ('\ua7a3', '\ua7a3'), ('\ua7a5', '\ua7a5'), ('\ua7a7', '\ua7a7'), ('\ua7a9', '\ua7a9'),
+        ('\ua7af', '\ua7af'), ('\ua7b5', '\ua7b5'), ('\ua7b7', '\ua7b7'), ('\ua7b9', '\ua7b9'),
+        ('\ua7bb', '\ua7bb'), ('\ua7bd', '\ua7bd'), ('\ua7bf', '\ua7bf'), ('\ua7c1', '\ua7c1'),
+        ('\ua7c3', '\ua7c3'), ('\ua7c8', '\ua7c8'), ('\ua7ca', '\ua7ca'), ('\ua7d1', '\ua7d1'),
+        ('\ua7d3', '\ua7d3'), ('\ua7d5', '\ua7d5'), ('\ua7d7', '\ua7d7'), ('\ua7d9', '\ua7d9'),
+        ('\ua7f6', '\ua7f6'), ('\ua7f8', '\ua7fa'), ('\uab30', '\uab5a'), ('\uab5c', '\uab68'),
+        ('\uab70', '\uabbf'), ('\ufb00', '\ufb06'), ('\ufb13', '\ufb17'), ('\uff41', '\uff5a'),
+        ('\U00010428', '\U0001044f'), ('\U000104d8', '\U000104fb'), ('\U00010597', '\U000105a1'),
+        ('\U000105a3', '\U000105b1'), ('\U000105b3', '\U000105b9'), ('\U000105bb', '\U000105bc'),
+        ('\U00010780', '\U00010780'), ('\U00010783', '\U00010785'), ('\U00010787', '\U000107b0'),
+        ('\U000107b2', '\U000107ba'), ('\U00010cc0', '\U00010cf2'), ('\U000118c0', '\U000118df'),
+        ('\U00016e60', '\U00016e7f'), ('\U0001d41a', '\U0001d433'), ('\U0001d44e', '\U0001d454'),
+        ('\U0001d456', '\U0001d467'), ('\U0001d482', '\U0001d49b'), ('\U0001d4b6', '\U0001d4b9'),
+        ('\U0001d4bb', '\U0001d4bb'), ('\U0001d4bd', '\U0001d4c3'), ('\U0001d4c5', '\U0001d4cf'),
+        ('\U0001d4ea', '\U0001d503'), ('\U0001d51e', '\U0001d537'), ('\U0001d552', '\U0001d56b'),
+        ('\U0001d586', '\U0001d59f'), ('\U0001d5ba', '\U0001d5d3'), ('\U0001d5ee', '\U0001d607'),
+        ('\U0001d622', '\U0001d63b'), ('\U0001d656', '\U0001d66f'), ('\U0001d68a', '\U0001d6a5'),
+        ('\U0001d6c2', '\U0001d6da'), ('\U0001d6dc', '\U0001d6e1'), ('\U0001d6fc', '\U0001d714'),
+        ('\U0001d716', '\U0001d71b'), ('\U0001d736', '\U0001d74e'), ('\U0001d750', '\U0001d755'),
+        ('\U0001d770', '\U0001d788'), ('\U0001d78a', '\U0001d78f'), ('\U0001d7aa', '\U0001d7c2'),
+        ('\U0001d7c4', '\U0001d7c9'), ('\U0001d7cb', '\U0001d7cb'), ('\U0001df00', '\U0001df09'),
+        ('\U0001df0b', '\U0001df1e'), ('\U0001e922', '\U0001e943')
+    ];
+
+    /// Return true if the character has the `Lowercase` property.
+    pub fn lowercase(c: char) -> bool {
+        super::bsearch_range_table(c, LOWERCASE_TABLE)
+    }
+
+    static UPPERCASE_TABLE: &'static [(char, char)] = &[
+        ('\x41', '\x5a'), ('\u00c0', '\u00d6'), ('\u00d8', '\u00de'), ('\u0100', '\u0100'),
+        ('\u0102', '\u0102'), ('\u0104', '\u0104'), ('\u0106', '\u0106'), ('\u0108', '\u0108'),
+        ('\u010a', '\u010a'), ('\u010c', '\u010c'), ('\u010e', '\u010e'), ('\u0110', '\u0110'),
+        ('\u0112', '\u0112'), ('\u0114', '\u0114'), ('\u0116', '\u0116'), ('\u0118', '\u0118'),
+        ('\u011a', '\u011a'), ('\u011c', '\u011c'), ('\u011e', '\u011e'), ('\u0120', '\u0120'),
+        ('\u0122', '\u0122'), ('\u0124', '\u0124'), ('\u0126', '\u0126'), ('\u0128', '\u0128'),
+        ('\u012a', '\u012a'), ('\u012c', '\u012c'), ('\u012e', '\u012e'), ('\u0130', '\u0130'),
+        ('\u0132', '\u0132'), ('\u0134', '\u0134'), ('\u0136', '\u0136'), ('\u0139', '\u0139'),
+        ('\u013b', '\u013b'), ('\u013d', '\u013d'), ('\u013f', '\u013f'), ('\u0141', '\u0141'),
+        ('\u0143', '\u0143'), ('\u0145', '\u0145'), ('\u0147', '\u0147'), ('\u014a', '\u014a'),
+        ('\u014c', '\u014c'), ('\u014e', '\u014e'), ('\u0150', '\u0150'), ('\u0152', '\u0152'),
+        ('\u0154', '\u0154'), ('\u0156', '\u0156'), ('\u0158', '\u0158'), ('\u015a', '\u015a'),
+        ('\u015c', '\u015c'), ('\u015e', '\u015e'), ('\u0160', '\u0160'), ('\u0162', '\u0162'),
+        ('\u0164', '\u0164'), ('\u0166', '\u0166'), ('\u0168', '\u0168'), ('\u016a', '\u016a'),
+        ('\u016c', '\u016c'), ('\u016e', '\u016e'), ('\u0170', '\u0170'), ('\u0172', '\u0172'),
+        ('\u0174', '\u0174'), ('\u0176', '\u0176'), ('\u0178', '\u0179'), ('\u017b', '\u017b'),
+        ('\u017d', '\u017d'), ('\u0181', '\u0182'), ('\u0184', '\u0184'), ('\u0186', '\u0187'),
+        ('\u0189', '\u018b'), ('\u018e', '\u0191'), ('\u0193', '\u0194'), ('\u0196', '\u0198'),
+        ('\u019c', '\u019d'), ('\u019f', '\u01a0'), ('\u01a2', '\u01a2'), ('\u01a4', '\u01a4'),
+        ('\u01a6', '\u01a7'), ('\u01a9', '\u01a9'), ('\u01ac', '\u01ac'), ('\u01ae', '\u01af'),
+        ('\u01b1', '\u01b3'), ('\u01b5', '\u01b5'), ('\u01b7', '\u01b8'), ('\u01bc', '\u01bc'),
+        ('\u01c4', '\u01c4'), ('\u01c7', '\u01c7'), ('\u01ca', '\u01ca'), ('\u01cd', '\u01cd'),
+        ('\u01cf', '\u01cf'), ('\u01d1', '\u01d1'), ('\u01d3', '\u01d3'), ('\u01d5', '\u01d5'),
+        ('\u01d7', '\u01d7'), ('\u01d9', '\u01d9'), ('\u01db', '\u01db'), ('\u01de', '\u01de'),
+        ('\u01e0', '\u01e0'), ('\u01e2', '\u01e2'), ('\u01e4', '\u01e4'), ('\u01e6', '\u01e6'),
+        ('\u01e8', '\u01e8'), ('\u01ea', '\u01ea'), ('\u01ec', '\u01ec'), ('\u01ee', '\u01ee'),
+        ('\u01f1', '\u01f1'), ('\u01f4', '\u01f4'), ('\u01f6', '\u01f8'), ('\u01fa', '\u01fa'),
+        ('\u01fc', '\u01fc'), ('\u01fe', '\u01fe'), ('\u0200', '\u0200'), ('\u0202', '\u0202'),
+        ('\u0204', '\u0204'), ('\u0206', '\u0206'), ('\u0208', '\u0208'), ('\u020a', '\u020a'),
+        ('\u020c', '\u020c'), ('\u020e', '\u020e'), ('\u0210', '\u0210'), ('\u0212', '\u0212'),
+        ('\u0214', '\u0214'), ('\u0216', '\u0216'), ('\u0218', '\u0218'), ('\u021a', '\u021a'),
+        ('\u021c', '\u021c'), ('\u021e', '\u021e'), ('\u0220', '\u0220'), ('\u0222', '\u0222'),
+        ('\u0224', '\u0224'), ('\u0226', '\u0226'), ('\u0228', '\u0228'), ('\u022a', '\u022a'),
+        ('\u022c', '\u022c'), ('\u022e', '\u022e'), ('\u0230', '\u0230'), ('\u0232', '\u0232'),
+        ('\u023a', '\u023b'), ('\u023d', '\u023e'), ('\u0241', '\u0241'), ('\u0243', '\u0246'),
+        ('\u0248', '\u0248'), ('\u024a', '\u024a'), ('\u024c', '\u024c'), ('\u024e', '\u024e'),
+        ('\u0370', '\u0370'), ('\u0372', '\u0372'), ('\u0376', '\u0376'), ('\u037f', '\u037f'),
+        ('\u0386', '\u0386'), ('\u0388', '\u038a'), ('\u038c', '\u038c'), ('\u038e', '\u038f'),
+        ('\u0391', '\u03a1'), ('\u03a3', '\u03ab'), ('\u03cf', '\u03cf'), ('\u03d2', '\u03d4'),
+        ('\u03d8', '\u03d8'), ('\u03da', '\u03da'), ('\u03dc', '\u03dc'), ('\u03de', '\u03de'),
+        ('\u03e0', '\u03e0'), ('\u03e2', '\u03e2'), ('\u03e4', '\u03e4'), ('\u03e6', '\u03e6'),
+        ('\u03e8', '\u03e8'), ('\u03ea', '\u03ea'), ('\u03ec', '\u03ec'), ('\u03ee', '\u03ee'),
+        ('\u03f4', '\u03f4'), ('\u03f7', '\u03f7'), ('\u03f9', '\u03fa'), ('\u03fd', '\u042f'),
+        ('\u0460', '\u0460'), ('\u0462', '\u0462'), ('\u0464', '\u0464'), ('\u0466', '\u0466'),
+        ('\u0468', '\u0468'), ('\u046a', '\u046a'), ('\u046c', '\u046c'), ('\u046e', '\u046e'),
+        ('\u0470', '\u0470'), ('\u0472', '\u0472'), ('\u0474', '\u0474'), ('\u0476', '\u0476'),
+        ('\u0478', '\u0478'), ('\u047a', '\u047a'), ('\u047c', '\u047c'), ('\u047e', '\u047e'),
+        ('\u0480', '\u0480'), ('\u048a', '\u048a'), ('\u048c', '\u048c'), ('\u048e', '\u048e'),
+        ('\u0490', '\u0490'), ('\u0492', '\u0492'), ('\u0494', '\u0494'), ('\u0496', '\u0496'),
+        ('\u0498', '\u0498'), ('\u049a', '\u049a'), ('\u049c', '\u049c'), ('\u049e', '\u049e'),
+        ('\u04a0', '\u04a0'), ('\u04a2', '\u04a2'), ('\u04a4', '\u04a4'), ('\u04a6', '\u04a6'),
+        ('\u04a8', '\u04a8'), ('\u04aa', '\u04aa'), ('\u04ac', '\u04ac'), ('\u04ae', '\u04ae'),
+        ('\u04b0', '\u04b0'), ('\u04b2', '\u04b2'), ('\u04b4', '\u04b4'), ('\u04b6', '\u04b6'),
+        ('\u04b8', '\u04b8'), ('\u04ba', '\u04ba'), ('\u04bc', '\u04bc'), ('\u04be', '\u04be'),
+        ('\u04c0', '\u04c1'), ('\u04c3', '\u04c3'), ('\u04c5', '\u04c5'), ('\u04c7', '\u04c7'),
+        ('\u04c9', '\u04c9'), ('\u04cb', '\u04cb'), ('\u04cd', '\u04cd'), ('\u04d0', '\u04d0'),
+        ('\u04d2', '\u04d2'), ('\u04d4', '\u04d4'), ('\u04d6', '\u04d6'), ('\u04d8', '\u04d8'),
+        ('\u04da', '\u04da'), ('\u04dc', '\u04dc'), ('\u04de', '\u04de'), ('\u04e0', '\u04e0'),
+        ('\u04e2', '\u04e2'), ('\u04e4', '\u04e4'), ('\u04e6', '\u04e6'), ('\u04e8', '\u04e8'),
+        ('\u04ea', '\u04ea'), ('\u04ec', '\u04ec'), ('\u04ee', '\u04ee'), ('\u04f0', '\u04f0'),
+        ('\u04f2', '\u04f2'), ('\u04f4', '\u04f4'), ('\u04f6', '\u04f6'), ('\u04f8', '\u04f8'),
+        ('\u04fa', '\u04fa'), ('\u04fc', '\u04fc'), ('\u04fe', '\u04fe'), ('\u0500', '\u0500'),
+        ('\u0502', '\u0502'), ('\u0504', '\u0504'), ('\u0506', '\u0506'), ('\u0508', '\u0508'),
+        ('\u050a', '\u050a'), ('\u050c', '\u050c'), ('\u050e', '\u050e'), ('\u0510', '\u0510'),
+        ('\u0512', '\u0512'), ('\u0514', '\u0514'), ('\u0516', '\u0516'), ('\u0518', '\u0518'),
+        ('\u051a', '\u051a'), ('\u051c', '\u051c'), ('\u051e', '\u051e'), ('\u0520', '\u0520'),
+        ('\u0522', '\u0522'), ('\u0524', '\u0524'), ('\u0526', '\u0526'), ('\u0528', '\u0528'),
+        ('\u052a', '\u052a'), ('\u052c', '\u052c'), ('\u052e', '\u052e'), ('\u0531', '\u0556'),
+        ('\u10a0', '\u10c5'), ('\u10c7', '\u10c7'), ('\u10cd', '\u10cd'), ('\u13a0', '\u13f5'),
+        ('\u1c90', '\u1cba'), ('\u1cbd', '\u1cbf'), ('\u1e00', '\u1e00'), ('\u1e02', '\u1e02'),
+        ('\u1e04', '\u1e04'), ('\u1e06', '\u1e06'), ('\u1e08', '\u1e08'), ('\u1e0a', '\u1e0a'),
+        ('\u1e0c', '\u1e0c'), ('\u1e0e', '\u1e0e'), ('\u1e10', '\u1e10'), ('\u1e12', '\u1e12'),
+        ('\u1e14', '\u1e14'), ('\u1e16', '\u1e16'), ('\u1e18', '\u1e18'), ('\u1e1a', '\u1e1a'),
+        ('\u1e1c', '\u1e1c'), ('\u1e1e', '\u1e1e'), ('\u1e20', '\u1e20'), ('\u1e22', '\u1e22'),
+        ('\u1e24', '\u1e24'), ('\u1e26', '\u1e26'), ('\u1e28', '\u1e28'), ('\u1e2a', '\u1e2a'),
+        ('\u1e2c', '\u1e2c'), ('\u1e2e', '\u1e2e'), ('\u1e30', '\u1e30'), ('\u1e32', '\u1e32'),
+        ('\u1e34', '\u1e34'), ('\u1e36', '\u1e36'), ('\u1e38', '\u1e38'), ('\u1e3a', '\u1e3a'),
+        ('\u1e3c', '\u1e3c'), ('\u1e3e', '\u1e3e'), ('\u1e40', '\u1e40'), ('\u1e42', '\u1e42'),
+        ('\u1e44', '\u1e44'), ('\u1e46', '\u1e46'), ('\u1e48', '\u1e48'), ('\u1e4a', '\u1e4a'),
+        ('\u1e4c', '\u1e4c'), ('\u1e4e', '\u1e4e'), ('\u1e50', '\u1e50'), ('\u1e52', '\u1e52'),
+        ('\u1e54', '\u1e54'), ('\u1e56', '\u1e56'), ('\u1e58', '\u1e58'), ('\u1e5a', '\u1e5a'),
+        ('\u1e5c', '\u1e5c'), ('\u1e5e', '\u1e5e'), ('\u1e60', '\u1e60'), ('\u1e62', '\u1e62'),
+        ('\u1e64', '\u1e64'), ('\u1e66', '\u1e66'), ('\u1e68', '\u1e68'), ('\u1e6a', '\u1e6a'),
+        ('\u1e6c', '\u1e6c'), ('\u1e6e', '\u1e6e'), ('\u1e70', '\u1e70'), ('\u1e72', '\u1e72'),
+        ('\u1e74', '\u1e74'), ('\u1e76', '\u1e76'), ('\u1e78', '\u1e78'), ('\u1e7a', '\u1e7a'),
+        ('\u1e7c', '\u1e7c'), ('\u1e7e', '\u1e7e'), ('\u1e80', '\u1e80'), ('\u1e82', '\u1e82'),
+        ('\u1e84', '\u1e84'), ('\u1e86', '\u1e86'), ('\u1e88', '\u1e88'), ('\u1e8a', '\u1e8a'),
+        ('\u1e8c', '\u1e8c'), ('\u1e8e', '\u1e8e'), ('\u1e90', '\u1e90'), ('\u1e92', '\u1e92'),
+        ('\u1e94', '\u1e94'), ('\u1e9e', '\u1e9e'), ('\u1ea0', '\u1ea0'), ('\u1ea2', '\u1ea2'),
+        ('\u1ea4', '\u1ea4'), ('\u1ea6', '\u1ea6'), ('\u1ea8', '\u1ea8'), ('\u1eaa', '\u1eaa'),
+        ('\u1eac', '\u1eac'), ('\u1eae', '\u1eae'), ('\u1eb0', '\u1eb0'), ('\u1eb2', '\u1eb2'),
+        ('\u1eb4', '\u1eb4'), ('\u1eb6', '\u1eb6'), ('\u1eb8', '\u1eb8'), ('\u1eba', '\u1eba'),
+        ('\u1ebc', '\u1ebc'), ('\u1ebe', '\u1ebe'), ('\u1ec0', '\u1ec0'), ('\u1ec2', '\u1ec2'),
+        ('\u1ec4', '\u1ec4'), ('\u1ec6', '\u1ec6'), ('\u1ec8', '\u1ec8'), ('\u1eca', '\u1eca'),
+        ('\u1ecc', '\u1ecc'), ('\u1ece', '\u1ece'), ('\u1ed0', '\u1ed0'), ('\u1ed2', '\u1ed2'),
+        ('\u1ed4', '\u1ed4'), ('\u1ed6', '\u1ed6'), ('\u1ed8', '\u1ed8'), ('\u1eda', '\u1eda'),
+        ('\u1edc', '\u1edc'), ('\u1ede', '\u1ede'), ('\u1ee0', '\u1ee0'), ('\u1ee2', '\u1ee2'),
+        ('\u1ee4', '\u1ee4'), ('\u1ee6', '\u1ee6'), ('\u1ee8', '\u1ee8'), ('\u1eea', '\u1eea'),
+        ('\u1eec', '\u1eec'), ('\u1eee', '\u1eee'), ('\u1ef0', '\u1ef0'), ('\u1ef2', '\u1ef2'),
+        ('\u1ef4', '\u1ef4'), ('\u1ef6', '\u1ef6'), ('\u1ef8', '\u1ef8'), ('\u1efa', '\u1efa'),
+        ('\u1efc', '\u1efc'), ('\u1efe', '\u1efe'), ('\u1f08', '\u1f0f'), ('\u1f18', '\u1f1d'),
+        ('\u1f28', '\u1f2f'), ('\u1f38', '\u1f3f'), ('\u1f48', '\u1f4d'), ('\u1f59', '\u1f59'),
+        ('\u1f5b', '\u1f5b'), ('\u1f5d', '\u1f5d'), ('\u1f5f', '\u1f5f'), ('\u1f68', '\u1f6f'),
+        ('\u1fb8', '\u1fbb'), ('\u1fc8', '\u1fcb'), ('\u1fd8', '\u1fdb'), ('\u1fe8', '\u1fec'),
+        ('\u1ff8', '\u1ffb'), ('\u2102', '\u2102'), ('\u2107', '\u2107'), ('\u210b', '\u210d'),
+        ('\u2110', '\u2112'), ('\u2115', '\u2115'), ('\u2119', '\u211d'), ('\u2124', '\u2124'),
+        ('\u2126', '\u2126'), ('\u2128', '\u2128'), ('\u212a', '\u212d'), ('\u2130', '\u2133'),
+        ('\u213e', '\u213f'), ('\u2145', '\u2145'), ('\u2160', '\u216f'), ('\u2183', '\u2183'),
+        ('\u24b6', '\u24cf'), ('\u2c00', '\u2c2f'), ('\u2c60', '\u2c60'), ('\u2c62', '\u2c64'),
+        ('\u2c67', '\u2c67'), ('\u2c69', '\u2c69'), ('\u2c6b', '\u2c6b'), ('\u2c6d', '\u2c70'),
+        ('\u2c72', '\u2c72'), ('\u2c75', '\u2c75'), ('\u2c7e', '\u2c80'), ('\u2c82', '\u2c82'),
+        ('\u2c84', '\u2c84'), ('\u2c86', '\u2c86'), ('\u2c88', '\u2c88'), ('\u2c8a', '\u2c8a'),
+        ('\u2c8c', '\u2c8c'), ('\u2c8e', '\u2c8e'), ('\u2c90', '\u2c90'), ('\u2c92', '\u2c92'),
+        ('\u2c94', '\u2c94'), ('\u2c96', '\u2c96'), ('\u2c98', '\u2c98'), ('\u2c9a', '\u2c9a'),
+        ('\u2c9c', '\u2c9c'), ('\u2c9e', '\u2c9e'), ('\u2ca0', '\u2ca0'), ('\u2ca2', '\u2ca2'),
+        ('\u2ca4', '\u2ca4'), ('\u2ca6', '\u2ca6'), ('\u2ca8', '\u2ca8'), ('\u2caa', '\u2caa'),
+        ('\u2cac', '\u2cac'), ('\u2cae', '\u2cae'), ('\u2cb0', '\u2cb0'), ('\u2cb2', '\u2cb2'),
+        ('\u2cb4', '\u2cb4'), ('\u2cb6', '\u2cb6'), ('\u2cb8', '\u2cb8'), ('\u2cba', '\u2cba'),
+        ('\u2cbc', '\u2cbc'), ('\u2cbe', '\u2cbe'), ('\u2cc0', '\u2cc0'), ('\u2cc2', '\u2cc2'),
+        ('\u2cc4', '\u2cc4'), ('\u2cc6', '\u2cc6'), ('\u2cc8', '\u2cc8'), ('\u2cca', '\u2cca'),
+        ('\u2ccc', '\u2ccc'), ('\u2cce', '\u2cce'), ('\u2cd0', '\u2cd0'), ('\u2cd2', '\u2cd2'),
+        ('\u2cd4', '\u2cd4'), ('\u2cd6', '\u2cd6'), ('\u2cd8', '\u2cd8'), ('\u2cda', '\u2cda'),
+        ('\u2cdc', '\u2cdc'), ('\u2cde', '\u2cde'), ('\u2ce0', '\u2ce0'), ('\u2ce2', '\u2ce2'),
+        ('\u2ceb', '\u2ceb'), ('\u2ced', '\u2ced'), ('\u2cf2', '\u2cf2'), ('\ua640', '\ua640'),
+        ('\ua642', '\ua642'), ('\ua644', '\ua644'), ('\ua646', '\ua646'), ('\ua648', '\ua648'),
+        ('\ua64a', '\ua64a'), ('\ua64c', '\ua64c'), ('\ua64e', '\ua64e'), ('\ua650', '\ua650'),
+        ('\ua652', '\ua652'), ('\ua654', '\ua654'), ('\ua656', '\ua656'), ('\ua658', '\ua658'),
+        ('\ua65a', '\ua65a'), ('\ua65c', '\ua65c'), ('\ua65e', '\ua65e'), ('\ua660', '\ua660'),
+        ('\ua662', '\ua662'), ('\ua664', '\ua664'), ('\ua666', '\ua666'), ('\ua668', '\ua668'),
+        ('\ua66a', '\ua66a'), ('\ua66c', '\ua66c'), ('\ua680', '\ua680'), ('\ua682', '\ua682'),
+        ('\ua684', '\ua684'), ('\ua686', '\ua686'), ('\ua688', '\ua688'), ('\ua68a', '\ua68a'),
+        ('\ua68c', '\ua68c'), ('\ua68e', '\ua68e'), ('\ua690', '\ua690'), ('\ua692', '\ua692'),
+        ('\ua694', '\ua694'), ('\ua696', '\ua696'), ('\ua698', '\ua698'), ('\ua69a', '\ua69a'),
+        ('\ua722', '\ua722'), ('\ua724', '\ua724'), ('\ua726', '\ua726'), ('\ua728', '\ua728'),
+        ('\ua72a', '\ua72a'), ('\ua72c', '\ua72c'), ('\ua72e', '\ua72e'), ('\ua732', '\ua732'),
+        ('\ua734', '\ua734'), ('\ua736', '\ua736'), ('\ua738', '\ua738'), ('\ua73a', '\ua73a'),
+        ('\ua73c', '\ua73c'), ('\ua73e', '\ua73e'), ('\ua740', '\ua740'), ('\ua742', '\ua742'),
+        ('\ua744', '\ua744'), ('\ua746', '\ua746'), ('\ua748', '\ua748'), ('\ua74a', '\ua74a'),
+        ('\ua74c', '\ua74c'), ('\ua74e', '\ua74e'), ('\ua750', '\ua750'), ('\ua752', '\ua752'),
+        ('\ua754', '\ua754'), ('\ua756', '\ua756'), ('\ua758', '\ua758'), ('\ua75a', '\ua75a'),
+        ('\ua75c', '\ua75c'), ('\ua75e', '\ua75e'), ('\ua760', '\ua760'), ('\ua762', '\ua762'),
+        ('\ua764', '\ua764'), ('\ua766', '\ua766'), ('\ua768', '\ua768'), ('\ua76a', '\ua76a'),
+        ('\ua76c', '\ua76c'), ('\ua76e', '\ua76e'), ('\ua779', '\ua779'), ('\ua77b', '\ua77b'),
+        ('\ua77d', '\ua77e'), ('\ua780', '\ua780'), ('\ua782', '\ua782'), ('\ua784', '\ua784'),
+        ('\ua786', '\ua786'), ('\ua78b', '\ua78b'), ('\ua78d', '\ua78d'), ('\ua790', '\ua790'),
+        ('\ua792', '\ua792'), ('\ua796', '\ua796'), ('\ua798', '\ua798'), ('\ua79a', '\ua79a'),
+        ('\ua79c', '\ua79c'), ('\ua79e', '\ua79e'), ('\ua7a0', '\ua7a0'), ('\ua7a2', '\ua7a2'),
+        ('\ua7a4', '\ua7a4'), ('\ua7a6', '\ua7a6'), ('\ua7a8', '\ua7a8'), ('\ua7aa', '\ua7ae'),
+        ('\ua7b0', '\ua7b4'), ('\ua7b6', '\ua7b6'), ('\ua7b8', '\ua7b8'), ('\ua7ba', '\ua7ba'),
+        ('\ua7bc', '\ua7bc'), ('\ua7be', '\ua7be'), ('\ua7c0', '\ua7c0'), ('\ua7c2', '\ua7c2'),
+        ('\ua7c4', '\ua7c7'), ('\ua7c9', '\ua7c9'), ('\ua7d0', '\ua7d0'), ('\ua7d6', '\ua7d6'),
+        ('\ua7d8', '\ua7d8'), ('\ua7f5', '\ua7f5'), ('\uff21', '\uff3a'),
+        ('\U00010400', '\U00010427'), ('\U000104b0', '\U000104d3'), ('\U00010570', '\U0001057a'),
+        ('\U0001057c', '\U0001058a'), ('\U0001058c', '\U00010592'), ('\U00010594', '\U00010595'),
+        ('\U00010c80', '\U00010cb2'), ('\U000118a0', '\U000118bf'), ('\U00016e40', '\U00016e5f'),
+        ('\U0001d400', '\U0001d419'), ('\U0001d434', '\U0001d44d'), ('\U0001d468', '\U0001d481'),
+        ('\U0001d49c', '\U0001d49c'), ('\U0001d49e', '\U0001d49f'), ('\U0001d4a2', '\U0001d4a2'),
+        ('\U0001d4a5', '\U0001d4a6'), ('\U0001d4a9', '\U0001d4ac'), ('\U0001d4ae', '\U0001d4b5'),
+        ('\U0001d4d0', '\U0001d4e9'), ('\U0001d504', '\U0001d505'), ('\U0001d507', '\U0001d50a'),
+        ('\U0001d50d', '\U0001d514'), ('\U0001d516', '\U0001d51c'), ('\U0001d538', '\U0001d539'),
+        ('\U0001d53b', '\U0001d53e'), ('\U0001d540', '\U0001d544'), ('\U0001d546', '\U0001d546'),
+        ('\U0001d54a', '\U0001d550'), ('\U0001d56c', '\U0001d585'), ('\U0001d5a0', '\U0001d5b9'),
+        ('\U0001d5d4', '\U0001d5ed'), ('\U0001d608', '\U0001d621'), ('\U0001d63c', '\U0001d655'),
+        ('\U0001d670', '\U0001d689'), ('\U0001d6a8', '\U0001d6c0'), ('\U0001d6e2', '\U0001d6fa'),
+        ('\U0001d71c', '\U0001d734'), ('\U0001d756', '\U0001d76e'), ('\U0001d790', '\U0001d7a8'),
+        ('\U0001d7ca', '\U0001d7ca'), ('\U0001e900', '\U0001e921'), ('\U0001f130', '\U0001f149'),
+        ('\U0001f150', '\U0001f169'), ('\U0001f170', '\U0001f189')
+    ];
+
+    /// Return true if the character has the `Uppercase` property.
+    pub fn uppercase(c: char) -> bool {
+        super::bsearch_range_table(c, UPPERCASE_TABLE)
+    }
+}
+
+pub mod property {
+    static WHITE_SPACE_TABLE: &'static [(char, char)] = &[
+        ('\x09', '\x0d'), ('\x20', '\x20'), ('\u0085', '\u0085'), ('\u00a0', '\u00a0'),
+        ('\u1680', '\u1680'), ('\u2000', '\u200a'), ('\u2028', '\u2029'), ('\u202f', '\u202f'),
+        ('\u205f', '\u205f'), ('\u3000', '\u3000')
+    ];
+
+    /// Return true if the character has the `White_Space` property.
+    pub fn white_space(c: char) -> bool {
+        super::bsearch_range_table(c, WHITE_SPACE_TABLE)
+    }
+}
+
+pub mod conversions {
+    use option::Option;
+
+    static UPPERCASE_MAPPING_TABLE: &'static [(char, char)] = &[
+        ('\x61', '\x41'), ('\x62', '\x42'), ('\x63', '\x43'), ('\x64', '\x44'), ('\x65', '\x45'),
+        ('\x66', '\x46'), ('\x67', '\x47'), ('\x68', '\x48'), ('\x69', '\x49'), ('\x6a', '\x4a'),
+        ('\x6b', '\x4b'), ('\x6c', '\x4c'), ('\x6d', '\x4d'), ('\x6e', '\x4e'), ('\x6f', '\x4f'),
+        ('\x70', '\x50'), ('\x71', '\x51'), ('\x72', '\x52'), ('\x73', '\x53'), ('\x74', '\x54'),
+        ('\x75', '\x55'), ('\x76', '\x56'), ('\x77', '\x57'), ('\x78', '\x58'), ('\x79', '\x59'),
+        ('\x7a', '\x5a'), ('\u00b5', '\u039c'), ('\u00e0', '\u00c0'), ('\u00e1', '\u00c1'),
+        ('\u00e2', '\u00c2'), ('\u00e3', '\u00c3'), ('\u00e4', '\u00c4'), ('\u00e5', '\u00c5'),
+        ('\u00e6', '\u00c6'), ('\u00e7', '\u00c7'), ('\u00e8', '\u00c8'), ('\u00e9', '\u00c9'),
+        ('\u00ea', '\u00ca'), ('\u00eb', '\u00cb'), ('\u00ec', '\u00cc'), ('\u00ed', '\u00cd'),
+        ('\u00ee', '\u00ce'), ('\u00ef', '\u00cf'), ('\u00f0', '\u00d0'), ('\u00f1', '\u00d1'),
+        ('\u00f2', '\u00d2'), ('\u00f3', '\u00d3'), ('\u00f4', '\u00d4'), ('\u00f5', '\u00d5'),
+        ('\u00f6', '\u00d6'), ('\u00f8', '\u00d8'), ('\u00f9', '\u00d9'), ('\u00fa', '\u00da'),
+        ('\u00fb', '\u00db'), ('\u00fc', '\u00dc'), ('\u00fd', '\u00dd'), ('\u00fe', '\u00de'),
+        ('\u00ff', '\u0178'), ('\u0101', '\u0100'), ('\u0103', '\u0102'), ('\u0105', '\u0104'),
+        ('\u0107', '\u0106'), ('\u0109', '\u0108'), ('\u010b', '\u010a'), ('\u010d', '\u010c'),
+        ('\u010f', '\u010e'), ('\u0111', '\u0110'), ('\u0113', '\u0112'), ('\u0115', '\u0114'),
+        ('\u0117', '\u0116'), ('\u0119', '\u0118'), ('\u011b', '\u011a'), ('\u011d', '\u011c'),
+        ('\u011f', '\u011e'), ('\u0121', '\u0120'), ('\u0123', '\u0122'), ('\u0125', '\u0124'),
+        ('\u0127', '\u0126'), ('\u0129', '\u0128'), ('\u012b', '\u012a'), ('\u012d', '\u012c'),
+        ('\u012f', '\u012e'), ('\u0131', '\x49'), ('\u0133', '\u0132'), ('\u0135', '\u0134'),
+        ('\u0137', '\u0136'), ('\u013a', '\u0139'), ('\u013c', '\u013b'), ('\u013e', '\u013d'),
+        ('\u0140', '\u013f'), ('\u0142', '\u0141'), ('\u0144', '\u0143'), ('\u0146', '\u0145'),
+        ('\u0148', '\u0147'), ('\u014b', '\u014a'), ('\u014d', '\u014c'), ('\u014f', '\u014e'),
+        ('\u0151', '\u0150'), ('\u0153', '\u0152'), ('\u0155', '\u0154'), ('\u0157', '\u0156'),
+        ('\u0159', '\u0158'), ('\u015b', '\u015a'), ('\u015d', '\u015c'), ('\u015f', '\u015e'),
+        ('\u0161', '\u0160'), ('\u0163', '\u0162'), ('\u0165', '\u0164'), ('\u0167', '\u0166'),
+        ('\u0169', '\u0168'), ('\u016b', '\u016a'), ('\u016d', '\u016c'), ('\u016f', '\u016e'),
+        ('\u0171', '\u0170'), ('\u0173', '\u0172'), ('\u0175', '\u0174'), ('\u0177', '\u0176'),
+        ('\u017a', '\u0179'), ('\u017c', '\u017b'), ('\u017e', '\u017d'), ('\u017f', '\x53'),
+        ('\u0180', '\u0243'), ('\u0183', '\u0182'), ('\u0185', '\u0184'), ('\u0188', '\u0187'),
+        ('\u018c', '\u018b'), ('\u0192', '\u0191'), ('\u0195', '\u01f6'), ('\u0199', '\u0198'),
+        ('\u019a', '\u023d'), ('\u019e', '\u0220'), ('\u01a1', '\u01a0'), ('\u01a3', '\u01a2'),
+        ('\u01a5', '\u01a4'), ('\u01a8', '\u01a7'), ('\u01ad', '\u01ac'), ('\u01b0', '\u01af'),
+        ('\u01b4', '\u01b3'), ('\u01b6', '\u01b5'), ('\u01b9', '\u01b8'), ('\u01bd', '\u01bc'),
+        ('\u01bf', '\u01f7'), ('\u01c5', '\u01c4'), ('\u01c6', '\u01c4'), ('\u01c8', '\u01c7'),
+        ('\u01c9', '\u01c7'), ('\u01cb', '\u01ca'), ('\u01cc', '\u01ca'), ('\u01ce', '\u01cd'),
+        ('\u01d0', '\u01cf'), ('\u01d2', '\u01d1'), ('\u01d4', '\u01d3'), ('\u01d6', '\u01d5'),
+        ('\u01d8', '\u01d7'), ('\u01da', '\u01d9'), ('\u01dc', '\u01db'), ('\u01dd', '\u018e'),
+        ('\u01df', '\u01de'), ('\u01e1', '\u01e0'), ('\u01e3', '\u01e2'), ('\u01e5', '\u01e4'),
+        ('\u01e7', '\u01e6'), ('\u01e9', '\u01e8'), ('\u01eb', '\u01ea'), ('\u01ed', '\u01ec'),
+        ('\u01ef', '\u01ee'), ('\u01f2', '\u01f1'), ('\u01f3', '\u01f1'), ('\u01f5', '\u01f4'),
+        ('\u01f9', '\u01f8'), ('\u01fb', '\u01fa'), ('\u01fd', '\u01fc'), ('\u01ff', '\u01fe'),
+        ('\u0201', '\u0200'), ('\u0203', '\u0202'), ('\u0205', '\u0204'), ('\u0207', '\u0206'),
+        ('\u0209', '\u0208'), ('\u020b', '\u020a'), ('\u020d', '\u020c'), ('\u020f', '\u020e'),
+        ('\u0211', '\u0210'), ('\u0213', '\u0212'), ('\u0215', '\u0214'), ('\u0217', '\u0216'),
+        ('\u0219', '\u0218'), ('\u021b', '\u021a'), ('\u021d', '\u021c'), ('\u021f', '\u021e'),
+        ('\u0223', '\u0222'), ('\u0225', '\u0224'), ('\u0227', '\u0226'), ('\u0229', '\u0228'),
+        ('\u022b', '\u022a'), ('\u022d', '\u022c'), ('\u022f', '\u022e'), ('\u0231', '\u0230'),
+        ('\u0233', '\u0232'), ('\u023c', '\u023b'), ('\u023f', '\u2c7e'), ('\u0240', '\u2c7f'),
+        ('\u0242', '\u0241'), ('\u0247', '\u0246'), ('\u0249', '\u0248'), ('\u024b', '\u024a'),
+        ('\u024d', '\u024c'), ('\u024f', '\u024e'), ('\u0250', '\u2c6f'), ('\u0251', '\u2c6d'),
+        ('\u0252', '\u2c70'), ('\u0253', '\u0181'), ('\u0254', '\u0186'), ('\u0256', '\u0189'),
+        ('\u0257', '\u018a'), ('\u0259', '\u018f'), ('\u025b', '\u0190'), ('\u025c', '\ua7ab'),
+        ('\u0260', '\u0193'), ('\u0261', '\ua7ac'), ('\u0263', '\u0194'), ('\u0265', '\ua78d'),
+        ('\u0266', '\ua7aa'), ('\u0268', '\u0197'), ('\u0269', '\u0196'), ('\u026a', '\ua7ae'),
+        ('\u026b', '\u2c62'), ('\u026c', '\ua7ad'), ('\u026f', '\u019c'), ('\u0271', '\u2c6e'),
+        ('\u0272', '\u019d'), ('\u0275', '\u019f'), ('\u027d', '\u2c64'), ('\u0280', '\u01a6'),
+        ('\u0282', '\ua7c5'), ('\u0283', '\u01a9'), ('\u0287', '\ua7b1'), ('\u0288', '\u01ae'),
+        ('\u0289', '\u0244'), ('\u028a', '\u01b1'), ('\u028b', '\u01b2'), ('\u028c', '\u0245'),
+        ('\u0292', '\u01b7'), ('\u029d', '\ua7b2'), ('\u029e', '\ua7b0'), ('\u0345', '\u0399'),
+        ('\u0371', '\u0370'), ('\u0373', '\u0372'), ('\u0377', '\u0376'), ('\u037b', '\u03fd'),
+        ('\u037c', '\u03fe'), ('\u037d', '\u03ff'), ('\u03ac', '\u0386'), ('\u03ad', '\u0388'),
+        ('\u03ae', '\u0389'), ('\u03af', '\u038a'), ('\u03b1', '\u0391'), ('\u03b2', '\u0392'),
+        ('\u03b3', '\u0393'), ('\u03b4', '\u0394'), ('\u03b5', '\u0395'), ('\u03b6', '\u0396'),
+        ('\u03b7', '\u0397'), ('\u03b8', '\u0398'), ('\u03b9', '\u0399'), ('\u03ba', '\u039a'),
+        ('\u03bb', '\u039b'), ('\u03bc', '\u039c'), ('\u03bd', '\u039d'), ('\u03be', '\u039e'),
+        ('\u03bf', '\u039f'), ('\u03c0', '\u03a0'), ('\u03c1', '\u03a1'), ('\u03c2', '\u03a3'),
+        ('\u03c3', '\u03a3'), ('\u03c4', '\u03a4'), ('\u03c5', '\u03a5'), ('\u03c6', '\u03a6'),
+        ('\u03c7', '\u03a7'), ('\u03c8', '\u03a8'), ('\u03c9', '\u03a9'), ('\u03ca', '\u03aa'),
+        ('\u03cb', '\u03ab'), ('\u03cc', '\u038c'), ('\u03cd', '\u038e'), ('\u03ce', '\u038f'),
+        ('\u03d0', '\u0392'), ('\u03d1', '\u0398'), ('\u03d5', '\u03a6'), ('\u03d6', '\u03a0'),
+        ('\u03d7', '\u03cf'), ('\u03d9', '\u03d8'), ('\u03db', '\u03da'), ('\u03dd', '\u03dc'),
+        ('\u03df', '\u03de'), ('\u03e1', '\u03e0'), ('\u03e3', '\u03e2'), ('\u03e5', '\u03e4'),
+        ('\u03e7', '\u03e6'), ('\u03e9', '\u03e8'), ('\u03eb', '\u03ea'), ('\u03ed', '\u03ec'),
+        ('\u03ef', '\u03ee'), ('\u03f0', '\u039a'), ('\u03f1', '\u03a1'), ('\u03f2', '\u03f9'),
+        ('\u03f3', '\u037f'), ('\u03f5', '\u0395'), ('\u03f8', '\u03f7'), ('\u03fb', '\u03fa'),
+        ('\u0430', '\u0410'), ('\u0431', '\u0411'), ('\u0432', '\u0412'), ('\u0433', '\u0413'),
+        ('\u0434', '\u0414'), ('\u0435', '\u0415'), ('\u0436', '\u0416'), ('\u0437', '\u0417'),
+        ('\u0438', '\u0418'), ('\u0439', '\u0419'), ('\u043a', '\u041a'), ('\u043b', '\u041b'),
+        ('\u043c', '\u041c'), ('\u043d', '\u041d'), ('\u043e', '\u041e'), ('\u043f', '\u041f'),
+        ('\u0440', '\u0420'), ('\u0441', '\u0421'), ('\u0442', '\u0422'), ('\u0443', '\u0423'),
+        ('\u0444', '\u0424'), ('\u0445', '\u0425'), ('\u0446', '\u0426'), ('\u0447', '\u0427'),
+        ('\u0448', '\u0428'), ('\u0449', '\u0429'), ('\u044a', '\u042a'), ('\u044b', '\u042b'),
+        ('\u044c', '\u042c'), ('\u044d', '\u042d'), ('\u044e', '\u042e'), ('\u044f', '\u042f'),
+        ('\u0450', '\u0400'), ('\u0451', '\u0401'), ('\u0452', '\u0402'), ('\u0453', '\u0403'),
+        ('\u0454', '\u0404'), ('\u0455', '\u0405'), ('\u0456', '\u0406'), ('\u0457', '\u0407'),
+        ('\u0458', '\u0408'), ('\u0459', '\u0409'), ('\u045a', '\u040a'), ('\u045b', '\u040b'),
+        ('\u045c', '\u040c'), ('\u045d', '\u040d'), ('\u045e', '\u040e'), ('\u045f', '\u040f'),
+        ('\u0461', '\u0460'), ('\u0463', '\u0462'), ('\u0465', '\u0464'), ('\u0467', '\u0466'),
+        ('\u0469', '\u0468'), ('\u046b', '\u046a'), ('\u046d', '\u046c'), ('\u046f', '\u046e'),
+        ('\u0471', '\u0470'), ('\u0473', '\u0472'), ('\u0475', '\u0474'), ('\u0477', '\u0476'),
+        ('\u0479', '\u0478'), ('\u047b', '\u047a'), ('\u047d', '\u047c'), ('\u047f', '\u047e'),
+        ('\u0481', '\u0480'), ('\u048b', '\u048a'), ('\u048d', '\u048c'), ('\u048f', '\u048e'),
+        ('\u0491', '\u0490'), ('\u0493', '\u0492'), ('\u0495', '\u0494'), ('\u0497', '\u0496'),
+        ('\u0499', '\u0498'), ('\u049b', '\u049a'), ('\u049d', '\u049c'), ('\u049f', '\u049e'),
+        ('\u04a1', '\u04a0'), ('\u04a3', '\u04a2'), ('\u04a5', '\u04a4'), ('\u04a7', '\u04a6'),
+        ('\u04a9', '\u04a8'), ('\u04ab', '\u04aa'), ('\u04ad', '\u04ac'), ('\u04af', '\u04ae'),
+        ('\u04b1', '\u04b0'), ('\u04b3', '\u04b2'), ('\u04b5', '\u04b4'), ('\u04b7', '\u04b6'),
+        ('\u04b9', '\u04b8'), ('\u04bb', '\u04ba'), ('\u04bd', '\u04bc'), ('\u04bf', '\u04be'),
+        ('\u04c2', '\u04c1'), ('\u04c4', '\u04c3'), ('\u04c6', '\u04c5'), ('\u04c8', '\u04c7'),
+        ('\u04ca', '\u04c9'), ('\u04cc', '\u04cb'), ('\u04ce', '\u04cd'), ('\u04cf', '\u04c0'),
+        ('\u04d1', '\u04d0'), ('\u04d3', '\u04d2'), ('\u04d5', '\u04d4'), ('\u04d7', '\u04d6'),
+        ('\u04d9', '\u04d8'), ('\u04db', '\u04da'), ('\u04dd', '\u04dc'), ('\u04df', '\u04de'),
+        ('\u04e1', '\u04e0'), ('\u04e3', '\u04e2'), ('\u04e5', '\u04e4'), ('\u04e7', '\u04e6'),
+        ('\u04e9', '\u04e8'), ('\u04eb', '\u04ea'), ('\u04ed', '\u04ec'), ('\u04ef', '\u04ee'),
+        ('\u04f1', '\u04f0'), ('\u04f3', '\u04f2'), ('\u04f5', '\u04f4'), ('\u04f7', '\u04f6'),
+        ('\u04f9', '\u04f8'), ('\u04fb', '\u04fa'), ('\u04fd', '\u04fc'), ('\u04ff', '\u04fe'),
+        ('\u0501', '\u0500'), ('\u0503', '\u0502'), ('\u0505', '\u0504'), ('\u0507', '\u0506'),
+        ('\u0509', '\u0508'), ('\u050b', '\u050a'), ('\u050d', '\u050c'), ('\u050f', '\u050e'),
+        ('\u0511', '\u0510'), ('\u0513', '\u0512'), ('\u0515', '\u0514'), ('\u0517', '\u0516'),
+        ('\u0519', '\u0518'), ('\u051b', '\u051a'), ('\u051d', '\u051c'), ('\u051f', '\u051e'),
+        ('\u0521', '\u0520'), ('\u0523', '\u0522'), ('\u0525', '\u0524'), ('\u0527', '\u0526'),
+        ('\u0529', '\u0528'), ('\u052b', '\u052a'), ('\u052d', '\u052c'), ('\u052f', '\u052e'),
+        ('\u0561', '\u0531'), ('\u0562', '\u0532'), ('\u0563', '\u0533'), ('\u0564', '\u0534'),
+        ('\u0565', '\u0535'), ('\u0566', '\u0536'), ('\u0567', '\u0537'), ('\u0568', '\u0538'),
+        ('\u0569', '\u0539'), ('\u056a', '\u053a'), ('\u056b', '\u053b'), ('\u056c', '\u053c'),
+        ('\u056d', '\u053d'), ('\u056e', '\u053e'), ('\u056f', '\u053f'), ('\u0570', '\u0540'),
+        ('\u0571', '\u0541'), ('\u0572', '\u0542'), ('\u0573', '\u0543'), ('\u0574', '\u0544'),
+        ('\u0575', '\u0545'), ('\u0576', '\u0546'), ('\u0577', '\u0547'), ('\u0578', '\u0548'),
+        ('\u0579', '\u0549'), ('\u057a', '\u054a'), ('\u057b', '\u054b'), ('\u057c', '\u054c'),
+        ('\u057d', '\u054d'), ('\u057e', '\u054e'), ('\u057f', '\u054f'), ('\u0580', '\u0550'),
+        ('\u0581', '\u0551'), ('\u0582', '\u0552'), ('\u0583', '\u0553'), ('\u0584', '\u0554'),
+        ('\u0585', '\u0555'), ('\u0586', '\u0556'), ('\u10d0', '\u1c90'), ('\u10d1', '\u1c91'),
+        ('\u10d2', '\u1c92'), ('\u10d3', '\u1c93'), ('\u10d4', '\u1c94'), ('\u10d5', '\u1c95'),
+        ('\u10d6', '\u1c96'), ('\u10d7', '\u1c97'), ('\u10d8', '\u1c98'), ('\u10d9', '\u1c99'),
+        ('\u10da', '\u1c9a'), ('\u10db', '\u1c9b'), ('\u10dc', '\u1c9c'), ('\u10dd', '\u1c9d'),
+        ('\u10de', '\u1c9e'), ('\u10df', '\u1c9f'), ('\u10e0', '\u1ca0'), ('\u10e1', '\u1ca1'),
+        ('\u10e2', '\u1ca2'), ('\u10e3', '\u1ca3'), ('\u10e4', '\u1ca4'), ('\u10e5', '\u1ca5'),
+        ('\u10e6', '\u1ca6'), ('\u10e7', '\u1ca7'), ('\u10e8', '\u1ca8'), ('\u10e9', '\u1ca9'),
+        ('\u10ea', '\u1caa'), ('\u10eb', '\u1cab'), ('\u10ec', '\u1cac'), ('\u10ed', '\u1cad'),
+        ('\u10ee', '\u1cae'), ('\u10ef', '\u1caf'), ('\u10f0', '\u1cb0'), ('\u10f1', '\u1cb1'),
+        ('\u10f2', '\u1cb2'), ('\u10f3', '\u1cb3'), ('\u10f4', '\u1cb4'), ('\u10f5', '\u1cb5'),
+        ('\u10f6', '\u1cb6'), ('\u10f7', '\u1cb7'), ('\u10f8', '\u1cb8'), ('\u10f9', '\u1cb9'),
+        ('\u10fa', '\u1cba'), ('\u10fd', '\u1cbd'), ('\u10fe', '\u1cbe'), ('\u10ff', '\u1cbf'),
+        ('\u13f8', '\u13f0'), ('\u13f9', '\u13f1'), ('\u13fa', '\u13f2'), ('\u13fb', '\u13f3'),
+        ('\u13fc', '\u13f4'), ('\u13fd', '\u13f5'), ('\u1c80', '\u0412'), ('\u1c81', '\u0414'),
+        ('\u1c82', '\u041e'), ('\u1c83', '\u0421'), ('\u1c84', '\u0422'), ('\u1c85', '\u0422'),
+        ('\u1c86', '\u042a'), ('\u1c87', '\u0462'), ('\u1c88', '\ua64a'), ('\u1d79', '\ua77d'),
+        ('\u1d7d', '\u2c63'), ('\u1d8e', '\ua7c6'), ('\u1e01', '\u1e00'), ('\u1e03', '\u1e02'),
+        ('\u1e05', '\u1e04'), ('\u1e07', '\u1e06'), ('\u1e09', '\u1e08'), ('\u1e0b', '\u1e0a'),
+        ('\u1e0d', '\u1e0c'), ('\u1e0f', '\u1e0e'), ('\u1e11', '\u1e10'), ('\u1e13', '\u1e12'),
+        ('\u1e15', '\u1e14'), ('\u1e17', '\u1e16'), ('\u1e19', '\u1e18'), ('\u1e1b', '\u1e1a'),
+        ('\u1e1d', '\u1e1c'), ('\u1e1f', '\u1e1e'), ('\u1e21', '\u1e20'), ('\u1e23', '\u1e22'),
+        ('\u1e25', '\u1e24'), ('\u1e27', '\u1e26'), ('\u1e29', '\u1e28'), ('\u1e2b', '\u1e2a'),
+        ('\u1e2d', '\u1e2c'), ('\u1e2f', '\u1e2e'), ('\u1e31', '\u1e30'), ('\u1e33', '\u1e32'),
+        ('\u1e35', '\u1e34'), ('\u1e37', '\u1e36'), ('\u1e39', '\u1e38'), ('\u1e3b', '\u1e3a'),
+        ('\u1e3d', '\u1e3c'), ('\u1e3f', '\u1e3e'), ('\u1e41', '\u1e40'), ('\u1e43', '\u1e42'),
+        ('\u1e45', '\u1e44'), ('\u1e47', '\u1e46'), ('\u1e49', '\u1e48'), ('\u1e4b', '\u1e4a'),
+        ('\u1e4d', '\u1e4c'), ('\u1e4f', '\u1e4e'), ('\u1e51', '\u1e50'), ('\u1e53', '\u1e52'),
+        ('\u1e55', '\u1e54'), ('\u1e57', '\u1e56'), ('\u1e59', '\u1e58'), ('\u1e5b', '\u1e5a'),
+        ('\u1e5d', '\u1e5c'), ('\u1e5f', '\u1e5e'), ('\u1e61', '\u1e60'), ('\u1e63', '\u1e62'),
+        ('\u1e65', '\u1e64'), ('\u1e67', '\u1e66'), ('\u1e69', '\u1e68'), ('\u1e6b', '\u1e6a'),
+        ('\u1e6d', '\u1e6c'), ('\u1e6f', '\u1e6e'), ('\u1e71', '\u1e70'), ('\u1e73', '\u1e72'),
+        ('\u1e75', '\u1e74'), ('\u1e77', '\u1e76'), ('\u1e79', '\u1e78'), ('\u1e7b', '\u1e7a'),
+        ('\u1e7d', '\u1e7c'), ('\u1e7f', '\u1e7e'), ('\u1e81', '\u1e80'), ('\u1e83', '\u1e82'),
+        ('\u1e85', '\u1e84'), ('\u1e87', '\u1e86'), ('\u1e89', '\u1e88'), ('\u1e8b', '\u1e8a'),
+        ('\u1e8d', '\u1e8c'), ('\u1e8f', '\u1e8e'), ('\u1e91', '\u1e90'), ('\u1e93', '\u1e92'),
+        ('\u1e95', '\u1e94'), ('\u1e9b', '\u1e60'), ('\u1ea1', '\u1ea0'), ('\u1ea3', '\u1ea2'),
+        ('\u1ea5', '\u1ea4'), ('\u1ea7', '\u1ea6'), ('\u1ea9', '\u1ea8'), ('\u1eab', '\u1eaa'),
+        ('\u1ead', '\u1eac'), ('\u1eaf', '\u1eae'), ('\u1eb1', '\u1eb0'), ('\u1eb3', '\u1eb2'),
+        ('\u1eb5', '\u1eb4'), ('\u1eb7', '\u1eb6'), ('\u1eb9', '\u1eb8'), ('\u1ebb', '\u1eba'),
+        ('\u1ebd', '\u1ebc'), ('\u1ebf', '\u1ebe'), ('\u1ec1', '\u1ec0'), ('\u1ec3', '\u1ec2'),
+        ('\u1ec5', '\u1ec4'), ('\u1ec7', '\u1ec6'), ('\u1ec9', '\u1ec8'), ('\u1ecb', '\u1eca'),
+        ('\u1ecd', '\u1ecc'), ('\u1ecf', '\u1ece'), ('\u1ed1', '\u1ed0'), ('\u1ed3', '\u1ed2'),
+        ('\u1ed5', '\u1ed4'), ('\u1ed7', '\u1ed6'), ('\u1ed9', '\u1ed8'), ('\u1edb', '\u1eda'),
+        ('\u1edd', '\u1edc'), ('\u1edf', '\u1ede'), ('\u1ee1', '\u1ee0'), ('\u1ee3', '\u1ee2'),
+        ('\u1ee5', '\u1ee4'), ('\u1ee7', '\u1ee6'), ('\u1ee9', '\u1ee8'), ('\u1eeb', '\u1eea'),
+        ('\u1eed', '\u1eec'), ('\u1eef', '\u1eee'), ('\u1ef1', '\u1ef0'), ('\u1ef3', '\u1ef2'),
+        ('\u1ef5', '\u1ef4'), ('\u1ef7', '\u1ef6'), ('\u1ef9', '\u1ef8'), ('\u1efb', '\u1efa'),
+        ('\u1efd', '\u1efc'), ('\u1eff', '\u1efe'), ('\u1f00', '\u1f08'), ('\u1f01', '\u1f09'),
+        ('\u1f02', '\u1f0a'), ('\u1f03', '\u1f0b'), ('\u1f04', '\u1f0c'), ('\u1f05', '\u1f0d'),
+        ('\u1f06', '\u1f0e'), ('\u1f07', '\u1f0f'), ('\u1f10', '\u1f18'), ('\u1f11', '\u1f19'),
+        ('\u1f12', '\u1f1a'), ('\u1f13', '\u1f1b'), ('\u1f14', '\u1f1c'), ('\u1f15', '\u1f1d'),
+        ('\u1f20', '\u1f28'), ('\u1f21', '\u1f29'), ('\u1f22', '\u1f2a'), ('\u1f23', '\u1f2b'),
+        ('\u1f24', '\u1f2c'), ('\u1f25', '\u1f2d'), ('\u1f26', '\u1f2e'), ('\u1f27', '\u1f2f'),
+        ('\u1f30', '\u1f38'), ('\u1f31', '\u1f39'), ('\u1f32', '\u1f3a'), ('\u1f33', '\u1f3b'),
+        ('\u1f34', '\u1f3c'), ('\u1f35', '\u1f3d'), ('\u1f36', '\u1f3e'), ('\u1f37', '\u1f3f'),
+        ('\u1f40', '\u1f48'), ('\u1f41', '\u1f49'), ('\u1f42', '\u1f4a'), ('\u1f43', '\u1f4b'),
+        ('\u1f44', '\u1f4c'), ('\u1f45', '\u1f4d'), ('\u1f51', '\u1f59'), ('\u1f53', '\u1f5b'),
+        ('\u1f55', '\u1f5d'), ('\u1f57', '\u1f5f'), ('\u1f60', '\u1f68'), ('\u1f61', '\u1f69'),
+        ('\u1f62', '\u1f6a'), ('\u1f63', '\u1f6b'), ('\u1f64', '\u1f6c'), ('\u1f65', '\u1f6d'),
+        ('\u1f66', '\u1f6e'), ('\u1f67', '\u1f6f'), ('\u1f70', '\u1fba'), ('\u1f71', '\u1fbb'),
+        ('\u1f72', '\u1fc8'), ('\u1f73', '\u1fc9'), ('\u1f74', '\u1fca'), ('\u1f75', '\u1fcb'),
+        ('\u1f76', '\u1fda'), ('\u1f77', '\u1fdb'), ('\u1f78', '\u1ff8'), ('\u1f79', '\u1ff9'),
+        ('\u1f7a', '\u1fea'), ('\u1f7b', '\u1feb'), ('\u1f7c', '\u1ffa'), ('\u1f7d', '\u1ffb'),
+        ('\u1f80', '\u1f88'), ('\u1f81', '\u1f89'), ('\u1f82', '\u1f8a'), ('\u1f83', '\u1f8b'),
+        ('\u1f84', '\u1f8c'), ('\u1f85', '\u1f8d'), ('\u1f86', '\u1f8e'), ('\u1f87', '\u1f8f'),
+        ('\u1f90', '\u1f98'), ('\u1f91', '\u1f99'), ('\u1f92', '\u1f9a'), ('\u1f93', '\u1f9b'),
+        ('\u1f94', '\u1f9c'), ('\u1f95', '\u1f9d'), ('\u1f96', '\u1f9e'), ('\u1f97', '\u1f9f'),
+        ('\u1fa0', '\u1fa8'), ('\u1fa1', '\u1fa9'), ('\u1fa2', '\u1faa'), ('\u1fa3', '\u1fab'),
+        ('\u1fa4', '\u1fac'), ('\u1fa5', '\u1fad'), ('\u1fa6', '\u1fae'), ('\u1fa7', '\u1faf'),
+        ('\u1fb0', '\u1fb8'), ('\u1fb1', '\u1fb9'), ('\u1fb3', '\u1fbc'), ('\u1fbe', '\u0399'),
+        ('\u1fc3', '\u1fcc'), ('\u1fd0', '\u1fd8'), ('\u1fd1', '\u1fd9'), ('\u1fe0', '\u1fe8'),
+        ('\u1fe1', '\u1fe9'), ('\u1fe5', '\u1fec'), ('\u1ff3', '\u1ffc'), ('\u214e', '\u2132'),
+        ('\u2170', '\u2160'), ('\u2171', '\u2161'), ('\u2172', '\u2162'), ('\u2173', '\u2163'),
+        ('\u2174', '\u2164'), ('\u2175', '\u2165'), ('\u2176', '\u2166'), ('\u2177', '\u2167'),
+        ('\u2178', '\u2168'), ('\u2179', '\u2169'), ('\u217a', '\u216a'), ('\u217b', '\u216b'),
+        ('\u217c', '\u216c'), ('\u217d', '\u216d'), ('\u217e', '\u216e'), ('\u217f', '\u216f'),
+        ('\u2184', '\u2183'), ('\u24d0', '\u24b6'), ('\u24d1', '\u24b7'), ('\u24d2', '\u24b8'),
+        ('\u24d3', '\u24b9'), ('\u24d4', '\u24ba'), ('\u24d5', '\u24bb'), ('\u24d6', '\u24bc'),
+        ('\u24d7', '\u24bd'), ('\u24d8', '\u24be'), ('\u24d9', '\u24bf'), ('\u24da', '\u24c0'),
+        ('\u24db', '\u24c1'), ('\u24dc', '\u24c2'), ('\u24dd', '\u24c3'), ('\u24de', '\u24c4'),
+        ('\u24df', '\u24c5'), ('\u24e0', '\u24c6'), ('\u24e1', '\u24c7'), ('\u24e2', '\u24c8'),
+        ('\u24e3', '\u24c9'), ('\u24e4', '\u24ca'), ('\u24e5', '\u24cb'), ('\u24e6', '\u24cc'),
+        ('\u24e7', '\u24cd'), ('\u24e8', '\u24ce'), ('\u24e9', '\u24cf'), ('\u2c30', '\u2c00'),
+        ('\u2c31', '\u2c01'), ('\u2c32', '\u2c02'), ('\u2c33', '\u2c03'), ('\u2c34', '\u2c04'),
+        ('\u2c35', '\u2c05'), ('\u2c36', '\u2c06'), ('\u2c37', '\u2c07'), ('\u2c38', '\u2c08'),
+        ('\u2c39', '\u2c09'), ('\u2c3a', '\u2c0a'), ('\u2c3b', '\u2c0b'), ('\u2c3c', '\u2c0c'),
+        ('\u2c3d', '\u2c0d'), ('\u2c3e', '\u2c0e'), ('\u2c3f', '\u2c0f'), ('\u2c40', '\u2c10'),
+        ('\u2c41', '\u2c11'), ('\u2c42', '\u2c12'), ('\u2c43', '\u2c13'), ('\u2c44', '\u2c14'),
+        ('\u2c45', '\u2c15'), ('\u2c46', '\u2c16'), ('\u2c47', '\u2c17'), ('\u2c48', '\u2c18'),
+        ('\u2c49', '\u2c19'), ('\u2c4a', '\u2c1a'), ('\u2c4b', '\u2c1b'), ('\u2c4c', '\u2c1c'),
+        ('\u2c4d', '\u2c1d'), ('\u2c4e', '\u2c1e'), ('\u2c4f', '\u2c1f'), ('\u2c50', '\u2c20'),
+        ('\u2c51', '\u2c21'), ('\u2c52', '\u2c22'), ('\u2c53', '\u2c23'), ('\u2c54', '\u2c24'),
+        ('\u2c55', '\u2c25'), ('\u2c56', '\u2c26'), ('\u2c57', '\u2c27'), ('\u2c58', '\u2c28'),
+        ('\u2c59', '\u2c29'), ('\u2c5a', '\u2c2a'), ('\u2c5b', '\u2c2b'), ('\u2c5c', '\u2c2c'),
+        ('\u2c5d', '\u2c2d'), ('\u2c5e', '\u2c2e'), ('\u2c5f', '\u2c2f'), ('\u2c61', '\u2c60'),
+        ('\u2c65', '\u023a'), ('\u2c66', '\u023e'), ('\u2c68', '\u2c67'), ('\u2c6a', '\u2c69'),
+        ('\u2c6c', '\u2c6b'), ('\u2c73', '\u2c72'), ('\u2c76', '\u2c75'), ('\u2c81', '\u2c80'),
+        ('\u2c83', '\u2c82'), ('\u2c85', '\u2c84'), ('\u2c87', '\u2c86'), ('\u2c89', '\u2c88'),
+        ('\u2c8b', '\u2c8a'), ('\u2c8d', '\u2c8c'), ('\u2c8f', '\u2c8e'), ('\u2c91', '\u2c90'),
+        ('\u2c93', '\u2c92'), ('\u2c95', '\u2c94'), ('\u2c97', '\u2c96'), ('\u2c99', '\u2c98'),
+        ('\u2c9b', '\u2c9a'), ('\u2c9d', '\u2c9c'), ('\u2c9f', '\u2c9e'), ('\u2ca1', '\u2ca0'),
+        ('\u2ca3', '\u2ca2'), ('\u2ca5', '\u2ca4'), ('\u2ca7', '\u2ca6'), ('\u2ca9', '\u2ca8'),
+        ('\u2cab', '\u2caa'), ('\u2cad', '\u2cac'), ('\u2caf', '\u2cae'), ('\u2cb1', '\u2cb0'),
+        ('\u2cb3', '\u2cb2'), ('\u2cb5', '\u2cb4'), ('\u2cb7', '\u2cb6'), ('\u2cb9', '\u2cb8'),
+        ('\u2cbb', '\u2cba'), ('\u2cbd', '\u2cbc'), ('\u2cbf', '\u2cbe'), ('\u2cc1', '\u2cc0'),
+        ('\u2cc3', '\u2cc2'), ('\u2cc5', '\u2cc4'), ('\u2cc7', '\u2cc6'), ('\u2cc9', '\u2cc8'),
+        ('\u2ccb', '\u2cca'), ('\u2ccd', '\u2ccc'), ('\u2ccf', '\u2cce'), ('\u2cd1', '\u2cd0'),
+        ('\u2cd3', '\u2cd2'), ('\u2cd5', '\u2cd4'), ('\u2cd7', '\u2cd6'), ('\u2cd9', '\u2cd8'),
+        ('\u2cdb', '\u2cda'), ('\u2cdd', '\u2cdc'), ('\u2cdf', '\u2cde'), ('\u2ce1', '\u2ce0'),
+        ('\u2ce3', '\u2ce2'), ('\u2cec', '\u2ceb'), ('\u2cee', '\u2ced'), ('\u2cf3', '\u2cf2'),
+        ('\u2d00', '\u10a0'), ('\u2d01', '\u10a1'), ('\u2d02', '\u10a2'), ('\u2d03', '\u10a3'),
+        ('\u2d04', '\u10a4'), ('\u2d05', '\u10a5'), ('\u2d06', '\u10a6'), ('\u2d07', '\u10a7'),
+        ('\u2d08', '\u10a8'), ('\u2d09', '\u10a9'), ('\u2d0a', '\u10aa'), ('\u2d0b', '\u10ab'),
+        ('\u2d0c', '\u10ac'), ('\u2d0d', '\u10ad'), ('\u2d0e', '\u10ae'), ('\u2d0f', '\u10af'),
+        ('\u2d10', '\u10b0'), ('\u2d11', '\u10b1'), ('\u2d12', '\u10b2'), ('\u2d13', '\u10b3'),
+        ('\u2d14', '\u10b4'), ('\u2d15', '\u10b5'), ('\u2d16', '\u10b6'), ('\u2d17', '\u10b7'),
+        ('\u2d18', '\u10b8'), ('\u2d19', '\u10b9'), ('\u2d1a', '\u10ba'), ('\u2d1b', '\u10bb'),
+        ('\u2d1c', '\u10bc'), ('\u2d1d', '\u10bd'), ('\u2d1e', '\u10be'), ('\u2d1f', '\u10bf'),
+        ('\u2d20', '\u10c0'), ('\u2d21', '\u10c1'), ('\u2d22', '\u10c2'), ('\u2d23', '\u10c3'),
+        ('\u2d24', '\u10c4'), ('\u2d25', '\u10c5'), ('\u2d27', '\u10c7'), ('\u2d2d', '\u10cd'),
+        ('\ua641', '\ua640'), ('\ua643', '\ua642'), ('\ua645', '\ua644'), ('\ua647', '\ua646'),
+        ('\ua649', '\ua648'), ('\ua64b', '\ua64a'), ('\ua64d', '\ua64c'), ('\ua64f', '\ua64e'),
+        ('\ua651', '\ua650'), ('\ua653', '\ua652'), ('\ua655', '\ua654'), ('\ua657', '\ua656'),
+        ('\ua659', '\ua658'), ('\ua65b', '\ua65a'), ('\ua65d', '\ua65c'), ('\ua65f', '\ua65e'),
+        ('\ua661', '\ua660'), ('\ua663', '\ua662'), ('\ua665', '\ua664'), ('\ua667', '\ua666'),
+        ('\ua669', '\ua668'), ('\ua66b', '\ua66a'), ('\ua66d', '\ua66c'), ('\ua681', '\ua680'),
+        ('\ua683', '\ua682'), ('\ua685', '\ua684'), ('\ua687', '\ua686'), ('\ua689', '\ua688'),
+        ('\ua68b', '\ua68a'), ('\ua68d', '\ua68c'), ('\ua68f', '\ua68e'), ('\ua691', '\ua690'),
+        ('\ua693', '\ua692'), ('\ua695', '\ua694'), ('\ua697', '\ua696'), ('\ua699', '\ua698'),
+        ('\ua69b', '\ua69a'), ('\ua723', '\ua722'), ('\ua725', '\ua724'), ('\ua727', '\ua726'),
+        ('\ua729', '\ua728'), ('\ua72b', '\ua72a'), ('\ua72d', '\ua72c'), ('\ua72f', '\ua72e'),
+        ('\ua733', '\ua732'), ('\ua735', '\ua734'), ('\ua737', '\ua736'), ('\ua739', '\ua738'),
+        ('\ua73b', '\ua73a'), ('\ua73d', '\ua73c'), ('\ua73f', '\ua73e'), ('\ua741', '\ua740'),
+        ('\ua743', '\ua742'), ('\ua745', '\ua744'), ('\ua747', '\ua746'), ('\ua749', '\ua748'),
+        ('\ua74b', '\ua74a'), ('\ua74d', '\ua74c'), ('\ua74f', '\ua74e'), ('\ua751', '\ua750'),
+        ('\ua753', '\ua752'), ('\ua755', '\ua754'), ('\ua757', '\ua756'), ('\ua759', '\ua758'),
+        ('\ua75b', '\ua75a'), ('\ua75d', '\ua75c'), ('\ua75f', '\ua75e'), ('\ua761', '\ua760'),
+        ('\ua763', '\ua762'), ('\ua765', '\ua764'), ('\ua767', '\ua766'), ('\ua769', '\ua768'),
+        ('\ua76b', '\ua76a'), ('\ua76d', '\ua76c'), ('\ua76f', '\ua76e'), ('\ua77a', '\ua779'),
+        ('\ua77c', '\ua77b'), ('\ua77f', '\ua77e'), ('\ua781', '\ua780'), ('\ua783', '\ua782'),
+        ('\ua785', '\ua784'), ('\ua787', '\ua786'), ('\ua78c', '\ua78b'), ('\ua791', '\ua790'),
+        ('\ua793', '\ua792'), ('\ua794', '\ua7c4'), ('\ua797', '\ua796'), ('\ua799', '\ua798'),
+        ('\ua79b', '\ua79a'), ('\ua79d', '\ua79c'), ('\ua79f', '\ua79e'), ('\ua7a1', '\ua7a0'),
+        ('\ua7a3', '\ua7a2'), ('\ua7a5', '\ua7a4'), ('\ua7a7', '\ua7a6'), ('\ua7a9', '\ua7a8'),
+        ('\ua7b5', '\ua7b4'), ('\ua7b7', '\ua7b6'), ('\ua7b9', '\ua7b8'), ('\ua7bb', '\ua7ba'),
+        ('\ua7bd', '\ua7bc'), ('\ua7bf', '\ua7be'), ('\ua7c1', '\ua7c0'), ('\ua7c3', '\ua7c2'),
+        ('\ua7c8', '\ua7c7'), ('\ua7ca', '\ua7c9'), ('\ua7d1', '\ua7d0'), ('\ua7d7', '\ua7d6'),
+        ('\ua7d9', '\ua7d8'), ('\ua7f6', '\ua7f5'), ('\uab53', '\ua7b3'), ('\uab70', '\u13a0'),
+        ('\uab71', '\u13a1'), ('\uab72', '\u13a2'), ('\uab73', '\u13a3'), ('\uab74', '\u13a4'),
+        ('\uab75', '\u13a5'), ('\uab76', '\u13a6'), ('\uab77', '\u13a7'), ('\uab78', '\u13a8'),
+        ('\uab79', '\u13a9'), ('\uab7a', '\u13aa'), ('\uab7b', '\u13ab'), ('\uab7c', '\u13ac'),
+        ('\uab7d', '\u13ad'), ('\uab7e', '\u13ae'), ('\uab7f', '\u13af'), ('\uab80', '\u13b0'),
+        ('\uab81', '\u13b1'), ('\uab82', '\u13b2'), ('\uab83', '\u13b3'), ('\uab84', '\u13b4'),
+        ('\uab85', '\u13b5'), ('\uab86', '\u13b6'), ('\uab87', '\u13b7'), ('\uab88', '\u13b8'),
+        ('\uab89', '\u13b9'), ('\uab8a', '\u13ba'), ('\uab8b', '\u13bb'), ('\uab8c', '\u13bc'),
+        ('\uab8d', '\u13bd'), ('\uab8e', '\u13be'), ('\uab8f', '\u13bf'), ('\uab90', '\u13c0'),
+        ('\uab91', '\u13c1'), ('\uab92', '\u13c2'), ('\uab93', '\u13c3'), ('\uab94', '\u13c4'),
+        ('\uab95', '\u13c5'), ('\uab96', '\u13c6'), ('\uab97', '\u13c7'), ('\uab98', '\u13c8'),
+        ('\uab99', '\u13c9'), ('\uab9a', '\u13ca'), ('\uab9b', '\u13cb'), ('\uab9c', '\u13cc'),
+        ('\uab9d', '\u13cd'), ('\uab9e', '\u13ce'), ('\uab9f', '\u13cf'), ('\uaba0', '\u13d0'),
+        ('\uaba1', '\u13d1'), ('\uaba2', '\u13d2'), ('\uaba3', '\u13d3'), ('\uaba4', '\u13d4'),
+        ('\uaba5', '\u13d5'), ('\uaba6', '\u13d6'), ('\uaba7', '\u13d7'), ('\uaba8', '\u13d8'),
+        ('\uaba9', '\u13d9'), ('\uabaa', '\u13da'), ('\uabab', '\u13db'), ('\uabac', '\u13dc'),
+        ('\uabad', '\u13dd'), ('\uabae', '\u13de'), ('\uabaf', '\u13df'), ('\uabb0', '\u13e0'),
+        ('\uabb1', '\u13e1'), ('\uabb2', '\u13e2'), ('\uabb3', '\u13e3'), ('\uabb4', '\u13e4'),
+        ('\uabb5', '\u13e5'), ('\uabb6', '\u13e6'), ('\uabb7', '\u13e7'), ('\uabb8', '\u13e8'),
+        ('\uabb9', '\u13e9'), ('\uabba', '\u13ea'), ('\uabbb', '\u13eb'), ('\uabbc', '\u13ec'),
+        ('\uabbd', '\u13ed'), ('\uabbe', '\u13ee'), ('\uabbf', '\u13ef'), ('\uff41', '\uff21'),
+        ('\uff42', '\uff22'), ('\uff43', '\uff23'), ('\uff44', '\uff24'), ('\uff45', '\uff25'),
+        ('\uff46', '\uff26'), ('\uff47', '\uff27'), ('\uff48', '\uff28'), ('\uff49', '\uff29'),
+        ('\uff4a', '\uff2a'), ('\uff4b', '\uff2b'), ('\uff4c', '\uff2c'), ('\uff4d', '\uff2d'),
+        ('\uff4e', '\uff2e'), ('\uff4f', '\uff2f'), ('\uff50', '\uff30'), ('\uff51', '\uff31'),
+        ('\uff52', '\uff32'), ('\uff53', '\uff33'), ('\uff54', '\uff34'), ('\uff55', '\uff35'),
+        ('\uff56', '\uff36'), ('\uff57', '\uff37'), ('\uff58', '\uff38'), ('\uff59', '\uff39'),
+        ('\uff5a', '\uff3a'), ('\U00010428', '\U00010400'), ('\U00010429', '\U00010401'),
+        ('\U0001042a', '\U00010402'), ('\U0001042b', '\U00010403'), ('\U0001042c', '\U00010404'),
+        ('\U0001042d', '\U00010405'), ('\U0001042e', '\U00010406'), ('\U0001042f', '\U00010407'),
+        ('\U00010430', '\U00010408'), ('\U00010431', '\U00010409'), ('\U00010432', '\U0001040a'),
+        ('\U00010433', '\U0001040b'), ('\U00010434', '\U0001040c'), ('\U00010435', '\U0001040d'),
+        ('\U00010436', '\U0001040e'), ('\U00010437', '\U0001040f'), ('\U00010438', '\U00010410'),
+        ('\U00010439', '\U00010411'), ('\U0001043a', '\U00010412'), ('\U0001043b', '\U00010413'),
+        ('\U0001043c', '\U00010414'), ('\U0001043d', '\U00010415'), ('\U0001043e', '\U00010416'),
+        ('\U0001043f', '\U00010417'), ('\U00010440', '\U00010418'), ('\U00010441', '\U00010419'),
+        ('\U00010442', '\U0001041a'), ('\U00010443', '\U0001041b'), ('\U00010444', '\U0001041c'),
+        ('\U00010445', '\U0001041d'), ('\U00010446', '\U0001041e'), ('\U00010447', '\U0001041f'),
+        ('\U00010448', '\U00010420'), ('\U00010449', '\U00010421'), ('\U0001044a', '\U00010422'),
+        ('\U0001044b', '\U00010423'), ('\U0001044c', '\U00010424'), ('\U0001044d', '\U00010425'),
+        ('\U0001044e', '\U00010426'), ('\U0001044f', '\U00010427'), ('\U000104d8', '\U000104b0'),
+        ('\U000104d9', '\U000104b1'), ('\U000104da', '\U000104b2'), ('\U000104db', '\U000104b3'),
+        ('\U000104dc', '\U000104b4'), ('\U000104dd', '\U000104b5'), ('\U000104de', '\U000104b6'),
+        ('\U000104df', '\U000104b7'), ('\U000104e0', '\U000104b8'), ('\U000104e1', '\U000104b9'),
+        ('\U000104e2', '\U000104ba'), ('\U000104e3', '\U000104bb'), ('\U000104e4', '\U000104bc'),
+        ('\U000104e5', '\U000104bd'), ('\U000104e6', '\U000104be'), ('\U000104e7', '\U000104bf'),
+        ('\U000104e8', '\U000104c0'), ('\U000104e9', '\U000104c1'), ('\U000104ea', '\U000104c2'),
+        ('\U000104eb', '\U000104c3'), ('\U000104ec', '\U000104c4'), ('\U000104ed', '\U000104c5'),
+        ('\U000104ee', '\U000104c6'), ('\U000104ef', '\U000104c7'), ('\U000104f0', '\U000104c8'),
+        ('\U000104f1', '\U000104c9'), ('\U000104f2', '\U000104ca'), ('\U000104f3', '\U000104cb'),
+        ('\U000104f4', '\U000104cc'), ('\U000104f5', '\U000104cd'), ('\U000104f6', '\U000104ce'),
+        ('\U000104f7', '\U000104cf'), ('\U000104f8', '\U000104d0'), ('\U000104f9', '\U000104d1'),
+        ('\U000104fa', '\U000104d2'), ('\U000104fb', '\U000104d3'), ('\U00010597', '\U00010570'),
+        ('\U00010598', '\U00010571'), ('\U00010599', '\U00010572'), ('\U0001059a', '\U00010573'),
+        ('\U0001059b', '\U00010574'), ('\U0001059c', '\U00010575'), ('\U0001059d', '\U00010576'),
+        ('\U0001059e', '\U00010577'), ('\U0001059f', '\U00010578'), ('\U000105a0', '\U00010579'),
+        ('\U000105a1', '\U0001057a'), ('\U000105a3', '\U0001057c'), ('\U000105a4', '\U0001057d'),
+        ('\U000105a5', '\U0001057e'), ('\U000105a6', '\U0001057f'), ('\U000105a7', '\U00010580'),
+        ('\U000105a8', '\U00010581'), ('\U000105a9', '\U00010582'), ('\U000105aa', '\U00010583'),
+        ('\U000105ab', '\U00010584'), ('\U000105ac', '\U00010585'), ('\U000105ad', '\U00010586'),
+        ('\U000105ae', '\U00010587'), ('\U000105af', '\U00010588'), ('\U000105b0', '\U00010589'),
+        ('\U000105b1', '\U0001058a'), ('\U000105b3', '\U0001058c'), ('\U000105b4', '\U0001058d'),
+        ('\U000105b5', '\U0001058e'), ('\U000105b6', '\U0001058f'), ('\U000105b7', '\U00010590'),
+        ('\U000105b8', '\U00010591'), ('\U000105b9', '\U00010592'), ('\U000105bb', '\U00010594'),
+        ('\U000105bc', '\U00010595'), ('\U00010cc0', '\U00010c80'), ('\U00010cc1', '\U00010c81'),
+        ('\U00010cc2', '\U00010c82'), ('\U00010cc3', '\U00010c83'), ('\U00010cc4', '\U00010c84'),
+        ('\U00010cc5', '\U00010c85'), ('\U00010cc6', '\U00010c86'), ('\U00010cc7', '\U00010c87'),
+        ('\U00010cc8', '\U00010c88'), ('\U00010cc9', '\U00010c89'), ('\U00010cca', '\U00010c8a'),
+        ('\U00010ccb', '\U00010c8b'), ('\U00010ccc', '\U00010c8c'), ('\U00010ccd', '\U00010c8d'),
+        ('\U00010cce', '\U00010c8e'), ('\U00010ccf', '\U00010c8f'), ('\U00010cd0', '\U00010c90'),
+        ('\U00010cd1', '\U00010c91'), ('\U00010cd2', '\U00010c92'), ('\U00010cd3', '\U00010c93'),
+        ('\U00010cd4', '\U00010c94'), ('\U00010cd5', '\U00010c95'), ('\U00010cd6', '\U00010c96'),
+        ('\U00010cd7', '\U00010c97'), ('\U00010cd8', '\U00010c98'), ('\U00010cd9', '\U00010c99'),
+        ('\U00010cda', '\U00010c9a'), ('\U00010cdb', '\U00010c9b'), ('\U00010cdc', '\U00010c9c'),
+        ('\U00010cdd', '\U00010c9d'), ('\U00010cde', '\U00010c9e'), ('\U00010cdf', '\U00010c9f'),
+        ('\U00010ce0', '\U00010ca0'), ('\U00010ce1', '\U00010ca1'), ('\U00010ce2', '\U00010ca2'),
+        ('\U00010ce3', '\U00010ca3'), ('\U00010ce4', '\U00010ca4'), ('\U00010ce5', '\U00010ca5'),
+        ('\U00010ce6', '\U00010ca6'), ('\U00010ce7', '\U00010ca7'), ('\U00010ce8', '\U00010ca8'),
+        ('\U00010ce9', '\U00010ca9'), ('\U00010cea', '\U00010caa'), ('\U00010ceb', '\U00010cab'),
+        ('\U00010cec', '\U00010cac'), ('\U00010ced', '\U00010cad'), ('\U00010cee', '\U00010cae'),
+        ('\U00010cef', '\U00010caf'), ('\U00010cf0', '\U00010cb0'), ('\U00010cf1', '\U00010cb1'),
+        ('\U00010cf2', '\U00010cb2'), ('\U000118c0', '\U000118a0'), ('\U000118c1', '\U000118a1'),
+        ('\U000118c2', '\U000118a2'), ('\U000118c3', '\U000118a3'), ('\U000118c4', '\U000118a4'),
+        ('\U000118c5', '\U000118a5'), ('\U000118c6', '\U000118a6'), ('\U000118c7', '\U000118a7'),
+        ('\U000118c8', '\U000118a8'), ('\U000118c9', '\U000118a9'), ('\U000118ca', '\U000118aa'),
+        ('\U000118cb', '\U000118ab'), ('\U000118cc', '\U000118ac'), ('\U000118cd', '\U000118ad'),
+        ('\U000118ce', '\U000118ae'), ('\U000118cf', '\U000118af'), ('\U000118d0', '\U000118b0'),
+        ('\U000118d1', '\U000118b1'), ('\U000118d2', '\U000118b2'), ('\U000118d3', '\U000118b3'),
+        ('\U000118d4', '\U000118b4'), ('\U000118d5', '\U000118b5'), ('\U000118d6', '\U000118b6'),
+        ('\U000118d7', '\U000118b7'), ('\U000118d8', '\U000118b8'), ('\U000118d9', '\U000118b9'),
+        ('\U000118da', '\U000118ba'), ('\U000118db', '\U000118bb'), ('\U000118dc', '\U000118bc'),
+        ('\U000118dd', '\U000118bd'), ('\U000118de', '\U000118be'), ('\U000118df', '\U000118bf'),
+        ('\U00016e60', '\U00016e40'), ('\U00016e61', '\U00016e41'), ('\U00016e62', '\U00016e42'),
+        ('\U00016e63', '\U00016e43'), ('\U00016e64', '\U00016e44'), ('\U00016e65', '\U00016e45'),
+        ('\U00016e66', '\U00016e46'), ('\U00016e67', '\U00016e47'), ('\U00016e68', '\U00016e48'),
+        ('\U00016e69', '\U00016e49'), ('\U00016e6a', '\U00016e4a'), ('\U00016e6b', '\U00016e4b'),
+        ('\U00016e6c', '\U00016e4c'), ('\U00016e6d', '\U00016e4d'), ('\U00016e6e', '\U00016e4e'),
+        ('\U00016e6f', '\U00016e4f'), ('\U00016e70', '\U00016e50'), ('\U00016e71', '\U00016e51'),
+        ('\U00016e72', '\U00016e52'), ('\U00016e73', '\U00016e53'), ('\U00016e74', '\U00016e54'),
+        ('\U00016e75', '\U00016e55'), ('\U00016e76', '\U00016e56'), ('\U00016e77', '\U00016e57'),
+        ('\U00016e78', '\U00016e58'), ('\U00016e79', '\U00016e59'), ('\U00016e7a', '\U00016e5a'),
+        ('\U00016e7b', '\U00016e5b'), ('\U00016e7c', '\U00016e5c'), ('\U00016e7d', '\U00016e5d'),
+        ('\U00016e7e', '\U00016e5e'), ('\U00016e7f', '\U00016e5f'), ('\U0001e922', '\U0001e900'),
+        ('\U0001e923', '\U0001e901'), ('\U0001e924', '\U0001e902'), ('\U0001e925', '\U0001e903'),
+        ('\U0001e926', '\U0001e904'), ('\U0001e927', '\U0001e905'), ('\U0001e928', '\U0001e906'),
+        ('\U0001e929', '\U0001e907'), ('\U0001e92a', '\U0001e908'), ('\U0001e92b', '\U0001e909'),
+        ('\U0001e92c', '\U0001e90a'), ('\U0001e92d', '\U0001e90b'), ('\U0001e92e', '\U0001e90c'),
+        ('\U0001e92f', '\U0001e90d'), ('\U0001e930', '\U0001e90e'), ('\U0001e931', '\U0001e90f'),
+        ('\U0001e932', '\U0001e910'), ('\U0001e933', '\U0001e911'), ('\U0001e934', '\U0001e912'),
+        ('\U0001e935', '\U0001e913'), ('\U0001e936', '\U0001e914'), ('\U0001e937', '\U0001e915'),
+        ('\U0001e938', '\U0001e916'), ('\U0001e939', '\U0001e917'), ('\U0001e93a', '\U0001e918'),
+        ('\U0001e93b', '\U0001e919'), ('\U0001e93c', '\U0001e91a'), ('\U0001e93d', '\U0001e91b'),
+        ('\U0001e93e', '\U0001e91c'), ('\U0001e93f', '\U0001e91d'), ('\U0001e940', '\U0001e91e'),
+        ('\U0001e941', '\U0001e91f'), ('\U0001e942', '\U0001e920'), ('\U0001e943', '\U0001e921')
+    ];
+
+    static LOWERCASE_MAPPING_TABLE: &'static [(char, char)] = &[
+        ('\x41', '\x61'), ('\x42', '\x62'), ('\x43', '\x63'), ('\x44', '\x64'), ('\x45', '\x65'),
+        ('\x46', '\x66'), ('\x47', '\x67'), ('\x48', '\x68'), ('\x49', '\x69'), ('\x4a', '\x6a'),
+        ('\x4b', '\x6b'), ('\x4c', '\x6c'), ('\x4d', '\x6d'), ('\x4e', '\x6e'), ('\x4f', '\x6f'),
+        ('\x50', '\x70'), ('\x51', '\x71'), ('\x52', '\x72'), ('\x53', '\x73'), ('\x54', '\x74'),
+        ('\x55', '\x75'), ('\x56', '\x76'), ('\x57', '\x77'), ('\x58', '\x78'), ('\x59', '\x79'),
+        ('\x5a', '\x7a'), ('\u00c0', '\u00e0'), ('\u00c1', '\u00e1'), ('\u00c2', '\u00e2'),
+        ('\u00c3', '\u00e3'), ('\u00c4', '\u00e4'), ('\u00c5', '\u00e5'), ('\u00c6', '\u00e6'),
+        ('\u00c7', '\u00e7'), ('\u00c8', '\u00e8'), ('\u00c9', '\u00e9'), ('\u00ca', '\u00ea'),
+        ('\u00cb', '\u00eb'), ('\u00cc', '\u00ec'), ('\u00cd', '\u00ed'), ('\u00ce', '\u00ee'),
+        ('\u00cf', '\u00ef'), ('\u00d0', '\u00f0'), ('\u00d1', '\u00f1'), ('\u00d2', '\u00f2'),
+        ('\u00d3', '\u00f3'), ('\u00d4', '\u00f4'), ('\u00d5', '\u00f5'), ('\u00d6', '\u00f6'),
+        ('\u00d8', '\u00f8'), ('\u00d9', '\u00f9'), ('\u00da', '\u00fa'), ('\u00db', '\u00fb'),
+        ('\u00dc', '\u00fc'), ('\u00dd', '\u00fd'), ('\u00de', '\u00fe'), ('\u0100', '\u0101'),
+        ('\u0102', '\u0103'), ('\u0104', '\u0105'), ('\u0106', '\u0107'), ('\u0108', '\u0109'),
+        ('\u010a', '\u010b'), ('\u010c', '\u010d'), ('\u010e', '\u010f'), ('\u0110', '\u0111'),
+        ('\u0112', '\u0113'), ('\u0114', '\u0115'), ('\u0116', '\u0117'), ('\u0118', '\u0119'),
+        ('\u011a', '\u011b'), ('\u011c', '\u011d'), ('\u011e', '\u011f'), ('\u0120', '\u0121'),
+        ('\u0122', '\u0123'), ('\u0124', '\u0125'), ('\u0126', '\u0127'), ('\u0128', '\u0129'),
+        ('\u012a', '\u012b'), ('\u012c', '\u012d'), ('\u012e', '\u012f'), ('\u0130', '\x69'),
+        ('\u0132', '\u0133'), ('\u0134', '\u0135'), ('\u0136', '\u0137'), ('\u0139', '\u013a'),
+        ('\u013b', '\u013c'), ('\u013d', '\u013e'), ('\u013f', '\u0140'), ('\u0141', '\u0142'),
+        ('\u0143', '\u0144'), ('\u0145', '\u0146'), ('\u0147', '\u0148'), ('\u014a', '\u014b'),
+        ('\u014c', '\u014d'), ('\u014e', '\u014f'), ('\u0150', '\u0151'), ('\u0152', '\u0153'),
+        ('\u0154', '\u0155'), ('\u0156', '\u0157'), ('\u0158', '\u0159'), ('\u015a', '\u015b'),
+        ('\u015c', '\u015d'), ('\u015e', '\u015f'), ('\u0160', '\u0161'), ('\u0162', '\u0163'),
+        ('\u0164', '\u0165'), ('\u0166', '\u0167'), ('\u0168', '\u0169'), ('\u016a', '\u016b'),
+        ('\u016c', '\u016d'), ('\u016e', '\u016f'), ('\u0170', '\u0171'), ('\u0172', '\u0173'),
+        ('\u0174', '\u0175'), ('\u0176', '\u0177'), ('\u0178', '\u00ff'), ('\u0179', '\u017a'),
+        ('\u017b', '\u017c'), ('\u017d', '\u017e'), ('\u0181', '\u0253'), ('\u0182', '\u0183'),
+        ('\u0184', '\u0185'), ('\u0186', '\u0254'), ('\u0187', '\u0188'), ('\u0189', '\u0256'),
+        ('\u018a', '\u0257'), ('\u018b', '\u018c'), ('\u018e', '\u01dd'), ('\u018f', '\u0259'),
+        ('\u0190', '\u025b'), ('\u0191', '\u0192'), ('\u0193', '\u0260'), ('\u0194', '\u0263'),
+        ('\u0196', '\u0269'), ('\u0197', '\u0268'), ('\u0198', '\u0199'), ('\u019c', '\u026f'),
+        ('\u019d', '\u0272'), ('\u019f', '\u0275'), ('\u01a0', '\u01a1'), ('\u01a2', '\u01a3'),
+        ('\u01a4', '\u01a5'), ('\u01a6', '\u0280'), ('\u01a7', '\u01a8'), ('\u01a9', '\u0283'),
+        ('\u01ac', '\u01ad'), ('\u01ae', '\u0288'), ('\u01af', '\u01b0'), ('\u01b1', '\u028a'),
+        ('\u01b2', '\u028b'), ('\u01b3', '\u01b4'), ('\u01b5', '\u01b6'), ('\u01b7', '\u0292'),
+        ('\u01b8', '\u01b9'), ('\u01bc', '\u01bd'), ('\u01c4', '\u01c6'), ('\u01c5', '\u01c6'),
+        ('\u01c7', '\u01c9'), ('\u01c8', '\u01c9'), ('\u01ca', '\u01cc'), ('\u01cb', '\u01cc'),
+        ('\u01cd', '\u01ce'), ('\u01cf', '\u01d0'), ('\u01d1', '\u01d2'), ('\u01d3', '\u01d4'),
+        ('\u01d5', '\u01d6'), ('\u01d7', '\u01d8'), ('\u01d9', '\u01da'), ('\u01db', '\u01dc'),
+        ('\u01de', '\u01df'), ('\u01e0', '\u01e1'), ('\u01e2', '\u01e3'), ('\u01e4', '\u01e5'),
+        ('\u01e6', '\u01e7'), ('\u01e8', '\u01e9'), ('\u01ea', '\u01eb'), ('\u01ec', '\u01ed'),
+        ('\u01ee', '\u01ef'), ('\u01f1', '\u01f3'), ('\u01f2', '\u01f3'), ('\u01f4', '\u01f5'),
+        ('\u01f6', '\u0195'), ('\u01f7', '\u01bf'), ('\u01f8', '\u01f9'), ('\u01fa', '\u01fb'),
+        ('\u01fc', '\u01fd'), ('\u01fe', '\u01ff'), ('\u0200', '\u0201'), ('\u0202', '\u0203'),
+        ('\u0204', '\u0205'), ('\u0206', '\u0207'), ('\u0208', '\u0209'), ('\u020a', '\u020b'),
+        ('\u020c', '\u020d'), ('\u020e', '\u020f'), ('\u0210', '\u0211'), ('\u0212', '\u0213'),
+        ('\u0214', '\u0215'), ('\u0216', '\u0217'), ('\u0218', '\u0219'), ('\u021a', '\u021b'),
+        ('\u021c', '\u021d'), ('\u021e', '\u021f'), ('\u0220', '\u019e'), ('\u0222', '\u0223'),
+        ('\u0224', '\u0225'), ('\u0226', '\u0227'), ('\u0228', '\u0229'), ('\u022a', '\u022b'),
+        ('\u022c', '\u022d'), ('\u022e', '\u022f'), ('\u0230', '\u0231'), ('\u0232', '\u0233'),
+        ('\u023a', '\u2c65'), ('\u023b', '\u023c'), ('\u023d', '\u019a'), ('\u023e', '\u2c66'),
+        ('\u0241', '\u0242'), ('\u0243', '\u0180'), ('\u0244', '\u0289'), ('\u0245', '\u028c'),
+        ('\u0246', '\u0247'), ('\u0248', '\u0249'), ('\u024a', '\u024b'), ('\u024c', '\u024d'),
+        ('\u024e', '\u024f'), ('\u0370', '\u0371'), ('\u0372', '\u0373'), ('\u0376', '\u0377'),
+        ('\u037f', '\u03f3'), ('\u0386', '\u03ac'), ('\u0388', '\u03ad'), ('\u0389', '\u03ae'),
+        ('\u038a', '\u03af'), ('\u038c', '\u03cc'), ('\u038e', '\u03cd'), ('\u038f', '\u03ce'),
+        ('\u0391', '\u03b1'), ('\u0392', '\u03b2'), ('\u0393', '\u03b3'), ('\u0394', '\u03b4'),
+        ('\u0395', '\u03b5'), ('\u0396', '\u03b6'), ('\u0397', '\u03b7'), ('\u0398', '\u03b8'),
+        ('\u0399', '\u03b9'), ('\u039a', '\u03ba'), ('\u039b', '\u03bb'), ('\u039c', '\u03bc'),
+        ('\u039d', '\u03bd'), ('\u039e', '\u03be'), ('\u039f', '\u03bf'), ('\u03a0', '\u03c0'),
+        ('\u03a1', '\u03c1'), ('\u03a3', '\u03c3'), ('\u03a4', '\u03c4'), ('\u03a5', '\u03c5'),
+        ('\u03a6', '\u03c6'), ('\u03a7', '\u03c7'), ('\u03a8', '\u03c8'), ('\u03a9', '\u03c9'),
+        ('\u03aa', '\u03ca'), ('\u03ab', '\u03cb'), ('\u03cf', '\u03d7'), ('\u03d8', '\u03d9'),
+        ('\u03da', '\u03db'), ('\u03dc', '\u03dd'), ('\u03de', '\u03df'), ('\u03e0', '\u03e1'),
+        ('\u03e2', '\u03e3'), ('\u03e4', '\u03e5'), ('\u03e6', '\u03e7'), ('\u03e8', '\u03e9'),
+        ('\u03ea', '\u03eb'), ('\u03ec', '\u03ed'), ('\u03ee', '\u03ef'), ('\u03f4', '\u03b8'),
+        ('\u03f7', '\u03f8'), ('\u03f9', '\u03f2'), ('\u03fa', '\u03fb'), ('\u03fd', '\u037b'),
+        ('\u03fe', '\u037c'), ('\u03ff', '\u037d'), ('\u0400', '\u0450'), ('\u0401', '\u0451'),
+        ('\u0402', '\u0452'), ('\u0403', '\u0453'), ('\u0404', '\u0454'), ('\u0405', '\u0455'),
+        ('\u0406', '\u0456'), ('\u0407', '\u0457'), ('\u0408', '\u0458'), ('\u0409', '\u0459'),
+        ('\u040a', '\u045a'), ('\u040b', '\u045b'), ('\u040c', '\u045c'), ('\u040d', '\u045d'),
+        ('\u040e', '\u045e'), ('\u040f', '\u045f'), ('\u0410', '\u0430'), ('\u0411', '\u0431'),
+        ('\u0412', '\u0432'), ('\u0413', '\u0433'), ('\u0414', '\u0434'), ('\u0415', '\u0435'),
+        ('\u0416', '\u0436'), ('\u0417', '\u0437'), ('\u0418', '\u0438'), ('\u0419', '\u0439'),
+        ('\u041a', '\u043a'), ('\u041b', '\u043b'), ('\u041c', '\u043c'), ('\u041d', '\u043d'),
+        ('\u041e', '\u043e'), ('\u041f', '\u043f'), ('\u0420', '\u0440'), ('\u0421', '\u0441'),
+        ('\u0422', '\u0442'), ('\u0423', '\u0443'), ('\u0424', '\u0444'), ('\u0425', '\u0445'),
+        ('\u0426', '\u0446'), ('\u0427', '\u0447'), ('\u0428', '\u0448'), ('\u0429', '\u0449'),
+        ('\u042a', '\u044a'), ('\u042b', '\u044b'), ('\u042c', '\u044c'), ('\u042d', '\u044d'),
+        ('\u042e', '\u044e'), ('\u042f', '\u044f'), ('\u0460', '\u0461'), ('\u0462', '\u0463'),
+        ('\u0464', '\u0465'), ('\u0466', '\u0467'), ('\u0468', '\u0469'), ('\u046a', '\u046b'),
+        ('\u046c', '\u046d'), ('\u046e', '\u046f'), ('\u0470', '\u0471'), ('\u0472', '\u0473'),
+        ('\u0474', '\u0475'), ('\u0476', '\u0477'), ('\u0478', '\u0479'), ('\u047a', '\u047b'),
+        ('\u047c', '\u047d'), ('\u047e', '\u047f'), ('\u0480', '\u0481'), ('\u048a', '\u048b'),
+        ('\u048c', '\u048d'), ('\u048e', '\u048f'), ('\u0490', '\u0491'), ('\u0492', '\u0493'),
+        ('\u0494', '\u0495'), ('\u0496', '\u0497'), ('\u0498', '\u0499'), ('\u049a', '\u049b'),
+        ('\u049c', '\u049d'), ('\u049e', '\u049f'), ('\u04a0', '\u04a1'), ('\u04a2', '\u04a3'),
+        ('\u04a4', '\u04a5'), ('\u04a6', '\u04a7'), ('\u04a8', '\u04a9'), ('\u04aa', '\u04ab'),
+        ('\u04ac', '\u04ad'), ('\u04ae', '\u04af'), ('\u04b0', '\u04b1'), ('\u04b2', '\u04b3'),
+        ('\u04b4', '\u04b5'), ('\u04b6', '\u04b7'), ('\u04b8', '\u04b9'), ('\u04ba', '\u04bb'),
+        ('\u04bc', '\u04bd'), ('\u04be', '\u04bf'), ('\u04c0', '\u04cf'), ('\u04c1', '\u04c2'),
+        ('\u04c3', '\u04c4'), ('\u04c5', '\u04c6'), ('\u04c7', '\u04c8'), ('\u04c9', '\u04ca'),
+        ('\u04cb', '\u04cc'), ('\u04cd', '\u04ce'), ('\u04d0', '\u04d1'), ('\u04d2', '\u04d3'),
+        ('\u04d4', '\u04d5'), ('\u04d6', '\u04d7'), ('\u04d8', '\u04d9'), ('\u04da', '\u04db'),
+        ('\u04dc', '\u04dd'), ('\u04de', '\u04df'), ('\u04e0', '\u04e1'), ('\u04e2', '\u04e3'),
+        ('\u04e4', '\u04e5'), ('\u04e6', '\u04e7'), ('\u04e8', '\u04e9'), ('\u04ea', '\u04eb'),
+        ('\u04ec', '\u04ed'), ('\u04ee', '\u04ef'), ('\u04f0', '\u04f1'), ('\u04f2', '\u04f3'),
+        ('\u04f4', '\u04f5'), ('\u04f6', '\u04f7'), ('\u04f8', '\u04f9'), ('\u04fa', '\u04fb'),
+        ('\u04fc', '\u04fd'), ('\u04fe', '\u04ff'), ('\u0500', '\u0501'), ('\u0502', '\u0503'),
+        ('\u0504', '\u0505'), ('\u0506', '\u0507'), ('\u0508', '\u0509'), ('\u050a', '\u050b'),
+        ('\u050c', '\u050d'), ('\u050e', '\u050f'), ('\u0510', '\u0511'), ('\u0512', '\u0513'),
+        ('\u0514', '\u0515'), ('\u0516', '\u0517'), ('\u0518', '\u0519'), ('\u051a', '\u051b'),
+        ('\u051c', '\u051d'), ('\u051e', '\u051f'), ('\u0520', '\u0521'), ('\u0522', '\u0523'),
+        ('\u0524', '\u0525'), ('\u0526', '\u0527'), ('\u0528', '\u0529'), ('\u052a', '\u052b'),
+        ('\u052c', '\u052d'), ('\u052e', '\u052f'), ('\u0531', '\u0561'), ('\u0532', '\u0562'),
+        ('\u0533', '\u0563'), ('\u0534', '\u0564'), ('\u0535', '\u0565'), ('\u0536', '\u0566'),
+        ('\u0537', '\u0567'), ('\u0538', '\u0568'), ('\u0539', '\u0569'), ('\u053a', '\u056a'),
+        ('\u053b', '\u056b'), ('\u053c', '\u056c'), ('\u053d', '\u056d'), ('\u053e', '\u056e'),
+        ('\u053f', '\u056f'), ('\u0540', '\u0570'), ('\u0541', '\u0571'), ('\u0542', '\u0572'),
+        ('\u0543', '\u0573'), ('\u0544', '\u0574'), ('\u0545', '\u0575'), ('\u0546', '\u0576'),
+        ('\u0547', '\u0577'), ('\u0548', '\u0578'), ('\u0549', '\u0579'), ('\u054a', '\u057a'),
+        ('\u054b', '\u057b'), ('\u054c', '\u057c'), ('\u054d', '\u057d'), ('\u054e', '\u057e'),
+        ('\u054f', '\u057f'), ('\u0550', '\u0580'), ('\u0551', '\u0581'), ('\u0552', '\u0582'),
+        ('\u0553', '\u0583'), ('\u0554', '\u0584'), ('\u0555', '\u0585'), ('\u0556', '\u0586'),
+        ('\u10a0', '\u2d00'), ('\u10a1', '\u2d01'), ('\u10a2', '\u2d02'), ('\u10a3', '\u2d03'),
+        ('\u10a4', '\u2d04'), ('\u10a5', '\u2d05'), ('\u10a6', '\u2d06'), ('\u10a7', '\u2d07'),
+        ('\u10a8', '\u2d08'), ('\u10a9', '\u2d09'), ('\u10aa', '\u2d0a'), ('\u10ab', '\u2d0b'),
+        ('\u10ac', '\u2d0c'), ('\u10ad', '\u2d0d'), ('\u10ae', '\u2d0e'), ('\u10af', '\u2d0f'),
+        ('\u10b0', '\u2d10'), ('\u10b1', '\u2d11'), ('\u10b2', '\u2d12'), ('\u10b3', '\u2d13'),
+        ('\u10b4', '\u2d14'), ('\u10b5', '\u2d15'), ('\u10b6', '\u2d16'), ('\u10b7', '\u2d17'),
+        ('\u10b8', '\u2d18'), ('\u10b9', '\u2d19'), ('\u10ba', '\u2d1a'), ('\u10bb', '\u2d1b'),
+        ('\u10bc', '\u2d1c'), ('\u10bd', '\u2d1d'), ('\u10be', '\u2d1e'), ('\u10bf', '\u2d1f'),
+        ('\u10c0', '\u2d20'), ('\u10c1', '\u2d21'), ('\u10c2', '\u2d22'), ('\u10c3', '\u2d23'),
+        ('\u10c4', '\u2d24'), ('\u10c5', '\u2d25'), ('\u10c7', '\u2d27'), ('\u10cd', '\u2d2d'),
+        ('\u13a0', '\uab70'), ('\u13a1', '\uab71'), ('\u13a2', '\uab72'), ('\u13a3', '\uab73'),
+        ('\u13a4', '\uab74'), ('\u13a5', '\uab75'), ('\u13a6', '\uab76'), ('\u13a7', '\uab77'),
+        ('\u13a8', '\uab78'), ('\u13a9', '\uab79'), ('\u13aa', '\uab7a'), ('\u13ab', '\uab7b'),
+        ('\u13ac', '\uab7c'), ('\u13ad', '\uab7d'), ('\u13ae', '\uab7e'), ('\u13af', '\uab7f'),
+        ('\u13b0', '\uab80'), ('\u13b1', '\uab81'), ('\u13b2', '\uab82'), ('\u13b3', '\uab83'),
+        ('\u13b4', '\uab84'), ('\u13b5', '\uab85'), ('\u13b6', '\uab86'), ('\u13b7', '\uab87'),
+        ('\u13b8', '\uab88'), ('\u13b9', '\uab89'), ('\u13ba', '\uab8a'), ('\u13bb', '\uab8b'),
+        ('\u13bc', '\uab8c'), ('\u13bd', '\uab8d'), ('\u13be', '\uab8e'), ('\u13bf', '\uab8f'),
+        ('\u13c0', '\uab90'), ('\u13c1', '\uab91'), ('\u13c2', '\uab92'), ('\u13c3', '\uab93'),
+        ('\u13c4', '\uab94'), ('\u13c5', '\uab95'), ('\u13c6', '\uab96'), ('\u13c7', '\uab97'),
+        ('\u13c8', '\uab98'), ('\u13c9', '\uab99'), ('\u13ca', '\uab9a'), ('\u13cb', '\uab9b'),
+        ('\u13cc', '\uab9c'), ('\u13cd', '\uab9d'), ('\u13ce', '\uab9e'), ('\u13cf', '\uab9f'),
+        ('\u13d0', '\uaba0'), ('\u13d1', '\uaba1'), ('\u13d2', '\uaba2'), ('\u13d3', '\uaba3'),
+        ('\u13d4', '\uaba4'), ('\u13d5', '\uaba5'), ('\u13d6', '\uaba6'), ('\u13d7', '\uaba7'),
+        ('\u13d8', '\uaba8'), ('\u13d9', '\uaba9'), ('\u13da', '\uabaa'), ('\u13db', '\uabab'),
+        ('\u13dc', '\uabac'), ('\u13dd', '\uabad'), ('\u13de', '\uabae'), ('\u13df', '\uabaf'),
+        ('\u13e0', '\uabb0'), ('\u13e1', '\uabb1'), ('\u13e2', '\uabb2'), ('\u13e3', '\uabb3'),
+        ('\u13e4', '\uabb4'), ('\u13e5', '\uabb5'), ('\u13e6', '\uabb6'), ('\u13e7', '\uabb7'),
+        ('\u13e8', '\uabb8'), ('\u13e9', '\uabb9'), ('\u13ea', '\uabba'), ('\u13eb', '\uabbb'),
+        ('\u13ec', '\uabbc'), ('\u13ed', '\uabbd'), ('\u13ee', '\uabbe'), ('\u13ef', '\uabbf'),
+        ('\u13f0', '\u13f8'), ('\u13f1', '\u13f9'), ('\u13f2', '\u13fa'), ('\u13f3', '\u13fb'),
+        ('\u13f4', '\u13fc'), ('\u13f5', '\u13fd'), ('\u1c90', '\u10d0'), ('\u1c91', '\u10d1'),
+        ('\u1c92', '\u10d2'), ('\u1c93', '\u10d3'), ('\u1c94', '\u10d4'), ('\u1c95', '\u10d5'),
+        ('\u1c96', '\u10d6'), ('\u1c97', '\u10d7'), ('\u1c98', '\u10d8'), ('\u1c99', '\u10d9'),
+        ('\u1c9a', '\u10da'), ('\u1c9b', '\u10db'), ('\u1c9c', '\u10dc'), ('\u1c9d', '\u10dd'),
+        ('\u1c9e', '\u10de'), ('\u1c9f', '\u10df'), ('\u1ca0', '\u10e0'), ('\u1ca1', '\u10e1'),
+        ('\u1ca2', '\u10e2'), ('\u1ca3', '\u10e3'), ('\u1ca4', '\u10e4'), ('\u1ca5', '\u10e5'),
+        ('\u1ca6', '\u10e6'), ('\u1ca7', '\u10e7'), ('\u1ca8', '\u10e8'), ('\u1ca9', '\u10e9'),
+        ('\u1caa', '\u10ea'), ('\u1cab', '\u10eb'), ('\u1cac', '\u10ec'), ('\u1cad', '\u10ed'),
+        ('\u1cae', '\u10ee'), ('\u1caf', '\u10ef'), ('\u1cb0', '\u10f0'), ('\u1cb1', '\u10f1'),
+        ('\u1cb2', '\u10f2'), ('\u1cb3', '\u10f3'), ('\u1cb4', '\u10f4'), ('\u1cb5', '\u10f5'),
+        ('\u1cb6', '\u10f6'), ('\u1cb7', '\u10f7'), ('\u1cb8', '\u10f8'), ('\u1cb9', '\u10f9'),
+        ('\u1cba', '\u10fa'), ('\u1cbd', '\u10fd'), ('\u1cbe', '\u10fe'), ('\u1cbf', '\u10ff'),
+        ('\u1e00', '\u1e01'), ('\u1e02', '\u1e03'), ('\u1e04', '\u1e05'), ('\u1e06', '\u1e07'),
+        ('\u1e08', '\u1e09'), ('\u1e0a', '\u1e0b'), ('\u1e0c', '\u1e0d'), ('\u1e0e', '\u1e0f'),
+        ('\u1e10', '\u1e11'), ('\u1e12', '\u1e13'), ('\u1e14', '\u1e15'), ('\u1e16', '\u1e17'),
+        ('\u1e18', '\u1e19'), ('\u1e1a', '\u1e1b'), ('\u1e1c', '\u1e1d'), ('\u1e1e', '\u1e1f'),
+        ('\u1e20', '\u1e21'), ('\u1e22', '\u1e23'), ('\u1e24', '\u1e25'), ('\u1e26', '\u1e27'),
+        ('\u1e28', '\u1e29'), ('\u1e2a', '\u1e2b'), ('\u1e2c', '\u1e2d'), ('\u1e2e', '\u1e2f'),
+        ('\u1e30', '\u1e31'), ('\u1e32', '\u1e33'), ('\u1e34', '\u1e35'), ('\u1e36', '\u1e37'),
+        ('\u1e38', '\u1e39'), ('\u1e3a', '\u1e3b'), ('\u1e3c', '\u1e3d'), ('\u1e3e', '\u1e3f'),
+        ('\u1e40', '\u1e41'), ('\u1e42', '\u1e43'), ('\u1e44', '\u1e45'), ('\u1e46', '\u1e47'),
+        ('\u1e48', '\u1e49'), ('\u1e4a', '\u1e4b'), ('\u1e4c', '\u1e4d'), ('\u1e4e', '\u1e4f'),
+        ('\u1e50', '\u1e51'), ('\u1e52', '\u1e53'), ('\u1e54', '\u1e55'), ('\u1e56', '\u1e57'),
+        ('\u1e58', '\u1e59'), ('\u1e5a', '\u1e5b'), ('\u1e5c', '\u1e5d'), ('\u1e5e', '\u1e5f'),
+        ('\u1e60', '\u1e61'), ('\u1e62', '\u1e63'), ('\u1e64', '\u1e65'), ('\u1e66', '\u1e67'),
+        ('\u1e68', '\u1e69'), ('\u1e6a', '\u1e6b'), ('\u1e6c', '\u1e6d'), ('\u1e6e', '\u1e6f'),
+        ('\u1e70', '\u1e71'), ('\u1e72', '\u1e73'), ('\u1e74', '\u1e75'), ('\u1e76', '\u1e77'),
+        ('\u1e78', '\u1e79'), ('\u1e7a', '\u1e7b'), ('\u1e7c', '\u1e7d'), ('\u1e7e', '\u1e7f'),
+        ('\u1e80', '\u1e81'), ('\u1e82', '\u1e83'), ('\u1e84', '\u1e85'), ('\u1e86', '\u1e87'),
+        ('\u1e88', '\u1e89'), ('\u1e8a', '\u1e8b'), ('\u1e8c', '\u1e8d'), ('\u1e8e', '\u1e8f'),
+        ('\u1e90', '\u1e91'), ('\u1e92', '\u1e93'), ('\u1e94', '\u1e95'), ('\u1e9e', '\u00df'),
+        ('\u1ea0', '\u1ea1'), ('\u1ea2', '\u1ea3'), ('\u1ea4', '\u1ea5'), ('\u1ea6', '\u1ea7'),
+        ('\u1ea8', '\u1ea9'), ('\u1eaa', '\u1eab'), ('\u1eac', '\u1ead'), ('\u1eae', '\u1eaf'),
+        ('\u1eb0', '\u1eb1'), ('\u1eb2', '\u1eb3'), ('\u1eb4', '\u1eb5'), ('\u1eb6', '\u1eb7'),
+        ('\u1eb8', '\u1eb9'), ('\u1eba', '\u1ebb'), ('\u1ebc', '\u1ebd'), ('\u1ebe', '\u1ebf'),
+        ('\u1ec0', '\u1ec1'), ('\u1ec2', '\u1ec3'), ('\u1ec4', '\u1ec5'), ('\u1ec6', '\u1ec7'),
+        ('\u1ec8', '\u1ec9'), ('\u1eca', '\u1ecb'), ('\u1ecc', '\u1ecd'), ('\u1ece', '\u1ecf'),
+        ('\u1ed0', '\u1ed1'), ('\u1ed2', '\u1ed3'), ('\u1ed4', '\u1ed5'), ('\u1ed6', '\u1ed7'),
+        ('\u1ed8', '\u1ed9'), ('\u1eda', '\u1edb'), ('\u1edc', '\u1edd'), ('\u1ede', '\u1edf'),
+        ('\u1ee0', '\u1ee1'), ('\u1ee2', '\u1ee3'), ('\u1ee4', '\u1ee5'), ('\u1ee6', '\u1ee7'),
+        ('\u1ee8', '\u1ee9'), ('\u1eea', '\u1eeb'), ('\u1eec', '\u1eed'), ('\u1eee', '\u1eef'),
+        ('\u1ef0', '\u1ef1'), ('\u1ef2', '\u1ef3'), ('\u1ef4', '\u1ef5'), ('\u1ef6', '\u1ef7'),
+        ('\u1ef8', '\u1ef9'), ('\u1efa', '\u1efb'), ('\u1efc', '\u1efd'), ('\u1efe', '\u1eff'),
+        ('\u1f08', '\u1f00'), ('\u1f09', '\u1f01'), ('\u1f0a', '\u1f02'), ('\u1f0b', '\u1f03'),
+        ('\u1f0c', '\u1f04'), ('\u1f0d', '\u1f05'), ('\u1f0e', '\u1f06'), ('\u1f0f', '\u1f07'),
+        ('\u1f18', '\u1f10'), ('\u1f19', '\u1f11'), ('\u1f1a', '\u1f12'), ('\u1f1b', '\u1f13'),
+        ('\u1f1c', '\u1f14'), ('\u1f1d', '\u1f15'), ('\u1f28', '\u1f20'), ('\u1f29', '\u1f21'),
+        ('\u1f2a', '\u1f22'), ('\u1f2b', '\u1f23'), ('\u1f2c', '\u1f24'), ('\u1f2d', '\u1f25'),
+        ('\u1f2e', '\u1f26'), ('\u1f2f', '\u1f27'), ('\u1f38', '\u1f30'), ('\u1f39', '\u1f31'),
+        ('\u1f3a', '\u1f32'), ('\u1f3b', '\u1f33'), ('\u1f3c', '\u1f34'), ('\u1f3d', '\u1f35'),
+        ('\u1f3e', '\u1f36'), ('\u1f3f', '\u1f37'), ('\u1f48', '\u1f40'), ('\u1f49', '\u1f41'),
+        ('\u1f4a', '\u1f42'), ('\u1f4b', '\u1f43'), ('\u1f4c', '\u1f44'), ('\u1f4d', '\u1f45'),
+        ('\u1f59', '\u1f51'), ('\u1f5b', '\u1f53'), ('\u1f5d', '\u1f55'), ('\u1f5f', '\u1f57'),
+        ('\u1f68', '\u1f60'), ('\u1f69', '\u1f61'), ('\u1f6a', '\u1f62'), ('\u1f6b', '\u1f63'),
+        ('\u1f6c', '\u1f64'), ('\u1f6d', '\u1f65'), ('\u1f6e', '\u1f66'), ('\u1f6f', '\u1f67'),
+        ('\u1f88', '\u1f80'), ('\u1f89', '\u1f81'), ('\u1f8a', '\u1f82'), ('\u1f8b', '\u1f83'),
+        ('\u1f8c', '\u1f84'), ('\u1f8d', '\u1f85'), ('\u1f8e', '\u1f86'), ('\u1f8f', '\u1f87'),
+        ('\u1f98', '\u1f90'), ('\u1f99', '\u1f91'), ('\u1f9a', '\u1f92'), ('\u1f9b', '\u1f93'),
+        ('\u1f9c', '\u1f94'), ('\u1f9d', '\u1f95'), ('\u1f9e', '\u1f96'), ('\u1f9f', '\u1f97'),
+        ('\u1fa8', '\u1fa0'), ('\u1fa9', '\u1fa1'), ('\u1faa', '\u1fa2'), ('\u1fab', '\u1fa3'),
+        ('\u1fac', '\u1fa4'), ('\u1fad', '\u1fa5'), ('\u1fae', '\u1fa6'), ('\u1faf', '\u1fa7'),
+        ('\u1fb8', '\u1fb0'), ('\u1fb9', '\u1fb1'), ('\u1fba', '\u1f70'), ('\u1fbb', '\u1f71'),
+        ('\u1fbc', '\u1fb3'), ('\u1fc8', '\u1f72'), ('\u1fc9', '\u1f73'), ('\u1fca', '\u1f74'),
+        ('\u1fcb', '\u1f75'), ('\u1fcc', '\u1fc3'), ('\u1fd8', '\u1fd0'), ('\u1fd9', '\u1fd1'),
+        ('\u1fda', '\u1f76'), ('\u1fdb', '\u1f77'), ('\u1fe8', '\u1fe0'), ('\u1fe9', '\u1fe1'),
+        ('\u1fea', '\u1f7a'), ('\u1feb', '\u1f7b'), ('\u1fec', '\u1fe5'), ('\u1ff8', '\u1f78'),
+        ('\u1ff9', '\u1f79'), ('\u1ffa', '\u1f7c'), ('\u1ffb', '\u1f7d'), ('\u1ffc', '\u1ff3'),
+        ('\u2126', '\u03c9'), ('\u212a', '\x6b'), ('\u212b', '\u00e5'), ('\u2132', '\u214e'),
+        ('\u2160', '\u2170'), ('\u2161', '\u2171'), ('\u2162', '\u2172'), ('\u2163', '\u2173'),
+        ('\u2164', '\u2174'), ('\u2165', '\u2175'), ('\u2166', '\u2176'), ('\u2167', '\u2177'),
+        ('\u2168', '\u2178'), ('\u2169', '\u2179'), ('\u216a', '\u217a'), ('\u216b', '\u217b'),
+        ('\u216c', '\u217c'), ('\u216d', '\u217d'), ('\u216e', '\u217e'), ('\u216f', '\u217f'),
+        ('\u2183', '\u2184'), ('\u24b6', '\u24d0'), ('\u24b7', '\u24d1'), ('\u24b8', '\u24d2'),
+        ('\u24b9', '\u24d3'), ('\u24ba', '\u24d4'), ('\u24bb', '\u24d5'), ('\u24bc', '\u24d6'),
+        ('\u24bd', '\u24d7'), ('\u24be', '\u24d8'), ('\u24bf', '\u24d9'), ('\u24c0', '\u24da'),
+        ('\u24c1', '\u24db'), ('\u24c2', '\u24dc'), ('\u24c3', '\u24dd'), ('\u24c4', '\u24de'),
+        ('\u24c5', '\u24df'), ('\u24c6', '\u24e0'), ('\u24c7', '\u24e1'), ('\u24c8', '\u24e2'),
+        ('\u24c9', '\u24e3'), ('\u24ca', '\u24e4'), ('\u24cb', '\u24e5'), ('\u24cc', '\u24e6'),
+        ('\u24cd', '\u24e7'), ('\u24ce', '\u24e8'), ('\u24cf', '\u24e9'), ('\u2c00', '\u2c30'),
+        ('\u2c01', '\u2c31'), ('\u2c02', '\u2c32'), ('\u2c03', '\u2c33'), ('\u2c04', '\u2c34'),
+        ('\u2c05', '\u2c35'), ('\u2c06', '\u2c36'), ('\u2c07', '\u2c37'), ('\u2c08', '\u2c38'),
+        ('\u2c09', '\u2c39'), ('\u2c0a', '\u2c3a'), ('\u2c0b', '\u2c3b'), ('\u2c0c', '\u2c3c'),
+        ('\u2c0d', '\u2c3d'), ('\u2c0e', '\u2c3e'), ('\u2c0f', '\u2c3f'), ('\u2c10', '\u2c40'),
+        ('\u2c11', '\u2c41'), ('\u2c12', '\u2c42'), ('\u2c13', '\u2c43'), ('\u2c14', '\u2c44'),
+        ('\u2c15', '\u2c45'), ('\u2c16', '\u2c46'), ('\u2c17', '\u2c47'), ('\u2c18', '\u2c48'),
+        ('\u2c19', '\u2c49'), ('\u2c1a', '\u2c4a'), ('\u2c1b', '\u2c4b'), ('\u2c1c', '\u2c4c'),
+        ('\u2c1d', '\u2c4d'), ('\u2c1e', '\u2c4e'), ('\u2c1f', '\u2c4f'), ('\u2c20', '\u2c50'),
+        ('\u2c21', '\u2c51'), ('\u2c22', '\u2c52'), ('\u2c23', '\u2c53'), ('\u2c24', '\u2c54'),
+        ('\u2c25', '\u2c55'), ('\u2c26', '\u2c56'), ('\u2c27', '\u2c57'), ('\u2c28', '\u2c58'),
+        ('\u2c29', '\u2c59'), ('\u2c2a', '\u2c5a'), ('\u2c2b', '\u2c5b'), ('\u2c2c', '\u2c5c'),
+        ('\u2c2d', '\u2c5d'), ('\u2c2e', '\u2c5e'), ('\u2c2f', '\u2c5f'), ('\u2c60', '\u2c61'),
+        ('\u2c62', '\u026b'), ('\u2c63', '\u1d7d'), ('\u2c64', '\u027d'), ('\u2c67', '\u2c68'),
+        ('\u2c69', '\u2c6a'), ('\u2c6b', '\u2c6c'), ('\u2c6d', '\u0251'), ('\u2c6e', '\u0271'),
+        ('\u2c6f', '\u0250'), ('\u2c70', '\u0252'), ('\u2c72', '\u2c73'), ('\u2c75', '\u2c76'),
+        ('\u2c7e', '\u023f'), ('\u2c7f', '\u0240'), ('\u2c80', '\u2c81'), ('\u2c82', '\u2c83'),
+        ('\u2c84', '\u2c85'), ('\u2c86', '\u2c87'), ('\u2c88', '\u2c89'), ('\u2c8a', '\u2c8b'),
+        ('\u2c8c', '\u2c8d'), ('\u2c8e', '\u2c8f'), ('\u2c90', '\u2c91'), ('\u2c92', '\u2c93'),
+        ('\u2c94', '\u2c95'), ('\u2c96', '\u2c97'), ('\u2c98', '\u2c99'), ('\u2c9a', '\u2c9b'),
+        ('\u2c9c', '\u2c9d'), ('\u2c9e', '\u2c9f'), ('\u2ca0', '\u2ca1'), ('\u2ca2', '\u2ca3'),
+        ('\u2ca4', '\u2ca5'), ('\u2ca6', '\u2ca7'), ('\u2ca8', '\u2ca9'), ('\u2caa', '\u2cab'),
+        ('\u2cac', '\u2cad'), ('\u2cae', '\u2caf'), ('\u2cb0', '\u2cb1'), ('\u2cb2', '\u2cb3'),
+        ('\u2cb4', '\u2cb5'), ('\u2cb6', '\u2cb7'), ('\u2cb8', '\u2cb9'), ('\u2cba', '\u2cbb'),
+        ('\u2cbc', '\u2cbd'), ('\u2cbe', '\u2cbf'), ('\u2cc0', '\u2cc1'), ('\u2cc2', '\u2cc3'),
+        ('\u2cc4', '\u2cc5'), ('\u2cc6', '\u2cc7'), ('\u2cc8', '\u2cc9'), ('\u2cca', '\u2ccb'),
+        ('\u2ccc', '\u2ccd'), ('\u2cce', '\u2ccf'), ('\u2cd0', '\u2cd1'), ('\u2cd2', '\u2cd3'),
+        ('\u2cd4', '\u2cd5'), ('\u2cd6', '\u2cd7'), ('\u2cd8', '\u2cd9'), ('\u2cda', '\u2cdb'),
+        ('\u2cdc', '\u2cdd'), ('\u2cde', '\u2cdf'), ('\u2ce0', '\u2ce1'), ('\u2ce2', '\u2ce3'),
+        ('\u2ceb', '\u2cec'), ('\u2ced', '\u2cee'), ('\u2cf2', '\u2cf3'), ('\ua640', '\ua641'),
+        ('\ua642', '\ua643'), ('\ua644', '\ua645'), ('\ua646', '\ua647'), ('\ua648', '\ua649'),
+        ('\ua64a', '\ua64b'), ('\ua64c', '\ua64d'), ('\ua64e', '\ua64f'), ('\ua650', '\ua651'),
+        ('\ua652', '\ua653'), ('\ua654', '\ua655'), ('\ua656', '\ua657'), ('\ua658', '\ua659'),
+        ('\ua65a', '\ua65b'), ('\ua65c', '\ua65d'), ('\ua65e', '\ua65f'), ('\ua660', '\ua661'),
+        ('\ua662', '\ua663'), ('\ua664', '\ua665'), ('\ua666', '\ua667'), ('\ua668', '\ua669'),
+        ('\ua66a', '\ua66b'), ('\ua66c', '\ua66d'), ('\ua680', '\ua681'), ('\ua682', '\ua683'),
+        ('\ua684', '\ua685'), ('\ua686', '\ua687'), ('\ua688', '\ua689'), ('\ua68a', '\ua68b'),
+        ('\ua68c', '\ua68d'), ('\ua68e', '\ua68f'), ('\ua690', '\ua691'), ('\ua692', '\ua693'),
+        ('\ua694', '\ua695'), ('\ua696', '\ua697'), ('\ua698', '\ua699'), ('\ua69a', '\ua69b'),
+        ('\ua722', '\ua723'), ('\ua724', '\ua725'), ('\ua726', '\ua727'), ('\ua728', '\ua729'),
+        ('\ua72a', '\ua72b'), ('\ua72c', '\ua72d'), ('\ua72e', '\ua72f'), ('\ua732', '\ua733'),
+        ('\ua734', '\ua735'), ('\ua736', '\ua737'), ('\ua738', '\ua739'), ('\ua73a', '\ua73b'),
+        ('\ua73c', '\ua73d'), ('\ua73e', '\ua73f'), ('\ua740', '\ua741'), ('\ua742', '\ua743'),
+        ('\ua744', '\ua745'), ('\ua746', '\ua747'), ('\ua748', '\ua749'), ('\ua74a', '\ua74b'),
+        ('\ua74c', '\ua74d'), ('\ua74e', '\ua74f'), ('\ua750', '\ua751'), ('\ua752', '\ua753'),
+        ('\ua754', '\ua755'), ('\ua756', '\ua757'), ('\ua758', '\ua759'), ('\ua75a', '\ua75b'),
+        ('\ua75c', '\ua75d'), ('\ua75e', '\ua75f'), ('\ua760', '\ua761'), ('\ua762', '\ua763'),
+        ('\ua764', '\ua765'), ('\ua766', '\ua767'), ('\ua768', '\ua769'), ('\ua76a', '\ua76b'),
+        ('\ua76c', '\ua76d'), ('\ua76e', '\ua76f'), ('\ua779', '\ua77a'), ('\ua77b', '\ua77c'),
+        ('\ua77d', '\u1d79'), ('\ua77e', '\ua77f'), ('\ua780', '\ua781'), ('\ua782', '\ua783'),
+        ('\ua784', '\ua785'), ('\ua786', '\ua787'), ('\ua78b', '\ua78c'), ('\ua78d', '\u0265'),
+        ('\ua790', '\ua791'), ('\ua792', '\ua793'), ('\ua796', '\ua797'), ('\ua798', '\ua799'),
+        ('\ua79a', '\ua79b'), ('\ua79c', '\ua79d'), ('\ua79e', '\ua79f'), ('\ua7a0', '\ua7a1'),
+        ('\ua7a2', '\ua7a3'), ('\ua7a4', '\ua7a5'), ('\ua7a6', '\ua7a7'), ('\ua7a8', '\ua7a9'),
+        ('\ua7aa', '\u0266'), ('\ua7ab', '\u025c'), ('\ua7ac', '\u0261'), ('\ua7ad', '\u026c'),
+        ('\ua7ae', '\u026a'), ('\ua7b0', '\u029e'), ('\ua7b1', '\u0287'), ('\ua7b2', '\u029d'),
+        ('\ua7b3', '\uab53'), ('\ua7b4', '\ua7b5'), ('\ua7b6', '\ua7b7'), ('\ua7b8', '\ua7b9'),
+        ('\ua7ba', '\ua7bb'), ('\ua7bc', '\ua7bd'), ('\ua7be', '\ua7bf'), ('\ua7c0', '\ua7c1'),
+        ('\ua7c2', '\ua7c3'), ('\ua7c4', '\ua794'), ('\ua7c5', '\u0282'), ('\ua7c6', '\u1d8e'),
+        ('\ua7c7', '\ua7c8'), ('\ua7c9', '\ua7ca'), ('\ua7d0', '\ua7d1'), ('\ua7d6', '\ua7d7'),
+        ('\ua7d8', '\ua7d9'), ('\ua7f5', '\ua7f6'), ('\uff21', '\uff41'), ('\uff22', '\uff42'),
+        ('\uff23', '\uff43'), ('\uff24', '\uff44'), ('\uff25', '\uff45'), ('\uff26', '\uff46'),
+        ('\uff27', '\uff47'), ('\uff28', '\uff48'), ('\uff29', '\uff49'), ('\uff2a', '\uff4a'),
+        ('\uff2b', '\uff4b'), ('\uff2c', '\uff4c'), ('\uff2d', '\uff4d'), ('\uff2e', '\uff4e'),
+        ('\uff2f', '\uff4f'), ('\uff30', '\uff50'), ('\uff31', '\uff51'), ('\uff32', '\uff52'),
+        ('\uff33', '\uff53'), ('\uff34', '\uff54'), ('\uff35', '\uff55'), ('\uff36', '\uff56'),
+        ('\uff37', '\uff57'), ('\uff38', '\uff58'), ('\uff39', '\uff59'), ('\uff3a', '\uff5a'),
+        ('\U00010400', '\U00010428'), ('\U00010401', '\U00010429'), ('\U00010402', '\U0001042a'),
+        ('\U00010403', '\U0001042b'), ('\U00010404', '\U0001042c'), ('\U00010405', '\U0001042d'),
+        ('\U00010406', '\U0001042e'), ('\U00010407', '\U0001042f'), ('\U00010408', '\U00010430'),
+        ('\U00010409', '\U00010431'), ('\U0001040a', '\U00010432'), ('\U0001040b', '\U00010433'),
+        ('\U0001040c', '\U00010434'), ('\U0001040d', '\U00010435'), ('\U0001040e', '\U00010436'),
+        ('\U0001040f', '\U00010437'), ('\U00010410', '\U00010438'), ('\U00010411', '\U00010439'),
+        ('\U00010412', '\U0001043a'), ('\U00010413', '\U0001043b'), ('\U00010414', '\U0001043c'),
+        ('\U00010415', '\U0001043d'), ('\U00010416', '\U0001043e'), ('\U00010417', '\U0001043f'),
+        ('\U00010418', '\U00010440'), ('\U00010419', '\U00010441'), ('\U0001041a', '\U00010442'),
+        ('\U0001041b', '\U00010443'), ('\U0001041c', '\U00010444'), ('\U0001041d', '\U00010445'),
+        ('\U0001041e', '\U00010446'), ('\U0001041f', '\U00010447'), ('\U00010420', '\U00010448'),
+        ('\U00010421', '\U00010449'), ('\U00010422', '\U0001044a'), ('\U00010423', '\U0001044b'),
+        ('\U00010424', '\U0001044c'), ('\U00010425', '\U0001044d'), ('\U00010426', '\U0001044e'),
+        ('\U00010427', '\U0001044f'), ('\U000104b0', '\U000104d8'), ('\U000104b1', '\U000104d9'),
+        ('\U000104b2', '\U000104da'), ('\U000104b3', '\U000104db'), ('\U000104b4', '\U000104dc'),
+        ('\U000104b5', '\U000104dd'), ('\U000104b6', '\U000104de'), ('\U000104b7', '\U000104df'),
+        ('\U000104b8', '\U000104e0'), ('\U000104b9', '\U000104e1'), ('\U000104ba', '\U000104e2'),
+        ('\U000104bb', '\U000104e3'), ('\U000104bc', '\U000104e4'), ('\U000104bd', '\U000104e5'),
+        ('\U000104be', '\U000104e6'), ('\U000104bf', '\U000104e7'), ('\U000104c0', '\U000104e8'),
+        ('\U000104c1', '\U000104e9'), ('\U000104c2', '\U000104ea'), ('\U000104c3', '\U000104eb'),
+        ('\U000104c4', '\U000104ec'), ('\U000104c5', '\U000104ed'), ('\U000104c6', '\U000104ee'),
+        ('\U000104c7', '\U000104ef'), ('\U000104c8', '\U000104f0'), ('\U000104c9', '\U000104f1'),
+        ('\U000104ca', '\U000104f2'), ('\U000104cb', '\U000104f3'), ('\U000104cc', '\U000104f4'),
+        ('\U000104cd', '\U000104f5'), ('\U000104ce', '\U000104f6'), ('\U000104cf', '\U000104f7'),
+        ('\U000104d0', '\U000104f8'), ('\U000104d1', '\U000104f9'), ('\U000104d2', '\U000104fa'),
+        ('\U000104d3', '\U000104fb'), ('\U00010570', '\U00010597'), ('\U00010571', '\U00010598'),
+        ('\U00010572', '\U00010599'), ('\U00010573', '\U0001059a'), ('\U00010574', '\U0001059b'),
+        ('\U00010575', '\U0001059c'), ('\U00010576', '\U0001059d'), ('\U00010577', '\U0001059e'),
+        ('\U00010578', '\U0001059f'), ('\U00010579', '\U000105a0'), ('\U0001057a', '\U000105a1'),
+        ('\U0001057c', '\U000105a3'), ('\U0001057d', '\U000105a4'), ('\U0001057e', '\U000105a5'),
+        ('\U0001057f', '\U000105a6'), ('\U00010580', '\U000105a7'), ('\U00010581', '\U000105a8'),
+        ('\U00010582', '\U000105a9'), ('\U00010583', '\U000105aa'), ('\U00010584', '\U000105ab'),
+        ('\U00010585', '\U000105ac'), ('\U00010586', '\U000105ad'), ('\U00010587', '\U000105ae'),
+        ('\U00010588', '\U000105af'), ('\U00010589', '\U000105b0'), ('\U0001058a', '\U000105b1'),
+        ('\U0001058c', '\U000105b3'), ('\U0001058d', '\U000105b4'), ('\U0001058e', '\U000105b5'),
+        ('\U0001058f', '\U000105b6'), ('\U00010590', '\U000105b7'), ('\U00010591', '\U000105b8'),
+        ('\U00010592', '\U000105b9'), ('\U00010594', '\U000105bb'), ('\U00010595', '\U000105bc'),
+        ('\U00010c80', '\U00010cc0'), ('\U00010c81', '\U00010cc1'), ('\U00010c82', '\U00010cc2'),
+        ('\U00010c83', '\U00010cc3'), ('\U00010c84', '\U00010cc4'), ('\U00010c85', '\U00010cc5'),
+        ('\U00010c86', '\U00010cc6'), ('\U00010c87', '\U00010cc7'), ('\U00010c88', '\U00010cc8'),
+        ('\U00010c89', '\U00010cc9'), ('\U00010c8a', '\U00010cca'), ('\U00010c8b', '\U00010ccb'),
+        ('\U00010c8c', '\U00010ccc'), ('\U00010c8d', '\U00010ccd'), ('\U00010c8e', '\U00010cce'),
+        ('\U00010c8f', '\U00010ccf'), ('\U00010c90', '\U00010cd0'), ('\U00010c91', '\U00010cd1'),
+        ('\U00010c92', '\U00010cd2'), ('\U00010c93', '\U00010cd3'), ('\U00010c94', '\U00010cd4'),
+        ('\U00010c95', '\U00010cd5'), ('\U00010c96', '\U00010cd6'), ('\U00010c97', '\U00010cd7'),
+        ('\U00010c98', '\U00010cd8'), ('\U00010c99', '\U00010cd9'), ('\U00010c9a', '\U00010cda'),
+        ('\U00010c9b', '\U00010cdb'), ('\U00010c9c', '\U00010cdc'), ('\U00010c9d', '\U00010cdd'),
+        ('\U00010c9e', '\U00010cde'), ('\U00010c9f', '\U00010cdf'), ('\U00010ca0', '\U00010ce0'),
+        ('\U00010ca1', '\U00010ce1'), ('\U00010ca2', '\U00010ce2'), ('\U00010ca3', '\U00010ce3'),
+        ('\U00010ca4', '\U00010ce4'), ('\U00010ca5', '\U00010ce5'), ('\U00010ca6', '\U00010ce6'),
+        ('\U00010ca7', '\U00010ce7'), ('\U00010ca8', '\U00010ce8'), ('\U00010ca9', '\U00010ce9'),
+        ('\U00010caa', '\U00010cea'), ('\U00010cab', '\U00010ceb'), ('\U00010cac', '\U00010cec'),
+        ('\U00010cad', '\U00010ced'), ('\U00010cae', '\U00010cee'), ('\U00010caf', '\U00010cef'),
+        ('\U00010cb0', '\U00010cf0'), ('\U00010cb1', '\U00010cf1'), ('\U00010cb2', '\U00010cf2'),
+        ('\U000118a0', '\U000118c0'), ('\U000118a1', '\U000118c1'), ('\U000118a2', '\U000118c2'),
+        ('\U000118a3', '\U000118c3'), ('\U000118a4', '\U000118c4'), ('\U000118a5', '\U000118c5'),
+        ('\U000118a6', '\U000118c6'), ('\U000118a7', '\U000118c7'), ('\U000118a8', '\U000118c8'),
+        ('\U000118a9', '\U000118c9'), ('\U000118aa', '\U000118ca'), ('\U000118ab', '\U000118cb'),
+        ('\U000118ac', '\U000118cc'), ('\U000118ad', '\U000118cd'), ('\U000118ae', '\U000118ce'),
+        ('\U000118af', '\U000118cf'), ('\U000118b0', '\U000118d0'), ('\U000118b1', '\U000118d1'),
+        ('\U000118b2', '\U000118d2'), ('\U000118b3', '\U000118d3'), ('\U000118b4', '\U000118d4'),
+        ('\U000118b5', '\U000118d5'), ('\U000118b6', '\U000118d6'), ('\U000118b7', '\U000118d7'),
+        ('\U000118b8', '\U000118d8'), ('\U000118b9', '\U000118d9'), ('\U000118ba', '\U000118da'),
+        ('\U000118bb', '\U000118db'), ('\U000118bc', '\U000118dc'), ('\U000118bd', '\U000118dd'),
+        ('\U000118be', '\U000118de'), ('\U000118bf', '\U000118df'), ('\U00016e40', '\U00016e60'),
+        ('\U00016e41', '\U00016e61'), ('\U00016e42', '\U00016e62'), ('\U00016e43', '\U00016e63'),
+        ('\U00016e44', '\U00016e64'), ('\U00016e45', '\U00016e65'), ('\U00016e46', '\U00016e66'),
+        ('\U00016e47', '\U00016e67'), ('\U00016e48', '\U00016e68'), ('\U00016e49', '\U00016e69'),
+        ('\U00016e4a', '\U00016e6a'), ('\U00016e4b', '\U00016e6b'), ('\U00016e4c', '\U00016e6c'),
+        ('\U00016e4d', '\U00016e6d'), ('\U00016e4e', '\U00016e6e'), ('\U00016e4f', '\U00016e6f'),
+        ('\U00016e50', '\U00016e70'), ('\U00016e51', '\U00016e71'), ('\U00016e52', '\U00016e72'),
+        ('\U00016e53', '\U00016e73'), ('\U00016e54', '\U00016e74'), ('\U00016e55', '\U00016e75'),
+        ('\U00016e56', '\U00016e76'), ('\U00016e57', '\U00016e77'), ('\U00016e58', '\U00016e78'),
+        ('\U00016e59', '\U00016e79'), ('\U00016e5a', '\U00016e7a'), ('\U00016e5b', '\U00016e7b'),
+        ('\U00016e5c', '\U00016e7c'), ('\U00016e5d', '\U00016e7d'), ('\U00016e5e', '\U00016e7e'),
+        ('\U00016e5f', '\U00016e7f'), ('\U0001e900', '\U0001e922'), ('\U0001e901', '\U0001e923'),
+        ('\U0001e902', '\U0001e924'), ('\U0001e903', '\U0001e925'), ('\U0001e904', '\U0001e926'),
+        ('\U0001e905', '\U0001e927'), ('\U0001e906', '\U0001e928'), ('\U0001e907', '\U0001e929'),
+        ('\U0001e908', '\U0001e92a'), ('\U0001e909', '\U0001e92b'), ('\U0001e90a', '\U0001e92c'),
+        ('\U0001e90b', '\U0001e92d'), ('\U0001e90c', '\U0001e92e'), ('\U0001e90d', '\U0001e92f'),
+        ('\U0001e90e', '\U0001e930'), ('\U0001e90f', '\U0001e931'), ('\U0001e910', '\U0001e932'),
+        ('\U0001e911', '\U0001e933'), ('\U0001e912', '\U0001e934'), ('\U0001e913', '\U0001e935'),
+        ('\U0001e914', '\U0001e936'), ('\U0001e915', '\U0001e937'), ('\U0001e916', '\U0001e938'),
+        ('\U0001e917', '\U0001e939'), ('\U0001e918', '\U0001e93a'), ('\U0001e919', '\U0001e93b'),
+        ('\U0001e91a', '\U0001e93c'), ('\U0001e91b', '\U0001e93d'), ('\U0001e91c', '\U0001e93e'),
+        ('\U0001e91d', '\U0001e93f'), ('\U0001e91e', '\U0001e940'), ('\U0001e91f', '\U0001e941'),
+        ('\U0001e920', '\U0001e942'), ('\U0001e921', '\U0001e943')
+    ];
+
+    /// Return the simple uppercase mapping of a character, or `None` if it maps to itself.
+    pub fn to_upper(c: char) -> Option<char> {
+        super::bsearch_map_table(c, UPPERCASE_MAPPING_TABLE)
+    }
+
+    /// Return the simple lowercase mapping of a character, or `None` if it maps to itself.
+    pub fn to_lower(c: char) -> Option<char> {
+        super::bsearch_map_table(c, LOWERCASE_MAPPING_TABLE)
+    }
+}
+
+pub mod east_asian_width {
+    use option::Option;
+    use char::{EastAsianWidth, Ambiguous, Fullwidth, Halfwidth, Narrow, Wide};
+
+    static EAST_ASIAN_WIDTH_TABLE: &'static [(char, char, EastAsianWidth)] = &[
+        ('\x20', '\x7e', Narrow), ('\u00a1', '\u00a1', Ambiguous), ('\u00a2', '\u00a3', Narrow),
+        ('\u00a4', '\u00a4', Ambiguous), ('\u00a5', '\u00a6', Narrow),
+        ('\u00a7', '\u00a8', Ambiguous), ('\u00aa', '\u00aa', Ambiguous),
+        ('\u00ac', '\u00ac', Narrow), ('\u00ad', '\u00ae', Ambiguous), ('\u00af', '\u00af', Narrow),
+        ('\u00b0', '\u00b4', Ambiguous), ('\u00b6', '\u00ba', Ambiguous),
+        ('\u00bc', '\u00bf', Ambiguous), ('\u00c6', '\u00c6', Ambiguous),
+        ('\u00d0', '\u00d0', Ambiguous), ('\u00d7', '\u00d8', Ambiguous),
+        ('\u00de', '\u00e1', Ambiguous), ('\u00e6', '\u00e6', Ambiguous),
+        ('\u00e8', '\u00ea', Ambiguous), ('\u00ec', '\u00ed', Ambiguous),
+        ('\u00f0', '\u00f0', Ambiguous), ('\u00f2', '\u00f3', Ambiguous),
+        ('\u00f7', '\u00fa', Ambiguous), ('\u00fc', '\u00fc', Ambiguous),
+        ('\u00fe', '\u00fe', Ambiguous), ('\u0101', '\u0101', Ambiguous),
+        ('\u0111', '\u0111', Ambiguous), ('\u0113', '\u0113', Ambiguous),
+        ('\u011b', '\u011b', Ambiguous), ('\u0126', '\u0127', Ambiguous),
+        ('\u012b', '\u012b', Ambiguous), ('\u0131', '\u0133', Ambiguous),
+        ('\u0138', '\u0138', Ambiguous), ('\u013f', '\u0142', Ambiguous),
+        ('\u0144', '\u0144', Ambiguous), ('\u0148', '\u014b', Ambiguous),
+        ('\u014d', '\u014d', Ambiguous), ('\u0152', '\u0153', Ambiguous),
+        ('\u0166', '\u0167', Ambiguous), ('\u016b', '\u016b', Ambiguous),
+        ('\u01ce', '\u01ce', Ambiguous), ('\u01d0', '\u01d0', Ambiguous),
+        ('\u01d2', '\u01d2', Ambiguous), ('\u01d4', '\u01d4', Ambiguous),
+        ('\u01d6', '\u01d6', Ambiguous), ('\u01d8', '\u01d8', Ambiguous),
+        ('\u01da', '\u01da', Ambiguous), ('\u01dc', '\u01dc', Ambiguous),
+        ('\u0251', '\u0251', Ambiguous), ('\u0261', '\u0261', Ambiguous),
+        ('\u02c4', '\u02c4', Ambiguous), ('\u02c7', '\u02c7', Ambiguous),
+        ('\u02c9', '\u02cb', Ambiguous), ('\u02cd', '\u02cd', Ambiguous),
+        ('\u02d0', '\u02d0', Ambiguous), ('\u02d8', '\u02db', Ambiguous),
+        ('\u02dd', '\u02dd', Ambiguous), ('\u02df', '\u02df', Ambiguous),
+        ('\u0300', '\u036f', Ambiguous), ('\u0391', '\u03a1', Ambiguous),
+        ('\u03a3', '\u03a9', Ambiguous), ('\u03b1', '\u03c1', Ambiguous),
+        ('\u03c3', '\u03c9', Ambiguous), ('\u0401', '\u0401', Ambiguous),
+        ('\u0410', '\u044f', Ambiguous), ('\u0451', '\u0451', Ambiguous),
+        ('\u1100', '\u115f', Wide), ('\u2010', '\u2010', Ambiguous),
+        ('\u2013', '\u2016', Ambiguous), ('\u2018', '\u2019', Ambiguous),
+        ('\u201c', '\u201d', Ambiguous), ('\u2020', '\u2022', Ambiguous),
+        ('\u2024', '\u2027', Ambiguous), ('\u2030', '\u2030', Ambiguous),
+        ('\u2032', '\u2033', Ambiguous), ('\u2035', '\u2035', Ambiguous),
+        ('\u203b', '\u203b', Ambiguous), ('\u203e', '\u203e', Ambiguous),
+        ('\u2074', '\u2074', Ambiguous), ('\u207f', '\u207f', Ambiguous),
+        ('\u2081', '\u2084', Ambiguous), ('\u20a9', '\u20a9', Halfwidth),
+        ('\u20ac', '\u20ac', Ambiguous), ('\u2103', '\u2103', Ambiguous),
+        ('\u2105', '\u2105', Ambiguous), ('\u2109', '\u2109', Ambiguous),
+        ('\u2113', '\u2113', Ambiguous), ('\u2116', '\u2116', Ambiguous),
+        ('\u2121', '\u2122', Ambiguous), ('\u2126', '\u2126', Ambiguous),
+        ('\u212b', '\u212b', Ambiguous), ('\u2153', '\u2154', Ambiguous),
+        ('\u215b', '\u215e', Ambiguous), ('\u2160', '\u216b', Ambiguous),
+        ('\u2170', '\u2179', Ambiguous), ('\u2189', '\u2189', Ambiguous),
+        ('\u2190', '\u2199', Ambiguous), ('\u21b8', '\u21b9', Ambiguous),
+        ('\u21d2', '\u21d2', Ambiguous), ('\u21d4', '\u21d4', Ambiguous),
+        ('\u21e7', '\u21e7', Ambiguous), ('\u2200', '\u2200', Ambiguous),
+        ('\u2202', '\u2203', Ambiguous), ('\u2207', '\u2208', Ambiguous),
+        ('\u220b', '\u220b', Ambiguous), ('\u220f', '\u220f', Ambiguous),
+        ('\u2211', '\u2211', Ambiguous), ('\u2215', '\u2215', Ambiguous),
+        ('\u221a', '\u221a', Ambiguous), ('\u221d', '\u2220', Ambiguous),
+        ('\u2223', '\u2223', Ambiguous), ('\u2225', '\u2225', Ambiguous),
+        ('\u2227', '\u222c', Ambiguous), ('\u222e', '\u222e', Ambiguous),
+        ('\u2234', '\u2237', Ambiguous), ('\u223c', '\u223d', Ambiguous),
+        ('\u2248', '\u2248', Ambiguous), ('\u224c', '\u224c', Ambiguous),
+        ('\u2252', '\u2252', Ambiguous), ('\u2260', '\u2261', Ambiguous),
+        ('\u2264', '\u2267', Ambiguous), ('\u226a', '\u226b', Ambiguous),
+        ('\u226e', '\u226f', Ambiguous), ('\u2282', '\u2283', Ambiguous),
+        ('\u2286', '\u2287', Ambiguous), ('\u2295', '\u2295', Ambiguous),
+        ('\u2299', '\u2299', Ambiguous), ('\u22a5', '\u22a5', Ambiguous),
+        ('\u22bf', '\u22bf', Ambiguous), ('\u2312', '\u2312', Ambiguous),
+        ('\u231a', '\u231b', Wide), ('\u2329', '\u232a', Wide), ('\u23e9', '\u23ec', Wide),
+        ('\u23f0', '\u23f0', Wide), ('\u23f3', '\u23f3', Wide), ('\u2460', '\u24e9', Ambiguous),
+        ('\u24eb', '\u254b', Ambiguous), ('\u2550', '\u2573', Ambiguous),
+        ('\u2580', '\u258f', Ambiguous), ('\u2592', '\u2595', Ambiguous),
+        ('\u25a0', '\u25a1', Ambiguous), ('\u25a3', '\u25a9', Ambiguous),
+        ('\u25b2', '\u25b3', Ambiguous), ('\u25b6', '\u25b7', Ambiguous),
+        ('\u25bc', '\u25bd', Ambiguous), ('\u25c0', '\u25c1', Ambiguous),
+        ('\u25c6', '\u25c8', Ambiguous), ('\u25cb', '\u25cb', Ambiguous),
+        ('\u25ce', '\u25d1', Ambiguous), ('\u25e2', '\u25e5', Ambiguous),
+        ('\u25ef', '\u25ef', Ambiguous), ('\u25fd', '\u25fe', Wide),
+        ('\u2605', '\u2606', Ambiguous), ('\u2609', '\u2609', Ambiguous),
+        ('\u260e', '\u260f', Ambiguous), ('\u2614', '\u2615', Wide),
+        ('\u261c', '\u261c', Ambiguous), ('\u261e', '\u261e', Ambiguous),
+        ('\u2640', '\u2640', Ambiguous), ('\u2642', '\u2642', Ambiguous),
+        ('\u2648', '\u2653', Wide), ('\u2660', '\u2661', Ambiguous),
+        ('\u2663', '\u2665', Ambiguous), ('\u2667', '\u266a', Ambiguous),
+        ('\u266c', '\u266d', Ambiguous), ('\u266f', '\u266f', Ambiguous),
+        ('\u267f', '\u267f', Wide), ('\u2693', '\u2693', Wide), ('\u269e', '\u269f', Ambiguous),
+        ('\u26a1', '\u26a1', Wide), ('\u26aa', '\u26ab', Wide), ('\u26bd', '\u26be', Wide),
+        ('\u26bf', '\u26bf', Ambiguous), ('\u26c4', '\u26c5', Wide),
+        ('\u26c6', '\u26cd', Ambiguous), ('\u26ce', '\u26ce', Wide),
+        ('\u26cf', '\u26d3', Ambiguous), ('\u26d4', '\u26d4', Wide),
+        ('\u26d5', '\u26e1', Ambiguous), ('\u26e3', '\u26e3', Ambiguous),
+        ('\u26e8', '\u26e9', Ambiguous), ('\u26ea', '\u26ea', Wide),
+        ('\u26eb', '\u26f1', Ambiguous), ('\u26f2', '\u26f3', Wide),
+        ('\u26f4', '\u26f4', Ambiguous), ('\u26f5', '\u26f5', Wide),
+        ('\u26f6', '\u26f9', Ambiguous), ('\u26fa', '\u26fa', Wide),
+        ('\u26fb', '\u26fc', Ambiguous), ('\u26fd', '\u26fd', Wide),
+        ('\u26fe', '\u26ff', Ambiguous), ('\u2705', '\u2705', Wide), ('\u270a', '\u270b', Wide),
+        ('\u2728', '\u2728', Wide), ('\u273d', '\u273d', Ambiguous), ('\u274c', '\u274c', Wide),
+        ('\u274e', '\u274e', Wide), ('\u2753', '\u2755', Wide), ('\u2757', '\u2757', Wide),
+        ('\u2776', '\u277f', Ambiguous), ('\u2795', '\u2797', Wide), ('\u27b0', '\u27b0', Wide),
+        ('\u27bf', '\u27bf', Wide), ('\u27e6', '\u27ed', Narrow), ('\u2985', '\u2986', Narrow),
+        ('\u2b1b', '\u2b1c', Wide), ('\u2b50', '\u2b50', Wide), ('\u2b55', '\u2b55', Wide),
+        ('\u2b56', '\u2b59', Ambiguous), ('\u2e80', '\u2e99', Wide), ('\u2e9b', '\u2ef3', Wide),
+        ('\u2f00', '\u2fd5', Wide), ('\u2ff0', '\u2ffb', Wide), ('\u3000', '\u3000', Fullwidth),
+        ('\u3001', '\u303e', Wide), ('\u3041', '\u3096', Wide), ('\u3099', '\u30ff', Wide),
+        ('\u3105', '\u312f', Wide), ('\u3131', '\u318e', Wide), ('\u3190', '\u31e3', Wide),
+        ('\u31f0', '\u321e', Wide), ('\u3220', '\u3247', Wide), ('\u3248', '\u324f', Ambiguous),
+        ('\u3250', '\u4dbf', Wide), ('\u4e00', '\ua48c', Wide), ('\ua490', '\ua4c6', Wide),
+        ('\ua960', '\ua97c', Wide), ('\uac00', '\ud7a3', Wide), ('\ue000', '\uf8ff', Ambiguous),
+        ('\uf900', '\ufaff', Wide), ('\ufe00', '\ufe0f', Ambiguous), ('\ufe10', '\ufe19', Wide),
+        ('\ufe30', '\ufe52', Wide), ('\ufe54', '\ufe66', Wide), ('\ufe68', '\ufe6b', Wide),
+        ('\uff01', '\uff60', Fullwidth), ('\uff61', '\uffbe', Halfwidth),
+        ('\uffc2', '\uffc7', Halfwidth), ('\uffca', '\uffcf', Halfwidth),
+        ('\uffd2', '\uffd7', Halfwidth), ('\uffda', '\uffdc', Halfwidth),
+        ('\uffe0', '\uffe6', Fullwidth), ('\uffe8', '\uffee', Halfwidth),
+        ('\ufffd', '\ufffd', Ambiguous), ('\U00016fe0', '\U00016fe4', Wide),
+        ('\U00016ff0', '\U00016ff1', Wide), ('\U00017000', '\U000187f7', Wide),
+        ('\U00018800', '\U00018cd5', Wide), ('\U00018d00', '\U00018d08', Wide),
+        ('\U0001aff0', '\U0001aff3', Wide), ('\U0001aff5', '\U0001affb', Wide),
+        ('\U0001affd', '\U0001affe', Wide), ('\U0001b000', '\U0001b122', Wide),
+        ('\U0001b150', '\U0001b152', Wide), ('\U0001b164', '\U0001b167', Wide),
+        ('\U0001b170', '\U0001b2fb', Wide), ('\U0001f004', '\U0001f004', Wide),
+        ('\U0001f0cf', '\U0001f0cf', Wide), ('\U0001f100', '\U0001f10a', Ambiguous),
+        ('\U0001f110', '\U0001f12d', Ambiguous), ('\U0001f130', '\U0001f169', Ambiguous),
+        ('\U0001f170', '\U0001f18d', Ambiguous), ('\U0001f18e', '\U0001f18e', Wide),
+        ('\U0001f18f', '\U0001f190', Ambiguous), ('\U0001f191', '\U0001f19a', Wide),
+        ('\U0001f19b', '\U0001f1ac', Ambiguous), ('\U0001f200', '\U0001f202', Wide),
+        ('\U0001f210', '\U0001f23b', Wide), ('\U0001f240', '\U0001f248', Wide),
+        ('\U0001f250', '\U0001f251', Wide), ('\U0001f260', '\U0001f265', Wide),
+        ('\U0001f300', '\U0001f320', Wide), ('\U0001f32d', '\U0001f335', Wide),
+        ('\U0001f337', '\U0001f37c', Wide), ('\U0001f37e', '\U0001f393', Wide),
+        ('\U0001f3a0', '\U0001f3ca', Wide), ('\U0001f3cf', '\U0001f3d3', Wide),
+        ('\U0001f3e0', '\U0001f3f0', Wide), ('\U0001f3f4', '\U0001f3f4', Wide),
+        ('\U0001f3f8', '\U0001f43e', Wide), ('\U0001f440', '\U0001f440', Wide),
+        ('\U0001f442', '\U0001f4fc', Wide), ('\U0001f4ff', '\U0001f53d', Wide),
+        ('\U0001f54b', '\U0001f54e', Wide), ('\U0001f550', '\U0001f567', Wide),
+        ('\U0001f57a', '\U0001f57a', Wide), ('\U0001f595', '\U0001f596', Wide),
+        ('\U0001f5a4', '\U0001f5a4', Wide), ('\U0001f5fb', '\U0001f64f', Wide),
+        ('\U0001f680', '\U0001f6c5', Wide), ('\U0001f6cc', '\U0001f6cc', Wide),
+        ('\U0001f6d0', '\U0001f6d2', Wide), ('\U0001f6d5', '\U0001f6d7', Wide),
+        ('\U0001f6dd', '\U0001f6df', Wide), ('\U0001f6eb', '\U0001f6ec', Wide),
+        ('\U0001f6f4', '\U0001f6fc', Wide), ('\U0001f7e0', '\U0001f7eb', Wide),
+        ('\U0001f7f0', '\U0001f7f0', Wide), ('\U0001f90c', '\U0001f93a', Wide),
+        ('\U0001f93c', '\U0001f945', Wide), ('\U0001f947', '\U0001f9ff', Wide),
+        ('\U0001fa70', '\U0001fa74', Wide), ('\U0001fa78', '\U0001fa7c', Wide),
+        ('\U0001fa80', '\U0001fa86', Wide), ('\U0001fa90', '\U0001faac', Wide),
+        ('\U0001fab0', '\U0001faba', Wide), ('\U0001fac0', '\U0001fac5', Wide),
+        ('\U0001fad0', '\U0001fad9', Wide), ('\U0001fae0', '\U0001fae7', Wide),
+        ('\U0001faf0', '\U0001faf6', Wide), ('\U00020000', '\U0002fffd', Wide),
+        ('\U00030000', '\U0003fffd', Wide), ('\U000e0100', '\U000e01ef', Ambiguous),
+        ('\U000f0000', '\U000ffffd', Ambiguous), ('\U00100000', '\U0010fffd', Ambiguous)
+    ];
+
+    /// Look up the `East_Asian_Width` of a character, or `None` if it is neutral.
+    pub fn lookup(c: char) -> Option<EastAsianWidth> {
+        super::bsearch_value_table(c, EAST_ASIAN_WIDTH_TABLE)
+    }
+}
@@ -10,16 +10,20 @@
 
 use container::Container;
 use mem::{forget, move_val_init, size_of, transmute};
-use fail::out_of_memory;
+use fail::{abort, out_of_memory};
+use hash::HashBytes;
 use heap::{free, alloc, realloc};
-use ops::Drop;
-use slice::{Items, Slice, iter, unchecked_get, unchecked_mut_get};
-use ptr::{offset, read_ptr};
-use uint::mul_with_overflow;
+use ops::{Drop, Index};
+use slice::{Items, Slice, iter, unchecked_get, unchecked_mut_get, unchecked_swap};
+use slice;
+use ptr::{copy_memory, copy_nonoverlapping_memory, offset, read_ptr};
+use uint::{add_with_overflow, mul_with_overflow};
 use option::{Option, Some, None};
-use iter::{Iterator, DoubleEndedIterator};
-use cmp::expect;
+use iter::{Iterator, DoubleEndedIterator, FromIterator, Extendable, range};
+use cmp::{Eq, Ord, Ordering, expect, max};
 use clone::Clone;
+use fmt;
+use fmt::Show;
 
 pub struct Vec<T> {
     priv len: uint,
@@ -64,6 +68,18 @@ impl<T> Vec<T> {
         }
     }
 
+    /// Reserve capacity for at least `extra` more elements, growing geometrically to keep repeated
+    /// calls amortized.
+    pub fn reserve_additional(&mut self, extra: uint) {
+        if self.cap - self.len < extra {
+            let (needed, overflow) = add_with_overflow(self.len, extra);
+            if overflow {
+                out_of_memory();
+            }
+            self.reserve(max(needed, self.cap * 2));
+        }
+    }
+
     #[inline]
     pub fn shrink_to_fit(&mut self) {
         if self.len == 0 {
@@ -111,15 +127,167 @@ impl<T> Vec<T> {
     }
 
     pub fn truncate(&mut self, len: uint) {
+        let old_len = self.len;
+        if len < old_len {
+            // shorten the vector before running the destructors, so a failure leaks the rest
+            self.len = len;
+            unsafe {
+                for i in range(len, old_len) {
+                    read_ptr(offset(self.ptr as *T, i as int));
+                }
+            }
+        }
+    }
+
+    /// Remove and destroy all of the elements, keeping the capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0)
+    }
+
+    /// Insert an element at `index`, shifting the following elements up by one. Calls `abort` if
+    /// `index` is greater than the length.
+    pub fn insert(&mut self, index: uint, value: T) {
+        if index > self.len {
+            abort()
+        }
+        self.reserve_additional(1);
         unsafe {
-            let mut i = len;
-            // drop any extra elements
-            while i < self.len {
-                read_ptr(unchecked_get(self.as_slice(), i));
-                i += 1;
+            let p = offset(self.ptr as *T, index as int) as *mut T;
+            copy_memory(offset(p as *T, 1) as *mut T, p as *T, self.len - index);
+            move_val_init(&mut *p, value);
+        }
+        self.len += 1;
+    }
+
+    /// Remove and return the element at `index`, shifting the following elements down by one.
+    pub fn remove(&mut self, index: uint) -> Option<T> {
+        if index >= self.len {
+            None
+        } else {
+            unsafe {
+                let p = offset(self.ptr as *T, index as int) as *mut T;
+                let x = read_ptr(p as *T);
+                copy_memory(p, offset(p as *T, 1), self.len - index - 1);
+                self.len -= 1;
+                Some(x)
             }
         }
-        self.len = len;
+    }
+
+    /// Remove and return the element at `index`, replacing it with the last element. This does
+    /// not preserve ordering, but is O(1).
+    pub fn swap_remove(&mut self, index: uint) -> Option<T> {
+        if index >= self.len {
+            None
+        } else {
+            let last = self.len - 1;
+            unsafe { unchecked_swap(self.as_mut_slice(), index, last) }
+            self.pop()
+        }
+    }
+
+    /// Retain only the elements satisfying the predicate, preserving their order.
+    pub fn retain(&mut self, f: |&T| -> bool) {
+        let len = self.len;
+        let mut kept = 0;
+        // Only the retained prefix belongs to the vector while the predicate and destructors run,
+        // so a failure in either leaks the unprocessed elements instead of dropping any twice.
+        self.len = 0;
+        unsafe {
+            for i in range(0, len) {
+                let p = offset(self.ptr as *T, i as int);
+                if f(&*p) {
+                    if kept != i {
+                        let dst = offset(self.ptr as *T, kept as int) as *mut T;
+                        copy_nonoverlapping_memory(dst, p, 1);
+                    }
+                    kept += 1;
+                    self.len = kept;
+                } else {
+                    read_ptr(p);
+                }
+            }
+        }
+    }
+
+    /// Remove consecutive elements considered the same by `same`, keeping the first of each run.
+    /// The closure is passed the candidate followed by the last retained element.
+    pub fn dedup_by(&mut self, same: |&T, &T| -> bool) {
+        let len = self.len;
+        if len <= 1 {
+            return
+        }
+        let mut kept = 1;
+        // as with `retain`, only the retained prefix is owned by the vector during the pass
+        self.len = 1;
+        unsafe {
+            for i in range(1, len) {
+                let p = offset(self.ptr as *T, i as int);
+                let last = offset(self.ptr as *T, kept as int - 1);
+                if same(&*p, &*last) {
+                    read_ptr(p);
+                } else {
+                    if kept != i {
+                        let dst = offset(self.ptr as *T, kept as int) as *mut T;
+                        copy_nonoverlapping_memory(dst, p, 1);
+                    }
+                    kept += 1;
+                    self.len = kept;
+                }
+            }
+        }
+    }
+
+    /// Remove the elements from `start` to `end`, returning them from an iterator. The remaining
+    /// elements are shifted down when the iterator is destroyed, and any elements it did not yield
+    /// are dropped. Calls `abort` if the range is invalid.
+    pub fn drain<'a>(&'a mut self, start: uint, end: uint) -> Drain<'a, T> {
+        unsafe {
+            let iter = transmute(iter(slice::slice(self.as_slice(), start, end)));
+            let tail_len = self.len - end;
+            // The drained range and the tail are owned by the iterator until it is destroyed, so
+            // leaking it leaks those elements instead of exposing them twice.
+            self.len = start;
+            Drain { vec: self, tail: end, tail_len: tail_len, iter: iter }
+        }
+    }
+
+    /// Split the vector in two at `at`, returning the elements from `at` onwards. Calls `abort` if
+    /// `at` is greater than the length.
+    pub fn split_off(&mut self, at: uint) -> Vec<T> {
+        if at > self.len {
+            abort()
+        }
+        let other_len = self.len - at;
+        let mut other = Vec::with_capacity(other_len);
+        unsafe {
+            copy_nonoverlapping_memory(other.ptr, offset(self.ptr as *T, at as int), other_len);
+        }
+        self.len = at;
+        other.len = other_len;
+        other
+    }
+
+    /// Move all of the elements of `other` to the end of the vector, leaving `other` empty.
+    pub fn append(&mut self, other: &mut Vec<T>) {
+        self.reserve_additional(other.len);
+        unsafe {
+            let end = offset(self.ptr as *T, self.len as int) as *mut T;
+            copy_nonoverlapping_memory(end, other.ptr as *T, other.len);
+        }
+        self.len += other.len;
+        other.len = 0;
+    }
+
+    /// Replace the elements from `start` to `end` with those yielded by the iterator, returning
+    /// the removed elements. Calls `abort` if the range is invalid.
+    pub fn splice<I: Iterator<T>>(&mut self, start: uint, end: uint, iterator: &mut I) -> Vec<T> {
+        let removed = self.drain(start, end).collect();
+        let mut tail = self.split_off(start);
+        self.extend(iterator);
+        self.append(&mut tail);
+        removed
     }
 
     #[inline]
@@ -170,6 +338,68 @@ impl<T: Clone> Vec<T> {
             xs
         }
     }
+
+    /// Append clones of the elements of a slice.
+    pub fn extend_from_slice(&mut self, xs: &[T]) {
+        self.reserve_additional(xs.len());
+        for x in iter(xs) {
+            self.push(x.clone());
+        }
+    }
+}
+
+impl<T: Eq> Vec<T> {
+    /// Remove consecutive equal elements, keeping the first of each run.
+    #[inline]
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| *a == *b)
+    }
+}
+
+impl<T> Vec<T> {
+    /// Sort the vector with a stable merge sort and a comparison function.
+    #[inline]
+    pub fn sort_by(&mut self, compare: |&T, &T| -> Ordering) {
+        slice::sort_by(self.as_mut_slice(), compare)
+    }
+
+    /// Sort the vector in place with an unstable introsort and a comparison function.
+    #[inline]
+    pub fn sort_unstable_by(&mut self, compare: |&T, &T| -> Ordering) {
+        slice::sort_unstable_by(self.as_mut_slice(), compare)
+    }
+
+    /// Move the elements satisfying the predicate to the front, returning their number.
+    #[inline]
+    pub fn partition(&mut self, predicate: |&T| -> bool) -> uint {
+        slice::partition(self.as_mut_slice(), predicate)
+    }
+}
+
+impl<T: Ord> Vec<T> {
+    /// Sort the vector with a stable merge sort.
+    #[inline]
+    pub fn sort(&mut self) {
+        slice::sort(self.as_mut_slice())
+    }
+
+    /// Sort the vector in place with an unstable introsort.
+    #[inline]
+    pub fn sort_unstable(&mut self) {
+        slice::sort_unstable(self.as_mut_slice())
+    }
+
+    /// Return true if the vector is sorted in ascending order.
+    #[inline]
+    pub fn is_sorted(&self) -> bool {
+        slice::is_sorted(self.as_slice())
+    }
+
+    /// Reorder the vector so that the element at `n` is in its sorted position.
+    #[inline]
+    pub fn select_nth(&mut self, n: uint) {
+        slice::select_nth(self.as_mut_slice(), n)
+    }
 }
 
 impl<T> Container for Vec<T> {
@@ -179,6 +409,70 @@ impl<T> Container for Vec<T> {
     }
 }
 
+impl<T> FromIterator<T> for Vec<T> {
+    fn from_iterator<I: Iterator<T>>(iterator: &mut I) -> Vec<T> {
+        let (lower, _) = iterator.size_hint();
+        let mut xs = Vec::with_capacity(lower);
+        for x in *iterator {
+            xs.push(x);
+        }
+        xs
+    }
+}
+
+impl<T> Extendable<T> for Vec<T> {
+    fn extend<I: Iterator<T>>(&mut self, iterator: &mut I) {
+        let (lower, _) = iterator.size_hint();
+        self.reserve_additional(lower);
+        for x in *iterator {
+            self.push(x);
+        }
+    }
+}
+
+impl<T: Clone> Clone for Vec<T> {
+    fn clone(&self) -> Vec<T> {
+        let mut xs = Vec::with_capacity(self.len);
+        xs.extend_from_slice(self.as_slice());
+        xs
+    }
+}
+
+impl<T: Clone> Index<uint, T> for Vec<T> {
+    /// Return a copy of the element at `index`, calling `abort` if it is out of bounds.
+    #[inline]
+    fn index(&self, index: &uint) -> T {
+        self.as_slice()[*index].clone()
+    }
+}
+
+impl<T: Eq> Eq for Vec<T> {
+    #[inline]
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Ord> Ord for Vec<T> {
+    #[inline]
+    fn lt(&self, other: &Vec<T>) -> bool {
+        self.as_slice() < other.as_slice()
+    }
+}
+
+impl<T: Show> Show for Vec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_slice().fmt(f)
+    }
+}
+
+impl<T: HashBytes> HashBytes for Vec<T> {
+    #[inline]
+    fn hash_bytes(&self, f: |&[u8]|) {
+        self.as_slice().hash_bytes(f)
+    }
+}
+
 #[unsafe_destructor]
 impl<T> Drop for Vec<T> {
     fn drop(&mut self) {
@@ -226,3 +520,45 @@ impl<T> Drop for MoveItems<T> {
         }
     }
 }
+
+pub struct Drain<'a, T> {
+    priv vec: &'a mut Vec<T>,
+    priv tail: uint, // the index of the first element after the drained range
+    priv tail_len: uint,
+    priv iter: Items<'static, T>
+}
+
+impl<'a, T> Iterator<T> for Drain<'a, T> {
+    fn next(&mut self) -> Option<T> {
+        unsafe {
+            self.iter.next().map(|x| read_ptr(x))
+        }
+    }
+
+    fn size_hint(&self) -> (uint, Option<uint>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator<T> for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        unsafe {
+            self.iter.next_back().map(|x| read_ptr(x))
+        }
+    }
+}
+
+#[unsafe_destructor]
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        // destroy the remaining drained elements
+        for _x in *self {}
+        unsafe {
+            let start = self.vec.len;
+            let src = offset(self.vec.ptr as *T, self.tail as int);
+            let dst = offset(self.vec.ptr as *T, start as int) as *mut T;
+            copy_memory(dst, src, self.tail_len);
+            self.vec.len = start + self.tail_len;
+        }
+    }
+}
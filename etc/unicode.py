@@ -0,0 +1,253 @@
+#!/usr/bin/env python3
+#
+# Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+# file at the top-level directory of this distribution and at
+# http://rust-lang.org/COPYRIGHT.
+#
+# Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+# http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+# <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+# option. This file may not be copied, modified, or distributed
+# except according to those terms.
+
+# Generate core/unicode.rs from the Unicode Character Database snapshot in etc/ucd.
+#
+# Usage: python3 etc/unicode.py
+#
+# To move to a new Unicode version, replace the files in etc/ucd with extracts of the new release
+# (keeping the same format) and rerun this script.
+
+import os
+import re
+
+ROOT = os.path.dirname(os.path.dirname(os.path.abspath(__file__)))
+UCD = os.path.join(ROOT, "etc", "ucd")
+OUT = os.path.join(ROOT, "core", "unicode.rs")
+
+CATEGORIES = ["Lu", "Ll", "Lt", "Lm", "Lo", "Mn", "Mc", "Me", "Nd", "Nl", "No", "Pc", "Pd", "Ps",
+              "Pe", "Pi", "Pf", "Po", "Sm", "Sc", "Sk", "So", "Zs", "Zl", "Zp", "Cc", "Cf", "Cs",
+              "Co", "Cn"]
+
+WIDTHS = {"A": "Ambiguous", "F": "Fullwidth", "H": "Halfwidth", "N": "Neutral", "Na": "Narrow",
+          "W": "Wide"}
+
+HEADER = """\
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// NOTE: generated by etc/unicode.py from the Unicode Character Database %s, do not edit
+
+//! Unicode property tables backing the classification functions in `char`.
+
+use container::Container;
+use option::{Option, Some, None};
+
+/// The version of the Unicode Character Database the tables were generated from.
+pub static UNICODE_VERSION: (uint, uint, uint) = (%s);
+
+fn bsearch_range_table(c: char, r: &'static [(char, char)]) -> bool {
+    let mut lo = 0;
+    let mut hi = r.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (start, end) = r[mid];
+        if c < start {
+            hi = mid
+        } else if c > end {
+            lo = mid + 1
+        } else {
+            return true
+        }
+    }
+    false
+}
+
+fn bsearch_value_table<T: Clone>(c: char, r: &'static [(char, char, T)]) -> Option<T> {
+    let mut lo = 0;
+    let mut hi = r.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (start, end, ref value) = r[mid];
+        if c < start {
+            hi = mid
+        } else if c > end {
+            lo = mid + 1
+        } else {
+            return Some(value.clone())
+        }
+    }
+    None
+}
+
+fn bsearch_map_table(c: char, r: &'static [(char, char)]) -> Option<char> {
+    let mut lo = 0;
+    let mut hi = r.len();
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let (key, value) = r[mid];
+        if c < key {
+            hi = mid
+        } else if c > key {
+            lo = mid + 1
+        } else {
+            return Some(value)
+        }
+    }
+    None
+}
+"""
+
+
+def parse(name):
+    """Yield (first, last, fields) for each data line of a UCD file."""
+    with open(os.path.join(UCD, name)) as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+            if not line:
+                continue
+            fields = [x.strip() for x in line.split(";")]
+            first, _, last = fields[0].partition("..")
+            yield int(first, 16), int(last or first, 16), fields[1:]
+
+
+def version():
+    with open(os.path.join(UCD, "DerivedGeneralCategory.txt")) as f:
+        m = re.search(r"version (\d+)\.(\d+)\.(\d+)", f.read())
+    return m.groups()
+
+
+def merge(ranges):
+    """Sort (first, last, value) ranges and coalesce adjacent ones with equal values."""
+    out = []
+    for first, last, value in sorted(ranges):
+        if out and out[-1][1] + 1 == first and out[-1][2] == value:
+            out[-1] = (out[-1][0], last, value)
+        else:
+            out.append((first, last, value))
+    return out
+
+
+def escape(c):
+    if c < 0x80:
+        return "'\\x%02x'" % c
+    if c < 0x10000:
+        return "'\\u%04x'" % c
+    return "'\\U%08x'" % c
+
+
+def emit_table(out, name, entries):
+    out.append("    static %s: &'static [%s] = &[\n" % (name[0], name[1]))
+    line = "       "
+    for i, entry in enumerate(entries):
+        item = " (%s)%s" % (", ".join(entry), "," if i + 1 < len(entries) else "")
+        if len(line) + len(item) > 100:
+            out.append(line + "\n")
+            line = "       "
+        line += item
+    out.append(line + "\n    ];\n")
+
+
+def emit_use(out, path, names):
+    line = "    use %s::{" % path
+    for i, name in enumerate(names):
+        item = name + (", " if i + 1 < len(names) else "};")
+        if len(line) + len(item.rstrip()) > 100:
+            out.append(line.rstrip() + "\n")
+            line = "              "
+        line += item
+    out.append(line + "\n\n")
+
+
+def emit_range_fn(out, fn, table, ranges, doc):
+    emit_table(out, (table, "(char, char)"), [(escape(a), escape(b)) for a, b, _ in ranges])
+    out.append("\n    /// %s\n" % doc)
+    out.append("    pub fn %s(c: char) -> bool {\n" % fn)
+    out.append("        super::bsearch_range_table(c, %s)\n    }\n" % table)
+
+
+def emit_properties(out, module, name, props):
+    values = {}
+    for first, last, fields in parse(name):
+        values.setdefault(fields[0], []).append((first, last, True))
+    out.append("\npub mod %s {\n" % module)
+    for i, prop in enumerate(props):
+        if i:
+            out.append("\n")
+        emit_range_fn(out, prop.lower(), prop.upper() + "_TABLE", merge(values[prop]),
+                      "Return true if the character has the `%s` property." % prop)
+    out.append("}\n")
+
+
+def main():
+    ver = version()
+    out = [HEADER % (".".join(ver), ", ".join(ver))]
+
+    categories = list(parse("DerivedGeneralCategory.txt"))
+    out.append("\npub mod general_category {\n")
+    out.append("    use option::Option;\n")
+    emit_use(out, "char", ["GeneralCategory"] + CATEGORIES[:-1])
+    emit_table(out, ("GENERAL_CATEGORY_TABLE", "(char, char, GeneralCategory)"),
+               [(escape(a), escape(b), v) for a, b, v in
+                merge((a, b, f[0]) for a, b, f in categories)])
+    out.append("\n    /// Look up the `General_Category` of a character, or `None` if it is unassigned.\n")
+    out.append("    pub fn lookup(c: char) -> Option<GeneralCategory> {\n")
+    out.append("        super::bsearch_value_table(c, GENERAL_CATEGORY_TABLE)\n    }\n\n")
+    emit_range_fn(out, "number", "NUMBER_TABLE",
+                  merge((a, b, True) for a, b, f in categories if f[0][0] == "N"),
+                  "Return true if the character is in one of the `Nd`, `Nl` or `No` categories.")
+    out.append("}\n")
+
+    emit_properties(out, "derived_property", "DerivedCoreProperties.txt",
+                    ["Alphabetic", "Lowercase", "Uppercase"])
+    emit_properties(out, "property", "PropList.txt", ["White_Space"])
+
+    upper = []
+    lower = []
+    for c, _, (up, lo) in parse("CaseMapping.txt"):
+        if up:
+            upper.append((escape(c), escape(int(up, 16))))
+        if lo:
+            lower.append((escape(c), escape(int(lo, 16))))
+    out.append("\npub mod conversions {\n")
+    out.append("    use option::Option;\n\n")
+    emit_table(out, ("UPPERCASE_MAPPING_TABLE", "(char, char)"), upper)
+    out.append("\n")
+    emit_table(out, ("LOWERCASE_MAPPING_TABLE", "(char, char)"), lower)
+    out.append("""
+    /// Return the simple uppercase mapping of a character, or `None` if it maps to itself.
+    pub fn to_upper(c: char) -> Option<char> {
+        super::bsearch_map_table(c, UPPERCASE_MAPPING_TABLE)
+    }
+
+    /// Return the simple lowercase mapping of a character, or `None` if it maps to itself.
+    pub fn to_lower(c: char) -> Option<char> {
+        super::bsearch_map_table(c, LOWERCASE_MAPPING_TABLE)
+    }
+}
+""")
+
+    out.append("\npub mod east_asian_width {\n")
+    out.append("    use option::Option;\n")
+    emit_use(out, "char", ["EastAsianWidth"] +
+             [v for v in sorted(WIDTHS.values()) if v != "Neutral"])
+    emit_table(out, ("EAST_ASIAN_WIDTH_TABLE", "(char, char, EastAsianWidth)"),
+               [(escape(a), escape(b), WIDTHS[v]) for a, b, v in
+                merge((a, b, f[0]) for a, b, f in parse("EastAsianWidth.txt"))])
+    out.append("\n    /// Look up the `East_Asian_Width` of a character, or `None` if it is neutral.\n")
+    out.append("    pub fn lookup(c: char) -> Option<EastAsianWidth> {\n")
+    out.append("        super::bsearch_value_table(c, EAST_ASIAN_WIDTH_TABLE)\n    }\n")
+    out.append("}\n")
+
+    with open(OUT, "w") as f:
+        f.write("".join(out))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,55 @@
+#[no_std];
+
+extern crate core;
+
+use core::array_vec::ArrayVec;
+use core::container::Container;
+use core::fail::abort;
+use core::iter::{Iterator, DoubleEndedIterator, range};
+use core::result::Err;
+
+fn test_push_pop() {
+    let mut xs: ArrayVec<int, [int, ..4]> = ArrayVec::new();
+    if xs.capacity() != 4 || xs.len() != 0 { abort() }
+    for i in range(0, 4) {
+        if xs.push(i).is_err() { abort() }
+    }
+    if !xs.is_full() { abort() }
+    match xs.push(4) {
+        Err(4) => (),
+        _ => abort()
+    }
+    if xs.as_slice() != &[0, 1, 2, 3] { abort() }
+    if xs.pop().get() != 3 || xs.len() != 3 { abort() }
+    xs.as_mut_slice()[0] = 10;
+    xs.truncate(2);
+    if xs.as_slice() != &[10, 1] { abort() }
+    xs.clear();
+    if xs.pop().is_some() { abort() }
+}
+
+fn test_move_iter() {
+    let mut xs: ArrayVec<~int, [~int, ..8]> = ArrayVec::new();
+    for i in range(0, 5) {
+        if xs.push(~i).is_err() { abort() }
+    }
+    let mut it = xs.move_iter();
+    let (lower, upper) = it.size_hint();
+    if lower != 5 || upper.get() != 5 { abort() }
+    if *it.next().get() != 0 { abort() }
+    if *it.next_back().get() != 4 { abort() }
+    // the remaining elements are destroyed with the iterator
+}
+
+fn test_destructor() {
+    let mut xs: ArrayVec<~int, [~int, ..4]> = ArrayVec::new();
+    if xs.push(~1).is_err() || xs.push(~2).is_err() { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_push_pop();
+    test_move_iter();
+    test_destructor();
+    0
+}
@@ -0,0 +1,99 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#[no_std];
+
+extern crate core;
+
+use core::char;
+use core::char::{Lu, Ll, Nd, Nl, Zs, Cc, Co, Cn, Narrow, Wide, Fullwidth, Ambiguous, Neutral};
+use core::fail::abort;
+use core::option::{Some, None};
+
+fn test_properties() {
+    if !char::is_alphabetic('a') || !char::is_alphabetic('ß') { abort() }
+    if !char::is_alphabetic('中') || char::is_alphabetic('1') { abort() }
+    if char::is_alphabetic(' ') || char::is_alphabetic('€') { abort() }
+
+    if !char::is_numeric('7') || !char::is_numeric('٣') || !char::is_numeric('Ⅻ') { abort() }
+    if !char::is_numeric('½') || char::is_numeric('x') { abort() }
+
+    if !char::is_lowercase('q') || !char::is_lowercase('é') || char::is_lowercase('Q') { abort() }
+    if !char::is_uppercase('Q') || !char::is_uppercase('Σ') || char::is_uppercase('σ') { abort() }
+
+    if !char::is_whitespace('\t') || !char::is_whitespace('\u3000') { abort() }
+    if char::is_whitespace('\x1c') || char::is_whitespace('\u200b') { abort() }
+
+    if !char::is_control('\x00') || !char::is_control('\x9f') { abort() }
+    if char::is_control('\xa0') || char::is_control('a') { abort() }
+}
+
+fn test_general_category() {
+    if char::general_category('A') != Lu || char::general_category('ö') != Ll { abort() }
+    if char::general_category('9') != Nd || char::general_category('Ⅻ') != Nl { abort() }
+    if char::general_category(' ') != Zs || char::general_category('\x7f') != Cc { abort() }
+    if char::general_category('\ue000') != Co || char::general_category('\U000e0080') != Cn {
+        abort()
+    }
+}
+
+fn test_east_asian_width() {
+    if char::east_asian_width('a') != Narrow || char::east_asian_width('中') != Wide { abort() }
+    if char::east_asian_width('Ａ') != Fullwidth || char::east_asian_width('±') != Ambiguous {
+        abort()
+    }
+    if char::east_asian_width('\x00') != Neutral || char::east_asian_width('٣') != Neutral {
+        abort()
+    }
+}
+
+fn test_case() {
+    if char::to_uppercase('a') != 'A' || char::to_uppercase('Z') != 'Z' { abort() }
+    if char::to_uppercase('ÿ') != 'Ÿ' || char::to_uppercase('σ') != 'Σ' { abort() }
+    if char::to_lowercase('Σ') != 'σ' || char::to_lowercase('\U00010400') != '\U00010428' {
+        abort()
+    }
+    // characters with only a multi-character mapping are left alone
+    if char::to_uppercase('ß') != 'ß' || char::to_lowercase('1') != '1' { abort() }
+}
+
+fn test_digits() {
+    if char::to_digit('7', 10) != Some(7) || char::to_digit('f', 16) != Some(15) { abort() }
+    if char::to_digit('Z', 36) != Some(35) || char::to_digit('8', 8) != None { abort() }
+    if char::to_digit('g', 16) != None || char::to_digit('٣', 10) != None { abort() }
+
+    if char::from_digit(7, 10) != Some('7') || char::from_digit(11, 16) != Some('b') { abort() }
+    if char::from_digit(2, 2) != None { abort() }
+}
+
+fn test_encode() {
+    let mut buf = [0u8, ..4];
+    if char::encode_utf8('€', buf) != 3 || buf[0] != 0xE2 || buf[1] != 0x82 || buf[2] != 0xAC {
+        abort()
+    }
+
+    let mut buf = [0u16, ..2];
+    if char::encode_utf16('€', buf) != 1 || buf[0] != 0x20AC { abort() }
+    if char::encode_utf16('\U0001F600', buf) != 2 || buf[0] != 0xD83D || buf[1] != 0xDE00 {
+        abort()
+    }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_properties();
+    test_general_category();
+    test_east_asian_width();
+    test_case();
+    test_digits();
+    test_encode();
+    0
+}
@@ -0,0 +1,58 @@
+#[no_std];
+
+extern crate core;
+
+use core::concurrent::{Queue, BlockingPriorityQueue, BoundedQueue, BoundedPriorityQueue};
+use core::container::Container;
+use core::fail::abort;
+use core::slice::iter;
+use core::iter::Iterator;
+
+fn test_queue_pop_all() {
+    let queue = Queue::new();
+    for &x in iter(&[3, 1, 2]) {
+        queue.push(x);
+    }
+    if queue.pop_all().as_slice() != &[3, 1, 2] { abort() }
+    if queue.try_pop().is_some() || queue.pop_all().len() != 0 { abort() }
+}
+
+fn test_priority_queue_pop_all() {
+    let queue = BlockingPriorityQueue::new();
+    for &x in iter(&[3, 1, 4, 2]) {
+        queue.push(x);
+    }
+    if queue.pop_all().as_slice() != &[4, 3, 2, 1] { abort() }
+    if queue.try_pop().is_some() || queue.pop_all().len() != 0 { abort() }
+}
+
+fn test_bounded_queue_pop_all() {
+    let queue = BoundedQueue::new(3);
+    for &x in iter(&[3, 1, 2]) {
+        queue.push(x);
+    }
+    if queue.try_push(4).is_ok() { abort() }
+    if queue.pop_all().as_slice() != &[3, 1, 2] { abort() }
+    if queue.try_pop().is_some() { abort() }
+    if queue.try_push(4).is_err() || queue.pop_all().as_slice() != &[4] { abort() }
+}
+
+fn test_bounded_priority_queue_pop_all() {
+    let queue = BoundedPriorityQueue::new(3);
+    for &x in iter(&[1, 3, 2]) {
+        queue.push(x);
+    }
+    if queue.try_push(4).is_ok() { abort() }
+    if queue.pop_all().as_slice() != &[3, 2, 1] { abort() }
+    if queue.try_pop().is_some() { abort() }
+    if queue.try_push(4).is_err() || queue.pop_all().as_slice() != &[4] { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_queue_pop_all();
+    test_priority_queue_pop_all();
+    test_bounded_queue_pop_all();
+    test_bounded_priority_queue_pop_all();
+    0
+}
@@ -0,0 +1,103 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#[no_std];
+
+extern crate core;
+
+use core::container::Container;
+use core::dec2flt::{parse_f32, parse_f64};
+use core::fail::abort;
+use core::from_str::from_str;
+use core::iter::range;
+use core::mem::transmute;
+use core::option::Option;
+use core::str::as_bytes;
+
+fn f64_bits(s: &str) -> u64 {
+    unsafe { transmute(parse_f64(as_bytes(s)).get()) }
+}
+
+fn f32_bits(s: &str) -> u32 {
+    unsafe { transmute(parse_f32(as_bytes(s)).get()) }
+}
+
+fn test_parse_f64() {
+    if parse_f64(bytes!("0.1")).get() != 0.1 || parse_f64(bytes!("-12.5e-3")).get() != -0.0125 {
+        abort()
+    }
+    if parse_f64(bytes!(".5")).get() != 0.5 || parse_f64(bytes!("+5.")).get() != 5.0 { abort() }
+    if parse_f64(bytes!("000123.4500E+2")).get() != 12345.0 { abort() }
+    if parse_f64(bytes!("1e23")).get() != 1e23 || parse_f64(bytes!("1E-5")).get() != 1e-5 {
+        abort()
+    }
+
+    // ties round to even
+    if f64_bits("9007199254740993") != 0x4340000000000000 { abort() }
+    if f64_bits("9007199254740995") != 0x4340000000000002 { abort() }
+
+    // the bounds of the range, and halfway to zero and infinity
+    if f64_bits("2.2250738585072014e-308") != 0x0010000000000000 { abort() }
+    if f64_bits("2.2250738585072011e-308") != 0x000fffffffffffff { abort() }
+    if f64_bits("4.9e-324") != 1 || f64_bits("2.4703282292062328e-324") != 1 { abort() }
+    if f64_bits("2.4703282292062327e-324") != 0 || f64_bits("1e-400") != 0 { abort() }
+    if f64_bits("1.7976931348623158e308") != 0x7fefffffffffffff { abort() }
+    if f64_bits("1.7976931348623159e308") != 0x7ff0000000000000 { abort() }
+    if f64_bits("1e99999999999") != 0x7ff0000000000000 { abort() }
+    if f64_bits("-0") != 0x8000000000000000 || f64_bits("0e999") != 0 { abort() }
+
+    // a digit far past the others still breaks a tie
+    let long = "9007199254740993.00000000000000000000000000000000000000000000000000000000000000001";
+    if f64_bits(long) != 0x4340000000000001 { abort() }
+
+    if f64_bits("inf") != 0x7ff0000000000000 || f64_bits("-Infinity") != 0xfff0000000000000 {
+        abort()
+    }
+    let nan = parse_f64(bytes!("NaN")).get();
+    if nan == nan { abort() }
+}
+
+fn test_parse_f32() {
+    if parse_f32(bytes!("0.1")).get() != 0.1f32 || parse_f32(bytes!("-1.5")).get() != -1.5 {
+        abort()
+    }
+    if f32_bits("16777217") != 0x4b800000 || f32_bits("3.4028235e38") != 0x7f7fffff { abort() }
+    if f32_bits("3.4028236e38") != 0x7f800000 || f32_bits("1.4e-45") != 1 { abort() }
+    if f32_bits("1e-46") != 0 || f32_bits("1.1754943e-38") != 0x00800000 { abort() }
+
+    // rounded directly rather than through a `f64`
+    if f32_bits("1.00000005960464477539062500000001") != 0x3f800001 { abort() }
+}
+
+fn test_invalid() {
+    let invalid = ["", "-", ".", "e5", "1e", "1e+", "1.2.3", " 1", "1 ", "0x10", "infinit", "1_0"];
+    for i in range(0, invalid.len()) {
+        if parse_f64(as_bytes(invalid[i])).is_some() { abort() }
+    }
+}
+
+fn test_from_str() {
+    let x: Option<f64> = from_str("2.5");
+    if x.get() != 2.5 { abort() }
+    let x: Option<f32> = from_str("-0.75");
+    if x.get() != -0.75 { abort() }
+    let x: Option<f64> = from_str("2.5x");
+    if x.is_some() { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_parse_f64();
+    test_parse_f32();
+    test_invalid();
+    test_from_str();
+    0
+}
@@ -5,6 +5,8 @@ extern crate core;
 use core::container::Container;
 use core::deque::Deque;
 use core::fail::abort;
+use core::iter::{Iterator, DoubleEndedIterator, Extendable};
+use core::slice::iter;
 
 fn test_new() {
     let xs = Deque::<int>::new();
@@ -65,6 +67,150 @@ fn test_swap() {
     if *xs.get(4).get() != 3 { abort() }
 }
 
+fn test_reserve_wrapped() {
+    let mut xs = Deque::with_capacity(4);
+    xs.push_back(3);
+    xs.push_back(4);
+    xs.push_front(2);
+    xs.push_front(1);
+    xs.reserve(10);
+    if xs.capacity() != 10 { abort() }
+    xs.push_back(5);
+    let mut i = 0;
+    while i < 5 {
+        if *xs.get(i).get() != i as int + 1 { abort() }
+        i += 1;
+    }
+}
+
+fn test_collect_extend() {
+    let xs = [1, 2, 3];
+    let mut d: Deque<int> = iter(xs).map(|&x| x).collect();
+    if d.len() != 3 || d.capacity() != 3 { abort() }
+    d.push_front(0);
+    d.extend(&mut iter(xs).map(|&x| x + 3));
+    if d.len() != 7 { abort() }
+    let mut i = 0;
+    while i < 7 {
+        if *d.get(i).get() != i as int { abort() }
+        i += 1;
+    }
+}
+
+fn deque(xs: &[int]) -> Deque<int> {
+    iter(xs).map(|&x| x).collect()
+}
+
+// [1, 2, 3, 4] wrapped around the end of a full buffer
+fn wrapped() -> Deque<int> {
+    let mut xs = Deque::with_capacity(4);
+    xs.push_back(3);
+    xs.push_back(4);
+    xs.push_front(2);
+    xs.push_front(1);
+    xs
+}
+
+fn test_iter() {
+    let mut xs = wrapped();
+    {
+        let mut it = xs.iter();
+        let (lower, upper) = it.size_hint();
+        if lower != 4 || upper.get() != 4 { abort() }
+        if *it.next().get() != 1 || *it.next_back().get() != 4 { abort() }
+        if *it.next().get() != 2 || *it.next().get() != 3 || it.next().is_some() { abort() }
+    }
+
+    for x in xs.mut_iter() {
+        *x *= 10;
+    }
+    if xs[0] != 10 || xs[3] != 40 { abort() }
+
+    let mut it = xs.move_iter();
+    if it.next_back().get() != 40 || it.next().get() != 10 { abort() }
+}
+
+fn test_as_slices() {
+    let mut xs = wrapped();
+    {
+        let (a, b) = xs.as_slices();
+        if a != &[1, 2] || b != &[3, 4] { abort() }
+    }
+    {
+        let xs = xs.make_contiguous();
+        if xs.len() != 4 || xs[0] != 1 || xs[3] != 4 { abort() }
+    }
+    let (a, b) = xs.as_slices();
+    if a != &[1, 2, 3, 4] || b.len() != 0 { abort() }
+}
+
+fn test_rotate() {
+    let mut xs = wrapped();
+    xs.rotate_left(1);
+    if xs != deque(&[2, 3, 4, 1]) { abort() }
+    xs.rotate_right(2);
+    if xs != deque(&[4, 1, 2, 3]) { abort() }
+
+    let mut ys = deque(&[1, 2, 3, 4, 5]);
+    ys.reserve(10);
+    ys.rotate_left(4);
+    if ys != deque(&[5, 1, 2, 3, 4]) { abort() }
+}
+
+fn test_insert_remove() {
+    let mut xs = wrapped();
+    xs.insert(1, 10);
+    xs.insert(4, 11);
+    xs.insert(6, 12);
+    if xs != deque(&[1, 10, 2, 3, 11, 4, 12]) { abort() }
+    if xs.remove(7).is_some() { abort() }
+    if xs.remove(1).get() != 10 || xs.remove(3).get() != 11 || xs.remove(4).get() != 12 {
+        abort()
+    }
+    if xs != wrapped() { abort() }
+}
+
+fn test_retain_truncate() {
+    let mut xs = deque(&[1, 2, 3, 4, 5, 6]);
+    xs.push_front(0);
+    xs.retain(|x| *x % 2 == 0);
+    if xs != deque(&[0, 2, 4, 6]) { abort() }
+    xs.truncate(2);
+    if xs != deque(&[0, 2]) { abort() }
+    xs.clear();
+    if xs.len() != 0 { abort() }
+}
+
+fn test_shrink_to_fit() {
+    let mut xs = wrapped();
+    xs.reserve(16);
+    xs.pop_back();
+    xs.shrink_to_fit();
+    if xs.capacity() != 3 || xs != deque(&[1, 2, 3]) { abort() }
+    xs.push_front(0);
+    if xs[0] != 0 || xs[3] != 3 { abort() }
+}
+
+fn test_clone_cmp() {
+    let xs = wrapped();
+    let ys = xs.clone();
+    if xs != ys || xs < ys { abort() }
+    let mut zs = ys.clone();
+    zs.push_back(5);
+    if !(xs < zs) || zs < xs { abort() }
+}
+
+fn test_drain() {
+    let mut xs = wrapped();
+    {
+        let mut drain = xs.drain();
+        if drain.next().get() != 1 || drain.next_back().get() != 4 { abort() }
+    }
+    if xs.len() != 0 { abort() }
+    xs.push_back(5);
+    if xs[0] != 5 { abort() }
+}
+
 #[start]
 fn main(_: int, _: **u8) -> int {
     test_new();
@@ -73,5 +219,15 @@ fn main(_: int, _: **u8) -> int {
     test_reserve();
     test_destructor();
     test_swap();
+    test_reserve_wrapped();
+    test_collect_extend();
+    test_iter();
+    test_as_slices();
+    test_rotate();
+    test_insert_remove();
+    test_retain_truncate();
+    test_shrink_to_fit();
+    test_clone_cmp();
+    test_drain();
     0
 }
@@ -0,0 +1,99 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#[no_std];
+
+extern crate core;
+
+use core::fail::abort;
+use core::flt2dec::{Decoded, FullDecoded, Nan, Infinite, Zero, Finite};
+use core::flt2dec::{decode_f32, decode_f64, format_exact, format_shortest};
+use core::flt2dec::{MAX_SIG_DIGITS, MAX_EXACT_DIGITS};
+use core::i16;
+use core::slice::{mut_slice_to, slice_to};
+
+fn finite(decoded: FullDecoded) -> Decoded {
+    match decoded {
+        Finite(d) => d,
+        _ => abort()
+    }
+}
+
+fn shortest(x: f64, digits: &[u8], k: i16) -> bool {
+    let mut buf = [0u8, ..MAX_SIG_DIGITS];
+    let (_, decoded) = decode_f64(x);
+    let (len, exp) = format_shortest(&finite(decoded), buf);
+    slice_to(buf, len) == digits && exp == k
+}
+
+fn exact(x: f64, len: uint, limit: i16, digits: &[u8], k: i16) -> bool {
+    let mut buf = [0u8, ..MAX_EXACT_DIGITS];
+    let (_, decoded) = decode_f64(x);
+    let (len, exp) = format_exact(&finite(decoded), mut_slice_to(buf, len), limit);
+    slice_to(buf, len) == digits && exp == k
+}
+
+fn test_decode() {
+    match decode_f64(0.0 / 0.0) { (_, Nan) => (), _ => abort() }
+    match decode_f64(-1.0 / 0.0) { (true, Infinite) => (), _ => abort() }
+    match decode_f64(-0.0) { (true, Zero) => (), _ => abort() }
+    match decode_f32(0.0) { (false, Zero) => (), _ => abort() }
+
+    // 1.0 is a power of two, so its lower neighbour is closer
+    let (_, one) = decode_f64(1.0);
+    let one = finite(one);
+    if one.mant != 1 << 54 || one.minus != 1 || one.plus != 2 || one.exp != -54 { abort() }
+    if !one.inclusive { abort() }
+    let (_, three) = decode_f32(3.0);
+    let three = finite(three);
+    if three.mant != 3 << 23 || three.minus != 1 || three.plus != 1 || three.exp != -23 { abort() }
+}
+
+fn test_shortest() {
+    if !shortest(1.0, bytes!("1"), 1) || !shortest(0.3, bytes!("3"), 0) { abort() }
+    if !shortest(0.1 + 0.2, bytes!("30000000000000004"), 0) { abort() }
+    if !shortest(1e23, bytes!("1"), 24) || !shortest(123.456, bytes!("123456"), 3) { abort() }
+    if !shortest(5e-324, bytes!("5"), -323) { abort() }
+    if !shortest(1.7976931348623157e308, bytes!("17976931348623157"), 309) { abort() }
+
+    let mut buf = [0u8, ..MAX_SIG_DIGITS];
+    let (_, decoded) = decode_f32(0.1);
+    let (len, exp) = format_shortest(&finite(decoded), buf);
+    if slice_to(buf, len) != bytes!("1") || exp != 0 { abort() }
+}
+
+fn test_exact() {
+    if !exact(0.1, 20, i16::MIN, bytes!("10000000000000000555"), 0) { abort() }
+    if !exact(0.5, 10, i16::MIN, bytes!("5"), 0) { abort() }
+    if !exact(2.5, 1, i16::MIN, bytes!("2"), 1) || !exact(3.5, 1, i16::MIN, bytes!("4"), 1) {
+        abort()
+    }
+    if !exact(999.9, 3, i16::MIN, bytes!("100"), 4) { abort() }
+
+    // stopping at a decimal position
+    if !exact(0.25, 10, -1, bytes!("2"), 0) || !exact(0.96, 10, 0, bytes!("1"), 1) { abort() }
+    if !exact(0.4, 10, 0, &[], 0) || !exact(0.04, 10, 0, &[], -1) { abort() }
+    if !exact(1234.0, 10, 2, bytes!("12"), 4) { abort() }
+
+    // the largest subnormal has the longest expansion
+    let mut buf = [0u8, ..MAX_EXACT_DIGITS];
+    let (_, decoded) = decode_f64(2.2250738585072009e-308);
+    let (len, exp) = format_exact(&finite(decoded), buf, i16::MIN);
+    if len != MAX_EXACT_DIGITS || exp != -307 || buf[len - 1] != '5' as u8 { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_decode();
+    test_shortest();
+    test_exact();
+    0
+}
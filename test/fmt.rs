@@ -0,0 +1,131 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#[no_std];
+#[feature(macro_rules, phase)];
+
+#[phase(syntax, link)]
+extern crate core;
+
+use core::fail::abort;
+use core::io::BufWriter;
+use core::option::{Option, Some, None};
+use core::result::{Ok, Err};
+use core::str;
+
+macro_rules! check(
+    ($expected:expr, $fmt:expr $(, $arg:expr)*) => {
+        {
+            let mut buf = [0u8, ..128];
+            let mut w = BufWriter::new(buf);
+            if write!(&mut w, $fmt $(, $arg)*).is_err() { abort() }
+            if str::from_utf8(w.as_slice()).get() != $expected { abort() }
+        }
+    }
+)
+
+fn test_integers() {
+    check!("x = 5", "x = {}", 5);
+    check!("-42 42 +7 0", "{} {} {:+} {}", -42i, 42u8, 7, 0u64);
+    check!("-9223372036854775808", "{}", -9223372036854775807i64 - 1);
+    check!("ff FF 0xff 0o17 0b101", "{:x} {:X} {:#x} {:#o} {:#b}", 255, 255, 255, 15, 5);
+    check!("ff ffff", "{:x} {:x}", -1i8, -1i16);
+}
+
+fn test_padding() {
+    check!("[   7] [7   ] [ 7  ] [0007] [-007]", "[{:4}] [{:<4}] [{:^4}] [{:04}] [{:04}]",
+           7, 7, 7, 7, -7);
+    check!("[**7**] [0x00ff] [ééé-1]", "[{:*^5}] [{:#06x}] [{:é>5}]", 7, 255, -1);
+    check!("[ab   ] [  abc] [ab] [é ]", "[{:5}] [{:>5}] [{:.2}] [{:2}]", "ab", "abc", "abc", 'é');
+}
+
+fn test_arguments() {
+    check!("b a a", "{1} {0} {}", "a", "b");
+    check!("{x}", "{{{}}}", 'x');
+    check!("no arguments", "no arguments");
+
+    let mut buf = [0u8, ..16];
+    let mut w = BufWriter::new(buf);
+    if write!(&mut w, "{", 1).is_ok() { abort() }
+    if write!(&mut w, "}", 1).is_ok() { abort() }
+    if write!(&mut w, "{} {}", 1).is_ok() { abort() }
+    if write!(&mut w, "{:.}", 1.0).is_ok() { abort() }
+    if write!(&mut w, "{:x}", "s").is_ok() { abort() }
+    if write!(&mut w, "{:q}", 1).is_ok() { abort() }
+    if write!(&mut w, "{:99999999999999999999}", 1).is_ok() { abort() }
+    if write!(&mut w, "{:.99999999999999999999}", 1.0).is_ok() { abort() }
+    if write!(&mut w, "{99999999999999999999}", 1).is_ok() { abort() }
+}
+
+fn test_types() {
+    let none: Option<int> = None;
+    let xs: &[int] = &[1, 2, 3];
+    check!("true é Some(3) None [1, 2, 3]", "{} {} {} {} {}", true, 'é', Some(3), none, xs);
+    check!("[01, 02, 03]", "{:02}", xs);
+}
+
+fn test_floats() {
+    check!("3.14 0.5 -2.0 1.5", "{:.2} {} {:.1} {:.1}", 3.14159, 0.5, -1.96, 1.5f32);
+    check!("inf -inf NaN", "{} {} {}", 1.0 / 0.0, -1.0 / 0.0, 0.0 / 0.0);
+    check!("NaN NaN [  NaN] [NaN  ]", "{:.1} {:.2e} [{:5.1}] [{:<5.0}]", 0.0 / 0.0, 0.0 / 0.0,
+           0.0 / 0.0, 0.0 / 0.0);
+    check!("[   inf] [  -inf] [  +inf]", "[{:06}] [{:06.2}] [{:+06}]", 1.0 / 0.0, -1.0 / 0.0,
+           1.0 / 0.0);
+    check!("inf -inf [ -inf ]", "{:.1} {:.3e} [{:^6.1}]", 1.0 / 0.0, -1.0 / 0.0, -1.0 / 0.0);
+    check!("   +2", "{:+5.0}", 2.25);
+    check!("100000000000000000000", "{:.0}", 1e20);
+    check!("0.1 100 0.30000000000000004 0.1", "{} {} {} {}", 0.1, 100.0, 0.1 + 0.2, 0.1f32);
+    check!("0 -0 -0.0 0.000", "{} {} {:.1} {:.3}", 0.0, -0.0, -0.0, 0.0);
+    check!("0.2 0.3 0.1000000000000000055511", "{:.1} {:.1} {:.22}", 0.25, 0.35, 0.1);
+    check!("10.0 0.0000001", "{:.1} {}", 9.96, 1e-7);
+    check!("[-0001.50] [ 1.5  ]", "[{:08.2}] [{:^6}]", -1.5, 1.5);
+}
+
+fn test_exponential() {
+    check!("1e-7 1.5E3 6.02e23 0e0", "{:e} {:E} {:e} {:e}", 1e-7, 1500.0, 6.02e23, 0.0);
+    check!("1.234e4 2.50e0 0.00e0 1e1", "{:.3e} {:.2e} {:.2e} {:.0e}", 12345.0, 2.5, 0.0, 9.6);
+    check!("5e-324 1.7976931348623157e308", "{:e} {:e}", 5e-324, 1.7976931348623157e308);
+    check!("3.4028235e38", "{:e}", 3.4028235e38f32);
+    check!("-1e-1", "{:e}", -0.1);
+
+    let mut buf = [0u8, ..16];
+    let mut w = BufWriter::new(buf);
+    if write!(&mut w, "{:e}", 1).is_ok() { abort() }
+}
+
+fn test_short_write() {
+    let mut buf = [0u8, ..4];
+    let mut w = BufWriter::new(buf);
+    match write!(&mut w, "{}", 123456) {
+        Ok(()) => abort(),
+        Err(e) => if e.kind() != core::io::ShortWrite { abort() }
+    }
+    if str::from_utf8(w.as_slice()).get() != "1234" { abort() }
+}
+
+fn test_format() {
+    let s = format!("{}-{:>3}", 1, "a");
+    if s.as_slice() != "1-  a" { abort() }
+    println!("fmt: {} {}", 1, "ok");
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_integers();
+    test_padding();
+    test_arguments();
+    test_types();
+    test_floats();
+    test_exponential();
+    test_short_write();
+    test_format();
+    0
+}
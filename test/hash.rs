@@ -13,8 +13,12 @@
 
 extern crate core;
 
-use core::hash::{Hash, HashBytes, State};
+use core::hash::{Hash, HashBytes, HashMap, State};
+use core::container::Container;
+use core::iter::{Iterator, Extendable};
+use core::slice::iter;
 use core::fail::abort;
+use core::lru::LruCache;
 use core::vec::Vec;
 
 macro_rules! u8to64_le (
@@ -135,8 +139,32 @@ fn test_siphash() {
     }
 }
 
+fn test_collect_extend() {
+    let xs = [1u, 2, 3];
+    let mut map: HashMap<uint, uint> = iter(xs).map(|&x| (x, x * 10)).collect();
+    if map.len() != 3 || *map.find(&2).get() != 20 { abort() }
+    map.extend(&mut iter(xs).map(|&x| (x + 2, x)));
+    if map.len() != 5 || *map.find(&3).get() != 1 || *map.find(&5).get() != 3 { abort() }
+}
+
+fn test_lru_collect_extend() {
+    let xs = [1u, 2, 3, 2];
+    let mut cache: LruCache<uint, uint> = iter(xs).map(|&x| (x, x * 10)).collect();
+    if cache.len() != 3 || cache.capacity() != 4 || *cache.get(&2).get() != 20 { abort() }
+    cache.extend(&mut iter(xs).map(|&x| (x + 3, x)));
+    if cache.len() != 4 || cache.get(&1).is_some() { abort() }
+    if *cache.get(&6).get() != 3 { abort() }
+
+    let mut empty: LruCache<uint, uint> = iter([]).map(|&x: &uint| (x, x)).collect();
+    if empty.len() != 0 || empty.capacity() != 1 { abort() }
+    empty.extend(&mut iter(xs).map(|&x| (x, x)));
+    if empty.len() != 1 || *empty.get(&2).get() != 2 { abort() }
+}
+
 #[start]
 fn main(_: int, _: **u8) -> int {
     test_siphash();
+    test_collect_extend();
+    test_lru_collect_extend();
     0
 }
@@ -12,11 +12,159 @@
 
 extern crate core;
 
-use core::io::{stdout, stderr};
+use core::container::Container;
+use core::fail::abort;
+use core::slice::{iter, slice_to};
+use core::iter::Iterator;
+use core::result::{Ok, Err};
+use core::io::{Reader, Writer, MemWriter, SliceReader, BufferedReader, BufferedWriter};
+use core::io::{BufWriter, Error, EndOfFile, NotFound, ShortWrite, stdout, stderr};
+use core::fail::{EAGAIN, ENOENT};
+use core::io::IoResult;
+use core::cmp::min;
 
-#[start]
-fn main(_: int, _: **u8) -> int {
+fn test_std_streams() {
     stdout().write(bytes!("foo\n"));
     stderr().write(bytes!("bar\n"));
+}
+
+fn eq_bytes(xs: &[u8], ys: &[u8]) -> bool {
+    if xs.len() != ys.len() { return false }
+    let mut i = 0;
+    for x in iter(xs) {
+        if *x != ys[i] { return false }
+        i += 1;
+    }
+    true
+}
+
+fn test_mem_writer() {
+    let mut w = MemWriter::new();
+    if w.write_all(bytes!("foo")).is_err() { abort() }
+    if w.write_byte(0x20).is_err() { abort() }
+    if w.write_all(bytes!("bar")).is_err() { abort() }
+    if !eq_bytes(w.as_slice(), bytes!("foo bar")) { abort() }
+}
+
+fn test_buf_writer() {
+    let mut buf = [0u8, ..8];
+    let mut w = BufWriter::new(buf);
+    if w.write_all(bytes!("foo ")).is_err() { abort() }
+    if w.write(bytes!("barbaz")).get() != 4 || w.position() != 8 { abort() }
+    match w.write_all(bytes!("x")) {
+        Ok(()) => abort(),
+        Err(e) => if e.kind() != ShortWrite { abort() }
+    }
+    if !eq_bytes(w.as_slice(), bytes!("foo barb")) { abort() }
+}
+
+fn test_slice_reader() {
+    let mut r = SliceReader::new(bytes!("hello world"));
+    if r.read_byte().get().get() != 'h' as u8 { abort() }
+
+    let mut buf = [0u8, ..4];
+    if r.read_exact(buf).is_err() { abort() }
+    if !eq_bytes(buf, bytes!("ello")) { abort() }
+
+    let rest = r.read_to_end().get();
+    if rest.len() != 6 { abort() }
+    if !eq_bytes(rest.as_slice(), bytes!(" world")) { abort() }
+
+    if r.read_byte().get().is_some() { abort() }
+    match r.read_exact(buf) {
+        Err(e) => if e.kind() != EndOfFile { abort() },
+        Ok(()) => abort()
+    }
+}
+
+fn test_buffered_reader() {
+    let input = bytes!("first\nsecond line\n\nlast");
+    let mut r = BufferedReader::with_capacity(4, SliceReader::new(input));
+
+    let line = r.read_line().get();
+    if !eq_bytes(line.as_slice(), bytes!("first\n")) { abort() }
+
+    let mut lines = r.lines();
+    if !eq_bytes(lines.next().get().get().as_slice(), bytes!("second line")) { abort() }
+    if lines.next().get().get().len() != 0 { abort() }
+    if !eq_bytes(lines.next().get().get().as_slice(), bytes!("last")) { abort() }
+    if lines.next().is_some() { abort() }
+}
+
+fn test_buffered_writer() {
+    let mut w = BufferedWriter::with_capacity(4, MemWriter::new());
+    if w.write_all(bytes!("ab")).is_err() { abort() }
+    if w.get_ref().as_slice().len() != 0 { abort() }
+    if w.write_all(bytes!("cde")).is_err() { abort() }
+    if !eq_bytes(w.get_ref().as_slice(), bytes!("ab")) { abort() }
+    if w.write_all(bytes!("0123456789")).is_err() { abort() }
+    if !eq_bytes(w.get_ref().as_slice(), bytes!("abcde0123456789")) { abort() }
+    if w.write_all(bytes!("x")).is_err() { abort() }
+    if w.flush().is_err() { abort() }
+    if !eq_bytes(w.get_ref().as_slice(), bytes!("abcde0123456789x")) { abort() }
+}
+
+// Accepts at most two bytes per write and fails once `budget` bytes have been written.
+struct Trickle {
+    out: MemWriter,
+    budget: uint
+}
+
+impl Writer for Trickle {
+    fn write(&mut self, xs: &[u8]) -> IoResult<uint> {
+        if self.budget == 0 {
+            return Err(Error::from_errno(EAGAIN))
+        }
+        let n = min(min(xs.len(), 2), self.budget);
+        self.budget -= n;
+        self.out.write(slice_to(xs, n))
+    }
+}
+
+fn test_buffered_writer_error() {
+    let mut w = BufferedWriter::with_capacity(8, Trickle { out: MemWriter::new(), budget: 3 });
+    if w.write_all(bytes!("abcdef")).is_err() { abort() }
+    if w.flush().is_ok() { abort() }
+    if !eq_bytes(w.get_ref().out.as_slice(), bytes!("abc")) { abort() }
+    w.get_mut().budget = 100;
+    if w.write_all(bytes!("gh")).is_err() { abort() }
+    if w.flush().is_err() { abort() }
+    if !eq_bytes(w.get_ref().out.as_slice(), bytes!("abcdefgh")) { abort() }
+}
+
+fn test_zero_capacity() {
+    let mut r = BufferedReader::with_capacity(0, SliceReader::new(bytes!("ab\ncd")));
+    if !eq_bytes(r.read_line().get().as_slice(), bytes!("ab\n")) { abort() }
+    if !eq_bytes(r.read_line().get().as_slice(), bytes!("cd")) { abort() }
+    if r.read_line().get().len() != 0 { abort() }
+
+    let mut w = BufferedWriter::with_capacity(0, MemWriter::new());
+    if w.write_all(bytes!("xyz")).is_err() || w.flush().is_err() { abort() }
+    if !eq_bytes(w.get_ref().as_slice(), bytes!("xyz")) { abort() }
+}
+
+fn test_error() {
+    let e = Error::from_errno(ENOENT);
+    if e.kind() != NotFound { abort() }
+    if e.code() != ENOENT { abort() }
+    if !eq_bytes(core::str::as_bytes(e.name()), bytes!("ENOENT")) { abort() }
+
+    let mut buf = [0u8, ..128];
+    if e.describe(buf).len() == 0 { abort() }
+    let mut tiny = [0u8, ..4];
+    if e.describe(tiny).len() > 4 { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_std_streams();
+    test_mem_writer();
+    test_buf_writer();
+    test_slice_reader();
+    test_buffered_reader();
+    test_buffered_writer();
+    test_buffered_writer_error();
+    test_zero_capacity();
+    test_error();
     0
 }
@@ -0,0 +1,136 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#[no_std];
+
+extern crate core;
+
+use core::option::{Some, None};
+use core::iter::{Iterator, DoubleEndedIterator, CloneableIterator, AdditiveIterator,
+                 MultiplicativeIterator, range, range_inclusive, range_step};
+use core::slice::iter;
+use core::fail::abort;
+
+fn test_adaptors() {
+    let xs = [1, 2, 3, 4, 5];
+    let ys = [6, 7];
+
+    if iter(xs).chain(iter(ys)).count() != 7 { abort() }
+    if iter(xs).chain(iter(ys)).invert().next() != Some(&7) { abort() }
+    if iter(xs).map(|&x| x * 2).sum() != 30 { abort() }
+    if iter(xs).filter(|&&x| x % 2 == 0).map(|&x| x).sum() != 6 { abort() }
+    if iter(xs).filter_map(|&x| if x > 3 { Some(x) } else { None }).sum() != 9 { abort() }
+    if iter(xs).skip_while(|&&x| x < 3).map(|&x| x).sum() != 12 { abort() }
+    if iter(xs).take_while(|&&x| x < 3).map(|&x| x).sum() != 3 { abort() }
+    if iter(xs).skip(3).map(|&x| x).sum() != 9 { abort() }
+    if iter(xs).take(2).map(|&x| x).product() != 2 { abort() }
+    if iter(xs).step_by(2).map(|&x| x).sum() != 9 { abort() }
+    if iter(xs).cycle().take(12).count() != 12 { abort() }
+    if iter(xs).scan(0, |acc, &x| { *acc += x; Some(*acc) }).last() != Some(15) { abort() }
+    if iter(ys).flat_map(|_| iter(xs)).count() != 10 { abort() }
+
+    match iter(xs).zip(iter(ys)).last() {
+        Some((&5, _)) | None => abort(),
+        Some((&x, &y)) => if x != 2 || y != 7 { abort() }
+    }
+
+    match iter(xs).enumerate().nth(3) {
+        Some((i, &x)) => if i != 3 || x != 4 { abort() },
+        None => abort()
+    }
+
+    let mut seen = 0;
+    if iter(xs).inspect(|&&x| seen += x).count() != 5 || seen != 15 { abort() }
+
+    let mut it = iter(xs).peekable();
+    if it.peek() != Some(&&1) || it.next() != Some(&1) { abort() }
+    if it.is_empty() { abort() }
+
+    let mut it = iter(xs);
+    if it.by_ref().take(2).count() != 2 || it.next() != Some(&3) { abort() }
+
+    let mut it = iter(ys).fuse();
+    it.count();
+    if it.next().is_some() { abort() }
+}
+
+fn test_consumers() {
+    let xs = [3, 1, 4, 1, 5];
+
+    if iter(xs).find(|&&x| x > 3) != Some(&4) { abort() }
+    if iter(xs).position(|&x| x == 1) != Some(1) { abort() }
+    if iter(xs).position(|&x| x == 9).is_some() { abort() }
+    if iter(xs).max_by(|&&x| x) != Some(&5) { abort() }
+    if iter(xs).min_by(|&&x| x) != Some(&1) { abort() }
+    if iter(xs).nth(5).is_some() { abort() }
+
+    let (lower, upper) = iter(xs).chain(iter(xs)).size_hint();
+    if lower != 10 || upper != Some(10) { abort() }
+    let (lower, upper) = iter(xs).skip(2).step_by(2).size_hint();
+    if lower != 2 || upper != Some(2) { abort() }
+}
+
+fn test_range() {
+    if range(0, 5).sum() != 10 { abort() }
+    if range(5, 0).next().is_some() { abort() }
+    if range(-3i8, 3).invert().next() != Some(2) { abort() }
+    if range(250u8, 255).last() != Some(254) { abort() }
+
+    let (lower, upper) = range(-128i8, 127).size_hint();
+    if lower != 255 || upper != Some(255) { abort() }
+
+    let mut it = range(0, 4);
+    if it.next() != Some(0) || it.next_back() != Some(3) || it.count() != 2 { abort() }
+}
+
+fn test_range_inclusive() {
+    if range_inclusive(250u8, 255).count() != 6 { abort() }
+    if range_inclusive(250u8, 255).last() != Some(255) { abort() }
+    if range_inclusive(3, 3).count() != 1 { abort() }
+    if range_inclusive(3, 2).next().is_some() { abort() }
+    if range_inclusive(-128i8, 127).invert().next() != Some(127) { abort() }
+
+    let (lower, upper) = range_inclusive(0u8, 255).size_hint();
+    if lower != 256 || upper != Some(256) { abort() }
+
+    let mut it = range_inclusive(1, 3);
+    if it.next_back() != Some(3) || it.next_back() != Some(2) || it.next_back() != Some(1) {
+        abort()
+    }
+    if it.next().is_some() || it.next_back().is_some() { abort() }
+}
+
+fn test_range_step() {
+    if range_step(0, 10, 3).sum() != 18 { abort() }
+    if range_step(10, 0, -3).sum() != 22 { abort() }
+    if range_step(10, 0, 3).next().is_some() { abort() }
+
+    // stepping past the maximum ends the iteration instead of wrapping
+    if range_step(200u8, 255, 50).last() != Some(250) { abort() }
+    if range_step(-100i8, -128, -20).count() != 2 { abort() }
+
+    let (lower, upper) = range_step(0, 10, 3).size_hint();
+    if lower != 4 || upper != Some(4) { abort() }
+
+    let mut it = range_step(10, 0, -3);
+    if it.next_back() != Some(1) || it.next_back() != Some(4) { abort() }
+    if it.next() != Some(10) || it.next() != Some(7) || it.next().is_some() { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_adaptors();
+    test_consumers();
+    test_range();
+    test_range_inclusive();
+    test_range_step();
+    0
+}
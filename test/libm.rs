@@ -0,0 +1,282 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+// Compares the pure Rust functions in `core::libm` against the C library behind `core::f32` and
+// `core::f64`, on special values and sampled inputs. The tolerances cover the error of both.
+
+#[no_std];
+
+extern crate core;
+
+use core::container::Container;
+use core::f32;
+use core::f64;
+use core::fail::abort;
+use core::iter::range;
+use core::libm;
+use core::mem::transmute;
+
+static SAMPLES: uint = 10000;
+
+static SPECIAL_F64: [u64, ..12] = [0, 0x8000000000000000, 0x7ff0000000000000, 0xfff0000000000000,
+                                   0x7ff8000000000000, 0x3ff0000000000000, 0xbff0000000000000,
+                                   0x3fe0000000000000, 0x0010000000000000, 1, 0x7fefffffffffffff,
+                                   0xffefffffffffffff];
+static SPECIAL_F32: [u32, ..12] = [0, 0x80000000, 0x7f800000, 0xff800000, 0x7fc00000, 0x3f800000,
+                                   0xbf800000, 0x3f000000, 0x00800000, 1, 0x7f7fffff, 0xff7fffff];
+
+// xorshift64
+struct Rng {
+    state: u64
+}
+
+impl Rng {
+    fn new() -> Rng {
+        Rng { state: 0x2545f4914f6cdd1d }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    // Half arbitrary bit patterns and half magnitudes between 2^-8 and 2^9, where the functions do
+    // most of their work. Signaling NaNs are quieted, as C treats them differently.
+    fn f64(&mut self) -> f64 {
+        let r = self.next();
+        let bits = if r & 1 == 0 {
+            self.next()
+        } else {
+            r >> 63 << 63 | (1023 - 8 + (r >> 1) % 17) << 52 | self.next() >> 12
+        };
+        let x: f64 = unsafe { transmute(bits) };
+        if x != x { unsafe { transmute(bits | 1 << 51) } } else { x }
+    }
+
+    fn f32(&mut self) -> f32 {
+        let r = self.next();
+        let bits = if r & 1 == 0 {
+            (self.next() >> 32) as u32
+        } else {
+            (r >> 63 << 31) as u32 | (127 - 8 + ((r >> 1) % 17) as u32) << 23 |
+                (self.next() >> 41) as u32
+        };
+        let x: f32 = unsafe { transmute(bits) };
+        if x != x { unsafe { transmute(bits | 1 << 22) } } else { x }
+    }
+}
+
+fn special_f64(i: uint) -> f64 {
+    unsafe { transmute(SPECIAL_F64[i]) }
+}
+
+fn special_f32(i: uint) -> f32 {
+    unsafe { transmute(SPECIAL_F32[i]) }
+}
+
+// The distance in ulp, counting the zeroes as adjacent values and NaNs as equal to each other.
+fn ulps_f64(a: f64, b: f64) -> u64 {
+    if a != a || b != b {
+        return if a != a && b != b { 0 } else { !0 }
+    }
+    let key = |x: f64| -> u64 {
+        let bits: u64 = unsafe { transmute(x) };
+        if bits >> 63 != 0 { !bits } else { bits | 1 << 63 }
+    };
+    let (a, b) = (key(a), key(b));
+    if a > b { a - b } else { b - a }
+}
+
+fn ulps_f32(a: f32, b: f32) -> u64 {
+    if a != a || b != b {
+        return if a != a && b != b { 0 } else { !0 }
+    }
+    let key = |x: f32| -> u32 {
+        let bits: u32 = unsafe { transmute(x) };
+        if bits >> 31 != 0 { !bits } else { bits | 1 << 31 }
+    };
+    let (a, b) = (key(a), key(b));
+    (if a > b { a - b } else { b - a }) as u64
+}
+
+fn check_f64(f: fn(f64) -> f64, g: fn(f64) -> f64, max_ulps: u64) {
+    let mut rng = Rng::new();
+    for i in range(0, SPECIAL_F64.len() + SAMPLES) {
+        let x = if i < SPECIAL_F64.len() { special_f64(i) } else { rng.f64() };
+        if ulps_f64(f(x), g(x)) > max_ulps { abort() }
+    }
+}
+
+fn check_f32(f: fn(f32) -> f32, g: fn(f32) -> f32, max_ulps: u64) {
+    let mut rng = Rng::new();
+    for i in range(0, SPECIAL_F32.len() + SAMPLES) {
+        let x = if i < SPECIAL_F32.len() { special_f32(i) } else { rng.f32() };
+        if ulps_f32(f(x), g(x)) > max_ulps { abort() }
+    }
+}
+
+fn check2_f64(f: fn(f64, f64) -> f64, g: fn(f64, f64) -> f64, max_ulps: u64) {
+    let mut rng = Rng::new();
+    let n = SPECIAL_F64.len();
+    for i in range(0, n * n + SAMPLES) {
+        let (x, y) = if i < n * n {
+            (special_f64(i % n), special_f64(i / n))
+        } else {
+            (rng.f64(), rng.f64())
+        };
+        if ulps_f64(f(x, y), g(x, y)) > max_ulps { abort() }
+    }
+}
+
+fn check2_f32(f: fn(f32, f32) -> f32, g: fn(f32, f32) -> f32, max_ulps: u64) {
+    let mut rng = Rng::new();
+    let n = SPECIAL_F32.len();
+    for i in range(0, n * n + SAMPLES) {
+        let (x, y) = if i < n * n {
+            (special_f32(i % n), special_f32(i / n))
+        } else {
+            (rng.f32(), rng.f32())
+        };
+        if ulps_f32(f(x, y), g(x, y)) > max_ulps { abort() }
+    }
+}
+
+fn test_exact() {
+    check_f64(libm::floor, f64::floor, 0);
+    check_f64(libm::ceil, f64::ceil, 0);
+    check_f64(libm::trunc, f64::trunc, 0);
+    check_f64(libm::round, f64::round, 0);
+    check_f64(libm::rint, f64::rint, 0);
+    check_f64(libm::nearbyint, f64::nearbyint, 0);
+    check_f64(libm::logb, f64::logb, 0);
+    check2_f64(libm::fmax, f64::max, 0);
+    check2_f64(libm::fmin, f64::min, 0);
+    check2_f64(libm::fdim, f64::dim, 0);
+    check2_f64(libm::nextafter, f64::nextafter, 0);
+
+    check_f32(libm::floorf, f32::floor, 0);
+    check_f32(libm::ceilf, f32::ceil, 0);
+    check_f32(libm::truncf, f32::trunc, 0);
+    check_f32(libm::roundf, f32::round, 0);
+    check_f32(libm::rintf, f32::rint, 0);
+    check_f32(libm::nearbyintf, f32::nearbyint, 0);
+    check_f32(libm::logbf, f32::logb, 0);
+    check2_f32(libm::fmaxf, f32::max, 0);
+    check2_f32(libm::fminf, f32::min, 0);
+    check2_f32(libm::fdimf, f32::dim, 0);
+    check2_f32(libm::nextafterf, f32::nextafter, 0);
+
+    let mut rng = Rng::new();
+    for _ in range(0, SAMPLES) {
+        let (x, y, z) = (rng.f64(), rng.f64(), rng.f64());
+        if ulps_f64(libm::fma(x, y, z), f64::fma(x, y, z)) != 0 { abort() }
+        let (m, e) = libm::frexp(x);
+        let (m2, e2) = f64::frexp(x);
+        if ulps_f64(m, m2) != 0 || (m == m && e != e2) { abort() }
+        let (i, frac) = libm::modf(x);
+        let (i2, frac2) = f64::modf(x);
+        if ulps_f64(i, i2) != 0 || ulps_f64(frac, frac2) != 0 { abort() }
+        if x == x && libm::ilogb(x) != f64::ilogb(x) { abort() }
+        let n = (rng.next() % 2200) as i32 - 1100;
+        if ulps_f64(libm::ldexp(x, n), f64::ldexp(x, n)) != 0 { abort() }
+
+        let (x, y, z) = (rng.f32(), rng.f32(), rng.f32());
+        if ulps_f32(libm::fmaf(x, y, z), f32::fma(x, y, z)) != 0 { abort() }
+        let (m, e) = libm::frexpf(x);
+        let (m2, e2) = f32::frexp(x);
+        if ulps_f32(m, m2) != 0 || (m == m && e != e2) { abort() }
+        let (i, frac) = libm::modff(x);
+        let (i2, frac2) = f32::modf(x);
+        if ulps_f32(i, i2) != 0 || ulps_f32(frac, frac2) != 0 { abort() }
+        if x == x && libm::ilogbf(x) != f32::ilogb(x) { abort() }
+        let n = (rng.next() % 320) as i32 - 160;
+        if ulps_f32(libm::ldexpf(x, n), f32::ldexp(x, n)) != 0 { abort() }
+    }
+}
+
+fn test_trigonometric() {
+    check_f64(libm::sin, f64::sin, 2);
+    check_f64(libm::cos, f64::cos, 2);
+    check_f64(libm::tan, f64::tan, 2);
+    check_f64(libm::asin, f64::asin, 2);
+    check_f64(libm::acos, f64::acos, 2);
+    check_f64(libm::atan, f64::atan, 2);
+    check2_f64(libm::atan2, f64::atan2, 2);
+
+    check_f32(libm::sinf, f32::sin, 2);
+    check_f32(libm::cosf, f32::cos, 2);
+    check_f32(libm::tanf, f32::tan, 2);
+    check_f32(libm::asinf, f32::asin, 2);
+    check_f32(libm::acosf, f32::acos, 2);
+    check_f32(libm::atanf, f32::atan, 2);
+    check2_f32(libm::atan2f, f32::atan2, 2);
+}
+
+fn test_exponential() {
+    check_f64(libm::exp, f64::exp, 2);
+    check_f64(libm::exp2, f64::exp2, 2);
+    check_f64(libm::expm1, f64::expm1, 2);
+    check_f64(libm::log, f64::log, 2);
+    check_f64(libm::log2, f64::log2, 2);
+    check_f64(libm::log10, f64::log10, 3);
+    check_f64(libm::log1p, f64::log1p, 2);
+    check2_f64(libm::pow, f64::pow, 2);
+
+    check_f32(libm::expf, f32::exp, 2);
+    check_f32(libm::exp2f, f32::exp2, 2);
+    check_f32(libm::expm1f, f32::expm1, 2);
+    check_f32(libm::logf, f32::log, 2);
+    check_f32(libm::log2f, f32::log2, 2);
+    check_f32(libm::log10f, f32::log10, 3);
+    check_f32(libm::log1pf, f32::log1p, 2);
+    check2_f32(libm::powf, f32::pow, 2);
+}
+
+fn test_hyperbolic() {
+    check_f64(libm::sinh, f64::sinh, 3);
+    check_f64(libm::cosh, f64::cosh, 3);
+    check_f64(libm::tanh, f64::tanh, 3);
+    check_f64(libm::asinh, f64::asinh, 3);
+    check_f64(libm::acosh, f64::acosh, 3);
+    check_f64(libm::atanh, f64::atanh, 3);
+
+    check_f32(libm::sinhf, f32::sinh, 3);
+    check_f32(libm::coshf, f32::cosh, 3);
+    check_f32(libm::tanhf, f32::tanh, 3);
+    check_f32(libm::asinhf, f32::asinh, 3);
+    check_f32(libm::acoshf, f32::acosh, 3);
+    check_f32(libm::atanhf, f32::atanh, 3);
+}
+
+fn test_special() {
+    check_f64(libm::cbrt, f64::cbrt, 4);
+    check2_f64(libm::hypot, f64::hypot, 2);
+    check_f64(libm::erf, f64::erf, 2);
+    check_f64(libm::erfc, f64::erfc, 4);
+    check_f64(libm::tgamma, f64::tgamma, 16);
+
+    check_f32(libm::cbrtf, f32::cbrt, 2);
+    check2_f32(libm::hypotf, f32::hypot, 2);
+    check_f32(libm::erff, f32::erf, 2);
+    check_f32(libm::erfcf, f32::erfc, 4);
+    check_f32(libm::tgammaf, f32::tgamma, 8);
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_exact();
+    test_trigonometric();
+    test_exponential();
+    test_hyperbolic();
+    test_special();
+    0
+}
@@ -0,0 +1,267 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#[no_std];
+
+extern crate core;
+
+use core::container::Container;
+use core::fail::abort;
+use core::iter::range;
+use core::from_str::from_str;
+use core::option::{Option, Some, None};
+use core::cmp::{Less, Equal, Greater};
+use core::num::{Int, UnsignedInt, MAX_RADIX_DIGITS};
+use core::num::{Float, FPNaN, FPInfinite, FPZero, FPSubnormal, FPNormal, Total};
+use core::priority_queue::PriorityQueue;
+use core::slice::sort_unstable;
+use core::{i8, i16, i32, i64, int, u8, u16, u32, u64, uint};
+use core::{f32, f64};
+
+fn test_from_str_radix() {
+    if u8::from_str_radix("255", 10) != Some(255) || u8::from_str_radix("256", 10) != None {
+        abort()
+    }
+    if i8::from_str_radix("-128", 10) != Some(-128) || i8::from_str_radix("128", 10) != None {
+        abort()
+    }
+    if i8::from_str_radix("-129", 10) != None || i8::from_str_radix("+127", 10) != Some(127) {
+        abort()
+    }
+    if u16::from_str_radix("ffFF", 16) != Some(0xFFFF) || u16::from_str_radix("z", 36) != Some(35) {
+        abort()
+    }
+    let min = -9223372036854775807i64 - 1;
+    if i64::from_str_radix("-8000000000000000", 16) != Some(min) { abort() }
+    if u64::from_str_radix("18446744073709551615", 10) != Some(!0) { abort() }
+    if u64::from_str_radix("18446744073709551616", 10) != None { abort() }
+
+    // empty, a lone sign, a sign on an unsigned type and invalid digits
+    if uint::from_str_radix("", 10) != None || int::from_str_radix("-", 10) != None { abort() }
+    if uint::from_str_radix("-0", 10) != None || uint::from_str_radix("12a", 10) != None { abort() }
+    if uint::from_str_radix("102", 2) != None || uint::from_str_radix(" 1", 10) != None { abort() }
+
+    if u8::from_bytes_radix(bytes!("777"), 8) != None { abort() }
+    if u8::from_bytes_radix(bytes!("377"), 8) != Some(255) { abort() }
+}
+
+fn test_to_str_radix() {
+    let mut buf = [0u8, ..MAX_RADIX_DIGITS];
+    if u8::to_str_radix(0, 10, buf) != "0" { abort() }
+    if u8::to_str_radix(255, 16, buf) != "ff" { abort() }
+    if i8::to_str_radix(-128, 10, buf) != "-128" { abort() }
+    if i8::to_str_radix(-1, 2, buf) != "-1" { abort() }
+    let min = -9223372036854775807i64 - 1;
+    if i64::to_str_radix(min, 16, buf) != "-8000000000000000" { abort() }
+    if int::to_str_radix(-35, 36, buf) != "-z" { abort() }
+    if u64::to_str_radix(!0, 2, buf).len() != 64 { abort() }
+    if i64::to_str_radix(min, 2, buf).len() != MAX_RADIX_DIGITS { abort() }
+
+    let mut small = [0u8, ..3];
+    if uint::to_str_radix(123, 10, small) != "123" { abort() }
+}
+
+fn test_from_str() {
+    let x: Option<int> = from_str("-17");
+    if x != Some(-17) { abort() }
+    let x: Option<u16> = from_str("65536");
+    if x != None { abort() }
+    let b: Option<bool> = from_str("true");
+    if b != Some(true) { abort() }
+    let b: Option<bool> = from_str("True");
+    if b != None { abort() }
+}
+
+fn test_checked() {
+    if 250u8.checked_add(&5) != Some(255) || 250u8.checked_add(&6) != None { abort() }
+    if 0u8.checked_sub(&1) != None || i8::MIN.checked_sub(&1) != None { abort() }
+    if 16i16.checked_mul(&2048) != None || (-16i16).checked_mul(&2048) != Some(i16::MIN) { abort() }
+    if 7i32.checked_div(&0) != None || i32::MIN.checked_div(&-1) != None { abort() }
+    if i32::MIN.checked_rem(&-1) != None || (-7i32).checked_rem(&2) != Some(-1) { abort() }
+    if i64::MIN.checked_neg() != None || 5i64.checked_neg() != Some(-5) { abort() }
+    if 0u32.checked_neg() != Some(0) || 1u32.checked_neg() != None { abort() }
+    if 1u64.checked_shl(63) != Some(1 << 63) || 1u64.checked_shl(64) != None { abort() }
+    if (-8i8).checked_shr(2) != Some(-2) || 1i8.checked_shr(8) != None { abort() }
+    if 3u8.checked_pow(5) != Some(243) || 3u8.checked_pow(6) != None { abort() }
+    if 2i64.checked_pow(62) != Some(1 << 62) || 2i64.checked_pow(63) != None { abort() }
+    if (-2i64).checked_pow(63) != Some(i64::MIN) || 0u8.checked_pow(0) != Some(1) { abort() }
+}
+
+fn test_saturating() {
+    if 200u8.saturating_add(&100) != 255 || 5u8.saturating_sub(&6) != 0 { abort() }
+    if 100i8.saturating_add(&100) != 127 || (-100i8).saturating_add(&-100) != -128 { abort() }
+    if (-100i8).saturating_sub(&100) != -128 || 100i8.saturating_sub(&-100) != 127 { abort() }
+    if 100i8.saturating_mul(&-2) != -128 || (-100i8).saturating_mul(&-2) != 127 { abort() }
+    if u64::MAX.saturating_mul(&2) != u64::MAX || 3u64.saturating_mul(&4) != 12 { abort() }
+}
+
+fn test_wrapping() {
+    if 255u8.wrapping_add(&2) != 1 || 0u8.wrapping_sub(&1) != 255 { abort() }
+    if 127i8.wrapping_add(&1) != -128 || 16i8.wrapping_mul(&16) != 0 { abort() }
+    if i32::MIN.wrapping_neg() != i32::MIN || 1u16.wrapping_neg() != 0xffff { abort() }
+    if 1u32.wrapping_shl(33) != 2 || (-4i32).wrapping_shr(33) != -2 { abort() }
+    if 3u8.pow(6) != 217 || 10u64.pow(19) != 10000000000000000000 { abort() }
+}
+
+fn test_bits() {
+    if i8::MIN != -128 || i8::MAX != 127 || u16::MAX != 0xffff || u32::MIN != 0 { abort() }
+    if int::BYTES != uint::BITS / 8 || int::MAX as uint != uint::MAX >> 1 { abort() }
+    let x: u32 = Int::max_value();
+    if x != u32::MAX || 0i16.bits() != 16 { abort() }
+
+    if 0b1011u8.count_ones() != 3 || (-1i64).count_ones() != 64 { abort() }
+    if 0b1011u8.count_zeros() != 5 || 0u16.count_zeros() != 16 { abort() }
+    if 1u32.leading_zeros() != 31 || 0u32.leading_zeros() != 32 || (-1i8).leading_zeros() != 0 {
+        abort()
+    }
+    if 8u64.trailing_zeros() != 3 || 0u8.trailing_zeros() != 8 { abort() }
+
+    if 0x81u8.rotate_left(1) != 0x03 || 0x81u8.rotate_right(1) != 0xc0 { abort() }
+    if (-128i8).rotate_left(9) != 1 || 0x1234u16.rotate_left(16) != 0x1234 { abort() }
+    if 0x12345678u32.rotate_right(8) != 0x78123456 { abort() }
+    if 0x12345678u32.swap_bytes() != 0x78563412 || 0x12u8.swap_bytes() != 0x12 { abort() }
+}
+
+fn test_power_of_two() {
+    if 0u8.is_power_of_two() || !1u8.is_power_of_two() || 6u32.is_power_of_two() { abort() }
+    if !(1u64 << 63).is_power_of_two() { abort() }
+    if 0u32.next_power_of_two() != 1 || 5u32.next_power_of_two() != 8 { abort() }
+    if 64u16.next_power_of_two() != 64 || 129u8.next_power_of_two() != 0 { abort() }
+    if 128u8.checked_next_power_of_two() != Some(128) { abort() }
+    if 129u8.checked_next_power_of_two() != None { abort() }
+}
+
+fn test_bytes() {
+    let be = u32::to_be_bytes(0x01020304);
+    let le = u32::to_le_bytes(0x01020304);
+    if be[0] != 1 || be[3] != 4 || le[0] != 4 || le[3] != 1 { abort() }
+    if u32::from_be_bytes(be) != 0x01020304 || u32::from_le_bytes(le) != 0x01020304 { abort() }
+    if i16::from_be_bytes([0xff, 0xfe]) != -2 || u8::to_le_bytes(7)[0] != 7 { abort() }
+    if u16::from_be(u16::to_be(0x1234)) != 0x1234 || i64::from_le(i64::to_le(-3)) != -3 {
+        abort()
+    }
+
+    let mut buf = [0u8, ..10];
+    (-2i32).write_be_bytes(buf);
+    if buf[0] != 0xff || buf[3] != 0xfe || buf[4] != 0 { abort() }
+    let x: i32 = Int::read_be_bytes(buf);
+    if x != -2 { abort() }
+    0x0102u16.write_le_bytes(buf);
+    let y: u16 = Int::read_le_bytes(buf);
+    if buf[0] != 2 || buf[1] != 1 || y != 0x0102 { abort() }
+}
+
+fn test_classify() {
+    if f64::classify(1.5) != FPNormal || f64::classify(-0.0) != FPZero { abort() }
+    if f64::classify(f64::MIN_POSITIVE / 2.0) != FPSubnormal { abort() }
+    if f64::classify(f64::NEG_INFINITY) != FPInfinite || f64::classify(f64::NAN) != FPNaN {
+        abort()
+    }
+    if f32::classify(1e-40) != FPSubnormal || f32::classify(f32::MAX) != FPNormal { abort() }
+    if f64::is_normal(0.0) || !f64::is_normal(f64::MIN_POSITIVE) { abort() }
+    if !f64::is_finite(f64::MAX) || f64::is_finite(f64::INFINITY) || f64::is_finite(f64::NAN) {
+        abort()
+    }
+    if !f32::is_infinite(f32::NEG_INFINITY) || f32::is_infinite(f32::NAN) { abort() }
+
+    if f64::signum(-0.0) != -1.0 || f64::signum(f64::INFINITY) != 1.0 { abort() }
+    if !f64::is_nan(f64::signum(f64::NAN)) { abort() }
+    if !f64::is_sign_negative(-0.0) || !f32::is_sign_positive(0.0) { abort() }
+
+    if 1.0 + f64::EPSILON == 1.0 || 1.0 + f64::EPSILON / 2.0 != 1.0 { abort() }
+    if 1.0 + f32::EPSILON == 1.0 || 1.0 + f32::EPSILON / 2.0 != 1.0 { abort() }
+    if f64::consts::PI != 3.141592653589793 || f32::consts::LN_2 != 0.6931472 { abort() }
+}
+
+fn test_float_bits() {
+    if f64::to_bits(1.0) != 0x3ff0000000000000 || f32::to_bits(-2.0) != 0xc0000000 { abort() }
+    if f64::from_bits(0x7ff0000000000000) != f64::INFINITY || f32::from_bits(1) != 1e-45 {
+        abort()
+    }
+    let (mantissa, exp, sign) = f64::integer_decode(1.0);
+    if mantissa != 1 << 52 || exp != -52 || sign != 1 { abort() }
+    let (mantissa, exp, sign) = f64::integer_decode(-0.75);
+    if mantissa != 3 << 51 || exp != -52 || sign != -1 { abort() }
+    let (mantissa, exp, sign) = f64::integer_decode(5e-324);
+    if mantissa != 2 || exp != -1075 || sign != 1 { abort() }
+    let (mantissa, exp, sign) = f32::integer_decode(1.0);
+    if mantissa != 1 << 23 || exp != -23 || sign != 1 { abort() }
+}
+
+fn test_total_cmp() {
+    let neg_nan = f64::from_bits(0xfff8000000000000);
+    let xs = [neg_nan, f64::NEG_INFINITY, -1.0, -5e-324, -0.0, 0.0, 5e-324, 1.0, f64::INFINITY,
+              f64::NAN];
+    for i in range(0, xs.len()) {
+        for j in range(0, xs.len()) {
+            let expected = if i < j { Less } else if i > j { Greater } else { Equal };
+            if f64::total_cmp(xs[i], xs[j]) != expected { abort() }
+            if xs[i].total_cmp(&xs[j]) != expected { abort() }
+        }
+    }
+    if f32::total_cmp(-0.0, 0.0) != Less || f32::total_cmp(f32::NAN, f32::INFINITY) != Greater {
+        abort()
+    }
+
+    let mut keys = [Total(2.5), Total(f64::NAN), Total(-0.0), Total(-3.0), Total(0.0)];
+    sort_unstable(keys);
+    let sorted = [-3.0, -0.0, 0.0, 2.5];
+    for i in range(0, sorted.len()) {
+        let Total(x) = keys[i];
+        if f64::to_bits(x) != f64::to_bits(sorted[i]) { abort() }
+    }
+    let Total(last) = keys[4];
+    if !f64::is_nan(last) { abort() }
+
+    let mut queue = PriorityQueue::new();
+    queue.push(Total(1.5f32));
+    queue.push(Total(-2.0));
+    queue.push(Total(4.0));
+    match queue.pop() {
+        Some(Total(x)) if x == 4.0 => (),
+        _ => abort()
+    }
+}
+
+fn test_float_trait() {
+    fn hypotenuse<T: Float>(a: T, b: T) -> T {
+        (a * a + b * b).sqrt()
+    }
+    if hypotenuse(3.0f64, 4.0) != 5.0 || hypotenuse(5.0f32, 12.0) != 13.0 { abort() }
+
+    let nan: f64 = Float::nan();
+    let inf: f32 = Float::infinity();
+    if !nan.is_nan() || !inf.is_infinite() || inf.classify() != FPInfinite { abort() }
+    if 2.0f64.powi(10) != 1024.0 || 8.0f64.cbrt() != 2.0 || (-1.5f64).floor() != -2.0 { abort() }
+    if 1.0f64.nextafter(&2.0) != 1.0 + f64::EPSILON || 3.0f32.mantissa_digits() != 24 { abort() }
+    let x: f32 = Float::from_bits(0x3f800000);
+    if x != 1.0 || 1.0f32.to_bits() != 0x3f800000 { abort() }
+    let (fraction, exp) = 6.0f64.frexp();
+    if fraction != 0.75 || exp != 3 { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_from_str_radix();
+    test_to_str_radix();
+    test_from_str();
+    test_checked();
+    test_saturating();
+    test_wrapping();
+    test_bits();
+    test_power_of_two();
+    test_bytes();
+    test_classify();
+    test_float_bits();
+    test_total_cmp();
+    test_float_trait();
+    0
+}
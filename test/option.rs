@@ -0,0 +1,78 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#[no_std];
+
+extern crate core;
+
+use core::option::{Option, Some, None};
+use core::iter::Iterator;
+use core::fail::abort;
+
+fn test_combinators() {
+    let x = Some(5);
+    let y: Option<int> = None;
+
+    if x.is_none() || !y.is_none() { abort() }
+    if x.unwrap_or(1) != 5 || y.unwrap_or(1) != 1 { abort() }
+    if y.unwrap_or_else(|| 7) != 7 { abort() }
+    if x.and_then(|v| if v > 3 { Some(v * 2) } else { None }) != Some(10) { abort() }
+    if x.filter(|v| *v > 10).is_some() { abort() }
+    if y.or(Some(3)) != Some(3) || x.or(Some(3)) != Some(5) { abort() }
+    if y.or_else(|| Some(4)) != Some(4) { abort() }
+    if x.zip(y).is_some() { abort() }
+    let (a, b) = x.zip(Some('a')).get();
+    if a != 5 || b != 'a' { abort() }
+    if x.expect("x should be set") != 5 { abort() }
+}
+
+fn test_replace() {
+    let mut x = Some(1);
+    if x.replace(2) != Some(1) { abort() }
+    if x != Some(2) { abort() }
+    let mut y = None;
+    if y.replace(3).is_some() { abort() }
+    if y != Some(3) { abort() }
+}
+
+fn test_ord() {
+    if !(None < Some(0)) { abort() }
+    if !(Some(1) < Some(2)) { abort() }
+    if Some(1) < None { abort() }
+}
+
+fn test_iter() {
+    let mut x = Some(5);
+    let mut count = 0;
+    for v in x.mut_iter() {
+        *v += 1;
+        count += 1;
+    }
+    if count != 1 || x != Some(6) { abort() }
+
+    let mut it = x.move_iter();
+    let (lower, upper) = it.size_hint();
+    if lower != 1 || upper != Some(1) { abort() }
+    if it.next() != Some(6) { abort() }
+    if it.next().is_some() { abort() }
+
+    let y: Option<int> = None;
+    if y.iter().next().is_some() { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_combinators();
+    test_replace();
+    test_ord();
+    test_iter();
+    0
+}
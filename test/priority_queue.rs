@@ -4,7 +4,11 @@ extern crate core;
 
 use core::container::Container;
 use core::fail::abort;
-use core::priority_queue::PriorityQueue;
+use core::priority_queue::{PriorityQueue, IndexedPriorityQueue};
+use core::iter::{Iterator, Extendable, range};
+use core::option::{Some, None};
+use core::vec::Vec;
+use core::slice::iter;
 
 fn test_new() {
     let xs = PriorityQueue::<int>::new();
@@ -83,6 +87,73 @@ fn test_push_top_pop() {
     if heap.pop().get() != 3 { abort() }
 }
 
+fn test_collect_extend() {
+    let xs = [2, 9, 4];
+    let mut heap: PriorityQueue<int> = iter(xs).map(|&x| x).collect();
+    if heap.len() != 3 || *heap.top().get() != 9 { abort() }
+    heap.extend(&mut iter(xs).map(|&x| x * 3));
+    if heap.len() != 6 || *heap.top().get() != 27 { abort() }
+    let sorted = heap.to_sorted_vec();
+    let expected = [2, 4, 6, 9, 12, 27];
+    let mut i = 0;
+    while i < 6 {
+        if sorted.as_slice()[i] != expected[i] { abort() }
+        i += 1;
+    }
+}
+
+fn test_indexed() {
+    let mut heap = IndexedPriorityQueue::new();
+    let a = heap.push(5);
+    let b = heap.push(10);
+    let c = heap.push(1);
+    if *heap.top().get() != 10 || !heap.contains(a) { abort() }
+
+    if heap.change_priority(c, 20).ok().get() != 1 { abort() }
+    if *heap.top().get() != 20 || *heap.get(c).get() != 20 { abort() }
+    if heap.change_priority(c, 0).ok().get() != 20 { abort() }
+    if *heap.top().get() != 10 { abort() }
+
+    if heap.remove(b).get() != 10 || heap.contains(b) || heap.remove(b).is_some() { abort() }
+    if heap.change_priority(b, 3).err().get() != 3 { abort() }
+
+    // the slot of `b` is reused, but the old handle stays invalid
+    let d = heap.push(7);
+    if heap.contains(b) || d == b || *heap.get(d).get() != 7 { abort() }
+
+    if heap.pop().get() != 7 || heap.pop().get() != 5 || heap.pop().get() != 0 { abort() }
+    if heap.pop().is_some() || heap.contains(a) { abort() }
+}
+
+fn test_indexed_order() {
+    let mut heap = IndexedPriorityQueue::with_capacity(50);
+    let mut handles = Vec::new();
+    for i in range(0u, 50) {
+        handles.push(heap.push((i * 37 % 50) as int));
+    }
+    for i in range(0u, 50) {
+        let handle = handles.as_slice()[i];
+        if i % 3 == 0 {
+            heap.remove(handle);
+        } else if i % 3 == 1 {
+            heap.change_priority(handle, (i * 7 % 50) as int);
+        }
+    }
+    let mut last = 50;
+    let mut count = 0;
+    loop {
+        match heap.pop() {
+            Some(x) => {
+                if x > last { abort() }
+                last = x;
+                count += 1;
+            }
+            None => break
+        }
+    }
+    if count != 33 { abort() }
+}
+
 #[start]
 fn main(_: int, _: **u8) -> int {
     test_new();
@@ -91,5 +162,8 @@ fn main(_: int, _: **u8) -> int {
     test_reserve();
     test_destructor();
     test_push_top_pop();
+    test_collect_extend();
+    test_indexed();
+    test_indexed_order();
     0
 }
@@ -0,0 +1,60 @@
+#[no_std];
+#[feature(macro_rules, phase)];
+
+#[phase(syntax, link)]
+extern crate core;
+
+use core::fail::abort;
+use core::option::{Some, None};
+use core::result::{Result, Ok, Err};
+
+fn test_ok_err() {
+    let x: Result<int, int> = Ok(5);
+    let y: Result<int, int> = Err(3);
+
+    if !x.is_ok() || x.is_err() || y.is_ok() || !y.is_err() { abort() }
+    if x.ok() != Some(5) || x.err() != None { abort() }
+    if y.ok() != None || y.err() != Some(3) { abort() }
+}
+
+fn test_combinators() {
+    let x: Result<int, int> = Ok(5);
+    let y: Result<int, int> = Err(3);
+
+    if x.map(|v| v * 2).get() != 10 || y.map(|v| v * 2).get_err() != 3 { abort() }
+    if x.map_err(|e| e + 1).get() != 5 || y.map_err(|e| e + 1).get_err() != 4 { abort() }
+
+    if x.and_then(|v| Ok::<int, int>(v + 1)).get() != 6 { abort() }
+    if x.and_then(|v| Err::<int, int>(v)).get_err() != 5 { abort() }
+    if y.and_then(|v| Ok::<int, int>(v + 1)).get_err() != 3 { abort() }
+
+    if x.or_else(|e| Ok::<int, int>(e * 10)).get() != 5 { abort() }
+    if y.or_else(|e| Ok::<int, int>(e * 10)).get() != 30 { abort() }
+    if y.or_else(|e| Err::<int, int>(e + 1)).get_err() != 4 { abort() }
+
+    if x.unwrap_or(0) != 5 || y.unwrap_or(0) != 0 { abort() }
+    if y.unwrap_or_else(|e| e * 2) != 6 { abort() }
+}
+
+fn halve(x: int) -> Result<int, int> {
+    if x % 2 == 0 { Ok(x / 2) } else { Err(x) }
+}
+
+fn quarter(x: int) -> Result<int, int> {
+    let half = try!(halve(x));
+    halve(half)
+}
+
+fn test_try() {
+    if quarter(8).get() != 2 { abort() }
+    if quarter(6).get_err() != 3 { abort() }
+    if quarter(7).get_err() != 7 { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_ok_err();
+    test_combinators();
+    test_try();
+    0
+}
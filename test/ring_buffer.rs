@@ -0,0 +1,81 @@
+#[no_std];
+
+extern crate core;
+
+use core::container::Container;
+use core::fail::abort;
+use core::iter::{Iterator, DoubleEndedIterator, range};
+use core::ring_buffer::{RingBuffer, SliceRingBuffer};
+
+fn test_push_evict() {
+    let mut xs = RingBuffer::new(3);
+    if xs.capacity() != 3 || xs.len() != 0 { abort() }
+    for i in range(0, 3) {
+        if xs.push(i).is_some() { abort() }
+    }
+    if !xs.is_full() { abort() }
+    if xs.push(3).get() != 0 || xs.push(4).get() != 1 { abort() }
+    if xs.len() != 3 || *xs.get(0).get() != 2 || xs.get(3).is_some() { abort() }
+
+    let (a, b) = xs.as_slices();
+    if a != &[2] || b != &[3, 4] { abort() }
+
+    let mut it = xs.iter();
+    let (lower, upper) = it.size_hint();
+    if lower != 3 || upper.get() != 3 { abort() }
+    if *it.next().get() != 2 || *it.next_back().get() != 4 || *it.next().get() != 3 { abort() }
+    if it.next().is_some() { abort() }
+}
+
+fn test_pop() {
+    let mut xs = RingBuffer::new(2);
+    xs.push(1);
+    xs.push(2);
+    xs.push(3);
+    if xs.pop_front().get() != 2 || xs.pop_back().get() != 3 || xs.pop_front().is_some() {
+        abort()
+    }
+    xs.push(4);
+    if *xs.get(0).get() != 4 { abort() }
+}
+
+fn test_zero_capacity() {
+    let mut xs = RingBuffer::new(0);
+    if xs.push(1).get() != 1 || xs.len() != 0 { abort() }
+}
+
+fn test_destructor() {
+    let mut xs = RingBuffer::new(3);
+    for i in range(0, 5) {
+        xs.push(~i);
+    }
+    xs.pop_front();
+}
+
+fn test_slice() {
+    let mut buf = [0, ..4];
+    {
+        let mut xs = SliceRingBuffer::new(buf);
+        if xs.capacity() != 4 { abort() }
+        for i in range(1, 7) {
+            xs.push(i);
+        }
+        if xs.len() != 4 || *xs.iter().next().get() != 3 { abort() }
+        let (a, b) = xs.as_slices();
+        if a != &[3, 4] || b != &[5, 6] { abort() }
+        if xs.pop_back().get() != 6 { abort() }
+        xs.clear();
+        if xs.len() != 0 || xs.pop_front().is_some() { abort() }
+    }
+    if buf[0] != 5 { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_push_evict();
+    test_pop();
+    test_zero_capacity();
+    test_destructor();
+    test_slice();
+    0
+}
@@ -0,0 +1,246 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#[no_std];
+
+extern crate core;
+
+use core::cmp::Ord;
+use core::container::Container;
+use core::fail::abort;
+use core::iter::{Iterator, DoubleEndedIterator, range};
+use core::slice;
+use core::vec::Vec;
+
+// xorshift, to get a reproducible mix of runs and duplicates
+fn random(state: &mut u32) -> u32 {
+    let mut x = *state;
+    x ^= x << 13;
+    x ^= x >> 17;
+    x ^= x << 5;
+    *state = x;
+    x
+}
+
+fn random_vec(len: uint, modulus: u32) -> Vec<u32> {
+    let mut state = 2463534242;
+    Vec::from_fn(len, |_| random(&mut state) % modulus)
+}
+
+fn test_sort() {
+    for &len in slice::iter([0u, 1, 2, 31, 32, 33, 100, 1000]) {
+        let mut xs = random_vec(len, 50);
+        xs.sort();
+        if !xs.is_sorted() || xs.len() != len { abort() }
+    }
+
+    // sort on the key only, and check that the original order of equal keys is kept
+    let keys = random_vec(500, 10);
+    let mut xs = Vec::from_fn(500, |i| (keys.as_slice()[i], i));
+    xs.sort_by(|&(a, _), &(b, _)| a.cmp(&b));
+    for i in range(1, xs.len()) {
+        let (k0, i0) = xs.as_slice()[i - 1];
+        let (k1, i1) = xs.as_slice()[i];
+        if k0 > k1 || (k0 == k1 && i0 > i1) { abort() }
+    }
+}
+
+fn test_sort_unstable() {
+    for &len in slice::iter([0u, 1, 2, 16, 17, 100, 1000]) {
+        let mut xs = random_vec(len, 1000);
+        xs.sort_unstable();
+        if !xs.is_sorted() || xs.len() != len { abort() }
+
+        let mut ys = random_vec(len, 3);
+        ys.sort_unstable_by(|a, b| b.cmp(a));
+        if !slice::is_sorted_by(ys.as_slice(), |a, b| b.cmp(a)) { abort() }
+    }
+
+    let mut sorted = Vec::from_fn(1000, |i| i);
+    sorted.sort_unstable();
+    if !sorted.is_sorted() { abort() }
+    let mut reversed = Vec::from_fn(1000, |i| 1000 - i);
+    slice::sort_unstable(reversed.as_mut_slice());
+    if !reversed.is_sorted() || reversed.as_slice()[0] != 1 { abort() }
+}
+
+fn test_select_nth() {
+    let mut xs = random_vec(1000, 100);
+    let mut sorted = random_vec(1000, 100);
+    sorted.sort();
+    for &n in slice::iter([0u, 1, 500, 998, 999]) {
+        xs.select_nth(n);
+        let nth = xs.as_slice()[n];
+        if nth != sorted.as_slice()[n] { abort() }
+        for i in range(0, n) {
+            if xs.as_slice()[i] > nth { abort() }
+        }
+        for i in range(n + 1, xs.len()) {
+            if xs.as_slice()[i] < nth { abort() }
+        }
+    }
+}
+
+fn test_partition() {
+    let mut xs = random_vec(100, 100);
+    let n = xs.partition(|&x| x % 2 == 0);
+    for i in range(0, xs.len()) {
+        if (xs.as_slice()[i] % 2 == 0) != (i < n) { abort() }
+    }
+
+    let mut empty: [int, ..0] = [];
+    if slice::partition(empty, |_| true) != 0 { abort() }
+}
+
+fn eq(xs: &[int], ys: &[int]) -> bool {
+    if xs.len() != ys.len() { return false }
+    for i in range(0, xs.len()) {
+        if xs[i] != ys[i] { return false }
+    }
+    true
+}
+
+fn test_chunks() {
+    let xs = [1, 2, 3, 4, 5];
+
+    let mut it = slice::chunks(xs, 2);
+    let (lower, upper) = it.size_hint();
+    if lower != 3 || upper != Some(3) { abort() }
+    if !eq(it.next().get(), [1, 2]) || !eq(it.next_back().get(), [5]) { abort() }
+    if !eq(it.next().get(), [3, 4]) || it.next().is_some() { abort() }
+
+    let mut ys = [1, 2, 3, 4, 5];
+    for chunk in slice::mut_chunks(ys, 2).invert() {
+        chunk[0] = 0;
+    }
+    if !eq(ys, [0, 2, 0, 4, 0]) { abort() }
+
+    let units = [(), (), (), (), ()];
+    let mut it = slice::chunks(units, 2);
+    if it.next_back().get().len() != 1 || it.next().get().len() != 2 || it.count() != 1 { abort() }
+
+    let mut it = slice::exact_chunks(xs, 2);
+    if !eq(it.remainder(), [5]) || it.count() != 2 { abort() }
+    if slice::exact_chunks(xs, 6).next().is_some() { abort() }
+}
+
+fn test_windows() {
+    let xs = [1, 2, 3, 4];
+    let mut it = slice::windows(xs, 3);
+    let (lower, upper) = it.size_hint();
+    if lower != 2 || upper != Some(2) { abort() }
+    if !eq(it.next_back().get(), [2, 3, 4]) || !eq(it.next().get(), [1, 2, 3]) { abort() }
+    if it.next().is_some() { abort() }
+    if slice::windows(xs, 5).next().is_some() { abort() }
+}
+
+fn test_split() {
+    let xs = [1, 0, 2, 3, 0, 0, 4];
+
+    let mut it = slice::split_by(xs, |&x| x == 0);
+    if !eq(it.next().get(), [1]) || !eq(it.next().get(), [2, 3]) { abort() }
+    if !eq(it.next().get(), []) || !eq(it.next().get(), [4]) || it.next().is_some() { abort() }
+    if slice::split_by([], |&x: &int| x == 0).count() != 1 { abort() }
+    if slice::split_by([0], |&x: &int| x == 0).count() != 2 { abort() }
+
+    let mut it = slice::rsplit(xs, |&x| x == 0);
+    if !eq(it.next().get(), [4]) || !eq(it.next().get(), []) { abort() }
+    if it.count() != 2 { abort() }
+
+    let mut it = slice::splitn(xs, 1, |&x| x == 0);
+    if !eq(it.next().get(), [1]) || !eq(it.next().get(), [2, 3, 0, 0, 4]) { abort() }
+    if it.next().is_some() { abort() }
+
+    let mut it = slice::rsplitn(xs, 2, |&x| x == 0);
+    if !eq(it.next().get(), [4]) || !eq(it.next().get(), []) { abort() }
+    if !eq(it.next().get(), [1, 0, 2, 3]) || it.next().is_some() { abort() }
+}
+
+fn test_search() {
+    let xs = [1, 2, 3, 2, 1];
+    if !slice::contains(xs, &3) || slice::contains(xs, &4) { abort() }
+    if slice::position_elem(xs, &2) != Some(1) || slice::rposition_elem(xs, &2) != Some(3) {
+        abort()
+    }
+    if !slice::starts_with(xs, [1, 2]) || slice::starts_with(xs, [2]) { abort() }
+    if !slice::ends_with(xs, [2, 1]) || slice::ends_with(xs, [1, 2, 3, 2, 1, 0]) { abort() }
+
+    let bytes = bytes!("the quick brown fox jumps over the lazy dog");
+    for i in range(0, bytes.len()) {
+        let b = bytes[i];
+        if slice::memchr(bytes, b) != slice::position_elem(bytes, &b) { abort() }
+        if slice::memrchr(bytes, b) != slice::rposition_elem(bytes, &b) { abort() }
+        if slice::memchr(slice::slice_from(bytes, i), b) != Some(0) { abort() }
+    }
+    if slice::memchr(bytes, 'Z' as u8).is_some() || slice::memrchr([], 0).is_some() { abort() }
+
+    if slice::find_bytes(bytes, bytes!("the")) != Some(0) { abort() }
+    if slice::find_bytes(bytes, bytes!("lazy dog")) != Some(35) { abort() }
+    if slice::find_bytes(bytes, bytes!("")) != Some(0) { abort() }
+    if slice::find_bytes(bytes, bytes!("lazy cat")).is_some() { abort() }
+    if slice::find_bytes(bytes!("aaabaaabaaab"), bytes!("aabaaab")) != Some(1) { abort() }
+    if slice::find_bytes(bytes!("abababac"), bytes!("ababac")) != Some(2) { abort() }
+    if slice::find_bytes(bytes!("ab"), bytes!("abc")).is_some() { abort() }
+
+    if slice::rfind_bytes(bytes, bytes!("the")) != Some(31) { abort() }
+    if slice::rfind_bytes(bytes, bytes!("dog")) != Some(40) { abort() }
+    if slice::rfind_bytes(bytes, bytes!("")) != Some(bytes.len()) { abort() }
+    if slice::rfind_bytes(bytes, bytes!("lazy cat")).is_some() { abort() }
+    if slice::rfind_bytes(bytes!("aaabaaabaaab"), bytes!("aabaaab")) != Some(5) { abort() }
+    if slice::rfind_bytes(bytes!("cabababa"), bytes!("ababa")) != Some(3) { abort() }
+    if slice::rfind_bytes(bytes!("ab"), bytes!("abc")).is_some() { abort() }
+}
+
+fn test_rearrange() {
+    let mut xs = [1, 2, 3, 4, 5];
+    slice::reverse(xs);
+    if !eq(xs, [5, 4, 3, 2, 1]) { abort() }
+    slice::rotate_left(xs, 2);
+    if !eq(xs, [3, 2, 1, 5, 4]) { abort() }
+    slice::rotate_right(xs, 1);
+    if !eq(xs, [4, 3, 2, 1, 5]) { abort() }
+    slice::rotate_left(xs, 5);
+    if !eq(xs, [4, 3, 2, 1, 5]) { abort() }
+
+    if slice::copy_from(xs, [7, 8]) != 2 || !eq(xs, [7, 8, 2, 1, 5]) { abort() }
+    slice::fill(slice::mut_slice_from(xs, 3), &0);
+    if !eq(xs, [7, 8, 2, 0, 0]) { abort() }
+}
+
+fn test_cmp() {
+    let a = [1, 2, 3];
+    let b = [1, 2, 4];
+    if slice::slice_to(a, 2) != slice::slice_to(b, 2) { abort() }
+    if slice::slice_to(a, 3) == slice::slice_to(b, 3) { abort() }
+    if !(slice::slice_to(a, 3) < slice::slice_to(b, 3)) { abort() }
+    if !(slice::slice_to(a, 2) < slice::slice_to(a, 3)) { abort() }
+
+    let x = Vec::from_fn(3, |i| i);
+    let mut y = Vec::from_fn(3, |i| i);
+    if x != y { abort() }
+    y.push(0);
+    if !(x < y) || x == y { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_sort();
+    test_sort_unstable();
+    test_select_nth();
+    test_partition();
+    test_chunks();
+    test_windows();
+    test_split();
+    test_search();
+    test_rearrange();
+    test_cmp();
+    0
+}
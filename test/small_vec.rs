@@ -0,0 +1,55 @@
+#[no_std];
+
+extern crate core;
+
+use core::container::Container;
+use core::fail::abort;
+use core::iter::{Iterator, DoubleEndedIterator, range};
+use core::small_vec::SmallVec;
+
+fn test_push_pop() {
+    let mut xs: SmallVec<int, [int, ..4]> = SmallVec::new();
+    for i in range(0, 4) {
+        xs.push(i);
+    }
+    if xs.spilled() || xs.capacity() != 4 { abort() }
+    xs.push(4);
+    if !xs.spilled() || xs.capacity() < 5 { abort() }
+    if xs.as_slice() != &[0, 1, 2, 3, 4] { abort() }
+    if xs.pop().get() != 4 || xs.len() != 4 { abort() }
+    xs.truncate(1);
+    if xs.as_slice() != &[0] { abort() }
+    xs.clear();
+    if xs.pop().is_some() { abort() }
+}
+
+fn test_move_iter() {
+    let xs: SmallVec<~int, [~int, ..2]> = range(0, 2).map(|i| ~i).collect();
+    if xs.spilled() { abort() }
+    let mut it = xs.move_iter();
+    if *it.next_back().get() != 1 || *it.next().get() != 0 || it.next().is_some() { abort() }
+
+    let ys: SmallVec<~int, [~int, ..2]> = range(0, 5).map(|i| ~i).collect();
+    if !ys.spilled() { abort() }
+    let mut it = ys.move_iter();
+    let (lower, upper) = it.size_hint();
+    if lower != 5 || upper.get() != 5 { abort() }
+    if *it.next().get() != 0 || *it.next_back().get() != 4 { abort() }
+}
+
+fn test_destructor() {
+    let mut xs: SmallVec<~int, [~int, ..2]> = SmallVec::new();
+    xs.push(~1);
+    let mut ys: SmallVec<~int, [~int, ..2]> = SmallVec::new();
+    for i in range(0, 3) {
+        ys.push(~i);
+    }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_push_pop();
+    test_move_iter();
+    test_destructor();
+    0
+}
@@ -0,0 +1,99 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#[no_std];
+
+extern crate core;
+
+use core::char;
+use core::container::Container;
+use core::fail::abort;
+use core::iter::{Iterator, DoubleEndedIterator};
+use core::option::{Some, None};
+use core::str;
+
+fn test_chars() {
+    let s = "aé€\U0001F600";
+    if str::char_len(s) != 4 { abort() }
+
+    let mut it = str::chars(s);
+    if it.next() != Some('a') || it.next_back() != Some('\U0001F600') { abort() }
+    if it.next() != Some('é') || it.next_back() != Some('€') || it.next().is_some() { abort() }
+
+    let mut it = str::char_indices(s);
+    match it.next_back() {
+        Some((6, '\U0001F600')) => (),
+        _ => abort()
+    }
+    match it.nth(2) {
+        Some((3, '€')) => (),
+        _ => abort()
+    }
+}
+
+fn test_find() {
+    let s = "key = value # comment";
+    if str::find(s, '=') != Some(4) || str::rfind(s, ' ') != Some(13) { abort() }
+    let seps: &[char] = &['#', '='];
+    if str::find(s, seps) != Some(4) { abort() }
+    if str::find(s, |c: char| c == 'v') != Some(6) || str::find(s, 'x').is_some() { abort() }
+    if str::find_str(s, "value") != Some(6) || str::find_str(s, "values").is_some() { abort() }
+    if str::rfind_str("abcabc", "bc") != Some(4) || str::rfind_str("ab", "abc").is_some() {
+        abort()
+    }
+
+    if !str::starts_with(s, "key") || str::starts_with(s, "value") { abort() }
+    if !str::ends_with(s, "comment") || str::ends_with(s, "key") { abort() }
+}
+
+fn test_split() {
+    let mut it = str::split("a,b,,c", ',');
+    if it.next() != Some("a") || it.next_back() != Some("c") { abort() }
+    if it.next() != Some("b") || it.next() != Some("") || it.next().is_some() { abort() }
+
+    let mut it = str::splitn("a=b=c", 1, '=');
+    if it.next() != Some("a") || it.next() != Some("b=c") || it.next().is_some() { abort() }
+
+    let mut it = str::lines("one\r\ntwo\n\nthree\n");
+    if it.next() != Some("one") || it.next_back() != Some("three") { abort() }
+    if it.next() != Some("two") || it.next() != Some("") || it.next().is_some() { abort() }
+    if str::lines("").count() != 0 || str::lines("\n").count() != 1 { abort() }
+
+    let mut it = str::words("  the\tquick 　 fox ");
+    if it.next() != Some("the") || it.next_back() != Some("fox") { abort() }
+    if it.next() != Some("quick") || it.next().is_some() { abort() }
+}
+
+fn test_trim() {
+    if str::trim(" \t x y \n") != "x y" { abort() }
+    if str::trim_left("  x ") != "x " || str::trim_right("  x ") != "  x" { abort() }
+    if str::trim("  é ") != "é" || str::trim("   ") != "" { abort() }
+    if !char::is_whitespace('\x85') || char::is_whitespace('x') { abort() }
+}
+
+fn test_replace() {
+    let s = str::replace("a.b.c", ".", "::");
+    if s.as_slice() != "a::b::c" { abort() }
+    let s = str::replace("aaa", "aa", "b");
+    if s.as_slice() != "ba" { abort() }
+    let s = str::replace("abc", "", "x");
+    if s.as_slice() != "abc" { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_chars();
+    test_find();
+    test_split();
+    test_trim();
+    test_replace();
+    0
+}
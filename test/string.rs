@@ -0,0 +1,101 @@
+// Copyright 2014 The Rust Project Developers. See the COPYRIGHT
+// file at the top-level directory of this distribution and at
+// http://rust-lang.org/COPYRIGHT.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+
+#[no_std];
+
+extern crate core;
+
+use core::container::Container;
+use core::fail::abort;
+use core::hash::HashMap;
+use core::iter::Iterator;
+use core::lru::LruCache;
+use core::option::{Some, None};
+use core::result::{Ok, Err};
+use core::slice;
+use core::str;
+use core::string::String;
+use core::vec::Vec;
+
+fn test_push_pop() {
+    let mut s = String::new();
+    s.push_str("a");
+    s.push_char('é');
+    s.push_char('€');
+    s.push_char('\U0001F600');
+    if s.len() != 10 || s.as_slice() != "aé€\U0001F600" { abort() }
+
+    if s.pop_char() != Some('\U0001F600') || s.pop_char() != Some('€') { abort() }
+    s.truncate(1);
+    if s.as_slice() != "a" || s.pop_char() != Some('a') || s.pop_char() != None { abort() }
+
+    let mut s = String::with_capacity(4);
+    if s.capacity() < 4 { abort() }
+    s.reserve(16);
+    if s.capacity() < 16 || s.len() != 0 { abort() }
+
+    let s: String = slice::iter(['x', 'ÿ', 'z']).map(|&c| c).collect();
+    if s.as_slice() != "xÿz" { abort() }
+}
+
+fn bytes(v: &[u8]) -> Vec<u8> {
+    let mut xs = Vec::new();
+    for &b in slice::iter(v) {
+        xs.push(b);
+    }
+    xs
+}
+
+fn test_from_utf8() {
+    match String::from_utf8(bytes(bytes!("caf", 0xC3, 0xA9))) {
+        Ok(s) => if s.as_slice() != "café" { abort() },
+        Err(_) => abort()
+    }
+
+    let invalid: [&[u8], ..6] = [
+        &[0x80], &[0xC0, 0x80], &[0xE0, 0x80, 0x80], &[0xED, 0xA0, 0x80],
+        &[0xF4, 0x90, 0x80, 0x80], &[0xE2, 0x82]
+    ];
+    for &v in slice::iter(invalid) {
+        if str::is_utf8(v) || str::from_utf8(v).is_some() { abort() }
+        match String::from_utf8(bytes(v)) {
+            Ok(_) => abort(),
+            Err(xs) => if xs.len() != v.len() { abort() }
+        }
+    }
+
+    let s = String::from_utf8_lossy(bytes!("a", 0xE2, 0x82, "b", 0xC0, 0x80, "c", 0xF0, 0x9F));
+    if s.as_slice() != "a�b��c�" { abort() }
+}
+
+fn test_traits() {
+    let a = String::from_str("apple");
+    let b = String::from_str("banana");
+    if a != a.clone() || a == b || !(a < b) { abort() }
+
+    let mut map = HashMap::with_capacity_and_keys(0, 0, 8);
+    map.swap(a.clone(), 1);
+    map.swap(b.clone(), 2);
+    if *map.find(&String::from_str("banana")).get() != 2 { abort() }
+
+    let mut cache = LruCache::new(0, 0, 1);
+    cache.put(a.clone(), 1);
+    cache.put(b.clone(), 2);
+    if cache.get(&a).is_some() || *cache.get(&b).get() != 2 { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_push_pop();
+    test_from_utf8();
+    test_traits();
+    0
+}
@@ -13,7 +13,7 @@
 extern crate core;
 
 use core::thread::Pool;
-use core::io::stderr;
+use core::io::{Writer, stderr};
 use core::time::{Time, sleep};
 
 #[start]
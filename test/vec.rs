@@ -0,0 +1,168 @@
+#[no_std];
+
+extern crate core;
+
+use core::container::Container;
+use core::fail::abort;
+use core::iter::{Iterator, DoubleEndedIterator, Extendable, range};
+use core::ops::Drop;
+use core::slice::iter;
+use core::vec::Vec;
+
+static mut DROPS: uint = 0;
+
+struct Counted(int);
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        unsafe { DROPS += 1 }
+    }
+}
+
+fn drops() -> uint {
+    unsafe { DROPS }
+}
+
+fn from_range(start: int, end: int) -> Vec<int> {
+    let mut xs = Vec::new();
+    for i in range(start, end) {
+        xs.push(i);
+    }
+    xs
+}
+
+fn test_insert_remove() {
+    let mut xs = from_range(0, 5);
+    xs.insert(0, 10);
+    xs.insert(3, 11);
+    xs.insert(7, 12);
+    if xs.as_slice() != &[10, 0, 1, 11, 2, 3, 4, 12] { abort() }
+
+    if xs.remove(8).is_some() { abort() }
+    if xs.remove(3).get() != 11 { abort() }
+    if xs.remove(0).get() != 10 { abort() }
+    if xs.remove(5).get() != 12 { abort() }
+    if xs.as_slice() != &[0, 1, 2, 3, 4] { abort() }
+
+    if xs.swap_remove(5).is_some() { abort() }
+    if xs.swap_remove(1).get() != 1 { abort() }
+    if xs.as_slice() != &[0, 4, 2, 3] { abort() }
+    if xs.swap_remove(3).get() != 3 { abort() }
+    if xs.as_slice() != &[0, 4, 2] { abort() }
+}
+
+fn test_truncate_clear() {
+    let mut xs = from_range(0, 5);
+    xs.truncate(10);
+    if xs.len() != 5 { abort() }
+    xs.truncate(2);
+    if xs.as_slice() != &[0, 1] { abort() }
+    xs.clear();
+    if xs.len() != 0 || xs.capacity() == 0 { abort() }
+}
+
+fn test_retain() {
+    let mut xs = from_range(0, 10);
+    xs.retain(|x| *x % 3 != 0);
+    if xs.as_slice() != &[1, 2, 4, 5, 7, 8] { abort() }
+    xs.retain(|_| false);
+    if xs.len() != 0 { abort() }
+}
+
+fn test_dedup() {
+    let mut xs = Vec::new();
+    for &x in iter(&[1, 1, 2, 3, 3, 3, 1, 4, 4]) {
+        xs.push(x);
+    }
+    xs.dedup();
+    if xs.as_slice() != &[1, 2, 3, 1, 4] { abort() }
+
+    let mut xs = from_range(0, 10);
+    xs.dedup_by(|a, b| *a / 4 == *b / 4);
+    if xs.as_slice() != &[0, 4, 8] { abort() }
+}
+
+fn test_drain() {
+    let mut xs = from_range(0, 8);
+    {
+        let mut drain = xs.drain(2, 6);
+        if drain.next().get() != 2 { abort() }
+        if drain.next_back().get() != 5 { abort() }
+    }
+    if xs.as_slice() != &[0, 1, 6, 7] { abort() }
+
+    let drained: Vec<int> = xs.drain(1, 4).collect();
+    if drained.as_slice() != &[1, 6, 7] || xs.as_slice() != &[0] { abort() }
+}
+
+fn test_split_off_append() {
+    let mut xs = from_range(0, 6);
+    let mut ys = xs.split_off(4);
+    if xs.as_slice() != &[0, 1, 2, 3] || ys.as_slice() != &[4, 5] { abort() }
+    xs.append(&mut ys);
+    if xs.as_slice() != &[0, 1, 2, 3, 4, 5] || ys.len() != 0 { abort() }
+    let zs = xs.split_off(6);
+    if zs.len() != 0 || xs.len() != 6 { abort() }
+
+    xs.extend_from_slice(&[6, 7]);
+    if xs.as_slice() != &[0, 1, 2, 3, 4, 5, 6, 7] { abort() }
+}
+
+fn test_splice() {
+    let mut xs = from_range(0, 6);
+    let removed = xs.splice(1, 4, &mut range(10, 15));
+    if removed.as_slice() != &[1, 2, 3] { abort() }
+    if xs.as_slice() != &[0, 10, 11, 12, 13, 14, 4, 5] { abort() }
+}
+
+fn test_index_clone_eq() {
+    let xs = from_range(0, 4);
+    if xs[2] != 2 { abort() }
+    let ys = xs.clone();
+    if xs != ys || xs < ys { abort() }
+    let zs = from_range(0, 5);
+    if !(xs < zs) || xs == zs { abort() }
+}
+
+fn test_collect_extend() {
+    let mut xs: Vec<int> = range(0, 4).map(|x| x * 10).collect();
+    if xs.as_slice() != &[0, 10, 20, 30] || xs.capacity() < 4 { abort() }
+    xs.extend(&mut range(0, 10).filter(|x| *x % 4 == 1));
+    if xs.as_slice() != &[0, 10, 20, 30, 1, 5, 9] { abort() }
+    let empty: Vec<int> = range(0, 0).collect();
+    if empty.len() != 0 { abort() }
+}
+
+fn test_destructors() {
+    let mut xs = Vec::new();
+    for i in range(0, 10) {
+        xs.push(Counted(i));
+    }
+    let base = drops();
+    xs.retain(|&Counted(i)| i % 2 == 0);
+    if drops() - base != 5 { abort() }
+    xs.dedup_by(|&Counted(a), &Counted(b)| a / 4 == b / 4);
+    if drops() - base != 7 || xs.len() != 3 { abort() }
+    {
+        let mut drain = xs.drain(0, 2);
+        drain.next();
+    }
+    if drops() - base != 9 || xs.len() != 1 { abort() }
+    xs.remove(0);
+    if drops() - base != 10 { abort() }
+}
+
+#[start]
+fn main(_: int, _: **u8) -> int {
+    test_insert_remove();
+    test_truncate_clear();
+    test_retain();
+    test_dedup();
+    test_drain();
+    test_split_off_append();
+    test_splice();
+    test_index_clone_eq();
+    test_collect_extend();
+    test_destructors();
+    0
+}